@@ -1,6 +1,7 @@
 use super::*;
+use crate::datatypes::PrimitiveType;
 use crate::offset::Offset;
-use crate::types::NativeType;
+use crate::types::{NativeType, months_days_ns};
 
 mod binary;
 mod binary_view;
@@ -217,6 +218,11 @@ pub fn equal(lhs: &dyn Array, rhs: &dyn Array) -> bool {
             let rhs = rhs.as_any().downcast_ref().unwrap();
             boolean::equal(lhs, rhs)
         },
+        Primitive(PrimitiveType::MonthDayNano) => {
+            let lhs = lhs.as_any().downcast_ref().unwrap();
+            let rhs = rhs.as_any().downcast_ref().unwrap();
+            primitive::equal::<months_days_ns>(lhs, rhs)
+        },
         Primitive(primitive) => with_match_primitive_type_full!(primitive, |$T| {
             let lhs = lhs.as_any().downcast_ref().unwrap();
             let rhs = rhs.as_any().downcast_ref().unwrap();
@@ -427,6 +427,174 @@ pub fn new_null_array(dtype: ArrowDataType, length: usize) -> Box<dyn Array> {
     }
 }
 
+/// Returns `array` with its [`ArrowDataType`] replaced by `dtype`, without copying the data.
+///
+/// This is used to add or remove an [`ArrowDataType::Extension`] from an array.
+///
+/// # Errors
+/// Errors if `dtype` has a different physical type than `array`, or if `array` is a union.
+pub fn with_dtype(array: &dyn Array, dtype: ArrowDataType) -> PolarsResult<Box<dyn Array>> {
+    use crate::datatypes::PhysicalType::*;
+    polars_error::polars_ensure!(
+        array.dtype().to_physical_type() == dtype.to_physical_type(),
+        SchemaMismatch: "cannot replace the dtype {:?} of an array by {:?}", array.dtype(), dtype
+    );
+    let validity = array.validity().cloned();
+    let any = array.as_any();
+    Ok(match dtype.to_physical_type() {
+        Null => Box::new(NullArray::new(dtype, array.len())),
+        Boolean => {
+            let array = any.downcast_ref::<BooleanArray>().unwrap();
+            Box::new(BooleanArray::new(dtype, array.values().clone(), validity))
+        },
+        Primitive(crate::datatypes::PrimitiveType::MonthDayNano) => Box::new(
+            any.downcast_ref::<PrimitiveArray<crate::types::months_days_ns>>()
+                .unwrap()
+                .clone()
+                .to(dtype),
+        ),
+        Primitive(primitive) => with_match_primitive_type_full!(primitive, |$T| {
+            Box::new(any.downcast_ref::<PrimitiveArray<$T>>().unwrap().clone().to(dtype))
+        }),
+        Binary => {
+            let array = any.downcast_ref::<BinaryArray<i32>>().unwrap();
+            Box::new(BinaryArray::new(
+                dtype,
+                array.offsets().clone(),
+                array.values().clone(),
+                validity,
+            ))
+        },
+        LargeBinary => {
+            let array = any.downcast_ref::<BinaryArray<i64>>().unwrap();
+            Box::new(BinaryArray::new(
+                dtype,
+                array.offsets().clone(),
+                array.values().clone(),
+                validity,
+            ))
+        },
+        FixedSizeBinary => Box::new(
+            any.downcast_ref::<FixedSizeBinaryArray>()
+                .unwrap()
+                .clone()
+                .to(dtype),
+        ),
+        Utf8 => {
+            let array = any.downcast_ref::<Utf8Array<i32>>().unwrap();
+            // SAFETY: the values are valid utf8 as they come from a utf8 array.
+            Box::new(unsafe {
+                Utf8Array::new_unchecked(
+                    dtype,
+                    array.offsets().clone(),
+                    array.values().clone(),
+                    validity,
+                )
+            })
+        },
+        LargeUtf8 => {
+            let array = any.downcast_ref::<Utf8Array<i64>>().unwrap();
+            // SAFETY: the values are valid utf8 as they come from a utf8 array.
+            Box::new(unsafe {
+                Utf8Array::new_unchecked(
+                    dtype,
+                    array.offsets().clone(),
+                    array.values().clone(),
+                    validity,
+                )
+            })
+        },
+        List => {
+            let array = any.downcast_ref::<ListArray<i32>>().unwrap();
+            Box::new(ListArray::new(
+                dtype,
+                array.offsets().clone(),
+                array.values().clone(),
+                validity,
+            ))
+        },
+        LargeList => {
+            let array = any.downcast_ref::<ListArray<i64>>().unwrap();
+            Box::new(ListArray::new(
+                dtype,
+                array.offsets().clone(),
+                array.values().clone(),
+                validity,
+            ))
+        },
+        FixedSizeList => {
+            let array = any.downcast_ref::<FixedSizeListArray>().unwrap();
+            Box::new(FixedSizeListArray::new(
+                dtype,
+                array.len(),
+                array.values().clone(),
+                validity,
+            ))
+        },
+        Struct => {
+            let array = any.downcast_ref::<StructArray>().unwrap();
+            Box::new(StructArray::new(
+                dtype,
+                array.len(),
+                array.values().to_vec(),
+                validity,
+            ))
+        },
+        Map => {
+            let array = any.downcast_ref::<MapArray>().unwrap();
+            Box::new(MapArray::new(
+                dtype,
+                array.offsets().clone(),
+                array.field().clone(),
+                validity,
+            ))
+        },
+        BinaryView => {
+            let array = any.downcast_ref::<BinaryViewArray>().unwrap();
+            // SAFETY: the views and buffers come from a valid array.
+            Box::new(unsafe {
+                BinaryViewArray::new_unchecked(
+                    dtype,
+                    array.views().clone(),
+                    array.data_buffers().clone(),
+                    validity,
+                    array.total_bytes_len(),
+                    array.total_buffer_len(),
+                )
+            })
+        },
+        Utf8View => {
+            let array = any.downcast_ref::<Utf8ViewArray>().unwrap();
+            // SAFETY: the views and buffers come from a valid array.
+            Box::new(unsafe {
+                Utf8ViewArray::new_unchecked(
+                    dtype,
+                    array.views().clone(),
+                    array.data_buffers().clone(),
+                    validity,
+                    array.total_bytes_len(),
+                    array.total_buffer_len(),
+                )
+            })
+        },
+        Dictionary(key_type) => {
+            match_integer_type!(key_type, |$T| {
+                let array = any.downcast_ref::<DictionaryArray<$T>>().unwrap();
+                // SAFETY: the keys come from a valid dictionary array.
+                Box::new(unsafe {
+                    DictionaryArray::<$T>::try_new_unchecked(
+                        dtype,
+                        array.keys().clone(),
+                        array.values().clone(),
+                    )
+                    .unwrap()
+                })
+            })
+        },
+        Union => polars_error::polars_bail!(nyi = "replacing the dtype of a union array"),
+    })
+}
+
 macro_rules! clone_dyn {
     ($array:expr, $ty:ty) => {{
         let f = |x: &$ty| Box::new(x.clone());
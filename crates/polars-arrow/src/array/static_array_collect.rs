@@ -353,6 +353,7 @@ impl TrivialIntoBytes for Cow<'_, [u8]> {}
 impl TrivialIntoBytes for &[u8] {}
 impl TrivialIntoBytes for String {}
 impl TrivialIntoBytes for &str {}
+impl<const N: usize> TrivialIntoBytes for [u8; N] {}
 impl<'a> IntoBytes for Cow<'a, str> {
     type AsRefT = Cow<'a, [u8]>;
     fn into_bytes(self) -> Cow<'a, [u8]> {
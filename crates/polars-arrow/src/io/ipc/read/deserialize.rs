@@ -7,8 +7,9 @@ use polars_error::PolarsResult;
 use super::array::*;
 use super::{Dictionaries, IpcBuffer, Node};
 use crate::array::*;
-use crate::datatypes::{ArrowDataType, Field, PhysicalType};
+use crate::datatypes::{ArrowDataType, Field, PhysicalType, PrimitiveType};
 use crate::io::ipc::IpcField;
+use crate::types::months_days_ns;
 use crate::{match_integer_type, with_match_primitive_type_full};
 
 #[allow(clippy::too_many_arguments)]
@@ -44,6 +45,18 @@ pub fn read<R: Read + Seek>(
             scratch,
         )
         .map(|x| x.boxed()),
+        Primitive(PrimitiveType::MonthDayNano) => read_primitive::<months_days_ns, _>(
+            field_nodes,
+            dtype,
+            buffers,
+            reader,
+            block_offset,
+            is_little_endian,
+            compression,
+            limit,
+            scratch,
+        )
+        .map(|x| x.boxed()),
         Primitive(primitive) => with_match_primitive_type_full!(primitive, |$T| {
             read_primitive::<$T, _>(
                 field_nodes,
@@ -656,18 +656,18 @@ impl TotalEq for f16 {
 
 impl TotalOrd for f16 {
     #[inline]
-    fn tot_cmp(&self, _other: &Self) -> std::cmp::Ordering {
-        unimplemented!()
+    fn tot_cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.to_f32().tot_cmp(&other.to_f32())
     }
 }
 
 impl MinMax for f16 {
-    fn nan_min_lt(&self, _other: &Self) -> bool {
-        unimplemented!()
+    fn nan_min_lt(&self, other: &Self) -> bool {
+        self.to_f32().nan_min_lt(&other.to_f32())
     }
 
-    fn nan_max_lt(&self, _other: &Self) -> bool {
-        unimplemented!()
+    fn nan_max_lt(&self, other: &Self) -> bool {
+        self.to_f32().nan_max_lt(&other.to_f32())
     }
 }
 
@@ -762,6 +762,21 @@ impl TotalOrd for i256 {
     }
 }
 
+impl ToTotalOrd for i256 {
+    type TotalOrdItem = i256;
+    type SourceItem = i256;
+
+    #[inline]
+    fn to_total_ord(&self) -> Self::TotalOrdItem {
+        *self
+    }
+
+    #[inline]
+    fn peel_total_ord(ord_item: Self::TotalOrdItem) -> Self::SourceItem {
+        ord_item
+    }
+}
+
 impl MinMax for i256 {
     fn nan_min_lt(&self, other: &Self) -> bool {
         self < other
@@ -27,7 +27,7 @@ macro_rules! with_match_primitive_type_full {(
 ) => ({
     macro_rules! __with_ty__ {( $_ $T:ident ) => ( $($body)* )}
     use $crate::datatypes::PrimitiveType::*;
-    use $crate::types::{f16};
+    use $crate::types::{f16, i256};
     match $key_type {
         Int8 => __with_ty__! { i8 },
         Int16 => __with_ty__! { i16 },
@@ -38,6 +38,7 @@ macro_rules! with_match_primitive_type_full {(
         UInt32 => __with_ty__! { u32 },
         UInt64 => __with_ty__! { u64 },
         Int128 => __with_ty__! { i128 },
+        Int256 => __with_ty__! { i256 },
         Float16 => __with_ty__! { f16 },
         Float32 => __with_ty__! { f32 },
         Float64 => __with_ty__! { f64 },
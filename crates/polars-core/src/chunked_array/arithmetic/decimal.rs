@@ -1,13 +1,41 @@
 use super::*;
+use crate::chunked_array::ops::decimal::{
+    DEC128_MAX_PREC, dec128_add, dec128_div, dec128_mul, dec128_rem, dec128_sub,
+};
+use crate::prelude::arity::broadcast_try_binary_elementwise;
+
+/// Apply an exact decimal kernel elementwise, `None` from the kernel means the result
+/// doesn't fit the decimal precision and is raised as an error.
+fn checked_decimal_op<F>(
+    lhs: &DecimalChunked,
+    rhs: &DecimalChunked,
+    scale: usize,
+    op_name: &str,
+    op: F,
+) -> PolarsResult<DecimalChunked>
+where
+    F: Fn(i128, i128) -> Option<Option<i128>>,
+{
+    let out = broadcast_try_binary_elementwise(&lhs.0, &rhs.0, |l, r| {
+        match (l, r) {
+        (Some(l), Some(r)) => op(l, r).ok_or_else(
+            || polars_err!(ComputeError: "decimal overflow in {}: result does not fit Decimal(38, {})", op_name, scale),
+        ),
+        _ => Ok(None),
+    }
+    })?;
+    Ok(out.into_decimal_unchecked(None, scale))
+}
 
 impl Add for &DecimalChunked {
     type Output = PolarsResult<DecimalChunked>;
 
     fn add(self, rhs: Self) -> Self::Output {
-        let scale = _get_decimal_scale_add_sub(self.scale(), rhs.scale());
-        let lhs = self.to_scale(scale)?;
-        let rhs = rhs.to_scale(scale)?;
-        Ok((&lhs.0 + &rhs.0).into_decimal_unchecked(None, scale))
+        let (ls, rs) = (self.scale(), rhs.scale());
+        let scale = _get_decimal_scale_add_sub(ls, rs);
+        checked_decimal_op(self, rhs, scale, "addition", |l, r| {
+            dec128_add(l, ls, r, rs).map(Some)
+        })
     }
 }
 
@@ -15,10 +43,35 @@ impl Sub for &DecimalChunked {
     type Output = PolarsResult<DecimalChunked>;
 
     fn sub(self, rhs: Self) -> Self::Output {
-        let scale = _get_decimal_scale_add_sub(self.scale(), rhs.scale());
-        let lhs = self.to_scale(scale)?;
-        let rhs = rhs.to_scale(scale)?;
-        Ok((&lhs.0 - &rhs.0).into_decimal_unchecked(None, scale))
+        let (ls, rs) = (self.scale(), rhs.scale());
+        let scale = _get_decimal_scale_add_sub(ls, rs);
+        checked_decimal_op(self, rhs, scale, "subtraction", |l, r| {
+            dec128_sub(l, ls, r, rs).map(Some)
+        })
+    }
+}
+
+impl DecimalChunked {
+    /// Multiply, rounding the excess digits of the result with `mode`.
+    pub fn mul_with_mode(&self, rhs: &Self, mode: RoundMode) -> PolarsResult<Self> {
+        let (ls, rs) = (self.scale(), rhs.scale());
+        let scale = _get_decimal_scale_mul(ls, rs);
+        checked_decimal_op(self, rhs, scale, "multiplication", |l, r| {
+            dec128_mul(l, ls, r, rs, scale, mode).map(Some)
+        })
+    }
+
+    /// Divide, rounding the excess digits of the result with `mode`. Division by zero results
+    /// in null.
+    pub fn div_with_mode(&self, rhs: &Self, mode: RoundMode) -> PolarsResult<Self> {
+        let (ls, rs) = (self.scale(), rhs.scale());
+        let scale = _get_decimal_scale_div(ls);
+        checked_decimal_op(self, rhs, scale, "division", |l, r| {
+            if r == 0 {
+                return Some(None);
+            }
+            dec128_div(l, ls, r, rs, scale, mode).map(Some)
+        })
     }
 }
 
@@ -26,18 +79,32 @@ impl Mul for &DecimalChunked {
     type Output = PolarsResult<DecimalChunked>;
 
     fn mul(self, rhs: Self) -> Self::Output {
-        let scale = _get_decimal_scale_mul(self.scale(), rhs.scale());
-        Ok((&self.0 * &rhs.0).into_decimal_unchecked(None, scale))
+        self.mul_with_mode(rhs, RoundMode::default())
     }
 }
 
 impl Div for &DecimalChunked {
     type Output = PolarsResult<DecimalChunked>;
 
+    /// Division by zero results in null.
     fn div(self, rhs: Self) -> Self::Output {
-        let scale = _get_decimal_scale_div(self.scale());
-        let lhs = self.to_scale(scale + rhs.scale())?;
-        Ok((&lhs.0 / &rhs.0).into_decimal_unchecked(None, scale))
+        self.div_with_mode(rhs, RoundMode::default())
+    }
+}
+
+impl Rem for &DecimalChunked {
+    type Output = PolarsResult<DecimalChunked>;
+
+    /// The result has the sign of the dividend, remainder by zero results in null.
+    fn rem(self, rhs: Self) -> Self::Output {
+        let (ls, rs) = (self.scale(), rhs.scale());
+        let scale = _get_decimal_scale_add_sub(ls, rs);
+        checked_decimal_op(self, rhs, scale, "remainder", |l, r| {
+            if r == 0 {
+                return Some(None);
+            }
+            dec128_rem(l, ls, r, rs).map(Some)
+        })
     }
 }
 
@@ -47,10 +114,10 @@ pub fn _get_decimal_scale_add_sub(scale_left: usize, scale_right: usize) -> usiz
 }
 
 pub fn _get_decimal_scale_mul(scale_left: usize, scale_right: usize) -> usize {
-    scale_left + scale_right
+    (scale_left + scale_right).min(DEC128_MAX_PREC)
 }
 
 pub fn _get_decimal_scale_div(scale_left: usize) -> usize {
     // Follow postgres and MySQL adding a fixed scale increment of 4
-    scale_left + 4
+    (scale_left + 4).min(DEC128_MAX_PREC)
 }
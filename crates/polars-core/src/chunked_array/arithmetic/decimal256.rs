@@ -0,0 +1,122 @@
+use ethnum::I256;
+
+use super::*;
+use crate::chunked_array::ops::decimal::{
+    DEC256_MAX_PREC, dec256_add, dec256_div, dec256_mul, dec256_rem, dec256_sub,
+};
+use crate::prelude::arity::broadcast_try_binary_elementwise;
+
+/// Apply an exact 256-bit decimal kernel elementwise, `None` from the kernel means the
+/// result doesn't fit the decimal precision and is raised as an error.
+fn checked_decimal256_op<F>(
+    lhs: &Decimal256Chunked,
+    rhs: &Decimal256Chunked,
+    scale: usize,
+    op_name: &str,
+    op: F,
+) -> PolarsResult<Decimal256Chunked>
+where
+    F: Fn(I256, I256) -> Option<Option<I256>>,
+{
+    let out: BinaryChunked = broadcast_try_binary_elementwise(&lhs.0, &rhs.0, |l, r| {
+        match (l, r) {
+            (Some(l), Some(r)) => op(ordered_bytes_to_i256(l), ordered_bytes_to_i256(r))
+                .map(|v| v.map(i256_to_ordered_bytes))
+                .ok_or_else(
+                    || polars_err!(ComputeError: "decimal overflow in {}: result does not fit Decimal256(76, {})", op_name, scale),
+                ),
+            _ => Ok(None),
+        }
+    })?;
+    Ok(out.into_decimal256_unchecked(None, scale))
+}
+
+impl Add for &Decimal256Chunked {
+    type Output = PolarsResult<Decimal256Chunked>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let (ls, rs) = (self.scale(), rhs.scale());
+        let scale = _get_decimal_scale_add_sub(ls, rs);
+        checked_decimal256_op(self, rhs, scale, "addition", |l, r| {
+            dec256_add(l, ls, r, rs).map(Some)
+        })
+    }
+}
+
+impl Sub for &Decimal256Chunked {
+    type Output = PolarsResult<Decimal256Chunked>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let (ls, rs) = (self.scale(), rhs.scale());
+        let scale = _get_decimal_scale_add_sub(ls, rs);
+        checked_decimal256_op(self, rhs, scale, "subtraction", |l, r| {
+            dec256_sub(l, ls, r, rs).map(Some)
+        })
+    }
+}
+
+impl Decimal256Chunked {
+    /// Multiply, rounding the excess digits of the result with `mode`.
+    pub fn mul_with_mode(&self, rhs: &Self, mode: RoundMode) -> PolarsResult<Self> {
+        let (ls, rs) = (self.scale(), rhs.scale());
+        let scale = _get_decimal256_scale_mul(ls, rs);
+        checked_decimal256_op(self, rhs, scale, "multiplication", |l, r| {
+            dec256_mul(l, ls, r, rs, scale, mode).map(Some)
+        })
+    }
+
+    /// Divide, rounding the excess digits of the result with `mode`. Division by zero results
+    /// in null.
+    pub fn div_with_mode(&self, rhs: &Self, mode: RoundMode) -> PolarsResult<Self> {
+        let (ls, rs) = (self.scale(), rhs.scale());
+        let scale = _get_decimal256_scale_div(ls);
+        checked_decimal256_op(self, rhs, scale, "division", |l, r| {
+            if r == I256::ZERO {
+                return Some(None);
+            }
+            dec256_div(l, ls, r, rs, scale, mode).map(Some)
+        })
+    }
+}
+
+impl Mul for &Decimal256Chunked {
+    type Output = PolarsResult<Decimal256Chunked>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.mul_with_mode(rhs, RoundMode::default())
+    }
+}
+
+impl Div for &Decimal256Chunked {
+    type Output = PolarsResult<Decimal256Chunked>;
+
+    /// Division by zero results in null.
+    fn div(self, rhs: Self) -> Self::Output {
+        self.div_with_mode(rhs, RoundMode::default())
+    }
+}
+
+impl Rem for &Decimal256Chunked {
+    type Output = PolarsResult<Decimal256Chunked>;
+
+    /// The result has the sign of the dividend, remainder by zero results in null.
+    fn rem(self, rhs: Self) -> Self::Output {
+        let (ls, rs) = (self.scale(), rhs.scale());
+        let scale = _get_decimal_scale_add_sub(ls, rs);
+        checked_decimal256_op(self, rhs, scale, "remainder", |l, r| {
+            if r == I256::ZERO {
+                return Some(None);
+            }
+            dec256_rem(l, ls, r, rs).map(Some)
+        })
+    }
+}
+
+// Used by polars-plan to determine schema.
+pub fn _get_decimal256_scale_mul(scale_left: usize, scale_right: usize) -> usize {
+    (scale_left + scale_right).min(DEC256_MAX_PREC)
+}
+
+pub fn _get_decimal256_scale_div(scale_left: usize) -> usize {
+    (scale_left + 4).min(DEC256_MAX_PREC)
+}
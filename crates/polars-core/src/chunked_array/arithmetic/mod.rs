@@ -1,6 +1,8 @@
 //! Implementations of arithmetic operations on ChunkedArrays.
 #[cfg(feature = "dtype-decimal")]
 mod decimal;
+#[cfg(feature = "dtype-decimal256")]
+mod decimal256;
 mod numeric;
 
 use std::ops::{Add, Div, Mul, Rem, Sub};
@@ -8,6 +10,8 @@ use std::ops::{Add, Div, Mul, Rem, Sub};
 use arrow::compute::utils::combine_validities_and;
 #[cfg(feature = "dtype-decimal")]
 pub use decimal::{_get_decimal_scale_add_sub, _get_decimal_scale_div, _get_decimal_scale_mul};
+#[cfg(feature = "dtype-decimal256")]
+pub use decimal256::{_get_decimal256_scale_div, _get_decimal256_scale_mul};
 use num_traits::{Num, NumCast, ToPrimitive};
 pub use numeric::ArithmeticChunked;
 
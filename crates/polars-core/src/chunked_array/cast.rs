@@ -219,6 +219,48 @@ where
                     .into_series())
                 }
             },
+            #[cfg(feature = "dtype-interval")]
+            DataType::Interval => {
+                polars_ensure!(
+                    self.dtype() == &DataType::Int128,
+                    InvalidOperation: "cannot cast {} to 'Interval'", self.dtype()
+                );
+                // SAFETY: we are guarded by the type system
+                let ca = unsafe { &*(self as *const ChunkedArray<T> as *const Int128Chunked) };
+                Ok(ca.clone().into_interval().into_series())
+            },
+            #[cfg(feature = "dtype-uuid")]
+            DataType::Uuid => {
+                polars_ensure!(
+                    self.dtype() == &DataType::Int128,
+                    InvalidOperation: "cannot cast {} to 'Uuid'", self.dtype()
+                );
+                // SAFETY: we are guarded by the type system
+                let ca = unsafe { &*(self as *const ChunkedArray<T> as *const Int128Chunked) };
+                Ok(ca.clone().into_uuid().into_series())
+            },
+            #[cfg(feature = "dtype-variant")]
+            DataType::Variant => Ok(self.to_variant().into_series()),
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => Ok(self
+                .cast_impl(&DataType::Float32, options)?
+                .f32()?
+                .to_float16()
+                .into_series()),
+            #[cfg(feature = "dtype-decimal256")]
+            DataType::Decimal256(precision, scale) => {
+                let scale = scale.unwrap_or(0);
+                if self.dtype().is_float() {
+                    // Floats go through their shortest round-tripping representation.
+                    let s = self.cast_impl(&DataType::String, options)?;
+                    Ok(s.str()?
+                        .to_decimal256(*precision, Some(scale))
+                        .into_series())
+                } else {
+                    let s = self.cast_impl(&DataType::Int128, options)?;
+                    Ok(s.i128()?.to_decimal256(*precision, scale).into_series())
+                }
+            },
             #[cfg(feature = "dtype-struct")]
             DataType::Struct(fields) => {
                 cast_single_to_struct(self.name().clone(), &self.chunks, fields, options)
@@ -336,6 +378,20 @@ impl ChunkCast for StringChunked {
                     polars_bail!(ComputeError: "expected 'precision' or 'scale' when casting to Decimal")
                 },
             },
+            #[cfg(feature = "dtype-decimal256")]
+            DataType::Decimal256(precision, scale) => {
+                Ok(self.to_decimal256(*precision, *scale).into_series())
+            },
+            #[cfg(feature = "dtype-uuid")]
+            DataType::Uuid => Ok(self.to_uuid().into_series()),
+            #[cfg(feature = "dtype-variant")]
+            DataType::Variant => Ok(self.to_variant().into_series()),
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => Ok(self
+                .cast_with_options(&DataType::Float32, options)?
+                .f32()?
+                .to_float16()
+                .into_series()),
             #[cfg(feature = "dtype-date")]
             DataType::Date => {
                 let result = cast_chunks(&self.chunks, dtype, options)?;
@@ -410,6 +466,16 @@ impl ChunkCast for BinaryChunked {
             DataType::Struct(fields) => {
                 cast_single_to_struct(self.name().clone(), &self.chunks, fields, options)
             },
+            #[cfg(feature = "dtype-uuid")]
+            DataType::Uuid => Ok(self.to_uuid().into_series()),
+            #[cfg(feature = "dtype-decimal256")]
+            DataType::Decimal256(_, _) => {
+                polars_bail!(InvalidOperation: "cannot cast {} to '{}'", self.dtype(), dtype)
+            },
+            #[cfg(feature = "dtype-variant")]
+            DataType::Variant => {
+                polars_bail!(InvalidOperation: "cannot cast {} to '{}'", self.dtype(), dtype)
+            },
             _ => cast_impl(self.name().clone(), &self.chunks, dtype, options),
         }
     }
@@ -449,6 +515,14 @@ impl ChunkCast for BooleanChunked {
             DataType::Categorical(_, _) | DataType::Enum(_, _) => {
                 polars_bail!(InvalidOperation: "cannot cast Boolean to Categorical");
             },
+            #[cfg(feature = "dtype-variant")]
+            DataType::Variant => Ok(self.to_variant().into_series()),
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => Ok(self
+                .cast_with_options(&DataType::Float32, options)?
+                .f32()?
+                .to_float16()
+                .into_series()),
             _ => cast_impl(self.name().clone(), &self.chunks, dtype, options),
         }
     }
@@ -575,6 +649,12 @@ impl ChunkCast for ArrayChunked {
                     ))
                 }
             },
+            #[cfg(feature = "dtype-uuid")]
+            Uuid if self.dtype() == &Uuid.to_physical() => Ok(UuidChunked::from_uuid_chunks(
+                self.name().clone(),
+                self.chunks().clone(),
+            )
+            .into_series()),
             _ => {
                 polars_bail!(
                     InvalidOperation: "cannot cast Array type (inner: '{:?}', to: '{:?}')",
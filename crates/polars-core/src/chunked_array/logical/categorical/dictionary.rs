@@ -0,0 +1,436 @@
+use std::collections::BTreeMap;
+use std::fmt::{Debug, Formatter};
+use std::fs::File;
+use std::hash::{Hash, Hasher};
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::Path;
+
+use arrow::array::*;
+use arrow::datatypes::Metadata;
+use polars_utils::format_pl_smallstr;
+use polars_utils::pl_str::PlSmallStr;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::prelude::*;
+
+/// The magic bytes at the start of a persisted [`CategoricalDictionary`].
+const MAGIC: &[u8; 8] = b"PLCATDIC";
+/// The version of the file format of a persisted [`CategoricalDictionary`].
+const FORMAT_VERSION: u32 = 1;
+/// The prefix of the file metadata keys that record the dictionary a column is encoded with.
+const METADATA_KEY_PREFIX: &str = "_PL_CATEGORICAL_DICTIONARY:";
+
+/// Categorical dictionaries by column name.
+pub type CategoricalDictionaries = BTreeMap<PlSmallStr, Arc<CategoricalDictionary>>;
+
+/// A persistable mapping of categories to stable physical codes.
+///
+/// The physical code of a category is its position in the dictionary. Dictionaries are
+/// append-only: adding categories bumps the [`version`], but never changes the codes of
+/// existing categories. Columns that are [`encode`]d with the same dictionary version share
+/// their categories and can be joined or concatenated without re-encoding, also when they are
+/// created in different processes.
+///
+/// [`version`]: CategoricalDictionary::version
+/// [`encode`]: CategoricalDictionary::encode
+#[derive(Clone)]
+pub struct CategoricalDictionary {
+    version: u64,
+    codes: PlHashMap<PlSmallStr, u32>,
+    rev_map: Arc<RevMapping>,
+}
+
+/// The categories in order of their codes.
+fn build_categories(codes: &PlHashMap<PlSmallStr, u32>) -> Utf8ViewArray {
+    let mut categories = vec![""; codes.len()];
+    for (category, code) in codes {
+        categories[*code as usize] = category.as_str();
+    }
+    Utf8ViewArray::from_slice_values(categories)
+}
+
+impl Debug for CategoricalDictionary {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "CategoricalDictionary(version={}, len={})",
+            self.version,
+            self.len()
+        )
+    }
+}
+
+impl PartialEq for CategoricalDictionary {
+    fn eq(&self, other: &Self) -> bool {
+        self.version == other.version && self.rev_map.same_src(&other.rev_map)
+    }
+}
+
+impl Eq for CategoricalDictionary {}
+
+impl Hash for CategoricalDictionary {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.version.hash(state);
+        // The categories of a dictionary are always local.
+        match self.rev_map.as_ref() {
+            RevMapping::Local(_, hash) => hash.hash(state),
+            RevMapping::Global(..) => unreachable!(),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for CategoricalDictionary {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut buf = vec![];
+        self.write(&mut buf).map_err(serde::ser::Error::custom)?;
+        serializer.serialize_bytes(&buf)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for CategoricalDictionary {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let buf = Vec::<u8>::deserialize(deserializer)?;
+        Self::read(buf.as_slice()).map_err(serde::de::Error::custom)
+    }
+}
+
+impl CategoricalDictionary {
+    /// Create a dictionary with version 1, errors if a category occurs more than once.
+    pub fn new<'a, I: IntoIterator<Item = &'a str>>(categories: I) -> PolarsResult<Self> {
+        Self::with_version(categories, 1)
+    }
+
+    fn with_version<'a, I: IntoIterator<Item = &'a str>>(
+        categories: I,
+        version: u64,
+    ) -> PolarsResult<Self> {
+        let mut codes = PlHashMap::new();
+        for category in categories {
+            let code = codes.len() as u32;
+            polars_ensure!(
+                codes.insert(PlSmallStr::from_str(category), code).is_none(),
+                Duplicate: "category '{}' occurs more than once in the categorical dictionary",
+                category
+            );
+        }
+        let rev_map = Arc::new(RevMapping::build_local(build_categories(&codes)));
+        Ok(Self {
+            version,
+            codes,
+            rev_map,
+        })
+    }
+
+    /// Create a dictionary of the unique values of a `String` or `Categorical` series, in order
+    /// of their first occurrence.
+    pub fn from_series(s: &Series) -> PolarsResult<Self> {
+        let mut out = Self::new(std::iter::empty())?;
+        out.update(s)?;
+        // The initial categories are not an update.
+        out.version = 1;
+        Ok(out)
+    }
+
+    /// The version of the dictionary, which is bumped whenever categories are added.
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    /// The number of categories.
+    pub fn len(&self) -> usize {
+        self.codes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.codes.is_empty()
+    }
+
+    /// The categories, in order of their physical codes.
+    pub fn categories(&self) -> &Utf8ViewArray {
+        self.rev_map.get_categories()
+    }
+
+    /// Get the physical code of a category.
+    pub fn find(&self, category: &str) -> Option<u32> {
+        self.codes.get(category).copied()
+    }
+
+    /// Get the category of a physical code.
+    pub fn get(&self, code: u32) -> Option<&str> {
+        self.categories().get(code as usize)
+    }
+
+    /// Whether the codes of this dictionary are valid in `other`, which is the case if `other`
+    /// is this dictionary or a later version of it.
+    pub fn is_prefix_of(&self, other: &Self) -> bool {
+        self.len() <= other.len()
+            && self
+                .categories()
+                .values_iter()
+                .zip(other.categories().values_iter())
+                .all(|(l, r)| l == r)
+    }
+
+    /// Append the categories that are not yet in the dictionary. The version is bumped if any
+    /// category is added. Returns the number of added categories.
+    pub fn extend<'a, I: IntoIterator<Item = &'a str>>(&mut self, categories: I) -> usize {
+        let n_before = self.len();
+        for category in categories {
+            if !self.codes.contains_key(category) {
+                let code = self.codes.len() as u32;
+                self.codes.insert(PlSmallStr::from_str(category), code);
+            }
+        }
+        let n_added = self.len() - n_before;
+        if n_added > 0 {
+            self.version += 1;
+            self.rev_map = Arc::new(RevMapping::build_local(build_categories(&self.codes)));
+        }
+        n_added
+    }
+
+    /// Append the values of a `String` or `Categorical` series that are not yet in the
+    /// dictionary, see [`CategoricalDictionary::extend`].
+    pub fn update(&mut self, s: &Series) -> PolarsResult<usize> {
+        Ok(match s.dtype() {
+            DataType::String => self.extend(s.str()?.iter().flatten()),
+            DataType::Categorical(_, _) | DataType::Enum(_, _) => {
+                self.extend(s.categorical()?.iter_str().flatten())
+            },
+            dt => polars_bail!(
+                SchemaMismatch: "expected a 'str' or 'cat' column, got '{}'",
+                dt
+            ),
+        })
+    }
+
+    /// The data type of the columns that are encoded with this dictionary.
+    pub fn dtype(&self) -> DataType {
+        DataType::Categorical(Some(self.rev_map.clone()), CategoricalOrdering::Physical)
+    }
+
+    /// Encode a `String` or `Categorical` series with the physical codes of this dictionary.
+    /// Errors if the series has a value that is not in the dictionary.
+    pub fn encode(&self, s: &Series) -> PolarsResult<Series> {
+        let encode_value = |opt_v: Option<&str>| match opt_v {
+            None => Ok(None),
+            Some(v) => match self.find(v) {
+                Some(code) => Ok(Some(code)),
+                None => polars_bail!(
+                    ComputeError: "value '{}' is not in the categorical dictionary (version {})",
+                    v, self.version
+                ),
+            },
+        };
+        let codes: UInt32Chunked = match s.dtype() {
+            DataType::String => s
+                .str()?
+                .iter()
+                .map(encode_value)
+                .collect::<PolarsResult<_>>()?,
+            DataType::Categorical(_, _) | DataType::Enum(_, _) => {
+                let ca = s.categorical()?;
+                let rev_map = ca.get_rev_map();
+                // Encode every category of the input once.
+                let mut translation = PlHashMap::new();
+                ca.physical()
+                    .iter()
+                    .map(|opt_code| {
+                        let Some(code) = opt_code else {
+                            return Ok(None);
+                        };
+                        if let Some(out) = translation.get(&code) {
+                            return Ok(Some(*out));
+                        }
+                        let out = encode_value(Some(rev_map.get(code)))?;
+                        translation.insert(code, out.unwrap());
+                        Ok(out)
+                    })
+                    .collect::<PolarsResult<_>>()?
+            },
+            dt => polars_bail!(
+                SchemaMismatch: "expected a 'str' or 'cat' column, got '{}'",
+                dt
+            ),
+        };
+        let codes = codes.with_name(s.name().clone());
+        // SAFETY: all codes are in the dictionary.
+        let out = unsafe {
+            CategoricalChunked::from_cats_and_rev_map_unchecked(
+                codes,
+                self.rev_map.clone(),
+                false,
+                CategoricalOrdering::Physical,
+            )
+        };
+        Ok(out.into_series())
+    }
+
+    /// Write the dictionary in its binary format.
+    pub fn write<W: Write>(&self, mut writer: W) -> PolarsResult<()> {
+        writer.write_all(MAGIC)?;
+        writer.write_all(&FORMAT_VERSION.to_le_bytes())?;
+        writer.write_all(&self.version.to_le_bytes())?;
+        writer.write_all(&(self.len() as u32).to_le_bytes())?;
+        for category in self.categories().values_iter() {
+            writer.write_all(&(category.len() as u32).to_le_bytes())?;
+            writer.write_all(category.as_bytes())?;
+        }
+        Ok(())
+    }
+
+    /// Read a dictionary that was written with [`CategoricalDictionary::write`].
+    pub fn read<R: Read>(mut reader: R) -> PolarsResult<Self> {
+        fn read_array<R: Read, const N: usize>(reader: &mut R) -> PolarsResult<[u8; N]> {
+            let mut buf = [0; N];
+            reader.read_exact(&mut buf)?;
+            Ok(buf)
+        }
+
+        polars_ensure!(
+            &read_array::<_, 8>(&mut reader)? == MAGIC,
+            ComputeError: "not a categorical dictionary file"
+        );
+        let format_version = u32::from_le_bytes(read_array(&mut reader)?);
+        polars_ensure!(
+            format_version == FORMAT_VERSION,
+            ComputeError: "unsupported categorical dictionary format version {}", format_version
+        );
+        let version = u64::from_le_bytes(read_array(&mut reader)?);
+        let len = u32::from_le_bytes(read_array(&mut reader)?);
+        let mut categories = Vec::with_capacity(len.min(1 << 16) as usize);
+        for _ in 0..len {
+            let n_bytes = u32::from_le_bytes(read_array(&mut reader)?) as usize;
+            let mut buf = vec![0; n_bytes];
+            reader.read_exact(&mut buf)?;
+            let category = String::from_utf8(buf).map_err(
+                |_| polars_err!(ComputeError: "categorical dictionary contains invalid utf-8"),
+            )?;
+            categories.push(category);
+        }
+        Self::with_version(categories.iter().map(|s| s.as_str()), version)
+    }
+
+    /// Encode the dictionary as a file metadata value:
+    /// `<version>;` followed by `<len in ascii>;<category>` for every category.
+    fn to_metadata_value(&self) -> PlSmallStr {
+        let mut encoded = String::with_capacity(self.len() * 10);
+        encoded.push_str(itoa::Buffer::new().format(self.version));
+        encoded.push(';');
+        for category in self.categories().values_iter() {
+            encoded.push_str(itoa::Buffer::new().format(category.len()));
+            encoded.push(';');
+            encoded.push_str(category);
+        }
+        PlSmallStr::from_string(encoded)
+    }
+
+    /// Decode a dictionary that was encoded with [`CategoricalDictionary::to_metadata_value`].
+    fn from_metadata_value(encoded: &str) -> PolarsResult<Self> {
+        let invalid =
+            || polars_err!(ComputeError: "invalid categorical dictionary in file metadata");
+
+        let (version, mut encoded) = encoded.split_once(';').ok_or_else(invalid)?;
+        let version = version.parse::<u64>().map_err(|_| invalid())?;
+        let mut categories = vec![];
+        while let Some((len, remainder)) = encoded.split_once(';') {
+            let len = len.parse::<usize>().map_err(|_| invalid())?;
+            if !remainder.is_char_boundary(len) {
+                return Err(invalid());
+            }
+            let (category, remainder) = remainder.split_at(len);
+            categories.push(category);
+            encoded = remainder;
+        }
+        if !encoded.is_empty() {
+            return Err(invalid());
+        }
+        Self::with_version(categories, version)
+    }
+
+    /// Save the dictionary to a file.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> PolarsResult<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write(&mut writer)?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Load a dictionary from a file that was written with [`CategoricalDictionary::save`].
+    pub fn load<P: AsRef<Path>>(path: P) -> PolarsResult<Self> {
+        Self::read(BufReader::new(File::open(path)?))
+    }
+}
+
+/// The file metadata that records the dictionaries that the columns of a file are encoded with.
+pub fn categorical_dictionaries_to_metadata(dictionaries: &CategoricalDictionaries) -> Metadata {
+    dictionaries
+        .iter()
+        .map(|(name, dictionary)| {
+            (
+                format_pl_smallstr!("{METADATA_KEY_PREFIX}{name}"),
+                dictionary.to_metadata_value(),
+            )
+        })
+        .collect()
+}
+
+/// Check that the codes of a file are valid in `dictionaries`, which is the case if every column
+/// was written with the same or an earlier version of its dictionary.
+///
+/// `metadata` are the key-value pairs of the file metadata. Columns that were not written with
+/// a dictionary are not checked, they are encoded when they are read.
+pub fn validate_categorical_dictionaries<'a, I>(
+    metadata: I,
+    dictionaries: &CategoricalDictionaries,
+) -> PolarsResult<()>
+where
+    I: IntoIterator<Item = (&'a str, &'a str)>,
+{
+    for (key, value) in metadata {
+        let Some(name) = key.strip_prefix(METADATA_KEY_PREFIX) else {
+            continue;
+        };
+        let Some(dictionary) = dictionaries.get(name) else {
+            continue;
+        };
+        let file_dictionary = CategoricalDictionary::from_metadata_value(value)?;
+        polars_ensure!(
+            file_dictionary.version() <= dictionary.version()
+                && file_dictionary.is_prefix_of(dictionary),
+            ComputeError: "column '{}' was written with version {} of a categorical dictionary \
+            that is incompatible with the given dictionary (version {})",
+            name, file_dictionary.version(), dictionary.version()
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_categorical_dictionary_round_trip() -> PolarsResult<()> {
+        let mut dict = CategoricalDictionary::new(["b", "a"])?;
+        assert_eq!(dict.extend(["a", "c"]), 1);
+        assert_eq!(dict.version(), 2);
+
+        let mut buf = vec![];
+        dict.write(&mut buf)?;
+        let out = CategoricalDictionary::read(buf.as_slice())?;
+        assert_eq!(out, dict);
+        assert_eq!(out.find("c"), Some(2));
+        assert!(CategoricalDictionary::read(&buf[..buf.len() - 1]).is_err());
+        assert!(CategoricalDictionary::new(["a", "a"]).is_err());
+
+        let value = dict.to_metadata_value();
+        assert_eq!(value.as_str(), "2;1;b1;a1;c");
+        assert_eq!(CategoricalDictionary::from_metadata_value(&value)?, dict);
+        assert!(CategoricalDictionary::from_metadata_value("2;3;ab").is_err());
+        Ok(())
+    }
+}
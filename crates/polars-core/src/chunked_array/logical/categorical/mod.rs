@@ -1,4 +1,5 @@
 mod builder;
+mod dictionary;
 mod from;
 mod merge;
 mod ops;
@@ -7,6 +8,7 @@ pub mod string_cache;
 
 use bitflags::bitflags;
 pub use builder::*;
+pub use dictionary::*;
 pub use merge::*;
 use polars_utils::itertools::Itertools;
 use polars_utils::sync::SyncPtr;
@@ -74,6 +74,12 @@ impl LogicalType for DecimalChunked {
 
             dtype = Cow::Owned(DataType::Decimal(to_precision, Some(to_scale)));
         }
+        #[cfg(feature = "dtype-decimal256")]
+        if let DataType::Decimal256(to_precision, to_scale) = dtype.as_ref() {
+            let to_precision = to_precision.or(self.precision());
+            let to_scale = to_scale.unwrap_or(self.scale());
+            return Ok(self.to_decimal256(to_precision, to_scale).into_series());
+        }
 
         let arrow_dtype = self.dtype().to_arrow(CompatLevel::newest());
         let chunks = self
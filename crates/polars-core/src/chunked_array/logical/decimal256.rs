@@ -0,0 +1,339 @@
+use arrow::buffer::Buffer;
+use ethnum::I256;
+
+use super::*;
+use crate::chunked_array::ops::decimal::{
+    DEC128_MAX_PREC, DEC256_MAX_PREC, dec128_fit, dec256_fits_precision, dec256_parse,
+    dec256_rescale,
+};
+use crate::prelude::*;
+
+pub type Decimal256Chunked = Logical<Decimal256Type, BinaryType>;
+
+const SIGN: u8 = 0x80;
+
+/// Encode a 256-bit decimal mantissa as 32 big-endian bytes.
+///
+/// The sign bit is flipped, so that the bytes order the same way as the numbers.
+#[inline]
+pub fn i256_to_ordered_bytes(v: I256) -> [u8; 32] {
+    let mut bytes = v.to_be_bytes();
+    bytes[0] ^= SIGN;
+    bytes
+}
+
+/// Decode a mantissa encoded by [`i256_to_ordered_bytes`].
+#[inline]
+pub fn ordered_bytes_to_i256(bytes: &[u8]) -> I256 {
+    let mut bytes: [u8; 32] = bytes.try_into().unwrap();
+    bytes[0] ^= SIGN;
+    I256::from_be_bytes(bytes)
+}
+
+/// Convert an arrow `Decimal256` array to the encoded physical representation.
+pub(crate) fn i256_to_binview_array(arr: &PrimitiveArray<i256>) -> BinaryViewArray {
+    arr.iter()
+        .map(|opt_v| opt_v.map(|v| i256_to_ordered_bytes(v.0)))
+        .collect_arr()
+}
+
+/// Convert the encoded physical representation to an arrow array of `dtype`, which must be a
+/// `Decimal256`.
+pub(crate) fn binview_to_i256_array(
+    arr: &BinaryViewArray,
+    dtype: ArrowDataType,
+) -> PrimitiveArray<i256> {
+    let values: Buffer<i256> = arr
+        .iter()
+        .map(|opt_b| opt_b.map_or(i256::default(), |b| i256(ordered_bytes_to_i256(b))))
+        .collect();
+    PrimitiveArray::new(dtype, values, arr.validity().cloned())
+}
+
+/// Collect mantissas at `scale`, values that don't fit `precision` become null.
+fn collect_decimal256<I: Iterator<Item = Option<I256>>>(
+    name: PlSmallStr,
+    iter: I,
+    precision: Option<usize>,
+    scale: usize,
+) -> Decimal256Chunked {
+    let max_precision = precision.unwrap_or(DEC256_MAX_PREC);
+    let out: BinaryChunked = iter
+        .map(|opt_v| {
+            opt_v
+                .filter(|v| dec256_fits_precision(*v, max_precision))
+                .map(i256_to_ordered_bytes)
+        })
+        .collect();
+    out.with_name(name)
+        .into_decimal256_unchecked(precision, scale)
+}
+
+impl BinaryChunked {
+    /// Interpret the bytes as encoded 256-bit decimal mantissas. See [`i256_to_ordered_bytes`].
+    #[inline]
+    pub fn into_decimal256_unchecked(
+        self,
+        precision: Option<usize>,
+        scale: usize,
+    ) -> Decimal256Chunked {
+        let mut dt = Decimal256Chunked::new_logical(self);
+        dt.2 = Some(DataType::Decimal256(precision, Some(scale)));
+        dt
+    }
+}
+
+impl Decimal256Chunked {
+    /// Construct a new [`Decimal256Chunked`] from an iterator over optional mantissas.
+    pub fn from_i256s<I: IntoIterator<Item = Option<I256>>>(
+        name: PlSmallStr,
+        v: I,
+        precision: Option<usize>,
+        scale: usize,
+    ) -> Self {
+        let out: BinaryChunked = v
+            .into_iter()
+            .map(|opt_v| opt_v.map(i256_to_ordered_bytes))
+            .collect();
+        out.with_name(name)
+            .into_decimal256_unchecked(precision, scale)
+    }
+
+    /// Iterate over the decoded mantissas.
+    pub fn iter_i256s(&self) -> impl Iterator<Item = Option<I256>> + '_ {
+        self.0.iter().map(|v| v.map(ordered_bytes_to_i256))
+    }
+
+    pub fn precision(&self) -> Option<usize> {
+        match self.2.as_ref().unwrap() {
+            DataType::Decimal256(precision, _) => *precision,
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn scale(&self) -> usize {
+        match self.2.as_ref().unwrap() {
+            DataType::Decimal256(_, scale) => scale.unwrap_or_else(|| unreachable!()),
+            _ => unreachable!(),
+        }
+    }
+
+    fn to_string_chunked(&self) -> StringChunked {
+        let mut buf = String::new();
+        let scale = self.scale();
+        let out: StringChunked = self
+            .iter_i256s()
+            .map(|opt_v| {
+                opt_v.map(|v| {
+                    buf.clear();
+                    crate::fmt::fmt_decimal256_string(&mut buf, v, scale, false).unwrap();
+                    buf.clone()
+                })
+            })
+            .collect();
+        out.with_name(self.name().clone())
+    }
+
+    fn to_float64(&self) -> Float64Chunked {
+        let scale = self.scale();
+        let out: Float64Chunked = self
+            .iter_i256s()
+            .map(|opt_v| opt_v.and_then(|v| format!("{v}e-{scale}").parse::<f64>().ok()))
+            .collect();
+        out.with_name(self.name().clone())
+    }
+
+    /// Truncate the values to integers, values that don't fit an `i128` become null.
+    fn to_int128(&self) -> Int128Chunked {
+        let scale = self.scale();
+        let out: Int128Chunked = self
+            .iter_i256s()
+            .map(|opt_v| {
+                let v = dec256_rescale(opt_v?, scale, 0, RoundMode::TowardZero)?;
+                let (hi, lo) = v.into_words();
+                ((hi == 0 && lo >= 0) || (hi == -1 && lo < 0)).then_some(lo)
+            })
+            .collect();
+        out.with_name(self.name().clone())
+    }
+}
+
+impl LogicalType for Decimal256Chunked {
+    fn dtype(&self) -> &DataType {
+        self.2.as_ref().unwrap()
+    }
+
+    #[inline]
+    fn get_any_value(&self, i: usize) -> PolarsResult<AnyValue<'_>> {
+        polars_ensure!(i < self.len(), oob = i, self.len());
+        Ok(unsafe { self.get_any_value_unchecked(i) })
+    }
+
+    #[inline]
+    unsafe fn get_any_value_unchecked(&self, i: usize) -> AnyValue<'_> {
+        match self.0.get_unchecked(i) {
+            Some(v) => AnyValue::Decimal256(i256(ordered_bytes_to_i256(v)), self.scale()),
+            None => AnyValue::Null,
+        }
+    }
+
+    fn cast_with_options(
+        &self,
+        dtype: &DataType,
+        cast_options: CastOptions,
+    ) -> PolarsResult<Series> {
+        let from_scale = self.scale();
+        let mode = RoundMode::default();
+        match dtype {
+            DataType::Decimal256(to_precision, to_scale) => {
+                let to_precision = to_precision.or(self.precision());
+                let to_scale = to_scale.unwrap_or(from_scale);
+                if to_precision == self.precision() && to_scale == from_scale {
+                    return Ok(self.clone().into_series());
+                }
+                let iter = self
+                    .iter_i256s()
+                    .map(|opt_v| dec256_rescale(opt_v?, from_scale, to_scale, mode));
+                Ok(
+                    collect_decimal256(self.name().clone(), iter, to_precision, to_scale)
+                        .into_series(),
+                )
+            },
+            DataType::Decimal(to_precision, to_scale) => {
+                let to_scale = to_scale.unwrap_or(from_scale.min(DEC128_MAX_PREC));
+                let max_precision = to_precision.unwrap_or(DEC128_MAX_PREC);
+                let out: Int128Chunked = self
+                    .iter_i256s()
+                    .map(|opt_v| {
+                        let v = dec256_rescale(opt_v?, from_scale, to_scale, mode)?;
+                        dec256_fits_precision(v, max_precision)
+                            .then(|| dec128_fit(v))
+                            .flatten()
+                    })
+                    .collect();
+                Ok(out
+                    .with_name(self.name().clone())
+                    .into_decimal_unchecked(*to_precision, to_scale)
+                    .into_series())
+            },
+            DataType::String => Ok(self.to_string_chunked().into_series()),
+            dt if dt.is_float() => self.to_float64().cast_with_options(dt, cast_options),
+            dt if dt.is_integer() => self.to_int128().cast_with_options(dt, cast_options),
+            dt => {
+                polars_bail!(
+                    InvalidOperation:
+                    "casting from {:?} to {:?} not supported",
+                    self.dtype(), dt
+                )
+            },
+        }
+    }
+}
+
+impl Int128Chunked {
+    /// Convert the integers to 256-bit decimals, values that don't fit `precision` become null.
+    pub(crate) fn to_decimal256(
+        &self,
+        precision: Option<usize>,
+        scale: usize,
+    ) -> Decimal256Chunked {
+        let mode = RoundMode::default();
+        let iter = self
+            .iter()
+            .map(|opt_v| dec256_rescale(I256::new(opt_v?), 0, scale, mode));
+        collect_decimal256(self.name().clone(), iter, precision, scale)
+    }
+}
+
+impl DecimalChunked {
+    /// Widen to 256-bit decimals, values that don't fit `precision` become null.
+    pub(crate) fn to_decimal256(
+        &self,
+        precision: Option<usize>,
+        scale: usize,
+    ) -> Decimal256Chunked {
+        let from_scale = self.scale();
+        let mode = RoundMode::default();
+        let iter = self
+            .0
+            .iter()
+            .map(|opt_v| dec256_rescale(I256::new(opt_v?), from_scale, scale, mode));
+        collect_decimal256(self.name().clone(), iter, precision, scale)
+    }
+}
+
+impl StringChunked {
+    /// Parse the strings as 256-bit decimals. Strings that are not valid numbers or don't fit
+    /// `precision` become null. If no `scale` is given it is inferred from the first values.
+    pub(crate) fn to_decimal256(
+        &self,
+        precision: Option<usize>,
+        scale: Option<usize>,
+    ) -> Decimal256Chunked {
+        let scale = scale.unwrap_or_else(|| {
+            self.iter()
+                .flatten()
+                .take(100)
+                .map(|s| arrow::compute::decimal::infer_scale(s.as_bytes()) as usize)
+                .max()
+                .unwrap_or(0)
+                .min(DEC256_MAX_PREC)
+        });
+        let mode = RoundMode::default();
+        let iter = self
+            .iter()
+            .map(|opt_s| dec256_parse(opt_s?.as_bytes(), scale, mode));
+        collect_decimal256(self.name().clone(), iter, precision, scale)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_decimal256_encoding_order() {
+        let max = I256::from_words(i128::MAX, -1);
+        let values = [
+            I256::MIN,
+            -max,
+            I256::new(-1) << 128u32,
+            I256::new(-1),
+            I256::ZERO,
+            I256::ONE,
+            I256::ONE << 128u32,
+            max,
+        ];
+        let encoded = values.map(i256_to_ordered_bytes);
+        assert!(encoded.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(encoded.map(|b| ordered_bytes_to_i256(&b)), values);
+    }
+
+    #[test]
+    fn test_decimal256_casts() {
+        let s = StringChunked::from_slice(
+            PlSmallStr::from_static("a"),
+            &["1.25", "-0.5", "1e3", "abc"],
+        );
+        let d = s.to_decimal256(None, Some(1));
+        let v = d.iter_i256s().collect::<Vec<_>>();
+        // 1.25 rounds half to even.
+        assert_eq!(
+            v,
+            [
+                Some(I256::new(12)),
+                Some(I256::new(-5)),
+                Some(I256::new(10000)),
+                None
+            ]
+        );
+
+        let s = d.cast(&DataType::String).unwrap();
+        let s = s.str().unwrap();
+        assert_eq!(s.get(1), Some("-0.5"));
+        assert_eq!(s.get(2), Some("1000.0"));
+
+        let d = d.cast(&DataType::Decimal256(Some(4), Some(1))).unwrap();
+        assert_eq!(d.null_count(), 2);
+    }
+}
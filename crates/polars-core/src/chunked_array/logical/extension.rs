@@ -0,0 +1,158 @@
+use crate::chunked_array::cast::CastOptions;
+use crate::prelude::*;
+
+/// The values of a user defined extension type, together with their storage.
+#[derive(Clone)]
+pub struct ExtensionChunked {
+    storage: Series,
+    dtype: DataType,
+}
+
+impl ExtensionChunked {
+    /// Interpret `storage` as values of the extension type `ext`.
+    pub fn new(storage: Series, ext: ExtensionTypeRef) -> PolarsResult<Self> {
+        polars_ensure!(
+            storage.dtype() == ext.storage_dtype(),
+            SchemaMismatch: "extension type '{}' is stored as {}, got {}",
+            ext.name(), ext.storage_dtype(), storage.dtype()
+        );
+        Ok(Self::new_unchecked(storage, ext))
+    }
+
+    pub(crate) fn new_unchecked(storage: Series, ext: ExtensionTypeRef) -> Self {
+        debug_assert_eq!(storage.dtype(), ext.storage_dtype());
+        Self {
+            storage,
+            dtype: DataType::Extension(ext),
+        }
+    }
+
+    /// Wrap a new storage [`Series`] in the same extension type.
+    pub(crate) fn with_storage(&self, storage: Series) -> Self {
+        Self::new_unchecked(storage, self.extension_type().clone())
+    }
+
+    pub fn dtype(&self) -> &DataType {
+        &self.dtype
+    }
+
+    pub fn extension_type(&self) -> &ExtensionTypeRef {
+        match &self.dtype {
+            DataType::Extension(ext) => ext,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Get the values in their storage type.
+    pub fn storage(&self) -> &Series {
+        &self.storage
+    }
+
+    pub(crate) fn storage_mut(&mut self) -> &mut Series {
+        &mut self.storage
+    }
+
+    pub fn into_storage(self) -> Series {
+        self.storage
+    }
+
+    pub fn name(&self) -> &PlSmallStr {
+        self.storage.name()
+    }
+
+    pub fn len(&self) -> usize {
+        self.storage.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.storage.is_empty()
+    }
+
+    /// Format the value at `index` with the display of the extension type.
+    pub fn str_value(&self, index: usize) -> PolarsResult<String> {
+        let av = self.storage.get(index)?;
+        Ok(match av {
+            AnyValue::Null => "null".to_string(),
+            av => self.extension_type().fmt_value(av),
+        })
+    }
+
+    pub(crate) fn cast_with_options(
+        &self,
+        dtype: &DataType,
+        options: CastOptions,
+    ) -> PolarsResult<Series> {
+        match dtype {
+            DataType::Extension(_) if dtype == self.dtype() => Ok(self.clone().into_series()),
+            DataType::Extension(_) => self.storage.cast_with_options(dtype, options),
+            _ => self
+                .extension_type()
+                .cast_from_storage(&self.storage, dtype, options),
+        }
+    }
+}
+
+impl Series {
+    /// Cast to the extension type `ext` with its [`PolarsExtensionType::cast_to_storage`].
+    pub(crate) fn cast_to_extension(
+        &self,
+        ext: &ExtensionTypeRef,
+        options: CastOptions,
+    ) -> PolarsResult<Series> {
+        let storage = ext.cast_to_storage(self, options)?;
+        ExtensionChunked::new(storage, ext.clone()).map(|ca| ca.into_series())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use super::*;
+
+    #[derive(Debug)]
+    struct Json;
+
+    impl PolarsExtensionType for Json {
+        fn name(&self) -> &str {
+            "test.json"
+        }
+
+        fn storage_dtype(&self) -> &DataType {
+            &DataType::String
+        }
+
+        fn fmt_value(&self, value: AnyValue<'_>) -> String {
+            format!("json({})", value.get_str().unwrap())
+        }
+    }
+
+    #[test]
+    fn test_extension_series() -> PolarsResult<()> {
+        let ext: ExtensionTypeRef = Arc::new(Json);
+        let storage = Series::new("a".into(), [Some("{}"), None, Some("[1]")]);
+        let s = storage.cast(&DataType::Extension(ext.clone()))?;
+        assert_eq!(s.dtype(), &DataType::Extension(ext.clone()));
+        assert_eq!(s.str_value(0)?, "json({})");
+        assert_eq!(s.str_value(1)?, "null");
+
+        // operations keep the extension type
+        let out = s
+            .slice(1, 2)
+            .filter(&BooleanChunked::new("".into(), [true, true]))?;
+        assert_eq!(out.dtype(), s.dtype());
+        assert_eq!(out.len(), 2);
+        assert!(s.equals_missing(&s.rechunk()));
+
+        // ordering is not supported by default
+        assert!(s.sort(Default::default()).is_err());
+
+        let out = s.cast(&DataType::String)?;
+        assert!(out.equals_missing(&storage));
+
+        // a storage of the wrong type is rejected
+        let storage = Series::new("a".into(), [1i32]);
+        assert!(ExtensionChunked::new(storage, ext).is_err());
+        Ok(())
+    }
+}
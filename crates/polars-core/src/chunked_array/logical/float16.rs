@@ -0,0 +1,88 @@
+use arrow::buffer::Buffer;
+use polars_utils::total_ord::canonical_f32;
+
+use super::*;
+use crate::chunked_array::ops::arity::unary_elementwise_values;
+use crate::prelude::*;
+
+pub type Float16Chunked = Logical<Float16Type, UInt16Type>;
+
+/// Convert an arrow `Float16` array to the physical `u16` representation.
+pub(crate) fn f16_to_u16_array(arr: &PrimitiveArray<f16>) -> PrimitiveArray<u16> {
+    let values: Buffer<u16> = arr.values().iter().map(|v| v.to_bits()).collect();
+    PrimitiveArray::new(ArrowDataType::UInt16, values, arr.validity().cloned())
+}
+
+/// Convert the physical `u16` representation to an arrow `Float16` array.
+pub(crate) fn u16_to_f16_array(arr: &PrimitiveArray<u16>) -> PrimitiveArray<f16> {
+    let values: Buffer<f16> = arr.values().iter().map(|v| f16::from_bits(*v)).collect();
+    PrimitiveArray::new(ArrowDataType::Float16, values, arr.validity().cloned())
+}
+
+impl UInt16Chunked {
+    /// Interpret the `u16` values as the bit patterns of half precision floats.
+    pub fn into_float16(self) -> Float16Chunked {
+        Float16Chunked::new_logical(self)
+    }
+}
+
+impl Float16Chunked {
+    /// Construct a new [`Float16Chunked`] from an iterator over optional half precision floats.
+    pub fn from_f16s<I: IntoIterator<Item = Option<f16>>>(name: PlSmallStr, v: I) -> Self {
+        UInt16Chunked::from_iter_options(name, v.into_iter().map(|v| v.map(f16::to_bits)))
+            .into_float16()
+    }
+
+    /// Iterate over the half precision floats.
+    pub fn iter_f16s(&self) -> impl Iterator<Item = Option<f16>> + '_ {
+        self.0.iter().map(|v| v.map(f16::from_bits))
+    }
+
+    /// The bit patterns of the values in canonical form, where -0 == 0 and all NaNs are equal.
+    ///
+    /// Like the other floats, values are hashed and grouped in this form.
+    pub fn to_canonical_bits(&self) -> UInt16Chunked {
+        unary_elementwise_values(&self.0, |v| {
+            f16::from_f32(canonical_f32(f16::from_bits(v).to_f32())).to_bits()
+        })
+    }
+
+    /// Widen the values to single precision. This is lossless.
+    pub fn to_float32(&self) -> Float32Chunked {
+        unary_elementwise_values(&self.0, |v| f16::from_bits(v).to_f32())
+    }
+}
+
+impl Float32Chunked {
+    /// Narrow the values to half precision, rounding to the nearest representable value.
+    pub fn to_float16(&self) -> Float16Chunked {
+        let out: UInt16Chunked = unary_elementwise_values(self, |v| f16::from_f32(v).to_bits());
+        out.into_float16()
+    }
+}
+
+impl LogicalType for Float16Chunked {
+    fn dtype(&self) -> &DataType {
+        &DataType::Float16
+    }
+
+    fn get_any_value(&self, i: usize) -> PolarsResult<AnyValue<'_>> {
+        self.0.get_any_value(i).map(|av| av.as_float16())
+    }
+
+    unsafe fn get_any_value_unchecked(&self, i: usize) -> AnyValue<'_> {
+        self.0.get_any_value_unchecked(i).as_float16()
+    }
+
+    fn cast_with_options(
+        &self,
+        dtype: &DataType,
+        cast_options: CastOptions,
+    ) -> PolarsResult<Series> {
+        match dtype {
+            DataType::Float16 => Ok(self.clone().into_series()),
+            DataType::Float32 => Ok(self.to_float32().into_series()),
+            dt => self.to_float32().cast_with_options(dt, cast_options),
+        }
+    }
+}
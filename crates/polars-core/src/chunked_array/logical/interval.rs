@@ -0,0 +1,140 @@
+use arrow::buffer::Buffer;
+use arrow::datatypes::IntervalUnit;
+use arrow::types::months_days_ns;
+
+use super::*;
+use crate::prelude::*;
+
+pub type IntervalChunked = Logical<IntervalType, Int128Type>;
+
+const DAYS_SIGN: u32 = 1 << 31;
+const NS_SIGN: u64 = 1 << 63;
+
+/// Pack an interval into an `i128`.
+///
+/// The months occupy the upper 32 bits, followed by the days and the nanoseconds. The sign bits
+/// of the lower parts are flipped, so that the packed integers order the same way as the
+/// intervals: by months, then days, then nanoseconds.
+#[inline]
+pub fn interval_to_i128(v: months_days_ns) -> i128 {
+    let months = v.months() as i128;
+    let days = (v.days() as u32 ^ DAYS_SIGN) as i128;
+    let ns = (v.ns() as u64 ^ NS_SIGN) as i128;
+    (months << 96) | (days << 64) | ns
+}
+
+/// Unpack an interval packed by [`interval_to_i128`].
+#[inline]
+pub fn i128_to_interval(v: i128) -> months_days_ns {
+    let months = (v >> 96) as i32;
+    let days = ((v >> 64) as u32 ^ DAYS_SIGN) as i32;
+    let ns = (v as u64 ^ NS_SIGN) as i64;
+    months_days_ns::new(months, days, ns)
+}
+
+/// Convert an arrow `Interval(MonthDayNano)` array to the packed physical representation.
+pub(crate) fn interval_array_to_i128(arr: &PrimitiveArray<months_days_ns>) -> PrimitiveArray<i128> {
+    let values: Buffer<i128> = arr.values().iter().map(|v| interval_to_i128(*v)).collect();
+    PrimitiveArray::new(ArrowDataType::Int128, values, arr.validity().cloned())
+}
+
+/// Convert the packed physical representation to an arrow `Interval(MonthDayNano)` array.
+pub(crate) fn i128_to_interval_array(arr: &PrimitiveArray<i128>) -> PrimitiveArray<months_days_ns> {
+    let values: Buffer<months_days_ns> =
+        arr.values().iter().map(|v| i128_to_interval(*v)).collect();
+    PrimitiveArray::new(
+        ArrowDataType::Interval(IntervalUnit::MonthDayNano),
+        values,
+        arr.validity().cloned(),
+    )
+}
+
+impl Int128Chunked {
+    /// Interpret packed `i128` values as intervals. See [`interval_to_i128`].
+    pub fn into_interval(self) -> IntervalChunked {
+        IntervalChunked::new_logical(self)
+    }
+}
+
+impl IntervalChunked {
+    /// Construct a new [`IntervalChunked`] from an iterator over optional intervals.
+    pub fn from_intervals<I: IntoIterator<Item = Option<months_days_ns>>>(
+        name: PlSmallStr,
+        v: I,
+    ) -> Self {
+        Int128Chunked::from_iter_options(name, v.into_iter().map(|v| v.map(interval_to_i128)))
+            .into_interval()
+    }
+
+    /// Iterate over the unpacked intervals.
+    pub fn iter_intervals(&self) -> impl Iterator<Item = Option<months_days_ns>> + '_ {
+        self.0.iter().map(|v| v.map(i128_to_interval))
+    }
+}
+
+impl LogicalType for IntervalChunked {
+    fn dtype(&self) -> &DataType {
+        &DataType::Interval
+    }
+
+    fn get_any_value(&self, i: usize) -> PolarsResult<AnyValue<'_>> {
+        self.0.get_any_value(i).map(|av| av.as_interval())
+    }
+
+    unsafe fn get_any_value_unchecked(&self, i: usize) -> AnyValue<'_> {
+        self.0.get_any_value_unchecked(i).as_interval()
+    }
+
+    fn cast_with_options(
+        &self,
+        dtype: &DataType,
+        _cast_options: CastOptions,
+    ) -> PolarsResult<Series> {
+        match dtype {
+            DataType::Interval => Ok(self.clone().into_series()),
+            DataType::String => {
+                let mut buf = String::new();
+                let out: StringChunked = self
+                    .iter_intervals()
+                    .map(|opt_v| {
+                        opt_v.map(|v| {
+                            buf.clear();
+                            crate::fmt::fmt_interval_string(&mut buf, v).unwrap();
+                            buf.clone()
+                        })
+                    })
+                    .collect();
+                Ok(out.with_name(self.name().clone()).into_series())
+            },
+            dt => {
+                polars_bail!(
+                    InvalidOperation:
+                    "casting from {:?} to {:?} not supported",
+                    self.dtype(), dt
+                )
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_interval_packing_order() {
+        let intervals = [
+            months_days_ns::new(-1, 40, 0),
+            months_days_ns::new(0, -1, i64::MAX),
+            months_days_ns::new(0, 0, -5),
+            months_days_ns::new(0, 0, 0),
+            months_days_ns::new(0, 0, 5),
+            months_days_ns::new(0, 1, i64::MIN),
+            months_days_ns::new(1, i32::MIN, 0),
+            months_days_ns::new(i32::MAX, i32::MAX, i64::MAX),
+        ];
+        let packed = intervals.map(interval_to_i128);
+        assert!(packed.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(packed.map(i128_to_interval), intervals);
+    }
+}
@@ -0,0 +1,167 @@
+use super::*;
+use crate::prelude::*;
+
+pub type MapChunked = Logical<MapType, ListType>;
+
+impl ListChunked {
+    /// Interpret a list of `key`/`value` structs as a map.
+    ///
+    /// # Safety
+    /// The inner type must be a struct with a non-null `key` field and a `value` field, in
+    /// that order, and the entries themselves must not be null.
+    pub unsafe fn into_map_unchecked(self) -> MapChunked {
+        let DataType::Struct(fields) = self.inner_dtype() else {
+            unreachable!()
+        };
+        let dtype = DataType::Map(
+            Box::new(fields[0].dtype().clone()),
+            Box::new(fields[1].dtype().clone()),
+        );
+        let mut ca = MapChunked::new_logical(self);
+        ca.2 = Some(dtype);
+        ca
+    }
+
+    /// Interpret a list of two-field structs as a map; the first field holds the keys and the
+    /// second field the values.
+    pub fn into_map(self) -> PolarsResult<MapChunked> {
+        let (key, value) = match self.inner_dtype() {
+            DataType::Struct(fields) if fields.len() == 2 => {
+                (fields[0].dtype().clone(), fields[1].dtype().clone())
+            },
+            dt => polars_bail!(
+                SchemaMismatch: "cannot interpret list of {} as a map; expected a list of structs with two fields", dt
+            ),
+        };
+        let entries = self.get_inner();
+        polars_ensure!(
+            entries.null_count() == 0,
+            ComputeError: "map entries cannot be null"
+        );
+        polars_ensure!(
+            entries.struct_()?.fields_as_series()[0].null_count() == 0,
+            ComputeError: "map keys cannot be null"
+        );
+
+        let storage = DataType::Map(Box::new(key), Box::new(value))
+            .map_storage_dtype()
+            .unwrap();
+        let ca = if self.dtype() == &storage {
+            self
+        } else {
+            self.apply_to_inner(&|s| {
+                let entries = s.struct_()?;
+                let mut fields = entries.fields_as_series();
+                fields[0].rename(PlSmallStr::from_static(MAP_KEY_NAME));
+                fields[1].rename(PlSmallStr::from_static(MAP_VALUE_NAME));
+                StructChunked::from_series(entries.name().clone(), entries.len(), fields.iter())
+                    .map(|ca| ca.into_series())
+            })?
+        };
+        // SAFETY: checked above.
+        Ok(unsafe { ca.into_map_unchecked() })
+    }
+}
+
+impl LogicalType for MapChunked {
+    fn dtype(&self) -> &DataType {
+        self.2.as_ref().unwrap()
+    }
+
+    fn get_any_value(&self, i: usize) -> PolarsResult<AnyValue<'_>> {
+        self.0.get_any_value(i)
+    }
+
+    unsafe fn get_any_value_unchecked(&self, i: usize) -> AnyValue<'_> {
+        self.0.get_any_value_unchecked(i)
+    }
+
+    fn cast_with_options(
+        &self,
+        dtype: &DataType,
+        cast_options: CastOptions,
+    ) -> PolarsResult<Series> {
+        match dtype {
+            DataType::Map(_, _) => {
+                let storage = dtype.map_storage_dtype().unwrap();
+                let ca = self.0.cast_with_options(&storage, cast_options)?;
+                // SAFETY: the entries are cast field by field, so they remain valid map entries.
+                Ok(unsafe { ca.list()?.clone().into_map_unchecked() }.into_series())
+            },
+            _ => self.0.cast_with_options(dtype, cast_options),
+        }
+    }
+}
+
+impl MapChunked {
+    /// Get the [`DataType`] of the keys.
+    pub fn key_dtype(&self) -> &DataType {
+        match self.dtype() {
+            DataType::Map(key, _) => key,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Get the [`DataType`] of the values.
+    pub fn value_dtype(&self) -> &DataType {
+        match self.dtype() {
+            DataType::Map(_, value) => value,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Get the entries of all maps as a single struct [`Series`] with a `key` and `value` field.
+    pub fn get_entries(&self) -> Series {
+        self.0.get_inner()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn entries(keys: &[&str], values: &[Option<i64>]) -> Series {
+        let keys = Series::new("a".into(), keys);
+        let values = Series::new("b".into(), values);
+        StructChunked::from_series("".into(), keys.len(), [keys, values].iter())
+            .unwrap()
+            .into_series()
+    }
+
+    #[test]
+    fn test_map_arrow_round_trip() -> PolarsResult<()> {
+        let list = ListChunked::from_iter([
+            Some(entries(&["x", "y"], &[Some(1), None])),
+            None,
+            Some(entries(&[], &[])),
+        ]);
+        let s = list.into_map()?.into_series();
+        assert_eq!(
+            s.dtype(),
+            &DataType::Map(Box::new(DataType::String), Box::new(DataType::Int64))
+        );
+        assert_eq!(s.null_count(), 1);
+
+        let arr = s.to_arrow(0, CompatLevel::newest());
+        assert!(matches!(arr.dtype(), ArrowDataType::Map(_, _)));
+        let out = Series::try_from((s.name().clone(), arr))?;
+        assert_eq!(out.dtype(), s.dtype());
+        assert!(out.equals_missing(&s));
+
+        // logical operations keep the map type
+        let out = s.slice(0, 1).extend_constant(AnyValue::Null, 1)?;
+        assert_eq!(out.dtype(), s.dtype());
+        Ok(())
+    }
+
+    #[test]
+    fn test_map_null_keys() {
+        let keys = Series::new("a".into(), [Some("x"), None]);
+        let values = Series::new("b".into(), [1i64, 2]);
+        let entries = StructChunked::from_series("".into(), 2, [keys, values].iter())
+            .unwrap()
+            .into_series();
+        let list = ListChunked::from_iter([Some(entries)]);
+        assert!(list.into_map().is_err());
+    }
+}
@@ -10,16 +10,40 @@ pub use datetime::*;
 mod decimal;
 #[cfg(feature = "dtype-decimal")]
 pub use decimal::*;
+#[cfg(feature = "dtype-decimal256")]
+mod decimal256;
+#[cfg(feature = "dtype-decimal256")]
+pub use decimal256::*;
 #[cfg(feature = "dtype-duration")]
 mod duration;
 #[cfg(feature = "dtype-duration")]
 pub use duration::*;
+#[cfg(feature = "dtype-extension")]
+mod extension;
+#[cfg(feature = "dtype-extension")]
+pub use extension::*;
+#[cfg(feature = "dtype-f16")]
+mod float16;
+#[cfg(feature = "dtype-f16")]
+pub use float16::*;
+#[cfg(feature = "dtype-interval")]
+mod interval;
+#[cfg(feature = "dtype-interval")]
+pub use interval::*;
+#[cfg(feature = "dtype-map")]
+mod map;
+#[cfg(feature = "dtype-map")]
+pub use map::*;
 #[cfg(feature = "dtype-categorical")]
 pub mod categorical;
 #[cfg(feature = "dtype-categorical")]
 pub mod enum_;
 #[cfg(feature = "dtype-time")]
 mod time;
+#[cfg(feature = "dtype-uuid")]
+mod uuid;
+#[cfg(feature = "dtype-variant")]
+mod variant;
 
 use std::marker::PhantomData;
 use std::ops::{Deref, DerefMut};
@@ -28,6 +52,10 @@ use std::ops::{Deref, DerefMut};
 pub use categorical::*;
 #[cfg(feature = "dtype-time")]
 pub use time::*;
+#[cfg(feature = "dtype-uuid")]
+pub use uuid::*;
+#[cfg(feature = "dtype-variant")]
+pub use variant::*;
 
 use crate::chunked_array::cast::CastOptions;
 use crate::prelude::*;
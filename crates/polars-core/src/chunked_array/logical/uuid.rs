@@ -0,0 +1,371 @@
+use arrow::array::{FixedSizeBinaryArray, FixedSizeListArray};
+use arrow::bitmap::BitmapBuilder;
+use arrow::buffer::Buffer;
+
+use super::*;
+use crate::prelude::*;
+
+/// UUIDs, stored as 16-byte fixed-size binary; see [`DataType::Uuid`].
+pub type UuidChunked = Logical<UuidType, FixedSizeListType>;
+
+/// The name of the canonical Arrow extension type for UUIDs.
+pub const UUID_EXTENSION_NAME: &str = "arrow.uuid";
+
+/// The number of bytes of a UUID.
+pub const UUID_SIZE: usize = 16;
+
+const SIGN: u128 = 1 << 127;
+
+/// Pack a UUID into an `i128`.
+///
+/// The sign bit is flipped, so that the packed integers order the same way as the bytes of the
+/// UUIDs.
+#[inline]
+pub fn uuid_to_i128(v: u128) -> i128 {
+    (v ^ SIGN) as i128
+}
+
+/// Unpack a UUID packed by [`uuid_to_i128`].
+#[inline]
+pub fn i128_to_uuid(v: i128) -> u128 {
+    v as u128 ^ SIGN
+}
+
+/// Parse a UUID in its hyphenated (`67e55044-10b1-426f-9247-bb680e5fe0c8`) or simple
+/// (`67e5504410b1426f9247bb680e5fe0c8`) form.
+pub fn parse_uuid(s: &str) -> Option<u128> {
+    let bytes = s.as_bytes();
+    let hyphenated = match bytes.len() {
+        32 => false,
+        36 => true,
+        _ => return None,
+    };
+    let mut v = 0u128;
+    for (i, &b) in bytes.iter().enumerate() {
+        if hyphenated && matches!(i, 8 | 13 | 18 | 23) {
+            if b != b'-' {
+                return None;
+            }
+            continue;
+        }
+        let digit = (b as char).to_digit(16)?;
+        v = (v << 4) | digit as u128;
+    }
+    Some(v)
+}
+
+fn uuid_array_dtype() -> ArrowDataType {
+    FixedSizeListArray::default_datatype(ArrowDataType::UInt8, UUID_SIZE)
+}
+
+/// The bytes of the UUIDs in `arr`, `UUID_SIZE` per value.
+fn uuid_bytes(arr: &FixedSizeListArray) -> &Buffer<u8> {
+    arr.values()
+        .as_any()
+        .downcast_ref::<PrimitiveArray<u8>>()
+        .unwrap()
+        .values()
+}
+
+/// Get the UUID at `idx` of `arr`.
+///
+/// # Safety
+/// `idx` must be in bounds.
+pub(crate) unsafe fn uuid_array_get_any_value(
+    arr: &FixedSizeListArray,
+    idx: usize,
+) -> AnyValue<'static> {
+    if arr.is_null_unchecked(idx) {
+        return AnyValue::Null;
+    }
+    let b = uuid_bytes(arr).get_unchecked(idx * UUID_SIZE..(idx + 1) * UUID_SIZE);
+    AnyValue::Uuid(u128::from_be_bytes(b.try_into().unwrap()))
+}
+
+/// Convert an arrow `FixedSizeBinary(16)` array to the physical representation without copying
+/// the values.
+pub(crate) fn fixed_size_binary_to_uuid_array(arr: &FixedSizeBinaryArray) -> FixedSizeListArray {
+    assert_eq!(arr.size(), UUID_SIZE);
+    let values = PrimitiveArray::new(ArrowDataType::UInt8, arr.values().clone(), None);
+    FixedSizeListArray::new(
+        uuid_array_dtype(),
+        arr.len(),
+        values.to_boxed(),
+        arr.validity().cloned(),
+    )
+}
+
+/// Convert the physical representation to an arrow array of `dtype`, which must be a (possibly
+/// extension typed) `FixedSizeBinary(16)`, without copying the values.
+pub(crate) fn uuid_array_to_fixed_size_binary(
+    arr: &FixedSizeListArray,
+    dtype: ArrowDataType,
+) -> FixedSizeBinaryArray {
+    FixedSizeBinaryArray::new(dtype, uuid_bytes(arr).clone(), arr.validity().cloned())
+}
+
+impl Int128Chunked {
+    /// Unpack `i128` values packed by [`uuid_to_i128`] into UUIDs.
+    pub fn into_uuid(self) -> UuidChunked {
+        UuidChunked::from_uuids(
+            self.name().clone(),
+            self.iter().map(|v| v.map(i128_to_uuid)),
+        )
+    }
+}
+
+impl UuidChunked {
+    /// Construct a new [`UuidChunked`] from an iterator over optional UUIDs.
+    pub fn from_uuids<I: IntoIterator<Item = Option<u128>>>(name: PlSmallStr, v: I) -> Self {
+        let iter = v.into_iter();
+        let (lower, _) = iter.size_hint();
+        let mut values = Vec::with_capacity(lower * UUID_SIZE);
+        let mut validity = BitmapBuilder::with_capacity(lower);
+        for opt_v in iter {
+            values.extend_from_slice(&opt_v.unwrap_or_default().to_be_bytes());
+            validity.push(opt_v.is_some());
+        }
+        let len = validity.len();
+        let arr = FixedSizeListArray::new(
+            uuid_array_dtype(),
+            len,
+            PrimitiveArray::from_vec(values).to_boxed(),
+            validity.into_opt_validity(),
+        );
+        Self::from_uuid_chunks(name, vec![arr.to_boxed()])
+    }
+
+    /// Construct a new [`UuidChunked`] from `FixedSizeList` chunks of 16 `u8`s.
+    pub(crate) fn from_uuid_chunks(name: PlSmallStr, chunks: Vec<ArrayRef>) -> Self {
+        // SAFETY: the chunks have the physical type of UUIDs.
+        let ca = unsafe {
+            ArrayChunked::from_chunks_and_dtype(name, chunks, DataType::Uuid.to_physical())
+        };
+        UuidChunked::new_logical(ca)
+    }
+
+    /// Iterate over the UUIDs.
+    pub fn iter_uuids(&self) -> impl Iterator<Item = Option<u128>> + '_ {
+        self.0.downcast_iter().flat_map(|arr| {
+            uuid_bytes(arr)
+                .chunks_exact(UUID_SIZE)
+                .zip(arr.iter().map(|v| v.is_some()))
+                .map(|(b, valid)| valid.then(|| u128::from_be_bytes(b.try_into().unwrap())))
+        })
+    }
+
+    /// Pack the UUIDs into `i128`s with [`uuid_to_i128`].
+    ///
+    /// The packed values compare, hash and order like the UUIDs, so that keyed operations use the
+    /// fixed-width integer kernels.
+    pub fn to_i128(&self) -> Int128Chunked {
+        let chunks = self.0.downcast_iter().map(|arr| {
+            let values: Buffer<i128> = uuid_bytes(arr)
+                .chunks_exact(UUID_SIZE)
+                .map(|b| uuid_to_i128(u128::from_be_bytes(b.try_into().unwrap())))
+                .collect();
+            PrimitiveArray::new(ArrowDataType::Int128, values, arr.validity().cloned())
+        });
+        Int128Chunked::from_chunk_iter(self.name().clone(), chunks)
+    }
+
+    /// Generate `len` random (version 4) UUIDs.
+    #[cfg(feature = "random")]
+    pub fn new_v4(name: PlSmallStr, len: usize) -> Self {
+        use rand::Rng;
+
+        let mut rng = rand::thread_rng();
+        Self::from_uuids(
+            name,
+            (0..len).map(|_| {
+                let v: u128 = rng.r#gen();
+                Some(with_version(v, 4))
+            }),
+        )
+    }
+
+    /// Generate `len` time-ordered (version 7) UUIDs for the current time.
+    ///
+    /// The UUIDs of a single call share their timestamp and increase monotonically.
+    #[cfg(feature = "random")]
+    pub fn new_v7(name: PlSmallStr, len: usize) -> Self {
+        use rand::Rng;
+
+        let millis = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |d| d.as_millis());
+        // The 74 random bits are incremented for every UUID. The top bit is cleared, so that
+        // this cannot overflow into the timestamp.
+        let random: u128 = rand::thread_rng().r#gen::<u128>() >> 55;
+        Self::from_uuids(
+            name,
+            (0..len as u128).map(|i| {
+                let v = (millis & 0xFFFF_FFFF_FFFF) << 80 | spread_random(random + i);
+                Some(with_version(v, 7))
+            }),
+        )
+    }
+}
+
+/// Set the version and the RFC 9562 variant bits of a UUID.
+#[cfg(feature = "random")]
+fn with_version(v: u128, version: u128) -> u128 {
+    let v = (v & !(0xF << 76)) | (version << 76);
+    (v & !(0b11 << 62)) | (0b10 << 62)
+}
+
+/// Spread 74 random bits over the 12 bit `rand_a` and the 62 bit `rand_b` fields of a UUID v7,
+/// keeping their order.
+#[cfg(feature = "random")]
+fn spread_random(v: u128) -> u128 {
+    let rand_a = (v >> 62) & 0xFFF;
+    let rand_b = v & ((1 << 62) - 1);
+    (rand_a << 64) | rand_b
+}
+
+impl LogicalType for UuidChunked {
+    fn dtype(&self) -> &DataType {
+        &DataType::Uuid
+    }
+
+    fn get_any_value(&self, i: usize) -> PolarsResult<AnyValue<'_>> {
+        polars_ensure!(i < self.len(), oob = i, self.len());
+        Ok(unsafe { self.get_any_value_unchecked(i) })
+    }
+
+    unsafe fn get_any_value_unchecked(&self, i: usize) -> AnyValue<'_> {
+        let (chunk_idx, idx) = self.0.index_to_chunked_index(i);
+        uuid_array_get_any_value(self.0.downcast_get_unchecked(chunk_idx), idx)
+    }
+
+    fn cast_with_options(
+        &self,
+        dtype: &DataType,
+        _cast_options: CastOptions,
+    ) -> PolarsResult<Series> {
+        match dtype {
+            DataType::Uuid => Ok(self.clone().into_series()),
+            DataType::String => {
+                let mut buf = String::new();
+                let out: StringChunked = self
+                    .iter_uuids()
+                    .map(|opt_v| {
+                        opt_v.map(|v| {
+                            buf.clear();
+                            crate::fmt::fmt_uuid_string(&mut buf, v).unwrap();
+                            buf.clone()
+                        })
+                    })
+                    .collect();
+                Ok(out.with_name(self.name().clone()).into_series())
+            },
+            DataType::Binary => {
+                let out: BinaryChunked = self
+                    .iter_uuids()
+                    .map(|opt_v| opt_v.map(|v| v.to_be_bytes()))
+                    .collect();
+                Ok(out.with_name(self.name().clone()).into_series())
+            },
+            dt => {
+                polars_bail!(
+                    InvalidOperation:
+                    "casting from {:?} to {:?} not supported",
+                    self.dtype(), dt
+                )
+            },
+        }
+    }
+}
+
+impl StringChunked {
+    /// Parse the strings as UUIDs. Strings that are not valid UUIDs become null.
+    pub(crate) fn to_uuid(&self) -> UuidChunked {
+        UuidChunked::from_uuids(
+            self.name().clone(),
+            self.iter().map(|opt_s| opt_s.and_then(parse_uuid)),
+        )
+    }
+}
+
+impl BinaryChunked {
+    /// Interpret the bytes as UUIDs. Values that are not exactly 16 bytes become null.
+    pub(crate) fn to_uuid(&self) -> UuidChunked {
+        UuidChunked::from_uuids(
+            self.name().clone(),
+            self.iter().map(|opt_b| {
+                let b: [u8; UUID_SIZE] = opt_b?.try_into().ok()?;
+                Some(u128::from_be_bytes(b))
+            }),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_uuid_packing_order() {
+        let uuids = [0, 1, u64::MAX as u128, SIGN - 1, SIGN, SIGN + 1, u128::MAX];
+        let packed = uuids.map(uuid_to_i128);
+        assert!(packed.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(packed.map(i128_to_uuid), uuids);
+    }
+
+    #[test]
+    fn test_parse_uuid() {
+        let v = 0x67e55044_10b1_426f_9247_bb680e5fe0c8;
+        assert_eq!(parse_uuid("67e55044-10b1-426f-9247-bb680e5fe0c8"), Some(v));
+        assert_eq!(parse_uuid("67E5504410B1426F9247BB680E5FE0C8"), Some(v));
+        assert_eq!(parse_uuid("67e55044-10b1-426f-9247-bb680e5fe0c"), None);
+        assert_eq!(parse_uuid("67e55044+10b1-426f-9247-bb680e5fe0c8"), None);
+        assert_eq!(parse_uuid("g7e55044-10b1-426f-9247-bb680e5fe0c8"), None);
+    }
+
+    #[test]
+    #[cfg(feature = "random")]
+    fn test_uuid_generation() {
+        let v4 = UuidChunked::new_v4(PlSmallStr::EMPTY, 100);
+        assert!(v4.iter_uuids().all(|v| (v.unwrap() >> 76) & 0xF == 4));
+        assert_eq!(v4.to_i128().n_unique().unwrap(), 100);
+
+        let v7 = UuidChunked::new_v7(PlSmallStr::EMPTY, 100);
+        let v7 = v7.iter_uuids().map(|v| v.unwrap()).collect::<Vec<_>>();
+        assert!(
+            v7.iter()
+                .all(|v| (v >> 76) & 0xF == 7 && (v >> 62) & 0b11 == 0b10)
+        );
+        assert!(v7.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn test_uuid_storage() {
+        let uuids = [Some(SIGN + 1), None, Some(1), Some(u128::MAX)];
+        let ca = UuidChunked::from_uuids(PlSmallStr::EMPTY, uuids);
+        assert_eq!(
+            ca.physical().dtype(),
+            &DataType::Array(Box::new(DataType::UInt8), 16)
+        );
+        assert_eq!(ca.iter_uuids().collect::<Vec<_>>(), uuids);
+
+        let packed = ca.to_i128();
+        assert_eq!(packed.null_count(), 1);
+        assert!(packed.get(2) < packed.get(0) && packed.get(0) < packed.get(3));
+        assert_eq!(packed.into_uuid().iter_uuids().collect::<Vec<_>>(), uuids);
+
+        // the conversions from and to arrow share the values
+        let ca = ca.slice(1, 3);
+        let arr = uuid_array_to_fixed_size_binary(
+            ca.downcast_iter().next().unwrap(),
+            ArrowDataType::FixedSizeBinary(16),
+        );
+        assert_eq!(arr.value(1), 1u128.to_be_bytes());
+        let arr = fixed_size_binary_to_uuid_array(&arr);
+        let ca = UuidChunked::from_uuid_chunks(PlSmallStr::EMPTY, vec![arr.to_boxed()]);
+        assert_eq!(ca.iter_uuids().collect::<Vec<_>>(), uuids[1..]);
+        assert_eq!(
+            unsafe { ca.get_any_value_unchecked(2) },
+            AnyValue::Uuid(u128::MAX)
+        );
+    }
+}
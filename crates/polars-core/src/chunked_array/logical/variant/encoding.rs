@@ -0,0 +1,878 @@
+//! The binary encoding of variant values, as described by the Parquet Variant specification.
+//!
+//! A variant consists of a metadata buffer, holding a dictionary with the keys of all objects in
+//! the value, and a value buffer that refers to those keys by their position in the dictionary.
+//! A [`VariantChunked`] stores both buffers back to back in a single binary value; the metadata
+//! header determines where the value starts.
+//!
+//! Values are always written in a canonical form: the dictionary holds exactly the keys that are
+//! used, in sorted order, and every value uses the smallest encoding that represents it exactly.
+//! Equal values therefore have equal bytes.
+//!
+//! [`VariantChunked`]: super::VariantChunked
+use std::borrow::Cow;
+use std::fmt::Write;
+
+const VERSION: u8 = 1;
+const SORTED_STRINGS: u8 = 1 << 4;
+
+const BASIC_PRIMITIVE: u8 = 0;
+const BASIC_SHORT_STRING: u8 = 1;
+const BASIC_OBJECT: u8 = 2;
+const BASIC_ARRAY: u8 = 3;
+
+const MAX_SHORT_STRING_LEN: usize = 63;
+/// The maximum nesting depth of values we decode, to guard against stack overflows.
+pub(super) const MAX_DEPTH: usize = 256;
+
+/// The metadata of a variant without any keys.
+pub(super) const EMPTY_METADATA: [u8; 3] = [VERSION | SORTED_STRINGS, 0, 0];
+
+pub(super) mod primitive {
+    pub const NULL: u8 = 0;
+    pub const TRUE: u8 = 1;
+    pub const FALSE: u8 = 2;
+    pub const INT8: u8 = 3;
+    pub const INT16: u8 = 4;
+    pub const INT32: u8 = 5;
+    pub const INT64: u8 = 6;
+    pub const DOUBLE: u8 = 7;
+    pub const DECIMAL4: u8 = 8;
+    pub const DECIMAL8: u8 = 9;
+    pub const DECIMAL16: u8 = 10;
+    pub const DATE: u8 = 11;
+    pub const TIMESTAMP: u8 = 12;
+    pub const TIMESTAMP_NTZ: u8 = 13;
+    pub const FLOAT: u8 = 14;
+    pub const BINARY: u8 = 15;
+    pub const STRING: u8 = 16;
+    pub const TIME_NTZ: u8 = 17;
+    pub const TIMESTAMP_NANOS: u8 = 18;
+    pub const TIMESTAMP_NTZ_NANOS: u8 = 19;
+    pub const UUID: u8 = 20;
+}
+
+/// A decoded variant value.
+///
+/// Strings may borrow from the buffer they were decoded from. Primitive values without a JSON
+/// counterpart, such as dates, are kept in their encoded form.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Value<'a> {
+    Null,
+    Boolean(bool),
+    Int(i64),
+    Double(f64),
+    /// An unscaled value and its scale.
+    Decimal(i128, u8),
+    String(Cow<'a, str>),
+    Array(Vec<Value<'a>>),
+    /// The fields of an object, the keys are unique.
+    Object(Vec<(Cow<'a, str>, Value<'a>)>),
+    /// Any other primitive value, including its header byte.
+    Other(Cow<'a, [u8]>),
+}
+
+#[inline]
+fn primitive_header(type_id: u8) -> u8 {
+    (type_id << 2) | BASIC_PRIMITIVE
+}
+
+/// The number of bytes needed to store `v` as a little-endian unsigned integer.
+fn int_size(v: usize) -> u8 {
+    match v {
+        0..=0xFF => 1,
+        0x100..=0xFFFF => 2,
+        0x10000..=0xFF_FFFF => 3,
+        _ => 4,
+    }
+}
+
+fn write_int(v: usize, size: u8, out: &mut Vec<u8>) {
+    out.extend_from_slice(&(v as u32).to_le_bytes()[..size as usize]);
+}
+
+fn read_int(bytes: &[u8], size: usize) -> Option<usize> {
+    let bytes = bytes.get(..size)?;
+    let mut buf = [0u8; 4];
+    buf[..size].copy_from_slice(bytes);
+    Some(u32::from_le_bytes(buf) as usize)
+}
+
+fn read_array<const N: usize>(bytes: &[u8]) -> Option<[u8; N]> {
+    bytes.get(..N)?.try_into().ok()
+}
+
+/// The size of an encoded primitive value, including its header byte.
+fn primitive_len(type_id: u8, data: &[u8]) -> Option<usize> {
+    use primitive::*;
+    let len = match type_id {
+        NULL | TRUE | FALSE => 0,
+        INT8 => 1,
+        INT16 => 2,
+        INT32 | DATE | FLOAT => 4,
+        INT64 | DOUBLE | TIMESTAMP | TIMESTAMP_NTZ | TIME_NTZ | TIMESTAMP_NANOS
+        | TIMESTAMP_NTZ_NANOS => 8,
+        DECIMAL4 => 5,
+        DECIMAL8 => 9,
+        DECIMAL16 => 17,
+        UUID => 16,
+        BINARY | STRING => 4 + read_int(data, 4)?,
+        _ => return None,
+    };
+    Some(1 + len)
+}
+
+fn write_metadata(keys: &[&str], out: &mut Vec<u8>) {
+    let total_len: usize = keys.iter().map(|k| k.len()).sum();
+    let offset_size = int_size(total_len.max(keys.len()));
+    out.push(VERSION | SORTED_STRINGS | ((offset_size - 1) << 6));
+    write_int(keys.len(), offset_size, out);
+    let mut offset = 0;
+    write_int(offset, offset_size, out);
+    for key in keys {
+        offset += key.len();
+        write_int(offset, offset_size, out);
+    }
+    for key in keys {
+        out.extend_from_slice(key.as_bytes());
+    }
+}
+
+fn write_string(s: &str, out: &mut Vec<u8>) {
+    if s.len() <= MAX_SHORT_STRING_LEN {
+        out.push(((s.len() as u8) << 2) | BASIC_SHORT_STRING);
+    } else {
+        out.push(primitive_header(primitive::STRING));
+        out.extend_from_slice(&(s.len() as u32).to_le_bytes());
+    }
+    out.extend_from_slice(s.as_bytes());
+}
+
+impl Value<'_> {
+    /// Encode the value together with its metadata.
+    pub(crate) fn encode(&self, out: &mut Vec<u8>) {
+        let mut keys = Vec::new();
+        self.collect_keys(&mut keys);
+        keys.sort_unstable();
+        keys.dedup();
+        write_metadata(&keys, out);
+        self.encode_value(&keys, out);
+    }
+
+    fn collect_keys<'b>(&'b self, keys: &mut Vec<&'b str>) {
+        match self {
+            Value::Array(values) => values.iter().for_each(|v| v.collect_keys(keys)),
+            Value::Object(fields) => {
+                for (key, value) in fields {
+                    keys.push(key);
+                    value.collect_keys(keys);
+                }
+            },
+            _ => {},
+        }
+    }
+
+    /// Encode only the value, against a sorted dictionary that holds all its keys.
+    pub(super) fn encode_value(&self, keys: &[&str], out: &mut Vec<u8>) {
+        use primitive::*;
+        match self {
+            Value::Null => out.push(primitive_header(NULL)),
+            Value::Boolean(v) => out.push(primitive_header(if *v { TRUE } else { FALSE })),
+            Value::Int(v) => {
+                let v = *v;
+                if let Ok(v) = i8::try_from(v) {
+                    out.push(primitive_header(INT8));
+                    out.extend_from_slice(&v.to_le_bytes());
+                } else if let Ok(v) = i16::try_from(v) {
+                    out.push(primitive_header(INT16));
+                    out.extend_from_slice(&v.to_le_bytes());
+                } else if let Ok(v) = i32::try_from(v) {
+                    out.push(primitive_header(INT32));
+                    out.extend_from_slice(&v.to_le_bytes());
+                } else {
+                    out.push(primitive_header(INT64));
+                    out.extend_from_slice(&v.to_le_bytes());
+                }
+            },
+            Value::Double(v) => {
+                out.push(primitive_header(DOUBLE));
+                out.extend_from_slice(&v.to_le_bytes());
+            },
+            Value::Decimal(v, scale) => {
+                let abs = v.unsigned_abs();
+                if abs < 10u128.pow(9) {
+                    out.push(primitive_header(DECIMAL4));
+                    out.push(*scale);
+                    out.extend_from_slice(&(*v as i32).to_le_bytes());
+                } else if abs < 10u128.pow(18) {
+                    out.push(primitive_header(DECIMAL8));
+                    out.push(*scale);
+                    out.extend_from_slice(&(*v as i64).to_le_bytes());
+                } else {
+                    out.push(primitive_header(DECIMAL16));
+                    out.push(*scale);
+                    out.extend_from_slice(&v.to_le_bytes());
+                }
+            },
+            Value::String(s) => write_string(s, out),
+            Value::Array(values) => {
+                let mut data = Vec::new();
+                let mut offsets = Vec::with_capacity(values.len() + 1);
+                for value in values {
+                    offsets.push(data.len());
+                    value.encode_value(keys, &mut data);
+                }
+                offsets.push(data.len());
+
+                let offset_size = int_size(data.len());
+                let is_large = values.len() > u8::MAX as usize;
+                let header = ((is_large as u8) << 2) | (offset_size - 1);
+                out.push((header << 2) | BASIC_ARRAY);
+                write_int(values.len(), if is_large { 4 } else { 1 }, out);
+                for offset in offsets {
+                    write_int(offset, offset_size, out);
+                }
+                out.extend_from_slice(&data);
+            },
+            Value::Object(fields) => {
+                // The fields are laid out in the order of their keys.
+                let mut sorted = fields.iter().collect::<Vec<_>>();
+                sorted.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+
+                let mut data = Vec::new();
+                let mut ids = Vec::with_capacity(sorted.len());
+                let mut offsets = Vec::with_capacity(sorted.len() + 1);
+                for (key, value) in sorted {
+                    ids.push(keys.binary_search(&key.as_ref()).unwrap());
+                    offsets.push(data.len());
+                    value.encode_value(keys, &mut data);
+                }
+                offsets.push(data.len());
+
+                let id_size = int_size(keys.len().saturating_sub(1));
+                let offset_size = int_size(data.len());
+                let is_large = fields.len() > u8::MAX as usize;
+                let header = ((is_large as u8) << 4) | ((id_size - 1) << 2) | (offset_size - 1);
+                out.push((header << 2) | BASIC_OBJECT);
+                write_int(fields.len(), if is_large { 4 } else { 1 }, out);
+                for id in ids {
+                    write_int(id, id_size, out);
+                }
+                for offset in offsets {
+                    write_int(offset, offset_size, out);
+                }
+                out.extend_from_slice(&data);
+            },
+            Value::Other(bytes) => out.extend_from_slice(bytes),
+        }
+    }
+
+    pub(crate) fn as_bool(&self) -> Option<bool> {
+        match self {
+            Value::Boolean(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    /// Convert a numeric value to an integer, truncating any fractional part.
+    pub(crate) fn as_i64(&self) -> Option<i64> {
+        match self {
+            Value::Int(v) => Some(*v),
+            Value::Boolean(v) => Some(*v as i64),
+            Value::Decimal(v, scale) => i64::try_from(v / 10i128.checked_pow(*scale as u32)?).ok(),
+            Value::Double(v) => {
+                let v = v.trunc();
+                (v >= i64::MIN as f64 && v < i64::MAX as f64).then_some(v as i64)
+            },
+            Value::String(s) => s.parse().ok(),
+            Value::Other(bytes) if bytes[0] == primitive_header(primitive::FLOAT) => {
+                Value::Double(f32::from_le_bytes(read_array(&bytes[1..])?) as f64).as_i64()
+            },
+            _ => None,
+        }
+    }
+
+    pub(crate) fn as_f64(&self) -> Option<f64> {
+        match self {
+            Value::Double(v) => Some(*v),
+            Value::Int(v) => Some(*v as f64),
+            Value::Boolean(v) => Some(*v as u8 as f64),
+            Value::Decimal(v, scale) => Some(*v as f64 / 10f64.powi(*scale as i32)),
+            Value::String(s) => s.parse().ok(),
+            Value::Other(bytes) if bytes[0] == primitive_header(primitive::FLOAT) => {
+                Some(f32::from_le_bytes(read_array(&bytes[1..])?) as f64)
+            },
+            _ => None,
+        }
+    }
+
+    /// Write the value as JSON.
+    ///
+    /// Values without a JSON counterpart are written as strings: dates and times in ISO 8601,
+    /// binary data in base64 and non-finite floats as `"NaN"`, `"Infinity"` or `"-Infinity"`.
+    pub(crate) fn write_json(&self, out: &mut String) {
+        match self {
+            Value::Null => out.push_str("null"),
+            Value::Boolean(v) => out.push_str(if *v { "true" } else { "false" }),
+            Value::Int(v) => write!(out, "{v}").unwrap(),
+            Value::Double(v) => write_json_double(*v, out),
+            Value::Decimal(v, scale) => write_decimal(*v, *scale, out),
+            Value::String(s) => write_json_string(s, out),
+            Value::Array(values) => {
+                out.push('[');
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    value.write_json(out);
+                }
+                out.push(']');
+            },
+            Value::Object(fields) => {
+                out.push('{');
+                for (i, (key, value)) in fields.iter().enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    write_json_string(key, out);
+                    out.push(':');
+                    value.write_json(out);
+                }
+                out.push('}');
+            },
+            Value::Other(bytes) => write_other_json(bytes, out),
+        }
+    }
+}
+
+fn write_json_double(v: f64, out: &mut String) {
+    if v.is_finite() {
+        write!(out, "{v:?}").unwrap()
+    } else if v.is_nan() {
+        out.push_str("\"NaN\"")
+    } else if v > 0.0 {
+        out.push_str("\"Infinity\"")
+    } else {
+        out.push_str("\"-Infinity\"")
+    }
+}
+
+fn write_decimal(v: i128, scale: u8, out: &mut String) {
+    if v < 0 {
+        out.push('-');
+    }
+    let scale = scale as usize;
+    let digits = format!("{:0>width$}", v.unsigned_abs(), width = scale + 1);
+    let (int, frac) = digits.split_at(digits.len() - scale);
+    out.push_str(int);
+    if !frac.is_empty() {
+        out.push('.');
+        out.push_str(frac);
+    }
+}
+
+pub(super) fn write_json_string(s: &str, out: &mut String) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '\u{08}' => out.push_str("\\b"),
+            '\u{0c}' => out.push_str("\\f"),
+            c if c < ' ' => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+/// Convert days since the UNIX epoch to a (year, month, day) date.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + (month <= 2) as i64;
+    (year, month, day)
+}
+
+fn write_date(days: i64, out: &mut String) {
+    let (year, month, day) = civil_from_days(days);
+    write!(out, "{year:04}-{month:02}-{day:02}").unwrap();
+}
+
+/// Write a time of day given in units of `10^-precision` seconds.
+fn write_time(v: i64, precision: u32, out: &mut String) {
+    let per_second = 10i64.pow(precision);
+    let (secs, frac) = (v / per_second, v % per_second);
+    let (hours, minutes, seconds) = (secs / 3600, secs / 60 % 60, secs % 60);
+    write!(
+        out,
+        "{hours:02}:{minutes:02}:{seconds:02}.{frac:0width$}",
+        width = precision as usize
+    )
+    .unwrap();
+}
+
+fn write_timestamp(v: i64, precision: u32, utc: bool, out: &mut String) {
+    let per_day = 86_400 * 10i64.pow(precision);
+    write_date(v.div_euclid(per_day), out);
+    out.push(' ');
+    write_time(v.rem_euclid(per_day), precision, out);
+    if utc {
+        out.push_str("+00:00");
+    }
+}
+
+fn write_base64(bytes: &[u8], out: &mut String) {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    for chunk in bytes.chunks(3) {
+        let b = [
+            chunk[0],
+            *chunk.get(1).unwrap_or(&0),
+            *chunk.get(2).unwrap_or(&0),
+        ];
+        let n = u32::from_be_bytes([0, b[0], b[1], b[2]]);
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(ALPHABET[(n >> (18 - 6 * i) & 0x3F) as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+}
+
+fn write_other_json(bytes: &[u8], out: &mut String) {
+    use primitive::*;
+    let data = &bytes[1..];
+    let i32_at = || i32::from_le_bytes(read_array(data).unwrap()) as i64;
+    let i64_at = || i64::from_le_bytes(read_array(data).unwrap());
+    if bytes[0] == primitive_header(FLOAT) {
+        let v = f32::from_le_bytes(read_array(data).unwrap());
+        if v.is_finite() {
+            write!(out, "{v:?}").unwrap();
+        } else {
+            write_json_double(v as f64, out);
+        }
+        return;
+    }
+
+    out.push('"');
+    match bytes[0] >> 2 {
+        DATE => write_date(i32_at(), out),
+        TIMESTAMP => write_timestamp(i64_at(), 6, true, out),
+        TIMESTAMP_NTZ => write_timestamp(i64_at(), 6, false, out),
+        TIMESTAMP_NANOS => write_timestamp(i64_at(), 9, true, out),
+        TIMESTAMP_NTZ_NANOS => write_timestamp(i64_at(), 9, false, out),
+        TIME_NTZ => write_time(i64_at(), 6, out),
+        BINARY => write_base64(&data[4..], out),
+        UUID => {
+            let v = u128::from_be_bytes(read_array(data).unwrap());
+            write!(
+                out,
+                "{:08x}-{:04x}-{:04x}-{:04x}-{:012x}",
+                v >> 96,
+                (v >> 80) & 0xFFFF,
+                (v >> 64) & 0xFFFF,
+                (v >> 48) & 0xFFFF,
+                v & 0xFFFF_FFFF_FFFF
+            )
+            .unwrap();
+        },
+        _ => unreachable!(),
+    }
+    out.push('"');
+}
+
+/// The metadata of a variant, holding the dictionary of object keys.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Metadata<'a> {
+    bytes: &'a [u8],
+    offset_size: usize,
+    dict_size: usize,
+}
+
+impl<'a> Metadata<'a> {
+    /// Read the metadata from the start of `bytes`, which may extend past its end.
+    pub(crate) fn try_new(bytes: &'a [u8]) -> Option<Self> {
+        let header = *bytes.first()?;
+        if header & 0b1111 != VERSION {
+            return None;
+        }
+        let offset_size = ((header >> 6) + 1) as usize;
+        let dict_size = read_int(bytes.get(1..)?, offset_size)?;
+        let keys_start = 1 + offset_size * (dict_size + 2);
+        let keys_len = read_int(bytes.get(keys_start - offset_size..)?, offset_size)?;
+        Some(Self {
+            bytes: bytes.get(..keys_start + keys_len)?,
+            offset_size,
+            dict_size,
+        })
+    }
+
+    /// The encoded metadata.
+    pub(crate) fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    fn key_offset(&self, i: usize) -> Option<usize> {
+        read_int(
+            self.bytes.get(1 + self.offset_size * (1 + i)..)?,
+            self.offset_size,
+        )
+    }
+
+    pub(crate) fn key(&self, id: usize) -> Option<&'a str> {
+        if id >= self.dict_size {
+            return None;
+        }
+        let keys_start = 1 + self.offset_size * (self.dict_size + 2);
+        let start = keys_start + self.key_offset(id)?;
+        let end = keys_start + self.key_offset(id + 1)?;
+        std::str::from_utf8(self.bytes.get(start..end)?).ok()
+    }
+
+    /// All keys in the dictionary.
+    pub(crate) fn keys(&self) -> Option<Vec<&'a str>> {
+        (0..self.dict_size).map(|id| self.key(id)).collect()
+    }
+}
+
+/// The layout of an object or array value.
+struct Container {
+    len: usize,
+    id_size: usize,
+    offset_size: usize,
+    ids_start: usize,
+    offsets_start: usize,
+    data_start: usize,
+}
+
+/// A reference to an encoded variant value.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct VariantRef<'a> {
+    metadata: Metadata<'a>,
+    /// The value, this may extend past the end of the value.
+    value: &'a [u8],
+}
+
+impl<'a> VariantRef<'a> {
+    /// Read a variant stored as metadata followed by the value.
+    pub(crate) fn from_bytes(bytes: &'a [u8]) -> Option<Self> {
+        let metadata = Metadata::try_new(bytes)?;
+        let value = &bytes[metadata.bytes.len()..];
+        Some(Self { metadata, value })
+    }
+
+    pub(crate) fn new(metadata: Metadata<'a>, value: &'a [u8]) -> Self {
+        Self { metadata, value }
+    }
+
+    pub(crate) fn metadata(&self) -> Metadata<'a> {
+        self.metadata
+    }
+
+    fn container(&self, basic_type: u8) -> Option<Container> {
+        let header = *self.value.first()?;
+        if header & 0b11 != basic_type {
+            return None;
+        }
+        let header = header >> 2;
+        let (is_large, id_size) = if basic_type == BASIC_OBJECT {
+            (header & (1 << 4) != 0, ((header >> 2) & 0b11) as usize + 1)
+        } else {
+            (header & (1 << 2) != 0, 0)
+        };
+        let offset_size = (header & 0b11) as usize + 1;
+        let len_size = if is_large { 4 } else { 1 };
+        let len = read_int(self.value.get(1..)?, len_size)?;
+        let ids_start = 1 + len_size;
+        let offsets_start = ids_start + len * id_size;
+        let data_start = offsets_start + (len + 1) * offset_size;
+        (data_start <= self.value.len()).then_some(Container {
+            len,
+            id_size,
+            offset_size,
+            ids_start,
+            offsets_start,
+            data_start,
+        })
+    }
+
+    fn child(&self, container: &Container, i: usize) -> Option<Self> {
+        let start = container.offsets_start + i * container.offset_size;
+        let offset = read_int(self.value.get(start..)?, container.offset_size)?;
+        Some(Self {
+            metadata: self.metadata,
+            value: self.value.get(container.data_start + offset..)?,
+        })
+    }
+
+    fn field_key(&self, container: &Container, i: usize) -> Option<&'a str> {
+        let start = container.ids_start + i * container.id_size;
+        self.metadata
+            .key(read_int(self.value.get(start..)?, container.id_size)?)
+    }
+
+    /// Look up the field with the given key, if this is an object.
+    pub(crate) fn field(&self, key: &str) -> Option<Self> {
+        let container = self.container(BASIC_OBJECT)?;
+        // The fields are sorted by key.
+        let (mut lo, mut hi) = (0, container.len);
+        while lo < hi {
+            let mid = (lo + hi) / 2;
+            match self.field_key(&container, mid)?.cmp(key) {
+                std::cmp::Ordering::Less => lo = mid + 1,
+                std::cmp::Ordering::Greater => hi = mid,
+                std::cmp::Ordering::Equal => return self.child(&container, mid),
+            }
+        }
+        None
+    }
+
+    /// Look up the element at the given index, if this is an array.
+    pub(crate) fn index(&self, i: usize) -> Option<Self> {
+        let container = self.container(BASIC_ARRAY)?;
+        if i >= container.len {
+            return None;
+        }
+        self.child(&container, i)
+    }
+
+    /// Decode the value, returns `None` if it is not validly encoded.
+    pub(crate) fn decode(&self) -> Option<Value<'a>> {
+        self.decode_with_depth(0)
+    }
+
+    fn decode_with_depth(&self, depth: usize) -> Option<Value<'a>> {
+        use primitive::*;
+        if depth > MAX_DEPTH {
+            return None;
+        }
+        let header = *self.value.first()?;
+        let data = &self.value[1..];
+        let value = match header & 0b11 {
+            BASIC_PRIMITIVE => match header >> 2 {
+                NULL => Value::Null,
+                TRUE => Value::Boolean(true),
+                FALSE => Value::Boolean(false),
+                INT8 => Value::Int(i8::from_le_bytes(read_array(data)?) as i64),
+                INT16 => Value::Int(i16::from_le_bytes(read_array(data)?) as i64),
+                INT32 => Value::Int(i32::from_le_bytes(read_array(data)?) as i64),
+                INT64 => Value::Int(i64::from_le_bytes(read_array(data)?)),
+                DOUBLE => Value::Double(f64::from_le_bytes(read_array(data)?)),
+                DECIMAL4 => Value::Decimal(
+                    i32::from_le_bytes(read_array(data.get(1..)?)?) as i128,
+                    *data.first()?,
+                ),
+                DECIMAL8 => Value::Decimal(
+                    i64::from_le_bytes(read_array(data.get(1..)?)?) as i128,
+                    *data.first()?,
+                ),
+                DECIMAL16 => Value::Decimal(
+                    i128::from_le_bytes(read_array(data.get(1..)?)?),
+                    *data.first()?,
+                ),
+                STRING => {
+                    let len = read_int(data, 4)?;
+                    Value::String(Cow::Borrowed(
+                        std::str::from_utf8(data.get(4..4 + len)?).ok()?,
+                    ))
+                },
+                type_id => {
+                    let len = primitive_len(type_id, data)?;
+                    Value::Other(Cow::Borrowed(self.value.get(..len)?))
+                },
+            },
+            BASIC_SHORT_STRING => {
+                let len = (header >> 2) as usize;
+                Value::String(Cow::Borrowed(std::str::from_utf8(data.get(..len)?).ok()?))
+            },
+            BASIC_OBJECT => {
+                let container = self.container(BASIC_OBJECT)?;
+                let fields = (0..container.len)
+                    .map(|i| {
+                        let key = self.field_key(&container, i)?;
+                        let value = self.child(&container, i)?.decode_with_depth(depth + 1)?;
+                        Some((Cow::Borrowed(key), value))
+                    })
+                    .collect::<Option<Vec<_>>>()?;
+                Value::Object(fields)
+            },
+            _ => {
+                let container = self.container(BASIC_ARRAY)?;
+                let values = (0..container.len)
+                    .map(|i| self.child(&container, i)?.decode_with_depth(depth + 1))
+                    .collect::<Option<Vec<_>>>()?;
+                Value::Array(values)
+            },
+        };
+        Some(value)
+    }
+}
+
+/// Encode a primitive value that has no [`Value`] counterpart.
+pub(crate) fn encode_other(type_id: u8, data: &[u8]) -> Value<'static> {
+    let mut bytes = Vec::with_capacity(1 + data.len());
+    bytes.push(primitive_header(type_id));
+    bytes.extend_from_slice(data);
+    Value::Other(Cow::Owned(bytes))
+}
+
+/// An element of a path into a variant value.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum PathElement {
+    Field(String),
+    Index(usize),
+}
+
+/// Parse a path such as `$.a.b[0]`, `a["b c"]` or `[1].a`. The leading `$` is optional.
+pub(crate) fn parse_path(path: &str) -> Option<Vec<PathElement>> {
+    let path = path.strip_prefix('$').unwrap_or(path);
+    let bytes = path.as_bytes();
+    let mut elements = Vec::new();
+    let mut pos = 0;
+    while pos < bytes.len() {
+        match bytes[pos] {
+            b'[' => {
+                let end = pos + path[pos..].find(']')?;
+                let inner = path[pos + 1..end].trim();
+                let element = match inner.as_bytes().first()? {
+                    q @ (b'"' | b'\'')
+                        if inner.len() >= 2 && inner.as_bytes()[inner.len() - 1] == *q =>
+                    {
+                        PathElement::Field(inner[1..inner.len() - 1].to_string())
+                    },
+                    _ => PathElement::Index(inner.parse().ok()?),
+                };
+                elements.push(element);
+                pos = end + 1;
+            },
+            b'.' if pos + 1 < bytes.len() && !matches!(bytes[pos + 1], b'.' | b'[') => pos += 1,
+            b'.' => return None,
+            _ => {
+                let end = path[pos..].find(['.', '[']).map_or(path.len(), |i| pos + i);
+                elements.push(PathElement::Field(path[pos..end].to_string()));
+                pos = end;
+            },
+        }
+    }
+    Some(elements)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn round_trip(value: &Value) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        value.encode(&mut bytes);
+        let decoded = VariantRef::from_bytes(&bytes).unwrap().decode().unwrap();
+        assert_eq!(&decoded, value);
+        bytes
+    }
+
+    #[test]
+    fn test_variant_encoding_round_trip() {
+        let long = "x".repeat(100);
+        let value = Value::Object(vec![
+            ("b".into(), Value::Int(-3)),
+            ("a".into(), Value::Int(1 << 40)),
+            ("s".into(), Value::String(long.as_str().into())),
+            ("d".into(), Value::Decimal(-12345678901234567890, 3)),
+            (
+                "l".into(),
+                Value::Array(vec![
+                    Value::Null,
+                    Value::Boolean(true),
+                    Value::Double(0.5),
+                    Value::Object(vec![("a".into(), Value::String("inner".into()))]),
+                ]),
+            ),
+        ]);
+        let mut expected = value.clone();
+        // Objects decode with their fields in key order.
+        if let Value::Object(fields) = &mut expected {
+            fields.sort_by(|a, b| a.0.cmp(&b.0));
+        }
+        let mut bytes = Vec::new();
+        value.encode(&mut bytes);
+        let v = VariantRef::from_bytes(&bytes).unwrap();
+        assert_eq!(v.decode().unwrap(), expected);
+        round_trip(&expected);
+
+        assert_eq!(v.field("b").unwrap().decode(), Some(Value::Int(-3)));
+        assert_eq!(
+            v.field("l")
+                .unwrap()
+                .index(3)
+                .unwrap()
+                .field("a")
+                .unwrap()
+                .decode(),
+            Some(Value::String("inner".into()))
+        );
+        assert!(v.field("c").is_none());
+        assert!(v.index(0).is_none());
+
+        // The dictionary holds each key once, in sorted order.
+        assert_eq!(v.metadata().keys().unwrap(), ["a", "b", "d", "l", "s"]);
+    }
+
+    #[test]
+    fn test_variant_canonical_encoding() {
+        // Integers use the smallest width that fits.
+        assert_eq!(
+            round_trip(&Value::Int(1))[EMPTY_METADATA.len()..],
+            [0x0C, 1]
+        );
+        assert_eq!(round_trip(&Value::Null)[EMPTY_METADATA.len()..], [0x00]);
+        assert_eq!(
+            round_trip(&Value::String("hi".into()))[EMPTY_METADATA.len()..],
+            [0x09, b'h', b'i']
+        );
+        assert_eq!(round_trip(&Value::Int(1))[..3], EMPTY_METADATA);
+    }
+
+    #[test]
+    fn test_variant_other_to_json() {
+        let mut out = String::new();
+        encode_other(primitive::DATE, &19_723i32.to_le_bytes()).write_json(&mut out);
+        assert_eq!(out, "\"2024-01-01\"");
+
+        out.clear();
+        let ts = 1_704_067_200_000_000i64 + 1_500_000;
+        encode_other(primitive::TIMESTAMP, &ts.to_le_bytes()).write_json(&mut out);
+        assert_eq!(out, "\"2024-01-01 00:00:01.500000+00:00\"");
+
+        out.clear();
+        let mut data = 3u32.to_le_bytes().to_vec();
+        data.extend_from_slice(b"abc");
+        encode_other(primitive::BINARY, &data).write_json(&mut out);
+        assert_eq!(out, "\"YWJj\"");
+    }
+
+    #[test]
+    fn test_variant_parse_path() {
+        use PathElement::*;
+        assert_eq!(parse_path("$"), Some(vec![]));
+        assert_eq!(
+            parse_path("$.a.b[2]"),
+            Some(vec![Field("a".into()), Field("b".into()), Index(2)])
+        );
+        assert_eq!(
+            parse_path("a['b.c'][0]"),
+            Some(vec![Field("a".into()), Field("b.c".into()), Index(0)])
+        );
+        assert_eq!(parse_path("a..b"), None);
+        assert_eq!(parse_path("a[x]"), None);
+    }
+}
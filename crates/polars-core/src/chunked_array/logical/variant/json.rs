@@ -0,0 +1,280 @@
+//! Parsing JSON text into variant values.
+use std::borrow::Cow;
+
+use super::encoding::{MAX_DEPTH, Value};
+
+/// The maximum number of significant digits of a decimal variant value.
+const MAX_DECIMAL_DIGITS: usize = 38;
+
+/// Parse a JSON document, returns `None` if it is not valid JSON.
+///
+/// Integers that fit an `i64` become integers, other numbers without an exponent become exact
+/// decimals if they have at most 38 digits and doubles otherwise. If an object has duplicate
+/// keys, the last one wins.
+pub(crate) fn parse_json(s: &str) -> Option<Value<'_>> {
+    let mut parser = Parser {
+        s,
+        bytes: s.as_bytes(),
+        pos: 0,
+    };
+    let value = parser.parse_value(0)?;
+    parser.skip_whitespace();
+    (parser.pos == parser.bytes.len()).then_some(value)
+}
+
+struct Parser<'a> {
+    s: &'a str,
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn skip_whitespace(&mut self) {
+        while let Some(b' ' | b'\t' | b'\n' | b'\r') = self.bytes.get(self.pos) {
+            self.pos += 1;
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    fn expect_literal(&mut self, literal: &str) -> Option<()> {
+        let end = self.pos + literal.len();
+        (self.bytes.get(self.pos..end)? == literal.as_bytes()).then(|| self.pos = end)
+    }
+
+    fn parse_value(&mut self, depth: usize) -> Option<Value<'a>> {
+        if depth > MAX_DEPTH {
+            return None;
+        }
+        self.skip_whitespace();
+        match self.peek()? {
+            b'n' => self.expect_literal("null").map(|_| Value::Null),
+            b't' => self.expect_literal("true").map(|_| Value::Boolean(true)),
+            b'f' => self.expect_literal("false").map(|_| Value::Boolean(false)),
+            b'"' => self.parse_string().map(Value::String),
+            b'[' => {
+                self.pos += 1;
+                let mut values = Vec::new();
+                self.skip_whitespace();
+                if self.peek()? == b']' {
+                    self.pos += 1;
+                    return Some(Value::Array(values));
+                }
+                loop {
+                    values.push(self.parse_value(depth + 1)?);
+                    self.skip_whitespace();
+                    match self.peek()? {
+                        b',' => self.pos += 1,
+                        b']' => {
+                            self.pos += 1;
+                            return Some(Value::Array(values));
+                        },
+                        _ => return None,
+                    }
+                }
+            },
+            b'{' => {
+                self.pos += 1;
+                let mut fields: Vec<(Cow<'a, str>, Value<'a>)> = Vec::new();
+                self.skip_whitespace();
+                if self.peek()? == b'}' {
+                    self.pos += 1;
+                    return Some(Value::Object(fields));
+                }
+                loop {
+                    self.skip_whitespace();
+                    if self.peek()? != b'"' {
+                        return None;
+                    }
+                    let key = self.parse_string()?;
+                    self.skip_whitespace();
+                    if self.peek()? != b':' {
+                        return None;
+                    }
+                    self.pos += 1;
+                    let value = self.parse_value(depth + 1)?;
+                    match fields.iter_mut().find(|(k, _)| *k == key) {
+                        Some(field) => field.1 = value,
+                        None => fields.push((key, value)),
+                    }
+                    self.skip_whitespace();
+                    match self.peek()? {
+                        b',' => self.pos += 1,
+                        b'}' => {
+                            self.pos += 1;
+                            return Some(Value::Object(fields));
+                        },
+                        _ => return None,
+                    }
+                }
+            },
+            b'-' | b'0'..=b'9' => self.parse_number(),
+            _ => None,
+        }
+    }
+
+    fn parse_hex4(&mut self) -> Option<u32> {
+        let hex = self.s.get(self.pos..self.pos + 4)?;
+        let v = u32::from_str_radix(hex, 16).ok()?;
+        self.pos += 4;
+        Some(v)
+    }
+
+    /// Parse a string, starting at its opening quote. The string is borrowed if it doesn't
+    /// contain escapes.
+    fn parse_string(&mut self) -> Option<Cow<'a, str>> {
+        self.pos += 1;
+        let start = self.pos;
+        loop {
+            match self.peek()? {
+                b'"' => {
+                    let s = &self.s[start..self.pos];
+                    self.pos += 1;
+                    return Some(Cow::Borrowed(s));
+                },
+                b'\\' => break,
+                b if b < 0x20 => return None,
+                _ => self.pos += 1,
+            }
+        }
+
+        let mut out = self.s[start..self.pos].to_string();
+        loop {
+            match self.peek()? {
+                b'"' => {
+                    self.pos += 1;
+                    return Some(Cow::Owned(out));
+                },
+                b'\\' => {
+                    self.pos += 1;
+                    let escaped = self.peek()?;
+                    self.pos += 1;
+                    match escaped {
+                        b'"' => out.push('"'),
+                        b'\\' => out.push('\\'),
+                        b'/' => out.push('/'),
+                        b'b' => out.push('\u{08}'),
+                        b'f' => out.push('\u{0c}'),
+                        b'n' => out.push('\n'),
+                        b'r' => out.push('\r'),
+                        b't' => out.push('\t'),
+                        b'u' => {
+                            let mut c = self.parse_hex4()?;
+                            if (0xD800..0xDC00).contains(&c) {
+                                // A surrogate pair.
+                                self.expect_literal("\\u")?;
+                                let low = self.parse_hex4()?;
+                                if !(0xDC00..0xE000).contains(&low) {
+                                    return None;
+                                }
+                                c = 0x10000 + ((c - 0xD800) << 10) + (low - 0xDC00);
+                            }
+                            out.push(char::from_u32(c)?);
+                        },
+                        _ => return None,
+                    }
+                },
+                b if b < 0x20 => return None,
+                _ => {
+                    // Copy up to the next quote or escape at once.
+                    let end = self.bytes[self.pos..]
+                        .iter()
+                        .position(|b| matches!(b, b'"' | b'\\') || *b < 0x20)
+                        .map_or(self.bytes.len(), |i| self.pos + i);
+                    out.push_str(&self.s[self.pos..end]);
+                    self.pos = end;
+                },
+            }
+        }
+    }
+
+    fn skip_digits(&mut self) -> usize {
+        let start = self.pos;
+        while let Some(b'0'..=b'9') = self.peek() {
+            self.pos += 1;
+        }
+        self.pos - start
+    }
+
+    fn parse_number(&mut self) -> Option<Value<'a>> {
+        let start = self.pos;
+        if self.peek() == Some(b'-') {
+            self.pos += 1;
+        }
+        let int_digits = self.skip_digits();
+        if int_digits == 0 || (int_digits > 1 && self.bytes[self.pos - int_digits] == b'0') {
+            return None;
+        }
+        let mut frac_digits = 0;
+        if self.peek() == Some(b'.') {
+            self.pos += 1;
+            frac_digits = self.skip_digits();
+            if frac_digits == 0 {
+                return None;
+            }
+        }
+        let mut has_exponent = false;
+        if let Some(b'e' | b'E') = self.peek() {
+            has_exponent = true;
+            self.pos += 1;
+            if let Some(b'+' | b'-') = self.peek() {
+                self.pos += 1;
+            }
+            if self.skip_digits() == 0 {
+                return None;
+            }
+        }
+
+        let text = &self.s[start..self.pos];
+        if !has_exponent {
+            if frac_digits == 0 {
+                if let Ok(v) = text.parse::<i64>() {
+                    return Some(Value::Int(v));
+                }
+            }
+            if int_digits + frac_digits <= MAX_DECIMAL_DIGITS {
+                let unscaled = text.replace('.', "").parse::<i128>().ok()?;
+                return Some(Value::Decimal(unscaled, frac_digits as u8));
+            }
+        }
+        text.parse::<f64>().ok().map(Value::Double)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_variant_parse_json() {
+        assert_eq!(parse_json(" 12 "), Some(Value::Int(12)));
+        assert_eq!(parse_json("-1.50"), Some(Value::Decimal(-150, 2)));
+        assert_eq!(parse_json("1e3"), Some(Value::Double(1000.0)));
+        assert_eq!(
+            parse_json("123456789012345678901"),
+            Some(Value::Decimal(123456789012345678901, 0))
+        );
+        assert_eq!(
+            parse_json(r#"["a\né😀", true, null]"#),
+            Some(Value::Array(vec![
+                Value::String("a\né😀".into()),
+                Value::Boolean(true),
+                Value::Null
+            ]))
+        );
+        assert_eq!(
+            parse_json(r#"{"a": 1, "b": {}, "a": 2}"#),
+            Some(Value::Object(vec![
+                ("a".into(), Value::Int(2)),
+                ("b".into(), Value::Object(vec![])),
+            ]))
+        );
+
+        for invalid in ["", "01", "1.", "[1,]", "{\"a\" 1}", "tru", "\"abc", "1 2"] {
+            assert_eq!(parse_json(invalid), None, "{invalid}");
+        }
+        assert_eq!(parse_json(&"[".repeat(1000)), None);
+    }
+}
@@ -0,0 +1,347 @@
+//! Semi-structured values of the [`DataType::Variant`] type.
+mod encoding;
+mod json;
+mod shred;
+
+use arrow::datatypes::ExtensionType;
+use encoding::{EMPTY_METADATA, Metadata, PathElement, Value, VariantRef, parse_path};
+use num_traits::ToPrimitive;
+pub use shred::*;
+
+use super::*;
+use crate::prelude::*;
+
+pub type VariantChunked = Logical<VariantType, BinaryType>;
+
+/// The name of the Arrow extension type for variants, as used by the Parquet Variant
+/// specification.
+pub const VARIANT_EXTENSION_NAME: &str = "arrow.parquet.variant";
+
+/// Format an encoded variant as JSON, returns `None` if it is not validly encoded.
+pub fn variant_to_json(bytes: &[u8]) -> Option<String> {
+    let value = VariantRef::from_bytes(bytes)?.decode()?;
+    let mut out = String::new();
+    value.write_json(&mut out);
+    Some(out)
+}
+
+/// Parse JSON text into an encoded variant, returns `None` if it is not valid JSON.
+pub fn json_to_variant(s: &str) -> Option<Vec<u8>> {
+    let mut out = Vec::new();
+    json::parse_json(s)?.encode(&mut out);
+    Some(out)
+}
+
+/// The string representation of an encoded variant. Strings are returned as is, all other
+/// values are formatted as JSON.
+pub(crate) fn variant_to_string(bytes: &[u8]) -> Option<String> {
+    match VariantRef::from_bytes(bytes)?.decode()? {
+        Value::String(s) => Some(s.into_owned()),
+        value => {
+            let mut out = String::new();
+            value.write_json(&mut out);
+            Some(out)
+        },
+    }
+}
+
+/// Encode a string as parsed JSON, or a boolean or number as a variant.
+pub(crate) fn any_value_to_variant(av: &AnyValue) -> Option<Vec<u8>> {
+    let value = match av {
+        AnyValue::String(s) => return json_to_variant(s),
+        AnyValue::StringOwned(s) => return json_to_variant(s),
+        AnyValue::Boolean(v) => Value::Boolean(*v),
+        av if av.is_float() => Value::Double(av.extract()?),
+        av if av.is_integer() => match av.extract() {
+            Some(v) => Value::Int(v),
+            None => Value::Decimal(av.extract()?, 0),
+        },
+        _ => return None,
+    };
+    let mut out = Vec::new();
+    value.encode(&mut out);
+    Some(out)
+}
+
+/// The arrow data type of variants that are not shredded.
+pub(crate) fn variant_arrow_dtype() -> ArrowDataType {
+    ArrowDataType::Extension(Box::new(ExtensionType {
+        name: PlSmallStr::from_static(VARIANT_EXTENSION_NAME),
+        inner: ArrowDataType::Struct(vec![
+            ArrowField::new(
+                PlSmallStr::from_static("metadata"),
+                ArrowDataType::BinaryView,
+                false,
+            ),
+            ArrowField::new(
+                PlSmallStr::from_static("value"),
+                ArrowDataType::BinaryView,
+                true,
+            ),
+        ]),
+        metadata: None,
+    }))
+}
+
+/// Split the encoded variants into the `metadata` and `value` fields of the arrow
+/// representation.
+pub(crate) fn binview_to_variant_array(arr: &BinaryViewArray) -> StructArray {
+    let mut metadata = MutableBinaryViewArray::<[u8]>::with_capacity(arr.len());
+    let mut value = MutableBinaryViewArray::<[u8]>::with_capacity(arr.len());
+    for opt_bytes in arr.iter() {
+        match opt_bytes.and_then(|b| Some((b, Metadata::try_new(b)?))) {
+            Some((bytes, md)) => {
+                let md = md.as_bytes();
+                metadata.push_value(md);
+                value.push_value(&bytes[md.len()..]);
+            },
+            None => {
+                metadata.push_value(EMPTY_METADATA);
+                value.push_null();
+            },
+        }
+    }
+    let metadata: BinaryViewArray = metadata.into();
+    let value: BinaryViewArray = value.into();
+    StructArray::new(
+        variant_arrow_dtype(),
+        arr.len(),
+        vec![metadata.boxed(), value.boxed()],
+        arr.validity().cloned(),
+    )
+}
+
+/// Collect values into a [`VariantChunked`].
+fn collect_variant<'a, I: Iterator<Item = Option<Value<'a>>>>(
+    name: PlSmallStr,
+    len: usize,
+    iter: I,
+) -> VariantChunked {
+    let mut builder = BinaryChunkedBuilder::new(name, len);
+    let mut buf = Vec::new();
+    for opt_value in iter {
+        match opt_value {
+            Some(value) => {
+                buf.clear();
+                value.encode(&mut buf);
+                builder.append_value(&buf);
+            },
+            None => builder.append_null(),
+        }
+    }
+    builder.finish().into_variant_unchecked()
+}
+
+impl BinaryChunked {
+    /// Interpret the bytes as encoded variants. The values must be canonically encoded, as done
+    /// by the constructors of [`VariantChunked`].
+    #[inline]
+    pub fn into_variant_unchecked(self) -> VariantChunked {
+        VariantChunked::new_logical(self)
+    }
+}
+
+impl StringChunked {
+    /// Parse the strings as JSON. Strings that are not valid JSON become null.
+    pub(crate) fn to_variant(&self) -> VariantChunked {
+        collect_variant(
+            self.name().clone(),
+            self.len(),
+            self.iter().map(|opt_s| opt_s.and_then(json::parse_json)),
+        )
+    }
+}
+
+impl BooleanChunked {
+    pub(crate) fn to_variant(&self) -> VariantChunked {
+        collect_variant(
+            self.name().clone(),
+            self.len(),
+            self.iter().map(|opt_v| opt_v.map(Value::Boolean)),
+        )
+    }
+}
+
+impl<T: PolarsNumericType> ChunkedArray<T> {
+    /// Convert the numbers to variants. Integers that don't fit an `i64` become decimals.
+    pub(crate) fn to_variant(&self) -> VariantChunked {
+        let is_float = self.dtype().is_float();
+        let to_value = |v: T::Native| {
+            if is_float {
+                v.to_f64().map(Value::Double)
+            } else if let Some(v) = v.to_i64() {
+                Some(Value::Int(v))
+            } else {
+                v.to_i128().map(|v| Value::Decimal(v, 0))
+            }
+        };
+        collect_variant(
+            self.name().clone(),
+            self.len(),
+            self.iter().map(|opt_v| opt_v.and_then(to_value)),
+        )
+    }
+}
+
+impl VariantChunked {
+    /// Parse the strings as JSON. Strings that are not valid JSON become null.
+    pub fn from_json(ca: &StringChunked) -> Self {
+        ca.to_variant()
+    }
+
+    /// Iterate over the decoded values.
+    fn iter_values(&self) -> impl Iterator<Item = Option<Value<'_>>> + '_ {
+        self.0
+            .iter()
+            .map(|opt_b| opt_b.and_then(|b| VariantRef::from_bytes(b)?.decode()))
+    }
+
+    /// Format the values as JSON.
+    pub fn to_json(&self) -> StringChunked {
+        let mut buf = String::new();
+        let out: StringChunked = self
+            .iter_values()
+            .map(|opt_v| {
+                opt_v.map(|v| {
+                    buf.clear();
+                    v.write_json(&mut buf);
+                    buf.clone()
+                })
+            })
+            .collect();
+        out.with_name(self.name().clone())
+    }
+
+    /// Extract the values at `path`, e.g. `$.a.b[0]`. Values that don't have the path become
+    /// null.
+    pub fn get_path(&self, path: &str) -> PolarsResult<VariantChunked> {
+        let Some(path) = parse_path(path) else {
+            polars_bail!(InvalidOperation: "invalid variant path: '{}'", path)
+        };
+        let iter = self.0.iter().map(|opt_b| {
+            let mut v = VariantRef::from_bytes(opt_b?)?;
+            for element in &path {
+                v = match element {
+                    PathElement::Field(name) => v.field(name)?,
+                    PathElement::Index(i) => v.index(*i)?,
+                };
+            }
+            v.decode()
+        });
+        Ok(collect_variant(self.name().clone(), self.len(), iter))
+    }
+
+    fn to_string_chunked(&self) -> StringChunked {
+        let out: StringChunked = self
+            .iter_values()
+            .map(|opt_v| {
+                opt_v.map(|v| match v {
+                    Value::String(s) => s.into_owned(),
+                    v => {
+                        let mut buf = String::new();
+                        v.write_json(&mut buf);
+                        buf
+                    },
+                })
+            })
+            .collect();
+        out.with_name(self.name().clone())
+    }
+
+    fn to_boolean(&self) -> BooleanChunked {
+        let out: BooleanChunked = self.iter_values().map(|opt_v| opt_v?.as_bool()).collect();
+        out.with_name(self.name().clone())
+    }
+
+    fn to_int64(&self) -> Int64Chunked {
+        let out: Int64Chunked = self.iter_values().map(|opt_v| opt_v?.as_i64()).collect();
+        out.with_name(self.name().clone())
+    }
+
+    fn to_float64(&self) -> Float64Chunked {
+        let out: Float64Chunked = self.iter_values().map(|opt_v| opt_v?.as_f64()).collect();
+        out.with_name(self.name().clone())
+    }
+}
+
+impl LogicalType for VariantChunked {
+    fn dtype(&self) -> &DataType {
+        &DataType::Variant
+    }
+
+    fn get_any_value(&self, i: usize) -> PolarsResult<AnyValue<'_>> {
+        polars_ensure!(i < self.len(), oob = i, self.len());
+        Ok(unsafe { self.get_any_value_unchecked(i) })
+    }
+
+    unsafe fn get_any_value_unchecked(&self, i: usize) -> AnyValue<'_> {
+        match self.0.get_unchecked(i) {
+            Some(v) => AnyValue::Variant(v),
+            None => AnyValue::Null,
+        }
+    }
+
+    fn cast_with_options(
+        &self,
+        dtype: &DataType,
+        cast_options: CastOptions,
+    ) -> PolarsResult<Series> {
+        let out = match dtype {
+            DataType::Variant => return Ok(self.clone().into_series()),
+            DataType::String => return Ok(self.to_string_chunked().into_series()),
+            DataType::Binary => return Ok(self.0.clone().into_series()),
+            DataType::Boolean => self.to_boolean().into_series(),
+            dt if dt.is_integer() => self.to_int64().cast_with_options(dtype, cast_options)?,
+            dt if dt.is_float() => self.to_float64().cast_with_options(dtype, cast_options)?,
+            dt => {
+                polars_bail!(
+                    InvalidOperation:
+                    "casting from {:?} to {:?} not supported",
+                    self.dtype(), dt
+                )
+            },
+        };
+        // Variant nulls become nulls, any other value that doesn't convert is a failure.
+        if cast_options.strict() {
+            let failures = self
+                .iter_values()
+                .zip(out.is_null().iter())
+                .filter(|(v, is_null)| {
+                    is_null.unwrap_or(false) && v.as_ref().is_some_and(|v| *v != Value::Null)
+                })
+                .count();
+            polars_ensure!(
+                failures == 0,
+                InvalidOperation: "conversion from `variant` to `{}` failed for {} value(s)",
+                dtype, failures
+            );
+        }
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_variant_json_round_trip() {
+        let inputs = [
+            r#"{"b":[1,2.5,"x"],"a":{"c":null,"d":true}}"#,
+            "-12345678901234567890.25",
+            "1.0E300",
+            r#""esc\"aped\n""#,
+        ];
+        let expected = [
+            r#"{"a":{"c":null,"d":true},"b":[1,2.5,"x"]}"#,
+            "-12345678901234567890.25",
+            "1e300",
+            r#""esc\"aped\n""#,
+        ];
+        for (input, expected) in inputs.iter().zip(expected) {
+            let bytes = json_to_variant(input).unwrap();
+            assert_eq!(variant_to_json(&bytes).unwrap(), expected);
+        }
+        assert_eq!(json_to_variant("{"), None);
+    }
+}
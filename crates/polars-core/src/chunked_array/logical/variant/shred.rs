@@ -0,0 +1,520 @@
+//! Shredding of variants into typed columns, as described by the Parquet Variant Shredding
+//! specification.
+//!
+//! A shredded variant is a struct of the row `metadata`, a binary `value` and a `typed_value`.
+//! Values that match the shredded type are stored in `typed_value` and leave `value` null, all
+//! other values are stored in `value`. Objects are shredded into a struct with a field for every
+//! shredded key, the fields that are not shredded remain in `value`.
+use std::borrow::Cow;
+
+use arrow::bitmap::MutableBitmap;
+use arrow::datatypes::ExtensionType;
+use arrow::offset::Offset;
+use polars_utils::aliases::PlHashMap;
+
+use super::encoding::{EMPTY_METADATA, Metadata, Value, VariantRef, encode_other, primitive};
+use super::{VARIANT_EXTENSION_NAME, VariantChunked};
+use crate::prelude::*;
+
+/// The number of values sampled to infer a [`ShreddedType`].
+const INFER_SAMPLE_SIZE: usize = 1000;
+/// The maximum depth of nested objects that are shredded.
+const MAX_SHRED_DEPTH: usize = 4;
+
+/// The type that variant values are shredded into.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ShreddedType {
+    Boolean,
+    Int64,
+    Float64,
+    String,
+    /// An object with the given shredded fields, sorted by name.
+    Object(Vec<(PlSmallStr, ShreddedType)>),
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum Kind {
+    Boolean,
+    Int64,
+    Float64,
+    String,
+}
+
+impl ShreddedType {
+    /// Infer the type to shred into from a sample of the values, returns `None` if the values
+    /// don't have a common shape.
+    ///
+    /// Objects are shredded if most values are objects, and only the keys that are present in at
+    /// least half of those objects are shredded.
+    pub fn infer(ca: &VariantChunked) -> Option<Self> {
+        let values =
+            ca.0.iter()
+                .flatten()
+                .filter_map(|b| VariantRef::from_bytes(b)?.decode())
+                .take(INFER_SAMPLE_SIZE)
+                .collect::<Vec<_>>();
+        let values = values.iter().collect::<Vec<_>>();
+        Self::infer_values(&values, 0)
+    }
+
+    fn infer_values(values: &[&Value], depth: usize) -> Option<Self> {
+        if values.is_empty() {
+            return None;
+        }
+        let objects = values
+            .iter()
+            .filter_map(|v| match v {
+                Value::Object(fields) => Some(fields),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        if objects.len() * 2 > values.len() {
+            if depth >= MAX_SHRED_DEPTH {
+                return None;
+            }
+            let mut field_values = PlHashMap::<&str, Vec<&Value>>::default();
+            for fields in &objects {
+                for (key, value) in fields.iter() {
+                    field_values.entry(key.as_ref()).or_default().push(value);
+                }
+            }
+            let mut fields = field_values
+                .into_iter()
+                .filter(|(_, values)| values.len() * 2 >= objects.len())
+                .filter_map(|(key, values)| {
+                    Some((key.into(), Self::infer_values(&values, depth + 1)?))
+                })
+                .collect::<Vec<(PlSmallStr, _)>>();
+            if fields.is_empty() {
+                return None;
+            }
+            fields.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+            return Some(Self::Object(fields));
+        }
+
+        let mut counts = PlHashMap::<Kind, usize>::default();
+        for value in values {
+            let kind = match value {
+                Value::Boolean(_) => Kind::Boolean,
+                Value::Int(_) => Kind::Int64,
+                Value::Double(_) => Kind::Float64,
+                Value::String(_) => Kind::String,
+                _ => continue,
+            };
+            *counts.entry(kind).or_default() += 1;
+        }
+        let (kind, count) = counts.into_iter().max_by_key(|(_, count)| *count)?;
+        (count * 2 >= values.len()).then_some(match kind {
+            Kind::Boolean => Self::Boolean,
+            Kind::Int64 => Self::Int64,
+            Kind::Float64 => Self::Float64,
+            Kind::String => Self::String,
+        })
+    }
+
+    fn typed_arrow_dtype(&self) -> ArrowDataType {
+        match self {
+            Self::Boolean => ArrowDataType::Boolean,
+            Self::Int64 => ArrowDataType::Int64,
+            Self::Float64 => ArrowDataType::Float64,
+            Self::String => ArrowDataType::Utf8View,
+            Self::Object(fields) => ArrowDataType::Struct(
+                fields
+                    .iter()
+                    .map(|(name, dtype)| {
+                        ArrowField::new(name.clone(), dtype.field_arrow_dtype(), false)
+                    })
+                    .collect(),
+            ),
+        }
+    }
+
+    fn field_arrow_dtype(&self) -> ArrowDataType {
+        ArrowDataType::Struct(vec![
+            ArrowField::new(
+                PlSmallStr::from_static("value"),
+                ArrowDataType::BinaryView,
+                true,
+            ),
+            ArrowField::new(
+                PlSmallStr::from_static("typed_value"),
+                self.typed_arrow_dtype(),
+                true,
+            ),
+        ])
+    }
+
+    /// The arrow data type of variants shredded into this type.
+    pub fn to_arrow(&self) -> ArrowDataType {
+        ArrowDataType::Extension(Box::new(ExtensionType {
+            name: PlSmallStr::from_static(VARIANT_EXTENSION_NAME),
+            inner: ArrowDataType::Struct(vec![
+                ArrowField::new(
+                    PlSmallStr::from_static("metadata"),
+                    ArrowDataType::BinaryView,
+                    false,
+                ),
+                ArrowField::new(
+                    PlSmallStr::from_static("value"),
+                    ArrowDataType::BinaryView,
+                    true,
+                ),
+                ArrowField::new(
+                    PlSmallStr::from_static("typed_value"),
+                    self.typed_arrow_dtype(),
+                    true,
+                ),
+            ]),
+            metadata: None,
+        }))
+    }
+}
+
+/// Builds the `typed_value` of a shredded type.
+enum TypedBuilder {
+    Boolean(MutableBooleanArray),
+    Int64(MutablePrimitiveArray<i64>),
+    Float64(MutablePrimitiveArray<f64>),
+    String(MutableBinaryViewArray<str>),
+    Object {
+        fields: Vec<(PlSmallStr, FieldBuilder)>,
+        validity: MutableBitmap,
+        dtype: ArrowDataType,
+    },
+}
+
+/// Builds the `value` and `typed_value` of a shredded type.
+struct FieldBuilder {
+    value: MutableBinaryViewArray<[u8]>,
+    typed: TypedBuilder,
+}
+
+impl TypedBuilder {
+    fn new(dtype: &ShreddedType) -> Self {
+        match dtype {
+            ShreddedType::Boolean => Self::Boolean(MutableBooleanArray::new()),
+            ShreddedType::Int64 => Self::Int64(MutablePrimitiveArray::new()),
+            ShreddedType::Float64 => Self::Float64(MutablePrimitiveArray::new()),
+            ShreddedType::String => Self::String(MutableBinaryViewArray::new()),
+            ShreddedType::Object(fields) => Self::Object {
+                fields: fields
+                    .iter()
+                    .map(|(name, dtype)| (name.clone(), FieldBuilder::new(dtype)))
+                    .collect(),
+                validity: MutableBitmap::new(),
+                dtype: dtype.typed_arrow_dtype(),
+            },
+        }
+    }
+
+    fn push_null(&mut self) {
+        match self {
+            Self::Boolean(arr) => arr.push_null(),
+            Self::Int64(arr) => arr.push_null(),
+            Self::Float64(arr) => arr.push_null(),
+            Self::String(arr) => arr.push_null(),
+            Self::Object {
+                fields, validity, ..
+            } => {
+                for (_, field) in fields {
+                    field.push(None, &[]);
+                }
+                validity.push(false);
+            },
+        }
+    }
+
+    /// Push the value if it matches the shredded type. Returns `None` if it doesn't match,
+    /// otherwise the residual value that must be stored in `value`, if any.
+    fn try_push<'a>(&mut self, value: &Value<'a>, keys: &[&str]) -> Option<Option<Value<'a>>> {
+        match (self, value) {
+            (Self::Boolean(arr), Value::Boolean(v)) => arr.push_value(*v),
+            (Self::Int64(arr), Value::Int(v)) => arr.push_value(*v),
+            (Self::Float64(arr), Value::Double(v)) => arr.push_value(*v),
+            (Self::String(arr), Value::String(v)) => arr.push_value(v.as_ref()),
+            (
+                Self::Object {
+                    fields: builders,
+                    validity,
+                    ..
+                },
+                Value::Object(fields),
+            ) => {
+                for (name, builder) in builders.iter_mut() {
+                    let field = fields.iter().find(|(key, _)| key == name.as_str());
+                    builder.push(field.map(|(_, v)| v), keys);
+                }
+                validity.push(true);
+                let residual = fields
+                    .iter()
+                    .filter(|(key, _)| {
+                        builders
+                            .binary_search_by(|(name, _)| name.as_str().cmp(key.as_ref()))
+                            .is_err()
+                    })
+                    .cloned()
+                    .collect::<Vec<_>>();
+                return Some((!residual.is_empty()).then_some(Value::Object(residual)));
+            },
+            _ => return None,
+        }
+        Some(None)
+    }
+
+    fn finish(self) -> Box<dyn Array> {
+        match self {
+            Self::Boolean(mut arr) => arr.as_box(),
+            Self::Int64(mut arr) => arr.as_box(),
+            Self::Float64(mut arr) => arr.as_box(),
+            Self::String(mut arr) => arr.as_box(),
+            Self::Object {
+                fields,
+                validity,
+                dtype,
+            } => {
+                let len = validity.len();
+                let values = fields
+                    .into_iter()
+                    .map(|(_, field)| field.finish().boxed())
+                    .collect();
+                StructArray::new(dtype, len, values, validity.into()).boxed()
+            },
+        }
+    }
+}
+
+impl FieldBuilder {
+    fn new(dtype: &ShreddedType) -> Self {
+        Self {
+            value: MutableBinaryViewArray::new(),
+            typed: TypedBuilder::new(dtype),
+        }
+    }
+
+    /// Push a value, `None` means that the value is missing.
+    fn push(&mut self, value: Option<&Value>, keys: &[&str]) {
+        let Some(value) = value else {
+            self.value.push_null();
+            self.typed.push_null();
+            return;
+        };
+        let residual = match self.typed.try_push(value, keys) {
+            Some(residual) => residual,
+            None => {
+                self.typed.push_null();
+                Some(value.clone())
+            },
+        };
+        match residual {
+            Some(residual) => {
+                let mut buf = Vec::new();
+                residual.encode_value(keys, &mut buf);
+                self.value.push_value(buf);
+            },
+            None => self.value.push_null(),
+        }
+    }
+
+    fn finish(self) -> StructArray {
+        let Self { value, typed } = self;
+        let value: BinaryViewArray = value.into();
+        let typed = typed.finish();
+        let dtype = ArrowDataType::Struct(vec![
+            ArrowField::new(
+                PlSmallStr::from_static("value"),
+                ArrowDataType::BinaryView,
+                true,
+            ),
+            ArrowField::new(
+                PlSmallStr::from_static("typed_value"),
+                typed.dtype().clone(),
+                true,
+            ),
+        ]);
+        StructArray::new(dtype, value.len(), vec![value.boxed(), typed], None)
+    }
+}
+
+/// Shred the encoded variants into `dtype`, see [`ShreddedType::to_arrow`] for the layout of
+/// the result.
+pub fn shred_variant_array(arr: &BinaryViewArray, dtype: &ShreddedType) -> StructArray {
+    let mut metadata = MutableBinaryViewArray::<[u8]>::with_capacity(arr.len());
+    let mut builder = FieldBuilder::new(dtype);
+    for opt_bytes in arr.iter() {
+        let variant = opt_bytes.and_then(VariantRef::from_bytes);
+        match variant.and_then(|v| Some((v.metadata(), v.decode()?))) {
+            Some((md, value)) => {
+                metadata.push_value(md.as_bytes());
+                // Re-encode against the dictionary of the row, which holds all keys.
+                let keys = md.keys().unwrap_or_default();
+                builder.push(Some(&value), &keys);
+            },
+            None => {
+                metadata.push_value(EMPTY_METADATA);
+                builder.push(None, &[]);
+            },
+        }
+    }
+    let metadata: BinaryViewArray = metadata.into();
+    let (_, _, mut values, _) = builder.finish().into_data();
+    values.insert(0, metadata.boxed());
+    StructArray::new(dtype.to_arrow(), arr.len(), values, arr.validity().cloned())
+}
+
+/// Decode the value of row `i` from a `value` and an optional `typed_value` array. Returns
+/// `None` if the value is missing.
+fn unshred_value<'a>(
+    metadata: Metadata<'a>,
+    value: Option<&'a BinaryViewArray>,
+    typed: Option<&'a dyn Array>,
+    i: usize,
+) -> PolarsResult<Option<Value<'a>>> {
+    let residual = match value.filter(|arr| arr.is_valid(i)) {
+        Some(arr) => Some(
+            VariantRef::new(metadata, arr.value(i))
+                .decode()
+                .ok_or_else(|| polars_err!(ComputeError: "invalid variant value"))?,
+        ),
+        None => None,
+    };
+    let Some(typed) = typed.filter(|arr| arr.is_valid(i)) else {
+        return Ok(residual);
+    };
+
+    macro_rules! primitive {
+        ($ty:ty) => {
+            typed
+                .as_any()
+                .downcast_ref::<PrimitiveArray<$ty>>()
+                .unwrap()
+                .value(i)
+        };
+    }
+    let value = match typed.dtype().to_logical_type() {
+        ArrowDataType::Boolean => Value::Boolean(
+            typed
+                .as_any()
+                .downcast_ref::<BooleanArray>()
+                .unwrap()
+                .value(i),
+        ),
+        ArrowDataType::Int8 => Value::Int(primitive!(i8) as i64),
+        ArrowDataType::Int16 => Value::Int(primitive!(i16) as i64),
+        ArrowDataType::Int32 => Value::Int(primitive!(i32) as i64),
+        ArrowDataType::Int64 => Value::Int(primitive!(i64)),
+        ArrowDataType::Float32 => encode_other(primitive::FLOAT, &primitive!(f32).to_le_bytes()),
+        ArrowDataType::Float64 => Value::Double(primitive!(f64)),
+        ArrowDataType::Decimal(_, scale) => Value::Decimal(primitive!(i128), *scale as u8),
+        ArrowDataType::Date32 => encode_other(primitive::DATE, &primitive!(i32).to_le_bytes()),
+        ArrowDataType::Utf8View => Value::String(Cow::Borrowed(
+            typed
+                .as_any()
+                .downcast_ref::<Utf8ViewArray>()
+                .unwrap()
+                .value(i),
+        )),
+        ArrowDataType::LargeUtf8 => Value::String(Cow::Borrowed(
+            typed
+                .as_any()
+                .downcast_ref::<Utf8Array<i64>>()
+                .unwrap()
+                .value(i),
+        )),
+        ArrowDataType::Struct(fields) => {
+            let arr = typed.as_any().downcast_ref::<StructArray>().unwrap();
+            let mut out = Vec::with_capacity(fields.len());
+            for (field, values) in fields.iter().zip(arr.values()) {
+                let (value, typed) = shredded_children(values.as_ref())?;
+                if let Some(v) = unshred_value(metadata, value, typed, i)? {
+                    out.push((Cow::Borrowed(field.name.as_str()), v));
+                }
+            }
+            // Merge the fields that are not shredded.
+            match residual {
+                Some(Value::Object(fields)) => out.extend(fields),
+                Some(_) => {
+                    polars_bail!(ComputeError: "shredded variant object has a non-object value")
+                },
+                None => {},
+            }
+            out.sort_by(|a, b| a.0.cmp(&b.0));
+            out.dedup_by(|a, b| a.0 == b.0);
+            return Ok(Some(Value::Object(out)));
+        },
+        ArrowDataType::LargeList(_) => unshred_list::<i64>(metadata, typed, i)?,
+        ArrowDataType::List(_) => unshred_list::<i32>(metadata, typed, i)?,
+        dt => polars_bail!(ComputeError: "unsupported shredded variant type: {:?}", dt),
+    };
+    Ok(Some(value))
+}
+
+fn unshred_list<'a, O: Offset>(
+    metadata: Metadata<'a>,
+    typed: &'a dyn Array,
+    i: usize,
+) -> PolarsResult<Value<'a>> {
+    let arr = typed.as_any().downcast_ref::<ListArray<O>>().unwrap();
+    let (start, end) = arr.offsets().start_end(i);
+    let (value, typed) = shredded_children(arr.values().as_ref())?;
+    let values = (start..end)
+        .map(|j| Ok(unshred_value(metadata, value, typed, j)?.unwrap_or(Value::Null)))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    Ok(Value::Array(values))
+}
+
+/// Get the `value` and `typed_value` children of a shredded struct.
+fn shredded_children(
+    arr: &dyn Array,
+) -> PolarsResult<(Option<&BinaryViewArray>, Option<&dyn Array>)> {
+    let Some(arr) = arr.as_any().downcast_ref::<StructArray>() else {
+        polars_bail!(ComputeError: "expected a struct in a shredded variant, got {:?}", arr.dtype())
+    };
+    let mut value = None;
+    let mut typed = None;
+    for (field, child) in arr.fields().iter().zip(arr.values()) {
+        match field.name.as_str() {
+            "value" => {
+                value = Some(
+                    child
+                        .as_any()
+                        .downcast_ref::<BinaryViewArray>()
+                        .ok_or_else(|| polars_err!(ComputeError: "expected a binary variant value, got {:?}", child.dtype()))?,
+                )
+            },
+            "typed_value" => typed = Some(child.as_ref()),
+            _ => {},
+        }
+    }
+    Ok((value, typed))
+}
+
+/// Decode the arrow representation of variants, shredded or not, into canonically encoded
+/// variants.
+///
+/// The `metadata` and `value` fields must be `BinaryView` arrays.
+pub fn unshred_variant_array(arr: &dyn Array) -> PolarsResult<BinaryViewArray> {
+    let (value, typed) = shredded_children(arr)?;
+    let arr = arr.as_any().downcast_ref::<StructArray>().unwrap();
+    let metadata = arr
+        .fields()
+        .iter()
+        .position(|f| f.name.as_str() == "metadata")
+        .and_then(|idx| arr.values()[idx].as_any().downcast_ref::<BinaryViewArray>())
+        .ok_or_else(|| polars_err!(ComputeError: "variant is missing its binary 'metadata'"))?;
+
+    let mut out = MutableBinaryViewArray::<[u8]>::with_capacity(arr.len());
+    let mut buf = Vec::new();
+    for i in 0..arr.len() {
+        if !arr.is_valid(i) {
+            out.push_null();
+            continue;
+        }
+        let md = Metadata::try_new(metadata.value(i))
+            .ok_or_else(|| polars_err!(ComputeError: "invalid variant metadata"))?;
+        let value = unshred_value(md, value, typed, i)?.unwrap_or(Value::Null);
+        buf.clear();
+        value.encode(&mut buf);
+        out.push_value(&buf);
+    }
+    Ok(out.into())
+}
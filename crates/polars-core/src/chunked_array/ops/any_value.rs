@@ -128,6 +128,39 @@ pub(crate) unsafe fn arr_to_any_value<'a>(
             let v = arr.value_unchecked(idx);
             AnyValue::Decimal(v, scale.unwrap_or_else(|| unreachable!()))
         },
+        #[cfg(feature = "dtype-decimal256")]
+        DataType::Decimal256(_, scale) => {
+            let arr = &*(arr as *const dyn Array as *const BinaryViewArray);
+            let v = arr.value_unchecked(idx);
+            AnyValue::Decimal256(
+                i256(ordered_bytes_to_i256(v)),
+                scale.unwrap_or_else(|| unreachable!()),
+            )
+        },
+        #[cfg(feature = "dtype-variant")]
+        DataType::Variant => {
+            let arr = &*(arr as *const dyn Array as *const BinaryViewArray);
+            AnyValue::Variant(arr.value_unchecked(idx))
+        },
+        #[cfg(feature = "dtype-interval")]
+        DataType::Interval => {
+            let arr = &*(arr as *const dyn Array as *const Int128Array);
+            let v = arr.value_unchecked(idx);
+            AnyValue::Interval(i128_to_interval(v))
+        },
+        #[cfg(feature = "dtype-f16")]
+        DataType::Float16 => {
+            let arr = &*(arr as *const dyn Array as *const UInt16Array);
+            let v = arr.value_unchecked(idx);
+            AnyValue::Float16(f16::from_bits(v))
+        },
+        #[cfg(feature = "dtype-uuid")]
+        DataType::Uuid => {
+            let arr = &*(arr as *const dyn Array as *const FixedSizeListArray);
+            uuid_array_get_any_value(arr, idx)
+        },
+        #[cfg(feature = "dtype-extension")]
+        DataType::Extension(ext) => arr_to_any_value(arr, idx, ext.storage_dtype()),
         #[cfg(feature = "object")]
         DataType::Object(_) => {
             // We should almost never hit this. The only known exception is when we put objects in
@@ -226,3 +226,28 @@ impl<'a> IntoTotalOrdInner<'a> for &'a CategoricalChunked {
         }
     }
 }
+
+#[cfg(feature = "dtype-f16")]
+struct HalfFloat<'a>(&'a UInt16Chunked);
+
+#[cfg(feature = "dtype-f16")]
+impl GetInner for HalfFloat<'_> {
+    type Item = Option<f16>;
+    unsafe fn get_unchecked(&self, idx: usize) -> Self::Item {
+        self.0.get_unchecked(idx).map(f16::from_bits)
+    }
+}
+
+#[cfg(feature = "dtype-f16")]
+impl<'a> IntoTotalEqInner<'a> for &'a Float16Chunked {
+    fn into_total_eq_inner(self) -> Box<dyn TotalEqInner + 'a> {
+        Box::new(HalfFloat(self.physical()))
+    }
+}
+
+#[cfg(feature = "dtype-f16")]
+impl<'a> IntoTotalOrdInner<'a> for &'a Float16Chunked {
+    fn into_total_ord_inner(self) -> Box<dyn TotalOrdInner + 'a> {
+        Box::new(HalfFloat(self.physical()))
+    }
+}
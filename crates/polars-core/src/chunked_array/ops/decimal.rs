@@ -1,6 +1,735 @@
+use std::cmp::Ordering;
+
+use ethnum::{I256, U256};
+
+use crate::chunked_array::arithmetic::_get_decimal_scale_div;
 use crate::chunked_array::cast::CastOptions;
 use crate::prelude::*;
 
+/// The maximum number of digits a 128-bit decimal can hold.
+pub const DEC128_MAX_PREC: usize = 38;
+
+/// The maximum number of digits a 256-bit decimal can hold.
+pub const DEC256_MAX_PREC: usize = 2 * DEC128_MAX_PREC;
+
+/// Powers of ten up to 10^76, the largest that fits in an [`I256`].
+fn pow10(n: usize) -> I256 {
+    debug_assert!(n <= 2 * DEC128_MAX_PREC);
+    I256::new(10).pow(n as u32)
+}
+
+/// Narrow an intermediate result to a 128-bit decimal mantissa, returning `None` if it
+/// needs more than [`DEC128_MAX_PREC`] digits.
+#[inline]
+pub fn dec128_fit(v: I256) -> Option<i128> {
+    if v.unsigned_abs() < pow10(DEC128_MAX_PREC).unsigned_abs() {
+        Some(v.as_i128())
+    } else {
+        None
+    }
+}
+
+impl RoundMode {
+    /// Divide `num` by `den` and round the quotient according to this mode.
+    ///
+    /// `den` must not be zero.
+    pub fn div_i256(self, num: I256, den: I256) -> I256 {
+        let q = num / den;
+        let r = num % den;
+        if r == I256::ZERO {
+            return q;
+        }
+        let negative = num.is_negative() != den.is_negative();
+        let away = if negative { q - 1 } else { q + 1 };
+        let half = || (r.unsigned_abs() << 1u32).cmp(&den.unsigned_abs());
+        match self {
+            Self::TowardZero => q,
+            Self::AwayFromZero => away,
+            Self::Floor => {
+                if negative {
+                    away
+                } else {
+                    q
+                }
+            },
+            Self::Ceiling => {
+                if negative {
+                    q
+                } else {
+                    away
+                }
+            },
+            Self::HalfAwayFromZero => match half() {
+                std::cmp::Ordering::Less => q,
+                _ => away,
+            },
+            Self::HalfToEven => match half() {
+                std::cmp::Ordering::Less => q,
+                std::cmp::Ordering::Equal if q % 2 == I256::ZERO => q,
+                _ => away,
+            },
+        }
+    }
+}
+
+/// Change the scale of `v`, rounding with `mode` if digits are dropped. Returns `None` on
+/// overflow.
+fn rescale_i256(v: I256, from: usize, to: usize, mode: RoundMode) -> Option<I256> {
+    if to >= from {
+        let diff = to - from;
+        if v == I256::ZERO {
+            Some(v)
+        } else if diff > 2 * DEC128_MAX_PREC {
+            None
+        } else {
+            v.checked_mul(pow10(diff))
+        }
+    } else {
+        let diff = from - to;
+        if diff > 2 * DEC128_MAX_PREC {
+            // Every representable value is smaller than 10^-diff, only the sign matters.
+            Some(mode.div_i256(v.signum(), pow10(2 * DEC128_MAX_PREC)))
+        } else {
+            Some(mode.div_i256(v, pow10(diff)))
+        }
+    }
+}
+
+/// Change the scale of a decimal mantissa, returning `None` on overflow.
+pub fn dec128_rescale(v: i128, from: usize, to: usize, mode: RoundMode) -> Option<i128> {
+    rescale_i256(I256::new(v), from, to, mode).and_then(dec128_fit)
+}
+
+/// `l + r` at output scale `max(ls, rs)`, returning `None` on overflow.
+pub fn dec128_add(l: i128, ls: usize, r: i128, rs: usize) -> Option<i128> {
+    let scale = ls.max(rs);
+    let l = rescale_i256(I256::new(l), ls, scale, RoundMode::TowardZero)?;
+    let r = rescale_i256(I256::new(r), rs, scale, RoundMode::TowardZero)?;
+    dec128_fit(l + r)
+}
+
+/// `l - r` at output scale `max(ls, rs)`, returning `None` on overflow.
+pub fn dec128_sub(l: i128, ls: usize, r: i128, rs: usize) -> Option<i128> {
+    dec128_add(l, ls, r.checked_neg()?, rs)
+}
+
+/// `l * r` at output scale `scale`, returning `None` on overflow.
+pub fn dec128_mul(
+    l: i128,
+    ls: usize,
+    r: i128,
+    rs: usize,
+    scale: usize,
+    mode: RoundMode,
+) -> Option<i128> {
+    // Both operands have at most 38 digits so the product can't overflow 256 bits.
+    let prod = I256::new(l) * I256::new(r);
+    rescale_i256(prod, ls + rs, scale, mode).and_then(dec128_fit)
+}
+
+/// `l / r` at output scale `scale`, returning `None` on overflow.
+///
+/// `r` must not be zero.
+pub fn dec128_div(
+    l: i128,
+    ls: usize,
+    r: i128,
+    rs: usize,
+    scale: usize,
+    mode: RoundMode,
+) -> Option<i128> {
+    debug_assert!(r != 0);
+    // l / 10^ls / (r / 10^rs) * 10^scale == l * 10^(scale + rs - ls) / r
+    let (num, den) = if scale + rs >= ls {
+        // If the numerator doesn't fit 256 bits the quotient can't fit 38 digits either.
+        let num = rescale_i256(I256::new(l), ls, scale + rs, mode)?;
+        (num, I256::new(r))
+    } else {
+        (I256::new(l), I256::new(r) * pow10(ls - scale - rs))
+    };
+    dec128_fit(mode.div_i256(num, den))
+}
+
+/// `l % r` at output scale `max(ls, rs)`, the result has the sign of `l`.
+///
+/// `r` must not be zero.
+pub fn dec128_rem(l: i128, ls: usize, r: i128, rs: usize) -> Option<i128> {
+    debug_assert!(r != 0);
+    let scale = ls.max(rs);
+    let l = rescale_i256(I256::new(l), ls, scale, RoundMode::TowardZero)?;
+    let r = rescale_i256(I256::new(r), rs, scale, RoundMode::TowardZero)?;
+    dec128_fit(l % r)
+}
+
+/// `base ^ exp` at the scale of the base, returning `None` on overflow.
+///
+/// Intermediate results are exact as long as they fit in 38 digits, beyond that excess
+/// fractional digits are rounded half to even before the final rounding with `mode`.
+pub fn dec128_pow(base: i128, scale: usize, exp: u32, mode: RoundMode) -> Option<i128> {
+    fn mul_trim(
+        (a, sa): (I256, usize),
+        (b, sb): (I256, usize),
+        min_scale: usize,
+    ) -> Option<(I256, usize)> {
+        let mut prod = a * b;
+        let mut scale = sa + sb;
+        let limit = pow10(DEC128_MAX_PREC).unsigned_abs();
+        let mut excess = 0;
+        while prod.unsigned_abs() >= limit * pow10(excess).unsigned_abs() {
+            excess += 1;
+        }
+        let drop = excess.min(scale - min_scale);
+        if drop > 0 {
+            prod = RoundMode::HalfToEven.div_i256(prod, pow10(drop));
+            scale -= drop;
+        }
+        // Still too large at the output scale, any further factor only moves away from
+        // zero (|base| >= 1) or the value could never have been this large (|base| < 1).
+        (prod.unsigned_abs() < limit).then_some((prod, scale))
+    }
+
+    let mut acc = (I256::ONE, 0);
+    let mut b = (I256::new(base), scale);
+    let mut exp = exp;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            acc = mul_trim(acc, b, 0)?;
+        }
+        exp >>= 1;
+        if exp > 0 {
+            b = mul_trim(b, b, scale)?;
+        }
+    }
+    rescale_i256(acc.0, acc.1, scale, mode).and_then(dec128_fit)
+}
+
+/// Narrow a 256-bit decimal mantissa to [`DEC256_MAX_PREC`] digits, returning `None` if it
+/// needs more.
+#[inline]
+pub fn dec256_fit(v: I256) -> Option<I256> {
+    (v.unsigned_abs() < pow10(DEC256_MAX_PREC).unsigned_abs()).then_some(v)
+}
+
+/// Whether a 256-bit decimal mantissa fits `precision` digits.
+#[inline]
+pub fn dec256_fits_precision(v: I256, precision: usize) -> bool {
+    v.unsigned_abs() < pow10(precision.min(DEC256_MAX_PREC)).unsigned_abs()
+}
+
+/// Change the scale of a 256-bit decimal mantissa, returning `None` on overflow.
+pub fn dec256_rescale(v: I256, from: usize, to: usize, mode: RoundMode) -> Option<I256> {
+    rescale_i256(v, from, to, mode).and_then(dec256_fit)
+}
+
+/// Compare two 256-bit decimal mantissas at scales `ls` and `rs`.
+pub fn dec256_cmp(l: I256, ls: usize, r: I256, rs: usize) -> Ordering {
+    let scale = ls.max(rs);
+    let l_up = rescale_i256(l, ls, scale, RoundMode::TowardZero);
+    let r_up = rescale_i256(r, rs, scale, RoundMode::TowardZero);
+    match (l_up, r_up) {
+        (Some(l), Some(r)) => l.cmp(&r),
+        // Only the side with the smaller scale is upscaled, if that overflows its magnitude
+        // is the larger one.
+        (None, _) => l.signum().cmp(&I256::ZERO),
+        (_, None) => I256::ZERO.cmp(&r.signum()),
+    }
+}
+
+/// `l + r` at output scale `max(ls, rs)`, returning `None` on overflow.
+pub fn dec256_add(l: I256, ls: usize, r: I256, rs: usize) -> Option<I256> {
+    let scale = ls.max(rs);
+    let l = rescale_i256(l, ls, scale, RoundMode::TowardZero)?;
+    let r = rescale_i256(r, rs, scale, RoundMode::TowardZero)?;
+    l.checked_add(r).and_then(dec256_fit)
+}
+
+/// `l - r` at output scale `max(ls, rs)`, returning `None` on overflow.
+pub fn dec256_sub(l: I256, ls: usize, r: I256, rs: usize) -> Option<I256> {
+    dec256_add(l, ls, r.checked_neg()?, rs)
+}
+
+/// `l * r` at output scale `scale`, returning `None` on overflow.
+///
+/// The product is computed exactly in 512 bits before it is rounded to the output scale.
+pub fn dec256_mul(
+    l: I256,
+    ls: usize,
+    r: I256,
+    rs: usize,
+    scale: usize,
+    mode: RoundMode,
+) -> Option<I256> {
+    let negative = l.is_negative() != r.is_negative();
+    let mut prod = U512::mul(l.unsigned_abs(), r.unsigned_abs());
+    let rem = if scale >= ls + rs {
+        if !prod.mul_pow10(scale - ls - rs) {
+            return None;
+        }
+        None
+    } else {
+        prod.div_pow10(ls + rs - scale)
+    };
+    let q = prod.to_u256().and_then(fit_magnitude)?;
+    from_magnitude(round_magnitude(mode, q, rem, negative), negative)
+}
+
+/// `l / r` at output scale `scale`, returning `None` on overflow.
+///
+/// `r` must not be zero.
+pub fn dec256_div(
+    l: I256,
+    ls: usize,
+    r: I256,
+    rs: usize,
+    scale: usize,
+    mode: RoundMode,
+) -> Option<I256> {
+    debug_assert!(r != I256::ZERO);
+    // l / 10^ls / (r / 10^rs) * 10^scale == l * 10^(scale + rs - ls) / r
+    if scale + rs < ls {
+        let den = pow10_checked(ls - scale - rs).and_then(|p| r.checked_mul(p));
+        return match den {
+            Some(den) => dec256_fit(mode.div_i256(l, den)),
+            // |l| < |den|, only the sign of the quotient matters for the rounding.
+            None => Some(mode.div_i256(l.signum() * r.signum(), pow10(DEC256_MAX_PREC))),
+        };
+    }
+
+    let negative = l.is_negative() != r.is_negative();
+    let (n, d) = (l.unsigned_abs(), r.unsigned_abs());
+    let k = scale + rs - ls;
+    let (q, rem) = match pow10_checked(k).and_then(|p| n.checked_mul(p.unsigned_abs())) {
+        Some(num) => (num / d, num % d),
+        None => {
+            // Long division, one decimal digit at a time. As rem < d < 10^76 and the
+            // quotient is checked to stay below 10^76, nothing can overflow.
+            let ten = U256::new(10);
+            let (mut q, mut rem) = (n / d, n % d);
+            for _ in 0..k {
+                let t = rem * ten;
+                q = fit_magnitude(q)? * ten + t / d;
+                rem = t % d;
+            }
+            (q, rem)
+        },
+    };
+    let rem = (rem != U256::ZERO).then(|| (rem << 1u32).cmp(&d));
+    let q = fit_magnitude(q)?;
+    from_magnitude(round_magnitude(mode, q, rem, negative), negative)
+}
+
+/// `l % r` at output scale `max(ls, rs)`, the result has the sign of `l`.
+///
+/// `r` must not be zero.
+pub fn dec256_rem(l: I256, ls: usize, r: I256, rs: usize) -> Option<I256> {
+    debug_assert!(r != I256::ZERO);
+    let scale = ls.max(rs);
+    let l = rescale_i256(l, ls, scale, RoundMode::TowardZero)?;
+    let r = rescale_i256(r, rs, scale, RoundMode::TowardZero)?;
+    dec256_fit(l % r)
+}
+
+/// Parse a number such as `-12.345` or `1.5e-3` to a 256-bit decimal mantissa at `scale`,
+/// rounding excess fractional digits with `mode`. Returns `None` if the string is not a
+/// number or the value doesn't fit.
+pub fn dec256_parse(s: &[u8], scale: usize, mode: RoundMode) -> Option<I256> {
+    let (negative, s) = match s.first()? {
+        b'-' => (true, &s[1..]),
+        b'+' => (false, &s[1..]),
+        _ => (false, s),
+    };
+    let (s, exp) = match s.iter().position(|b| matches!(b, b'e' | b'E')) {
+        Some(i) => {
+            let exp = std::str::from_utf8(&s[i + 1..]).ok()?.parse::<i32>().ok()?;
+            (&s[..i], exp)
+        },
+        None => (s, 0),
+    };
+    let (int, frac) = match s.iter().position(|b| *b == b'.') {
+        Some(i) => (&s[..i], &s[i + 1..]),
+        None => (s, &s[s.len()..]),
+    };
+    if int.is_empty() && frac.is_empty() {
+        return None;
+    }
+
+    let ten = I256::new(10);
+    let mut mantissa = I256::ZERO;
+    for b in int.iter().chain(frac) {
+        if !b.is_ascii_digit() {
+            return None;
+        }
+        mantissa = mantissa
+            .checked_mul(ten)?
+            .checked_add(I256::new((b - b'0') as i128))?;
+    }
+    if negative {
+        mantissa = -mantissa;
+    }
+
+    // The digits are the mantissa at scale `frac.len() - exp`.
+    let from = frac.len() as i64 - exp as i64;
+    if from < 0 {
+        let mantissa = mantissa.checked_mul(pow10_checked((-from) as usize)?)?;
+        dec256_rescale(mantissa, 0, scale, mode)
+    } else {
+        dec256_rescale(mantissa, from as usize, scale, mode)
+    }
+}
+
+fn pow10_checked(n: usize) -> Option<I256> {
+    (n <= DEC256_MAX_PREC).then(|| pow10(n))
+}
+
+/// Check that a magnitude fits [`DEC256_MAX_PREC`] digits.
+fn fit_magnitude(v: U256) -> Option<U256> {
+    (v < pow10(DEC256_MAX_PREC).unsigned_abs()).then_some(v)
+}
+
+fn from_magnitude(v: U256, negative: bool) -> Option<I256> {
+    let (hi, lo) = fit_magnitude(v)?.into_words();
+    let v = I256::from_words(hi as i128, lo as i128);
+    Some(if negative { -v } else { v })
+}
+
+/// Round the magnitude `q` of a quotient with the given sign. `rem` tells how the remainder
+/// compares to half a unit and is `None` if the division was exact.
+fn round_magnitude(mode: RoundMode, q: U256, rem: Option<Ordering>, negative: bool) -> U256 {
+    let Some(half) = rem else {
+        return q;
+    };
+    let away = match mode {
+        RoundMode::TowardZero => false,
+        RoundMode::AwayFromZero => true,
+        RoundMode::Floor => negative,
+        RoundMode::Ceiling => !negative,
+        RoundMode::HalfAwayFromZero => half != Ordering::Less,
+        RoundMode::HalfToEven => match half {
+            Ordering::Less => false,
+            Ordering::Equal => q % U256::new(2) == U256::ONE,
+            Ordering::Greater => true,
+        },
+    };
+    if away { q + U256::ONE } else { q }
+}
+
+/// An unsigned 512-bit integer as little-endian 64-bit limbs, only used to hold the exact
+/// product of two 256-bit decimal mantissas.
+struct U512([u64; 8]);
+
+impl U512 {
+    fn mul(a: U256, b: U256) -> Self {
+        fn limbs(v: U256) -> [u64; 4] {
+            let (hi, lo) = v.into_words();
+            [lo as u64, (lo >> 64) as u64, hi as u64, (hi >> 64) as u64]
+        }
+
+        let (a, b) = (limbs(a), limbs(b));
+        let mut out = [0u64; 8];
+        for i in 0..4 {
+            let mut carry = 0u128;
+            for j in 0..4 {
+                let t = a[i] as u128 * b[j] as u128 + out[i + j] as u128 + carry;
+                out[i + j] = t as u64;
+                carry = t >> 64;
+            }
+            out[i + 4] = carry as u64;
+        }
+        Self(out)
+    }
+
+    /// Multiply by `10^n`, returning `false` on overflow.
+    fn mul_pow10(&mut self, mut n: usize) -> bool {
+        while n > 0 {
+            let k = n.min(19);
+            let m = 10u128.pow(k as u32);
+            let mut carry = 0u128;
+            for limb in self.0.iter_mut() {
+                let t = *limb as u128 * m + carry;
+                *limb = t as u64;
+                carry = t >> 64;
+            }
+            if carry != 0 {
+                return false;
+            }
+            n -= k;
+        }
+        true
+    }
+
+    /// Divide by `10^n`, returning how the remainder compares to half a unit or `None` if the
+    /// division was exact.
+    fn div_pow10(&mut self, mut n: usize) -> Option<Ordering> {
+        let mut half = None;
+        // Whether any of the less significant digits dropped so far are non-zero.
+        let mut sticky = false;
+        while n > 0 {
+            let k = n.min(19);
+            let d = 10u128.pow(k as u32);
+            let mut rem = 0u128;
+            for limb in self.0.iter_mut().rev() {
+                let cur = (rem << 64) | *limb as u128;
+                *limb = (cur / d) as u64;
+                rem = cur % d;
+            }
+            half = (rem != 0 || sticky).then(|| match (2 * rem).cmp(&d) {
+                Ordering::Equal if sticky => Ordering::Greater,
+                ord => ord,
+            });
+            sticky |= rem != 0;
+            n -= k;
+        }
+        half
+    }
+
+    fn to_u256(&self) -> Option<U256> {
+        let l = &self.0;
+        if l[4..].iter().any(|limb| *limb != 0) {
+            return None;
+        }
+        let lo = (l[0] as u128) | ((l[1] as u128) << 64);
+        let hi = (l[2] as u128) | ((l[3] as u128) << 64);
+        Some(U256::from_words(hi, lo))
+    }
+}
+
+/// Floor of the square root of a non-negative integer.
+fn isqrt_i256(n: I256) -> I256 {
+    debug_assert!(!n.is_negative());
+    if n < I256::new(2) {
+        return n;
+    }
+    let bits = 256 - n.leading_zeros();
+    let mut x = I256::ONE << bits.div_ceil(2);
+    loop {
+        let y = (x + n / x) >> 1u32;
+        if y >= x {
+            return x;
+        }
+        x = y;
+    }
+}
+
+/// Running sums of 128-bit decimal mantissas for exact mean, variance and standard
+/// deviation.
+#[derive(Clone, Debug, Default)]
+pub struct DecimalMoments {
+    n: u64,
+    sum: I256,
+    sum_sq: I256,
+    overflow: bool,
+}
+
+impl DecimalMoments {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    pub fn insert(&mut self, v: i128) {
+        let v = I256::new(v);
+        self.n += 1;
+        self.sum += v;
+        match self.sum_sq.checked_add(v * v) {
+            Some(sum_sq) => self.sum_sq = sum_sq,
+            None => self.overflow = true,
+        }
+    }
+
+    /// Remove a previously inserted value, used for sliding windows.
+    ///
+    /// After an overflow the sum of squares is lost, [`DecimalMoments::has_overflow`] tells
+    /// when it has to be rebuilt from the values in the window.
+    #[inline]
+    pub fn remove(&mut self, v: i128) {
+        let v = I256::new(v);
+        self.n -= 1;
+        self.sum -= v;
+        if !self.overflow {
+            self.sum_sq -= v * v;
+        }
+    }
+
+    pub fn has_overflow(&self) -> bool {
+        self.overflow
+    }
+
+    pub fn combine(&mut self, other: &Self) {
+        self.n += other.n;
+        self.sum += other.sum;
+        self.overflow |= other.overflow;
+        match self.sum_sq.checked_add(other.sum_sq) {
+            Some(sum_sq) => self.sum_sq = sum_sq,
+            None => self.overflow = true,
+        }
+    }
+
+    pub fn count(&self) -> u64 {
+        self.n
+    }
+
+    /// The sum at the input scale, errors on overflow.
+    pub fn sum(&self) -> PolarsResult<i128> {
+        dec128_fit(self.sum).ok_or_else(|| polars_err!(ComputeError: "decimal overflow in sum"))
+    }
+
+    /// The mean of values at `scale`, computed at `out_scale`.
+    pub fn mean(
+        &self,
+        scale: usize,
+        out_scale: usize,
+        mode: RoundMode,
+    ) -> PolarsResult<Option<i128>> {
+        if self.n == 0 {
+            return Ok(None);
+        }
+        Self::div_at_scale(self.sum, I256::new(self.n as i128), scale, out_scale, mode)
+            .map(Some)
+            .ok_or_else(|| polars_err!(ComputeError: "decimal overflow in mean"))
+    }
+
+    /// `num / den` where `num` has scale `scale`, rounded to `out_scale`.
+    fn div_at_scale(
+        num: I256,
+        den: I256,
+        scale: usize,
+        out_scale: usize,
+        mode: RoundMode,
+    ) -> Option<i128> {
+        let out = if out_scale >= scale {
+            num.checked_mul(pow10(out_scale - scale))
+                .map(|num| mode.div_i256(num, den))
+        } else {
+            den.checked_mul(pow10(scale - out_scale))
+                .map(|den| mode.div_i256(num, den))
+        };
+        out.and_then(dec128_fit)
+    }
+
+    /// `n * sum(x^2) - sum(x)^2` and `n * (n - ddof)`, so `var = num / den` at twice the
+    /// input scale.
+    fn var_parts(&self, ddof: u8, op: &str) -> PolarsResult<Option<(I256, I256)>> {
+        if self.n <= ddof as u64 {
+            return Ok(None);
+        }
+        let n = I256::new(self.n as i128);
+        let num = (!self.overflow)
+            .then(|| n.checked_mul(self.sum_sq))
+            .flatten()
+            .zip(self.sum.checked_mul(self.sum))
+            .and_then(|(a, b)| a.checked_sub(b))
+            .ok_or_else(|| polars_err!(ComputeError: "decimal overflow in {}", op))?;
+        Ok(Some((num, n * I256::new((self.n - ddof as u64) as i128))))
+    }
+
+    /// The variance of values at `scale`, computed at `out_scale`.
+    pub fn var(
+        &self,
+        scale: usize,
+        out_scale: usize,
+        ddof: u8,
+        mode: RoundMode,
+    ) -> PolarsResult<Option<i128>> {
+        let Some((num, den)) = self.var_parts(ddof, "var")? else {
+            return Ok(None);
+        };
+        Self::div_at_scale(num, den, 2 * scale, out_scale, mode)
+            .map(Some)
+            .ok_or_else(|| polars_err!(ComputeError: "decimal overflow in var"))
+    }
+
+    /// The standard deviation of values at `scale`, computed at `out_scale`.
+    pub fn std(
+        &self,
+        scale: usize,
+        out_scale: usize,
+        ddof: u8,
+        mode: RoundMode,
+    ) -> PolarsResult<Option<i128>> {
+        let overflow = || polars_err!(ComputeError: "decimal overflow in std");
+        let Some((num, den)) = self.var_parts(ddof, "std")? else {
+            return Ok(None);
+        };
+        // The result mantissa is sqrt(x) with x = num * 10^(2 * (out_scale - scale)) / den.
+        let num =
+            rescale_i256(num, 0, 2 * out_scale.saturating_sub(scale), mode).ok_or_else(overflow)?;
+        let den =
+            rescale_i256(den, 0, 2 * scale.saturating_sub(out_scale), mode).ok_or_else(overflow)?;
+        let q = isqrt_i256(num / den);
+        // Decide on the rounding exactly by comparing x with q^2 and (q + 1/2)^2.
+        let exact = q * q * den == num;
+        let round_up = match mode {
+            _ if exact => false,
+            RoundMode::TowardZero | RoundMode::Floor => false,
+            RoundMode::AwayFromZero | RoundMode::Ceiling => true,
+            RoundMode::HalfAwayFromZero | RoundMode::HalfToEven => {
+                let lhs = num.checked_mul(I256::new(4)).ok_or_else(overflow)?;
+                let rhs = (I256::new(4) * q * q + I256::new(4) * q + I256::ONE)
+                    .checked_mul(den)
+                    .ok_or_else(overflow)?;
+                match lhs.cmp(&rhs) {
+                    std::cmp::Ordering::Less => false,
+                    std::cmp::Ordering::Greater => true,
+                    std::cmp::Ordering::Equal => {
+                        mode == RoundMode::HalfAwayFromZero || q % 2 != I256::ZERO
+                    },
+                }
+            },
+        };
+        let q = if round_up { q + 1 } else { q };
+        dec128_fit(q).map(Some).ok_or_else(overflow)
+    }
+}
+
+impl DecimalChunked {
+    /// The exact sum, errors if it doesn't fit the decimal precision.
+    pub fn checked_sum(&self) -> PolarsResult<i128> {
+        let mut sum = I256::ZERO;
+        for arr in self.0.downcast_iter() {
+            for v in arr.non_null_values_iter() {
+                sum += I256::new(v);
+            }
+        }
+        dec128_fit(sum).ok_or_else(|| polars_err!(ComputeError: "decimal overflow in sum"))
+    }
+
+    fn moments_reduce<F>(&self, f: F) -> PolarsResult<Scalar>
+    where
+        F: Fn(&DecimalMoments, usize, usize, RoundMode) -> PolarsResult<Option<i128>>,
+    {
+        let scale = self.scale();
+        let out_scale = _get_decimal_scale_div(scale);
+        let v = f(&self.moments(), scale, out_scale, RoundMode::default())?;
+        let av = v.map_or(AnyValue::Null, |v| AnyValue::Decimal(v, out_scale));
+        Ok(Scalar::new(DataType::Decimal(None, Some(out_scale)), av))
+    }
+
+    /// The exact mean with the scale of a decimal division.
+    pub fn mean_reduce(&self) -> PolarsResult<Scalar> {
+        self.moments_reduce(|m, scale, out_scale, mode| m.mean(scale, out_scale, mode))
+    }
+
+    /// The exact variance with the scale of a decimal division.
+    pub fn var_reduce(&self, ddof: u8) -> PolarsResult<Scalar> {
+        self.moments_reduce(|m, scale, out_scale, mode| m.var(scale, out_scale, ddof, mode))
+    }
+
+    /// The standard deviation with the scale of a decimal division, correctly rounded.
+    pub fn std_reduce(&self, ddof: u8) -> PolarsResult<Scalar> {
+        self.moments_reduce(|m, scale, out_scale, mode| m.std(scale, out_scale, ddof, mode))
+    }
+
+    fn moments(&self) -> DecimalMoments {
+        let mut moments = DecimalMoments::new();
+        for arr in self.0.downcast_iter() {
+            for v in arr.non_null_values_iter() {
+                moments.insert(v);
+            }
+        }
+        moments
+    }
+}
+
 impl StringChunked {
     /// Convert an [`StringChunked`] to a [`Series`] of [`DataType::Decimal`].
     /// Scale needed for the decimal type are inferred.  Parsing is not strict.  
@@ -53,4 +782,103 @@ mod test {
         assert_eq!(s.get(4).unwrap(), AnyValue::Decimal(-400000, 5));
         assert_eq!(s.get(6).unwrap(), AnyValue::Decimal(525251, 5));
     }
+
+    #[test]
+    fn test_round_modes() {
+        use super::*;
+        let div =
+            |mode: RoundMode, n: i128, d: i128| mode.div_i256(I256::new(n), I256::new(d)).as_i128();
+        // 2.5, -2.5, 3.5 and 2.6
+        for (mode, expected) in [
+            (RoundMode::HalfToEven, [2, -2, 4, 3]),
+            (RoundMode::HalfAwayFromZero, [3, -3, 4, 3]),
+            (RoundMode::TowardZero, [2, -2, 3, 2]),
+            (RoundMode::AwayFromZero, [3, -3, 4, 3]),
+            (RoundMode::Floor, [2, -3, 3, 2]),
+            (RoundMode::Ceiling, [3, -2, 4, 3]),
+        ] {
+            let out = [
+                div(mode, 5, 2),
+                div(mode, 5, -2),
+                div(mode, 7, 2),
+                div(mode, 13, 5),
+            ];
+            assert_eq!(out, expected, "{mode}");
+        }
+        assert_eq!(
+            "half_away_from_zero".parse::<RoundMode>().unwrap(),
+            RoundMode::HalfAwayFromZero
+        );
+        assert!("nearest".parse::<RoundMode>().is_err());
+    }
+
+    #[test]
+    fn test_decimal_kernels() {
+        use super::*;
+        let mode = RoundMode::HalfToEven;
+        // 1.0 / 3 at scale 38 still fits
+        assert_eq!(
+            dec128_div(10, 1, 3, 0, 38, mode),
+            Some(33333333333333333333333333333333333333)
+        );
+        assert_eq!(dec128_div(10i128.pow(37), 0, 1, 2, 0, mode), None);
+        assert_eq!(dec128_mul(15, 1, 15, 1, 1, mode), Some(22));
+        assert_eq!(dec128_rescale(-125, 2, 1, mode), Some(-12));
+        assert_eq!(dec128_pow(11, 1, 10, mode), Some(26));
+        assert_eq!(dec128_pow(10, 0, 38, mode), None);
+
+        // The standard deviation of [1, 2, 3, 4] is sqrt(5/3) = 1.29099444...
+        let mut moments = DecimalMoments::new();
+        for v in [1, 2, 3, 4] {
+            moments.insert(v);
+        }
+        assert_eq!(moments.mean(0, 2, mode).unwrap(), Some(250));
+        assert_eq!(moments.var(0, 4, 1, mode).unwrap(), Some(16667));
+        assert_eq!(moments.std(0, 8, 1, mode).unwrap(), Some(129099445));
+        assert_eq!(
+            moments.std(0, 8, 1, RoundMode::Floor).unwrap(),
+            Some(129099444)
+        );
+        assert_eq!(moments.std(0, 0, 4, mode).unwrap(), None);
+    }
+
+    #[test]
+    fn test_decimal256_kernels() {
+        use super::*;
+        let mode = RoundMode::HalfToEven;
+        let i = |v: i128| I256::new(v);
+        let max = pow10(DEC256_MAX_PREC) - 1;
+        assert_eq!(dec256_fit(max), Some(max));
+        assert_eq!(dec256_fit(max + 1), None);
+        assert_eq!(dec256_add(max, 0, i(1), 0), None);
+        assert_eq!(dec256_sub(i(15), 1, i(3), 0), Some(i(-15)));
+
+        // The 512-bit product is rounded back to the output scale.
+        assert_eq!(dec256_mul(max, 38, max, 38, 0, mode), None);
+        assert_eq!(
+            dec256_mul(pow10(70), 70, pow10(70), 70, 2, mode),
+            Some(i(100))
+        );
+        assert_eq!(dec256_mul(i(15), 1, i(-15), 1, 1, mode), Some(i(-22)));
+
+        // 1 / 3 at scale 75 needs the long division.
+        let third = dec256_div(i(1), 0, i(3), 0, 75, mode).unwrap();
+        assert_eq!(third, max / 30);
+        assert_eq!(dec256_div(i(2), 0, i(3), 0, 75, mode), Some(max / 15 + 1));
+        assert_eq!(
+            dec256_div(i(1), 76, i(3), 0, 0, RoundMode::Ceiling),
+            Some(i(1))
+        );
+        assert_eq!(dec256_rem(i(-75), 1, i(2), 0), Some(i(-15)));
+        assert_eq!(dec256_cmp(i(10), 1, i(1), 0), Ordering::Equal);
+        assert_eq!(dec256_cmp(max, 0, i(1), 76), Ordering::Greater);
+        assert_eq!(dec256_cmp(-max, 0, i(1), 76), Ordering::Less);
+
+        assert_eq!(dec256_parse(b"-1.25e1", 1, mode), Some(i(-125)));
+        assert_eq!(dec256_parse(b"0.05", 1, mode), Some(i(0)));
+        assert_eq!(dec256_parse(b".5", 0, mode), Some(i(0)));
+        assert_eq!(dec256_parse(b"1e76", 0, mode), None);
+        assert_eq!(dec256_parse(b"1.2.3", 0, mode), None);
+        assert_eq!(dec256_parse(b"-", 0, mode), None);
+    }
 }
@@ -1,6 +1,7 @@
 //! Traits for miscellaneous operations on ChunkedArray
 use arrow::offset::OffsetsBuffer;
 use polars_compute::rolling::QuantileMethod;
+use strum_macros::IntoStaticStr;
 
 use crate::prelude::*;
 
@@ -18,7 +19,7 @@ mod bitwise_reduce;
 pub(crate) mod chunkops;
 pub(crate) mod compare_inner;
 #[cfg(feature = "dtype-decimal")]
-mod decimal;
+pub mod decimal;
 pub(crate) mod downcast;
 pub(crate) mod explode;
 mod explode_and_offsets;
@@ -433,6 +434,48 @@ impl FillNullStrategy {
     }
 }
 
+/// How to round a value that has more digits than the target precision allows.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default, Hash, IntoStaticStr)]
+#[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
+#[strum(serialize_all = "snake_case")]
+pub enum RoundMode {
+    /// Round to the nearest value, ties go to the even neighbour (banker's rounding).
+    #[default]
+    HalfToEven,
+    /// Round to the nearest value, ties go away from zero.
+    HalfAwayFromZero,
+    /// Drop the excess digits (truncation).
+    TowardZero,
+    /// Round away from zero whenever digits are dropped.
+    AwayFromZero,
+    /// Round towards negative infinity.
+    Floor,
+    /// Round towards positive infinity.
+    Ceiling,
+}
+
+impl std::str::FromStr for RoundMode {
+    type Err = PolarsError;
+
+    fn from_str(s: &str) -> PolarsResult<Self> {
+        Ok(match s {
+            "half_to_even" => Self::HalfToEven,
+            "half_away_from_zero" => Self::HalfAwayFromZero,
+            "toward_zero" => Self::TowardZero,
+            "away_from_zero" => Self::AwayFromZero,
+            "floor" => Self::Floor,
+            "ceiling" => Self::Ceiling,
+            _ => polars_bail!(InvalidOperation: "unknown rounding mode '{}'", s),
+        })
+    }
+}
+
+impl std::fmt::Display for RoundMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.into())
+    }
+}
+
 /// Replace None values with a value
 pub trait ChunkFillNullValue<T> {
     /// Replace None values with a give value `T`.
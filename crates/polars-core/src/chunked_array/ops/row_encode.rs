@@ -106,10 +106,26 @@ pub fn get_row_encoding_context(dtype: &DataType, ordered: bool) -> Option<RowEn
         DataType::Decimal(precision, _) => {
             Some(RowEncodingContext::Decimal(precision.unwrap_or(38)))
         },
+        #[cfg(feature = "dtype-interval")]
+        DataType::Interval => None,
+        #[cfg(feature = "dtype-decimal256")]
+        DataType::Decimal256(_, _) => None,
+        #[cfg(feature = "dtype-uuid")]
+        DataType::Uuid => Some(RowEncodingContext::FixedSizeBinary),
+        #[cfg(feature = "dtype-variant")]
+        DataType::Variant => None,
+        #[cfg(feature = "dtype-f16")]
+        DataType::Float16 => Some(RowEncodingContext::Float16),
+        #[cfg(feature = "dtype-extension")]
+        DataType::Extension(ext) => get_row_encoding_context(ext.storage_dtype(), ordered),
 
         #[cfg(feature = "dtype-array")]
         DataType::Array(dtype, _) => get_row_encoding_context(dtype, ordered),
         DataType::List(dtype) => get_row_encoding_context(dtype, ordered),
+        #[cfg(feature = "dtype-map")]
+        DataType::Map(_, _) => {
+            get_row_encoding_context(&dtype.map_storage_dtype().unwrap(), ordered)
+        },
         #[cfg(feature = "dtype-categorical")]
         DataType::Categorical(revmap, ordering) | DataType::Enum(revmap, ordering) => {
             let is_enum = dtype.is_enum();
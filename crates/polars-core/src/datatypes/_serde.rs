@@ -116,6 +116,21 @@ enum SerializableDataType {
     Enum(Option<Series>, CategoricalOrdering),
     #[cfg(feature = "object")]
     Object(String),
+    #[cfg(feature = "dtype-uuid")]
+    Uuid,
+    #[cfg(feature = "dtype-f16")]
+    Float16,
+    /// An extension type is serialized by name and deserialized as an unresolved extension type.
+    #[cfg(feature = "dtype-extension")]
+    Extension(String, Option<String>, Box<SerializableDataType>),
+    #[cfg(feature = "dtype-decimal256")]
+    Decimal256(Option<usize>, Option<usize>),
+    #[cfg(feature = "dtype-variant")]
+    Variant,
+    #[cfg(feature = "dtype-interval")]
+    Interval,
+    #[cfg(feature = "dtype-map")]
+    Map(Box<SerializableDataType>, Box<SerializableDataType>),
 }
 
 impl From<&DataType> for SerializableDataType {
@@ -169,8 +184,29 @@ impl From<&DataType> for SerializableDataType {
             Enum(None, ordering) => Self::Enum(None, *ordering),
             #[cfg(feature = "dtype-decimal")]
             Decimal(precision, scale) => Self::Decimal(*precision, *scale),
+            #[cfg(feature = "dtype-decimal256")]
+            Decimal256(precision, scale) => Self::Decimal256(*precision, *scale),
             #[cfg(feature = "object")]
             Object(name) => Self::Object(name.to_string()),
+            #[cfg(feature = "dtype-uuid")]
+            Uuid => Self::Uuid,
+            #[cfg(feature = "dtype-variant")]
+            Variant => Self::Variant,
+            #[cfg(feature = "dtype-interval")]
+            Interval => Self::Interval,
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => Self::Map(
+                Box::new(key.as_ref().into()),
+                Box::new(value.as_ref().into()),
+            ),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => Self::Float16,
+            #[cfg(feature = "dtype-extension")]
+            Extension(ext) => Self::Extension(
+                ext.name().to_string(),
+                ext.metadata().map(|md| md.to_string()),
+                Box::new(ext.storage_dtype().into()),
+            ),
             dt => panic!("{dt:?} not supported"),
         }
     }
@@ -229,8 +265,26 @@ impl From<SerializableDataType> for DataType {
             Enum(None, ordering) => Self::Enum(None, ordering),
             #[cfg(feature = "dtype-decimal")]
             Decimal(precision, scale) => Self::Decimal(precision, scale),
+            #[cfg(feature = "dtype-decimal256")]
+            Decimal256(precision, scale) => Self::Decimal256(precision, scale),
             #[cfg(feature = "object")]
             Object(_) => Self::Object("unknown"),
+            #[cfg(feature = "dtype-uuid")]
+            Uuid => Self::Uuid,
+            #[cfg(feature = "dtype-variant")]
+            Variant => Self::Variant,
+            #[cfg(feature = "dtype-interval")]
+            Interval => Self::Interval,
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => Self::Map(Box::new((*key).into()), Box::new((*value).into())),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => Self::Float16,
+            #[cfg(feature = "dtype-extension")]
+            Extension(name, metadata, storage) => DataType::Extension(unresolved_extension_type(
+                &name,
+                (*storage).into(),
+                metadata.as_deref(),
+            )),
         }
     }
 }
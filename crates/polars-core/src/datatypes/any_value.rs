@@ -107,6 +107,23 @@ pub enum AnyValue<'a> {
     /// A 128-bit fixed point decimal number with a scale.
     #[cfg(feature = "dtype-decimal")]
     Decimal(i128, usize),
+    /// A calendar interval of months, days and nanoseconds.
+    #[cfg(feature = "dtype-interval")]
+    Interval(months_days_ns),
+    /// A 128-bit universally unique identifier.
+    #[cfg(feature = "dtype-uuid")]
+    Uuid(u128),
+    /// A 16-bit (half precision) floating point number.
+    #[cfg(feature = "dtype-f16")]
+    Float16(f16),
+    /// A 256-bit fixed point decimal number with a scale.
+    #[cfg(feature = "dtype-decimal256")]
+    Decimal256(i256, usize),
+    /// A semi-structured value in the variant binary encoding, see [`VariantChunked`].
+    #[cfg(feature = "dtype-variant")]
+    Variant(&'a [u8]),
+    #[cfg(feature = "dtype-variant")]
+    VariantOwned(Vec<u8>),
 }
 
 #[cfg(feature = "serde")]
@@ -195,6 +212,32 @@ impl Serialize for AnyValue<'_> {
             AnyValue::Decimal(v, scale) => {
                 serializer.serialize_newtype_variant(name, 25, "Decimal", &(*v, *scale))
             },
+            #[cfg(feature = "dtype-interval")]
+            AnyValue::Interval(v) => serializer.serialize_newtype_variant(
+                name,
+                26,
+                "Interval",
+                &(v.months(), v.days(), v.ns()),
+            ),
+            #[cfg(feature = "dtype-uuid")]
+            AnyValue::Uuid(v) => serializer.serialize_newtype_variant(name, 27, "Uuid", v),
+            #[cfg(feature = "dtype-f16")]
+            AnyValue::Float16(v) => {
+                serializer.serialize_newtype_variant(name, 28, "Float16", &v.to_bits())
+            },
+            #[cfg(feature = "dtype-decimal256")]
+            AnyValue::Decimal256(v, scale) => {
+                let (hi, lo) = v.0.into_words();
+                serializer.serialize_newtype_variant(name, 29, "Decimal256", &(hi, lo, *scale))
+            },
+            #[cfg(feature = "dtype-variant")]
+            AnyValue::Variant(v) => {
+                serializer.serialize_newtype_variant(name, 30, "VariantOwned", v)
+            },
+            #[cfg(feature = "dtype-variant")]
+            AnyValue::VariantOwned(v) => {
+                serializer.serialize_newtype_variant(name, 30, "VariantOwned", v)
+            },
         }
     }
 }
@@ -243,6 +286,11 @@ impl<'a> Deserialize<'a> for AnyValue<'static> {
             Object,
             Struct,
             Decimal,
+            Interval,
+            Uuid,
+            Float16,
+            Decimal256,
+            VariantOwned,
         };
 
         struct OuterVisitor;
@@ -358,6 +406,25 @@ impl<'a> Deserialize<'a> for AnyValue<'static> {
                         let (v, scale) = variant.newtype_variant()?;
                         AnyValue::Decimal(v, scale)
                     }),
+                    (AvField::Interval, variant) => feature_gated!("dtype-interval", {
+                        let (months, days, ns) = variant.newtype_variant()?;
+                        AnyValue::Interval(months_days_ns::new(months, days, ns))
+                    }),
+                    (AvField::Uuid, variant) => {
+                        feature_gated!("dtype-uuid", AnyValue::Uuid(variant.newtype_variant()?))
+                    },
+                    (AvField::Float16, variant) => feature_gated!(
+                        "dtype-f16",
+                        AnyValue::Float16(f16::from_bits(variant.newtype_variant()?))
+                    ),
+                    (AvField::Decimal256, variant) => feature_gated!("dtype-decimal256", {
+                        let (hi, lo, scale) = variant.newtype_variant()?;
+                        AnyValue::Decimal256(i256(ethnum::I256::from_words(hi, lo)), scale)
+                    }),
+                    (AvField::VariantOwned, variant) => feature_gated!(
+                        "dtype-variant",
+                        AnyValue::VariantOwned(variant.newtype_variant()?)
+                    ),
                 };
                 Ok(out)
             }
@@ -384,6 +451,10 @@ impl AnyValue<'static> {
             DataType::Decimal(_p, s) => {
                 AnyValue::Decimal(0, s.expect("unknown scale during execution"))
             },
+            #[cfg(feature = "dtype-decimal256")]
+            DataType::Decimal256(_p, s) => {
+                AnyValue::Decimal256(i256::default(), s.expect("unknown scale during execution"))
+            },
             _ => AnyValue::Null,
         }
     }
@@ -444,6 +515,16 @@ impl<'a> AnyValue<'a> {
             StructOwned(payload) => DataType::Struct(payload.1.clone()),
             #[cfg(feature = "dtype-decimal")]
             Decimal(_, scale) => DataType::Decimal(None, Some(*scale)),
+            #[cfg(feature = "dtype-interval")]
+            Interval(_) => DataType::Interval,
+            #[cfg(feature = "dtype-uuid")]
+            Uuid(_) => DataType::Uuid,
+            #[cfg(feature = "dtype-f16")]
+            Float16(_) => DataType::Float16,
+            #[cfg(feature = "dtype-decimal256")]
+            Decimal256(_, scale) => DataType::Decimal256(None, Some(*scale)),
+            #[cfg(feature = "dtype-variant")]
+            Variant(_) | VariantOwned(_) => DataType::Variant,
             #[cfg(feature = "object")]
             Object(o) => DataType::Object(o.type_name()),
             #[cfg(feature = "object")]
@@ -468,6 +549,8 @@ impl<'a> AnyValue<'a> {
             UInt64(v) => NumCast::from(*v),
             Float32(v) => NumCast::from(*v),
             Float64(v) => NumCast::from(*v),
+            #[cfg(feature = "dtype-f16")]
+            Float16(v) => NumCast::from(v.to_f32()),
             #[cfg(feature = "dtype-date")]
             Date(v) => NumCast::from(*v),
             #[cfg(feature = "dtype-datetime")]
@@ -485,6 +568,8 @@ impl<'a> AnyValue<'a> {
                     NumCast::from(f? / 10f64.powi(*scale as _))
                 }
             },
+            #[cfg(feature = "dtype-decimal256")]
+            Decimal256(v, scale) => NumCast::from(format!("{v}e-{scale}").parse::<f64>().ok()?),
             Boolean(v) => NumCast::from(if *v { 1 } else { 0 }),
             String(v) => {
                 if let Ok(val) = (*v).parse::<i128>() {
@@ -517,7 +602,12 @@ impl<'a> AnyValue<'a> {
     }
 
     pub fn is_float(&self) -> bool {
-        matches!(self, AnyValue::Float32(_) | AnyValue::Float64(_))
+        match self {
+            AnyValue::Float32(_) | AnyValue::Float64(_) => true,
+            #[cfg(feature = "dtype-f16")]
+            AnyValue::Float16(_) => true,
+            _ => false,
+        }
     }
 
     pub fn is_integer(&self) -> bool {
@@ -546,6 +636,8 @@ impl<'a> AnyValue<'a> {
         match self {
             AnyValue::Float32(f) => f.is_nan(),
             AnyValue::Float64(f) => f.is_nan(),
+            #[cfg(feature = "dtype-f16")]
+            AnyValue::Float16(f) => f.to_f32().is_nan(),
             _ => false,
         }
     }
@@ -582,6 +674,8 @@ impl<'a> AnyValue<'a> {
             (av, DataType::Int128) => AnyValue::Int128(av.extract::<i128>()?),
             (av, DataType::Float32) => AnyValue::Float32(av.extract::<f32>()?),
             (av, DataType::Float64) => AnyValue::Float64(av.extract::<f64>()?),
+            #[cfg(feature = "dtype-f16")]
+            (av, DataType::Float16) => AnyValue::Float16(f16::from_f32(av.extract::<f32>()?)),
 
             // to boolean
             (AnyValue::UInt8(v), DataType::Boolean) => AnyValue::Boolean(*v != u8::default()),
@@ -595,10 +689,36 @@ impl<'a> AnyValue<'a> {
             (AnyValue::Int128(v), DataType::Boolean) => AnyValue::Boolean(*v != i128::default()),
             (AnyValue::Float32(v), DataType::Boolean) => AnyValue::Boolean(*v != f32::default()),
             (AnyValue::Float64(v), DataType::Boolean) => AnyValue::Boolean(*v != f64::default()),
+            #[cfg(feature = "dtype-f16")]
+            (AnyValue::Float16(v), DataType::Boolean) => AnyValue::Boolean(v.to_f32() != 0.0),
+
+            // to and from variant
+            #[cfg(feature = "dtype-variant")]
+            (AnyValue::Variant(v), DataType::Variant) => AnyValue::Variant(v),
+            #[cfg(feature = "dtype-variant")]
+            (AnyValue::VariantOwned(v), DataType::Variant) => AnyValue::VariantOwned(v.clone()),
+            #[cfg(feature = "dtype-variant")]
+            (av, DataType::Variant) => {
+                AnyValue::VariantOwned(crate::chunked_array::logical::any_value_to_variant(av)?)
+            },
+            #[cfg(feature = "dtype-variant")]
+            (AnyValue::Variant(v), DataType::String) => {
+                AnyValue::StringOwned(crate::chunked_array::logical::variant_to_string(v)?.into())
+            },
+            #[cfg(feature = "dtype-variant")]
+            (AnyValue::VariantOwned(v), DataType::String) => {
+                AnyValue::StringOwned(crate::chunked_array::logical::variant_to_string(v)?.into())
+            },
 
             // to string
             (AnyValue::String(v), DataType::String) => AnyValue::String(v),
             (AnyValue::StringOwned(v), DataType::String) => AnyValue::StringOwned(v.clone()),
+            #[cfg(feature = "dtype-decimal256")]
+            (AnyValue::Decimal256(v, scale), DataType::String) => {
+                let mut buf = std::string::String::new();
+                crate::fmt::fmt_decimal256_string(&mut buf, v.0, *scale, false).ok()?;
+                AnyValue::StringOwned(PlSmallStr::from_string(buf))
+            },
 
             (av, DataType::String) => {
                 let mut tmp = vec![];
@@ -736,6 +856,29 @@ impl<'a> AnyValue<'a> {
                 let converted = value.checked_mul(factor)?;
                 AnyValue::Decimal(converted, *scale)
             },
+            #[cfg(feature = "dtype-decimal256")]
+            (av, DataType::Decimal256(prec, scale)) => {
+                use crate::chunked_array::ops::decimal::{
+                    DEC256_MAX_PREC, dec256_fits_precision, dec256_rescale,
+                };
+
+                let (value, scale_av) = match av {
+                    AnyValue::Decimal256(v, scale_av) => (v.0, *scale_av),
+                    #[cfg(feature = "dtype-decimal")]
+                    AnyValue::Decimal(v, scale_av) => (ethnum::I256::new(*v), *scale_av),
+                    av => (ethnum::I256::new(av.to_i128()?), 0),
+                };
+                let scale = scale.unwrap_or(scale_av);
+                // TODO: Allow lossy conversion?
+                if scale < scale_av {
+                    return None;
+                }
+                let converted = dec256_rescale(value, scale_av, scale, RoundMode::TowardZero)?;
+                if !dec256_fits_precision(converted, prec.unwrap_or(DEC256_MAX_PREC)) {
+                    return None;
+                }
+                AnyValue::Decimal256(i256(converted), scale)
+            },
 
             // to self
             (av, dtype) if av.dtype() == *dtype => self.clone(),
@@ -826,6 +969,8 @@ impl AnyValue<'_> {
             StringOwned(v) => v.hash(state),
             Float32(v) => v.to_ne_bytes().hash(state),
             Float64(v) => v.to_ne_bytes().hash(state),
+            #[cfg(feature = "dtype-f16")]
+            Float16(v) => v.to_bits().hash(state),
             Binary(v) => v.hash(state),
             BinaryOwned(v) => v.hash(state),
             Boolean(v) => v.hash(state),
@@ -886,6 +1031,19 @@ impl AnyValue<'_> {
                 v.hash(state);
                 k.hash(state);
             },
+            #[cfg(feature = "dtype-interval")]
+            Interval(v) => v.hash(state),
+            #[cfg(feature = "dtype-uuid")]
+            Uuid(v) => v.hash(state),
+            #[cfg(feature = "dtype-decimal256")]
+            Decimal256(v, k) => {
+                v.hash(state);
+                k.hash(state);
+            },
+            #[cfg(feature = "dtype-variant")]
+            Variant(v) => v.hash(state),
+            #[cfg(feature = "dtype-variant")]
+            VariantOwned(v) => v.hash(state),
             Null => {},
         }
     }
@@ -940,6 +1098,33 @@ impl<'a> AnyValue<'a> {
         }
     }
 
+    #[cfg(feature = "dtype-interval")]
+    pub(crate) fn as_interval(&self) -> AnyValue<'static> {
+        match self {
+            AnyValue::Int128(v) => AnyValue::Interval(i128_to_interval(*v)),
+            AnyValue::Null => AnyValue::Null,
+            dt => panic!("cannot create interval from other type. dtype: {dt}"),
+        }
+    }
+
+    #[cfg(feature = "dtype-uuid")]
+    pub(crate) fn as_uuid(&self) -> AnyValue<'static> {
+        match self {
+            AnyValue::Int128(v) => AnyValue::Uuid(i128_to_uuid(*v)),
+            AnyValue::Null => AnyValue::Null,
+            dt => panic!("cannot create uuid from other type. dtype: {dt}"),
+        }
+    }
+
+    #[cfg(feature = "dtype-f16")]
+    pub(crate) fn as_float16(&self) -> AnyValue<'static> {
+        match self {
+            AnyValue::UInt16(v) => AnyValue::Float16(f16::from_bits(*v)),
+            AnyValue::Null => AnyValue::Null,
+            dt => panic!("cannot create float16 from other type. dtype: {dt}"),
+        }
+    }
+
     #[cfg(feature = "dtype-time")]
     pub(crate) fn as_time(&self) -> AnyValue<'static> {
         match self {
@@ -968,6 +1153,8 @@ impl<'a> AnyValue<'a> {
         match self {
             AnyValue::Float32(v) => Some((*v).into()),
             AnyValue::Float64(v) => Some(*v),
+            #[cfg(feature = "dtype-f16")]
+            AnyValue::Float16(v) => Some(v.to_f32().into()),
             _ => None,
         }
     }
@@ -984,6 +1171,8 @@ impl<'a> AnyValue<'a> {
             (UInt64(l), UInt64(r)) => UInt64(l + r),
             (Float32(l), Float32(r)) => Float32(l + r),
             (Float64(l), Float64(r)) => Float64(l + r),
+            #[cfg(feature = "dtype-f16")]
+            (Float16(l), Float16(r)) => Float16(f16::from_f32(l.to_f32() + r.to_f32())),
             #[cfg(feature = "dtype-duration")]
             (Duration(l, lu), Duration(r, ru)) => {
                 if lu != ru {
@@ -1000,6 +1189,14 @@ impl<'a> AnyValue<'a> {
 
                 Decimal(l + r, *ls)
             },
+            #[cfg(feature = "dtype-decimal256")]
+            (Decimal256(l, ls), Decimal256(r, rs)) => {
+                // Overflow results in null.
+                match crate::chunked_array::ops::decimal::dec256_add(l.0, *ls, r.0, *rs) {
+                    Some(v) => Decimal256(i256(v), *ls.max(rs)),
+                    None => Null,
+                }
+            },
             _ => unimplemented!(),
         }
     }
@@ -1009,6 +1206,8 @@ impl<'a> AnyValue<'a> {
         match self {
             AnyValue::BinaryOwned(data) => AnyValue::Binary(data),
             AnyValue::StringOwned(data) => AnyValue::String(data.as_str()),
+            #[cfg(feature = "dtype-variant")]
+            AnyValue::VariantOwned(data) => AnyValue::Variant(data),
             #[cfg(feature = "dtype-datetime")]
             AnyValue::DatetimeOwned(v, tu, tz) => {
                 AnyValue::Datetime(*v, *tu, tz.as_ref().map(AsRef::as_ref))
@@ -1080,6 +1279,18 @@ impl<'a> AnyValue<'a> {
             },
             #[cfg(feature = "dtype-decimal")]
             Decimal(val, scale) => Decimal(val, scale),
+            #[cfg(feature = "dtype-interval")]
+            Interval(v) => Interval(v),
+            #[cfg(feature = "dtype-uuid")]
+            Uuid(v) => Uuid(v),
+            #[cfg(feature = "dtype-f16")]
+            Float16(v) => Float16(v),
+            #[cfg(feature = "dtype-decimal256")]
+            Decimal256(v, scale) => Decimal256(v, scale),
+            #[cfg(feature = "dtype-variant")]
+            Variant(v) => VariantOwned(v.to_vec()),
+            #[cfg(feature = "dtype-variant")]
+            VariantOwned(v) => VariantOwned(v),
             #[cfg(feature = "dtype-categorical")]
             Categorical(v, rev, arr) => CategoricalOwned(v, Arc::new(rev.clone()), arr),
             #[cfg(feature = "dtype-categorical")]
@@ -1177,6 +1388,10 @@ impl AnyValue<'_> {
             (l, BinaryOwned(r)) => *l == AnyValue::Binary(r.as_slice()),
             #[cfg(feature = "object")]
             (l, ObjectOwned(r)) => *l == AnyValue::Object(&*r.0),
+            #[cfg(feature = "dtype-variant")]
+            (VariantOwned(l), r) => AnyValue::Variant(l.as_slice()) == *r,
+            #[cfg(feature = "dtype-variant")]
+            (l, VariantOwned(r)) => *l == AnyValue::Variant(r.as_slice()),
             #[cfg(feature = "dtype-datetime")]
             (DatetimeOwned(lv, ltu, ltz), r) => {
                 Datetime(*lv, *ltu, ltz.as_ref().map(|v| v.as_ref())) == *r
@@ -1272,6 +1487,20 @@ impl AnyValue<'_> {
                 struct_value_iter(*r_idx, r_arr),
                 null_equal,
             ),
+            #[cfg(feature = "dtype-interval")]
+            (Interval(l), Interval(r)) => l == r,
+            #[cfg(feature = "dtype-uuid")]
+            (Uuid(l), Uuid(r)) => l == r,
+            #[cfg(feature = "dtype-f16")]
+            (Float16(l), Float16(r)) => l.tot_eq(r),
+            #[cfg(feature = "dtype-decimal256")]
+            (Decimal256(l_v, l_s), Decimal256(r_v, r_s)) => {
+                use crate::chunked_array::ops::decimal::dec256_cmp;
+                dec256_cmp(l_v.0, *l_s, r_v.0, *r_s).is_eq()
+            },
+            // Variants are canonically encoded, so equal values have equal bytes.
+            #[cfg(feature = "dtype-variant")]
+            (Variant(l), Variant(r)) => l == r,
             #[cfg(feature = "dtype-decimal")]
             (Decimal(l_v, l_s), Decimal(r_v, r_s)) => {
                 // l_v / 10**l_s == r_v / 10**r_s
@@ -1355,6 +1584,10 @@ impl PartialOrd for AnyValue<'_> {
             (l, BinaryOwned(r)) => l.partial_cmp(&AnyValue::Binary(r.as_slice())),
             #[cfg(feature = "object")]
             (l, ObjectOwned(r)) => l.partial_cmp(&AnyValue::Object(&*r.0)),
+            #[cfg(feature = "dtype-variant")]
+            (VariantOwned(l), r) => AnyValue::Variant(l.as_slice()).partial_cmp(r),
+            #[cfg(feature = "dtype-variant")]
+            (l, VariantOwned(r)) => l.partial_cmp(&AnyValue::Variant(r.as_slice())),
             #[cfg(feature = "dtype-datetime")]
             (DatetimeOwned(lv, ltu, ltz), r) => {
                 Datetime(*lv, *ltu, ltz.as_ref().map(|v| v.as_ref())).partial_cmp(r)
@@ -1446,6 +1679,23 @@ impl PartialOrd for AnyValue<'_> {
             | (Struct(..), Struct(..)) => {
                 unimplemented!("ordering for Struct dtype is not supported")
             },
+            #[cfg(feature = "dtype-interval")]
+            (Interval(l), Interval(r)) => {
+                use crate::chunked_array::logical::interval_to_i128;
+                interval_to_i128(*l).partial_cmp(&interval_to_i128(*r))
+            },
+            #[cfg(feature = "dtype-uuid")]
+            (Uuid(l), Uuid(r)) => l.partial_cmp(r),
+            #[cfg(feature = "dtype-f16")]
+            (Float16(l), Float16(r)) => Some(l.tot_cmp(r)),
+            #[cfg(feature = "dtype-decimal256")]
+            (Decimal256(l_v, l_s), Decimal256(r_v, r_s)) => {
+                use crate::chunked_array::ops::decimal::dec256_cmp;
+                Some(dec256_cmp(l_v.0, *l_s, r_v.0, *r_s))
+            },
+            // Variants only have an arbitrary, but consistent, order.
+            #[cfg(feature = "dtype-variant")]
+            (Variant(l), Variant(r)) => l.partial_cmp(r),
             #[cfg(feature = "dtype-decimal")]
             (Decimal(l_v, l_s), Decimal(r_v, r_s)) => {
                 // l_v / 10**l_s <=> r_v / 10**r_s
@@ -1719,7 +1969,7 @@ impl From<bool> for AnyValue<'static> {
 
 #[cfg(test)]
 mod test {
-    #[cfg(feature = "dtype-categorical")]
+    #[cfg(any(feature = "dtype-categorical", feature = "dtype-decimal256"))]
     use super::*;
 
     #[test]
@@ -1812,4 +2062,14 @@ mod test {
             assert_eq!(dt_p, dt);
         }
     }
+
+    #[test]
+    #[cfg(feature = "dtype-decimal256")]
+    fn test_decimal256_add() {
+        let dec = |v: i128, scale| AnyValue::Decimal256(i256(ethnum::I256::new(v)), scale);
+        assert_eq!(dec(125, 2).add(&dec(5, 1)), dec(175, 2));
+
+        let max = AnyValue::Decimal256(i256(ethnum::I256::new(10).pow(76) - 1), 0);
+        assert_eq!(max.add(&dec(1, 0)), AnyValue::Null);
+    }
 }
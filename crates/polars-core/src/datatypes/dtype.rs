@@ -16,6 +16,14 @@ use crate::utils::materialize_dyn_int;
 pub type TimeZone = PlSmallStr;
 
 static MAINTAIN_PL_TYPE: &str = "maintain_type";
+/// Name of the key field of the entries of a map.
+#[cfg(feature = "dtype-map")]
+pub const MAP_KEY_NAME: &str = "key";
+/// Name of the value field of the entries of a map.
+#[cfg(feature = "dtype-map")]
+pub const MAP_VALUE_NAME: &str = "value";
+#[cfg(feature = "dtype-map")]
+const MAP_ENTRIES_NAME: &str = "entries";
 static PL_KEY: &str = "pl";
 
 pub trait MetaDataExt: IntoMetadata {
@@ -102,6 +110,11 @@ pub enum DataType {
     Int32,
     Int64,
     Int128,
+    /// A 16-bit (half precision) floating point number.
+    ///
+    /// Values are stored in 16 bits, but computations are done in 32 bits.
+    #[cfg(feature = "dtype-f16")]
+    Float16,
     Float32,
     Float64,
     /// Fixed point decimal type optional precision and non-negative scale.
@@ -109,6 +122,10 @@ pub enum DataType {
     /// Meaning max precision is 38.
     #[cfg(feature = "dtype-decimal")]
     Decimal(Option<usize>, Option<usize>), // precision/scale; scale being None means "infer"
+    /// Fixed point decimal type backed by a signed 256-bit integer, which allows for up to 76
+    /// significant digits. Precision and scale behave as for [`DataType::Decimal`].
+    #[cfg(feature = "dtype-decimal256")]
+    Decimal256(Option<usize>, Option<usize>),
     /// String data
     String,
     Binary,
@@ -121,6 +138,21 @@ pub enum DataType {
     Datetime(TimeUnit, Option<TimeZone>),
     /// 64-bit integer representing difference between times in milliseconds or nanoseconds
     Duration(TimeUnit),
+    /// A calendar interval of months, days and nanoseconds. Unlike [`DataType::Duration`] it
+    /// has no fixed length; e.g. the length of one month depends on the date it is added to.
+    #[cfg(feature = "dtype-interval")]
+    Interval,
+    /// A 128-bit universally unique identifier.
+    ///
+    /// The values are stored as 16-byte fixed-size binary, with `Array(UInt8, 16)` as the
+    /// physical type. This has the memory layout of the Arrow `arrow.uuid` extension type, so
+    /// converting from and to Arrow doesn't copy the values.
+    #[cfg(feature = "dtype-uuid")]
+    Uuid,
+    /// Semi-structured data, such as JSON, stored losslessly in the Parquet Variant binary
+    /// encoding. Each value can be a scalar, an array or an object with values of any type.
+    #[cfg(feature = "dtype-variant")]
+    Variant,
     /// A 64-bit time representing the elapsed time since midnight in nanoseconds
     Time,
     /// A nested list with a fixed size in each row
@@ -142,6 +174,12 @@ pub enum DataType {
     Enum(Option<Arc<RevMapping>>, CategoricalOrdering),
     #[cfg(feature = "dtype-struct")]
     Struct(Vec<Field>),
+    /// A map of keys to values, stored as a list of `key`/`value` structs in each row
+    #[cfg(feature = "dtype-map")]
+    Map(Box<DataType>, Box<DataType>),
+    /// A user defined extension type, stored as its storage [`DataType`]
+    #[cfg(feature = "dtype-extension")]
+    Extension(ExtensionTypeRef),
     // some logical types we cannot know statically, e.g. Datetime
     Unknown(UnknownKind),
 }
@@ -189,6 +227,13 @@ impl PartialEq for DataType {
 
                     is_prec_eq && is_scale_eq
                 },
+                #[cfg(feature = "dtype-decimal256")]
+                (Decimal256(l_prec, l_scale), Decimal256(r_prec, r_scale)) => {
+                    let is_prec_eq = l_prec.is_none() || r_prec.is_none() || l_prec == r_prec;
+                    let is_scale_eq = l_scale.is_none() || r_scale.is_none() || l_scale == r_scale;
+
+                    is_prec_eq && is_scale_eq
+                },
                 #[cfg(feature = "object")]
                 (Object(lhs), Object(rhs)) => lhs == rhs,
                 #[cfg(feature = "dtype-struct")]
@@ -197,6 +242,12 @@ impl PartialEq for DataType {
                 (Array(left_inner, left_width), Array(right_inner, right_width)) => {
                     left_width == right_width && left_inner == right_inner
                 },
+                #[cfg(feature = "dtype-map")]
+                (Map(left_key, left_value), Map(right_key, right_value)) => {
+                    left_key == right_key && left_value == right_value
+                },
+                #[cfg(feature = "dtype-extension")]
+                (Extension(lhs), Extension(rhs)) => extension_eq(lhs.as_ref(), rhs.as_ref()),
                 (Unknown(l), Unknown(r)) => match (l, r) {
                     (UnknownKind::Int(_), UnknownKind::Int(_)) => true,
                     _ => l == r,
@@ -257,6 +308,8 @@ impl DataType {
             DataType::Array(inner, _) => inner.is_known(),
             #[cfg(feature = "dtype-struct")]
             DataType::Struct(fields) => fields.iter().all(|fld| fld.dtype.is_known()),
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(key, value) => key.is_known() && value.is_known(),
             DataType::Unknown(_) => false,
             _ => true,
         }
@@ -296,6 +349,11 @@ impl DataType {
                     })
                     .try_collect_vec()?,
             )),
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(key, value) => Ok(DataType::Map(
+                Box::new(key.materialize_unknown(allow_unknown)?),
+                Box::new(value.materialize_unknown(allow_unknown)?),
+            )),
             _ => Ok(self),
         }
     }
@@ -394,6 +452,8 @@ impl DataType {
                 dt if dt.is_primitive_numeric() => true,
                 #[cfg(feature = "dtype-decimal")]
                 D::Decimal(_, _) => true,
+                #[cfg(feature = "dtype-decimal256")]
+                D::Decimal256(_, _) => true,
                 D::String | D::Binary => true,
                 _ => false,
             },
@@ -403,6 +463,10 @@ impl DataType {
             (D::Array(from, l_width), D::Array(to, r_width)) => {
                 l_width == r_width && from.can_cast_to(to)?
             },
+            #[cfg(feature = "dtype-map")]
+            (D::Map(l_key, l_value), D::Map(r_key, r_value)) => {
+                l_key.can_cast_to(r_key)? && l_value.can_cast_to(r_value)?
+            },
             #[cfg(feature = "dtype-struct")]
             (D::Struct(l_fields), D::Struct(r_fields)) => {
                 if l_fields.is_empty() {
@@ -442,6 +506,16 @@ impl DataType {
             Time => Int64,
             #[cfg(feature = "dtype-decimal")]
             Decimal(_, _) => Int128,
+            #[cfg(feature = "dtype-decimal256")]
+            Decimal256(_, _) => Binary,
+            #[cfg(feature = "dtype-interval")]
+            Interval => Int128,
+            #[cfg(feature = "dtype-uuid")]
+            Uuid => Array(Box::new(UInt8), 16),
+            #[cfg(feature = "dtype-variant")]
+            Variant => Binary,
+            #[cfg(feature = "dtype-f16")]
+            Float16 => UInt16,
             #[cfg(feature = "dtype-categorical")]
             Categorical(_, _) | Enum(_, _) => UInt32,
             #[cfg(feature = "dtype-array")]
@@ -455,10 +529,26 @@ impl DataType {
                     .collect();
                 Struct(new_fields)
             },
+            #[cfg(feature = "dtype-map")]
+            Map(_, _) => self.map_storage_dtype().unwrap().to_physical(),
+            #[cfg(feature = "dtype-extension")]
+            Extension(ext) => ext.storage_dtype().to_physical(),
             _ => self.clone(),
         }
     }
 
+    /// Get the (logical) type a map is stored as: a list of `key`/`value` structs.
+    #[cfg(feature = "dtype-map")]
+    pub fn map_storage_dtype(&self) -> Option<DataType> {
+        match self {
+            DataType::Map(key, value) => Some(DataType::List(Box::new(DataType::Struct(vec![
+                Field::new(PlSmallStr::from_static(MAP_KEY_NAME), (**key).clone()),
+                Field::new(PlSmallStr::from_static(MAP_VALUE_NAME), (**value).clone()),
+            ])))),
+            _ => None,
+        }
+    }
+
     pub fn is_supported_list_arithmetic_input(&self) -> bool {
         self.is_primitive_numeric() || self.is_bool() || self.is_null()
     }
@@ -484,9 +574,9 @@ impl DataType {
             )
     }
 
-    /// Check if this [`DataType`] is a primitive numeric type (excludes Decimal).
+    /// Check if this [`DataType`] is a primitive numeric type (excludes Decimal and Float16).
     pub fn is_primitive_numeric(&self) -> bool {
-        self.is_float() || self.is_integer()
+        (self.is_float() && !self.is_float16()) || self.is_integer()
     }
 
     /// Check if this [`DataType`] is a boolean.
@@ -512,7 +602,19 @@ impl DataType {
     }
 
     pub fn is_nested(&self) -> bool {
-        self.is_list() || self.is_struct() || self.is_array()
+        self.is_list() || self.is_struct() || self.is_array() || self.is_map()
+    }
+
+    /// Check if this [`DataType`] is a map.
+    pub fn is_map(&self) -> bool {
+        #[cfg(feature = "dtype-map")]
+        {
+            matches!(self, DataType::Map(_, _))
+        }
+        #[cfg(not(feature = "dtype-map"))]
+        {
+            false
+        }
     }
 
     /// Check if this [`DataType`] is a struct
@@ -564,6 +666,10 @@ impl DataType {
             Array(inner, _) => inner.contains_views(),
             #[cfg(feature = "dtype-struct")]
             Struct(fields) => fields.iter().any(|field| field.dtype.contains_views()),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => key.contains_views() || value.contains_views(),
+            #[cfg(feature = "dtype-extension")]
+            Extension(ext) => ext.storage_dtype().contains_views(),
             _ => false,
         }
     }
@@ -580,6 +686,10 @@ impl DataType {
             Struct(fields) => fields
                 .iter()
                 .any(|field| field.dtype.contains_categoricals()),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => key.contains_categoricals() || value.contains_categoricals(),
+            #[cfg(feature = "dtype-extension")]
+            Extension(ext) => ext.storage_dtype().contains_categoricals(),
             _ => false,
         }
     }
@@ -594,12 +704,20 @@ impl DataType {
             Array(inner, _) => inner.contains_objects(),
             #[cfg(feature = "dtype-struct")]
             Struct(fields) => fields.iter().any(|field| field.dtype.contains_objects()),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => key.contains_objects() || value.contains_objects(),
+            #[cfg(feature = "dtype-extension")]
+            Extension(ext) => ext.storage_dtype().contains_objects(),
             _ => false,
         }
     }
 
     /// Check if type is sortable
     pub fn is_ord(&self) -> bool {
+        #[cfg(feature = "dtype-extension")]
+        if let DataType::Extension(ext) = self {
+            return ext.supports_ordering() && ext.storage_dtype().is_ord();
+        }
         #[cfg(feature = "dtype-categorical")]
         let is_cat = matches!(self, DataType::Categorical(_, _) | DataType::Enum(_, _));
         #[cfg(not(feature = "dtype-categorical"))]
@@ -624,13 +742,67 @@ impl DataType {
         }
     }
 
+    /// Check if this [`DataType`] is a 256-bit Decimal type (of any scale/precision).
+    pub fn is_decimal256(&self) -> bool {
+        match self {
+            #[cfg(feature = "dtype-decimal256")]
+            DataType::Decimal256(_, _) => true,
+            _ => false,
+        }
+    }
+
+    /// Check if this [`DataType`] is a calendar interval.
+    pub fn is_interval(&self) -> bool {
+        match self {
+            #[cfg(feature = "dtype-interval")]
+            DataType::Interval => true,
+            _ => false,
+        }
+    }
+
+    /// Check if this [`DataType`] is a 16-bit float.
+    pub fn is_float16(&self) -> bool {
+        match self {
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => true,
+            _ => false,
+        }
+    }
+
+    /// Check if this [`DataType`] is a UUID.
+    pub fn is_uuid(&self) -> bool {
+        match self {
+            #[cfg(feature = "dtype-uuid")]
+            DataType::Uuid => true,
+            _ => false,
+        }
+    }
+
+    /// Check if this [`DataType`] is a variant.
+    pub fn is_variant(&self) -> bool {
+        match self {
+            #[cfg(feature = "dtype-variant")]
+            DataType::Variant => true,
+            _ => false,
+        }
+    }
+
+    /// Check if this [`DataType`] is a user defined extension type.
+    pub fn is_extension(&self) -> bool {
+        match self {
+            #[cfg(feature = "dtype-extension")]
+            DataType::Extension(_) => true,
+            _ => false,
+        }
+    }
+
     /// Check if this [`DataType`] is a basic floating point type (excludes Decimal).
     /// Note, this also includes `Unknown(UnknownKind::Float)`.
     pub fn is_float(&self) -> bool {
         matches!(
             self,
             DataType::Float32 | DataType::Float64 | DataType::Unknown(UnknownKind::Float)
-        )
+        ) || self.is_float16()
     }
 
     /// Check if this [`DataType`] is an integer. Note, this also includes `Unknown(UnknownKind::Int)`.
@@ -742,6 +914,8 @@ impl DataType {
             UInt16 => Scalar::from(u16::MAX),
             UInt32 => Scalar::from(u32::MAX),
             UInt64 => Scalar::from(u64::MAX),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => Scalar::new(Float16, AnyValue::Float16(f16::from_f32(f32::INFINITY))),
             Float32 => Scalar::from(f32::INFINITY),
             Float64 => Scalar::from(f64::INFINITY),
             #[cfg(feature = "dtype-time")]
@@ -764,6 +938,8 @@ impl DataType {
             UInt16 => Scalar::from(u16::MIN),
             UInt32 => Scalar::from(u32::MIN),
             UInt64 => Scalar::from(u64::MIN),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => Scalar::new(Float16, AnyValue::Float16(f16::from_f32(f32::NEG_INFINITY))),
             Float32 => Scalar::from(f32::NEG_INFINITY),
             Float64 => Scalar::from(f64::NEG_INFINITY),
             #[cfg(feature = "dtype-time")]
@@ -793,6 +969,8 @@ impl DataType {
             Int32 => Ok(ArrowDataType::Int32),
             Int64 => Ok(ArrowDataType::Int64),
             Int128 => Ok(ArrowDataType::Int128),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => Ok(ArrowDataType::Float16),
             Float32 => Ok(ArrowDataType::Float32),
             Float64 => Ok(ArrowDataType::Float64),
             #[cfg(feature = "dtype-decimal")]
@@ -805,6 +983,13 @@ impl DataType {
                     scale.unwrap_or(0), // and what else can we do here?
                 ))
             },
+            #[cfg(feature = "dtype-decimal256")]
+            Decimal256(precision, scale) => {
+                let precision = (*precision).unwrap_or(76);
+                polars_ensure!(precision <= 76 && precision > 0, InvalidOperation: "decimal256 precision should be <= 76 & >= 1");
+
+                Ok(ArrowDataType::Decimal256(precision, scale.unwrap_or(0)))
+            },
             String => {
                 let dt = if compat_level.0 >= 1 {
                     ArrowDataType::Utf8View
@@ -824,6 +1009,20 @@ impl DataType {
             Date => Ok(ArrowDataType::Date32),
             Datetime(unit, tz) => Ok(ArrowDataType::Timestamp(unit.to_arrow(), tz.clone())),
             Duration(unit) => Ok(ArrowDataType::Duration(unit.to_arrow())),
+            #[cfg(feature = "dtype-interval")]
+            Interval => Ok(ArrowDataType::Interval(
+                arrow::datatypes::IntervalUnit::MonthDayNano,
+            )),
+            #[cfg(feature = "dtype-uuid")]
+            Uuid => Ok(ArrowDataType::Extension(Box::new(
+                arrow::datatypes::ExtensionType {
+                    name: PlSmallStr::from_static(UUID_EXTENSION_NAME),
+                    inner: ArrowDataType::FixedSizeBinary(16),
+                    metadata: None,
+                },
+            ))),
+            #[cfg(feature = "dtype-variant")]
+            Variant => Ok(crate::chunked_array::logical::variant_arrow_dtype()),
             Time => Ok(ArrowDataType::Time64(ArrowTimeUnit::Nanosecond)),
             #[cfg(feature = "dtype-array")]
             Array(dt, size) => Ok(dt
@@ -856,6 +1055,33 @@ impl DataType {
                     .collect();
                 Ok(ArrowDataType::Struct(fields))
             },
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => {
+                let entries = ArrowDataType::Struct(vec![
+                    ArrowField::new(
+                        PlSmallStr::from_static(MAP_KEY_NAME),
+                        key.try_to_arrow(compat_level)?,
+                        false,
+                    ),
+                    value.to_arrow_field(PlSmallStr::from_static(MAP_VALUE_NAME), compat_level),
+                ]);
+                Ok(ArrowDataType::Map(
+                    Box::new(ArrowField::new(
+                        PlSmallStr::from_static(MAP_ENTRIES_NAME),
+                        entries,
+                        false,
+                    )),
+                    false,
+                ))
+            },
+            #[cfg(feature = "dtype-extension")]
+            Extension(ext) => Ok(ArrowDataType::Extension(Box::new(
+                arrow::datatypes::ExtensionType {
+                    name: PlSmallStr::from_str(ext.name()),
+                    inner: ext.storage_dtype().try_to_arrow(compat_level)?,
+                    metadata: ext.metadata().map(PlSmallStr::from_str),
+                },
+            ))),
             BinaryOffset => Ok(ArrowDataType::LargeBinary),
             Unknown(kind) => {
                 let dt = match kind {
@@ -880,6 +1106,8 @@ impl DataType {
             Array(field, _) => field.is_nested_null(),
             #[cfg(feature = "dtype-struct")]
             Struct(fields) => fields.iter().all(|fld| fld.dtype.is_nested_null()),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => key.is_nested_null() && value.is_nested_null(),
             _ => false,
         }
     }
@@ -905,9 +1133,15 @@ impl DataType {
                 }
                 Ok(must_cast)
             },
+            #[cfg(feature = "dtype-map")]
+            (DataType::Map(lk, lv), DataType::Map(rk, rv)) => {
+                Ok(lk.matches_schema_type(rk)? | lv.matches_schema_type(rv)?)
+            },
             (DataType::Null, DataType::Null) => Ok(false),
             #[cfg(feature = "dtype-decimal")]
             (DataType::Decimal(_, s1), DataType::Decimal(_, s2)) => Ok(s1 != s2),
+            #[cfg(feature = "dtype-decimal256")]
+            (DataType::Decimal256(_, s1), DataType::Decimal256(_, s2)) => Ok(s1 != s2),
             // We don't allow the other way around, only if our current type is
             // null and the schema isn't we allow it.
             (DataType::Null, _) => Ok(true),
@@ -933,6 +1167,8 @@ impl Display for DataType {
             DataType::Int32 => "i32",
             DataType::Int64 => "i64",
             DataType::Int128 => "i128",
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => "f16",
             DataType::Float32 => "f32",
             DataType::Float64 => "f64",
             #[cfg(feature = "dtype-decimal")]
@@ -945,6 +1181,16 @@ impl Display for DataType {
                     _ => f.write_str("decimal[?]"), // shouldn't happen
                 };
             },
+            #[cfg(feature = "dtype-decimal256")]
+            DataType::Decimal256(precision, scale) => {
+                return match (precision, scale) {
+                    (Some(precision), Some(scale)) => {
+                        f.write_str(&format!("decimal256[{precision},{scale}]"))
+                    },
+                    (None, Some(scale)) => f.write_str(&format!("decimal256[*,{scale}]")),
+                    _ => f.write_str("decimal256[?]"), // shouldn't happen
+                };
+            },
             DataType::String => "str",
             DataType::Binary => "binary",
             DataType::Date => "date",
@@ -956,6 +1202,12 @@ impl Display for DataType {
                 return f.write_str(&s);
             },
             DataType::Duration(tu) => return write!(f, "duration[{tu}]"),
+            #[cfg(feature = "dtype-interval")]
+            DataType::Interval => "interval",
+            #[cfg(feature = "dtype-uuid")]
+            DataType::Uuid => "uuid",
+            #[cfg(feature = "dtype-variant")]
+            DataType::Variant => "variant",
             DataType::Time => "time",
             #[cfg(feature = "dtype-array")]
             DataType::Array(_, _) => {
@@ -978,6 +1230,10 @@ impl Display for DataType {
             DataType::Enum(_, _) => "enum",
             #[cfg(feature = "dtype-struct")]
             DataType::Struct(fields) => return write!(f, "struct[{}]", fields.len()),
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(key, value) => return write!(f, "map[{key}, {value}]"),
+            #[cfg(feature = "dtype-extension")]
+            DataType::Extension(ext) => return write!(f, "extension[{}]", ext.name()),
             DataType::Unknown(kind) => match kind {
                 UnknownKind::Any => "unknown",
                 UnknownKind::Int(_) => "dyn int",
@@ -0,0 +1,180 @@
+//! User defined extension types.
+//!
+//! An extension type gives a column of some storage [`DataType`] its own identity, e.g. a UUID
+//! stored as binary or a JSON document stored as a string. In Arrow the type is stored as an
+//! [`ArrowDataType::Extension`] with the name and the serialized metadata of the type, which
+//! allows it to survive IPC and Parquet round trips.
+//!
+//! Arrow extension types are read as [`UnresolvedExtensionType`]s, and resolved by name through
+//! an [`ExtensionRegistry`].
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use polars_utils::aliases::PlHashMap;
+
+use super::*;
+use crate::chunked_array::cast::CastOptions;
+use crate::prelude::*;
+
+/// Describes a user defined extension type.
+pub trait PolarsExtensionType: Send + Sync + Debug {
+    /// The name of the type. This identifies the type in the registry and in Arrow metadata.
+    fn name(&self) -> &str;
+
+    /// The serialized parameters of the type, if any.
+    fn metadata(&self) -> Option<&str> {
+        None
+    }
+
+    /// The [`DataType`] in which the values are stored.
+    fn storage_dtype(&self) -> &DataType;
+
+    /// Format a single value of the storage type for display.
+    fn fmt_value(&self, value: AnyValue<'_>) -> String {
+        value.str_value().into_owned()
+    }
+
+    /// Cast a [`Series`] into the storage type of this extension type.
+    ///
+    /// This is called when a [`Series`] is cast to this extension type, and can be used to
+    /// validate or parse the incoming values.
+    fn cast_to_storage(&self, s: &Series, options: CastOptions) -> PolarsResult<Series> {
+        s.cast_with_options(self.storage_dtype(), options)
+    }
+
+    /// Cast the storage of a [`Series`] of this extension type to `dtype`.
+    fn cast_from_storage(
+        &self,
+        storage: &Series,
+        dtype: &DataType,
+        options: CastOptions,
+    ) -> PolarsResult<Series> {
+        storage.cast_with_options(dtype, options)
+    }
+
+    /// Whether values of this type can be ordered. Equality is always defined by the storage.
+    fn supports_ordering(&self) -> bool {
+        false
+    }
+}
+
+pub type ExtensionTypeRef = Arc<dyn PolarsExtensionType>;
+
+/// Takes the storage [`DataType`] and the metadata and constructs the extension type.
+pub type ExtensionTypeConstructor =
+    Arc<dyn Fn(&DataType, Option<&str>) -> PolarsResult<ExtensionTypeRef> + Send + Sync>;
+
+/// The extension types that can be resolved, by name.
+///
+/// Arrow extension types are read as [`UnresolvedExtensionType`]s. A registry resolves these
+/// into the types registered under their names, so that every part of an application can use
+/// its own set of types.
+#[derive(Clone, Default)]
+pub struct ExtensionRegistry {
+    constructors: PlHashMap<PlSmallStr, ExtensionTypeConstructor>,
+}
+
+impl ExtensionRegistry {
+    /// Register an extension type under `name`, replacing any earlier registration.
+    pub fn register(&mut self, name: PlSmallStr, constructor: ExtensionTypeConstructor) {
+        self.constructors.insert(name, constructor);
+    }
+
+    /// Remove the extension type registered under `name`. Returns whether it was registered.
+    pub fn unregister(&mut self, name: &str) -> bool {
+        self.constructors.remove(name).is_some()
+    }
+
+    /// Whether an extension type is registered under `name`.
+    pub fn contains(&self, name: &str) -> bool {
+        self.constructors.contains_key(name)
+    }
+
+    /// Construct the extension type registered under `name`. Returns `None` if no type is
+    /// registered under that name.
+    pub fn get(
+        &self,
+        name: &str,
+        storage: &DataType,
+        metadata: Option<&str>,
+    ) -> Option<PolarsResult<ExtensionTypeRef>> {
+        let constructor = self.constructors.get(name)?;
+        let out = constructor(storage, metadata).and_then(|ext| {
+            polars_ensure!(
+                ext.name() == name && ext.storage_dtype() == storage,
+                ComputeError: "extension type '{}' cannot be stored as {}", name, storage
+            );
+            Ok(ext)
+        });
+        Some(out)
+    }
+
+    /// Resolve the extension type of `s` if it is registered. Other series are returned as is.
+    pub fn resolve_series(&self, s: &Series) -> PolarsResult<Series> {
+        let DataType::Extension(ext) = s.dtype() else {
+            return Ok(s.clone());
+        };
+        let storage = s.extension()?.storage();
+        match self.get(ext.name(), storage.dtype(), ext.metadata()) {
+            Some(ext) => Ok(ExtensionChunked::new(storage.clone(), ext?)?.into_series()),
+            None => Ok(s.clone()),
+        }
+    }
+
+    /// Resolve the extension types of the columns of `df` that are registered.
+    pub fn resolve_frame(&self, df: &DataFrame) -> PolarsResult<DataFrame> {
+        let columns = df
+            .get_columns()
+            .iter()
+            .map(|c| {
+                self.resolve_series(c.as_materialized_series())
+                    .map(Column::from)
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        // SAFETY: the names and lengths of the columns are unchanged.
+        Ok(unsafe { DataFrame::new_no_checks(df.height(), columns) })
+    }
+}
+
+/// An extension type read from Arrow that is not resolved by an [`ExtensionRegistry`].
+///
+/// It keeps the name and the metadata of the type, so it is written back unchanged, and
+/// otherwise behaves as its storage type.
+#[derive(Debug)]
+pub struct UnresolvedExtensionType {
+    name: PlSmallStr,
+    metadata: Option<String>,
+    storage: DataType,
+}
+
+impl PolarsExtensionType for UnresolvedExtensionType {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn metadata(&self) -> Option<&str> {
+        self.metadata.as_deref()
+    }
+
+    fn storage_dtype(&self) -> &DataType {
+        &self.storage
+    }
+}
+
+pub(crate) fn unresolved_extension_type(
+    name: &str,
+    storage: DataType,
+    metadata: Option<&str>,
+) -> ExtensionTypeRef {
+    Arc::new(UnresolvedExtensionType {
+        name: PlSmallStr::from_str(name),
+        metadata: metadata.map(|md| md.to_string()),
+        storage,
+    })
+}
+
+pub(crate) fn extension_eq(lhs: &dyn PolarsExtensionType, rhs: &dyn PolarsExtensionType) -> bool {
+    lhs.name() == rhs.name()
+        && lhs.metadata() == rhs.metadata()
+        && lhs.storage_dtype() == rhs.storage_dtype()
+}
@@ -160,6 +160,9 @@ impl DataType {
             #[cfg(feature = "dtype-i128")]
             ArrowDataType::Int128 => DataType::Int128,
             ArrowDataType::Boolean => DataType::Boolean,
+            #[cfg(feature = "dtype-f16")]
+            ArrowDataType::Float16 => DataType::Float16,
+            #[cfg(not(feature = "dtype-f16"))]
             ArrowDataType::Float16 => DataType::Float32,
             ArrowDataType::Float32 => DataType::Float32,
             ArrowDataType::Float64 => DataType::Float64,
@@ -231,10 +234,50 @@ impl DataType {
                     panic!("activate the 'object' feature to be able to load POLARS_EXTENSION_TYPE")
                 }
             },
+            #[cfg(feature = "dtype-uuid")]
+            ArrowDataType::Extension(ext)
+                if ext.name.as_str() == UUID_EXTENSION_NAME
+                    && ext.inner == ArrowDataType::FixedSizeBinary(16) =>
+            {
+                DataType::Uuid
+            },
+            #[cfg(feature = "dtype-variant")]
+            ArrowDataType::Extension(ext) if ext.name.as_str() == VARIANT_EXTENSION_NAME => {
+                DataType::Variant
+            },
+            ArrowDataType::Extension(ext) => {
+                // The field metadata belongs to the extension type, not to its storage.
+                let storage = Self::from_arrow(&ext.inner, bin_to_view, None);
+                #[cfg(feature = "dtype-extension")]
+                {
+                    DataType::Extension(unresolved_extension_type(
+                        &ext.name,
+                        storage,
+                        ext.metadata.as_deref(),
+                    ))
+                }
+                #[cfg(not(feature = "dtype-extension"))]
+                {
+                    storage
+                }
+            },
             #[cfg(feature = "dtype-decimal")]
             ArrowDataType::Decimal(precision, scale) => {
                 DataType::Decimal(Some(*precision), Some(*scale))
             },
+            #[cfg(feature = "dtype-decimal256")]
+            ArrowDataType::Decimal256(precision, scale) => {
+                DataType::Decimal256(Some(*precision), Some(*scale))
+            },
+            // Without 256-bit support, narrow decimals of at most 38 digits on load.
+            #[cfg(all(feature = "dtype-decimal", not(feature = "dtype-decimal256")))]
+            ArrowDataType::Decimal256(precision, scale) => {
+                DataType::Decimal(Some(*precision), Some(*scale))
+            },
+            #[cfg(feature = "dtype-interval")]
+            ArrowDataType::Interval(arrow::datatypes::IntervalUnit::MonthDayNano) => {
+                DataType::Interval
+            },
             ArrowDataType::Utf8View | ArrowDataType::LargeUtf8 | ArrowDataType::Utf8 => {
                 DataType::String
             },
@@ -247,6 +290,15 @@ impl DataType {
                 }
             },
             ArrowDataType::FixedSizeBinary(_) => DataType::Binary,
+            #[cfg(feature = "dtype-map")]
+            ArrowDataType::Map(inner, _is_sorted) => match inner.dtype() {
+                ArrowDataType::Struct(fields) if fields.len() == 2 => DataType::Map(
+                    Self::from_arrow_field(&fields[0]).boxed(),
+                    Self::from_arrow_field(&fields[1]).boxed(),
+                ),
+                _ => DataType::List(Self::from_arrow_field(inner).boxed()),
+            },
+            #[cfg(not(feature = "dtype-map"))]
             ArrowDataType::Map(inner, _is_sorted) => {
                 DataType::List(Self::from_arrow_field(inner).boxed())
             },
@@ -11,6 +11,8 @@ mod _serde;
 mod aliases;
 mod any_value;
 mod dtype;
+#[cfg(feature = "dtype-extension")]
+mod extension;
 mod field;
 mod into_scalar;
 #[cfg(feature = "object")]
@@ -31,8 +33,16 @@ use arrow::datatypes::IntegerType;
 pub use arrow::datatypes::reshape::*;
 pub use arrow::datatypes::{ArrowDataType, TimeUnit as ArrowTimeUnit};
 use arrow::types::NativeType;
+#[cfg(feature = "dtype-f16")]
+pub use arrow::types::f16;
+#[cfg(feature = "dtype-decimal256")]
+pub use arrow::types::i256;
+#[cfg(feature = "dtype-interval")]
+pub use arrow::types::months_days_ns;
 use bytemuck::Zeroable;
 pub use dtype::*;
+#[cfg(feature = "dtype-extension")]
+pub use extension::*;
 pub use field::*;
 pub use into_scalar::*;
 use num_traits::{AsPrimitive, Bounded, FromPrimitive, Num, NumCast, One, Zero};
@@ -212,8 +222,16 @@ impl_polars_datatype!(BooleanType, Boolean, BooleanArray, 'a, bool, bool, bool,
 
 #[cfg(feature = "dtype-decimal")]
 impl_polars_datatype_pass_dtype!(DecimalType, DataType::Unknown(UnknownKind::Any), PrimitiveArray<i128>, 'a, i128, i128, i128, FalseT, TrueT);
+#[cfg(feature = "dtype-decimal256")]
+impl_polars_datatype_pass_dtype!(Decimal256Type, DataType::Unknown(UnknownKind::Any), BinaryViewArray, 'a, &'a [u8], Option<&'a [u8]>, Box<[u8]>, TrueT, TrueT);
 impl_polars_datatype_pass_dtype!(DatetimeType, DataType::Unknown(UnknownKind::Any), PrimitiveArray<i64>, 'a, i64, i64, i64, FalseT, TrueT);
 impl_polars_datatype_pass_dtype!(DurationType, DataType::Unknown(UnknownKind::Any), PrimitiveArray<i64>, 'a, i64, i64, i64, FalseT, TrueT);
+#[cfg(feature = "dtype-interval")]
+impl_polars_datatype!(IntervalType, Interval, PrimitiveArray<i128>, 'a, i128, i128, i128, TrueT);
+#[cfg(feature = "dtype-f16")]
+impl_polars_datatype!(Float16Type, Float16, PrimitiveArray<u16>, 'a, u16, u16, u16, TrueT);
+#[cfg(feature = "dtype-variant")]
+impl_polars_datatype_pass_dtype!(VariantType, DataType::Variant, BinaryViewArray, 'a, &'a [u8], Option<&'a [u8]>, Box<[u8]>, TrueT, TrueT);
 impl_polars_datatype_pass_dtype!(CategoricalType, DataType::Unknown(UnknownKind::Any), PrimitiveArray<u32>, 'a, u32, u32, u32, FalseT, TrueT);
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -262,6 +280,45 @@ unsafe impl PolarsDataType for StructType {
     }
 }
 
+#[cfg(feature = "dtype-map")]
+pub struct MapType {}
+#[cfg(feature = "dtype-map")]
+unsafe impl PolarsDataType for MapType {
+    type Physical<'a> = Box<dyn Array>;
+    type OwnedPhysical = Box<dyn Array>;
+    type ZeroablePhysical<'a> = Option<Box<dyn Array>>;
+    type Array = ListArray<i64>;
+    type IsNested = TrueT;
+    type HasViews = FalseT;
+    type IsStruct = FalseT;
+    type IsObject = FalseT;
+    type IsLogical = TrueT;
+
+    fn get_dtype() -> DataType {
+        // Null as we cannot know anything without self.
+        DataType::Map(Box::new(DataType::Null), Box::new(DataType::Null))
+    }
+}
+
+#[cfg(feature = "dtype-uuid")]
+pub struct UuidType {}
+#[cfg(feature = "dtype-uuid")]
+unsafe impl PolarsDataType for UuidType {
+    type Physical<'a> = Box<dyn Array>;
+    type OwnedPhysical = Box<dyn Array>;
+    type ZeroablePhysical<'a> = Option<Box<dyn Array>>;
+    type Array = FixedSizeListArray;
+    type IsNested = TrueT;
+    type HasViews = FalseT;
+    type IsStruct = FalseT;
+    type IsObject = FalseT;
+    type IsLogical = TrueT;
+
+    fn get_dtype() -> DataType {
+        DataType::Uuid
+    }
+}
+
 #[cfg(feature = "dtype-array")]
 pub struct FixedSizeListType {}
 #[cfg(feature = "dtype-array")]
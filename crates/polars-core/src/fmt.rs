@@ -243,6 +243,42 @@ fn format_object_array(
     }
 }
 
+#[cfg(feature = "dtype-extension")]
+fn format_extension_array(
+    f: &mut Formatter<'_>,
+    s: &Series,
+    name: &str,
+    array_type: &str,
+) -> fmt::Result {
+    write!(
+        f,
+        "shape: ({},)\n{}: '{}' [{}]\n[\n",
+        fmt_int_string_custom(&s.len().to_string(), 3, "_"),
+        array_type,
+        name,
+        s.dtype()
+    )?;
+    let limit = get_row_limit();
+    let ellipsis = get_ellipsis();
+    let write_row = |f: &mut Formatter<'_>, i| writeln!(f, "\t{}", s.str_value(i).unwrap());
+    if s.len() > limit {
+        let half = limit / 2;
+        let rest = limit % 2;
+        for i in 0..(half + rest) {
+            write_row(f, i)?;
+        }
+        writeln!(f, "\t{ellipsis}")?;
+        for i in (s.len() - half)..s.len() {
+            write_row(f, i)?;
+        }
+    } else {
+        for i in 0..s.len() {
+            write_row(f, i)?;
+        }
+    }
+    write!(f, "]")
+}
+
 impl<T> Debug for ChunkedArray<T>
 where
     T: PolarsNumericType,
@@ -365,6 +401,10 @@ impl Debug for Series {
                     format_array!(f, self.i128().unwrap(), "i128", self.name(), "Series")
                 )
             },
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => {
+                format_array!(f, self.f16().unwrap(), "f16", self.name(), "Series")
+            },
             DataType::Float32 => {
                 format_array!(f, self.f32().unwrap(), "f32", self.name(), "Series")
             },
@@ -385,11 +425,34 @@ impl Debug for Series {
                 let dt = format!("{}", self.dtype());
                 format_array!(f, self.duration().unwrap(), &dt, self.name(), "Series")
             },
+            #[cfg(feature = "dtype-interval")]
+            DataType::Interval => {
+                format_array!(
+                    f,
+                    self.interval().unwrap(),
+                    "interval",
+                    self.name(),
+                    "Series"
+                )
+            },
+            #[cfg(feature = "dtype-uuid")]
+            DataType::Uuid => {
+                format_array!(f, self.uuid().unwrap(), "uuid", self.name(), "Series")
+            },
             #[cfg(feature = "dtype-decimal")]
             DataType::Decimal(_, _) => {
                 let dt = format!("{}", self.dtype());
                 format_array!(f, self.decimal().unwrap(), &dt, self.name(), "Series")
             },
+            #[cfg(feature = "dtype-decimal256")]
+            DataType::Decimal256(_, _) => {
+                let dt = format!("{}", self.dtype());
+                format_array!(f, self.decimal256().unwrap(), &dt, self.name(), "Series")
+            },
+            #[cfg(feature = "dtype-variant")]
+            DataType::Variant => {
+                format_array!(f, self.variant().unwrap(), "variant", self.name(), "Series")
+            },
             #[cfg(feature = "dtype-array")]
             DataType::Array(_, _) => {
                 let dt = format!("{}", self.dtype());
@@ -401,6 +464,8 @@ impl Debug for Series {
             },
             #[cfg(feature = "object")]
             DataType::Object(_) => format_object_array(f, self, self.name(), "Series"),
+            #[cfg(feature = "dtype-extension")]
+            DataType::Extension(_) => format_extension_array(f, self, self.name(), "Series"),
             #[cfg(feature = "dtype-categorical")]
             DataType::Categorical(_, _) => {
                 format_array!(f, self.categorical().unwrap(), "cat", self.name(), "Series")
@@ -1052,6 +1117,67 @@ pub fn fmt_duration_string<W: Write>(f: &mut W, v: i64, unit: TimeUnit) -> fmt::
     Ok(())
 }
 
+#[cfg(feature = "dtype-uuid")]
+pub fn fmt_uuid_string<W: Write>(f: &mut W, v: u128) -> fmt::Result {
+    // the hyphenated form, eg: "67e55044-10b1-426f-9247-bb680e5fe0c8"
+    write!(
+        f,
+        "{:08x}-{:04x}-{:04x}-{:04x}-{:012x}",
+        v >> 96,
+        (v >> 80) & 0xFFFF,
+        (v >> 64) & 0xFFFF,
+        (v >> 48) & 0xFFFF,
+        v & 0xFFFF_FFFF_FFFF
+    )
+}
+
+#[cfg(feature = "dtype-decimal256")]
+pub fn fmt_decimal256_string<W: Write>(
+    f: &mut W,
+    v: ethnum::I256,
+    scale: usize,
+    trim_zeros: bool,
+) -> fmt::Result {
+    // the digits of the mantissa with the decimal point inserted, eg: "-0.0120"
+    if v.is_negative() {
+        f.write_char('-')?;
+    }
+    let digits = format!("{:0>width$}", v.unsigned_abs(), width = scale + 1);
+    let (int, frac) = digits.split_at(digits.len() - scale);
+    f.write_str(int)?;
+    let frac = if trim_zeros {
+        frac.trim_end_matches('0')
+    } else {
+        frac
+    };
+    if !frac.is_empty() {
+        f.write_char('.')?;
+        f.write_str(frac)?;
+    }
+    Ok(())
+}
+
+#[cfg(feature = "dtype-interval")]
+pub fn fmt_interval_string<W: Write>(f: &mut W, v: months_days_ns) -> fmt::Result {
+    // write the calendar parts, followed by the nanoseconds formatted
+    // as a duration, eg: "1mo 2d 3h 15m"
+    let mut buffer = itoa::Buffer::new();
+    let mut sep = "";
+    for (value, suffix) in [(v.months(), "mo"), (v.days(), "d")] {
+        if value != 0 {
+            f.write_str(sep)?;
+            f.write_str(buffer.format(value))?;
+            f.write_str(suffix)?;
+            sep = " ";
+        }
+    }
+    if v.ns() != 0 || sep.is_empty() {
+        f.write_str(sep)?;
+        fmt_duration_string(f, v.ns(), TimeUnit::Nanoseconds)?;
+    }
+    Ok(())
+}
+
 #[cfg(feature = "dtype-duration")]
 pub fn iso_duration_string(s: &mut String, mut v: i64, unit: TimeUnit) {
     if v == 0 {
@@ -1159,6 +1285,8 @@ impl Display for AnyValue<'_> {
             AnyValue::Int32(v) => fmt_integer(f, width, *v),
             AnyValue::Int64(v) => fmt_integer(f, width, *v),
             AnyValue::Int128(v) => feature_gated!("dtype-i128", fmt_integer(f, width, *v)),
+            #[cfg(feature = "dtype-f16")]
+            AnyValue::Float16(v) => fmt_float(f, width, v.to_f32()),
             AnyValue::Float32(v) => fmt_float(f, width, *v),
             AnyValue::Float64(v) => fmt_float(f, width, *v),
             AnyValue::Boolean(v) => write!(f, "{}", *v),
@@ -1206,6 +1334,16 @@ impl Display for AnyValue<'_> {
             AnyValue::StructOwned(payload) => fmt_struct(f, &payload.0),
             #[cfg(feature = "dtype-decimal")]
             AnyValue::Decimal(v, scale) => fmt_decimal(f, *v, *scale),
+            #[cfg(feature = "dtype-interval")]
+            AnyValue::Interval(v) => fmt_interval_string(f, *v),
+            #[cfg(feature = "dtype-uuid")]
+            AnyValue::Uuid(v) => fmt_uuid_string(f, *v),
+            #[cfg(feature = "dtype-decimal256")]
+            AnyValue::Decimal256(v, scale) => fmt_decimal256(f, v.0, *scale),
+            #[cfg(feature = "dtype-variant")]
+            AnyValue::Variant(v) => fmt_variant(f, v),
+            #[cfg(feature = "dtype-variant")]
+            AnyValue::VariantOwned(v) => fmt_variant(f, v),
         }
     }
 }
@@ -1303,6 +1441,22 @@ fn fmt_decimal(f: &mut Formatter<'_>, v: i128, scale: usize) -> fmt::Result {
     f.write_str(fmt_float_string(fmt_buf.format(v, scale, trim_zeros)).as_str())
 }
 
+#[inline]
+#[cfg(feature = "dtype-variant")]
+fn fmt_variant(f: &mut Formatter<'_>, v: &[u8]) -> fmt::Result {
+    match crate::chunked_array::logical::variant_to_json(v) {
+        Some(json) => write!(f, "{json}"),
+        None => f.write_str("<invalid variant>"),
+    }
+}
+
+#[cfg(feature = "dtype-decimal256")]
+fn fmt_decimal256(f: &mut Formatter<'_>, v: ethnum::I256, scale: usize) -> fmt::Result {
+    let mut buf = String::new();
+    fmt_decimal256_string(&mut buf, v, scale, get_trim_decimal_zeros())?;
+    f.write_str(fmt_float_string(&buf).as_str())
+}
+
 #[cfg(all(
     test,
     feature = "temporal",
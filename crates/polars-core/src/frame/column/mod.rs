@@ -298,6 +298,30 @@ impl Column {
     pub fn try_decimal(&self) -> Option<&DecimalChunked> {
         self.as_materialized_series().try_decimal()
     }
+    #[cfg(feature = "dtype-decimal256")]
+    pub fn try_decimal256(&self) -> Option<&Decimal256Chunked> {
+        self.as_materialized_series().try_decimal256()
+    }
+    #[cfg(feature = "dtype-interval")]
+    pub fn try_interval(&self) -> Option<&IntervalChunked> {
+        self.as_materialized_series().try_interval()
+    }
+    #[cfg(feature = "dtype-uuid")]
+    pub fn try_uuid(&self) -> Option<&UuidChunked> {
+        self.as_materialized_series().try_uuid()
+    }
+    #[cfg(feature = "dtype-variant")]
+    pub fn try_variant(&self) -> Option<&VariantChunked> {
+        self.as_materialized_series().try_variant()
+    }
+    #[cfg(feature = "dtype-f16")]
+    pub fn try_f16(&self) -> Option<&Float16Chunked> {
+        self.as_materialized_series().try_f16()
+    }
+    #[cfg(feature = "dtype-extension")]
+    pub fn try_extension(&self) -> Option<&ExtensionChunked> {
+        self.as_materialized_series().try_extension()
+    }
     #[cfg(feature = "dtype-array")]
     pub fn try_array(&self) -> Option<&ArrayChunked> {
         self.as_materialized_series().try_array()
@@ -380,6 +404,34 @@ impl Column {
     pub fn decimal(&self) -> PolarsResult<&DecimalChunked> {
         self.as_materialized_series().decimal()
     }
+    #[cfg(feature = "dtype-decimal256")]
+    pub fn decimal256(&self) -> PolarsResult<&Decimal256Chunked> {
+        self.as_materialized_series().decimal256()
+    }
+    #[cfg(feature = "dtype-interval")]
+    pub fn interval(&self) -> PolarsResult<&IntervalChunked> {
+        self.as_materialized_series().interval()
+    }
+    #[cfg(feature = "dtype-uuid")]
+    pub fn uuid(&self) -> PolarsResult<&UuidChunked> {
+        self.as_materialized_series().uuid()
+    }
+    #[cfg(feature = "dtype-variant")]
+    pub fn variant(&self) -> PolarsResult<&VariantChunked> {
+        self.as_materialized_series().variant()
+    }
+    #[cfg(feature = "dtype-f16")]
+    pub fn f16(&self) -> PolarsResult<&Float16Chunked> {
+        self.as_materialized_series().f16()
+    }
+    #[cfg(feature = "dtype-extension")]
+    pub fn extension(&self) -> PolarsResult<&ExtensionChunked> {
+        self.as_materialized_series().extension()
+    }
+    #[cfg(feature = "dtype-map")]
+    pub fn map_(&self) -> PolarsResult<&MapChunked> {
+        self.as_materialized_series().map_()
+    }
     #[cfg(feature = "dtype-array")]
     pub fn array(&self) -> PolarsResult<&ArrayChunked> {
         self.as_materialized_series().array()
@@ -1461,6 +1513,13 @@ impl Column {
     }
 
     pub(crate) fn str_value(&self, index: usize) -> PolarsResult<Cow<str>> {
+        #[cfg(feature = "dtype-extension")]
+        if let DataType::Extension(ext) = self.dtype() {
+            return Ok(Cow::Owned(match self.get(index)? {
+                AnyValue::Null => "null".to_string(),
+                av => ext.fmt_value(av),
+            }));
+        }
         Ok(self.get(index)?.str_value())
     }
 
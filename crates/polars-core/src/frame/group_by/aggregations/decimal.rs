@@ -0,0 +1,85 @@
+use arrow::legacy::is_valid::IsValid;
+
+use super::*;
+use crate::chunked_array::arithmetic::_get_decimal_scale_div;
+use crate::chunked_array::ops::decimal::DecimalMoments;
+
+/// Aggregate the running sums of every group, `f` turns them into the output mantissa.
+///
+/// Group aggregations are infallible, so a group whose result overflows the decimal
+/// precision becomes null.
+unsafe fn agg_moments<F>(
+    ca: &DecimalChunked,
+    groups: &GroupsType,
+    precision: Option<usize>,
+    scale: usize,
+    f: F,
+) -> Series
+where
+    F: Fn(&DecimalMoments) -> PolarsResult<Option<i128>> + Send + Sync,
+{
+    // Prevent a rechunk for every individual group.
+    let phys = ca.physical().rechunk();
+    let arr = phys.downcast_as_array();
+    let moments = |idx: &mut dyn Iterator<Item = usize>| {
+        let mut moments = DecimalMoments::new();
+        for i in idx {
+            if arr.is_valid_unchecked(i) {
+                moments.insert(arr.value_unchecked(i));
+            }
+        }
+        f(&moments).ok().flatten()
+    };
+    let out = match groups {
+        GroupsType::Idx(groups) => _agg_helper_idx::<Int128Type, _>(groups, |(_, idx)| {
+            debug_assert!(idx.len() <= arr.len());
+            moments(&mut idx.iter().map(|i| *i as usize))
+        }),
+        GroupsType::Slice { groups, .. } => {
+            _agg_helper_slice::<Int128Type, _>(groups, |[first, len]| {
+                debug_assert!(first + len <= arr.len() as IdxSize);
+                moments(&mut (first as usize..(first + len) as usize))
+            })
+        },
+    };
+    out.i128()
+        .unwrap()
+        .clone()
+        .into_decimal_unchecked(precision, scale)
+        .into_series()
+}
+
+impl DecimalChunked {
+    pub(crate) unsafe fn agg_sum(&self, groups: &GroupsType) -> Series {
+        agg_moments(self, groups, self.precision(), self.scale(), |m| {
+            m.sum().map(Some)
+        })
+    }
+
+    pub(crate) unsafe fn agg_mean(&self, groups: &GroupsType) -> Series {
+        let scale = self.scale();
+        let out_scale = _get_decimal_scale_div(scale);
+        let mode = RoundMode::default();
+        agg_moments(self, groups, None, out_scale, |m| {
+            m.mean(scale, out_scale, mode)
+        })
+    }
+
+    pub(crate) unsafe fn agg_var(&self, groups: &GroupsType, ddof: u8) -> Series {
+        let scale = self.scale();
+        let out_scale = _get_decimal_scale_div(scale);
+        let mode = RoundMode::default();
+        agg_moments(self, groups, None, out_scale, |m| {
+            m.var(scale, out_scale, ddof, mode)
+        })
+    }
+
+    pub(crate) unsafe fn agg_std(&self, groups: &GroupsType, ddof: u8) -> Series {
+        let scale = self.scale();
+        let out_scale = _get_decimal_scale_div(scale);
+        let mode = RoundMode::default();
+        agg_moments(self, groups, None, out_scale, |m| {
+            m.std(scale, out_scale, ddof, mode)
+        })
+    }
+}
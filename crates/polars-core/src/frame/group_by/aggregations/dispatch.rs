@@ -133,6 +133,13 @@ impl Series {
         use DataType::*;
         match s.dtype() {
             Boolean => s.cast(&Float64).unwrap().agg_mean(groups),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => SeriesWrap(s.f16().unwrap().to_float32())
+                .agg_mean(groups)
+                .f32()
+                .unwrap()
+                .to_float16()
+                .into_series(),
             Float32 => SeriesWrap(s.f32().unwrap().clone()).agg_mean(groups),
             Float64 => SeriesWrap(s.f64().unwrap().clone()).agg_mean(groups),
             dt if dt.is_primitive_numeric() => apply_method_physical_integer!(s, agg_mean, groups),
@@ -169,6 +176,8 @@ impl Series {
                 * (MS_IN_DAY as f64))
                 .cast(&Datetime(TimeUnit::Milliseconds, None))
                 .unwrap(),
+            #[cfg(feature = "dtype-decimal")]
+            Decimal(_, _) => s.decimal().unwrap().agg_mean(groups),
             _ => Series::full_null(PlSmallStr::EMPTY, groups.len(), s.dtype()),
         }
     }
@@ -185,6 +194,13 @@ impl Series {
         use DataType::*;
         match s.dtype() {
             Boolean => s.cast(&Float64).unwrap().agg_median(groups),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => SeriesWrap(s.f16().unwrap().to_float32())
+                .agg_median(groups)
+                .f32()
+                .unwrap()
+                .to_float16()
+                .into_series(),
             Float32 => SeriesWrap(s.f32().unwrap().clone()).agg_median(groups),
             Float64 => SeriesWrap(s.f64().unwrap().clone()).agg_median(groups),
             dt if dt.is_primitive_numeric() => {
@@ -243,6 +259,16 @@ impl Series {
 
         use DataType::*;
         match s.dtype() {
+            #[cfg(feature = "dtype-f16")]
+            Float16 => s
+                .f16()
+                .unwrap()
+                .to_float32()
+                .agg_quantile(groups, quantile, method)
+                .f32()
+                .unwrap()
+                .to_float16()
+                .into_series(),
             Float32 => s.f32().unwrap().agg_quantile(groups, quantile, method),
             Float64 => s.f64().unwrap().agg_quantile(groups, quantile, method),
             dt if dt.is_primitive_numeric() || dt.is_temporal() => {
@@ -9,6 +9,13 @@ pub fn mean_reduce(value: Option<f64>, dtype: DataType) -> Scalar {
             let val = value.map(|m| m as f32);
             Scalar::new(dtype, val.into())
         },
+        #[cfg(feature = "dtype-f16")]
+        DataType::Float16 => {
+            let val = value.map_or(AnyValue::Null, |m| {
+                AnyValue::Float16(crate::datatypes::f16::from_f32(m as f32))
+            });
+            Scalar::new(dtype, val)
+        },
         dt if dt.is_primitive_numeric() || dt.is_decimal() || dt.is_bool() => {
             Scalar::new(DataType::Float64, value.into())
         },
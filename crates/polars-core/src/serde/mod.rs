@@ -38,6 +38,18 @@ mod test {
         assert!(ca.into_series().equals_missing(&out));
     }
 
+    #[test]
+    #[cfg(all(feature = "dtype-map", feature = "dtype-interval"))]
+    fn test_serde_map_interval_dtype() {
+        let dtype = DataType::Map(
+            Box::new(DataType::String),
+            Box::new(DataType::List(Box::new(DataType::Interval))),
+        );
+        let json = serde_json::to_string(&dtype).unwrap();
+        let out = serde_json::from_str::<DataType>(&json).unwrap();
+        assert_eq!(out, dtype);
+    }
+
     fn sample_dataframe() -> DataFrame {
         let s1 = Series::new("foo".into(), &[1, 2, 3]);
         let s2 = Series::new("bar".into(), &[Some(true), None, Some(false)]);
@@ -73,6 +73,11 @@ impl Series {
             let dtype = DataType::Decimal(None, None);
             return Self::from_any_values_and_dtype(name, values, &dtype, strict);
         }
+        #[cfg(feature = "dtype-decimal256")]
+        if dtype.is_decimal256() {
+            let dtype = DataType::Decimal256(None, None);
+            return Self::from_any_values_and_dtype(name, values, &dtype, strict);
+        }
 
         Self::from_any_values_and_dtype(name, values, &dtype, strict)
     }
@@ -130,7 +135,36 @@ impl Series {
             DataType::Decimal(precision, scale) => {
                 any_values_to_decimal(values, *precision, *scale, strict)?.into_series()
             },
+            #[cfg(feature = "dtype-decimal256")]
+            DataType::Decimal256(precision, scale) => {
+                any_values_to_decimal256(values, *precision, *scale, strict)?.into_series()
+            },
+            #[cfg(feature = "dtype-interval")]
+            DataType::Interval => any_values_to_interval(values, strict)?.into_series(),
+            #[cfg(feature = "dtype-uuid")]
+            DataType::Uuid => any_values_to_uuid(values, strict)?.into_series(),
+            #[cfg(feature = "dtype-variant")]
+            DataType::Variant => any_values_to_variant(values, strict)?.into_series(),
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => any_values_to_float16(values, strict)?.into_series(),
+            #[cfg(feature = "dtype-extension")]
+            DataType::Extension(ext) => {
+                let storage = Self::from_any_values_and_dtype(
+                    PlSmallStr::EMPTY,
+                    values,
+                    ext.storage_dtype(),
+                    strict,
+                )?;
+                ExtensionChunked::new_unchecked(storage, ext.clone()).into_series()
+            },
             DataType::List(inner) => any_values_to_list(values, inner, strict)?.into_series(),
+            #[cfg(feature = "dtype-map")]
+            dt @ DataType::Map(_, _) => {
+                let storage = dt.map_storage_dtype().unwrap();
+                any_values_to_list(values, storage.inner_dtype().unwrap(), strict)?
+                    .into_map()?
+                    .into_series()
+            },
             #[cfg(feature = "dtype-array")]
             DataType::Array(inner, size) => any_values_to_array(values, inner, strict, *size)?
                 .into_series()
@@ -198,6 +232,8 @@ fn any_values_to_f32(values: &[AnyValue], strict: bool) -> PolarsResult<Float32C
         for av in values {
             match av {
                 AnyValue::Float32(i) => builder.append_value(*i),
+                #[cfg(feature = "dtype-f16")]
+                AnyValue::Float16(i) => builder.append_value(i.to_f32()),
                 AnyValue::Null => builder.append_null(),
                 av => return Err(invalid_value_error(&DataType::Float32, av)),
             }
@@ -218,6 +254,8 @@ fn any_values_to_f64(values: &[AnyValue], strict: bool) -> PolarsResult<Float64C
             match av {
                 AnyValue::Float64(i) => builder.append_value(*i),
                 AnyValue::Float32(i) => builder.append_value(*i as f64),
+                #[cfg(feature = "dtype-f16")]
+                AnyValue::Float16(i) => builder.append_value(i.to_f32() as f64),
                 AnyValue::Null => builder.append_null(),
                 av => return Err(invalid_value_error(&DataType::Float64, av)),
             }
@@ -418,6 +456,83 @@ fn any_values_to_duration(
     Ok(builder.finish().into_duration(time_unit))
 }
 
+#[cfg(feature = "dtype-interval")]
+fn any_values_to_interval(values: &[AnyValue], strict: bool) -> PolarsResult<IntervalChunked> {
+    let mut builder = PrimitiveChunkedBuilder::<Int128Type>::new(PlSmallStr::EMPTY, values.len());
+    for av in values {
+        match av {
+            AnyValue::Interval(v) => builder.append_value(interval_to_i128(*v)),
+            AnyValue::Null => builder.append_null(),
+            av => {
+                if strict {
+                    return Err(invalid_value_error(&DataType::Interval, av));
+                }
+                builder.append_null()
+            },
+        }
+    }
+    Ok(builder.finish().into_interval())
+}
+
+#[cfg(feature = "dtype-uuid")]
+fn any_values_to_uuid(values: &[AnyValue], strict: bool) -> PolarsResult<UuidChunked> {
+    let mut uuids = Vec::with_capacity(values.len());
+    for av in values {
+        let opt_v = match av {
+            AnyValue::Uuid(v) => Some(*v),
+            AnyValue::Null => {
+                uuids.push(None);
+                continue;
+            },
+            AnyValue::String(s) => parse_uuid(s),
+            AnyValue::StringOwned(s) => parse_uuid(s),
+            AnyValue::Binary(b) => (*b).try_into().ok().map(u128::from_be_bytes),
+            AnyValue::BinaryOwned(b) => b.as_slice().try_into().ok().map(u128::from_be_bytes),
+            _ => None,
+        };
+        if opt_v.is_none() && strict {
+            return Err(invalid_value_error(&DataType::Uuid, av));
+        }
+        uuids.push(opt_v);
+    }
+    Ok(UuidChunked::from_uuids(PlSmallStr::EMPTY, uuids))
+}
+
+#[cfg(feature = "dtype-variant")]
+fn any_values_to_variant(values: &[AnyValue], strict: bool) -> PolarsResult<VariantChunked> {
+    let mut builder = BinaryChunkedBuilder::new(PlSmallStr::EMPTY, values.len());
+    for av in values {
+        match av {
+            AnyValue::Variant(v) => builder.append_value(v),
+            AnyValue::VariantOwned(v) => builder.append_value(v),
+            AnyValue::Null => builder.append_null(),
+            av => match any_value_to_variant(av) {
+                Some(v) => builder.append_value(v),
+                None if strict => return Err(invalid_value_error(&DataType::Variant, av)),
+                None => builder.append_null(),
+            },
+        }
+    }
+    Ok(builder.finish().into_variant_unchecked())
+}
+
+#[cfg(feature = "dtype-f16")]
+fn any_values_to_float16(values: &[AnyValue], strict: bool) -> PolarsResult<Float16Chunked> {
+    let mut builder = PrimitiveChunkedBuilder::<UInt16Type>::new(PlSmallStr::EMPTY, values.len());
+    for av in values {
+        match av {
+            AnyValue::Float16(v) => builder.append_value(v.to_bits()),
+            AnyValue::Null => builder.append_null(),
+            av => match av.extract::<f32>() {
+                Some(v) if !strict => builder.append_value(f16::from_f32(v).to_bits()),
+                _ if strict => return Err(invalid_value_error(&DataType::Float16, av)),
+                _ => builder.append_null(),
+            },
+        }
+    }
+    Ok(builder.finish().into_float16())
+}
+
 #[cfg(feature = "dtype-categorical")]
 fn any_values_to_categorical(
     values: &[AnyValue],
@@ -579,6 +694,46 @@ fn any_values_to_decimal(
     builder.finish().into_decimal(precision, scale)
 }
 
+#[cfg(feature = "dtype-decimal256")]
+fn any_values_to_decimal256(
+    values: &[AnyValue],
+    precision: Option<usize>,
+    scale: Option<usize>, // If None, we're inferring the scale.
+    strict: bool,
+) -> PolarsResult<Decimal256Chunked> {
+    let scale = scale.unwrap_or_else(|| {
+        values
+            .iter()
+            .filter_map(|av| match av {
+                AnyValue::Decimal256(_, scale) | AnyValue::Decimal(_, scale) => Some(*scale),
+                _ => None,
+            })
+            .max()
+            .unwrap_or(0)
+    });
+    let target_dtype = DataType::Decimal256(precision, Some(scale));
+
+    let mut out = Vec::with_capacity(values.len());
+    for av in values {
+        let opt_v = match av {
+            AnyValue::Null => None,
+            // Integers and decimals of equal or less scale are converted even in 'strict' mode.
+            av => match av.strict_cast(&target_dtype) {
+                Some(AnyValue::Decimal256(v, _)) => Some(v.0),
+                _ if strict => return Err(invalid_value_error(&target_dtype, av)),
+                _ => None,
+            },
+        };
+        out.push(opt_v);
+    }
+    Ok(Decimal256Chunked::from_i256s(
+        PlSmallStr::EMPTY,
+        out,
+        precision,
+        scale,
+    ))
+}
+
 fn any_values_to_list(
     avs: &[AnyValue],
     inner_type: &DataType,
@@ -641,8 +641,20 @@ impl Rem for &Series {
 
 // Series +-/* numbers instead of Series
 
+/// Half precision floats are computed in single precision, other logical types on their physical
+/// representation.
+fn to_arithmetic_repr(s: &Series) -> Cow<'_, Series> {
+    match s.dtype() {
+        #[cfg(feature = "dtype-f16")]
+        DataType::Float16 => Cow::Owned(s.f16().unwrap().to_float32().into_series()),
+        _ => s.to_physical_repr(),
+    }
+}
+
 fn finish_cast(inp: &Series, out: Series) -> Series {
     match inp.dtype() {
+        #[cfg(feature = "dtype-f16")]
+        DataType::Float16 => out.f32().unwrap().to_float16().into_series(),
         #[cfg(feature = "dtype-date")]
         DataType::Date => out.into_date(),
         #[cfg(feature = "dtype-datetime")]
@@ -662,7 +674,7 @@ where
     type Output = Series;
 
     fn sub(self, rhs: T) -> Self::Output {
-        let s = self.to_physical_repr();
+        let s = to_arithmetic_repr(self);
         macro_rules! sub {
             ($ca:expr) => {{ $ca.sub(rhs).into_series() }};
         }
@@ -690,7 +702,7 @@ where
     type Output = Series;
 
     fn add(self, rhs: T) -> Self::Output {
-        let s = self.to_physical_repr();
+        let s = to_arithmetic_repr(self);
         macro_rules! add {
             ($ca:expr) => {{ $ca.add(rhs).into_series() }};
         }
@@ -717,7 +729,7 @@ where
     type Output = Series;
 
     fn div(self, rhs: T) -> Self::Output {
-        let s = self.to_physical_repr();
+        let s = to_arithmetic_repr(self);
         macro_rules! div {
             ($ca:expr) => {{ $ca.div(rhs).into_series() }};
         }
@@ -741,7 +753,7 @@ where
 // TODO: remove this, temporary band-aid.
 impl Series {
     pub fn wrapping_trunc_div_scalar<T: Num + NumCast>(&self, rhs: T) -> Self {
-        let s = self.to_physical_repr();
+        let s = to_arithmetic_repr(self);
         macro_rules! div {
             ($ca:expr) => {{
                 let rhs = NumCast::from(rhs).unwrap();
@@ -761,7 +773,7 @@ where
     type Output = Series;
 
     fn mul(self, rhs: T) -> Self::Output {
-        let s = self.to_physical_repr();
+        let s = to_arithmetic_repr(self);
         macro_rules! mul {
             ($ca:expr) => {{ $ca.mul(rhs).into_series() }};
         }
@@ -788,7 +800,7 @@ where
     type Output = Series;
 
     fn rem(self, rhs: T) -> Self::Output {
-        let s = self.to_physical_repr();
+        let s = to_arithmetic_repr(self);
         macro_rules! rem {
             ($ca:expr) => {{ $ca.rem(rhs).into_series() }};
         }
@@ -859,7 +871,7 @@ where
         rhs + self
     }
     fn sub(self, rhs: &Series) -> Self::Output {
-        let s = rhs.to_physical_repr();
+        let s = to_arithmetic_repr(rhs);
         macro_rules! sub {
             ($rhs:expr) => {{ $rhs.lhs_sub(self).into_series() }};
         }
@@ -868,7 +880,7 @@ where
         finish_cast(rhs, out)
     }
     fn div(self, rhs: &Series) -> Self::Output {
-        let s = rhs.to_physical_repr();
+        let s = to_arithmetic_repr(rhs);
         macro_rules! div {
             ($rhs:expr) => {{ $rhs.lhs_div(self).into_series() }};
         }
@@ -881,7 +893,7 @@ where
         rhs * self
     }
     fn rem(self, rhs: &Series) -> Self::Output {
-        let s = rhs.to_physical_repr();
+        let s = to_arithmetic_repr(rhs);
         macro_rules! rem {
             ($rhs:expr) => {{ $rhs.lhs_rem(self).into_series() }};
         }
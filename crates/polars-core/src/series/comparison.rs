@@ -1,5 +1,7 @@
 //! Comparison operations on Series.
 
+use std::borrow::Cow;
+
 use polars_error::feature_gated;
 
 use crate::prelude::*;
@@ -54,8 +56,8 @@ macro_rules! impl_eq_compare {
                     SchemaMismatch: "could not evaluate comparison between series '{}' of dtype: {} and series '{}' of dtype: {}",
                     lhs.name(), lhs.dtype(), rhs.name(), rhs.dtype()
             ))?;
-        let lhs = lhs.to_physical_repr();
-        let rhs = rhs.to_physical_repr();
+        let lhs = to_comparable_repr(&lhs);
+        let rhs = to_comparable_repr(&rhs);
         let mut out = match lhs.dtype() {
             Null => lhs.null().unwrap().$method(rhs.null().unwrap()),
             Boolean => lhs.bool().unwrap().$method(rhs.bool().unwrap()),
@@ -101,6 +103,12 @@ macro_rules! impl_ineq_compare {
         use DataType::*;
         let (lhs, rhs) = ($self, $rhs);
         validate_types(lhs.dtype(), rhs.dtype())?;
+        if [lhs.dtype(), rhs.dtype()]
+            .iter()
+            .any(|dt| dt.is_extension() && !dt.is_ord())
+        {
+            bail_invalid_ineq!(lhs, rhs, $op);
+        }
 
         polars_ensure!(
             lhs.len() == rhs.len() ||
@@ -149,8 +157,8 @@ macro_rules! impl_ineq_compare {
                 rhs.name(), rhs.dtype()
             )
         )?;
-        let lhs = lhs.to_physical_repr();
-        let rhs = rhs.to_physical_repr();
+        let lhs = to_comparable_repr(&lhs);
+        let rhs = to_comparable_repr(&rhs);
         let mut out = match lhs.dtype() {
             Null => lhs.null().unwrap().$method(rhs.null().unwrap()),
             Boolean => lhs.bool().unwrap().$method(rhs.bool().unwrap()),
@@ -180,6 +188,18 @@ macro_rules! impl_ineq_compare {
     }};
 }
 
+/// The physical representation used for comparisons. Half precision floats are compared as
+/// single precision floats, as their bit patterns don't order like their values.
+fn to_comparable_repr(s: &Series) -> Cow<'_, Series> {
+    match s.dtype() {
+        #[cfg(feature = "dtype-f16")]
+        DataType::Float16 => Cow::Owned(s.f16().unwrap().to_float32().into_series()),
+        #[cfg(feature = "dtype-uuid")]
+        DataType::Uuid => Cow::Owned(s.uuid().unwrap().to_i128().into_series()),
+        _ => s.to_physical_repr(),
+    }
+}
+
 fn validate_types(left: &DataType, right: &DataType) -> PolarsResult<()> {
     use DataType::*;
 
@@ -254,25 +274,25 @@ where
 
     fn equal(&self, rhs: Rhs) -> Self::Item {
         validate_types(self.dtype(), &DataType::Int8)?;
-        let s = self.to_physical_repr();
+        let s = to_comparable_repr(self);
         Ok(apply_method_physical_numeric!(&s, equal, rhs))
     }
 
     fn equal_missing(&self, rhs: Rhs) -> Self::Item {
         validate_types(self.dtype(), &DataType::Int8)?;
-        let s = self.to_physical_repr();
+        let s = to_comparable_repr(self);
         Ok(apply_method_physical_numeric!(&s, equal_missing, rhs))
     }
 
     fn not_equal(&self, rhs: Rhs) -> Self::Item {
         validate_types(self.dtype(), &DataType::Int8)?;
-        let s = self.to_physical_repr();
+        let s = to_comparable_repr(self);
         Ok(apply_method_physical_numeric!(&s, not_equal, rhs))
     }
 
     fn not_equal_missing(&self, rhs: Rhs) -> Self::Item {
         validate_types(self.dtype(), &DataType::Int8)?;
-        let s = self.to_physical_repr();
+        let s = to_comparable_repr(self);
         Ok(apply_method_physical_numeric!(&s, not_equal_missing, rhs))
     }
 }
@@ -285,25 +305,25 @@ where
 
     fn gt(&self, rhs: Rhs) -> Self::Item {
         validate_types(self.dtype(), &DataType::Int8)?;
-        let s = self.to_physical_repr();
+        let s = to_comparable_repr(self);
         Ok(apply_method_physical_numeric!(&s, gt, rhs))
     }
 
     fn gt_eq(&self, rhs: Rhs) -> Self::Item {
         validate_types(self.dtype(), &DataType::Int8)?;
-        let s = self.to_physical_repr();
+        let s = to_comparable_repr(self);
         Ok(apply_method_physical_numeric!(&s, gt_eq, rhs))
     }
 
     fn lt(&self, rhs: Rhs) -> Self::Item {
         validate_types(self.dtype(), &DataType::Int8)?;
-        let s = self.to_physical_repr();
+        let s = to_comparable_repr(self);
         Ok(apply_method_physical_numeric!(&s, lt, rhs))
     }
 
     fn lt_eq(&self, rhs: Rhs) -> Self::Item {
         validate_types(self.dtype(), &DataType::Int8)?;
-        let s = self.to_physical_repr();
+        let s = to_comparable_repr(self);
         Ok(apply_method_physical_numeric!(&s, lt_eq, rhs))
     }
 }
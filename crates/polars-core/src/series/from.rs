@@ -1,6 +1,7 @@
+use arrow::array::with_dtype;
 #[cfg(feature = "dtype-categorical")]
 use arrow::compute::concatenate::concatenate_unchecked;
-use arrow::datatypes::Metadata;
+use arrow::datatypes::{IntervalUnit, Metadata};
 #[cfg(any(
     feature = "dtype-date",
     feature = "dtype-datetime",
@@ -75,6 +76,33 @@ impl Series {
                     scale.unwrap_or_else(|| unreachable!("scale should be set")),
                 )
                 .into_series(),
+            #[cfg(feature = "dtype-decimal256")]
+            Decimal256(precision, scale) => BinaryChunked::from_chunks(name, chunks)
+                .into_decimal256_unchecked(
+                    *precision,
+                    scale.unwrap_or_else(|| unreachable!("scale should be set")),
+                )
+                .into_series(),
+            #[cfg(feature = "dtype-interval")]
+            Interval => Int128Chunked::from_chunks(name, chunks)
+                .into_interval()
+                .into_series(),
+            #[cfg(feature = "dtype-uuid")]
+            Uuid => UuidChunked::from_uuid_chunks(name, chunks).into_series(),
+            #[cfg(feature = "dtype-variant")]
+            Variant => BinaryChunked::from_chunks(name, chunks)
+                .into_variant_unchecked()
+                .into_series(),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => UInt16Chunked::from_chunks(name, chunks)
+                .into_float16()
+                .into_series(),
+            #[cfg(feature = "dtype-extension")]
+            Extension(ext) => {
+                let storage =
+                    Series::from_chunks_and_dtype_unchecked(name, chunks, ext.storage_dtype());
+                ExtensionChunked::new_unchecked(storage, ext.clone()).into_series()
+            },
             #[cfg(feature = "dtype-array")]
             Array(_, _) => {
                 ArrayChunked::from_chunks_and_dtype_unchecked(name, chunks, dtype.clone())
@@ -82,6 +110,14 @@ impl Series {
             },
             List(_) => ListChunked::from_chunks_and_dtype_unchecked(name, chunks, dtype.clone())
                 .into_series(),
+            #[cfg(feature = "dtype-map")]
+            Map(_, _) => ListChunked::from_chunks_and_dtype_unchecked(
+                name,
+                chunks,
+                dtype.map_storage_dtype().unwrap(),
+            )
+            .into_map_unchecked()
+            .into_series(),
             String => StringChunked::from_chunks(name, chunks).into_series(),
             Binary => BinaryChunked::from_chunks(name, chunks).into_series(),
             #[cfg(feature = "dtype-categorical")]
@@ -217,6 +253,20 @@ impl Series {
                 "dtype-i128",
                 Ok(Int128Chunked::from_chunks(name, chunks).into_series())
             ),
+            #[cfg(feature = "dtype-f16")]
+            ArrowDataType::Float16 => {
+                let chunks = chunks
+                    .iter()
+                    .map(|arr| {
+                        let arr = arr.as_any().downcast_ref::<PrimitiveArray<f16>>().unwrap();
+                        f16_to_u16_array(arr).to_boxed()
+                    })
+                    .collect();
+                Ok(UInt16Chunked::from_chunks(name, chunks)
+                    .into_float16()
+                    .into_series())
+            },
+            #[cfg(not(feature = "dtype-f16"))]
             ArrowDataType::Float16 => {
                 let chunks =
                     cast_chunks(&chunks, &DataType::Float32, CastOptions::NonStrict).unwrap();
@@ -295,8 +345,7 @@ impl Series {
                     ArrowTimeUnit::Nanosecond => s,
                 })
             },
-            ArrowDataType::Decimal(precision, scale)
-            | ArrowDataType::Decimal256(precision, scale) => {
+            ArrowDataType::Decimal(precision, scale) => {
                 feature_gated!("dtype-decimal", {
                     polars_ensure!(*scale <= *precision, InvalidOperation: "invalid decimal precision and scale (prec={precision}, scale={scale})");
                     polars_ensure!(*precision <= 38, InvalidOperation: "polars does not support decimals about 38 precision");
@@ -319,6 +368,64 @@ impl Series {
                     Ok(s)
                 })
             },
+            #[cfg(feature = "dtype-decimal256")]
+            ArrowDataType::Decimal256(precision, scale) => {
+                polars_ensure!(*scale <= *precision, InvalidOperation: "invalid decimal precision and scale (prec={precision}, scale={scale})");
+
+                let chunks = chunks
+                    .iter()
+                    .map(|arr| {
+                        let arr = arr.as_any().downcast_ref().unwrap();
+                        i256_to_binview_array(arr).to_boxed()
+                    })
+                    .collect::<Vec<_>>();
+                Ok(BinaryChunked::from_chunks(name, chunks)
+                    .into_decimal256_unchecked(Some(*precision), *scale)
+                    .into_series())
+            },
+            #[cfg(not(feature = "dtype-decimal256"))]
+            ArrowDataType::Decimal256(precision, scale) => {
+                feature_gated!("dtype-decimal", {
+                    polars_ensure!(*scale <= *precision, InvalidOperation: "invalid decimal precision and scale (prec={precision}, scale={scale})");
+                    polars_ensure!(*precision <= 38, InvalidOperation: "decimals above 38 precision require the 'dtype-decimal256' feature");
+
+                    // Values of at most 38 digits fit an i128.
+                    let chunks = chunks
+                        .iter()
+                        .map(|arr| {
+                            let arr = arr
+                                .as_any()
+                                .downcast_ref::<PrimitiveArray<arrow::types::i256>>()
+                                .unwrap();
+                            let values: arrow::buffer::Buffer<i128> =
+                                arr.values().iter().map(|v| v.0.as_i128()).collect();
+                            PrimitiveArray::new(
+                                ArrowDataType::Int128,
+                                values,
+                                arr.validity().cloned(),
+                            )
+                            .to_boxed()
+                        })
+                        .collect::<Vec<_>>();
+                    Ok(Int128Chunked::from_chunks(name, chunks)
+                        .into_decimal_unchecked(Some(*precision), *scale)
+                        .into_series())
+                })
+            },
+            ArrowDataType::Interval(IntervalUnit::MonthDayNano) => {
+                feature_gated!("dtype-interval", {
+                    let chunks = chunks
+                        .iter()
+                        .map(|arr| {
+                            let arr = arr.as_any().downcast_ref().unwrap();
+                            interval_array_to_i128(arr).to_boxed()
+                        })
+                        .collect::<Vec<_>>();
+                    Ok(Int128Chunked::from_chunks(name, chunks)
+                        .into_interval()
+                        .into_series())
+                })
+            },
             ArrowDataType::Null => Ok(new_null(name, &chunks)),
             #[cfg(not(feature = "dtype-categorical"))]
             ArrowDataType::Dictionary(_, _, _) => {
@@ -461,6 +568,49 @@ impl Series {
                 };
                 Ok(s)
             },
+            #[cfg(feature = "dtype-uuid")]
+            ArrowDataType::Extension(ext)
+                if ext.name.as_str() == UUID_EXTENSION_NAME
+                    && ext.inner == ArrowDataType::FixedSizeBinary(16) =>
+            {
+                let chunks = chunks
+                    .iter()
+                    .map(|arr| {
+                        let arr = arr.as_any().downcast_ref().unwrap();
+                        fixed_size_binary_to_uuid_array(arr).to_boxed()
+                    })
+                    .collect::<Vec<_>>();
+                Ok(UuidChunked::from_uuid_chunks(name, chunks).into_series())
+            },
+            #[cfg(feature = "dtype-variant")]
+            ArrowDataType::Extension(ext) if ext.name.as_str() == VARIANT_EXTENSION_NAME => {
+                let chunks = chunks
+                    .iter()
+                    .map(|arr| unshred_variant_array(arr.as_ref()).map(|arr| arr.to_boxed()))
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                Ok(BinaryChunked::from_chunks(name, chunks)
+                    .into_variant_unchecked()
+                    .into_series())
+            },
+            ArrowDataType::Extension(ext) => {
+                let chunks = chunks
+                    .iter()
+                    .map(|arr| with_dtype(arr.as_ref(), ext.inner.clone()))
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                let storage =
+                    Series::_try_from_arrow_unchecked_with_md(name, chunks, &ext.inner, md)?;
+                #[cfg(feature = "dtype-extension")]
+                {
+                    let ext = unresolved_extension_type(
+                        &ext.name,
+                        storage.dtype().clone(),
+                        ext.metadata.as_deref(),
+                    );
+                    Ok(ExtensionChunked::new_unchecked(storage, ext).into_series())
+                }
+                #[cfg(not(feature = "dtype-extension"))]
+                Ok(storage)
+            },
             #[cfg(feature = "dtype-struct")]
             ArrowDataType::Struct(_) => {
                 let (chunks, dtype) = to_physical_and_dtype(chunks, md);
@@ -482,6 +632,51 @@ impl Series {
     }
 }
 
+#[cfg(feature = "dtype-map")]
+fn map_arrays_to_series(name: PlSmallStr, chunks: Vec<ArrayRef>) -> PolarsResult<Series> {
+    let chunks = chunks
+        .iter()
+        .map(|arr| {
+            // The entries are stored as `struct<key, value>`, independent of the names of the
+            // fields in the arrow array.
+            let arr = arr.as_any().downcast_ref::<MapArray>().unwrap();
+            let entries = arr.field().as_any().downcast_ref::<StructArray>().unwrap();
+            let fields = entries.fields();
+            let dtype = ArrowDataType::Struct(vec![
+                ArrowField {
+                    name: PlSmallStr::from_static(MAP_KEY_NAME),
+                    ..fields[0].clone()
+                },
+                ArrowField {
+                    name: PlSmallStr::from_static(MAP_VALUE_NAME),
+                    ..fields[1].clone()
+                },
+            ]);
+            let entries = StructArray::new(
+                dtype,
+                entries.len(),
+                entries.values().to_vec(),
+                entries.validity().cloned(),
+            );
+
+            // map has i32 offsets
+            let dtype = ListArray::<i32>::default_datatype(entries.dtype().clone());
+            Box::new(ListArray::<i32>::new(
+                dtype,
+                arr.offsets().clone(),
+                entries.boxed(),
+                arr.validity().cloned(),
+            )) as ArrayRef
+        })
+        .collect::<Vec<_>>();
+    Series::try_from((name, chunks))?
+        .list()?
+        .clone()
+        .into_map()
+        .map(|ca| ca.into_series())
+}
+
+#[cfg(not(feature = "dtype-map"))]
 fn map_arrays_to_series(name: PlSmallStr, chunks: Vec<ArrayRef>) -> PolarsResult<Series> {
     let chunks = chunks
         .iter()
@@ -656,12 +851,28 @@ unsafe fn to_physical_and_dtype(
         | ArrowDataType::Timestamp(_, _)
         | ArrowDataType::Date32
         | ArrowDataType::Decimal(_, _)
+        | ArrowDataType::Decimal256(_, _)
         | ArrowDataType::Date64) => {
             let dt = dt.clone();
             let mut s = Series::_try_from_arrow_unchecked(PlSmallStr::EMPTY, arrays, &dt).unwrap();
             let dtype = s.dtype().clone();
             (std::mem::take(s.chunks_mut()), dtype)
         },
+        dt @ ArrowDataType::Extension(_) => {
+            let dt = dt.clone();
+            let mut s =
+                Series::_try_from_arrow_unchecked_with_md(PlSmallStr::EMPTY, arrays, &dt, md)
+                    .unwrap();
+            let dtype = s.dtype().clone();
+            (std::mem::take(s.chunks_mut()), dtype)
+        },
+        #[cfg(feature = "dtype-interval")]
+        dt @ ArrowDataType::Interval(IntervalUnit::MonthDayNano) => {
+            let dt = dt.clone();
+            let mut s = Series::_try_from_arrow_unchecked(PlSmallStr::EMPTY, arrays, &dt).unwrap();
+            let dtype = s.dtype().clone();
+            (std::mem::take(s.chunks_mut()), dtype)
+        },
         dt => {
             let dtype = DataType::from_arrow(dt, true, md);
             (arrays, dtype)
@@ -730,6 +941,11 @@ impl TryFrom<(&ArrowField, Vec<ArrayRef>)> for Series {
         let (field, chunks) = field_arr;
 
         let dtype = check_types(&chunks)?;
+        // Readers may produce the storage arrays of an extension type.
+        let dtype = match &field.dtype {
+            ArrowDataType::Extension(ext) if ext.inner == dtype => field.dtype.clone(),
+            _ => dtype,
+        };
 
         // SAFETY:
         // dtype is checked
@@ -0,0 +1,289 @@
+use super::*;
+use crate::prelude::*;
+
+unsafe impl IntoSeries for ExtensionChunked {
+    fn into_series(self) -> Series {
+        Series(Arc::new(SeriesWrap(self)))
+    }
+}
+
+impl private::PrivateSeriesNumeric for SeriesWrap<ExtensionChunked> {
+    fn bit_repr(&self) -> Option<BitRepr> {
+        None
+    }
+}
+
+impl SeriesWrap<ExtensionChunked> {
+    fn apply_storage<F: Fn(&Series) -> Series>(&self, f: F) -> Series {
+        self.finish(f(self.0.storage()))
+    }
+
+    fn finish(&self, storage: Series) -> Series {
+        self.0.with_storage(storage).into_series()
+    }
+
+    fn ensure_ordered(&self) -> PolarsResult<()> {
+        polars_ensure!(
+            self.0.extension_type().supports_ordering(),
+            InvalidOperation: "extension type '{}' does not support ordering",
+            self.0.extension_type().name()
+        );
+        Ok(())
+    }
+
+    fn reduce_storage(&self, scalar: Scalar) -> Scalar {
+        Scalar::new(self.dtype().clone(), scalar.value().clone().into_static())
+    }
+}
+
+impl private::PrivateSeries for SeriesWrap<ExtensionChunked> {
+    fn compute_len(&mut self) {
+        self.0.storage_mut()._get_inner_mut().compute_len()
+    }
+    fn _field(&self) -> Cow<Field> {
+        Cow::Owned(Field::new(self.0.name().clone(), self.0.dtype().clone()))
+    }
+    fn _dtype(&self) -> &DataType {
+        self.0.dtype()
+    }
+    fn _get_flags(&self) -> StatisticsFlags {
+        self.0.storage()._get_flags()
+    }
+    fn _set_flags(&mut self, flags: StatisticsFlags) {
+        self.0.storage_mut()._get_inner_mut()._set_flags(flags)
+    }
+
+    unsafe fn equal_element(&self, idx_self: usize, idx_other: usize, other: &Series) -> bool {
+        let other = other.extension().unwrap().storage();
+        self.0.storage().equal_element(idx_self, idx_other, other)
+    }
+
+    #[cfg(feature = "zip_with")]
+    fn zip_with_same_type(&self, mask: &BooleanChunked, other: &Series) -> PolarsResult<Series> {
+        let other = other.extension()?.storage();
+        let storage = self.0.storage().zip_with_same_type(mask, other)?;
+        Ok(self.finish(storage))
+    }
+
+    fn into_total_eq_inner<'a>(&'a self) -> Box<dyn TotalEqInner + 'a> {
+        self.0.storage().into_total_eq_inner()
+    }
+    fn into_total_ord_inner<'a>(&'a self) -> Box<dyn TotalOrdInner + 'a> {
+        self.0.storage().into_total_ord_inner()
+    }
+
+    fn vec_hash(
+        &self,
+        random_state: PlSeedableRandomStateQuality,
+        buf: &mut Vec<u64>,
+    ) -> PolarsResult<()> {
+        self.0.storage().vec_hash(random_state, buf)
+    }
+
+    fn vec_hash_combine(
+        &self,
+        build_hasher: PlSeedableRandomStateQuality,
+        hashes: &mut [u64],
+    ) -> PolarsResult<()> {
+        self.0.storage().vec_hash_combine(build_hasher, hashes)
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_list(&self, groups: &GroupsType) -> Series {
+        let list = self.0.storage().agg_list(groups);
+        let list = list.list().unwrap();
+        let inner_dtype = Box::new(self.dtype().clone());
+        ListChunked::from_chunks_and_dtype_unchecked(
+            list.name().clone(),
+            list.chunks().clone(),
+            DataType::List(inner_dtype),
+        )
+        .into_series()
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn group_tuples(&self, multithreaded: bool, sorted: bool) -> PolarsResult<GroupsType> {
+        self.0.storage().group_tuples(multithreaded, sorted)
+    }
+
+    fn arg_sort_multiple(
+        &self,
+        by: &[Column],
+        options: &SortMultipleOptions,
+    ) -> PolarsResult<IdxCa> {
+        self.ensure_ordered()?;
+        self.0.storage().arg_sort_multiple(by, options)
+    }
+}
+
+impl SeriesTrait for SeriesWrap<ExtensionChunked> {
+    fn rename(&mut self, name: PlSmallStr) {
+        self.0.storage_mut().rename(name);
+    }
+
+    fn chunk_lengths(&self) -> ChunkLenIter {
+        self.0.storage().chunk_lengths()
+    }
+    fn name(&self) -> &PlSmallStr {
+        self.0.name()
+    }
+
+    fn chunks(&self) -> &Vec<ArrayRef> {
+        self.0.storage().chunks()
+    }
+    unsafe fn chunks_mut(&mut self) -> &mut Vec<ArrayRef> {
+        self.0.storage_mut()._get_inner_mut().chunks_mut()
+    }
+    fn shrink_to_fit(&mut self) {
+        self.0.storage_mut().shrink_to_fit()
+    }
+
+    fn slice(&self, offset: i64, length: usize) -> Series {
+        self.apply_storage(|s| s.slice(offset, length))
+    }
+
+    fn split_at(&self, offset: i64) -> (Series, Series) {
+        let (a, b) = self.0.storage().split_at(offset);
+        (self.finish(a), self.finish(b))
+    }
+
+    fn append(&mut self, other: &Series) -> PolarsResult<()> {
+        polars_ensure!(self.0.dtype() == other.dtype(), append);
+        let other = other.extension()?.storage();
+        self.0.storage_mut().append(other)?;
+        Ok(())
+    }
+    fn append_owned(&mut self, other: Series) -> PolarsResult<()> {
+        polars_ensure!(self.0.dtype() == other.dtype(), append);
+        let other = other.extension()?.clone().into_storage();
+        self.0.storage_mut().append_owned(other)?;
+        Ok(())
+    }
+
+    fn extend(&mut self, other: &Series) -> PolarsResult<()> {
+        polars_ensure!(self.0.dtype() == other.dtype(), extend);
+        let other = other.extension()?.storage();
+        self.0.storage_mut().extend(other)?;
+        Ok(())
+    }
+
+    fn filter(&self, filter: &BooleanChunked) -> PolarsResult<Series> {
+        Ok(self.finish(self.0.storage().filter(filter)?))
+    }
+
+    fn take(&self, indices: &IdxCa) -> PolarsResult<Series> {
+        Ok(self.finish(self.0.storage().take(indices)?))
+    }
+
+    unsafe fn take_unchecked(&self, indices: &IdxCa) -> Series {
+        self.finish(self.0.storage().take_unchecked(indices))
+    }
+
+    fn take_slice(&self, indices: &[IdxSize]) -> PolarsResult<Series> {
+        Ok(self.finish(self.0.storage().take_slice(indices)?))
+    }
+
+    unsafe fn take_slice_unchecked(&self, indices: &[IdxSize]) -> Series {
+        self.finish(self.0.storage().take_slice_unchecked(indices))
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn rechunk(&self) -> Series {
+        self.apply_storage(|s| s.rechunk())
+    }
+
+    fn new_from_index(&self, index: usize, length: usize) -> Series {
+        self.apply_storage(|s| s.new_from_index(index, length))
+    }
+
+    fn cast(&self, dtype: &DataType, cast_options: CastOptions) -> PolarsResult<Series> {
+        self.0.cast_with_options(dtype, cast_options)
+    }
+
+    #[inline]
+    unsafe fn get_unchecked(&self, index: usize) -> AnyValue {
+        self.0.storage().get_unchecked(index)
+    }
+
+    fn sort_with(&self, options: SortOptions) -> PolarsResult<Series> {
+        self.ensure_ordered()?;
+        Ok(self.finish(self.0.storage().sort_with(options)?))
+    }
+
+    fn arg_sort(&self, options: SortOptions) -> IdxCa {
+        self.ensure_ordered().unwrap();
+        self.0.storage().arg_sort(options)
+    }
+
+    fn null_count(&self) -> usize {
+        self.0.storage().null_count()
+    }
+
+    fn has_nulls(&self) -> bool {
+        self.0.storage().has_nulls()
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn unique(&self) -> PolarsResult<Series> {
+        Ok(self.finish(self.0.storage().unique()?))
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn n_unique(&self) -> PolarsResult<usize> {
+        self.0.storage().n_unique()
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn arg_unique(&self) -> PolarsResult<IdxCa> {
+        self.0.storage().arg_unique()
+    }
+
+    fn is_null(&self) -> BooleanChunked {
+        self.0.storage().is_null()
+    }
+
+    fn is_not_null(&self) -> BooleanChunked {
+        self.0.storage().is_not_null()
+    }
+
+    fn reverse(&self) -> Series {
+        self.apply_storage(|s| s.reverse())
+    }
+
+    fn shift(&self, periods: i64) -> Series {
+        self.apply_storage(|s| s.shift(periods))
+    }
+
+    fn clone_inner(&self) -> Arc<dyn SeriesTrait> {
+        Arc::new(SeriesWrap(Clone::clone(&self.0)))
+    }
+
+    fn min_reduce(&self) -> PolarsResult<Scalar> {
+        self.ensure_ordered()?;
+        Ok(self.reduce_storage(self.0.storage().min_reduce()?))
+    }
+
+    fn max_reduce(&self) -> PolarsResult<Scalar> {
+        self.ensure_ordered()?;
+        Ok(self.reduce_storage(self.0.storage().max_reduce()?))
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        &self.0
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        &mut self.0
+    }
+
+    fn as_phys_any(&self) -> &dyn Any {
+        self.0.storage().as_phys_any()
+    }
+
+    fn as_arc_any(self: Arc<Self>) -> Arc<dyn Any + Send + Sync> {
+        self as _
+    }
+}
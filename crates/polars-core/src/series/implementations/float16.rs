@@ -0,0 +1,409 @@
+use polars_compute::rolling::QuantileMethod;
+
+use super::*;
+#[cfg(feature = "algorithm_group_by")]
+use crate::frame::group_by::*;
+use crate::prelude::*;
+
+unsafe impl IntoSeries for Float16Chunked {
+    fn into_series(self) -> Series {
+        Series(Arc::new(SeriesWrap(self)))
+    }
+}
+
+/// Compares the canonical bit patterns, so that -0 == 0 and all NaNs are equal.
+struct CanonicalFloat16(UInt16Chunked);
+
+impl TotalEqInner for CanonicalFloat16 {
+    unsafe fn eq_element_unchecked(&self, idx_a: usize, idx_b: usize) -> bool {
+        self.0.get_unchecked(idx_a) == self.0.get_unchecked(idx_b)
+    }
+}
+
+impl private::PrivateSeriesNumeric for SeriesWrap<Float16Chunked> {
+    fn bit_repr(&self) -> Option<BitRepr> {
+        None
+    }
+}
+
+impl SeriesWrap<Float16Chunked> {
+    fn apply_physical_to_s<F: Fn(&UInt16Chunked) -> UInt16Chunked>(&self, f: F) -> Series {
+        f(&self.0).into_float16().into_series()
+    }
+
+    fn to_float32_series(&self) -> Series {
+        self.0.to_float32().into_series()
+    }
+
+    /// Computations are done in single precision, the result is narrowed back to half precision.
+    fn narrow(s: Series) -> Series {
+        match s.dtype() {
+            DataType::Float32 => s.f32().unwrap().to_float16().into_series(),
+            _ => s.cast(&DataType::Float16).unwrap(),
+        }
+    }
+
+    fn narrow_scalar(scalar: Scalar) -> Scalar {
+        let av = match scalar.value().extract::<f32>() {
+            Some(v) => AnyValue::Float16(f16::from_f32(v)),
+            None => AnyValue::Null,
+        };
+        Scalar::new(DataType::Float16, av)
+    }
+
+    fn arithmetic(
+        &self,
+        rhs: &Series,
+        op: impl Fn(&Series, &Series) -> PolarsResult<Series>,
+    ) -> PolarsResult<Series> {
+        let rhs = rhs.cast(&DataType::Float32)?;
+        op(&self.to_float32_series(), &rhs).map(Self::narrow)
+    }
+}
+
+impl private::PrivateSeries for SeriesWrap<Float16Chunked> {
+    fn compute_len(&mut self) {
+        self.0.compute_len()
+    }
+    fn _field(&self) -> Cow<Field> {
+        Cow::Owned(self.0.field())
+    }
+    fn _dtype(&self) -> &DataType {
+        self.0.dtype()
+    }
+    fn _get_flags(&self) -> StatisticsFlags {
+        self.0.get_flags()
+    }
+    fn _set_flags(&mut self, flags: StatisticsFlags) {
+        self.0.set_flags(flags)
+    }
+
+    #[cfg(feature = "zip_with")]
+    fn zip_with_same_type(&self, mask: &BooleanChunked, other: &Series) -> PolarsResult<Series> {
+        let other = other.f16()?;
+        self.0
+            .physical()
+            .zip_with(mask, other.physical())
+            .map(|ca| ca.into_float16().into_series())
+    }
+
+    fn into_total_eq_inner<'a>(&'a self) -> Box<dyn TotalEqInner + 'a> {
+        Box::new(CanonicalFloat16(self.0.to_canonical_bits()))
+    }
+    fn into_total_ord_inner<'a>(&'a self) -> Box<dyn TotalOrdInner + 'a> {
+        (&self.0).into_total_ord_inner()
+    }
+
+    fn vec_hash(
+        &self,
+        random_state: PlSeedableRandomStateQuality,
+        buf: &mut Vec<u64>,
+    ) -> PolarsResult<()> {
+        self.0.to_canonical_bits().vec_hash(random_state, buf)?;
+        Ok(())
+    }
+
+    fn vec_hash_combine(
+        &self,
+        build_hasher: PlSeedableRandomStateQuality,
+        hashes: &mut [u64],
+    ) -> PolarsResult<()> {
+        self.0
+            .to_canonical_bits()
+            .vec_hash_combine(build_hasher, hashes)?;
+        Ok(())
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_min(&self, groups: &GroupsType) -> Series {
+        Self::narrow(self.to_float32_series().agg_min(groups))
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_max(&self, groups: &GroupsType) -> Series {
+        Self::narrow(self.to_float32_series().agg_max(groups))
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_sum(&self, groups: &GroupsType) -> Series {
+        Self::narrow(self.to_float32_series().agg_sum(groups))
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_std(&self, groups: &GroupsType, ddof: u8) -> Series {
+        Self::narrow(self.to_float32_series().agg_std(groups, ddof))
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_var(&self, groups: &GroupsType, ddof: u8) -> Series {
+        Self::narrow(self.to_float32_series().agg_var(groups, ddof))
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_list(&self, groups: &GroupsType) -> Series {
+        // we cannot cast and dispatch as the inner type of the list would be incorrect
+        self.0
+            .agg_list(groups)
+            .cast(&DataType::List(Box::new(self.dtype().clone())))
+            .unwrap()
+    }
+
+    fn subtract(&self, rhs: &Series) -> PolarsResult<Series> {
+        self.arithmetic(rhs, |l, r| l - r)
+    }
+    fn add_to(&self, rhs: &Series) -> PolarsResult<Series> {
+        self.arithmetic(rhs, |l, r| l + r)
+    }
+    fn multiply(&self, rhs: &Series) -> PolarsResult<Series> {
+        self.arithmetic(rhs, |l, r| l * r)
+    }
+    fn divide(&self, rhs: &Series) -> PolarsResult<Series> {
+        self.arithmetic(rhs, |l, r| l / r)
+    }
+    fn remainder(&self, rhs: &Series) -> PolarsResult<Series> {
+        self.arithmetic(rhs, |l, r| l % r)
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn group_tuples(&self, multithreaded: bool, sorted: bool) -> PolarsResult<GroupsType> {
+        self.0
+            .to_canonical_bits()
+            .group_tuples(multithreaded, sorted)
+    }
+
+    fn arg_sort_multiple(
+        &self,
+        by: &[Column],
+        options: &SortMultipleOptions,
+    ) -> PolarsResult<IdxCa> {
+        self.0.to_float32().arg_sort_multiple(by, options)
+    }
+}
+
+impl SeriesTrait for SeriesWrap<Float16Chunked> {
+    fn rename(&mut self, name: PlSmallStr) {
+        self.0.rename(name);
+    }
+
+    fn chunk_lengths(&self) -> ChunkLenIter {
+        self.0.chunk_lengths()
+    }
+    fn name(&self) -> &PlSmallStr {
+        self.0.name()
+    }
+
+    fn chunks(&self) -> &Vec<ArrayRef> {
+        self.0.chunks()
+    }
+    unsafe fn chunks_mut(&mut self) -> &mut Vec<ArrayRef> {
+        self.0.chunks_mut()
+    }
+    fn shrink_to_fit(&mut self) {
+        self.0.shrink_to_fit()
+    }
+
+    fn slice(&self, offset: i64, length: usize) -> Series {
+        self.apply_physical_to_s(|ca| ca.slice(offset, length))
+    }
+
+    fn split_at(&self, offset: i64) -> (Series, Series) {
+        let (a, b) = self.0.split_at(offset);
+        (
+            a.into_float16().into_series(),
+            b.into_float16().into_series(),
+        )
+    }
+
+    fn append(&mut self, other: &Series) -> PolarsResult<()> {
+        polars_ensure!(self.0.dtype() == other.dtype(), append);
+        self.0.append(other.f16()?.physical())
+    }
+    fn append_owned(&mut self, mut other: Series) -> PolarsResult<()> {
+        polars_ensure!(self.0.dtype() == other.dtype(), append);
+        self.0.append_owned(std::mem::take(
+            &mut other
+                ._get_inner_mut()
+                .as_any_mut()
+                .downcast_mut::<Float16Chunked>()
+                .unwrap()
+                .0,
+        ))
+    }
+
+    fn extend(&mut self, other: &Series) -> PolarsResult<()> {
+        polars_ensure!(self.0.dtype() == other.dtype(), extend);
+        self.0.extend(other.f16()?.physical())?;
+        Ok(())
+    }
+
+    fn filter(&self, filter: &BooleanChunked) -> PolarsResult<Series> {
+        self.0
+            .filter(filter)
+            .map(|ca| ca.into_float16().into_series())
+    }
+
+    fn _sum_as_f64(&self) -> f64 {
+        self.0.to_float32()._sum_as_f64()
+    }
+
+    fn mean(&self) -> Option<f64> {
+        self.0.to_float32().mean()
+    }
+
+    fn median(&self) -> Option<f64> {
+        self.0.to_float32().median().map(|v| v as f64)
+    }
+
+    fn std(&self, ddof: u8) -> Option<f64> {
+        self.0.to_float32().std(ddof)
+    }
+
+    fn var(&self, ddof: u8) -> Option<f64> {
+        self.0.to_float32().var(ddof)
+    }
+
+    fn take(&self, indices: &IdxCa) -> PolarsResult<Series> {
+        Ok(self.0.take(indices)?.into_float16().into_series())
+    }
+
+    unsafe fn take_unchecked(&self, indices: &IdxCa) -> Series {
+        self.0.take_unchecked(indices).into_float16().into_series()
+    }
+
+    fn take_slice(&self, indices: &[IdxSize]) -> PolarsResult<Series> {
+        Ok(self.0.take(indices)?.into_float16().into_series())
+    }
+
+    unsafe fn take_slice_unchecked(&self, indices: &[IdxSize]) -> Series {
+        self.0.take_unchecked(indices).into_float16().into_series()
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn rechunk(&self) -> Series {
+        self.0.rechunk().into_owned().into_float16().into_series()
+    }
+
+    fn new_from_index(&self, index: usize, length: usize) -> Series {
+        self.0
+            .new_from_index(index, length)
+            .into_float16()
+            .into_series()
+    }
+
+    fn cast(&self, dtype: &DataType, cast_options: CastOptions) -> PolarsResult<Series> {
+        self.0.cast_with_options(dtype, cast_options)
+    }
+
+    #[inline]
+    unsafe fn get_unchecked(&self, index: usize) -> AnyValue {
+        self.0.get_any_value_unchecked(index)
+    }
+
+    fn sort_with(&self, options: SortOptions) -> PolarsResult<Series> {
+        Ok(self
+            .0
+            .to_float32()
+            .sort_with(options)
+            .to_float16()
+            .into_series())
+    }
+
+    fn arg_sort(&self, options: SortOptions) -> IdxCa {
+        self.0.to_float32().arg_sort(options)
+    }
+
+    fn null_count(&self) -> usize {
+        self.0.null_count()
+    }
+
+    fn has_nulls(&self) -> bool {
+        self.0.has_nulls()
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn unique(&self) -> PolarsResult<Series> {
+        self.0
+            .to_canonical_bits()
+            .unique()
+            .map(|ca| ca.into_float16().into_series())
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn n_unique(&self) -> PolarsResult<usize> {
+        self.0.to_canonical_bits().n_unique()
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn arg_unique(&self) -> PolarsResult<IdxCa> {
+        self.0.to_canonical_bits().arg_unique()
+    }
+
+    fn is_null(&self) -> BooleanChunked {
+        self.0.is_null()
+    }
+
+    fn is_not_null(&self) -> BooleanChunked {
+        self.0.is_not_null()
+    }
+
+    fn reverse(&self) -> Series {
+        self.apply_physical_to_s(|ca| ca.reverse())
+    }
+
+    fn shift(&self, periods: i64) -> Series {
+        self.apply_physical_to_s(|ca| ca.shift(periods))
+    }
+
+    fn sum_reduce(&self) -> PolarsResult<Scalar> {
+        Ok(Self::narrow_scalar(self.0.to_float32().sum_reduce()))
+    }
+    fn max_reduce(&self) -> PolarsResult<Scalar> {
+        Ok(Self::narrow_scalar(self.0.to_float32().max_reduce()))
+    }
+    fn min_reduce(&self) -> PolarsResult<Scalar> {
+        Ok(Self::narrow_scalar(self.0.to_float32().min_reduce()))
+    }
+    fn median_reduce(&self) -> PolarsResult<Scalar> {
+        self.to_float32_series()
+            .median_reduce()
+            .map(Self::narrow_scalar)
+    }
+    fn var_reduce(&self, ddof: u8) -> PolarsResult<Scalar> {
+        self.to_float32_series()
+            .var_reduce(ddof)
+            .map(Self::narrow_scalar)
+    }
+    fn std_reduce(&self, ddof: u8) -> PolarsResult<Scalar> {
+        self.to_float32_series()
+            .std_reduce(ddof)
+            .map(Self::narrow_scalar)
+    }
+    fn quantile_reduce(&self, quantile: f64, method: QuantileMethod) -> PolarsResult<Scalar> {
+        self.to_float32_series()
+            .quantile_reduce(quantile, method)
+            .map(Self::narrow_scalar)
+    }
+
+    fn clone_inner(&self) -> Arc<dyn SeriesTrait> {
+        Arc::new(SeriesWrap(Clone::clone(&self.0)))
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        &self.0
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        &mut self.0
+    }
+
+    fn as_phys_any(&self) -> &dyn Any {
+        self.0.physical()
+    }
+
+    fn as_arc_any(self: Arc<Self>) -> Arc<dyn Any + Send + Sync> {
+        self as _
+    }
+}
@@ -12,10 +12,20 @@ mod date;
 mod datetime;
 #[cfg(feature = "dtype-decimal")]
 mod decimal;
+#[cfg(feature = "dtype-decimal256")]
+mod decimal256;
 #[cfg(feature = "dtype-duration")]
 mod duration;
+#[cfg(feature = "dtype-extension")]
+mod extension;
+#[cfg(feature = "dtype-f16")]
+mod float16;
 mod floats;
+#[cfg(feature = "dtype-interval")]
+mod interval;
 mod list;
+#[cfg(feature = "dtype-map")]
+mod map;
 pub(crate) mod null;
 #[cfg(feature = "object")]
 mod object;
@@ -24,6 +34,10 @@ mod string;
 mod struct_;
 #[cfg(feature = "dtype-time")]
 mod time;
+#[cfg(feature = "dtype-uuid")]
+mod uuid;
+#[cfg(feature = "dtype-variant")]
+mod variant;
 
 use std::any::Any;
 use std::borrow::Cow;
@@ -85,6 +85,32 @@ impl Series {
                 );
                 Box::new(arr)
             },
+            #[cfg(feature = "dtype-map")]
+            dt @ DataType::Map(_, _) => {
+                // Convert the entries as a list of structs, then reinterpret that as a map.
+                let list = self.map_().unwrap().physical().clone().into_series();
+                let list = list.to_arrow(chunk_idx, compat_level);
+                let list = list.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+                let entries = list
+                    .values()
+                    .as_any()
+                    .downcast_ref::<StructArray>()
+                    .unwrap();
+
+                let dtype = dt.to_arrow(compat_level);
+                let ArrowDataType::Map(field, _) = &dtype else {
+                    unreachable!()
+                };
+                let entries = StructArray::new(
+                    field.dtype().clone(),
+                    entries.len(),
+                    entries.values().to_vec(),
+                    None,
+                );
+                let offsets = arrow::offset::OffsetsBuffer::<i32>::try_from(list.offsets())
+                    .expect("map entries should fit in 32-bit offsets");
+                MapArray::new(dtype, offsets, entries.boxed(), list.validity().cloned()).boxed()
+            },
             #[cfg(feature = "dtype-array")]
             DataType::Array(inner, width) => {
                 let ca = self.array().unwrap();
@@ -164,6 +190,53 @@ impl Series {
                 .clone()
                 .to(self.dtype().to_arrow(CompatLevel::newest()))
                 .to_boxed(),
+            #[cfg(feature = "dtype-decimal256")]
+            DataType::Decimal256(_, _) => {
+                let arr = self.decimal256().unwrap().chunks()[chunk_idx]
+                    .as_any()
+                    .downcast_ref::<BinaryViewArray>()
+                    .unwrap();
+                binview_to_i256_array(arr, self.dtype().to_arrow(compat_level)).to_boxed()
+            },
+            #[cfg(feature = "dtype-interval")]
+            DataType::Interval => {
+                let arr = self.interval().unwrap().chunks()[chunk_idx]
+                    .as_any()
+                    .downcast_ref::<PrimitiveArray<i128>>()
+                    .unwrap();
+                i128_to_interval_array(arr).to_boxed()
+            },
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => {
+                let arr = self.f16().unwrap().chunks()[chunk_idx]
+                    .as_any()
+                    .downcast_ref::<PrimitiveArray<u16>>()
+                    .unwrap();
+                u16_to_f16_array(arr).to_boxed()
+            },
+            #[cfg(feature = "dtype-uuid")]
+            DataType::Uuid => {
+                let arr = self.uuid().unwrap().chunks()[chunk_idx]
+                    .as_any()
+                    .downcast_ref::<FixedSizeListArray>()
+                    .unwrap();
+                uuid_array_to_fixed_size_binary(arr, self.dtype().to_arrow(compat_level)).to_boxed()
+            },
+            #[cfg(feature = "dtype-variant")]
+            DataType::Variant => {
+                let arr = self.variant().unwrap().chunks()[chunk_idx]
+                    .as_any()
+                    .downcast_ref::<BinaryViewArray>()
+                    .unwrap();
+                binview_to_variant_array(arr).to_boxed()
+            },
+            #[cfg(feature = "dtype-extension")]
+            DataType::Extension(_) => {
+                let storage = self.extension().unwrap().storage();
+                let arr = storage.to_arrow(chunk_idx, compat_level);
+                // The storage of an extension type is never a union.
+                arrow::array::with_dtype(arr.as_ref(), self.dtype().to_arrow(compat_level)).unwrap()
+            },
             #[cfg(feature = "object")]
             DataType::Object(_) => {
                 use crate::chunked_array::object::builder::object_series_to_arrow_array;
@@ -460,6 +460,13 @@ impl Series {
             opt => opt,
         };
 
+        #[cfg(feature = "dtype-extension")]
+        if let D::Extension(ext) = dtype {
+            if dtype != self.dtype() {
+                return self.cast_to_extension(ext, options);
+            }
+        }
+
         let ret = self.0.cast(dtype, new_options);
 
         match options {
@@ -513,6 +520,37 @@ impl Series {
             (D::Int128, D::Decimal(precision, scale)) => {
                 self.clone().into_decimal(*precision, scale.unwrap())
             },
+            #[cfg(feature = "dtype-decimal256")]
+            (D::Binary, D::Decimal256(precision, scale)) => Ok(self
+                .binary()
+                .unwrap()
+                .clone()
+                .into_decimal256_unchecked(*precision, scale.unwrap())
+                .into_series()),
+            #[cfg(feature = "dtype-interval")]
+            (D::Int128, D::Interval) => {
+                Ok(self.i128().unwrap().clone().into_interval().into_series())
+            },
+            #[cfg(feature = "dtype-uuid")]
+            (D::Array(_, _), D::Uuid) => Ok(UuidChunked::from_uuid_chunks(
+                self.name().clone(),
+                self.array().unwrap().chunks().clone(),
+            )
+            .into_series()),
+            #[cfg(feature = "dtype-variant")]
+            (D::Binary, D::Variant) => Ok(self
+                .binary()
+                .unwrap()
+                .clone()
+                .into_variant_unchecked()
+                .into_series()),
+            #[cfg(feature = "dtype-f16")]
+            (D::UInt16, D::Float16) => Ok(self.u16().unwrap().clone().into_float16().into_series()),
+            #[cfg(feature = "dtype-extension")]
+            (_, D::Extension(ext)) => {
+                let storage = self.from_physical_unchecked(ext.storage_dtype())?;
+                Ok(ExtensionChunked::new_unchecked(storage, ext.clone()).into_series())
+            },
 
             #[cfg(feature = "dtype-categorical")]
             (D::UInt32, D::Categorical(revmap, ordering)) => match revmap {
@@ -576,6 +614,11 @@ impl Series {
                     .from_physical_unchecked(to.as_slice())
                     .map(|ca| ca.into_series())
             },
+            #[cfg(feature = "dtype-map")]
+            (D::List(_), D::Map(_, _)) => unsafe {
+                let storage = self.from_physical_unchecked(&dtype.map_storage_dtype().unwrap())?;
+                Ok(storage.list()?.clone().into_map_unchecked().into_series())
+            },
 
             _ => panic!("invalid from_physical({dtype:?}) for {:?}", self.dtype()),
         }
@@ -711,11 +754,17 @@ impl Series {
     /// * Datetime -> Int64
     /// * Duration -> Int64
     /// * Decimal -> Int128
+    /// * Decimal256 -> Binary
+    /// * Interval -> Int128
+    /// * Uuid -> Array(UInt8, 16)
+    /// * Variant -> Binary
+    /// * Extension -> physical of the storage type
     /// * Time -> Int64
     /// * Categorical -> UInt32
     /// * List(inner) -> List(physical of inner)
     /// * Array(inner) -> Array(physical of inner)
     /// * Struct -> Struct with physical repr of each struct column
+    /// * Map(key, value) -> List(Struct with physical repr of key and value)
     pub fn to_physical_repr(&self) -> Cow<Series> {
         use DataType::*;
         match self.dtype() {
@@ -736,6 +785,24 @@ impl Series {
             },
             #[cfg(feature = "dtype-decimal")]
             Decimal(_, _) => Cow::Owned(self.decimal().unwrap().0.clone().into_series()),
+            #[cfg(feature = "dtype-decimal256")]
+            Decimal256(_, _) => Cow::Owned(self.decimal256().unwrap().0.clone().into_series()),
+            #[cfg(feature = "dtype-interval")]
+            Interval => Cow::Owned(self.interval().unwrap().0.clone().into_series()),
+            #[cfg(feature = "dtype-uuid")]
+            Uuid => Cow::Owned(self.uuid().unwrap().0.clone().into_series()),
+            #[cfg(feature = "dtype-variant")]
+            Variant => Cow::Owned(self.variant().unwrap().0.clone().into_series()),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => Cow::Owned(self.f16().unwrap().0.clone().into_series()),
+            #[cfg(feature = "dtype-extension")]
+            Extension(_) => Cow::Owned(
+                self.extension()
+                    .unwrap()
+                    .storage()
+                    .to_physical_repr()
+                    .into_owned(),
+            ),
             List(_) => match self.list().unwrap().to_physical_repr() {
                 Cow::Borrowed(_) => Cow::Borrowed(self),
                 Cow::Owned(ca) => Cow::Owned(ca.into_series()),
@@ -750,6 +817,11 @@ impl Series {
                 Cow::Borrowed(_) => Cow::Borrowed(self),
                 Cow::Owned(ca) => Cow::Owned(ca.into_series()),
             },
+            #[cfg(feature = "dtype-map")]
+            Map(_, _) => {
+                let ca = self.map_().unwrap().physical();
+                Cow::Owned(ca.to_physical_repr().into_owned().into_series())
+            },
             _ => Cow::Borrowed(self),
         }
     }
@@ -927,6 +999,10 @@ impl Series {
 
     // used for formatting
     pub fn str_value(&self, index: usize) -> PolarsResult<Cow<str>> {
+        #[cfg(feature = "dtype-extension")]
+        if let Some(ca) = self.try_extension() {
+            return ca.str_value(index).map(Cow::Owned);
+        }
         Ok(self.0.get(index)?.str_value())
     }
     /// Get the head of the Series.
@@ -942,7 +1018,18 @@ impl Series {
         self.slice(-(len as i64), len)
     }
 
+    /// The mean as a [`Scalar`].
+    ///
+    /// The mean of a decimal is exact, it is null if the result overflows the decimal
+    /// precision.
     pub fn mean_reduce(&self) -> Scalar {
+        #[cfg(feature = "dtype-decimal")]
+        if let Ok(ca) = self.decimal() {
+            return ca.mean_reduce().unwrap_or_else(|_| {
+                let scale = crate::chunked_array::arithmetic::_get_decimal_scale_div(ca.scale());
+                Scalar::null(DataType::Decimal(None, Some(scale)))
+            });
+        }
         crate::scalar::reduce::mean_reduce(self.mean(), self.dtype().clone())
     }
 
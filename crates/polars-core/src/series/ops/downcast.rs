@@ -148,11 +148,60 @@ impl Series {
         try_unpack_chunked!(self, DataType::Decimal(_, _) => DecimalChunked)
     }
 
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Decimal256`]
+    #[cfg(feature = "dtype-decimal256")]
+    pub fn try_decimal256(&self) -> Option<&Decimal256Chunked> {
+        try_unpack_chunked!(self, DataType::Decimal256(_, _) => Decimal256Chunked)
+    }
+
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Interval`]
+    #[cfg(feature = "dtype-interval")]
+    pub fn try_interval(&self) -> Option<&IntervalChunked> {
+        try_unpack_chunked!(self, DataType::Interval => IntervalChunked)
+    }
+
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Uuid`]
+    #[cfg(feature = "dtype-uuid")]
+    pub fn try_uuid(&self) -> Option<&UuidChunked> {
+        try_unpack_chunked!(self, DataType::Uuid => UuidChunked)
+    }
+
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Variant`]
+    #[cfg(feature = "dtype-variant")]
+    pub fn try_variant(&self) -> Option<&VariantChunked> {
+        try_unpack_chunked!(self, DataType::Variant => VariantChunked)
+    }
+
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Float16`]
+    #[cfg(feature = "dtype-f16")]
+    pub fn try_f16(&self) -> Option<&Float16Chunked> {
+        try_unpack_chunked!(self, DataType::Float16 => Float16Chunked)
+    }
+
+    /// Unpack to [`ExtensionChunked`] of dtype [`DataType::Extension`]
+    #[cfg(feature = "dtype-extension")]
+    pub fn try_extension(&self) -> Option<&ExtensionChunked> {
+        try_unpack_chunked!(self, DataType::Extension(_) => ExtensionChunked)
+    }
+
     /// Unpack to [`ChunkedArray`] of dtype list
     pub fn try_list(&self) -> Option<&ListChunked> {
         try_unpack_chunked!(self, DataType::List(_) => ListChunked)
     }
 
+    /// Unpack to [`ExtensionChunked`] of dtype [`DataType::Extension`]
+    #[cfg(feature = "dtype-extension")]
+    pub fn extension(&self) -> PolarsResult<&ExtensionChunked> {
+        self.try_extension()
+            .ok_or_else(|| unpack_chunked_err!(self => "Extension"))
+    }
+
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Map`]
+    #[cfg(feature = "dtype-map")]
+    pub fn try_map_(&self) -> Option<&MapChunked> {
+        try_unpack_chunked!(self, DataType::Map(_, _) => MapChunked)
+    }
+
     /// Unpack to [`ChunkedArray`] of dtype [`DataType::Array`]
     #[cfg(feature = "dtype-array")]
     pub fn try_array(&self) -> Option<&ArrayChunked> {
@@ -322,6 +371,48 @@ impl Series {
             .ok_or_else(|| unpack_chunked_err!(self => "Decimal"))
     }
 
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Decimal256`]
+    #[cfg(feature = "dtype-decimal256")]
+    pub fn decimal256(&self) -> PolarsResult<&Decimal256Chunked> {
+        self.try_decimal256()
+            .ok_or_else(|| unpack_chunked_err!(self => "Decimal256"))
+    }
+
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Interval`]
+    #[cfg(feature = "dtype-interval")]
+    pub fn interval(&self) -> PolarsResult<&IntervalChunked> {
+        self.try_interval()
+            .ok_or_else(|| unpack_chunked_err!(self => "Interval"))
+    }
+
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Uuid`]
+    #[cfg(feature = "dtype-uuid")]
+    pub fn uuid(&self) -> PolarsResult<&UuidChunked> {
+        self.try_uuid()
+            .ok_or_else(|| unpack_chunked_err!(self => "Uuid"))
+    }
+
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Variant`]
+    #[cfg(feature = "dtype-variant")]
+    pub fn variant(&self) -> PolarsResult<&VariantChunked> {
+        self.try_variant()
+            .ok_or_else(|| unpack_chunked_err!(self => "Variant"))
+    }
+
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Float16`]
+    #[cfg(feature = "dtype-f16")]
+    pub fn f16(&self) -> PolarsResult<&Float16Chunked> {
+        self.try_f16()
+            .ok_or_else(|| unpack_chunked_err!(self => "Float16"))
+    }
+
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Map`]
+    #[cfg(feature = "dtype-map")]
+    pub fn map_(&self) -> PolarsResult<&MapChunked> {
+        self.try_map_()
+            .ok_or_else(|| unpack_chunked_err!(self => "Map"))
+    }
+
     /// Unpack to [`ChunkedArray`] of dtype list
     pub fn list(&self) -> PolarsResult<&ListChunked> {
         self.try_list()
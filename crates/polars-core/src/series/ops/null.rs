@@ -17,6 +17,14 @@ impl Series {
             DataType::Array(inner_dtype, width) => {
                 ArrayChunked::full_null_with_dtype(name, size, inner_dtype, *width).into_series()
             },
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(_, _) => {
+                let storage = dtype.map_storage_dtype().unwrap();
+                let ca =
+                    ListChunked::full_null_with_dtype(name, size, storage.inner_dtype().unwrap());
+                // SAFETY: the storage type is a list of key/value entries.
+                unsafe { ca.into_map_unchecked() }.into_series()
+            },
             #[cfg(feature = "dtype-categorical")]
             dt @ (DataType::Categorical(rev_map, ord) | DataType::Enum(rev_map, ord)) => {
                 let mut ca = CategoricalChunked::full_null(
@@ -47,10 +55,36 @@ impl Series {
             DataType::Time => Int64Chunked::full_null(name, size)
                 .into_time()
                 .into_series(),
+            #[cfg(feature = "dtype-extension")]
+            DataType::Extension(ext) => ExtensionChunked::new_unchecked(
+                Series::full_null(name, size, ext.storage_dtype()),
+                ext.clone(),
+            )
+            .into_series(),
+            #[cfg(feature = "dtype-interval")]
+            DataType::Interval => Int128Chunked::full_null(name, size)
+                .into_interval()
+                .into_series(),
+            #[cfg(feature = "dtype-uuid")]
+            DataType::Uuid => {
+                UuidChunked::from_uuids(name, std::iter::repeat_n(None, size)).into_series()
+            },
+            #[cfg(feature = "dtype-variant")]
+            DataType::Variant => BinaryChunked::full_null(name, size)
+                .into_variant_unchecked()
+                .into_series(),
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => UInt16Chunked::full_null(name, size)
+                .into_float16()
+                .into_series(),
             #[cfg(feature = "dtype-decimal")]
             DataType::Decimal(precision, scale) => Int128Chunked::full_null(name, size)
                 .into_decimal_unchecked(*precision, scale.unwrap_or(0))
                 .into_series(),
+            #[cfg(feature = "dtype-decimal256")]
+            DataType::Decimal256(precision, scale) => BinaryChunked::full_null(name, size)
+                .into_decimal256_unchecked(*precision, scale.unwrap_or(0))
+                .into_series(),
             #[cfg(feature = "dtype-struct")]
             DataType::Struct(fields) => {
                 let fields = fields
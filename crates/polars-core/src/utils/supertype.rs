@@ -31,6 +31,7 @@ pub fn get_numeric_upcast_supertype_lossless(l: &DataType, r: &DataType) -> Opti
     } else if l.is_float() && r.is_float() {
         match (l, r) {
             (Float64, _) | (_, Float64) => Some(Float64),
+            (Float32, _) | (_, Float32) => Some(Float32),
             v => {
                 // Did we add a new float type?
                 if cfg!(debug_assertions) {
@@ -274,6 +275,18 @@ pub fn get_supertype_with_options(
 
             (Float64, Float32) => Some(Float64),
 
+            // Half precision floats only represent small integers exactly.
+            #[cfg(feature = "dtype-f16")]
+            (Float16, Boolean | Int8 | UInt8) => Some(Float16),
+            #[cfg(feature = "dtype-f16")]
+            (Float16, Int16 | UInt16 | Float32) => Some(Float32),
+            #[cfg(feature = "dtype-f16")]
+            (Float16, Int32 | UInt32 | Int64 | UInt64 | Int128 | Float64) => Some(Float64),
+            #[cfg(all(feature = "dtype-f16", feature = "dtype-decimal"))]
+            (Decimal(_, _), Float16) => Some(Float16),
+            #[cfg(all(feature = "dtype-f16", feature = "dtype-decimal256"))]
+            (Decimal256(_, _), Float16) => Some(Float16),
+
             // Time related dtypes
             #[cfg(feature = "dtype-date")]
             (Date, UInt32) => Some(Int64),
@@ -403,7 +416,7 @@ pub fn get_supertype_with_options(
                         }
                     },
                     // numeric vs float|str -> always float|str|decimal
-                    UnknownKind::Float | UnknownKind::Int(_) if dt.is_float() | dt.is_decimal() => Some(dt.clone()),
+                    UnknownKind::Float | UnknownKind::Int(_) if dt.is_float() | dt.is_decimal() | dt.is_decimal256() => Some(dt.clone()),
                     UnknownKind::Float if dt.is_integer() => Some(Unknown(UnknownKind::Float)),
                     // Materialize float to float or decimal
                     UnknownKind::Float if dt.is_float() | dt.is_decimal() => Some(dt.clone()),
@@ -467,6 +480,14 @@ pub fn get_supertype_with_options(
             (Decimal(_, _), f @ (Float32 | Float64)) => Some(f.clone()),
             #[cfg(feature = "dtype-decimal")]
             (d @ Decimal(_, _), dt) if dt.is_signed_integer() || dt.is_unsigned_integer() => Some(d.clone()),
+            #[cfg(feature = "dtype-decimal256")]
+            (Decimal256(p1, s1), Decimal256(p2, s2) | Decimal(p2, s2)) => {
+                Some(Decimal256((*p1).zip(*p2).map(|(p1, p2)| p1.max(p2)), (*s1).max(*s2)))
+            }
+            #[cfg(feature = "dtype-decimal256")]
+            (Decimal256(_, _), f @ (Float32 | Float64)) => Some(f.clone()),
+            #[cfg(feature = "dtype-decimal256")]
+            (d @ Decimal256(_, _), dt) if dt.is_signed_integer() || dt.is_unsigned_integer() => Some(d.clone()),
             _ => None,
         }
     }
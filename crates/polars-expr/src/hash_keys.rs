@@ -7,6 +7,8 @@ use polars_core::error::polars_err;
 use polars_core::frame::DataFrame;
 use polars_core::prelude::row_encode::_get_rows_encoded_unordered;
 use polars_core::prelude::{ChunkedArray, DataType, PlRandomState, PolarsDataType};
+#[cfg(feature = "dtype-uuid")]
+use polars_core::series::IntoSeries;
 use polars_core::series::Series;
 use polars_utils::IdxSize;
 use polars_utils::cardinality_sketch::CardinalitySketch;
@@ -30,6 +32,8 @@ pub fn hash_keys_variant_for_dtype(dt: &DataType) -> HashKeysVariant {
         DataType::Decimal(_, _) => HashKeysVariant::Single,
         #[cfg(feature = "dtype-categorical")]
         DataType::Enum(_, _) => HashKeysVariant::Single,
+        #[cfg(feature = "dtype-uuid")]
+        DataType::Uuid => HashKeysVariant::Single,
 
         DataType::String | DataType::Binary => HashKeysVariant::Binview,
 
@@ -164,9 +168,15 @@ impl HashKeys {
                 null_is_valid,
             })
         } else {
+            let keys = match df[0].dtype() {
+                // UUIDs are hashed and compared as packed fixed-width integers.
+                #[cfg(feature = "dtype-uuid")]
+                DataType::Uuid => df[0].uuid().unwrap().to_i128().into_series(),
+                _ => df[0].as_materialized_series().clone(),
+            };
             Self::Single(SingleKeys {
                 random_state,
-                keys: df[0].as_materialized_series().clone(),
+                keys,
                 null_is_valid,
             })
         }
@@ -105,6 +105,8 @@ pub fn new_idx_table(key_schema: Arc<Schema>) -> Box<dyn IdxTable> {
             DataType::Decimal(_, _) => Box::new(SKIT::<Int128Type>::new()),
             #[cfg(feature = "dtype-categorical")]
             DataType::Enum(_, _) => Box::new(SKIT::<UInt32Type>::new()),
+            #[cfg(feature = "dtype-uuid")]
+            DataType::Uuid => Box::new(SKIT::<Int128Type>::new()),
 
             DataType::String | DataType::Binary => Box::new(binview::BinviewKeyIdxTable::new()),
 
@@ -0,0 +1,86 @@
+use polars_core::chunked_array::arithmetic::_get_decimal_scale_div;
+use polars_core::chunked_array::ops::decimal::DecimalMoments;
+
+use super::*;
+
+#[derive(Clone, Copy)]
+pub enum DecimalReduction {
+    Sum,
+    Mean,
+    Var { ddof: u8 },
+    Std { ddof: u8 },
+}
+
+/// Exact sum, mean, variance and standard deviation of decimals.
+///
+/// Like the in-memory group-by, a group whose result overflows the decimal precision
+/// becomes null.
+pub fn new_decimal_reduction(dtype: DataType, kind: DecimalReduction) -> Box<dyn GroupedReduction> {
+    Box::new(VecGroupedReduction::new(dtype, DecimalReducer { kind }))
+}
+
+#[derive(Clone)]
+struct DecimalReducer {
+    kind: DecimalReduction,
+}
+
+impl Reducer for DecimalReducer {
+    type Dtype = Int128Type;
+    type Value = DecimalMoments;
+
+    fn init(&self) -> Self::Value {
+        DecimalMoments::new()
+    }
+
+    fn cast_series<'a>(&self, s: &'a Series) -> Cow<'a, Series> {
+        s.to_physical_repr()
+    }
+
+    fn combine(&self, a: &mut Self::Value, b: &Self::Value) {
+        a.combine(b)
+    }
+
+    #[inline(always)]
+    fn reduce_one(&self, a: &mut Self::Value, b: Option<i128>, _seq_id: u64) {
+        if let Some(v) = b {
+            a.insert(v);
+        }
+    }
+
+    fn reduce_ca(&self, v: &mut Self::Value, ca: &ChunkedArray<Self::Dtype>, _seq_id: u64) {
+        for arr in ca.downcast_iter() {
+            for x in arr.non_null_values_iter() {
+                v.insert(x);
+            }
+        }
+    }
+
+    fn finish(
+        &self,
+        v: Vec<Self::Value>,
+        m: Option<Bitmap>,
+        dtype: &DataType,
+    ) -> PolarsResult<Series> {
+        assert!(m.is_none());
+        let DataType::Decimal(precision, Some(scale)) = *dtype else {
+            unreachable!()
+        };
+        let out_scale = _get_decimal_scale_div(scale);
+        let mode = RoundMode::default();
+        let finish = |m: &DecimalMoments| match self.kind {
+            DecimalReduction::Sum => m.sum().map(Some),
+            DecimalReduction::Mean => m.mean(scale, out_scale, mode),
+            DecimalReduction::Var { ddof } => m.var(scale, out_scale, ddof, mode),
+            DecimalReduction::Std { ddof } => m.std(scale, out_scale, ddof, mode),
+        };
+        let ca: Int128Chunked = v
+            .iter()
+            .map(|m| finish(m).ok().flatten())
+            .collect_ca(PlSmallStr::EMPTY);
+        let out = match self.kind {
+            DecimalReduction::Sum => ca.into_decimal_unchecked(precision, scale),
+            _ => ca.into_decimal_unchecked(None, out_scale),
+        };
+        Ok(out.into_series())
+    }
+}
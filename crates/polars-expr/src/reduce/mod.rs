@@ -1,6 +1,8 @@
 #![allow(unsafe_op_in_unsafe_fn)]
 mod convert;
 mod count;
+#[cfg(feature = "dtype-decimal")]
+mod decimal;
 mod first_last;
 mod len;
 mod mean;
@@ -33,7 +33,9 @@ pub fn new_sum_reduction(dtype: DataType) -> Box<dyn GroupedReduction> {
         Float32 => Box::new(SumReduce::<Float32Type>::new(dtype)),
         Float64 => Box::new(SumReduce::<Float64Type>::new(dtype)),
         #[cfg(feature = "dtype-decimal")]
-        Decimal(_, _) => Box::new(SumReduce::<Int128Type>::new(dtype)),
+        Decimal(_, _) => {
+            super::decimal::new_decimal_reduction(dtype, super::decimal::DecimalReduction::Sum)
+        },
         Duration(_) => Box::new(SumReduce::<Int64Type>::new(dtype)),
         // For compatibility with the current engine, should probably be an error.
         String | Binary => Box::new(super::NullGroupedReduction::new(dtype)),
@@ -46,10 +48,6 @@ fn cast_sum_input<'a>(s: &'a Series, dt: &DataType) -> PolarsResult<Cow<'a, Seri
     match dt {
         Boolean => Ok(Cow::Owned(s.cast(&IDX_DTYPE)?)),
         Int8 | UInt8 | Int16 | UInt16 => Ok(Cow::Owned(s.cast(&Int64)?)),
-        #[cfg(feature = "dtype-decimal")]
-        Decimal(_, _) => Ok(Cow::Owned(
-            s.decimal().unwrap().physical().clone().into_series(),
-        )),
         #[cfg(feature = "dtype-duration")]
         Duration(_) => Ok(Cow::Owned(
             s.duration().unwrap().physical().clone().into_series(),
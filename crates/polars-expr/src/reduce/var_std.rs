@@ -16,21 +16,19 @@ pub fn new_var_std_reduction(dtype: DataType, is_std: bool, ddof: u8) -> Box<dyn
                 Box::new(VGR::new(dtype, VarStdReducer::<$T> {
                     is_std,
                     ddof,
-                    needs_cast: false,
                     _phantom: PhantomData,
                 }))
             })
         },
         #[cfg(feature = "dtype-decimal")]
-        Decimal(_, _) => Box::new(VGR::new(
-            dtype,
-            VarStdReducer::<Float64Type> {
-                is_std,
-                ddof,
-                needs_cast: true,
-                _phantom: PhantomData,
-            },
-        )),
+        Decimal(_, _) => {
+            let kind = if is_std {
+                super::decimal::DecimalReduction::Std { ddof }
+            } else {
+                super::decimal::DecimalReduction::Var { ddof }
+            };
+            super::decimal::new_decimal_reduction(dtype, kind)
+        },
         Duration(..) => todo!(),
         _ => unimplemented!(),
     }
@@ -39,7 +37,6 @@ pub fn new_var_std_reduction(dtype: DataType, is_std: bool, ddof: u8) -> Box<dyn
 struct VarStdReducer<T> {
     is_std: bool,
     ddof: u8,
-    needs_cast: bool,
     _phantom: PhantomData<T>,
 }
 
@@ -48,7 +45,6 @@ impl<T> Clone for VarStdReducer<T> {
         Self {
             is_std: self.is_std,
             ddof: self.ddof,
-            needs_cast: self.needs_cast,
             _phantom: PhantomData,
         }
     }
@@ -62,14 +58,6 @@ impl<T: PolarsNumericType> Reducer for VarStdReducer<T> {
         VarState::default()
     }
 
-    fn cast_series<'a>(&self, s: &'a Series) -> Cow<'a, Series> {
-        if self.needs_cast {
-            Cow::Owned(s.cast(&DataType::Float64).unwrap())
-        } else {
-            Cow::Borrowed(s)
-        }
-    }
-
     fn combine(&self, a: &mut Self::Value, b: &Self::Value) {
         a.combine(b)
     }
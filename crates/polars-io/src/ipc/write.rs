@@ -99,6 +99,9 @@ impl<W: Write> IpcWriter<W> {
                 compression: self.compression.map(|c| c.into()),
             },
         );
+        if let Some(custom_metadata) = self.custom_schema_metadata {
+            writer.set_custom_schema_metadata(custom_metadata);
+        }
         writer.start()?;
 
         Ok(BatchedWriter {
@@ -1,17 +1,21 @@
 use std::io::Write;
 use std::sync::Mutex;
 
+use arrow::datatypes::Metadata;
 use arrow::record_batch::RecordBatch;
 use polars_core::POOL;
 use polars_core::prelude::*;
 use polars_parquet::read::{ParquetError, fallible_streaming_iterator};
 use polars_parquet::write::{
     CompressedPage, Compressor, DynIter, DynStreamingIterator, Encoding, FallibleStreamingIterator,
-    FileWriter, Page, ParquetType, RowGroupIterColumns, SchemaDescriptor, WriteOptions,
+    FileWriter, KeyValue, Page, ParquetType, RowGroupIterColumns, SchemaDescriptor, WriteOptions,
     array_to_columns,
 };
 use rayon::prelude::*;
 
+#[cfg(feature = "dtype-variant")]
+use super::variant::VariantShredding;
+
 pub struct BatchedWriter<W: Write> {
     // A mutex so that streaming engine can get concurrent read access to
     // compress pages.
@@ -23,6 +27,9 @@ pub struct BatchedWriter<W: Write> {
     pub(super) encodings: Vec<Vec<Encoding>>,
     pub(super) options: WriteOptions,
     pub(super) parallel: bool,
+    pub(super) custom_metadata: Option<Arc<Metadata>>,
+    #[cfg(feature = "dtype-variant")]
+    pub(super) variant_shredding: Option<VariantShredding>,
 }
 
 impl<W: Write> BatchedWriter<W> {
@@ -38,14 +45,36 @@ impl<W: Write> BatchedWriter<W> {
             encodings,
             options,
             parallel,
+            custom_metadata: None,
+            #[cfg(feature = "dtype-variant")]
+            variant_shredding: None,
         }
     }
 
+    /// Set custom key-value metadata that is written to the file footer.
+    pub fn with_custom_metadata(mut self, custom_metadata: Option<Arc<Metadata>>) -> Self {
+        self.custom_metadata = custom_metadata;
+        self
+    }
+
+    /// The record batches that are written for `df`, one per chunk.
+    fn record_batches<'a>(&'a self, df: &'a DataFrame) -> impl Iterator<Item = RecordBatch> + 'a {
+        df.iter_chunks(CompatLevel::newest(), false)
+            .enumerate()
+            .map(move |(_chunk_idx, batch)| {
+                #[cfg(feature = "dtype-variant")]
+                if let Some(shredding) = &self.variant_shredding {
+                    return shredding.shred(df, _chunk_idx, batch);
+                }
+                batch
+            })
+    }
+
     pub fn encode_and_compress<'a>(
         &'a self,
         df: &'a DataFrame,
     ) -> impl Iterator<Item = PolarsResult<RowGroupIterColumns<'static, PolarsError>>> + 'a {
-        let rb_iter = df.iter_chunks(CompatLevel::newest(), false);
+        let rb_iter = self.record_batches(df);
         rb_iter.filter_map(move |batch| match batch.len() {
             0 => None,
             _ => {
@@ -67,7 +96,7 @@ impl<W: Write> BatchedWriter<W> {
     /// The caller must ensure the chunks in the given [`DataFrame`] are aligned.
     pub fn write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
         let row_group_iter = prepare_rg_iter(
-            df,
+            self.record_batches(df),
             &self.parquet_schema,
             &self.encodings,
             self.options,
@@ -115,21 +144,28 @@ impl<W: Write> BatchedWriter<W> {
 
     /// Writes the footer of the parquet file. Returns the total size of the file.
     pub fn finish(&self) -> PolarsResult<u64> {
+        let key_value_metadata = self.custom_metadata.as_ref().map(|md| {
+            md.iter()
+                .map(|(key, value)| KeyValue {
+                    key: key.to_string(),
+                    value: Some(value.to_string()),
+                })
+                .collect()
+        });
         let mut writer = self.writer.lock().unwrap();
-        let size = writer.end(None)?;
+        let size = writer.end(key_value_metadata)?;
         Ok(size)
     }
 }
 
 // Note that the df should be rechunked
 fn prepare_rg_iter<'a>(
-    df: &'a DataFrame,
+    rb_iter: impl Iterator<Item = RecordBatch> + 'a,
     parquet_schema: &'a SchemaDescriptor,
     encodings: &'a [Vec<Encoding>],
     options: WriteOptions,
     parallel: bool,
 ) -> impl Iterator<Item = PolarsResult<RowGroupIterColumns<'static, PolarsError>>> + 'a {
-    let rb_iter = df.iter_chunks(CompatLevel::newest(), false);
     rb_iter.filter_map(move |batch| match batch.len() {
         0 => None,
         _ => {
@@ -2,6 +2,8 @@
 
 mod batched_writer;
 mod options;
+#[cfg(feature = "dtype-variant")]
+mod variant;
 mod writer;
 
 pub use batched_writer::BatchedWriter;
@@ -0,0 +1,54 @@
+use arrow::array::BinaryViewArray;
+use arrow::record_batch::RecordBatch;
+use polars_core::prelude::*;
+
+/// The types that the variant columns of a [`DataFrame`] are shredded into when written.
+pub(super) struct VariantShredding {
+    /// The shredded type per column index.
+    columns: Vec<(usize, ShreddedType)>,
+}
+
+impl VariantShredding {
+    /// Infer the shredding of the variant columns from their values. Returns `None` if no
+    /// column is shredded.
+    pub(super) fn infer(df: &DataFrame) -> Option<Self> {
+        let columns: Vec<_> = df
+            .get_columns()
+            .iter()
+            .enumerate()
+            .filter_map(|(i, c)| Some((i, ShreddedType::infer(c.try_variant()?)?)))
+            .collect();
+        (!columns.is_empty()).then_some(Self { columns })
+    }
+
+    pub(super) fn apply_to_schema(&self, schema: &mut ArrowSchema) {
+        for (i, shredded) in &self.columns {
+            let (_, field) = schema.get_at_index_mut(*i).unwrap();
+            field.dtype = shredded.to_arrow();
+        }
+    }
+
+    /// Replace the variant columns of `batch`, which is chunk `chunk_idx` of `df`, by their
+    /// shredded representation.
+    pub(super) fn shred(
+        &self,
+        df: &DataFrame,
+        chunk_idx: usize,
+        batch: RecordBatch,
+    ) -> RecordBatch {
+        let height = batch.height();
+        let (schema, mut arrays) = batch.into_schema_and_arrays();
+        let mut schema = schema.as_ref().clone();
+        self.apply_to_schema(&mut schema);
+
+        for (i, shredded) in &self.columns {
+            let ca = df.get_columns()[*i].variant().unwrap();
+            let arr = ca.physical().chunks()[chunk_idx]
+                .as_any()
+                .downcast_ref::<BinaryViewArray>()
+                .unwrap();
+            arrays[*i] = shred_variant_array(arr, shredded).boxed();
+        }
+        RecordBatch::new(height, Arc::new(schema), arrays)
+    }
+}
@@ -1,7 +1,7 @@
 use std::io::Write;
 use std::sync::Mutex;
 
-use arrow::datatypes::PhysicalType;
+use arrow::datatypes::{Metadata, PhysicalType};
 use polars_core::frame::chunk_df_for_writing;
 use polars_core::prelude::*;
 use polars_parquet::write::{
@@ -12,6 +12,8 @@ use polars_parquet::write::{
 use super::ParquetWriteOptions;
 use super::batched_writer::BatchedWriter;
 use super::options::ParquetCompression;
+#[cfg(feature = "dtype-variant")]
+use super::variant::VariantShredding;
 use crate::shared::schema_to_arrow_checked;
 
 impl ParquetWriteOptions {
@@ -41,6 +43,11 @@ pub struct ParquetWriter<W> {
     data_page_size: Option<usize>,
     /// Serialize columns in parallel
     parallel: bool,
+    /// Custom key-value metadata that is written to the file footer
+    custom_metadata: Option<Arc<Metadata>>,
+    /// The types that variant columns are shredded into
+    #[cfg(feature = "dtype-variant")]
+    variant_shredding: Option<VariantShredding>,
 }
 
 impl<W> ParquetWriter<W>
@@ -59,6 +66,9 @@ where
             row_group_size: None,
             data_page_size: None,
             parallel: true,
+            custom_metadata: None,
+            #[cfg(feature = "dtype-variant")]
+            variant_shredding: None,
         }
     }
 
@@ -96,8 +106,21 @@ where
         self
     }
 
+    /// Set custom key-value metadata that is written to the file footer.
+    pub fn with_custom_metadata(mut self, custom_metadata: Option<Arc<Metadata>>) -> Self {
+        self.custom_metadata = custom_metadata;
+        self
+    }
+
     pub fn batched(self, schema: &Schema) -> PolarsResult<BatchedWriter<W>> {
+        #[cfg(not(feature = "dtype-variant"))]
         let schema = schema_to_arrow_checked(schema, CompatLevel::newest(), "parquet")?;
+        #[cfg(feature = "dtype-variant")]
+        let mut schema = schema_to_arrow_checked(schema, CompatLevel::newest(), "parquet")?;
+        #[cfg(feature = "dtype-variant")]
+        if let Some(shredding) = &self.variant_shredding {
+            shredding.apply_to_schema(&mut schema);
+        }
         let parquet_schema = to_parquet_schema(&schema)?;
         let encodings = get_encodings(&schema);
         let options = self.materialize_options();
@@ -109,9 +132,18 @@ where
             encodings,
             options,
             parallel: self.parallel,
+            custom_metadata: self.custom_metadata,
+            #[cfg(feature = "dtype-variant")]
+            variant_shredding: self.variant_shredding,
         })
     }
 
+    #[cfg(feature = "dtype-variant")]
+    fn with_variant_shredding(mut self, shredding: Option<VariantShredding>) -> Self {
+        self.variant_shredding = shredding;
+        self
+    }
+
     fn materialize_options(&self) -> WriteOptions {
         WriteOptions {
             statistics: self.statistics,
@@ -122,9 +154,15 @@ where
     }
 
     /// Write the given DataFrame in the writer `W`. Returns the total size of the file.
+    ///
+    /// Variant columns are shredded into the typed columns that are inferred from their values.
     pub fn finish(self, df: &mut DataFrame) -> PolarsResult<u64> {
         let chunked_df = chunk_df_for_writing(df, self.row_group_size.unwrap_or(512 * 512))?;
-        let mut batched = self.batched(chunked_df.schema())?;
+        #[cfg(feature = "dtype-variant")]
+        let writer = self.with_variant_shredding(VariantShredding::infer(&chunked_df));
+        #[cfg(not(feature = "dtype-variant"))]
+        let writer = self;
+        let mut batched = writer.batched(chunked_df.schema())?;
         batched.write_batch(&chunked_df)?;
         batched.finish()
     }
@@ -148,7 +186,7 @@ fn encoding_map(dtype: &ArrowDataType) -> Encoding {
         PhysicalType::Primitive(dt) => {
             use arrow::types::PrimitiveType::*;
             match dt {
-                Float32 | Float64 | Float16 => Encoding::Plain,
+                Float32 | Float64 | Float16 | MonthDayNano => Encoding::Plain,
                 _ => Encoding::RleDictionary,
             }
         },
@@ -22,7 +22,7 @@ pub(crate) fn concat_impl<L: AsRef<[LazyFrame]>>(
             .ok_or_else(|| polars_err!(NoData: "empty container given"))?,
     );
 
-    let opt_state = lf.opt_state;
+    let opt_state = lf.get_opt_state();
     let cached_arenas = lf.cached_arena.clone();
 
     let mut lps = Vec::with_capacity(inputs.len());
@@ -56,7 +56,7 @@ pub fn concat_lf_horizontal<L: AsRef<[LazyFrame]>>(
     let lfs = inputs.as_ref();
     let (opt_state, cached_arena) = lfs
         .first()
-        .map(|lf| (lf.opt_state, lf.cached_arena.clone()))
+        .map(|lf| (lf.get_opt_state(), lf.cached_arena.clone()))
         .ok_or_else(
             || polars_err!(NoData: "Require at least one LazyFrame for horizontal concatenation"),
         )?;
@@ -8,6 +8,8 @@ mod err;
 mod exitable;
 #[cfg(feature = "pivot")]
 pub mod pivot;
+#[cfg(feature = "cse")]
+mod result_cache;
 
 #[cfg(any(
     feature = "parquet",
@@ -44,6 +46,10 @@ pub use polars_plan::frame::{AllowedOptimizations, OptFlags};
 use polars_plan::global::FETCH_ROWS;
 use polars_utils::pl_str::PlSmallStr;
 use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+#[cfg(feature = "cse")]
+pub use result_cache::{
+    ResultCacheConfig, clear_result_cache, result_cache_len, set_result_cache_config,
+};
 
 use crate::frame::cached_arenas::CachedArena;
 #[cfg(feature = "streaming")]
@@ -61,6 +67,7 @@ impl IntoLazy for DataFrame {
         LazyFrame {
             logical_plan: lp,
             opt_state: Default::default(),
+            optimization_rules: Default::default(),
             cached_arena: Default::default(),
         }
     }
@@ -81,14 +88,23 @@ impl IntoLazy for LazyFrame {
 pub struct LazyFrame {
     pub logical_plan: DslPlan,
     pub(crate) opt_state: OptFlags,
+    pub(crate) optimization_rules: OptimizationRules,
     pub(crate) cached_arena: Arc<Mutex<Option<CachedArena>>>,
 }
 
+/// The optimization settings that are passed on to the frames derived from a [`LazyFrame`].
+#[derive(Clone, Default)]
+pub(crate) struct OptState {
+    flags: OptFlags,
+    rules: OptimizationRules,
+}
+
 impl From<DslPlan> for LazyFrame {
     fn from(plan: DslPlan) -> Self {
         Self {
             logical_plan: plan,
             opt_state: OptFlags::default(),
+            optimization_rules: Default::default(),
             cached_arena: Default::default(),
         }
     }
@@ -97,12 +113,13 @@ impl From<DslPlan> for LazyFrame {
 impl LazyFrame {
     pub(crate) fn from_inner(
         logical_plan: DslPlan,
-        opt_state: OptFlags,
+        opt_state: OptState,
         cached_arena: Arc<Mutex<Option<CachedArena>>>,
     ) -> Self {
         Self {
             logical_plan,
-            opt_state,
+            opt_state: opt_state.flags,
+            optimization_rules: opt_state.rules,
             cached_arena,
         }
     }
@@ -111,16 +128,15 @@ impl LazyFrame {
         DslBuilder::from(self.logical_plan)
     }
 
-    fn get_opt_state(&self) -> OptFlags {
-        self.opt_state
+    pub(crate) fn get_opt_state(&self) -> OptState {
+        OptState {
+            flags: self.opt_state,
+            rules: self.optimization_rules.clone(),
+        }
     }
 
-    fn from_logical_plan(logical_plan: DslPlan, opt_state: OptFlags) -> Self {
-        LazyFrame {
-            logical_plan,
-            opt_state,
-            cached_arena: Default::default(),
-        }
+    fn from_logical_plan(logical_plan: DslPlan, opt_state: OptState) -> Self {
+        Self::from_inner(logical_plan, opt_state, Default::default())
     }
 
     /// Get current optimizations.
@@ -202,6 +218,50 @@ impl LazyFrame {
         self
     }
 
+    /// Reuse the results of identical subplans across separate `collect` calls.
+    ///
+    /// The query, its joins, aggregations and sorts, and the inputs of the `Cache` nodes in the
+    /// optimized plan (e.g. inserted by [`LazyFrame::cache`]) are fingerprinted by their plan and
+    /// the modification times of the files they scan. Their results are kept in a process-wide
+    /// cache, configured with [`set_result_cache_config`].
+    #[cfg(feature = "cse")]
+    pub fn with_result_cache(mut self, toggle: bool) -> Self {
+        self.opt_state.set(OptFlags::RESULT_CACHE, toggle);
+        self
+    }
+
+    /// Toggle the optimization rules added with [`LazyFrame::with_optimization_rule`].
+    ///
+    /// These run alongside the built-in expression and plan rewrites and are enabled by default.
+    pub fn with_user_optimization_rules(mut self, toggle: bool) -> Self {
+        self.opt_state
+            .set(OptFlags::USER_OPTIMIZATION_RULES, toggle);
+        self
+    }
+
+    /// Add a user-defined optimization rule under `name` to this query.
+    ///
+    /// The rule is passed on to the frames derived from this one. `factory` is called once for
+    /// every optimized query. If a rule with the same name was already added, it is replaced.
+    pub fn with_optimization_rule<F>(mut self, name: &str, factory: F) -> Self
+    where
+        F: Fn() -> Box<dyn OptimizationRule> + Send + Sync + 'static,
+    {
+        self.optimization_rules.insert(name, Arc::new(factory));
+        self
+    }
+
+    /// Remove the user-defined optimization rule added under `name`, if any.
+    pub fn without_optimization_rule(mut self, name: &str) -> Self {
+        self.optimization_rules.remove(name);
+        self
+    }
+
+    /// The user-defined optimization rules of this query.
+    pub fn optimization_rules(&self) -> &OptimizationRules {
+        &self.optimization_rules
+    }
+
     /// Toggle slice pushdown optimization.
     pub fn with_slice_pushdown(mut self, toggle: bool) -> Self {
         self.opt_state.set(OptFlags::SLICE_PUSHDOWN, toggle);
@@ -247,7 +307,8 @@ impl LazyFrame {
     // to `true` for describe.
     fn _describe_to_alp_optimized(mut self) -> PolarsResult<IRPlan> {
         let (mut lp_arena, mut expr_arena) = self.get_arenas();
-        let node = self.optimize_with_scratch(&mut lp_arena, &mut expr_arena, &mut vec![], true)?;
+        let node =
+            self.optimize_with_scratch(&mut lp_arena, &mut expr_arena, &mut vec![], true, None)?;
 
         Ok(IRPlan::new(node, lp_arena, expr_arena))
     }
@@ -269,6 +330,60 @@ impl LazyFrame {
             .describe_tree_format())
     }
 
+    /// Return a machine-readable JSON representation of the logical plan.
+    ///
+    /// If `optimized` is `true`, the optimized plan is returned. Every node contains its id, kind,
+    /// inputs, output schema, expressions, (pushed down) predicate and the estimated number of
+    /// rows if known.
+    pub fn describe_plan_json(&self, optimized: bool) -> PolarsResult<String> {
+        let lp = if optimized {
+            self.clone().to_alp_optimized()
+        } else {
+            self.clone().to_alp()
+        }?;
+        Ok(lp.display_json().to_string())
+    }
+
+    /// Return a machine-readable JSON representation of the physical plan of the streaming
+    /// engine.
+    #[cfg(feature = "new_streaming")]
+    pub fn describe_physical_plan_json(&self) -> PolarsResult<String> {
+        let mut lf = self.clone().with_new_streaming(true);
+        if !matches!(lf.logical_plan, DslPlan::Sink { .. }) {
+            lf.logical_plan = DslPlan::Sink {
+                input: Arc::new(lf.logical_plan),
+                payload: SinkType::Memory,
+            };
+        }
+        let mut alp_plan = lf.to_alp_optimized()?;
+        polars_stream::physical_plan_json(
+            alp_plan.lp_top,
+            &mut alp_plan.lp_arena,
+            &mut alp_plan.expr_arena,
+        )
+    }
+
+    /// Optimize the logical plan and return a trace of every optimizer pass that ran.
+    ///
+    /// Every pass records its name, the time it took and the plan before and after it ran, which
+    /// shows why e.g. a predicate was not pushed down. The [`Display`](std::fmt::Display)
+    /// implementation of the trace prints a diff of the plan for every pass that changed it.
+    ///
+    /// Returns `Err` if optimizing the logical plan fails.
+    pub fn describe_optimization_trace(&self) -> PolarsResult<OptimizerTrace> {
+        let mut lf = self.clone();
+        let (mut lp_arena, mut expr_arena) = lf.get_arenas();
+        let mut trace = OptimizerTrace::default();
+        lf.optimize_with_scratch(
+            &mut lp_arena,
+            &mut expr_arena,
+            &mut vec![],
+            true,
+            Some(&mut trace),
+        )?;
+        Ok(trace)
+    }
+
     /// Return a String describing the logical plan.
     ///
     /// If `optimized` is `true`, explains the optimized plan. If `optimized` is `false`,
@@ -579,13 +694,13 @@ impl LazyFrame {
         lp_arena: &mut Arena<IR>,
         expr_arena: &mut Arena<AExpr>,
     ) -> PolarsResult<Node> {
-        self.optimize_with_scratch(lp_arena, expr_arena, &mut vec![], false)
+        self.optimize_with_scratch(lp_arena, expr_arena, &mut vec![], false, None)
     }
 
     pub fn to_alp_optimized(mut self) -> PolarsResult<IRPlan> {
         let (mut lp_arena, mut expr_arena) = self.get_arenas();
         let node =
-            self.optimize_with_scratch(&mut lp_arena, &mut expr_arena, &mut vec![], false)?;
+            self.optimize_with_scratch(&mut lp_arena, &mut expr_arena, &mut vec![], false, None)?;
 
         Ok(IRPlan::new(node, lp_arena, expr_arena))
     }
@@ -608,6 +723,7 @@ impl LazyFrame {
         expr_arena: &mut Arena<AExpr>,
         scratch: &mut Vec<Node>,
         enable_fmt: bool,
+        trace: Option<&mut OptimizerTrace>,
     ) -> PolarsResult<Node> {
         #[allow(unused_mut)]
         let mut opt_state = self.opt_state;
@@ -628,6 +744,7 @@ impl LazyFrame {
         let lp_top = optimize(
             self.logical_plan,
             opt_state,
+            &self.optimization_rules,
             lp_arena,
             expr_arena,
             scratch,
@@ -643,6 +760,7 @@ impl LazyFrame {
                 let io_expr = phys_expr_to_io_expr(phys_expr);
                 Some(io_expr)
             }),
+            trace,
         )?;
 
         if streaming {
@@ -685,8 +803,19 @@ impl LazyFrame {
         let (mut lp_arena, mut expr_arena) = self.get_arenas();
 
         let mut scratch = vec![];
+        #[cfg(feature = "cse")]
+        let use_result_cache = self.opt_state.result_cache();
         let lp_top =
-            self.optimize_with_scratch(&mut lp_arena, &mut expr_arena, &mut scratch, false)?;
+            self.optimize_with_scratch(&mut lp_arena, &mut expr_arena, &mut scratch, false, None)?;
+        #[cfg(feature = "cse")]
+        if use_result_cache {
+            result_cache::resolve_cached_subplans(
+                lp_top,
+                &mut lp_arena,
+                &mut expr_arena,
+                Engine::InMemory,
+            )?;
+        }
 
         post_opt(
             lp_top,
@@ -782,6 +911,15 @@ impl LazyFrame {
             _ => {},
         }
         let mut alp_plan = self.clone().to_alp_optimized()?;
+        #[cfg(feature = "cse")]
+        if self.opt_state.result_cache() {
+            result_cache::resolve_cached_subplans(
+                alp_plan.lp_top,
+                &mut alp_plan.lp_arena,
+                &mut alp_plan.expr_arena,
+                engine,
+            )?;
+        }
 
         match engine {
             Engine::Auto | Engine::Streaming => feature_gated!("new_streaming", {
@@ -823,21 +961,35 @@ impl LazyFrame {
         }
     }
 
-    pub fn explain_all(plans: Vec<DslPlan>, opt_state: OptFlags) -> PolarsResult<String> {
-        let sink_multiple = LazyFrame {
+    /// A frame that sinks all of `lfs`, optimized with `opt_state` and the optimization rules
+    /// of every one of them.
+    fn sink_multiple(lfs: Vec<LazyFrame>, opt_state: OptFlags) -> LazyFrame {
+        let mut optimization_rules = OptimizationRules::default();
+        let plans = lfs
+            .into_iter()
+            .map(|lf| {
+                optimization_rules.extend(&lf.optimization_rules);
+                lf.logical_plan
+            })
+            .collect();
+        LazyFrame {
             logical_plan: DslPlan::SinkMultiple { inputs: plans },
             opt_state,
+            optimization_rules,
             cached_arena: Default::default(),
-        };
-        sink_multiple.explain(true)
+        }
+    }
+
+    pub fn explain_all(lfs: Vec<LazyFrame>, opt_state: OptFlags) -> PolarsResult<String> {
+        Self::sink_multiple(lfs, opt_state).explain(true)
     }
 
     pub fn collect_all_with_engine(
-        plans: Vec<DslPlan>,
+        lfs: Vec<LazyFrame>,
         mut engine: Engine,
         opt_state: OptFlags,
     ) -> PolarsResult<Vec<DataFrame>> {
-        if plans.is_empty() {
+        if lfs.is_empty() {
             return Ok(Vec::new());
         }
 
@@ -850,11 +1002,7 @@ impl LazyFrame {
             engine = Engine::InMemory;
         }
 
-        let mut sink_multiple = LazyFrame {
-            logical_plan: DslPlan::SinkMultiple { inputs: plans },
-            opt_state,
-            cached_arena: Default::default(),
-        };
+        let mut sink_multiple = Self::sink_multiple(lfs, opt_state);
 
         #[cfg(feature = "new_streaming")]
         {
@@ -877,7 +1025,18 @@ impl LazyFrame {
             ),
             _ => {},
         }
+        #[cfg(feature = "cse")]
+        let use_result_cache = sink_multiple.opt_state.result_cache();
         let mut alp_plan = sink_multiple.to_alp_optimized()?;
+        #[cfg(feature = "cse")]
+        if use_result_cache {
+            result_cache::resolve_cached_subplans(
+                alp_plan.lp_top,
+                &mut alp_plan.lp_arena,
+                &mut alp_plan.expr_arena,
+                engine,
+            )?;
+        }
 
         if engine == Engine::Streaming {
             feature_gated!("new_streaming", {
@@ -1211,6 +1370,19 @@ impl LazyFrame {
             !matches!(self.logical_plan, DslPlan::Sink { .. }),
             InvalidOperation: "cannot create a sink on top of another sink"
         );
+        #[cfg(feature = "dtype-categorical")]
+        {
+            let sink_options = match &payload {
+                SinkType::Memory => None,
+                SinkType::File(sink) => Some(&sink.sink_options),
+                SinkType::Partition(sink) => Some(&sink.sink_options),
+            };
+            if let Some(dictionaries) =
+                sink_options.and_then(|options| options.categorical_dictionaries.as_ref())
+            {
+                self = self.with_categorical_dictionaries(dictionaries);
+            }
+        }
         self.logical_plan = DslPlan::Sink {
             input: Arc::new(self.logical_plan),
             payload: payload.clone(),
@@ -2155,6 +2327,27 @@ impl LazyFrame {
         }
     }
 
+    /// Encode `String` or `Categorical` columns with persisted [`CategoricalDictionary`]s, so
+    /// that their physical codes are the same in every process that uses the dictionaries.
+    ///
+    /// Scans and sinks apply this with their `categorical_dictionaries` option. Collecting errors
+    /// if a column has a value that is not in its dictionary.
+    #[cfg(feature = "dtype-categorical")]
+    pub fn with_categorical_dictionaries(
+        self,
+        dictionaries: &CategoricalDictionaries,
+    ) -> LazyFrame {
+        let exprs = dictionaries
+            .iter()
+            .map(|(name, dictionary)| {
+                col(name.clone())
+                    .cat()
+                    .encode_with_dictionary(dictionary.clone())
+            })
+            .collect::<Vec<_>>();
+        self.with_columns(exprs)
+    }
+
     /// Return the number of non-null elements for each column.
     pub fn count(self) -> LazyFrame {
         self.select(vec![col(PlSmallStr::from_static("*")).count()])
@@ -2182,13 +2375,14 @@ impl LazyFrame {
         S: Into<PlSmallStr>,
     {
         let key = key.into();
+        let opt_state = self.get_opt_state();
 
         let lp = DslPlan::MergeSorted {
             input_left: Arc::new(self.logical_plan),
             input_right: Arc::new(other.logical_plan),
             key,
         };
-        Ok(LazyFrame::from_logical_plan(lp, self.opt_state))
+        Ok(LazyFrame::from_logical_plan(lp, opt_state))
     }
 }
 
@@ -2196,7 +2390,7 @@ impl LazyFrame {
 #[derive(Clone)]
 pub struct LazyGroupBy {
     pub logical_plan: DslPlan,
-    opt_state: OptFlags,
+    opt_state: OptState,
     keys: Vec<Expr>,
     maintain_order: bool,
     #[cfg(feature = "dynamic_group_by")]
@@ -2207,11 +2401,7 @@ pub struct LazyGroupBy {
 
 impl From<LazyGroupBy> for LazyFrame {
     fn from(lgb: LazyGroupBy) -> Self {
-        Self {
-            logical_plan: lgb.logical_plan,
-            opt_state: lgb.opt_state,
-            cached_arena: Default::default(),
-        }
+        Self::from_logical_plan(lgb.logical_plan, lgb.opt_state)
     }
 }
 
@@ -2257,6 +2447,39 @@ impl LazyGroupBy {
         LazyFrame::from_logical_plan(lp, self.opt_state)
     }
 
+    /// Group by multiple grouping sets of the keys and aggregate, e.g. to compute subtotals.
+    ///
+    /// The result contains the rows of every grouping set, with the keys that are not part of a
+    /// set set to null, and a [`GROUPING_ID`] column that identifies the set of every row.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// fn example(df: DataFrame) -> PolarsResult<LazyFrame> {
+    ///       df.lazy()
+    ///        .group_by([col("region"), col("city")])
+    ///        .agg_grouping_sets(&GroupingSets::rollup(2), [col("sales").sum()])
+    /// }
+    /// ```
+    pub fn agg_grouping_sets<E: AsRef<[Expr]>>(
+        self,
+        sets: &GroupingSets,
+        aggs: E,
+    ) -> PolarsResult<LazyFrame> {
+        #[cfg(feature = "dynamic_group_by")]
+        polars_ensure!(
+            self.dynamic_options.is_none() && self.rolling_options.is_none(),
+            InvalidOperation: "grouping sets are not supported in dynamic or rolling group by"
+        );
+        let lp = DslBuilder::from(self.logical_plan)
+            .grouping_sets(self.keys, sets, aggs, self.maintain_order)?
+            .build();
+        Ok(LazyFrame::from_logical_plan(lp, self.opt_state))
+    }
+
     /// Return first n rows of each group
     pub fn head(self, n: Option<usize>) -> LazyFrame {
         let keys = self
@@ -2440,7 +2663,7 @@ impl JoinBuilder {
 
     /// Finish builder
     pub fn finish(self) -> LazyFrame {
-        let opt_state = self.lf.opt_state;
+        let opt_state = self.lf.get_opt_state();
         let other = self.other.expect("'with' not set in join builder");
 
         let args = JoinArgs {
@@ -2474,7 +2697,7 @@ impl JoinBuilder {
 
     // Finish with join predicates
     pub fn join_where(self, predicates: Vec<Expr>) -> LazyFrame {
-        let opt_state = self.lf.opt_state;
+        let opt_state = self.lf.get_opt_state();
         let other = self.other.expect("with not set");
 
         // Decompose `And` conjunctions into their component expressions
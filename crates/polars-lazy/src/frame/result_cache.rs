@@ -0,0 +1,343 @@
+//! Persistent cache of subplan results that is shared between separate `collect` calls.
+//!
+//! When the [`OptFlags::RESULT_CACHE`] optimization is enabled, the query and its expensive
+//! subplans (joins, aggregations, sorts and the inputs of `Cache` nodes) are fingerprinted (see
+//! [`subplan_fingerprint`]) and their results are kept in a process-wide LRU cache, so that a
+//! later query that contains the same subplan can reuse it.
+use std::path::PathBuf;
+use std::sync::{LazyLock, Mutex};
+
+use polars_core::error::feature_gated;
+use polars_core::prelude::*;
+use polars_mem_engine::create_physical_plan;
+use polars_plan::plans::visitor::{PinnedInput, SubplanFingerprint, subplan_fingerprint};
+
+use crate::prelude::*;
+
+/// Configuration of the process-wide result cache.
+#[derive(Clone, Debug)]
+pub struct ResultCacheConfig {
+    /// Maximum estimated size in bytes of the results kept in memory.
+    pub capacity: usize,
+    /// Directory to which results evicted from memory are written as IPC files. If `None`,
+    /// evicted results are dropped.
+    pub spill_dir: Option<PathBuf>,
+}
+
+impl Default for ResultCacheConfig {
+    fn default() -> Self {
+        Self {
+            capacity: 1 << 30,
+            spill_dir: None,
+        }
+    }
+}
+
+struct Entry {
+    df: DataFrame,
+    size: usize,
+    last_used: u64,
+    // Checked on every hit, so that a hash collision never returns the result of another plan.
+    key: Vec<u8>,
+    // Keep the address-identified inputs alive while the entry exists.
+    pinned: Vec<PinnedInput>,
+}
+
+struct SpilledEntry {
+    path: PathBuf,
+    key: Vec<u8>,
+    pinned: Vec<PinnedInput>,
+}
+
+#[derive(Default)]
+struct ResultCache {
+    config: ResultCacheConfig,
+    entries: PlHashMap<u64, Entry>,
+    spilled: PlHashMap<u64, SpilledEntry>,
+    size: usize,
+    tick: u64,
+}
+
+static RESULT_CACHE: LazyLock<Mutex<ResultCache>> = LazyLock::new(Default::default);
+
+impl ResultCache {
+    fn get(&mut self, fingerprint: &SubplanFingerprint) -> PolarsResult<Option<DataFrame>> {
+        let hash = fingerprint.hash;
+        self.tick += 1;
+        if let Some(entry) = self.entries.get_mut(&hash) {
+            if entry.key != fingerprint.key {
+                return Ok(None);
+            }
+            entry.last_used = self.tick;
+            return Ok(Some(entry.df.clone()));
+        }
+
+        if self
+            .spilled
+            .get(&hash)
+            .is_none_or(|spilled| spilled.key != fingerprint.key)
+        {
+            return Ok(None);
+        }
+        let spilled = self.spilled.remove(&hash).unwrap();
+        let df = read_spilled(&spilled.path);
+        let _ = std::fs::remove_file(&spilled.path);
+        let df = df?;
+        self.insert(hash, spilled.key, df.clone(), spilled.pinned);
+        Ok(Some(df))
+    }
+
+    fn insert(&mut self, hash: u64, key: Vec<u8>, df: DataFrame, pinned: Vec<PinnedInput>) {
+        let size = df.estimated_size();
+        if size > self.config.capacity {
+            return;
+        }
+        self.tick += 1;
+        let entry = Entry {
+            df,
+            size,
+            last_used: self.tick,
+            key,
+            pinned,
+        };
+        if let Some(old) = self.entries.insert(hash, entry) {
+            self.size -= old.size;
+        }
+        // A newer result for the same hash supersedes the spilled one.
+        if let Some(spilled) = self.spilled.remove(&hash) {
+            let _ = std::fs::remove_file(&spilled.path);
+        }
+        self.size += size;
+        self.evict();
+    }
+
+    /// Evict the least recently used results until the cache fits in its capacity.
+    ///
+    /// A result that cannot be spilled is dropped, as it can always be computed again.
+    fn evict(&mut self) {
+        while self.size > self.config.capacity {
+            let hash = *self
+                .entries
+                .iter()
+                .min_by_key(|(_, e)| e.last_used)
+                .map(|(k, _)| k)
+                .unwrap();
+            let mut entry = self.entries.remove(&hash).unwrap();
+            self.size -= entry.size;
+
+            if let Some(dir) = &self.config.spill_dir {
+                let path = dir.join(format!("{hash:016x}.ipc"));
+                if let Err(err) = write_spilled(&path, &mut entry.df) {
+                    if polars_core::config::verbose() {
+                        eprintln!(
+                            "result cache: dropping result that failed to spill to {}: {err}",
+                            path.display()
+                        );
+                    }
+                    let _ = std::fs::remove_file(&path);
+                    continue;
+                }
+                self.spilled.insert(
+                    hash,
+                    SpilledEntry {
+                        path,
+                        key: entry.key,
+                        pinned: entry.pinned,
+                    },
+                );
+            }
+        }
+    }
+
+    fn clear(&mut self) {
+        for spilled in self.spilled.values() {
+            let _ = std::fs::remove_file(&spilled.path);
+        }
+        self.entries.clear();
+        self.spilled.clear();
+        self.size = 0;
+    }
+}
+
+#[cfg(feature = "ipc")]
+fn write_spilled(path: &std::path::Path, df: &mut DataFrame) -> PolarsResult<()> {
+    use polars_io::SerWriter;
+    use polars_io::ipc::IpcWriter;
+
+    let file = std::fs::File::create(path)?;
+    IpcWriter::new(std::io::BufWriter::new(file)).finish(df)
+}
+
+#[cfg(not(feature = "ipc"))]
+fn write_spilled(_path: &std::path::Path, _df: &mut DataFrame) -> PolarsResult<()> {
+    polars_bail!(InvalidOperation: "spilling the result cache to disk requires the 'ipc' feature")
+}
+
+#[cfg(feature = "ipc")]
+fn read_spilled(path: &std::path::Path) -> PolarsResult<DataFrame> {
+    use polars_io::SerReader;
+    use polars_io::ipc::IpcReader;
+
+    let file = polars_utils::open_file(path)?;
+    IpcReader::new(file).finish()
+}
+
+#[cfg(not(feature = "ipc"))]
+fn read_spilled(_path: &std::path::Path) -> PolarsResult<DataFrame> {
+    unreachable!()
+}
+
+/// Set the configuration of the result cache.
+///
+/// Results that no longer fit in the new capacity are evicted (and possibly spilled).
+pub fn set_result_cache_config(config: ResultCacheConfig) -> PolarsResult<()> {
+    #[cfg(not(feature = "ipc"))]
+    polars_ensure!(
+        config.spill_dir.is_none(),
+        InvalidOperation: "spilling the result cache to disk requires the 'ipc' feature"
+    );
+    if let Some(dir) = &config.spill_dir {
+        std::fs::create_dir_all(dir)?;
+    }
+    let mut cache = RESULT_CACHE.lock().unwrap();
+    cache.config = config;
+    cache.evict();
+    Ok(())
+}
+
+/// Remove all results from the result cache, including the ones spilled to disk.
+pub fn clear_result_cache() {
+    RESULT_CACHE.lock().unwrap().clear()
+}
+
+/// Number of results in the result cache, both in memory and spilled to disk.
+pub fn result_cache_len() -> usize {
+    let cache = RESULT_CACHE.lock().unwrap();
+    cache.entries.len() + cache.spilled.len()
+}
+
+/// Replace the query and its expensive subplans with their results.
+///
+/// Top-down, the results of the query itself, its joins, aggregations and sorts, and the inputs
+/// of its `Cache` nodes are taken from the result cache if their fingerprint is known. Otherwise
+/// their own subplans are resolved first, after which they are executed with the given engine
+/// and their results are added to the cache. Subplans that cannot be fingerprinted are left as
+/// is.
+pub(crate) fn resolve_cached_subplans(
+    root: Node,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+    engine: Engine,
+) -> PolarsResult<()> {
+    if !resolve_subplan(root, lp_arena, expr_arena, engine)? {
+        resolve_inputs(root, lp_arena, expr_arena, engine)?;
+    }
+    Ok(())
+}
+
+/// Resolve the expensive subplans below `node`.
+fn resolve_inputs(
+    node: Node,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+    engine: Engine,
+) -> PolarsResult<()> {
+    let mut stack = vec![node];
+    while let Some(node) = stack.pop() {
+        let lp = lp_arena.get(node);
+        let is_cache = matches!(lp, IR::Cache { .. });
+        for input in lp.get_inputs_vec() {
+            let is_expensive = matches!(
+                lp_arena.get(input),
+                IR::Join { .. } | IR::GroupBy { .. } | IR::Distinct { .. } | IR::Sort { .. }
+            );
+            if (is_cache || is_expensive) && resolve_subplan(input, lp_arena, expr_arena, engine)? {
+                continue;
+            }
+            stack.push(input);
+        }
+    }
+    Ok(())
+}
+
+/// Replace the subplan at `node` with its result, returns whether it was replaced.
+fn resolve_subplan(
+    node: Node,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+    engine: Engine,
+) -> PolarsResult<bool> {
+    // Already resolved, e.g. through another cache node that shares this input.
+    if matches!(lp_arena.get(node), IR::DataFrameScan { .. }) {
+        return Ok(true);
+    }
+    // The fingerprint must be taken before nested subplans are resolved.
+    let Some(fingerprint) = subplan_fingerprint(node, lp_arena, expr_arena) else {
+        return Ok(false);
+    };
+
+    let cached = RESULT_CACHE.lock().unwrap().get(&fingerprint)?;
+    let df = match cached {
+        Some(df) => df,
+        None => {
+            resolve_inputs(node, lp_arena, expr_arena, engine)?;
+            let df = execute_subplan(node, lp_arena, expr_arena, engine)?;
+            RESULT_CACHE.lock().unwrap().insert(
+                fingerprint.hash,
+                fingerprint.key,
+                df.clone(),
+                fingerprint.pinned,
+            );
+            df
+        },
+    };
+
+    let schema = df.schema().clone();
+    lp_arena.replace(
+        node,
+        IR::DataFrameScan {
+            df: Arc::new(df),
+            schema,
+            output_schema: None,
+        },
+    );
+    Ok(true)
+}
+
+/// Copy the plan rooted at `node`, as executing a plan takes its nodes from the arena while
+/// (cache) nodes in the subplan may be shared with the rest of the query.
+fn copy_subplan(node: Node, lp_arena: &mut Arena<IR>) -> Node {
+    let lp = lp_arena.get(node);
+    let (exprs, inputs) = (lp.get_exprs(), lp.get_inputs_vec());
+    let inputs = inputs
+        .into_iter()
+        .map(|input| copy_subplan(input, lp_arena))
+        .collect();
+    let lp = lp_arena.get(node).with_exprs_and_input(exprs, inputs);
+    lp_arena.add(lp)
+}
+
+fn execute_subplan(
+    node: Node,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+    engine: Engine,
+) -> PolarsResult<DataFrame> {
+    let input = copy_subplan(node, lp_arena);
+    match engine {
+        Engine::Streaming => feature_gated!("new_streaming", {
+            let sink = lp_arena.add(IR::Sink {
+                input,
+                payload: SinkTypeIR::Memory,
+            });
+            let _hold = StringCacheHolder::hold();
+            polars_stream::run_query(sink, lp_arena, expr_arena).map(|v| v.unwrap())
+        }),
+        // The old streaming engine is part of the optimized plan and executed by the in-memory
+        // engine.
+        _ => {
+            let mut physical_plan = create_physical_plan(input, lp_arena, expr_arena)?;
+            physical_plan.execute(&mut ExecutionState::new())
+        },
+    }
+}
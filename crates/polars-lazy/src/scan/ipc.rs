@@ -16,6 +16,9 @@ pub struct ScanArgsIpc {
     pub cloud_options: Option<CloudOptions>,
     pub hive_options: HiveOptions,
     pub include_file_paths: Option<PlSmallStr>,
+    /// Encode these columns with persisted categorical dictionaries.
+    #[cfg(feature = "dtype-categorical")]
+    pub categorical_dictionaries: Option<CategoricalDictionaries>,
 }
 
 impl Default for ScanArgsIpc {
@@ -28,6 +31,8 @@ impl Default for ScanArgsIpc {
             cloud_options: Default::default(),
             hive_options: Default::default(),
             include_file_paths: None,
+            #[cfg(feature = "dtype-categorical")]
+            categorical_dictionaries: None,
         }
     }
 }
@@ -53,7 +58,30 @@ impl LazyFileListReader for LazyIpcReader {
 
         let options = IpcScanOptions {};
 
-        let lf: LazyFrame = DslBuilder::scan_ipc(
+        #[cfg(feature = "dtype-categorical")]
+        if let Some(dictionaries) = &args.categorical_dictionaries {
+            use polars_io::SerReader;
+            use polars_io::ipc::IpcReader;
+
+            super::for_each_source_file(
+                &self.sources,
+                true,
+                args.cloud_options.as_ref(),
+                |memslice| {
+                    let mut reader = IpcReader::new(std::io::Cursor::new(memslice));
+                    let Some(metadata) = reader.custom_metadata()? else {
+                        return Ok(());
+                    };
+                    validate_categorical_dictionaries(
+                        metadata.iter().map(|(k, v)| (k.as_str(), v.as_str())),
+                        dictionaries,
+                    )
+                },
+            )?;
+        }
+
+        #[allow(unused_mut)]
+        let mut lf: LazyFrame = DslBuilder::scan_ipc(
             self.sources,
             options,
             args.n_rows,
@@ -67,6 +95,11 @@ impl LazyFileListReader for LazyIpcReader {
         .build()
         .into();
 
+        #[cfg(feature = "dtype-categorical")]
+        if let Some(dictionaries) = &args.categorical_dictionaries {
+            lf = lf.with_categorical_dictionaries(dictionaries);
+        }
+
         Ok(lf)
     }
 
@@ -11,3 +11,41 @@ pub(super) mod parquet;
 
 #[cfg(feature = "catalog")]
 mod catalog;
+
+/// Call `f` with the contents of every file of a scan, to validate file metadata when the scan is
+/// created.
+#[cfg(all(
+    feature = "dtype-categorical",
+    any(feature = "ipc", feature = "parquet")
+))]
+fn for_each_source_file<F>(
+    sources: &polars_plan::dsl::ScanSources,
+    glob: bool,
+    cloud_options: Option<&polars_io::cloud::CloudOptions>,
+    mut f: F,
+) -> polars_core::error::PolarsResult<()>
+where
+    F: FnMut(polars_utils::mmap::MemSlice) -> polars_core::error::PolarsResult<()>,
+{
+    use polars_core::prelude::*;
+    use polars_plan::dsl::ScanSources;
+
+    let sources = match sources {
+        ScanSources::Paths(paths) => {
+            polars_ensure!(
+                !sources.is_cloud_url(),
+                nyi = "categorical dictionaries for cloud scans"
+            );
+            ScanSources::Paths(polars_io::path_utils::expand_paths(
+                paths,
+                glob,
+                cloud_options,
+            )?)
+        },
+        sources => sources.clone(),
+    };
+    for source in sources.iter() {
+        f(source.to_memslice()?)?;
+    }
+    Ok(())
+}
@@ -23,6 +23,9 @@ pub struct ScanArgsParquet {
     pub glob: bool,
     pub include_file_paths: Option<PlSmallStr>,
     pub allow_missing_columns: bool,
+    /// Encode these columns with persisted categorical dictionaries.
+    #[cfg(feature = "dtype-categorical")]
+    pub categorical_dictionaries: Option<CategoricalDictionaries>,
 }
 
 impl Default for ScanArgsParquet {
@@ -41,6 +44,8 @@ impl Default for ScanArgsParquet {
             glob: true,
             include_file_paths: None,
             allow_missing_columns: false,
+            #[cfg(feature = "dtype-categorical")]
+            categorical_dictionaries: None,
         }
     }
 }
@@ -65,6 +70,31 @@ impl LazyFileListReader for LazyParquetReader {
     fn finish(self) -> PolarsResult<LazyFrame> {
         let row_index = self.args.row_index;
 
+        #[cfg(feature = "dtype-categorical")]
+        if let Some(dictionaries) = &self.args.categorical_dictionaries {
+            use polars_io::SerReader;
+            use polars_io::parquet::read::ParquetReader;
+
+            super::for_each_source_file(
+                &self.sources,
+                self.args.glob,
+                self.args.cloud_options.as_ref(),
+                |memslice| {
+                    let mut reader = ParquetReader::new(std::io::Cursor::new(memslice));
+                    let Some(metadata) = reader.get_metadata()?.key_value_metadata().as_ref()
+                    else {
+                        return Ok(());
+                    };
+                    validate_categorical_dictionaries(
+                        metadata
+                            .iter()
+                            .filter_map(|kv| Some((kv.key.as_str(), kv.value.as_deref()?))),
+                        dictionaries,
+                    )
+                },
+            )?;
+        }
+
         let mut lf: LazyFrame = DslBuilder::scan_parquet(
             self.sources,
             self.args.n_rows,
@@ -89,6 +119,11 @@ impl LazyFileListReader for LazyParquetReader {
             lf = lf.with_row_index(row_index.name.clone(), Some(row_index.offset))
         }
 
+        #[cfg(feature = "dtype-categorical")]
+        if let Some(dictionaries) = &self.args.categorical_dictionaries {
+            lf = lf.with_categorical_dictionaries(dictionaries);
+        }
+
         Ok(lf)
     }
 
@@ -1,5 +1,7 @@
 use std::collections::BTreeSet;
 
+use polars_plan::constants::CSE_HOISTED;
+
 use super::*;
 
 fn cached_before_root(q: LazyFrame) {
@@ -353,3 +355,176 @@ fn test_cse_prune_scan_filter_difference() -> PolarsResult<()> {
 
     Ok(())
 }
+
+/// Write `contents` to `path` with the given modification time, which is part of the
+/// fingerprint of a scan: overwriting a file with the same modification time makes a hit in the
+/// result cache observable.
+#[cfg(feature = "csv")]
+fn write_with_mtime(path: &std::path::Path, contents: &str, mtime: std::time::SystemTime) {
+    std::fs::write(path, contents).unwrap();
+    std::fs::File::options()
+        .write(true)
+        .open(path)
+        .unwrap()
+        .set_modified(mtime)
+        .unwrap();
+}
+
+#[test]
+#[cfg(feature = "csv")]
+fn test_result_cache_across_collects() -> PolarsResult<()> {
+    let path = std::env::temp_dir().join("polars_test_result_cache.csv");
+    let mtime = std::time::SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(1_000_000);
+    let query = || {
+        LazyCsvReader::new(&path)
+            .finish()
+            .unwrap()
+            .filter(col("a").gt(lit(1i64)))
+            .cache()
+            .select([col("a").sum()])
+            .with_result_cache(true)
+    };
+
+    write_with_mtime(&path, "a\n1\n2\n3\n", mtime);
+    let expected = df![ "a" => [5i64] ]?;
+    assert!(query().collect()?.equals(&expected));
+
+    // Same plan and modification time: the cached result is reused.
+    write_with_mtime(&path, "a\n10\n20\n30\n", mtime);
+    assert!(query().collect()?.equals(&expected));
+
+    // A new modification time invalidates the cached result.
+    let mtime = mtime + std::time::Duration::from_secs(1);
+    write_with_mtime(&path, "a\n10\n20\n30\n", mtime);
+    let expected = df![ "a" => [60i64] ]?;
+    assert!(query().collect()?.equals(&expected));
+
+    let _ = std::fs::remove_file(&path);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "csv")]
+fn test_result_cache_without_cache_nodes() -> PolarsResult<()> {
+    let path = std::env::temp_dir().join("polars_test_result_cache_no_cache_nodes.csv");
+    let mtime = std::time::SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(1_000_000);
+    // Neither query contains a `Cache` node; the second one only shares the aggregation.
+    let agg = || {
+        LazyCsvReader::new(&path)
+            .finish()
+            .unwrap()
+            .group_by_stable([col("k")])
+            .agg([col("v").sum()])
+    };
+    let query = || {
+        agg()
+            .sort(["k"], Default::default())
+            .with_result_cache(true)
+    };
+    let filtered = || agg().filter(col("v").gt(lit(2i64))).with_result_cache(true);
+
+    write_with_mtime(&path, "k,v\na,1\nb,2\na,3\n", mtime);
+    let expected = df![ "k" => ["a", "b"], "v" => [4i64, 2] ]?;
+    assert!(query().collect()?.equals(&expected));
+
+    // Same plans and modification time: the cached results are reused, also by another query
+    // that contains the same aggregation.
+    write_with_mtime(&path, "k,v\na,10\nb,20\na,30\n", mtime);
+    assert!(query().collect()?.equals(&expected));
+    let expected = df![ "k" => ["a"], "v" => [4i64] ]?;
+    assert!(filtered().collect()?.equals(&expected));
+
+    let _ = std::fs::remove_file(&path);
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "new_streaming", feature = "csv"))]
+fn test_result_cache_streaming_engine() -> PolarsResult<()> {
+    let path = std::env::temp_dir().join("polars_test_result_cache_streaming.csv");
+    let mtime = std::time::SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(1_000_000);
+    let query = || {
+        LazyCsvReader::new(&path)
+            .finish()
+            .unwrap()
+            .filter(col("a").gt(lit(1i64)))
+            .cache()
+            .select([col("a").sum()])
+            .with_result_cache(true)
+    };
+
+    write_with_mtime(&path, "a\n1\n2\n3\n", mtime);
+    let expected = df![ "a" => [5i64] ]?;
+    let out = query().collect_with_engine(Engine::Streaming)?;
+    assert!(out.equals(&expected));
+
+    // The result computed by the streaming engine is reused.
+    write_with_mtime(&path, "a\n10\n20\n30\n", mtime);
+    let out = query().collect_with_engine(Engine::Streaming)?;
+    assert!(out.equals(&expected));
+
+    let _ = std::fs::remove_file(&path);
+    Ok(())
+}
+
+#[test]
+fn test_cse_hoist_across_filter() -> PolarsResult<()> {
+    let df = df![
+        "s" => ["a1", "b22", "c", "d333"],
+        "a" => [1, 2, 3, 4],
+    ]?;
+    let digits = || col("s").str().extract(lit(r"(\d+)"), 1);
+
+    let hoisted_count = |q: LazyFrame| {
+        let IRPlan {
+            lp_top, lp_arena, ..
+        } = q.to_alp_optimized().unwrap();
+        (&lp_arena)
+            .iter(lp_top)
+            .filter(|(_, lp)| match lp {
+                IR::HStack { exprs, .. } => exprs
+                    .iter()
+                    .any(|e| e.output_name().starts_with(CSE_HOISTED)),
+                _ => false,
+            })
+            .count()
+    };
+
+    // The regex extraction is evaluated once and shared by the filter and the projection.
+    let q = df
+        .clone()
+        .lazy()
+        .filter(digits().is_not_null())
+        .select([digits().alias("digits"), col("a")])
+        .with_comm_subexpr_elim(true);
+    assert_eq!(hoisted_count(q.clone()), 1);
+    let out = q.collect()?;
+    let expected = df![
+        "digits" => ["1", "22", "333"],
+        "a" => [1, 2, 4],
+    ]?;
+    assert!(out.equals(&expected));
+
+    // Also across `with_columns`, without leaking the temporary column.
+    let q = df
+        .clone()
+        .lazy()
+        .filter(digits().is_not_null())
+        .with_column(digits().alias("digits"))
+        .with_comm_subexpr_elim(true);
+    assert_eq!(hoisted_count(q.clone()), 1);
+    let out = q.collect()?;
+    assert_eq!(out.get_column_names(), &["s", "a", "digits"]);
+    assert_eq!(out.height(), 3);
+
+    // Cheap expressions are not hoisted.
+    let q = df
+        .lazy()
+        .filter((col("a") + lit(1)).gt(lit(2)))
+        .select([(col("a") + lit(1)).alias("b")])
+        .with_comm_subexpr_elim(true);
+    assert_eq!(hoisted_count(q.clone()), 0);
+    assert_eq!(q.collect()?.height(), 3);
+
+    Ok(())
+}
@@ -420,6 +420,8 @@ fn test_ipc_globbing() -> PolarsResult<()> {
             cloud_options: None,
             hive_options: Default::default(),
             include_file_paths: None,
+            #[cfg(feature = "dtype-categorical")]
+            categorical_dictionaries: None,
         },
     )?
     .collect()?;
@@ -675,3 +675,158 @@ fn test_cluster_with_columns_chain() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[test]
+fn test_optimization_trace() -> PolarsResult<()> {
+    let df = df!("a" => [1, 2, 3], "b" => [4, 5, 6])?;
+
+    let q = df
+        .lazy()
+        .with_column(col("a").alias("c"))
+        .filter(col("b").gt(lit(4)))
+        .select([col("c")]);
+
+    let trace = q.describe_optimization_trace()?;
+    let names = trace
+        .passes
+        .iter()
+        .map(|p| p.name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(names.first(), Some(&"to_ir"));
+    assert!(names.contains(&"projection_pushdown"));
+
+    let pd = trace
+        .passes
+        .iter()
+        .find(|p| p.name == "predicate_pushdown")
+        .unwrap();
+    assert!(pd.changed());
+    assert!(
+        pd.diff()
+            .iter()
+            .any(|l| l.starts_with('-') && l.contains("FILTER"))
+    );
+
+    // The plan after the last pass is the optimized plan.
+    assert_eq!(
+        trace.passes.last().unwrap().after,
+        q.describe_optimized_plan()?
+    );
+    Ok(())
+}
+
+#[test]
+fn test_describe_plan_json() -> PolarsResult<()> {
+    let df = df!("a" => [1, 2, 3], "b" => ["x", "y", "z"])?;
+    let q = df
+        .lazy()
+        .filter(col("a").gt(lit(1)))
+        .select([col("b"), (col("a") * lit(2)).alias(r#"c "\n""#)]);
+
+    let plan: serde_json::Value = serde_json::from_str(&q.describe_plan_json(true)?).unwrap();
+    assert_eq!(plan["version"], 1);
+    let nodes = plan["nodes"].as_array().unwrap();
+    let root = nodes.iter().find(|n| n["id"] == plan["root"]).unwrap();
+    assert_eq!(root["kind"], "SELECT");
+    assert_eq!(root["exprs"].as_array().unwrap().len(), 2);
+    assert_eq!(root["schema"][1]["name"], r#"c "\n""#);
+
+    let scan = nodes
+        .iter()
+        .find(|n| n["kind"] == "DATA_FRAME_SCAN")
+        .unwrap();
+    assert_eq!(scan["estimated_rows"], 3);
+
+    #[cfg(feature = "new_streaming")]
+    {
+        let plan: serde_json::Value =
+            serde_json::from_str(&q.describe_physical_plan_json()?).unwrap();
+        let nodes = plan["nodes"].as_array().unwrap();
+        assert!(nodes.iter().any(|n| n["kind"] == "in-memory-sink"));
+        assert!(nodes.iter().any(|n| n["kind"] == "filter"));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_user_optimization_rules() -> PolarsResult<()> {
+    // Rewrites `<expr> * 12345` into `<expr> + 12345`.
+    struct MultiplyToAdd;
+
+    impl OptimizationRule for MultiplyToAdd {
+        fn optimize_expr(
+            &mut self,
+            expr_arena: &mut Arena<AExpr>,
+            expr_node: Node,
+            _lp_arena: &Arena<IR>,
+            _lp_node: Node,
+        ) -> PolarsResult<Option<AExpr>> {
+            Ok(match expr_arena.get(expr_node) {
+                AExpr::BinaryExpr {
+                    left,
+                    op: Operator::Multiply,
+                    right,
+                } if matches!(
+                    expr_arena.get(*right),
+                    AExpr::Literal(lv) if lv.to_any_value().and_then(|av| av.extract::<i64>()) == Some(12345)
+                ) =>
+                {
+                    Some(AExpr::BinaryExpr {
+                        left: *left,
+                        op: Operator::Plus,
+                        right: *right,
+                    })
+                },
+                _ => None,
+            })
+        }
+    }
+
+    let rule = "multiply_to_add";
+    let df = df!("a" => [1i64, 2])?;
+    // The rule is passed on to the derived frames.
+    let q = df
+        .clone()
+        .lazy()
+        .with_optimization_rule(rule, || Box::new(MultiplyToAdd))
+        .select([col("a") * lit(12345i64)]);
+    assert!(q.optimization_rules().names().eq([rule]));
+    let out = q.clone().collect()?;
+    assert_eq!(out.column("a")?.i64()?.get(1), Some(12347));
+
+    // Every rule is traced separately, under the name it was added with.
+    let trace = q.describe_optimization_trace()?;
+    assert!(trace.passes.iter().all(|p| p.name != "stack_optimizer"));
+    let pass = trace
+        .passes
+        .iter()
+        .find(|p| p.name == rule && p.changed())
+        .unwrap();
+    assert!(
+        pass.diff()
+            .iter()
+            .any(|l| l.starts_with('+') && l.contains("+ (12345)"))
+    );
+
+    let out = q.clone().with_user_optimization_rules(false).collect()?;
+    assert_eq!(out.column("a")?.i64()?.get(1), Some(24690));
+
+    // Queries that are collected together are optimized with the rules of all of them.
+    let other = df.clone().lazy().select([col("a") * lit(12345i64)]);
+    let outs = LazyFrame::collect_all_with_engine(
+        vec![other, q.clone()],
+        Engine::InMemory,
+        OptFlags::default(),
+    )?;
+    for out in outs {
+        assert_eq!(out.column("a")?.i64()?.get(1), Some(12347));
+    }
+
+    let out = q.without_optimization_rule(rule).collect()?;
+    assert_eq!(out.column("a")?.i64()?.get(1), Some(24690));
+
+    // Other queries are not affected.
+    let out = df.lazy().select([col("a") * lit(12345i64)]).collect()?;
+    assert_eq!(out.column("a")?.i64()?.get(1), Some(24690));
+    Ok(())
+}
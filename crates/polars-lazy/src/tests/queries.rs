@@ -1052,6 +1052,72 @@ fn test_group_by_cum_sum() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_group_by_grouping_sets() -> PolarsResult<()> {
+    let df = df![
+        "region" => ["eu", "eu", "eu", "us"],
+        "city" => ["ams", "ams", "ber", "nyc"],
+        "sales" => [1, 2, 3, 4],
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .group_by_stable([col("region"), col("city")])
+        .agg_grouping_sets(&GroupingSets::rollup(2), [col("sales").sum()])?
+        .collect()?;
+    let expected = df![
+        "region" => [Some("eu"), Some("eu"), Some("us"), Some("eu"), Some("us"), None],
+        "city" => [Some("ams"), Some("ber"), Some("nyc"), None, None, None],
+        "sales" => [3, 3, 4, 6, 4, 10],
+        GROUPING_ID => [0u64, 0, 0, 1, 1, 3],
+    ]?;
+    assert!(out.equals_missing(&expected), "{out:?}");
+
+    let out = df
+        .clone()
+        .lazy()
+        .group_by_stable([col("region"), col("city")])
+        .agg_grouping_sets(&GroupingSets::cube(2)?, [col("sales").sum()])?
+        .filter(col(GROUPING_ID).eq(lit(2u64)))
+        .collect()?;
+    let expected = df![
+        "region" => [None::<&str>, None, None],
+        "city" => ["ams", "ber", "nyc"],
+        "sales" => [3, 3, 4],
+        GROUPING_ID => [2u64, 2, 2],
+    ]?;
+    assert!(out.equals_missing(&expected), "{out:?}");
+
+    // The grand total is a single row, even without input rows.
+    let out = df
+        .clone()
+        .lazy()
+        .filter(col("sales").gt(lit(10)))
+        .group_by_stable([col("region")])
+        .agg_grouping_sets(&GroupingSets::rollup(1), [col("sales").count()])?
+        .collect()?;
+    let expected = df![
+        "region" => [None::<&str>],
+        "sales" => [0 as IdxSize],
+        GROUPING_ID => [1u64],
+    ]?;
+    assert!(out.equals_missing(&expected), "{out:?}");
+
+    assert!(GroupingSets::cube(GroupingSets::MAX_CUBE_KEYS).is_ok());
+    assert!(GroupingSets::cube(GroupingSets::MAX_CUBE_KEYS + 1).is_err());
+    assert!(GroupingSets::cube(64).is_err());
+    let keys = (0..64).map(|_| col("region")).collect::<Vec<_>>();
+    assert!(
+        df.lazy()
+            .group_by(keys)
+            .agg_grouping_sets(&GroupingSets::rollup(64), [col("sales").sum()])
+            .is_err()
+    );
+
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "range")]
 fn test_arg_sort_multiple() -> PolarsResult<()> {
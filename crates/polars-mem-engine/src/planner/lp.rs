@@ -259,6 +259,7 @@ fn create_physical_plan_impl(
                                 .with_statistics(options.statistics)
                                 .with_row_group_size(options.row_group_size)
                                 .with_data_page_size(options.data_page_size)
+                                .with_custom_metadata(sink_options.custom_metadata())
                                 .finish(&mut df)?;
 
                             if let Writeable::Local(file) = &mut file {
@@ -292,10 +293,14 @@ fn create_physical_plan_impl(
                                 &path,
                                 cloud_options.as_ref(),
                             )?;
-                            IpcWriter::new(BufWriter::new(file.deref_mut()))
+                            let mut writer = IpcWriter::new(BufWriter::new(file.deref_mut()))
                                 .with_compression(options.compression)
-                                .with_compat_level(options.compat_level)
-                                .finish(&mut df)?;
+                                .with_compat_level(options.compat_level);
+                            if let Some(custom_metadata) = sink_options.custom_metadata() {
+                                writer.set_custom_schema_metadata(custom_metadata);
+                            }
+                            writer.finish(&mut df)?;
+                            drop(writer);
 
                             if let Writeable::Local(file) = &mut file {
                                 polars_io::utils::sync_on_close::sync_on_close(
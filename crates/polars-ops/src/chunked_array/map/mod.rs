@@ -0,0 +1,190 @@
+use arrow::array::{Array, ListArray};
+use arrow::bitmap::BitmapBuilder;
+use arrow::offset::OffsetsBuffer;
+use polars_core::prelude::*;
+
+pub trait MapNameSpace {
+    fn as_map(&self) -> &MapChunked;
+
+    /// Get the keys of every map as a list.
+    fn map_keys(&self) -> PolarsResult<ListChunked> {
+        entries_field(self.as_map(), 0)
+    }
+
+    /// Get the values of every map as a list.
+    fn map_values(&self) -> PolarsResult<ListChunked> {
+        entries_field(self.as_map(), 1)
+    }
+
+    /// Look up the value of `key` in every map; `key` is either a single key or one key per
+    /// map. Returns null if the key is not present; if a map contains the key more than once,
+    /// the first entry is used.
+    fn map_get(&self, key: &Series) -> PolarsResult<Series> {
+        let ca = self.as_map();
+        polars_ensure!(
+            key.len() == 1 || key.len() == ca.len(),
+            ShapeMismatch: "`map.get` got {} keys for {} maps", key.len(), ca.len()
+        );
+        let key = key.cast(ca.key_dtype())?;
+
+        let list = ca.physical().rechunk();
+        let arr = list.downcast_as_array();
+        let offsets = arr.offsets();
+        let start = *offsets.first() as usize;
+        let entries = ca
+            .get_entries()
+            .slice(start as i64, offsets.range() as usize);
+        let entries = entries.struct_()?;
+        let fields = entries.fields_as_series();
+
+        let keys = if key.len() == 1 {
+            key
+        } else {
+            let rows = offsets
+                .lengths()
+                .enumerate()
+                .flat_map(|(row, len)| std::iter::repeat_n(row as IdxSize, len))
+                .collect_ca(PlSmallStr::EMPTY);
+            // SAFETY: row indices are in bounds.
+            unsafe { key.take_unchecked(&rows) }
+        };
+        let matches = fields[0].equal(&keys)?;
+        let matches = matches.rechunk();
+        let matches = matches.downcast_as_array();
+
+        let idx: IdxCa = offsets
+            .offset_and_length_iter()
+            .map(|(offset, len)| {
+                let offset = offset - start;
+                (offset..offset + len)
+                    .find(|&i| matches.is_valid(i) && matches.value(i))
+                    .map(|i| i as IdxSize)
+            })
+            .collect();
+        let mut out = fields[1].take(&idx)?;
+        out.rename(ca.name().clone());
+        Ok(out)
+    }
+}
+
+impl MapNameSpace for MapChunked {
+    fn as_map(&self) -> &MapChunked {
+        self
+    }
+}
+
+fn entries_field(ca: &MapChunked, field: usize) -> PolarsResult<ListChunked> {
+    ca.physical()
+        .apply_to_inner(&|s| Ok(s.struct_()?.fields_as_series()[field].clone()))
+}
+
+/// Create a map from a list of keys and a list of values of the same lengths. A map is null if
+/// either list is null.
+pub fn map_from_lists(keys: &ListChunked, values: &ListChunked) -> PolarsResult<MapChunked> {
+    polars_ensure!(
+        keys.len() == values.len(),
+        ShapeMismatch: "cannot create maps from {} lists of keys and {} lists of values", keys.len(), values.len()
+    );
+    let keys = keys.rechunk();
+    let values = values.rechunk();
+    let key_arr = keys.downcast_as_array();
+    let value_arr = values.downcast_as_array();
+
+    let mut key_idx = Vec::new();
+    let mut value_idx = Vec::new();
+    let mut offsets = Vec::with_capacity(keys.len() + 1);
+    offsets.push(0i64);
+    let mut validity = BitmapBuilder::with_capacity(keys.len());
+    for (row, ((k_offset, k_len), (v_offset, v_len))) in key_arr
+        .offsets()
+        .offset_and_length_iter()
+        .zip(value_arr.offsets().offset_and_length_iter())
+        .enumerate()
+    {
+        let is_valid = key_arr.is_valid(row) && value_arr.is_valid(row);
+        if is_valid {
+            polars_ensure!(
+                k_len == v_len,
+                ShapeMismatch: "map keys and values must have the same length; found {} keys and {} values in row {}",
+                k_len, v_len, row
+            );
+            key_idx.extend((k_offset..k_offset + k_len).map(|i| i as IdxSize));
+            value_idx.extend((v_offset..v_offset + v_len).map(|i| i as IdxSize));
+        }
+        validity.push(is_valid);
+        offsets.push(key_idx.len() as i64);
+    }
+
+    let mut key = keys.get_inner().take_slice(&key_idx)?;
+    key.rename(PlSmallStr::from_static(MAP_KEY_NAME));
+    let mut value = values.get_inner().take_slice(&value_idx)?;
+    value.rename(PlSmallStr::from_static(MAP_VALUE_NAME));
+    let entries = StructChunked::from_series(PlSmallStr::EMPTY, key.len(), [key, value].iter())?
+        .into_series();
+
+    let entries_arr = entries.to_physical_repr().rechunk().chunks()[0].clone();
+    // SAFETY: the offsets are monotonically increasing and end at the number of entries.
+    let offsets = unsafe { OffsetsBuffer::new_unchecked(offsets.into()) };
+    let arr = ListArray::<i64>::new(
+        ListArray::<i64>::default_datatype(entries_arr.dtype().clone()),
+        offsets,
+        entries_arr,
+        validity.into_opt_validity(),
+    );
+    // SAFETY: the array holds the physical representation of the entries.
+    let list = unsafe {
+        Series::from_chunks_and_dtype_unchecked(
+            keys.name().clone(),
+            vec![arr.boxed()],
+            &DataType::List(Box::new(entries.dtype().clone())),
+        )
+    };
+    list.list()?.clone().into_map()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_map_from_lists_and_get() -> PolarsResult<()> {
+        let keys = ListChunked::from_iter([
+            Some(Series::new("".into(), ["a", "b"])),
+            None,
+            Some(Series::new("".into(), ["b", "b"])),
+        ]);
+        let values = ListChunked::from_iter([
+            Some(Series::new("".into(), [1i32, 2])),
+            Some(Series::new("".into(), [3i32])),
+            Some(Series::new("".into(), [4i32, 5])),
+        ]);
+        let map = map_from_lists(&keys, &values)?;
+        assert_eq!(
+            map.dtype(),
+            &DataType::Map(Box::new(DataType::String), Box::new(DataType::Int32))
+        );
+        assert_eq!(map.null_count(), 1);
+        assert!(
+            map.map_keys()?.into_series().equals_missing(
+                &ListChunked::from_iter([
+                    Some(Series::new("".into(), ["a", "b"])),
+                    None,
+                    Some(Series::new("".into(), ["b", "b"])),
+                ])
+                .into_series()
+            )
+        );
+
+        let out = map.map_get(&Series::new("".into(), ["b"]))?;
+        assert_eq!(Vec::from(out.i32()?), &[Some(2), None, Some(4)]);
+        let out = map.map_get(&Series::new("".into(), ["a", "a", "c"]))?;
+        assert_eq!(Vec::from(out.i32()?), &[Some(1), None, None]);
+        let out = map.map_values()?.lst_lengths();
+        assert_eq!(Vec::from(&out), &[Some(2), None, Some(2)]);
+
+        assert!(map_from_lists(&keys, &keys.slice(0, 2)).is_err());
+        assert!(map_from_lists(&values, &keys.slice(1, 2)).is_err());
+        Ok(())
+    }
+}
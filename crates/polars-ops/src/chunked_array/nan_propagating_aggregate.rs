@@ -41,6 +41,10 @@ pub fn nan_min_s(s: &Series, name: PlSmallStr) -> Series {
             let ca = s.f64().unwrap();
             Series::new(name, [ca_nan_agg(ca, MinMax::min_propagate_nan)])
         },
+        // Other floats (half precision) are aggregated in single precision.
+        dt if dt.is_float() => nan_min_s(&s.cast(&DataType::Float32).unwrap(), name)
+            .cast(dt)
+            .unwrap(),
         _ => panic!("expected float"),
     }
 }
@@ -55,6 +59,9 @@ pub fn nan_max_s(s: &Series, name: PlSmallStr) -> Series {
             let ca = s.f64().unwrap();
             Series::new(name, [ca_nan_agg(ca, MinMax::max_propagate_nan)])
         },
+        dt if dt.is_float() => nan_max_s(&s.cast(&DataType::Float32).unwrap(), name)
+            .cast(dt)
+            .unwrap(),
         _ => panic!("expected float"),
     }
 }
@@ -209,6 +216,9 @@ pub unsafe fn group_agg_nan_min_s(s: &Series, groups: &GroupsType) -> Series {
             let ca = s.f64().unwrap();
             group_nan_min(ca, groups)
         },
+        dt if dt.is_float() => group_agg_nan_min_s(&s.cast(&DataType::Float32).unwrap(), groups)
+            .cast(dt)
+            .unwrap(),
         _ => panic!("expected float"),
     }
 }
@@ -225,6 +235,9 @@ pub unsafe fn group_agg_nan_max_s(s: &Series, groups: &GroupsType) -> Series {
             let ca = s.f64().unwrap();
             group_nan_max(ca, groups)
         },
+        dt if dt.is_float() => group_agg_nan_max_s(&s.cast(&DataType::Float32).unwrap(), groups)
+            .cast(dt)
+            .unwrap(),
         _ => panic!("expected float"),
     }
 }
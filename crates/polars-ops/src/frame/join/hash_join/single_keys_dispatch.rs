@@ -11,6 +11,17 @@ use polars_utils::total_ord::{ToTotalOrd, TotalEq, TotalHash};
 use super::*;
 use crate::series::SeriesSealed;
 
+/// The physical representation the single key join kernels operate on.
+///
+/// UUIDs are joined as packed fixed-width integers instead of their `Array(UInt8, 16)` physical.
+fn to_join_key_repr(s: &Series) -> Cow<'_, Series> {
+    match s.dtype() {
+        #[cfg(feature = "dtype-uuid")]
+        DataType::Uuid => Cow::Owned(s.uuid().unwrap().to_i128().into_series()),
+        _ => s.to_physical_repr(),
+    }
+}
+
 pub trait SeriesJoin: SeriesSealed + Sized {
     #[doc(hidden)]
     fn hash_join_left(
@@ -20,7 +31,7 @@ pub trait SeriesJoin: SeriesSealed + Sized {
         nulls_equal: bool,
     ) -> PolarsResult<LeftJoinIds> {
         let s_self = self.as_series();
-        let (lhs, rhs) = (s_self.to_physical_repr(), other.to_physical_repr());
+        let (lhs, rhs) = (to_join_key_repr(s_self), to_join_key_repr(other));
         validate.validate_probe(&lhs, &rhs, false, nulls_equal)?;
 
         let lhs_dtype = lhs.dtype();
@@ -89,6 +100,12 @@ pub trait SeriesJoin: SeriesSealed + Sized {
                     num_group_join_left(lhs, rhs, validate, nulls_equal)
                 })
             },
+            #[cfg(feature = "dtype-i128")]
+            T::Int128 => {
+                let lhs = lhs.i128().unwrap();
+                let rhs = rhs.i128().unwrap();
+                num_group_join_left(lhs, rhs, validate, nulls_equal)
+            },
             _ => {
                 let lhs = s_self.bit_repr();
                 let rhs = other.bit_repr();
@@ -125,7 +142,7 @@ pub trait SeriesJoin: SeriesSealed + Sized {
         nulls_equal: bool,
     ) -> PolarsResult<Vec<IdxSize>> {
         let s_self = self.as_series();
-        let (lhs, rhs) = (s_self.to_physical_repr(), other.to_physical_repr());
+        let (lhs, rhs) = (to_join_key_repr(s_self), to_join_key_repr(other));
 
         let lhs_dtype = lhs.dtype();
         let rhs_dtype = rhs.dtype();
@@ -184,6 +201,12 @@ pub trait SeriesJoin: SeriesSealed + Sized {
                     num_group_join_anti_semi(lhs, rhs, anti, nulls_equal)
                 })
             },
+            #[cfg(feature = "dtype-i128")]
+            T::Int128 => {
+                let lhs = lhs.i128().unwrap();
+                let rhs = rhs.i128().unwrap();
+                num_group_join_anti_semi(lhs, rhs, anti, nulls_equal)
+            },
             _ => {
                 let lhs = s_self.bit_repr();
                 let rhs = other.bit_repr();
@@ -220,7 +243,7 @@ pub trait SeriesJoin: SeriesSealed + Sized {
         nulls_equal: bool,
     ) -> PolarsResult<(InnerJoinIds, bool)> {
         let s_self = self.as_series();
-        let (lhs, rhs) = (s_self.to_physical_repr(), other.to_physical_repr());
+        let (lhs, rhs) = (to_join_key_repr(s_self), to_join_key_repr(other));
         validate.validate_probe(&lhs, &rhs, true, nulls_equal)?;
 
         let lhs_dtype = lhs.dtype();
@@ -302,6 +325,12 @@ pub trait SeriesJoin: SeriesSealed + Sized {
                     group_join_inner::<$T>(lhs, rhs, validate, nulls_equal)
                 })
             },
+            #[cfg(feature = "dtype-i128")]
+            T::Int128 => {
+                let lhs = lhs.i128().unwrap();
+                let rhs = rhs.i128().unwrap();
+                group_join_inner::<Int128Type>(lhs, rhs, validate, nulls_equal)
+            },
             _ => {
                 let lhs = s_self.bit_repr();
                 let rhs = other.bit_repr();
@@ -337,7 +366,7 @@ pub trait SeriesJoin: SeriesSealed + Sized {
         nulls_equal: bool,
     ) -> PolarsResult<(PrimitiveArray<IdxSize>, PrimitiveArray<IdxSize>)> {
         let s_self = self.as_series();
-        let (lhs, rhs) = (s_self.to_physical_repr(), other.to_physical_repr());
+        let (lhs, rhs) = (to_join_key_repr(s_self), to_join_key_repr(other));
         validate.validate_probe(&lhs, &rhs, true, nulls_equal)?;
 
         let lhs_dtype = lhs.dtype();
@@ -389,6 +418,12 @@ pub trait SeriesJoin: SeriesSealed + Sized {
                     hash_join_outer(lhs, rhs, validate, nulls_equal)
                 })
             },
+            #[cfg(feature = "dtype-i128")]
+            T::Int128 => {
+                let lhs = lhs.i128().unwrap();
+                let rhs = rhs.i128().unwrap();
+                hash_join_outer(lhs, rhs, validate, nulls_equal)
+            },
             _ => {
                 let (Some(lhs), Some(rhs)) = (s_self.bit_repr(), other.bit_repr()) else {
                     polars_bail!(nyi = "Hash Join Outer between {lhs_dtype} and {rhs_dtype}");
@@ -161,6 +161,33 @@ where
     out.with_name(ca.name().clone())
 }
 
+/// Cumulative sum of decimal mantissas that errors instead of exceeding the decimal precision.
+#[cfg(feature = "dtype-decimal")]
+fn cum_sum_decimal(ca: &Int128Chunked, reverse: bool) -> PolarsResult<Int128Chunked> {
+    use polars_core::chunked_array::ops::decimal::dec128_add;
+
+    let mut overflow = false;
+    let mut step = |state: &mut i128, v: Option<i128>| match v {
+        Some(v) => match dec128_add(*state, 0, v, 0) {
+            Some(sum) => {
+                *state = sum;
+                Some(Some(sum))
+            },
+            None => {
+                overflow = true;
+                Some(None)
+            },
+        },
+        None => Some(None),
+    };
+    let out: Int128Chunked = match reverse {
+        false => ca.iter().scan(0, &mut step).collect_trusted(),
+        true => ca.iter().rev().scan(0, &mut step).collect_reversed(),
+    };
+    polars_ensure!(!overflow, ComputeError: "decimal overflow in cum_sum");
+    Ok(out.with_name(ca.name().clone()))
+}
+
 fn cum_prod_numeric<T>(ca: &ChunkedArray<T>, reverse: bool) -> ChunkedArray<T>
 where
     T: PolarsNumericType,
@@ -222,7 +249,7 @@ pub fn cum_sum(s: &Series, reverse: bool) -> PolarsResult<Series> {
         #[cfg(feature = "dtype-decimal")]
         Decimal(precision, scale) => {
             let ca = s.decimal().unwrap().as_ref();
-            cum_sum_numeric(ca, reverse)
+            cum_sum_decimal(ca, reverse)?
                 .into_decimal_unchecked(*precision, scale.unwrap())
                 .into_series()
         },
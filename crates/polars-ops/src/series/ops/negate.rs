@@ -27,6 +27,14 @@ pub fn negate(s: &Series) -> PolarsResult<Series> {
             let out = ca.wrapping_neg().into_series();
             out.cast(s.dtype())?
         },
+        #[cfg(feature = "dtype-interval")]
+        Interval => {
+            let ca = s.interval().unwrap();
+            let out = ca
+                .iter_intervals()
+                .map(|opt_v| opt_v.map(|v| months_days_ns::new(-v.months(), -v.days(), -v.ns())));
+            IntervalChunked::from_intervals(ca.name().clone(), out).into_series()
+        },
         dt => polars_bail!(opq = neg, dt),
     };
     Ok(out)
@@ -6,63 +6,68 @@ use crate::series::ops::SeriesSealed;
 
 pub trait RoundSeries: SeriesSealed {
     /// Round underlying floating point array to given decimal.
+    ///
+    /// Floats round half away from zero, decimals use the default [`RoundMode`].
     fn round(&self, decimals: u32) -> PolarsResult<Series> {
+        self.round_with_mode(decimals, None)
+    }
+
+    /// Round underlying floating point or decimal array to given decimal with the given
+    /// rounding mode, see [`RoundSeries::round`] for the default.
+    fn round_with_mode(&self, decimals: u32, mode: Option<RoundMode>) -> PolarsResult<Series> {
         let s = self.as_series();
 
         if let Ok(ca) = s.f32() {
             return if decimals == 0 {
-                let s = ca.apply_values(|val| val.round()).into_series();
+                let s = ca
+                    .apply_values(|val| round_float(val as f64, mode) as f32)
+                    .into_series();
                 Ok(s)
             } else {
                 // Note we do the computation on f64 floats to not lose precision
                 // when the computation is done, we cast to f32
                 let multiplier = 10.0.pow(decimals as f64);
                 let s = ca
-                    .apply_values(|val| ((val as f64 * multiplier).round() / multiplier) as f32)
+                    .apply_values(|val| {
+                        (round_float(val as f64 * multiplier, mode) / multiplier) as f32
+                    })
                     .into_series();
                 Ok(s)
             };
         }
         if let Ok(ca) = s.f64() {
             return if decimals == 0 {
-                let s = ca.apply_values(|val| val.round()).into_series();
+                let s = ca.apply_values(|val| round_float(val, mode)).into_series();
                 Ok(s)
             } else {
                 let multiplier = 10.0.pow(decimals as f64);
                 let s = ca
-                    .apply_values(|val| (val * multiplier).round() / multiplier)
+                    .apply_values(|val| round_float(val * multiplier, mode) / multiplier)
                     .into_series();
                 Ok(s)
             };
         }
         #[cfg(feature = "dtype-decimal")]
         if let Some(ca) = s.try_decimal() {
+            use polars_core::chunked_array::ops::decimal::dec128_rescale;
+
             let precision = ca.precision();
-            let scale = ca.scale() as u32;
+            let scale = ca.scale();
+            let decimals = decimals as usize;
 
             if scale <= decimals {
                 return Ok(ca.clone().into_series());
             }
 
-            let decimal_delta = scale - decimals;
-            let multiplier = 10i128.pow(decimal_delta);
-            let threshold = multiplier / 2;
-
+            let mode = mode.unwrap_or_default();
             let ca = ca
-                .apply_values(|v| {
-                    // We use rounding=ROUND_HALF_EVEN
-                    let rem = v % multiplier;
-                    let is_v_floor_even = ((v - rem) / multiplier) % 2 == 0;
-                    let threshold = threshold + i128::from(is_v_floor_even);
-                    let round_offset = if rem.abs() >= threshold {
-                        multiplier
-                    } else {
-                        0
-                    };
-                    let round_offset = if v < 0 { -round_offset } else { round_offset };
-                    v - rem + round_offset
-                })
-                .into_decimal_unchecked(precision, scale as usize);
+                .physical()
+                .try_apply_nonnull_values_generic::<Int128Type, _, _, _>(|v| {
+                    dec128_rescale(v, scale, decimals, mode)
+                        .and_then(|v| dec128_rescale(v, decimals, scale, mode))
+                        .ok_or_else(|| polars_err!(ComputeError: "decimal overflow in round"))
+                })?
+                .into_decimal_unchecked(precision, scale);
 
             return Ok(ca.into_series());
         }
@@ -209,6 +214,20 @@ pub trait RoundSeries: SeriesSealed {
     }
 }
 
+fn round_float(v: f64, mode: Option<RoundMode>) -> f64 {
+    match mode {
+        None | Some(RoundMode::HalfAwayFromZero) => v.round(),
+        Some(RoundMode::HalfToEven) => v.round_ties_even(),
+        Some(RoundMode::TowardZero) => v.trunc(),
+        Some(RoundMode::AwayFromZero) => {
+            let t = v.trunc();
+            if t == v { t } else { t + v.signum() }
+        },
+        Some(RoundMode::Floor) => v.floor(),
+        Some(RoundMode::Ceiling) => v.ceil(),
+    }
+}
+
 impl RoundSeries for Series {}
 
 #[cfg(test)]
@@ -6,3 +6,5 @@ pub mod write;
 pub use crate::parquet::bloom_filter;
 
 const ARROW_SCHEMA_META_KEY: &str = "ARROW:schema";
+/// The name of the canonical arrow extension type that maps to the parquet `UUID` logical type.
+const UUID_EXTENSION_NAME: &str = "arrow.uuid";
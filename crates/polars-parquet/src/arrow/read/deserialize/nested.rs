@@ -132,6 +132,14 @@ pub fn columns_to_iter_recursive(
                 let array = create_map(field.dtype().clone(), &mut nested, array);
                 Ok((nested, array, ptm))
             },
+            ArrowDataType::Extension(ext) => {
+                // Extension types are stored as their inner type.
+                let inner = Field::new(field.name.clone(), ext.inner.clone(), field.is_nullable);
+                let (nested, array, ptm) =
+                    columns_to_iter_recursive(columns, types, inner, init, filter)?;
+                let array = arrow::array::with_dtype(array.as_ref(), field.dtype().clone())?;
+                Ok((nested, array, ptm))
+            },
 
             ArrowDataType::Dictionary(key_type, value_type, _) => {
                 // @note: this should only hit in two cases:
@@ -3,7 +3,7 @@ use arrow::bitmap::Bitmap;
 use arrow::datatypes::{
     ArrowDataType, DTYPE_CATEGORICAL, DTYPE_ENUM_VALUES, Field, IntegerType, IntervalUnit, TimeUnit,
 };
-use arrow::types::{NativeType, days_ms, i256};
+use arrow::types::{NativeType, days_ms, i256, months_days_ns};
 use ethnum::I256;
 use polars_compute::cast::CastOptionsImpl;
 
@@ -167,6 +167,36 @@ pub fn page_iter_to_array(
                 ptm,
             )
         },
+        (PhysicalType::FixedLenByteArray(16), Interval(IntervalUnit::MonthDayNano)) => {
+            let n = 16;
+            let (nested, array, ptm) = PageDecoder::new(
+                pages,
+                ArrowDataType::FixedSizeBinary(n),
+                fixed_size_binary::BinaryDecoder { size: n },
+                init_nested,
+            )?
+            .collect(filter)?;
+
+            let values = array
+                .values()
+                .chunks_exact(n)
+                .map(|value: &[u8]| {
+                    months_days_ns::new(
+                        i32::from_le_bytes(value[..4].try_into().unwrap()),
+                        i32::from_le_bytes(value[4..8].try_into().unwrap()),
+                        i64::from_le_bytes(value[8..].try_into().unwrap()),
+                    )
+                })
+                .collect::<Vec<_>>();
+            let validity = array.validity().cloned();
+
+            (
+                nested,
+                PrimitiveArray::<months_days_ns>::try_new(dtype.clone(), values.into(), validity)?
+                    .to_boxed(),
+                ptm,
+            )
+        },
         (PhysicalType::Int32, Decimal(_, _)) => PageDecoder::new(
             pages,
             dtype,
@@ -314,6 +344,29 @@ pub fn page_iter_to_array(
         .collect_boxed(filter)?,
 
         // Float16
+        (PhysicalType::FixedLenByteArray(2), Float16) => {
+            let (nested, mut fsb_array, ptm) = PageDecoder::new(
+                pages,
+                ArrowDataType::FixedSizeBinary(2),
+                fixed_size_binary::BinaryDecoder { size: 2 },
+                init_nested,
+            )?
+            .collect(filter)?;
+
+            let validity = fsb_array.take_validity();
+            let values = fsb_array.values().as_slice();
+            assert_eq!(values.len() % 2, 0);
+            let values = values
+                .chunks_exact(2)
+                .map(|v| arrow::types::f16::from_le_bytes([v[0], v[1]]))
+                .collect();
+
+            (
+                nested,
+                PrimitiveArray::<arrow::types::f16>::new(dtype, values, validity).to_boxed(),
+                ptm,
+            )
+        },
         (PhysicalType::FixedLenByteArray(2), Float32) => {
             // @NOTE: To reduce code bloat, we just use the FixedSizeBinary decoder.
 
@@ -74,9 +74,6 @@ impl<'a, D: Decoder> State<'a, D> {
     ) -> ParquetResult<Self> {
         let translation = D::Translation::new(decoder, page, dict, None)?;
 
-        let is_optional =
-            page.descriptor.primitive_type.field_info.repetition == Repetition::Optional;
-
         if page_validity
             .as_ref()
             .is_some_and(|bm| bm.unset_bits() == 0)
@@ -84,6 +81,11 @@ impl<'a, D: Decoder> State<'a, D> {
             page_validity = None;
         }
 
+        // A required leaf still has missing values where one of its parents is null.
+        let is_optional = page.descriptor.primitive_type.field_info.repetition
+            == Repetition::Optional
+            || page_validity.is_some();
+
         Ok(Self {
             dict,
             dict_mask: None,
@@ -1,7 +1,8 @@
 //! This module has entry points, [`parquet_to_arrow_schema`] and the more configurable [`parquet_to_arrow_schema_with_options`].
-use arrow::datatypes::{ArrowDataType, ArrowSchema, Field, IntervalUnit, TimeUnit};
+use arrow::datatypes::{ArrowDataType, ArrowSchema, ExtensionType, Field, IntervalUnit, TimeUnit};
 use polars_utils::pl_str::PlSmallStr;
 
+use crate::arrow::UUID_EXTENSION_NAME;
 use crate::arrow::read::schema::SchemaInferenceOptions;
 use crate::parquet::schema::Repetition;
 use crate::parquet::schema::types::{
@@ -170,12 +171,26 @@ fn from_fixed_len_byte_array(
     converted_type: Option<PrimitiveConvertedType>,
 ) -> ArrowDataType {
     match (logical_type, converted_type) {
+        (Some(PrimitiveLogicalType::Decimal(precision, scale)), _)
+        | (None, Some(PrimitiveConvertedType::Decimal(precision, scale)))
+            if precision > 38 =>
+        {
+            ArrowDataType::Decimal256(precision, scale)
+        },
         (Some(PrimitiveLogicalType::Decimal(precision, scale)), _) => {
             ArrowDataType::Decimal(precision, scale)
         },
         (None, Some(PrimitiveConvertedType::Decimal(precision, scale))) => {
             ArrowDataType::Decimal(precision, scale)
         },
+        (Some(PrimitiveLogicalType::Float16), _) if length == 2 => ArrowDataType::Float16,
+        (Some(PrimitiveLogicalType::Uuid), _) if length == 16 => {
+            ArrowDataType::Extension(Box::new(ExtensionType {
+                name: PlSmallStr::from_static(UUID_EXTENSION_NAME),
+                inner: ArrowDataType::FixedSizeBinary(16),
+                metadata: None,
+            }))
+        },
         (None, Some(PrimitiveConvertedType::Interval)) => {
             // There is currently no reliable way of determining which IntervalUnit
             // to return. Thus without the original Arrow schema, the results
@@ -244,8 +259,8 @@ fn non_repeated_group(
     match (logical_type, converted_type) {
         (Some(GroupLogicalType::List), _) => to_list(fields, parent_name, options),
         (None, Some(GroupConvertedType::List)) => to_list(fields, parent_name, options),
-        (Some(GroupLogicalType::Map), _) => to_list(fields, parent_name, options),
-        (None, Some(GroupConvertedType::Map) | Some(GroupConvertedType::MapKeyValue)) => {
+        (Some(GroupLogicalType::Map), _)
+        | (None, Some(GroupConvertedType::Map) | Some(GroupConvertedType::MapKeyValue)) => {
             to_map(fields, options)
         },
         _ => to_struct(fields, options),
@@ -266,10 +281,18 @@ fn to_struct(fields: &[ParquetType], options: &SchemaInferenceOptions) -> Option
     }
 }
 
-/// Converts a parquet group type to an arrow [`ArrowDataType::Struct`].
+/// Converts a parquet map group to an arrow [`ArrowDataType::Map`].
 /// Returns [`None`] if all its fields are empty
 fn to_map(fields: &[ParquetType], options: &SchemaInferenceOptions) -> Option<ArrowDataType> {
-    let inner = to_field(&fields[0], options)?;
+    // The map group holds a single repeated group with the key and value of the entries.
+    let inner = match &fields[0] {
+        ParquetType::GroupType {
+            field_info, fields, ..
+        } if field_info.repetition == Repetition::Repeated => {
+            Field::new(field_info.name.clone(), to_struct(fields, options)?, false)
+        },
+        entries => to_field(entries, options)?,
+    };
     Some(ArrowDataType::Map(Box::new(inner), false))
 }
 
@@ -503,6 +526,39 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_parquet_map() -> PolarsResult<()> {
+        let message = "
+        message test_schema {
+            OPTIONAL group map (MAP) {
+                REPEATED group key_value {
+                    REQUIRED BINARY key (UTF8);
+                    OPTIONAL INT32 value;
+                }
+            }
+        }
+        ";
+        let entries = ArrowDataType::Struct(vec![
+            Field::new("key".into(), ArrowDataType::Utf8View, false),
+            Field::new("value".into(), ArrowDataType::Int32, true),
+        ]);
+        let expected = &[Field::new(
+            "map".into(),
+            ArrowDataType::Map(
+                Box::new(Field::new("key_value".into(), entries, false)),
+                false,
+            ),
+            true,
+        )];
+
+        let parquet_schema = SchemaDescriptor::try_from_message(message)?;
+        let fields = parquet_to_arrow_schema(parquet_schema.fields());
+        let fields = fields.iter_values().cloned().collect::<Vec<_>>();
+
+        assert_eq!(fields, expected);
+        Ok(())
+    }
+
     #[ignore]
     #[test]
     fn test_parquet_lists() -> PolarsResult<()> {
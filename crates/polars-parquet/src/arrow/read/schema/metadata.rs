@@ -50,13 +50,14 @@ fn convert_dtype(mut dtype: ArrowDataType) -> ArrowDataType {
             convert_field(field.as_mut());
             dtype = LargeList(field);
         },
-        LargeList(ref mut field) | FixedSizeList(ref mut field, _) => convert_field(field.as_mut()),
+        LargeList(ref mut field) | FixedSizeList(ref mut field, _) | Map(ref mut field, _) => {
+            convert_field(field.as_mut())
+        },
         Struct(ref mut fields) => {
             for field in fields {
                 convert_field(field);
             }
         },
-        Float16 => dtype = Float32,
         Binary | LargeBinary => dtype = BinaryView,
         Utf8 | LargeUtf8 => dtype = Utf8View,
         Dictionary(_, ref mut dtype, _) => {
@@ -66,13 +67,6 @@ fn convert_dtype(mut dtype: ArrowDataType) -> ArrowDataType {
         Extension(ref mut ext) => {
             ext.inner = convert_dtype(std::mem::take(&mut ext.inner));
         },
-        Map(mut field, _ordered) => {
-            // Polars doesn't support Map.
-            // A map is physically a `List<Struct<K, V>>`
-            // So we read as list.
-            convert_field(field.as_mut());
-            dtype = LargeList(field);
-        },
         _ => {},
     }
 
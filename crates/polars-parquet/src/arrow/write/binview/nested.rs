@@ -7,7 +7,6 @@ use crate::arrow::write::Nested;
 use crate::parquet::encoding::Encoding;
 use crate::parquet::page::DataPage;
 use crate::parquet::schema::types::PrimitiveType;
-use crate::read::schema::is_nullable;
 use crate::write::EncodeNullability;
 
 pub fn array_to_page(
@@ -16,8 +15,7 @@ pub fn array_to_page(
     type_: PrimitiveType,
     nested: &[Nested],
 ) -> PolarsResult<DataPage> {
-    let is_optional = is_nullable(&type_.field_info);
-    let encode_options = EncodeNullability::new(is_optional);
+    let encode_options = EncodeNullability::new_nested(&type_, array);
 
     let mut buffer = vec![];
     let (repetition_levels_byte_length, definition_levels_byte_length) =
@@ -3,7 +3,6 @@ use polars_error::PolarsResult;
 
 use super::super::{EncodeNullability, WriteOptions, nested, utils};
 use super::basic::{build_statistics, encode_plain};
-use crate::arrow::read::schema::is_nullable;
 use crate::arrow::write::Nested;
 use crate::parquet::encoding::Encoding;
 use crate::parquet::page::DataPage;
@@ -15,8 +14,7 @@ pub fn array_to_page(
     type_: PrimitiveType,
     nested: &[Nested],
 ) -> PolarsResult<DataPage> {
-    let is_optional = is_nullable(&type_.field_info);
-    let encode_options = EncodeNullability::new(is_optional);
+    let encode_options = EncodeNullability::new_nested(&type_, array);
 
     let mut buffer = vec![];
     let (repetition_levels_byte_length, definition_levels_byte_length) =
@@ -2,7 +2,8 @@ mod basic;
 mod nested;
 
 use arrow::array::{Array, FixedSizeBinaryArray, PrimitiveArray};
-use arrow::types::i256;
+use arrow::datatypes::ArrowDataType;
+use arrow::types::{NativeType, f16, i256};
 pub use basic::array_to_page;
 pub use nested::array_to_page as nested_array_to_page;
 
@@ -158,3 +159,54 @@ pub(super) fn build_statistics_decimal256(
             .flatten(),
     }
 }
+
+/// Encode half precision floats as the little-endian `FIXED_LEN_BYTE_ARRAY(2)` values of the
+/// parquet `FLOAT16` logical type.
+pub(super) fn float16_to_fixed_size_binary(array: &PrimitiveArray<f16>) -> FixedSizeBinaryArray {
+    let mut values = Vec::<u8>::with_capacity(2 * array.len());
+    array
+        .values()
+        .iter()
+        .for_each(|x| values.extend_from_slice(&x.to_le_bytes()));
+    FixedSizeBinaryArray::new(
+        ArrowDataType::FixedSizeBinary(2),
+        values.into(),
+        array.validity().cloned(),
+    )
+}
+
+pub(super) fn build_statistics_float16(
+    array: &PrimitiveArray<f16>,
+    primitive_type: PrimitiveType,
+    options: &StatisticsOptions,
+) -> FixedLenStatistics {
+    // NaN values are not taken into account for the statistics of floating point columns.
+    let values = || {
+        array
+            .iter()
+            .flatten()
+            .map(|x| x.to_f32())
+            .filter(|x| !x.is_nan())
+    };
+    FixedLenStatistics {
+        primitive_type,
+        null_count: options.null_count.then_some(array.null_count() as i64),
+        distinct_count: None,
+        max_value: options
+            .max_value
+            .then(|| {
+                values()
+                    .max_by(f32::total_cmp)
+                    .map(|x| f16::from_f32(x).to_le_bytes().to_vec())
+            })
+            .flatten(),
+        min_value: options
+            .min_value
+            .then(|| {
+                values()
+                    .min_by(f32::total_cmp)
+                    .map(|x| f16::from_f32(x).to_le_bytes().to_vec())
+            })
+            .flatten(),
+    }
+}
@@ -5,7 +5,6 @@ use super::encode_plain;
 use crate::parquet::page::DataPage;
 use crate::parquet::schema::types::PrimitiveType;
 use crate::parquet::statistics::FixedLenStatistics;
-use crate::read::schema::is_nullable;
 use crate::write::{EncodeNullability, Encoding, Nested, WriteOptions, nested, utils};
 
 pub fn array_to_page(
@@ -15,8 +14,7 @@ pub fn array_to_page(
     nested: &[Nested],
     statistics: Option<FixedLenStatistics>,
 ) -> PolarsResult<DataPage> {
-    let is_optional = is_nullable(&type_.field_info);
-    let encode_options = EncodeNullability::new(is_optional);
+    let encode_options = EncodeNullability::new_nested(&type_, array);
 
     let mut buffer = vec![];
     let (repetition_levels_byte_length, definition_levels_byte_length) =
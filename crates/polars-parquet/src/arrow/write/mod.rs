@@ -27,7 +27,7 @@ mod utils;
 
 use arrow::array::*;
 use arrow::datatypes::*;
-use arrow::types::{NativeType, days_ms, i256};
+use arrow::types::{NativeType, days_ms, i256, months_days_ns};
 pub use nested::{num_values, write_rep_and_def};
 pub use pages::{to_leaves, to_nested, to_parquet_leaves};
 use polars_utils::pl_str::PlSmallStr;
@@ -99,6 +99,7 @@ pub use row_group::{RowGroupIterator, row_group_iter};
 pub use schema::to_parquet_type;
 
 use self::pages::{FixedSizeListNested, PrimitiveNested, StructNested};
+use crate::read::schema::is_nullable;
 use crate::write::dictionary::encode_as_dictionary_optional;
 
 impl StatisticsOptions {
@@ -144,6 +145,12 @@ impl EncodeNullability {
         }
     }
 
+    /// The nullability of the values of a nested leaf. Besides its own nulls, a leaf has the
+    /// nulls of its parents, which are not encoded even if the leaf itself is required.
+    fn new_nested(type_: &ParquetPrimitiveType, array: &dyn Array) -> Self {
+        Self::new(is_nullable(&type_.field_info) || array.validity().is_some())
+    }
+
     fn is_optional(self) -> bool {
         matches!(self, Self::Optional)
     }
@@ -455,6 +462,18 @@ pub fn array_to_page_simple(
                 encoding,
             );
         },
+        ArrowDataType::Float16 => {
+            let array = array.as_any().downcast_ref().unwrap();
+            let statistics = options.has_statistics().then(|| {
+                fixed_size_binary::build_statistics_float16(
+                    array,
+                    type_.clone(),
+                    &options.statistics,
+                )
+            });
+            let array = fixed_size_binary::float16_to_fixed_size_binary(array);
+            fixed_size_binary::array_to_page(&array, options, type_, statistics)
+        },
         ArrowDataType::Float32 => primitive::array_to_page_plain::<f32, f32>(
             array.as_any().downcast_ref().unwrap(),
             options,
@@ -561,6 +580,40 @@ pub fn array_to_page_simple(
             };
             fixed_size_binary::array_to_page(&array, options, type_, statistics)
         },
+        ArrowDataType::Interval(IntervalUnit::MonthDayNano) => {
+            // Parquet's INTERVAL only has millisecond precision, so the values are stored as
+            // plain 16 byte values. The arrow schema in the file metadata restores the type.
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<months_days_ns>>()
+                .unwrap();
+            let mut values = Vec::<u8>::with_capacity(16 * array.len());
+            array
+                .values()
+                .iter()
+                .for_each(|x| values.extend_from_slice(&x.to_le_bytes()));
+            let array = FixedSizeBinaryArray::new(
+                ArrowDataType::FixedSizeBinary(16),
+                values.into(),
+                array.validity().cloned(),
+            );
+            let statistics = if options.has_statistics() {
+                // The byte-wise min/max of these values is meaningless.
+                let statistics_options = StatisticsOptions {
+                    min_value: false,
+                    max_value: false,
+                    ..options.statistics
+                };
+                Some(fixed_size_binary::build_statistics(
+                    &array,
+                    type_.clone(),
+                    &statistics_options,
+                ))
+            } else {
+                None
+            };
+            fixed_size_binary::array_to_page(&array, options, type_, statistics)
+        },
         ArrowDataType::FixedSizeBinary(_) => {
             let array = array.as_any().downcast_ref().unwrap();
             let statistics = if options.has_statistics() {
@@ -808,6 +861,18 @@ fn array_to_page_nested(
             let array = array.as_any().downcast_ref().unwrap();
             primitive::nested_array_to_page::<i64, i64>(array, options, type_, nested)
         },
+        Float16 => {
+            let array = array.as_any().downcast_ref().unwrap();
+            let statistics = options.has_statistics().then(|| {
+                fixed_size_binary::build_statistics_float16(
+                    array,
+                    type_.clone(),
+                    &options.statistics,
+                )
+            });
+            let array = fixed_size_binary::float16_to_fixed_size_binary(array);
+            fixed_size_binary::nested_array_to_page(&array, options, type_, nested, statistics)
+        },
         Float32 => {
             let array = array.as_any().downcast_ref().unwrap();
             primitive::nested_array_to_page::<f32, f32>(array, options, type_, nested)
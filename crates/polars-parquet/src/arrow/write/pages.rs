@@ -269,24 +269,24 @@ fn to_nested_recursive(
 }
 
 fn expand_list_validity<'a, O: Offset>(
-    array: &'a ListArray<O>,
+    offsets: &[O],
+    values: &'a dyn Array,
     validity: BitmapState,
     array_stack: &mut Vec<(&'a dyn Array, BitmapState)>,
 ) {
     let BitmapState::SomeSet(list_validity) = validity else {
         array_stack.push((
-            array.values().as_ref(),
+            values,
             match validity {
                 BitmapState::AllSet => BitmapState::AllSet,
                 BitmapState::SomeSet(_) => unreachable!(),
-                BitmapState::AllUnset(_) => BitmapState::AllUnset(array.values().len()),
+                BitmapState::AllUnset(_) => BitmapState::AllUnset(values.len()),
             },
         ));
         return;
     };
 
-    let offsets = array.offsets().buffer();
-    let mut validity = MutableBitmap::with_capacity(array.values().len());
+    let mut validity = MutableBitmap::with_capacity(values.len());
     let mut list_validity_iter = list_validity.iter();
 
     // @NOTE: We need to take into account here that the list might only point to a slice of the
@@ -308,13 +308,13 @@ fn expand_list_validity<'a, O: Offset>(
 
         idx += num_zeros;
     }
-    validity.extend_constant(array.values().len() - validity.len(), false);
+    validity.extend_constant(values.len() - validity.len(), false);
 
-    debug_assert_eq!(idx, array.len());
+    debug_assert_eq!(idx, offsets.len() - 1);
     let validity = validity.freeze();
 
-    debug_assert_eq!(validity.len(), array.values().len());
-    array_stack.push((array.values().as_ref(), BitmapState::SomeSet(validity)));
+    debug_assert_eq!(validity.len(), values.len());
+    array_stack.push((values, BitmapState::SomeSet(validity)));
 }
 
 #[derive(Clone)]
@@ -405,11 +405,21 @@ pub fn to_leaves(array: &dyn Array, leaves: &mut Vec<Box<dyn Array>>) {
             },
             P::List => {
                 let array = array.as_any().downcast_ref::<ListArray<i32>>().unwrap();
-                expand_list_validity(array, validity, &mut array_stack);
+                expand_list_validity(
+                    array.offsets().buffer(),
+                    array.values().as_ref(),
+                    validity,
+                    &mut array_stack,
+                );
             },
             P::LargeList => {
                 let array = array.as_any().downcast_ref::<ListArray<i64>>().unwrap();
-                expand_list_validity(array, validity, &mut array_stack);
+                expand_list_validity(
+                    array.offsets().buffer(),
+                    array.values().as_ref(),
+                    validity,
+                    &mut array_stack,
+                );
             },
             P::FixedSizeList => {
                 let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
@@ -455,7 +465,12 @@ pub fn to_leaves(array: &dyn Array, leaves: &mut Vec<Box<dyn Array>>) {
             },
             P::Map => {
                 let array = array.as_any().downcast_ref::<MapArray>().unwrap();
-                array_stack.push((array.field().as_ref(), validity));
+                expand_list_validity(
+                    array.offsets().buffer(),
+                    array.field().as_ref(),
+                    validity,
+                    &mut array_stack,
+                );
             },
             P::Null
             | P::Boolean
@@ -4,7 +4,6 @@ use polars_error::PolarsResult;
 
 use super::super::{WriteOptions, nested, utils};
 use super::basic::{build_statistics, encode_plain};
-use crate::arrow::read::schema::is_nullable;
 use crate::arrow::write::Nested;
 use crate::parquet::encoding::Encoding;
 use crate::parquet::page::DataPage;
@@ -23,8 +22,7 @@ where
     R: NativeType,
     T: num_traits::AsPrimitive<R>,
 {
-    let is_optional = is_nullable(&type_.field_info);
-    let encode_options = EncodeNullability::new(is_optional);
+    let encode_options = EncodeNullability::new_nested(&type_, array);
 
     let mut buffer = vec![];
 
@@ -1,11 +1,11 @@
-use arrow::datatypes::{ArrowDataType, ArrowSchema, ExtensionType, Field, TimeUnit};
+use arrow::datatypes::{ArrowDataType, ArrowSchema, ExtensionType, Field, IntervalUnit, TimeUnit};
 use arrow::io::ipc::write::{default_ipc_fields, schema_to_bytes};
 use base64::Engine as _;
 use base64::engine::general_purpose;
 use polars_error::{PolarsResult, polars_bail};
 use polars_utils::pl_str::PlSmallStr;
 
-use super::super::ARROW_SCHEMA_META_KEY;
+use super::super::{ARROW_SCHEMA_META_KEY, UUID_EXTENSION_NAME};
 use crate::arrow::write::decimal_length_from_precision;
 use crate::parquet::metadata::KeyValue;
 use crate::parquet::schema::Repetition;
@@ -87,6 +87,19 @@ pub fn to_parquet_type(field: &Field) -> PolarsResult<ParquetType> {
     } else {
         Repetition::Required
     };
+    // the canonical arrow uuid extension maps to the parquet uuid logical type
+    if let ArrowDataType::Extension(ext) = field.dtype() {
+        if ext.name == UUID_EXTENSION_NAME && ext.inner == ArrowDataType::FixedSizeBinary(16) {
+            return Ok(ParquetType::try_from_primitive(
+                name,
+                PhysicalType::FixedLenByteArray(16),
+                repetition,
+                None,
+                Some(PrimitiveLogicalType::Uuid),
+                None,
+            )?);
+        }
+    }
     // create type from field
     match field.dtype().to_logical_type() {
         ArrowDataType::Null => Ok(ParquetType::try_from_primitive(
@@ -132,6 +145,14 @@ pub fn to_parquet_type(field: &Field) -> PolarsResult<ParquetType> {
             None,
             None,
         )?),
+        ArrowDataType::Float16 => Ok(ParquetType::try_from_primitive(
+            name,
+            PhysicalType::FixedLenByteArray(2),
+            repetition,
+            None,
+            Some(PrimitiveLogicalType::Float16),
+            None,
+        )?),
         ArrowDataType::Float32 => Ok(ParquetType::try_from_primitive(
             name,
             PhysicalType::Float,
@@ -377,12 +398,20 @@ pub fn to_parquet_type(field: &Field) -> PolarsResult<ParquetType> {
                     name,
                     PhysicalType::FixedLenByteArray(32),
                     repetition,
-                    None,
-                    None,
+                    Some(PrimitiveConvertedType::Decimal(precision, scale)),
+                    logical_type,
                     None,
                 )?)
             }
         },
+        ArrowDataType::Interval(IntervalUnit::MonthDayNano) => Ok(ParquetType::try_from_primitive(
+            name,
+            PhysicalType::FixedLenByteArray(16),
+            repetition,
+            None,
+            None,
+            None,
+        )?),
         ArrowDataType::Interval(_) => Ok(ParquetType::try_from_primitive(
             name,
             PhysicalType::FixedLenByteArray(12),
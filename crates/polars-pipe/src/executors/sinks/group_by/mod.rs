@@ -48,6 +48,11 @@ pub(super) fn physical_agg_to_logical(cols: &mut [Column], output_schema: &Schem
                     }
                 }
             },
+            // The keys are decoded as the bit patterns, which must not be cast numerically.
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 if s.dtype() == &DataType::UInt16 => {
+                *s = s.u16().unwrap().clone().into_float16().into_column()
+            },
             _ => {
                 let dtype_left = s.dtype();
                 if dtype_left != dtype
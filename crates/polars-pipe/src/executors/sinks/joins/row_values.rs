@@ -52,6 +52,8 @@ impl RowValues {
         let mut ctxts = Vec::with_capacity(self.join_column_eval.len());
         for phys_e in self.join_column_eval.iter() {
             let s = phys_e.evaluate(chunk, &context.execution_state)?;
+            // The encoding context is determined by the logical type, as on the build side.
+            ctxts.push(get_row_encoding_context(s.dtype(), false));
             let mut s = s.to_physical_repr().rechunk();
             if chunk.data.is_empty() {
                 s = s.clear()
@@ -60,7 +62,6 @@ impl RowValues {
                 names.push(s.name().to_string());
             }
             self.join_columns_material.push(s.array_ref(0).clone());
-            ctxts.push(get_row_encoding_context(s.dtype(), false));
         }
 
         // We determine the indices of the columns that have to be removed
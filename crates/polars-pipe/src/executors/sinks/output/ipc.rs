@@ -1,5 +1,6 @@
 use std::path::Path;
 
+use arrow::datatypes::Metadata;
 use cloud::CloudOptions;
 use crossbeam_channel::bounded;
 use file::try_get_writeable;
@@ -18,10 +19,14 @@ impl IpcSink {
         options: IpcWriterOptions,
         schema: &Schema,
         cloud_options: Option<&CloudOptions>,
+        custom_metadata: Option<Arc<Metadata>>,
     ) -> PolarsResult<FilesSink> {
-        let writer = IpcWriter::new(try_get_writeable(path.to_str().unwrap(), cloud_options)?)
-            .with_compression(options.compression)
-            .batched(schema)?;
+        let mut writer = IpcWriter::new(try_get_writeable(path.to_str().unwrap(), cloud_options)?)
+            .with_compression(options.compression);
+        if let Some(custom_metadata) = custom_metadata {
+            writer.set_custom_schema_metadata(custom_metadata);
+        }
+        let writer = writer.batched(schema)?;
 
         let writer = Box::new(writer) as Box<dyn SinkWriter + Send>;
 
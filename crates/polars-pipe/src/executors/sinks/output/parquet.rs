@@ -2,6 +2,7 @@ use std::any::Any;
 use std::path::Path;
 use std::thread::JoinHandle;
 
+use arrow::datatypes::Metadata;
 use crossbeam_channel::{Receiver, Sender, bounded};
 use polars_core::prelude::*;
 use polars_io::cloud::CloudOptions;
@@ -70,12 +71,14 @@ impl ParquetSink {
         options: ParquetWriteOptions,
         schema: &Schema,
         cloud_options: Option<&CloudOptions>,
+        custom_metadata: Option<Arc<Metadata>>,
     ) -> PolarsResult<Self> {
         let writer = ParquetWriter::new(try_get_writeable(path.to_str().unwrap(), cloud_options)?)
             .with_compression(options.compression)
             .with_data_page_size(options.data_page_size)
             .with_statistics(options.statistics)
             .with_row_group_size(options.row_group_size)
+            .with_custom_metadata(custom_metadata)
             // This is important! Otherwise we will deadlock
             // See: #7074
             .set_parallel(false)
@@ -184,7 +184,7 @@ where
                 SinkTypeIR::File(FileSinkType {
                     path,
                     file_type,
-                    sink_options: _,
+                    sink_options,
                     cloud_options,
                 }) => {
                     let path = path.as_ref().as_path();
@@ -195,6 +195,7 @@ where
                             *options,
                             input_schema.as_ref(),
                             cloud_options.as_ref(),
+                            sink_options.custom_metadata(),
                         )?)
                             as Box<dyn SinkTrait>,
                         #[cfg(feature = "ipc")]
@@ -203,6 +204,7 @@ where
                             *options,
                             input_schema.as_ref(),
                             cloud_options.as_ref(),
+                            sink_options.custom_metadata(),
                         )?) as Box<dyn SinkTrait>,
                         #[cfg(feature = "csv")]
                         FileType::Csv(options) => Box::new(CsvSink::new(
@@ -490,11 +492,11 @@ where
                     options.slice,
                 ))
             } else {
-                match (
-                    output_schema.get_at_index(0).unwrap().1.to_physical(),
-                    keys.len(),
-                ) {
-                    (dt, 1) if dt.is_integer() => {
+                let key_dtype = output_schema.get_at_index(0).unwrap().1;
+                match (key_dtype.to_physical(), keys.len()) {
+                    // Half precision floats have an integer physical type, but -0 and 0 and all
+                    // NaNs must land in the same group.
+                    (dt, 1) if dt.is_integer() && !key_dtype.is_float() => {
                         with_match_physical_integer_polars_type!(dt, |$T| {
                             Box::new(group_by::PrimitiveGroupbySink::<$T>::new(
                                 key_columns[0].clone(),
@@ -4,6 +4,7 @@ use polars_utils::pl_str::PlSmallStr;
 
 pub static MAP_LIST_NAME: &str = "map_list";
 pub static CSE_REPLACED: &str = "__POLARS_CSER_";
+pub static CSE_HOISTED: &str = "__POLARS_CSEH_";
 pub static POLARS_TMP_PREFIX: &str = "_POLARS_";
 pub const LEN: &str = "len";
 const LITERAL_NAME: &str = "literal";
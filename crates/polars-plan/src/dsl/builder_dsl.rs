@@ -330,6 +330,91 @@ impl DslBuilder {
         .into()
     }
 
+    /// Aggregate over multiple grouping sets of `keys` and combine the results.
+    ///
+    /// The output has the keys, the aggregations and a [`GROUPING_ID`] column. Keys that are not
+    /// part of a grouping set are null in the rows of that set.
+    ///
+    /// The empty grouping set (the grand total) is evaluated as a `select` of the aggregations,
+    /// so it yields a single row even if the input is empty.
+    pub fn grouping_sets<E: AsRef<[Expr]>>(
+        self,
+        keys: Vec<Expr>,
+        sets: &GroupingSets,
+        aggs: E,
+        maintain_order: bool,
+    ) -> PolarsResult<Self> {
+        polars_ensure!(!sets.sets().is_empty(), InvalidOperation: "expected at least one grouping set");
+        GroupingSets::check_n_keys(keys.len())?;
+        let key_names = keys
+            .iter()
+            .map(expr_output_name)
+            .collect::<PolarsResult<Vec<_>>>()?;
+        polars_ensure!(
+            !key_names.iter().any(|name| name == GROUPING_ID),
+            Duplicate: "group by key cannot be named '{}' in a grouping sets aggregation", GROUPING_ID
+        );
+
+        let input = Arc::new(self.0);
+        let inputs = sets
+            .sets()
+            .iter()
+            .map(|set| {
+                if let Some(i) = set.iter().find(|i| **i >= keys.len()) {
+                    polars_bail!(OutOfBounds: "grouping set refers to key {} but there are only {} keys", i, keys.len())
+                }
+                let grouping_id = GroupingSets::grouping_id(set, keys.len());
+                let input = DslBuilder(input.as_ref().clone());
+                let aggregated = if set.is_empty() {
+                    input.project(aggs.as_ref().to_vec(), Default::default())
+                } else {
+                    input.group_by(
+                        set.iter().map(|i| keys[*i].clone()).collect(),
+                        aggs.as_ref(),
+                        None,
+                        maintain_order,
+                        #[cfg(feature = "dynamic_group_by")]
+                        None,
+                        #[cfg(feature = "dynamic_group_by")]
+                        None,
+                    )
+                };
+                Ok(aggregated
+                    .with_columns(
+                        vec![lit(grouping_id).alias(GROUPING_ID)],
+                        Default::default(),
+                    )
+                    .build())
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        // Keys that are missing from a set are filled with nulls by the diagonal concatenation.
+        let union = DslPlan::Union {
+            inputs,
+            args: UnionArgs {
+                to_supertypes: true,
+                diagonal: true,
+                ..Default::default()
+            },
+        };
+
+        let mut projection = key_names
+            .iter()
+            .map(|name| col(name.clone()))
+            .collect::<Vec<_>>();
+        projection.push(
+            col(PlSmallStr::from_static("*")).exclude(
+                key_names
+                    .iter()
+                    .cloned()
+                    .chain([PlSmallStr::from_static(GROUPING_ID)])
+                    .collect::<Vec<_>>(),
+            ),
+        );
+        projection.push(col(GROUPING_ID));
+        Ok(DslBuilder(union).project(projection, Default::default()))
+    }
+
     pub fn build(self) -> DslPlan {
         self.0
     }
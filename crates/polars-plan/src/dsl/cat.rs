@@ -36,4 +36,12 @@ impl CategoricalNameSpace {
                 suffix,
             )))
     }
+
+    /// Encode a `String` or `Categorical` column with the physical codes of a persisted
+    /// [`CategoricalDictionary`].
+    pub fn encode_with_dictionary(self, dictionary: Arc<CategoricalDictionary>) -> Expr {
+        self.0.map_private(FunctionExpr::Categorical(
+            CategoricalFunction::EncodeWithDictionary(dictionary),
+        ))
+    }
 }
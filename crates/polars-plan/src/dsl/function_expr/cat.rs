@@ -13,6 +13,7 @@ pub enum CategoricalFunction {
     StartsWith(String),
     #[cfg(feature = "strings")]
     EndsWith(String),
+    EncodeWithDictionary(Arc<CategoricalDictionary>),
 }
 
 impl CategoricalFunction {
@@ -28,6 +29,7 @@ impl CategoricalFunction {
             StartsWith(_) => mapper.with_dtype(DataType::Boolean),
             #[cfg(feature = "strings")]
             EndsWith(_) => mapper.with_dtype(DataType::Boolean),
+            EncodeWithDictionary(dictionary) => mapper.with_dtype(dictionary.dtype()),
         }
     }
 }
@@ -45,6 +47,7 @@ impl Display for CategoricalFunction {
             StartsWith(_) => "starts_with",
             #[cfg(feature = "strings")]
             EndsWith(_) => "ends_with",
+            EncodeWithDictionary(_) => "encode_with_dictionary",
         };
         write!(f, "cat.{s}")
     }
@@ -63,6 +66,7 @@ impl From<CategoricalFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             StartsWith(prefix) => map!(starts_with, prefix.as_str()),
             #[cfg(feature = "strings")]
             EndsWith(suffix) => map!(ends_with, suffix.as_str()),
+            EncodeWithDictionary(dictionary) => map!(encode_with_dictionary, &dictionary),
         }
     }
 }
@@ -152,3 +156,9 @@ fn ends_with(s: &Column, suffix: &str) -> PolarsResult<Column> {
     let ca = s.categorical()?;
     apply_to_cats_binary(ca, |s| s.as_binary().ends_with(suffix.as_bytes()))
 }
+
+fn encode_with_dictionary(s: &Column, dictionary: &CategoricalDictionary) -> PolarsResult<Column> {
+    dictionary
+        .encode(s.as_materialized_series())
+        .map(Column::from)
+}
@@ -0,0 +1,95 @@
+use polars_ops::chunked_array::map::MapNameSpace;
+
+use super::*;
+use crate::{map, map_as_slice};
+
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum MapFunction {
+    Get,
+    Keys,
+    Values,
+    FromLists,
+}
+
+impl MapFunction {
+    pub(super) fn get_field(&self, mapper: FieldsMapper) -> PolarsResult<Field> {
+        use MapFunction::*;
+        match self {
+            Get => mapper.try_map_dtype(|dt| map_dtypes(dt).map(|(_, v)| v.clone())),
+            Keys => mapper.try_map_dtype(|dt| {
+                map_dtypes(dt).map(|(k, _)| DataType::List(Box::new(k.clone())))
+            }),
+            Values => mapper.try_map_dtype(|dt| {
+                map_dtypes(dt).map(|(_, v)| DataType::List(Box::new(v.clone())))
+            }),
+            FromLists => {
+                let args = mapper.args();
+                polars_ensure!(args.len() == 2, ComputeError: "`map.from_lists` expects keys and values");
+                let key = list_inner_dtype(args[0].dtype())?;
+                let value = list_inner_dtype(args[1].dtype())?;
+                mapper.with_dtype(DataType::Map(Box::new(key), Box::new(value)))
+            },
+        }
+    }
+}
+
+fn map_dtypes(dtype: &DataType) -> PolarsResult<(&DataType, &DataType)> {
+    match dtype {
+        DataType::Map(key, value) => Ok((key, value)),
+        dt => polars_bail!(InvalidOperation: "expected Map type, got: {}", dt),
+    }
+}
+
+fn list_inner_dtype(dtype: &DataType) -> PolarsResult<DataType> {
+    match dtype {
+        DataType::List(inner) => Ok(*inner.clone()),
+        dt => polars_bail!(InvalidOperation: "expected List type, got: {}", dt),
+    }
+}
+
+impl Display for MapFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use MapFunction::*;
+        let name = match self {
+            Get => "get",
+            Keys => "keys",
+            Values => "values",
+            FromLists => "from_lists",
+        };
+        write!(f, "map.{name}")
+    }
+}
+
+impl From<MapFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
+    fn from(func: MapFunction) -> Self {
+        use MapFunction::*;
+        match func {
+            Get => map_as_slice!(get),
+            Keys => map!(keys),
+            Values => map!(values),
+            FromLists => map_as_slice!(from_lists),
+        }
+    }
+}
+
+pub(super) fn get(s: &[Column]) -> PolarsResult<Column> {
+    let ca = s[0].map_()?;
+    let key = s[1].as_materialized_series();
+    ca.map_get(key).map(Column::from)
+}
+
+pub(super) fn keys(s: &Column) -> PolarsResult<Column> {
+    s.map_()?.map_keys().map(|ca| ca.into_column())
+}
+
+pub(super) fn values(s: &Column) -> PolarsResult<Column> {
+    s.map_()?.map_values().map(|ca| ca.into_column())
+}
+
+pub(super) fn from_lists(s: &[Column]) -> PolarsResult<Column> {
+    let keys = s[0].list()?;
+    let values = s[1].list()?;
+    polars_ops::chunked_array::map::map_from_lists(keys, values)
+        .map(|ca| ca.into_series().into_column())
+}
@@ -39,6 +39,8 @@ mod index_of;
 mod list;
 #[cfg(feature = "log")]
 mod log;
+#[cfg(feature = "dtype-map")]
+mod map;
 mod nan;
 #[cfg(feature = "peaks")]
 mod peaks;
@@ -74,6 +76,10 @@ mod temporal;
 #[cfg(feature = "trigonometry")]
 pub mod trigonometry;
 mod unique;
+#[cfg(feature = "dtype-uuid")]
+mod uuid;
+#[cfg(feature = "dtype-variant")]
+mod variant;
 
 use std::fmt::{Display, Formatter};
 use std::hash::{Hash, Hasher};
@@ -85,6 +91,8 @@ pub use correlation::CorrelationMethod;
 #[cfg(feature = "fused")]
 pub use fused::FusedOperator;
 pub use list::ListFunction;
+#[cfg(feature = "dtype-map")]
+pub use map::MapFunction;
 pub use polars_core::datatypes::ReshapeDimension;
 use polars_core::prelude::*;
 #[cfg(feature = "random")]
@@ -116,6 +124,8 @@ pub use self::strings::StringFunction;
 pub use self::struct_::StructFunction;
 #[cfg(feature = "trigonometry")]
 pub use self::trigonometry::TrigonometricFunction;
+#[cfg(feature = "dtype-uuid")]
+pub use self::uuid::UuidVersion;
 use super::*;
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -128,6 +138,8 @@ pub enum FunctionExpr {
     #[cfg(feature = "dtype-categorical")]
     Categorical(CategoricalFunction),
     ListExpr(ListFunction),
+    #[cfg(feature = "dtype-map")]
+    MapExpr(MapFunction),
     #[cfg(feature = "strings")]
     StringExpr(StringFunction),
     #[cfg(feature = "dtype-struct")]
@@ -195,6 +207,10 @@ pub enum FunctionExpr {
         seed: Option<u64>,
     },
     Repeat,
+    #[cfg(feature = "dtype-uuid")]
+    Uuid(UuidVersion),
+    #[cfg(feature = "dtype-variant")]
+    VariantGet(PlSmallStr),
     #[cfg(feature = "round_series")]
     Clip {
         has_min: bool,
@@ -269,6 +285,7 @@ pub enum FunctionExpr {
     #[cfg(feature = "round_series")]
     Round {
         decimals: u32,
+        mode: Option<RoundMode>,
     },
     #[cfg(feature = "round_series")]
     RoundSF {
@@ -385,6 +402,8 @@ impl Hash for FunctionExpr {
             #[cfg(feature = "dtype-categorical")]
             Categorical(f) => f.hash(state),
             ListExpr(f) => f.hash(state),
+            #[cfg(feature = "dtype-map")]
+            MapExpr(f) => f.hash(state),
             #[cfg(feature = "strings")]
             StringExpr(f) => f.hash(state),
             #[cfg(feature = "dtype-struct")]
@@ -472,6 +491,10 @@ impl Hash for FunctionExpr {
                 b.hash(state);
             },
             Repeat => {},
+            #[cfg(feature = "dtype-uuid")]
+            Uuid(version) => version.hash(state),
+            #[cfg(feature = "dtype-variant")]
+            VariantGet(path) => path.hash(state),
             #[cfg(feature = "rank")]
             Rank { options, seed } => {
                 options.hash(state);
@@ -527,7 +550,10 @@ impl Hash for FunctionExpr {
             Exp => {},
             Unique(a) => a.hash(state),
             #[cfg(feature = "round_series")]
-            Round { decimals } => decimals.hash(state),
+            Round { decimals, mode } => {
+                decimals.hash(state);
+                mode.hash(state);
+            },
             #[cfg(feature = "round_series")]
             FunctionExpr::RoundSF { digits } => digits.hash(state),
             #[cfg(feature = "round_series")]
@@ -624,6 +650,8 @@ impl Display for FunctionExpr {
             #[cfg(feature = "dtype-categorical")]
             Categorical(func) => return write!(f, "{func}"),
             ListExpr(func) => return write!(f, "{func}"),
+            #[cfg(feature = "dtype-map")]
+            MapExpr(func) => return write!(f, "{func}"),
             #[cfg(feature = "strings")]
             StringExpr(func) => return write!(f, "{func}"),
             #[cfg(feature = "dtype-struct")]
@@ -674,6 +702,10 @@ impl Display for FunctionExpr {
             Kurtosis(..) => "kurtosis",
             ArgUnique => "arg_unique",
             Repeat => "repeat",
+            #[cfg(feature = "dtype-uuid")]
+            Uuid(version) => version.into(),
+            #[cfg(feature = "dtype-variant")]
+            VariantGet(_) => "variant_get",
             #[cfg(feature = "rank")]
             Rank { .. } => "rank",
             #[cfg(feature = "round_series")]
@@ -898,6 +930,8 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
             #[cfg(feature = "dtype-categorical")]
             Categorical(func) => func.into(),
             ListExpr(func) => func.into(),
+            #[cfg(feature = "dtype-map")]
+            MapExpr(func) => func.into(),
             #[cfg(feature = "strings")]
             StringExpr(func) => func.into(),
             #[cfg(feature = "dtype-struct")]
@@ -1037,6 +1071,10 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
             Kurtosis(fisher, bias) => map!(dispatch::kurtosis, fisher, bias),
             ArgUnique => map!(dispatch::arg_unique),
             Repeat => map_as_slice!(repeat::repeat),
+            #[cfg(feature = "dtype-uuid")]
+            Uuid(version) => map_as_slice!(uuid::generate_uuids, version),
+            #[cfg(feature = "dtype-variant")]
+            VariantGet(path) => map!(variant::variant_get, &path),
             #[cfg(feature = "rank")]
             Rank { options, seed } => map!(dispatch::rank, options, seed),
             #[cfg(feature = "dtype-struct")]
@@ -1102,7 +1140,7 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
             Exp => map!(log::exp),
             Unique(stable) => map!(unique::unique, stable),
             #[cfg(feature = "round_series")]
-            Round { decimals } => map!(round::round, decimals),
+            Round { decimals, mode } => map!(round::round, decimals, mode),
             #[cfg(feature = "round_series")]
             RoundSF { digits } => map!(round::round_sig_figs, digits),
             #[cfg(feature = "round_series")]
@@ -132,10 +132,64 @@ where
     }
 }
 
+#[cfg(feature = "dtype-decimal")]
+fn pow_on_decimal(base: &DecimalChunked, exponent: &Column) -> PolarsResult<Option<Column>> {
+    use polars_core::chunked_array::ops::decimal::dec128_pow;
+    use polars_core::prelude::arity::broadcast_try_binary_elementwise;
+
+    if exponent.dtype().is_float() {
+        let base = base.cast(&DataType::Float64)?;
+        let exponent = exponent.cast(&DataType::Float64)?;
+        return pow_on_floats(base.f64().unwrap(), exponent.f64().unwrap());
+    }
+    let exponent = exponent.strict_cast(&DataType::UInt32).map_err(|err| polars_err!(
+        InvalidOperation:
+        "{}\n\nHint: if you were trying to raise a decimal to a negative power, please cast your base or exponent to float first.",
+        err
+    ))?;
+    let scale = base.scale();
+    let mode = RoundMode::default();
+    let out = broadcast_try_binary_elementwise(
+        base.physical(),
+        exponent.u32().unwrap(),
+        |b, e| {
+            match (b, e) {
+            (Some(b), Some(e)) => dec128_pow(b, scale, e, mode).map(Some).ok_or_else(
+                || polars_err!(ComputeError: "decimal overflow in pow: result does not fit Decimal(38, {})", scale),
+            ),
+            _ => Ok(None),
+        }
+        },
+    )?;
+    Ok(Some(
+        out.with_name(base.name().clone())
+            .into_decimal_unchecked(None, scale)
+            .into_column(),
+    ))
+}
+
 fn pow_on_series(base: &Column, exponent: &Column) -> PolarsResult<Option<Column>> {
     use DataType::*;
 
     let base_dtype = base.dtype();
+    // Half precision floats are raised in single precision.
+    #[cfg(feature = "dtype-f16")]
+    if base_dtype == &Float16 || exponent.dtype() == &Float16 {
+        let widen = |c: &Column| match c.dtype() {
+            Float16 => c.cast(&Float32),
+            _ => Ok(c.clone()),
+        };
+        let narrow = base_dtype == &Float16 || base_dtype.is_integer();
+        let out = pow_on_series(&widen(base)?, &widen(exponent)?)?;
+        return match out {
+            Some(out) if narrow => out.cast(&Float16).map(Some),
+            out => Ok(out),
+        };
+    }
+    #[cfg(feature = "dtype-decimal")]
+    if let Decimal(_, _) = base_dtype {
+        return pow_on_decimal(base.decimal()?, exponent);
+    }
     polars_ensure!(
         base_dtype.is_primitive_numeric(),
         InvalidOperation: "`pow` operation not supported for dtype `{}` as base", base_dtype
@@ -1,7 +1,7 @@
 use super::*;
 
-pub(super) fn round(c: &Column, decimals: u32) -> PolarsResult<Column> {
-    c.try_apply_unary_elementwise(|s| s.round(decimals))
+pub(super) fn round(c: &Column, decimals: u32, mode: Option<RoundMode>) -> PolarsResult<Column> {
+    c.try_apply_unary_elementwise(|s| s.round_with_mode(decimals, mode))
 }
 
 pub(super) fn round_sig_figs(c: &Column, digits: i32) -> PolarsResult<Column> {
@@ -20,6 +20,8 @@ impl FunctionExpr {
             #[cfg(feature = "dtype-categorical")]
             Categorical(func) => func.get_field(mapper),
             ListExpr(func) => func.get_field(mapper),
+            #[cfg(feature = "dtype-map")]
+            MapExpr(func) => func.get_field(mapper),
             #[cfg(feature = "strings")]
             StringExpr(s) => s.get_field(mapper),
             #[cfg(feature = "dtype-struct")]
@@ -67,7 +69,8 @@ impl FunctionExpr {
                 use RollingFunction::*;
                 match rolling_func {
                     Min(_) | Max(_) => mapper.with_same_dtype(),
-                    Mean(_) | Quantile(_) | Var(_) | Std(_) => mapper.map_to_float_dtype(),
+                    Mean(_) | Var(_) | Std(_) => mapper.map_to_moment_dtype(),
+                    Quantile(_) => mapper.map_to_float_dtype(),
                     Sum(_) => mapper.sum_dtype(),
                     #[cfg(feature = "cov")]
                     CorrCov {..} => mapper.map_to_float_dtype(),
@@ -97,6 +100,10 @@ impl FunctionExpr {
             Kurtosis(..) => mapper.with_dtype(DataType::Float64),
             ArgUnique => mapper.with_dtype(IDX_DTYPE),
             Repeat => mapper.with_same_dtype(),
+            #[cfg(feature = "dtype-uuid")]
+            Uuid(_) => mapper.with_dtype(DataType::Uuid),
+            #[cfg(feature = "dtype-variant")]
+            VariantGet(_) => mapper.with_dtype(DataType::Variant),
             #[cfg(feature = "rank")]
             Rank { options, .. } => mapper.with_dtype(match options.method {
                 RankMethod::Average => DataType::Float64,
@@ -437,6 +444,19 @@ impl<'a> FieldsMapper<'a> {
         })
     }
 
+    /// Map to a float supertype, decimals stay exact with the scale of a decimal division.
+    pub fn map_to_moment_dtype(&self) -> PolarsResult<Field> {
+        self.map_dtype(|dtype| match dtype {
+            #[cfg(feature = "dtype-decimal")]
+            DataType::Decimal(_, Some(scale)) => DataType::Decimal(
+                None,
+                Some(polars_core::chunked_array::arithmetic::_get_decimal_scale_div(*scale)),
+            ),
+            DataType::Float32 => DataType::Float32,
+            _ => DataType::Float64,
+        })
+    }
+
     /// Map to a float supertype if numeric, else preserve
     pub fn map_numeric_to_float_dtype(&self) -> PolarsResult<Field> {
         self.map_dtype(|dtype| {
@@ -596,6 +616,16 @@ impl<'a> FieldsMapper<'a> {
     pub(super) fn pow_dtype(&self) -> PolarsResult<Field> {
         let base_dtype = self.fields[0].dtype();
         let exponent_dtype = self.fields[1].dtype();
+        #[cfg(feature = "dtype-decimal")]
+        if let DataType::Decimal(_, Some(scale)) = base_dtype {
+            // Integer powers of a decimal are exact and keep the scale of the base.
+            let dtype = if exponent_dtype.is_float() {
+                DataType::Float64
+            } else {
+                DataType::Decimal(None, Some(*scale))
+            };
+            return Ok(Field::new(self.fields[0].name().clone(), dtype));
+        }
         if base_dtype.is_integer() {
             if exponent_dtype.is_float() {
                 Ok(Field::new(
@@ -116,6 +116,8 @@ pub enum StringFunction {
     Split(bool),
     #[cfg(feature = "dtype-decimal")]
     ToDecimal(usize),
+    #[cfg(feature = "dtype-interval")]
+    ToInterval(bool),
     #[cfg(feature = "nightly")]
     Titlecase,
     Uppercase,
@@ -180,6 +182,8 @@ impl StringFunction {
             Titlecase => mapper.with_same_dtype(),
             #[cfg(feature = "dtype-decimal")]
             ToDecimal(_) => mapper.with_dtype(DataType::Decimal(None, None)),
+            #[cfg(feature = "dtype-interval")]
+            ToInterval(_) => mapper.with_dtype(DataType::Interval),
             #[cfg(feature = "string_encoding")]
             HexEncode => mapper.with_same_dtype(),
             #[cfg(feature = "binary_encoding")]
@@ -295,6 +299,8 @@ impl Display for StringFunction {
             Titlecase => "titlecase",
             #[cfg(feature = "dtype-decimal")]
             ToDecimal(_) => "to_decimal",
+            #[cfg(feature = "dtype-interval")]
+            ToInterval(_) => "to_interval",
             Uppercase => "uppercase",
             #[cfg(feature = "string_pad")]
             ZFill => "zfill",
@@ -399,6 +405,8 @@ impl From<StringFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             Base64Decode(strict) => map!(strings::base64_decode, strict),
             #[cfg(feature = "dtype-decimal")]
             ToDecimal(infer_len) => map!(strings::to_decimal, infer_len),
+            #[cfg(feature = "dtype-interval")]
+            ToInterval(strict) => map!(strings::to_interval, strict),
             #[cfg(feature = "extract_jsonpath")]
             JsonDecode {
                 dtype,
@@ -1090,6 +1098,12 @@ pub(super) fn to_decimal(s: &Column, infer_len: usize) -> PolarsResult<Column> {
     ca.to_decimal(infer_len).map(Column::from)
 }
 
+#[cfg(feature = "dtype-interval")]
+pub(super) fn to_interval(s: &Column, strict: bool) -> PolarsResult<Column> {
+    let ca = s.str()?;
+    ca.as_interval(strict).map(|ca| ca.into_column())
+}
+
 #[cfg(feature = "extract_jsonpath")]
 pub(super) fn json_decode(
     s: &Column,
@@ -0,0 +1,36 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use strum_macros::IntoStaticStr;
+
+use super::*;
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash, IntoStaticStr)]
+pub enum UuidVersion {
+    /// Random UUIDs.
+    #[strum(serialize = "uuid_v4")]
+    V4,
+    /// Time-ordered UUIDs.
+    #[strum(serialize = "uuid_v7")]
+    V7,
+}
+
+pub(super) fn generate_uuids(args: &[Column], version: UuidVersion) -> PolarsResult<Column> {
+    let n = &args[0];
+    polars_ensure!(
+        n.dtype().is_integer(),
+        SchemaMismatch: "expected expression of dtype 'integer', got '{}'", n.dtype()
+    );
+
+    let first_value = n.get(0)?;
+    let n = first_value.extract::<usize>().ok_or_else(
+        || polars_err!(ComputeError: "could not parse value '{}' as a size.", first_value),
+    )?;
+
+    let name = PlSmallStr::from_static(version.into());
+    let ca = match version {
+        UuidVersion::V4 => UuidChunked::new_v4(name, n),
+        UuidVersion::V7 => UuidChunked::new_v7(name, n),
+    };
+    Ok(ca.into_column())
+}
@@ -0,0 +1,5 @@
+use super::*;
+
+pub(super) fn variant_get(c: &Column, path: &str) -> PolarsResult<Column> {
+    Ok(c.variant()?.get_path(path)?.into_column())
+}
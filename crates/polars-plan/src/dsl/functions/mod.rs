@@ -19,6 +19,8 @@ mod selectors;
 mod syntactic_sugar;
 #[cfg(feature = "temporal")]
 mod temporal;
+#[cfg(feature = "dtype-uuid")]
+mod uuid;
 
 pub use arity::*;
 #[cfg(all(feature = "business", feature = "dtype-date"))]
@@ -44,6 +46,8 @@ pub use selectors::*;
 pub use syntactic_sugar::*;
 #[cfg(feature = "temporal")]
 pub use temporal::*;
+#[cfg(feature = "dtype-uuid")]
+pub use uuid::*;
 
 #[cfg(feature = "arg_where")]
 use crate::dsl::function_expr::FunctionExpr;
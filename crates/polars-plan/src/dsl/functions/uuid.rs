@@ -0,0 +1,27 @@
+use super::*;
+
+fn uuid(n: Expr, version: UuidVersion) -> Expr {
+    let expr = Expr::Function {
+        input: vec![n],
+        function: FunctionExpr::Uuid(version),
+        options: FunctionOptions {
+            flags: FunctionFlags::default()
+                | FunctionFlags::ALLOW_RENAME
+                | FunctionFlags::CHANGES_LENGTH,
+            ..Default::default()
+        },
+    };
+    expr.alias(PlSmallStr::from_static("uuid"))
+}
+
+/// Generate a column of `n` random (version 4) UUIDs.
+pub fn uuid_v4(n: Expr) -> Expr {
+    uuid(n, UuidVersion::V4)
+}
+
+/// Generate a column of `n` time-ordered (version 7) UUIDs.
+///
+/// The UUIDs share the timestamp of the moment they are generated and increase monotonically.
+pub fn uuid_v7(n: Expr) -> Expr {
+    uuid(n, UuidVersion::V7)
+}
@@ -0,0 +1,34 @@
+use crate::dsl::function_expr::MapFunction;
+use crate::prelude::*;
+
+/// Specialized expressions for [`Series`] of [`DataType::Map`].
+pub struct MapNameSpace(pub Expr);
+
+impl MapNameSpace {
+    /// Get the value of `key` in every map, or null if the map does not contain the key.
+    pub fn get(self, key: Expr) -> Expr {
+        self.0
+            .map_many_private(FunctionExpr::MapExpr(MapFunction::Get), &[key], false, None)
+    }
+
+    /// Get the keys of every map as a list.
+    pub fn keys(self) -> Expr {
+        self.0.map_private(FunctionExpr::MapExpr(MapFunction::Keys))
+    }
+
+    /// Get the values of every map as a list.
+    pub fn values(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::MapExpr(MapFunction::Values))
+    }
+}
+
+/// Create a map column from a list column of keys and a list column of values.
+pub fn map_from_lists(keys: Expr, values: Expr) -> Expr {
+    keys.map_many_private(
+        FunctionExpr::MapExpr(MapFunction::FromLists),
+        &[values],
+        false,
+        None,
+    )
+}
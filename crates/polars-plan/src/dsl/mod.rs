@@ -26,6 +26,8 @@ mod from;
 pub mod function_expr;
 pub mod functions;
 mod list;
+#[cfg(feature = "dtype-map")]
+mod map;
 #[cfg(feature = "meta")]
 mod meta;
 mod name;
@@ -55,6 +57,8 @@ pub use function_expr::schema::FieldsMapper;
 pub use function_expr::*;
 pub use functions::*;
 pub use list::*;
+#[cfg(feature = "dtype-map")]
+pub use map::*;
 #[cfg(feature = "meta")]
 pub use meta::*;
 pub use name::*;
@@ -897,7 +901,19 @@ impl Expr {
     /// Round underlying floating point array to given decimal numbers.
     #[cfg(feature = "round_series")]
     pub fn round(self, decimals: u32) -> Self {
-        self.map_private(FunctionExpr::Round { decimals })
+        self.map_private(FunctionExpr::Round {
+            decimals,
+            mode: None,
+        })
+    }
+
+    /// Round to given decimal numbers with an explicit [`RoundMode`].
+    #[cfg(feature = "round_series")]
+    pub fn round_with_mode(self, decimals: u32, mode: RoundMode) -> Self {
+        self.map_private(FunctionExpr::Round {
+            decimals,
+            mode: Some(mode),
+        })
     }
 
     /// Round to a number of significant figures.
@@ -1569,6 +1585,14 @@ impl Expr {
         self.apply_private(FunctionExpr::PeakMax)
     }
 
+    #[cfg(feature = "dtype-variant")]
+    /// Extract the values at a JSON path like `$.a.b[0]` from a variant column.
+    ///
+    /// Values that don't have the path become null. Cast the result to get typed values.
+    pub fn variant_get(self, path: &str) -> Expr {
+        self.apply_private(FunctionExpr::VariantGet(path.into()))
+    }
+
     #[cfg(feature = "rank")]
     /// Assign ranks to data, dealing with ties appropriately.
     pub fn rank(self, options: RankOptions, seed: Option<u64>) -> Expr {
@@ -1976,6 +2000,12 @@ impl Expr {
         array::ArrayNameSpace(self)
     }
 
+    /// Get the [`map::MapNameSpace`].
+    #[cfg(feature = "dtype-map")]
+    pub fn map_(self) -> map::MapNameSpace {
+        map::MapNameSpace(self)
+    }
+
     /// Get the [`CategoricalNameSpace`].
     #[cfg(feature = "dtype-categorical")]
     pub fn cat(self) -> cat::CategoricalNameSpace {
@@ -5,6 +5,7 @@ use std::path::PathBuf;
 use std::str::FromStr;
 use std::sync::Arc;
 
+use arrow::datatypes::Metadata;
 use polars_core::error::PolarsResult;
 use polars_core::prelude::*;
 #[cfg(feature = "csv")]
@@ -345,6 +346,11 @@ pub struct SinkOptions {
 
     /// Recursively create all the directories in the path.
     pub mkdir: bool,
+
+    /// Encode these columns with persisted categorical dictionaries. IPC and Parquet files record
+    /// the dictionary versions in their metadata, so that scans can validate them.
+    #[cfg(feature = "dtype-categorical")]
+    pub categorical_dictionaries: Option<CategoricalDictionaries>,
 }
 
 impl Default for SinkOptions {
@@ -353,10 +359,23 @@ impl Default for SinkOptions {
             sync_on_close: Default::default(),
             maintain_order: true,
             mkdir: false,
+            #[cfg(feature = "dtype-categorical")]
+            categorical_dictionaries: None,
         }
     }
 }
 
+impl SinkOptions {
+    /// The custom file metadata that is written by the sink.
+    pub fn custom_metadata(&self) -> Option<Arc<Metadata>> {
+        #[cfg(feature = "dtype-categorical")]
+        if let Some(dictionaries) = &self.categorical_dictionaries {
+            return Some(Arc::new(categorical_dictionaries_to_metadata(dictionaries)));
+        }
+        None
+    }
+}
+
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct FileSinkType {
@@ -502,6 +521,103 @@ pub enum FileType {
     #[cfg(feature = "json")]
     Json(JsonWriterOptions),
 }
+
+/// Name of the column that identifies the grouping set of every row of a grouping sets
+/// aggregation.
+pub const GROUPING_ID: &str = "grouping_id";
+
+/// The sets of keys a grouping sets aggregation groups by.
+///
+/// Every set is a list of indices into the group by keys. The keys that are not part of a set
+/// are null in the rows of that set, and the set is identified by its `grouping_id`: a bitmask
+/// in which the bit of a key is set if that key is *not* part of the set, with the first key in
+/// the most significant position.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct GroupingSets {
+    sets: Vec<Vec<usize>>,
+}
+
+impl GroupingSets {
+    pub fn new(sets: Vec<Vec<usize>>) -> Self {
+        Self { sets }
+    }
+
+    /// `ROLLUP` over `n_keys` keys: every prefix of the keys, from all keys to none.
+    pub fn rollup(n_keys: usize) -> Self {
+        Self::new((0..=n_keys).rev().map(|n| (0..n).collect()).collect())
+    }
+
+    /// The maximum number of keys of a `CUBE`, which has `2^n_keys` grouping sets.
+    pub const MAX_CUBE_KEYS: usize = 12;
+
+    /// `CUBE` over `n_keys` keys: every subset of the keys, from all keys to none.
+    pub fn cube(n_keys: usize) -> PolarsResult<Self> {
+        polars_ensure!(
+            n_keys <= Self::MAX_CUBE_KEYS,
+            InvalidOperation: "CUBE supports at most {} keys, got {}; \
+            use ROLLUP or explicit GROUPING SETS instead",
+            Self::MAX_CUBE_KEYS, n_keys
+        );
+        let full = (1u64 << n_keys) - 1;
+        Ok(Self::new(
+            (0..=full)
+                .map(|grouping_id| {
+                    (0..n_keys)
+                        .filter(|i| grouping_id & (1 << (n_keys - 1 - i)) == 0)
+                        .collect()
+                })
+                .collect(),
+        ))
+    }
+
+    /// Every key needs a bit in the `grouping_id`.
+    pub fn check_n_keys(n_keys: usize) -> PolarsResult<()> {
+        polars_ensure!(
+            n_keys < 64,
+            InvalidOperation: "grouping sets support at most 63 keys, got {}", n_keys
+        );
+        Ok(())
+    }
+
+    pub fn sets(&self) -> &[Vec<usize>] {
+        &self.sets
+    }
+
+    /// The `grouping_id` of the given set.
+    pub fn grouping_id(set: &[usize], n_keys: usize) -> u64 {
+        (0..n_keys)
+            .filter(|i| !set.contains(i))
+            .fold(0, |id, i| id | (1 << (n_keys - 1 - i)))
+    }
+
+    /// The `GROUPING` of the given keys in the rows of a grouping sets aggregation over
+    /// `n_keys` keys.
+    ///
+    /// This is a bitmask like the `grouping_id`, but with a bit for each of the given keys only,
+    /// the first given key in the most significant position.
+    pub fn grouping(keys: &[usize], n_keys: usize) -> PolarsResult<Expr> {
+        polars_ensure!(!keys.is_empty(), InvalidOperation: "GROUPING requires at least one key");
+        Self::check_n_keys(keys.len())?;
+        if let Some(i) = keys.iter().find(|i| **i >= n_keys) {
+            polars_bail!(OutOfBounds: "GROUPING refers to key {} but there are only {} keys", i, n_keys)
+        }
+        let grouping_id = crate::dsl::col(GROUPING_ID);
+        Ok(keys
+            .iter()
+            .enumerate()
+            .map(|(j, i)| {
+                let bit = grouping_id
+                    .clone()
+                    .floor_div(crate::dsl::lit(1u64 << (n_keys - 1 - i)))
+                    % crate::dsl::lit(2u64);
+                bit * crate::dsl::lit(1u64 << (keys.len() - 1 - j))
+            })
+            .reduce(|acc, bit| acc + bit)
+            .unwrap())
+    }
+}
+
 //
 // Arguments given to `concat`. Differs from `UnionOptions` as the latter is IR state.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -206,6 +206,223 @@ impl DslPlan {
         Ok(DslPlanDisplay(self.clone().to_alp()?))
     }
 
+    /// Apply `f` to every node of every expression in the plan, including the expressions
+    /// of the plans nested in sub-queries.
+    #[recursive]
+    pub fn map_exprs(self, f: &mut impl FnMut(Expr) -> Expr) -> Self {
+        fn map_input(input: Arc<DslPlan>, f: &mut impl FnMut(Expr) -> Expr) -> Arc<DslPlan> {
+            Arc::new(Arc::unwrap_or_clone(input).map_exprs(f))
+        }
+        fn map_inputs(inputs: Vec<DslPlan>, f: &mut impl FnMut(Expr) -> Expr) -> Vec<DslPlan> {
+            inputs.into_iter().map(|p| p.map_exprs(f)).collect()
+        }
+        fn map_expr(expr: Expr, f: &mut impl FnMut(Expr) -> Expr) -> Expr {
+            expr.map_expr(|e| match e {
+                Expr::SubPlan(plan, names) => {
+                    let plan = (**plan).clone().map_exprs(f);
+                    Expr::SubPlan(SpecialEq::new(Arc::new(plan)), names)
+                },
+                e => f(e),
+            })
+        }
+        fn map_exprs(exprs: Vec<Expr>, f: &mut impl FnMut(Expr) -> Expr) -> Vec<Expr> {
+            exprs.into_iter().map(|e| map_expr(e, f)).collect()
+        }
+        fn map_selectors(
+            selectors: Vec<Selector>,
+            f: &mut impl FnMut(Expr) -> Expr,
+        ) -> Vec<Selector> {
+            fn map_selector(selector: Selector, f: &mut impl FnMut(Expr) -> Expr) -> Selector {
+                let mut map_box = |s: Box<Selector>| Box::new(map_selector(*s, f));
+                match selector {
+                    Selector::Add(l, r) => Selector::Add(map_box(l), map_box(r)),
+                    Selector::Sub(l, r) => Selector::Sub(map_box(l), map_box(r)),
+                    Selector::ExclusiveOr(l, r) => Selector::ExclusiveOr(map_box(l), map_box(r)),
+                    Selector::Intersect(l, r) => Selector::Intersect(map_box(l), map_box(r)),
+                    Selector::Root(e) => Selector::Root(Box::new(map_expr(*e, f))),
+                }
+            }
+            selectors.into_iter().map(|s| map_selector(s, f)).collect()
+        }
+
+        match self {
+            #[cfg(feature = "python")]
+            plan @ DslPlan::PythonScan { .. } => plan,
+            plan @ (DslPlan::Scan { .. } | DslPlan::DataFrameScan { .. }) => plan,
+            DslPlan::Filter { input, predicate } => DslPlan::Filter {
+                input: map_input(input, f),
+                predicate: map_expr(predicate, f),
+            },
+            DslPlan::Cache { input, id } => DslPlan::Cache {
+                input: map_input(input, f),
+                id,
+            },
+            DslPlan::Select {
+                expr,
+                input,
+                options,
+            } => DslPlan::Select {
+                expr: map_exprs(expr, f),
+                input: map_input(input, f),
+                options,
+            },
+            DslPlan::GroupBy {
+                input,
+                keys,
+                aggs,
+                maintain_order,
+                options,
+                apply,
+            } => DslPlan::GroupBy {
+                input: map_input(input, f),
+                keys: map_exprs(keys, f),
+                aggs: map_exprs(aggs, f),
+                maintain_order,
+                options,
+                apply,
+            },
+            DslPlan::Join {
+                input_left,
+                input_right,
+                left_on,
+                right_on,
+                predicates,
+                options,
+            } => DslPlan::Join {
+                input_left: map_input(input_left, f),
+                input_right: map_input(input_right, f),
+                left_on: map_exprs(left_on, f),
+                right_on: map_exprs(right_on, f),
+                predicates: map_exprs(predicates, f),
+                options,
+            },
+            DslPlan::HStack {
+                input,
+                exprs,
+                options,
+            } => DslPlan::HStack {
+                input: map_input(input, f),
+                exprs: map_exprs(exprs, f),
+                options,
+            },
+            DslPlan::Distinct { input, options } => DslPlan::Distinct {
+                input: map_input(input, f),
+                options,
+            },
+            DslPlan::Sort {
+                input,
+                by_column,
+                slice,
+                sort_options,
+            } => DslPlan::Sort {
+                input: map_input(input, f),
+                by_column: map_exprs(by_column, f),
+                slice,
+                sort_options,
+            },
+            DslPlan::Slice { input, offset, len } => DslPlan::Slice {
+                input: map_input(input, f),
+                offset,
+                len,
+            },
+            DslPlan::MapFunction { input, function } => DslPlan::MapFunction {
+                input: map_input(input, f),
+                function: match function {
+                    DslFunction::Explode {
+                        columns,
+                        allow_empty,
+                    } => DslFunction::Explode {
+                        columns: map_selectors(columns, f),
+                        allow_empty,
+                    },
+                    DslFunction::Unnest(columns) => DslFunction::Unnest(map_selectors(columns, f)),
+                    DslFunction::Stats(StatsFunction::Quantile { quantile, method }) => {
+                        DslFunction::Stats(StatsFunction::Quantile {
+                            quantile: map_expr(quantile, f),
+                            method,
+                        })
+                    },
+                    DslFunction::FillNan(fill_value) => {
+                        DslFunction::FillNan(map_expr(fill_value, f))
+                    },
+                    DslFunction::Drop(DropFunction { to_drop, strict }) => {
+                        DslFunction::Drop(DropFunction {
+                            to_drop: map_selectors(to_drop, f),
+                            strict,
+                        })
+                    },
+                    #[cfg(feature = "pivot")]
+                    DslFunction::Unpivot { args } => DslFunction::Unpivot {
+                        args: UnpivotArgsDSL {
+                            on: map_selectors(args.on, f),
+                            index: map_selectors(args.index, f),
+                            ..args
+                        },
+                    },
+                    #[cfg(feature = "python")]
+                    function @ DslFunction::OpaquePython(_) => function,
+                    function @ (DslFunction::RowIndex { .. }
+                    | DslFunction::Rename { .. }
+                    | DslFunction::Stats(_)
+                    | DslFunction::FunctionIR(_)) => function,
+                },
+            },
+            DslPlan::Union { inputs, args } => DslPlan::Union {
+                inputs: map_inputs(inputs, f),
+                args,
+            },
+            DslPlan::HConcat { inputs, options } => DslPlan::HConcat {
+                inputs: map_inputs(inputs, f),
+                options,
+            },
+            DslPlan::ExtContext { input, contexts } => DslPlan::ExtContext {
+                input: map_input(input, f),
+                contexts: map_inputs(contexts, f),
+            },
+            DslPlan::Sink { input, payload } => DslPlan::Sink {
+                input: map_input(input, f),
+                payload: match payload {
+                    SinkType::Partition(mut partition) => {
+                        partition.variant = match partition.variant {
+                            PartitionVariant::Parted {
+                                key_exprs,
+                                include_key,
+                            } => PartitionVariant::Parted {
+                                key_exprs: map_exprs(key_exprs, f),
+                                include_key,
+                            },
+                            PartitionVariant::ByKey {
+                                key_exprs,
+                                include_key,
+                            } => PartitionVariant::ByKey {
+                                key_exprs: map_exprs(key_exprs, f),
+                                include_key,
+                            },
+                            variant @ PartitionVariant::MaxSize(_) => variant,
+                        };
+                        SinkType::Partition(partition)
+                    },
+                    payload @ (SinkType::Memory | SinkType::File(_)) => payload,
+                },
+            },
+            DslPlan::SinkMultiple { inputs } => DslPlan::SinkMultiple {
+                inputs: map_inputs(inputs, f),
+            },
+            #[cfg(feature = "merge_sorted")]
+            DslPlan::MergeSorted {
+                input_left,
+                input_right,
+                key,
+            } => DslPlan::MergeSorted {
+                input_left: map_input(input_left, f),
+                input_right: map_input(input_right, f),
+                key,
+            },
+            // The cached conversion is discarded, as the plan changes.
+            DslPlan::IR { dsl, .. } => Arc::unwrap_or_clone(dsl).map_exprs(f),
+        }
+    }
+
     pub fn to_alp(self) -> PolarsResult<IRPlan> {
         let mut lp_arena = Arena::with_capacity(16);
         let mut expr_arena = Arena::with_capacity(16);
@@ -361,6 +361,16 @@ impl StringNameSpace {
             .map_private(StringFunction::ToDecimal(infer_length).into())
     }
 
+    /// Convert a String column into an Interval column.
+    ///
+    /// Accepts duration strings such as `"1mo 2d 3h"` as well as verbose ones such as
+    /// `"2 months 5 days"`. If `strict` is false, unparsable values become null.
+    #[cfg(feature = "dtype-interval")]
+    pub fn to_interval(self, strict: bool) -> Expr {
+        self.0
+            .map_private(StringFunction::ToInterval(strict).into())
+    }
+
     /// Concat the values into a string array.
     /// # Arguments
     ///
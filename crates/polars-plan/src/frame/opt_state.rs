@@ -38,6 +38,10 @@ bitflags! {
         /// Check if operations are order dependent and unset maintaining_order if
         /// the order would not be observed.
         const CHECK_ORDER_OBSERVE = 1 << 16;
+        /// Reuse the results of cached subplans across separate queries. This is opt-in.
+        const RESULT_CACHE = 1 << 17;
+        /// Run the user-defined optimization rules of the query.
+        const USER_OPTIMIZATION_RULES = 1 << 18;
     }
 }
 
@@ -80,11 +84,21 @@ impl OptFlags {
     pub fn fast_projection(&self) -> bool {
         self.contains(OptFlags::FAST_PROJECTION)
     }
+    pub fn result_cache(&self) -> bool {
+        self.contains(OptFlags::RESULT_CACHE)
+    }
+    pub fn user_optimization_rules(&self) -> bool {
+        self.contains(OptFlags::USER_OPTIMIZATION_RULES)
+    }
 }
 
 impl Default for OptFlags {
     fn default() -> Self {
-        Self::from_bits_truncate(u32::MAX) & !Self::NEW_STREAMING & !Self::STREAMING & !Self::EAGER
+        Self::from_bits_truncate(u32::MAX)
+            & !Self::NEW_STREAMING
+            & !Self::STREAMING
+            & !Self::EAGER
+            & !Self::RESULT_CACHE
     }
 }
 
@@ -2,6 +2,10 @@
 use polars_core::chunked_array::arithmetic::{
     _get_decimal_scale_add_sub, _get_decimal_scale_div, _get_decimal_scale_mul,
 };
+#[cfg(feature = "dtype-decimal256")]
+use polars_core::chunked_array::arithmetic::{
+    _get_decimal256_scale_div, _get_decimal256_scale_mul,
+};
 use recursive::recursive;
 
 use super::*;
@@ -19,6 +23,17 @@ fn float_type(field: &mut Field) {
     }
 }
 
+/// Like [`float_type`], but decimals stay exact and get the scale of a decimal division.
+fn moment_type(field: &mut Field) {
+    match field.dtype {
+        #[cfg(feature = "dtype-decimal")]
+        DataType::Decimal(_, Some(scale)) => {
+            field.coerce(DataType::Decimal(None, Some(_get_decimal_scale_div(scale))))
+        },
+        _ => float_type(field),
+    }
+}
+
 fn validate_expr(node: Node, arena: &Arena<AExpr>, schema: &Schema) -> PolarsResult<()> {
     let mut ctx = ToFieldContext {
         schema,
@@ -260,7 +275,7 @@ impl AExpr {
                         let mut field = ctx.arena.get(*expr).to_field_impl(ctx, &mut false)?;
                         match field.dtype {
                             Date => field.coerce(Datetime(TimeUnit::Milliseconds, None)),
-                            _ => float_type(&mut field),
+                            _ => moment_type(&mut field),
                         }
                         Ok(field)
                     },
@@ -272,13 +287,13 @@ impl AExpr {
                     Std(expr, _) => {
                         *agg_list = false;
                         let mut field = ctx.arena.get(*expr).to_field_impl(ctx, &mut false)?;
-                        float_type(&mut field);
+                        moment_type(&mut field);
                         Ok(field)
                     },
                     Var(expr, _) => {
                         *agg_list = false;
                         let mut field = ctx.arena.get(*expr).to_field_impl(ctx, &mut false)?;
-                        float_type(&mut field);
+                        moment_type(&mut field);
                         Ok(field)
                     },
                     NUnique(expr) => {
@@ -457,6 +472,8 @@ fn get_arithmetic_field(
                 | (Date, Duration(_))
                 | (Duration(_), Time)
                 | (Time, Duration(_)) => try_get_supertype(left_field.dtype(), &right_type)?,
+                #[cfg(feature = "dtype-interval")]
+                (Datetime(_, _) | Date, Interval) => return Ok(left_field),
                 (Datetime(tu, _), Date) | (Date, Datetime(tu, _)) => Duration(*tu),
                 // T - T != T if T is a datetime / date
                 (Datetime(tul, _), Datetime(tur, _)) => Duration(get_time_units(tul, tur)),
@@ -509,6 +526,11 @@ fn get_arithmetic_field(
                     let scale = _get_decimal_scale_add_sub(*scale_left, *scale_right);
                     Decimal(None, Some(scale))
                 },
+                #[cfg(feature = "dtype-decimal256")]
+                (Decimal256(_, Some(scale_left)), Decimal256(_, Some(scale_right))) => {
+                    let scale = _get_decimal_scale_add_sub(*scale_left, *scale_right);
+                    Decimal256(None, Some(scale))
+                },
                 (left, right) => try_get_supertype(left, right)?,
             }
         },
@@ -521,6 +543,10 @@ fn get_arithmetic_field(
                 | (Date, Duration(_))
                 | (Duration(_), Time)
                 | (Time, Duration(_)) => try_get_supertype(left_field.dtype(), &right_type)?,
+                #[cfg(feature = "dtype-interval")]
+                (Datetime(_, _) | Date, Interval) => return Ok(left_field),
+                #[cfg(feature = "dtype-interval")]
+                (Interval, dt @ (Datetime(_, _) | Date)) => dt.clone(),
                 (_, Datetime(_, _))
                 | (Datetime(_, _), _)
                 | (_, Date)
@@ -563,6 +589,11 @@ fn get_arithmetic_field(
                     let scale = _get_decimal_scale_add_sub(*scale_left, *scale_right);
                     Decimal(None, Some(scale))
                 },
+                #[cfg(feature = "dtype-decimal256")]
+                (Decimal256(_, Some(scale_left)), Decimal256(_, Some(scale_right))) => {
+                    let scale = _get_decimal_scale_add_sub(*scale_left, *scale_right);
+                    Decimal256(None, Some(scale))
+                },
                 (left, right) => try_get_supertype(left, right)?,
             }
         },
@@ -619,6 +650,19 @@ fn get_arithmetic_field(
                     left_field.coerce(dtype);
                     return Ok(left_field);
                 },
+                #[cfg(feature = "dtype-decimal256")]
+                (Decimal256(_, Some(scale_left)), Decimal256(_, Some(scale_right))) => {
+                    let scale = match op {
+                        Operator::Multiply => _get_decimal256_scale_mul(*scale_left, *scale_right),
+                        Operator::Divide | Operator::TrueDivide => {
+                            _get_decimal256_scale_div(*scale_left)
+                        },
+                        _ => _get_decimal_scale_add_sub(*scale_left, *scale_right),
+                    };
+                    let dtype = Decimal256(None, Some(scale));
+                    left_field.coerce(dtype);
+                    return Ok(left_field);
+                },
 
                 (l @ List(a), r @ List(b))
                     if ![a, b]
@@ -726,6 +770,11 @@ fn get_truediv_dtype(left_dtype: &DataType, right_dtype: &DataType) -> PolarsRes
             let scale = _get_decimal_scale_div(*scale_left);
             Decimal(None, Some(scale))
         },
+        #[cfg(feature = "dtype-decimal256")]
+        (Decimal256(_, Some(scale_left)), Decimal256(_, _)) => {
+            let scale = _get_decimal256_scale_div(*scale_left);
+            Decimal256(None, Some(scale))
+        },
         (dt, _) if dt.is_primitive_numeric() => Float64,
         #[cfg(feature = "dtype-duration")]
         (Duration(_), Duration(_)) => Float64,
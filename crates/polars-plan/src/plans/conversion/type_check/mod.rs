@@ -9,6 +9,10 @@ use crate::plans::conversion::get_schema;
 pub struct TypeCheckRule;
 
 impl OptimizationRule for TypeCheckRule {
+    fn name(&self) -> &str {
+        "type_check"
+    }
+
     fn optimize_plan(
         &mut self,
         ir_arena: &mut Arena<IR>,
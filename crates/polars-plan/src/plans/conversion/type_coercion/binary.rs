@@ -1,4 +1,4 @@
-#[cfg(feature = "dtype-categorical")]
+#[cfg(any(feature = "dtype-categorical", feature = "dtype-uuid"))]
 use polars_utils::matches_any_order;
 
 use super::*;
@@ -96,6 +96,47 @@ fn process_struct_numeric_arithmetic(
     }
 }
 
+/// Adding a calendar interval is not a fixed-length shift, so `Date`/`Datetime` +/- `Interval`
+/// is rewritten to `dt.offset_by`.
+#[cfg(all(feature = "dtype-interval", feature = "offset_by"))]
+fn process_interval_arithmetic(
+    type_left: &DataType,
+    node_left: Node,
+    node_right: Node,
+    op: Operator,
+    expr_arena: &mut Arena<AExpr>,
+) -> PolarsResult<Option<AExpr>> {
+    let options = FunctionOptions {
+        collect_groups: ApplyOptions::ElementWise,
+        ..Default::default()
+    };
+    let (node_temporal, mut node_interval) = match type_left {
+        DataType::Interval => {
+            polars_ensure!(
+                op == Operator::Plus,
+                InvalidOperation: "{} not allowed on interval and temporal values", op
+            );
+            (node_right, node_left)
+        },
+        _ => (node_left, node_right),
+    };
+    if op == Operator::Minus {
+        node_interval = expr_arena.add(AExpr::Function {
+            input: vec![ExprIR::from_node(node_interval, expr_arena)],
+            function: FunctionExpr::Negate,
+            options,
+        });
+    }
+    Ok(Some(AExpr::Function {
+        input: vec![
+            ExprIR::from_node(node_temporal, expr_arena),
+            ExprIR::from_node(node_interval, expr_arena),
+        ],
+        function: FunctionExpr::TemporalExpr(TemporalFunction::OffsetBy),
+        options,
+    }))
+}
+
 #[cfg(any(
     feature = "dtype-date",
     feature = "dtype-datetime",
@@ -213,6 +254,14 @@ pub(super) fn process_binary(
         match (&type_left, &type_right) {
             (Duration(_), Duration(_)) => return Ok(None),
             (Duration(_), r) if r.is_primitive_numeric() => return Ok(None),
+            #[cfg(all(feature = "dtype-interval", feature = "offset_by"))]
+            (Date | Datetime(_, _), Interval) | (Interval, Date | Datetime(_, _))
+                if matches!(op, Operator::Plus | Operator::Minus) =>
+            {
+                return process_interval_arithmetic(
+                    &type_left, node_left, node_right, op, expr_arena,
+                );
+            },
             (String, a) | (a, String) if a.is_primitive_numeric() => {
                 polars_bail!(InvalidOperation: "arithmetic on string and numeric not allowed, try an explicit cast first")
             },
@@ -241,7 +290,22 @@ pub(super) fn process_binary(
     }
 
     // Coerce types:
-    let st = unpack!(get_supertype(&type_left, &type_right));
+    let st = get_supertype(&type_left, &type_right);
+    // Parse the strings when comparing them to uuids, so that the comparison is done on the
+    // fixed-width values.
+    #[cfg(feature = "dtype-uuid")]
+    let st = if op.is_comparison_or_bitwise()
+        && matches_any_order!(
+            &type_left,
+            &type_right,
+            String | Unknown(UnknownKind::Str),
+            Uuid
+        ) {
+        Some(Uuid)
+    } else {
+        st
+    };
+    let st = unpack!(st);
     let mut st = modify_supertype(st, left, right, &type_left, &type_right);
 
     if is_cat_str_binary(&type_left, &type_right) {
@@ -86,6 +86,10 @@ fn materialize(aexpr: &AExpr) -> Option<AExpr> {
 }
 
 impl OptimizationRule for TypeCoercionRule {
+    fn name(&self) -> &str {
+        "type_coercion"
+    }
+
     fn optimize_expr(
         &mut self,
         expr_arena: &mut Arena<AExpr>,
@@ -182,6 +182,18 @@ impl FunctionIR {
         }
     }
 
+    /// Whether the output of this function is fully determined by its hash and input.
+    #[cfg(feature = "cse")]
+    pub(crate) fn is_fingerprintable(&self) -> bool {
+        use FunctionIR::*;
+        match self {
+            Opaque { .. } | Pipeline { .. } => false,
+            #[cfg(feature = "python")]
+            OpaquePython(_) => false,
+            _ => true,
+        }
+    }
+
     /// Whether this function will increase the number of rows
     pub fn expands_rows(&self) -> bool {
         use FunctionIR::*;
@@ -0,0 +1,115 @@
+use std::fmt;
+
+use polars_core::prelude::PlHashSet;
+use polars_core::schema::Schema;
+use serde_json::{Value, json};
+
+use crate::prelude::*;
+
+/// Version of the JSON plan format written by [`IRJsonDisplay`].
+///
+/// This is bumped whenever the format changes in a backwards incompatible way.
+pub const JSON_PLAN_FORMAT_VERSION: u32 = 1;
+
+/// Converts a schema to a JSON array of `{"name": .., "dtype": ..}` objects.
+pub fn schema_to_json(schema: &Schema) -> Value {
+    schema
+        .iter()
+        .map(|(name, dtype)| json!({ "name": name.as_str(), "dtype": dtype.to_string() }))
+        .collect()
+}
+
+/// Converts a list of expressions to a JSON array of strings.
+pub fn exprs_to_json(exprs: &[ExprIR], expr_arena: &Arena<AExpr>) -> Value {
+    exprs
+        .iter()
+        .map(|e| Value::String(e.display(expr_arena).to_string()))
+        .collect()
+}
+
+/// Machine-readable JSON representation of an [`IR`] plan.
+///
+/// The output is an object with the format `version`, the `root` node id and a flat list of
+/// `nodes`. Every node has an `id`, its `kind`, the ids of its `inputs`, its output `schema`, the
+/// `exprs` it evaluates, the `predicate` it applies (for scans this is the pushed down
+/// predicate), the `sources` it scans and the `estimated_rows` if known.
+pub struct IRJsonDisplay<'a> {
+    lp: IRPlanRef<'a>,
+}
+
+impl<'a> IRJsonDisplay<'a> {
+    pub fn new(lp: IRPlanRef<'a>) -> Self {
+        Self { lp }
+    }
+
+    fn node_to_json(&self, node: Node) -> Value {
+        let expr_arena = self.lp.expr_arena;
+        let ir = self.lp.lp_arena.get(node);
+
+        let kind: &'static str = ir.into();
+        let inputs = ir.get_inputs().iter().map(|i| i.0).collect::<Vec<_>>();
+
+        let (exprs, predicate) = match ir {
+            IR::Filter { predicate, .. } => (vec![], Some(predicate)),
+            IR::Scan { predicate, .. } => (vec![], predicate.as_ref()),
+            _ => (ir.get_exprs(), None),
+        };
+        let predicate = predicate.map(|p| p.display(expr_arena).to_string());
+
+        let sources = match ir {
+            IR::Scan { sources, .. } => match sources.as_paths() {
+                Some(paths) => paths
+                    .iter()
+                    .map(|p| Value::String(p.to_string_lossy().into_owned()))
+                    .collect(),
+                None => Value::String(sources.id().to_string()),
+            },
+            _ => Value::Null,
+        };
+
+        let estimated_rows = match ir {
+            IR::Scan { file_info, .. } => file_info.row_estimation.0,
+            IR::DataFrameScan { df, .. } => Some(df.height()),
+            _ => None,
+        };
+
+        json!({
+            "id": node.0,
+            "kind": kind,
+            "inputs": inputs,
+            "schema": schema_to_json(&ir.schema(self.lp.lp_arena)),
+            "exprs": exprs_to_json(&exprs, expr_arena),
+            "predicate": predicate,
+            "sources": sources,
+            "estimated_rows": estimated_rows,
+        })
+    }
+
+    pub fn to_json(&self) -> Value {
+        // Inputs can be shared (e.g. caches), every node is only written once.
+        let mut visited = PlHashSet::default();
+        let mut stack = vec![self.lp.lp_top];
+        let mut nodes = vec![];
+        while let Some(node) = stack.pop() {
+            if !visited.insert(node) {
+                continue;
+            }
+            nodes.push(self.node_to_json(node));
+
+            let inputs = self.lp.lp_arena.get(node).get_inputs();
+            stack.extend(inputs.iter().rev());
+        }
+
+        json!({
+            "version": JSON_PLAN_FORMAT_VERSION,
+            "root": self.lp.lp_top.0,
+            "nodes": nodes,
+        })
+    }
+}
+
+impl fmt::Display for IRJsonDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_json())
+    }
+}
@@ -1,6 +1,7 @@
 mod dot;
 mod format;
 mod inputs;
+mod json;
 mod schema;
 pub(crate) mod tree_format;
 
@@ -9,6 +10,7 @@ use std::fmt;
 
 pub use dot::{EscapeLabel, IRDotDisplay, PathsDisplay, ScanSourcesDisplay};
 pub use format::{ExprIRDisplay, IRDisplay};
+pub use json::{IRJsonDisplay, JSON_PLAN_FORMAT_VERSION, exprs_to_json, schema_to_json};
 use polars_core::prelude::*;
 use polars_utils::idx_vec::UnitVec;
 use polars_utils::unitvec;
@@ -204,6 +206,10 @@ impl IRPlan {
     pub fn display_dot(&self) -> dot::IRDotDisplay {
         self.as_ref().display_dot()
     }
+
+    pub fn display_json(&self) -> json::IRJsonDisplay {
+        self.as_ref().display_json()
+    }
 }
 
 impl<'a> IRPlanRef<'a> {
@@ -243,6 +249,10 @@ impl<'a> IRPlanRef<'a> {
         dot::IRDotDisplay::new(self)
     }
 
+    pub fn display_json(self) -> json::IRJsonDisplay<'a> {
+        json::IRJsonDisplay::new(self)
+    }
+
     pub fn describe(self) -> String {
         self.display().to_string()
     }
@@ -28,6 +28,10 @@ impl SimpleProjectionAndCollapse {
 }
 
 impl OptimizationRule for SimpleProjectionAndCollapse {
+    fn name(&self) -> &str {
+        "simple_projection_and_collapse"
+    }
+
     fn optimize_plan(
         &mut self,
         lp_arena: &mut Arena<IR>,
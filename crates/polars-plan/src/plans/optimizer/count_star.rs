@@ -13,6 +13,10 @@ impl CountStar {
 }
 
 impl OptimizationRule for CountStar {
+    fn name(&self) -> &str {
+        "count_star"
+    }
+
     // Replace select count(*) from datasource with specialized map function.
     fn optimize_plan(
         &mut self,
@@ -338,6 +338,11 @@ impl ExprIdentifierVisitor<'_> {
                 function: FunctionExpr::Random { .. },
                 ..
             } => REFUSE_NO_MEMBER,
+            #[cfg(feature = "dtype-uuid")]
+            AExpr::Function {
+                function: FunctionExpr::Uuid(_),
+                ..
+            } => REFUSE_NO_MEMBER,
             #[cfg(feature = "rolling_window")]
             AExpr::Function {
                 function: FunctionExpr::RollingExpr { .. },
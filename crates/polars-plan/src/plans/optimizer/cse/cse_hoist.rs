@@ -0,0 +1,358 @@
+//! Common subexpression elimination across consecutive plan nodes.
+//!
+//! [`CommonSubExprOptimizer`](super::CommonSubExprOptimizer) only deduplicates expressions
+//! within a single projection. An expensive expression that is both used in a `filter` and in
+//! the `select`/`with_columns` that follows it is therefore computed twice. This pass hoists
+//! such expressions into a new `HStack` below the lower node, so that both nodes can refer to
+//! the temporary column instead.
+//!
+//! Hoisting materializes a temporary column that must be carried through the lower node (e.g.
+//! gathered by the filter), so expressions are only hoisted if their estimated cost is at least
+//! [`MIN_HOIST_COST`]. Unlike the temporaries of a single projection, these columns are real
+//! columns of the plan, so they get their own [`CSE_HOISTED`] prefix.
+use std::hash::{BuildHasher, Hasher};
+
+use polars_utils::aliases::PlFixedStateQuality;
+use polars_utils::idx_vec::UnitVec;
+use polars_utils::{format_pl_smallstr, unitvec};
+
+use super::*;
+use crate::constants::CSE_HOISTED;
+use crate::plans::aexpr::traverse_and_hash_aexpr;
+use crate::plans::visitor::AExprArena;
+
+/// Cost of a function that does considerable work per element, e.g. regex matching or parsing.
+const EXPENSIVE: u32 = 8;
+/// Minimal estimated cost of an expression before it is hoisted.
+const MIN_HOIST_COST: u32 = EXPENSIVE;
+
+fn node_cost(ae: &AExpr) -> u32 {
+    match ae {
+        AExpr::Column(_) | AExpr::Literal(_) | AExpr::Len | AExpr::Alias(_, _) => 0,
+        #[cfg(feature = "strings")]
+        AExpr::Function {
+            function: FunctionExpr::StringExpr(function),
+            ..
+        } => string_function_cost(function),
+        AExpr::Function { .. } | AExpr::AnonymousFunction { .. } => 2,
+        _ => 1,
+    }
+}
+
+#[cfg(feature = "strings")]
+fn string_function_cost(function: &StringFunction) -> u32 {
+    use StringFunction::*;
+    match function {
+        #[cfg(feature = "regex")]
+        Contains { literal: false, .. }
+        | Find { literal: false, .. }
+        | Replace { literal: false, .. } => EXPENSIVE,
+        #[cfg(feature = "extract_groups")]
+        ExtractGroups { .. } => EXPENSIVE,
+        #[cfg(feature = "extract_jsonpath")]
+        JsonDecode { .. } | JsonPathMatch => EXPENSIVE,
+        #[cfg(feature = "temporal")]
+        Strptime(_, _) => EXPENSIVE,
+        Extract(_) | ExtractAll | CountMatches(false) => EXPENSIVE,
+        _ => 2,
+    }
+}
+
+/// Estimated cost per row of evaluating the expression at `node`.
+fn expr_cost(node: Node, expr_arena: &Arena<AExpr>) -> u32 {
+    expr_arena
+        .iter(node)
+        .map(|(_, ae)| node_cost(ae))
+        .fold(0, u32::saturating_add)
+}
+
+struct Hoister<'a> {
+    /// Columns that have a different meaning above the lower node than below it.
+    redefined: &'a PlHashSet<PlSmallStr>,
+}
+
+impl Hoister<'_> {
+    fn can_hoist(&self, node: Node, expr_arena: &Arena<AExpr>) -> bool {
+        if expr_arena.get(node).is_leaf() {
+            return false;
+        }
+        let mut has_column = false;
+        for (_, ae) in expr_arena.iter(node) {
+            if let AExpr::Column(name) = ae {
+                if self.redefined.contains(name) {
+                    return false;
+                }
+                has_column = true;
+            }
+        }
+        has_column
+            && expr_cost(node, expr_arena) >= MIN_HOIST_COST
+            && is_elementwise_rec(node, expr_arena)
+    }
+
+    /// All sub-expressions of `exprs` that could be hoisted.
+    fn candidates(&self, exprs: &[ExprIR], expr_arena: &Arena<AExpr>) -> Vec<Node> {
+        exprs
+            .iter()
+            .flat_map(|e| expr_arena.iter(e.node()).map(|(node, _)| node))
+            .filter(|node| self.can_hoist(*node, expr_arena))
+            .collect()
+    }
+
+    /// Collect the largest sub-expressions of `exprs` that are equal to one of the `candidates`.
+    fn collect_shared(
+        &self,
+        exprs: &[ExprIR],
+        candidates: &[Node],
+        expr_arena: &Arena<AExpr>,
+        shared: &mut Vec<Node>,
+    ) {
+        let is_equal =
+            |l: Node, r: Node| AExprArena::new(l, expr_arena) == AExprArena::new(r, expr_arena);
+
+        let mut stack: UnitVec<Node> = exprs.iter().map(|e| e.node()).collect();
+        while let Some(node) = stack.pop() {
+            if self.can_hoist(node, expr_arena) && candidates.iter().any(|c| is_equal(*c, node)) {
+                if !shared.iter().any(|s| is_equal(*s, node)) {
+                    shared.push(node);
+                }
+                continue;
+            }
+            expr_arena.get(node).inputs_rev(&mut stack);
+        }
+    }
+}
+
+/// Replace the `shared` sub-expressions in the expression at `node` by their temporary column.
+fn replace_shared(
+    node: Node,
+    shared: &[(Node, PlSmallStr)],
+    expr_arena: &mut Arena<AExpr>,
+) -> Node {
+    if let Some((_, name)) = shared
+        .iter()
+        .find(|(s, _)| AExprArena::new(*s, expr_arena) == AExprArena::new(node, expr_arena))
+    {
+        return expr_arena.add(AExpr::col(name.clone()));
+    }
+
+    let ae = expr_arena.get(node).clone();
+    let mut inputs = unitvec![];
+    ae.inputs_rev(&mut inputs);
+
+    let mut changed = false;
+    for input in inputs.as_mut_slice() {
+        let new = replace_shared(*input, shared, expr_arena);
+        changed |= new != *input;
+        *input = new;
+    }
+    if !changed {
+        return node;
+    }
+    inputs.as_mut_slice().reverse();
+    expr_arena.add(ae.replace_inputs(&inputs))
+}
+
+fn replace_shared_exprs(
+    exprs: &[ExprIR],
+    shared: &[(Node, PlSmallStr)],
+    expr_arena: &mut Arena<AExpr>,
+) -> Vec<ExprIR> {
+    exprs
+        .iter()
+        .map(|e| {
+            let node = replace_shared(e.node(), shared, expr_arena);
+            let mut e = e.clone();
+            if node != e.node() {
+                e.set_node(node);
+                // The left-hand rule for the output name no longer applies.
+                if !e.has_alias() {
+                    let name = e.output_name().clone();
+                    e.set_alias(name);
+                }
+            }
+            e
+        })
+        .collect()
+}
+
+/// The expressions a node evaluates on its input.
+fn node_exprs(ir: &IR) -> Option<&[ExprIR]> {
+    match ir {
+        IR::Select { expr, .. } => Some(expr),
+        IR::HStack { exprs, .. } => Some(exprs),
+        IR::Filter { predicate, .. } => Some(std::slice::from_ref(predicate)),
+        _ => None,
+    }
+}
+
+/// Hoist expensive expressions that are shared between a `Select`, `HStack` or `Filter` and its
+/// `Filter` or `HStack` input into a new `HStack` below that input.
+pub(crate) fn hoist_shared_exprs(
+    root: Node,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+) -> PolarsResult<()> {
+    // A lower node that has multiple parents cannot be rewritten for one of them.
+    let mut parents = PlHashMap::<Node, u32>::default();
+    let mut stack = vec![root];
+    while let Some(node) = stack.pop() {
+        for input in lp_arena.get(node).get_inputs_vec() {
+            let count = parents.entry(input).or_default();
+            *count += 1;
+            // Only visit shared inputs once.
+            if *count == 1 {
+                stack.push(input);
+            }
+        }
+    }
+
+    let hb = PlFixedStateQuality::with_seed(0);
+    let mut stack = vec![root];
+    while let Some(upper) = stack.pop() {
+        let hoisted_input = try_hoist(upper, &parents, &hb, lp_arena, expr_arena)?;
+        match hoisted_input {
+            Some(input) => stack.push(input),
+            None => lp_arena.get(upper).copy_inputs(&mut stack),
+        }
+    }
+    Ok(())
+}
+
+/// Returns the input below the new `HStack` if expressions were hoisted.
+fn try_hoist(
+    upper: Node,
+    parents: &PlHashMap<Node, u32>,
+    hb: &PlFixedStateQuality,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+) -> PolarsResult<Option<Node>> {
+    let upper_ir = lp_arena.get(upper);
+    let Some(upper_exprs) = node_exprs(upper_ir) else {
+        return Ok(None);
+    };
+    let lower = upper_ir.get_input().unwrap();
+    if parents.get(&lower).copied() != Some(1) {
+        return Ok(None);
+    }
+    let lower_ir = lp_arena.get(lower);
+    let (input, redefined) = match lower_ir {
+        IR::Filter { input, .. } => (*input, PlHashSet::default()),
+        IR::HStack { input, exprs, .. } => (
+            *input,
+            exprs.iter().map(|e| e.output_name().clone()).collect(),
+        ),
+        _ => return Ok(None),
+    };
+    let lower_exprs = node_exprs(lower_ir).unwrap();
+
+    let hoister = Hoister {
+        redefined: &redefined,
+    };
+    let candidates = hoister.candidates(lower_exprs, expr_arena);
+    if candidates.is_empty() {
+        return Ok(None);
+    }
+    let mut shared = vec![];
+    hoister.collect_shared(upper_exprs, &candidates, expr_arena, &mut shared);
+    if shared.is_empty() {
+        return Ok(None);
+    }
+
+    let input_schema = lp_arena.get(input).schema(lp_arena).into_owned();
+    let shared = shared
+        .into_iter()
+        .map(|node| {
+            let mut h = hb.build_hasher();
+            traverse_and_hash_aexpr(node, expr_arena, &mut h);
+            let name = format_pl_smallstr!("{}{:#x}", CSE_HOISTED, h.finish());
+            (node, name)
+        })
+        .collect::<Vec<_>>();
+    if shared
+        .iter()
+        .any(|(_, name)| input_schema.contains(name.as_str()))
+    {
+        return Ok(None);
+    }
+
+    if verbose() {
+        eprintln!(
+            "hoisting {} shared expression(s) below {}",
+            shared.len(),
+            <&'static str>::from(lp_arena.get(lower))
+        )
+    }
+
+    let hoisted = shared
+        .iter()
+        .map(|(node, name)| ExprIR::new(*node, OutputName::Alias(name.clone())))
+        .collect();
+    let new_input = IRBuilder::new(input, expr_arena, lp_arena)
+        .with_columns(hoisted, Default::default())
+        .build();
+    let new_input = lp_arena.add(new_input);
+
+    let lower_exprs = replace_shared_exprs(
+        node_exprs(lp_arena.get(lower)).unwrap(),
+        &shared,
+        expr_arena,
+    );
+    let new_lower = match lp_arena.get(lower) {
+        IR::Filter { .. } => IR::Filter {
+            input: new_input,
+            predicate: lower_exprs.into_iter().next().unwrap(),
+        },
+        IR::HStack { options, .. } => {
+            let options = *options;
+            IRBuilder::new(new_input, expr_arena, lp_arena)
+                .with_columns(lower_exprs, options)
+                .build()
+        },
+        _ => unreachable!(),
+    };
+    lp_arena.replace(lower, new_lower);
+
+    let upper_exprs = replace_shared_exprs(
+        node_exprs(lp_arena.get(upper)).unwrap(),
+        &shared,
+        expr_arena,
+    );
+    let upper_schema = lp_arena.get(upper).schema(lp_arena).into_owned();
+    let new_upper = match lp_arena.get(upper) {
+        IR::Select {
+            schema, options, ..
+        } => IR::Select {
+            input: lower,
+            expr: upper_exprs,
+            schema: schema.clone(),
+            options: *options,
+        },
+        // These pass through the temporary columns, which must be projected away.
+        IR::HStack { options, .. } => {
+            let options = *options;
+            let hstack = IRBuilder::new(lower, expr_arena, lp_arena)
+                .with_columns(upper_exprs, options)
+                .build();
+            let hstack = lp_arena.add(hstack);
+            IR::SimpleProjection {
+                input: hstack,
+                columns: upper_schema,
+            }
+        },
+        IR::Filter { .. } => {
+            let filter = lp_arena.add(IR::Filter {
+                input: lower,
+                predicate: upper_exprs.into_iter().next().unwrap(),
+            });
+            IR::SimpleProjection {
+                input: filter,
+                columns: upper_schema,
+            }
+        },
+        _ => unreachable!(),
+    };
+    lp_arena.replace(upper, new_upper);
+
+    Ok(Some(input))
+}
@@ -1,7 +1,9 @@
 mod cse_expr;
+mod cse_hoist;
 mod cse_lp;
 
 pub(super) use cse_expr::CommonSubExprOptimizer;
+pub(super) use cse_hoist::hoist_shared_exprs;
 pub(super) use cse_lp::{elim_cmn_subplans, prune_unused_caches};
 
 use super::*;
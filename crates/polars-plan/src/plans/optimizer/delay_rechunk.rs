@@ -14,6 +14,10 @@ impl DelayRechunk {
 }
 
 impl OptimizationRule for DelayRechunk {
+    fn name(&self) -> &str {
+        "delay_rechunk"
+    }
+
     fn optimize_plan(
         &mut self,
         lp_arena: &mut Arena<IR>,
@@ -15,6 +15,10 @@ fn get_union_inputs(node: Node, lp_arena: &Arena<IR>) -> Option<&[Node]> {
 }
 
 impl OptimizationRule for FlattenUnionRule {
+    fn name(&self) -> &str {
+        "flatten_union"
+    }
+
     fn optimize_plan(
         &mut self,
         lp_arena: &mut polars_utils::arena::Arena<IR>,
@@ -59,6 +59,10 @@ fn check_eligible(
 }
 
 impl OptimizationRule for FusedArithmetic {
+    fn name(&self) -> &str {
+        "fused_arithmetic"
+    }
+
     #[allow(clippy::float_cmp)]
     fn optimize_expr(
         &mut self,
@@ -24,6 +24,8 @@ mod simplify_expr;
 mod slice_pushdown_expr;
 mod slice_pushdown_lp;
 mod stack_opt;
+mod trace;
+mod user_rules;
 
 use collapse_and_project::SimpleProjectionAndCollapse;
 use delay_rechunk::DelayRechunk;
@@ -34,6 +36,8 @@ pub use projection_pushdown::ProjectionPushDown;
 pub use simplify_expr::{SimplifyBooleanRule, SimplifyExprRule};
 use slice_pushdown_lp::SlicePushDown;
 pub use stack_opt::{OptimizationRule, StackOptimizer};
+pub use trace::{OptimizerPass, OptimizerTrace};
+pub use user_rules::{OptimizationRuleFactory, OptimizationRules};
 
 use self::flatten_union::FlattenUnionRule;
 use self::set_order::set_order_flags;
@@ -60,13 +64,16 @@ pub(crate) fn init_hashmap<K, V>(max_len: Option<usize>) -> PlHashMap<K, V> {
     PlHashMap::with_capacity(std::cmp::min(max_len.unwrap_or(HASHMAP_SIZE), HASHMAP_SIZE))
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn optimize(
     logical_plan: DslPlan,
     mut opt_flags: OptFlags,
+    user_rules: &OptimizationRules,
     lp_arena: &mut Arena<IR>,
     expr_arena: &mut Arena<AExpr>,
     scratch: &mut Vec<Node>,
     expr_eval: ExprEval<'_>,
+    mut trace: Option<&mut OptimizerTrace>,
 ) -> PolarsResult<Node> {
     #[allow(dead_code)]
     let verbose = verbose();
@@ -96,7 +103,12 @@ More information on the new streaming engine: https://github.com/pola-rs/polars/
     if opt_flags.contains(OptFlags::EAGER) {
         opt_flags &= !(OptFlags::COMM_SUBEXPR_ELIM | OptFlags::COMM_SUBEXPR_ELIM);
     }
+    let start = std::time::Instant::now();
     let mut lp_top = to_alp(logical_plan, expr_arena, lp_arena, &mut opt_flags)?;
+    if let Some(trace) = trace.as_deref_mut() {
+        let after = describe_plan(lp_top, lp_arena, expr_arena);
+        trace.push("to_ir", start.elapsed(), String::new(), after);
+    }
 
     // Don't run optimizations that don't make sense on a single node.
     // This keeps eager execution more snappy.
@@ -114,6 +126,23 @@ More information on the new streaming engine: https://github.com/pola-rs/polars/
 
     let mut _opt_members = &mut None;
 
+    // Records the time taken by a pass and the plan before and after it, if tracing is enabled.
+    macro_rules! traced {
+        ($name:expr, $body:expr) => {{
+            let before = trace
+                .is_some()
+                .then(|| describe_plan(lp_top, lp_arena, expr_arena));
+            let start = std::time::Instant::now();
+            let out = $body;
+            if let (Some(trace), Some(before)) = (trace.as_deref_mut(), before) {
+                let duration = start.elapsed();
+                let after = describe_plan(lp_top, lp_arena, expr_arena);
+                trace.push($name, duration, before, after);
+            }
+            out
+        }};
+    }
+
     macro_rules! get_or_init_members {
         () => {
             _get_or_init_members(_opt_members, lp_top, lp_arena, expr_arena)
@@ -130,7 +159,10 @@ More information on the new streaming engine: https://github.com/pola-rs/polars/
     if opt_flags.contains(OptFlags::CHECK_ORDER_OBSERVE) {
         let members = get_or_init_members!();
         if members.has_group_by | members.has_sort | members.has_distinct {
-            set_order_flags(lp_top, lp_arena, expr_arena, scratch);
+            traced!(
+                "check_order_observe",
+                set_order_flags(lp_top, lp_arena, expr_arena, scratch)
+            );
         }
     }
 
@@ -150,11 +182,14 @@ More information on the new streaming engine: https://github.com/pola-rs/polars/
                 eprintln!("found multiple sources; run comm_subplan_elim")
             }
 
-            let (lp, changed, cid2c) = cse::elim_cmn_subplans(lp_top, lp_arena, expr_arena);
+            let changed = traced!("comm_subplan_elim", {
+                let (lp, changed, cid2c) = cse::elim_cmn_subplans(lp_top, lp_arena, expr_arena);
 
-            prune_unused_caches(lp_arena, cid2c);
+                prune_unused_caches(lp_arena, cid2c);
 
-            lp_top = lp;
+                lp_top = lp;
+                changed
+            });
             members.has_cache |= changed;
             changed
         } else {
@@ -169,31 +204,44 @@ More information on the new streaming engine: https://github.com/pola-rs/polars/
     // Should be run before predicate pushdown.
     if opt_flags.projection_pushdown() {
         let mut projection_pushdown_opt = ProjectionPushDown::new(opt_flags.new_streaming());
-        let alp = lp_arena.take(lp_top);
-        let alp = projection_pushdown_opt.optimize(alp, lp_arena, expr_arena)?;
-        lp_arena.replace(lp_top, alp);
+        traced!("projection_pushdown", {
+            let alp = lp_arena.take(lp_top);
+            let alp = projection_pushdown_opt.optimize(alp, lp_arena, expr_arena)?;
+            lp_arena.replace(lp_top, alp);
+        });
 
         if projection_pushdown_opt.is_count_star {
             let mut count_star_opt = CountStar::new();
-            count_star_opt.optimize_plan(lp_arena, expr_arena, lp_top)?;
+            traced!(
+                "count_star",
+                count_star_opt.optimize_plan(lp_arena, expr_arena, lp_top)?
+            );
         }
     }
 
     if opt_flags.predicate_pushdown() {
         let mut predicate_pushdown_opt =
             PredicatePushDown::new(expr_eval, opt_flags.new_streaming());
-        let alp = lp_arena.take(lp_top);
-        let alp = predicate_pushdown_opt.optimize(alp, lp_arena, expr_arena)?;
-        lp_arena.replace(lp_top, alp);
+        traced!("predicate_pushdown", {
+            let alp = lp_arena.take(lp_top);
+            let alp = predicate_pushdown_opt.optimize(alp, lp_arena, expr_arena)?;
+            lp_arena.replace(lp_top, alp);
+        });
     }
 
     if opt_flags.cluster_with_columns() {
-        cluster_with_columns::optimize(lp_top, lp_arena, expr_arena)
+        traced!(
+            "cluster_with_columns",
+            cluster_with_columns::optimize(lp_top, lp_arena, expr_arena)
+        )
     }
 
     // Make sure it is after predicate pushdown
     if opt_flags.collapse_joins() && get_or_init_members!().has_filter_with_join_input {
-        collapse_joins::optimize(lp_top, lp_arena, expr_arena);
+        traced!(
+            "collapse_joins",
+            collapse_joins::optimize(lp_top, lp_arena, expr_arena)
+        );
     }
 
     // Make sure its before slice pushdown.
@@ -210,10 +258,12 @@ More information on the new streaming engine: https://github.com/pola-rs/polars/
     if opt_flags.slice_pushdown() {
         let mut slice_pushdown_opt =
             SlicePushDown::new(opt_flags.streaming(), opt_flags.new_streaming());
-        let alp = lp_arena.take(lp_top);
-        let alp = slice_pushdown_opt.optimize(alp, lp_arena, expr_arena)?;
+        traced!("slice_pushdown", {
+            let alp = lp_arena.take(lp_top);
+            let alp = slice_pushdown_opt.optimize(alp, lp_arena, expr_arena)?;
 
-        lp_arena.replace(lp_top, alp);
+            lp_arena.replace(lp_top, alp);
+        });
 
         // Expressions use the stack optimizer.
         rules.push(Box::new(slice_pushdown_opt));
@@ -228,34 +278,54 @@ More information on the new streaming engine: https://github.com/pola-rs/polars/
         rules.push(Box::new(FlattenUnionRule {}));
     }
 
-    lp_top = opt.optimize_loop(&mut rules, expr_arena, lp_arena, lp_top)?;
+    if opt_flags.user_optimization_rules() {
+        rules.extend(user_rules.instantiate());
+    }
+
+    // Every rule is traced separately, as the rules are interleaved in a single loop.
+    lp_top = opt.optimize_loop_traced(
+        &mut rules,
+        expr_arena,
+        lp_arena,
+        lp_top,
+        trace.as_deref_mut(),
+    )?;
 
     if _cse_plan_changed
         && get_members_opt!()
             .is_some_and(|members| members.has_joins_or_unions && members.has_cache)
     {
         // We only want to run this on cse inserted caches
-        cache_states::set_cache_states(
-            lp_top,
-            lp_arena,
-            expr_arena,
-            scratch,
-            expr_eval,
-            verbose,
-            opt_flags.new_streaming(),
-        )?;
+        traced!(
+            "cache_states",
+            cache_states::set_cache_states(
+                lp_top,
+                lp_arena,
+                expr_arena,
+                scratch,
+                expr_eval,
+                verbose,
+                opt_flags.new_streaming(),
+            )?
+        );
     }
 
     // This one should run (nearly) last as this modifies the projections
     #[cfg(feature = "cse")]
     if comm_subexpr_elim && !get_or_init_members!().has_ext_context {
+        traced!("hoist_shared_exprs", {
+            cse::hoist_shared_exprs(lp_top, lp_arena, expr_arena)?;
+        });
+
         let mut optimizer = CommonSubExprOptimizer::new();
         let alp_node = IRNode::new_mutate(lp_top);
 
-        lp_top = try_with_ir_arena(lp_arena, expr_arena, |arena| {
-            let rewritten = alp_node.rewrite(&mut optimizer, arena)?;
-            Ok(rewritten.node())
-        })?;
+        traced!("comm_subexpr_elim", {
+            lp_top = try_with_ir_arena(lp_arena, expr_arena, |arena| {
+                let rewritten = alp_node.rewrite(&mut optimizer, arena)?;
+                Ok(rewritten.node())
+            })?;
+        });
     }
 
     // During debug we check if the optimizations have not modified the final schema.
@@ -275,6 +345,19 @@ More information on the new streaming engine: https://github.com/pola-rs/polars/
     Ok(lp_top)
 }
 
+pub(super) fn describe_plan(
+    lp_top: Node,
+    lp_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) -> String {
+    IRPlanRef {
+        lp_top,
+        lp_arena,
+        expr_arena,
+    }
+    .describe()
+}
+
 fn _get_or_init_members<'a>(
     opt_members: &'a mut Option<MemberCollector>,
     lp_top: Node,
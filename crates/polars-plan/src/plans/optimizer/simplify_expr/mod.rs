@@ -135,6 +135,10 @@ macro_rules! eval_binary_cmp_same_type {
 pub struct SimplifyBooleanRule {}
 
 impl OptimizationRule for SimplifyBooleanRule {
+    fn name(&self) -> &str {
+        "simplify_boolean"
+    }
+
     fn optimize_expr(
         &mut self,
         expr_arena: &mut Arena<AExpr>,
@@ -453,6 +457,10 @@ fn string_addition_to_linear_concat(
 pub struct SimplifyExprRule {}
 
 impl OptimizationRule for SimplifyExprRule {
+    fn name(&self) -> &str {
+        "simplify_expr"
+    }
+
     #[allow(clippy::float_cmp)]
     fn optimize_expr(
         &mut self,
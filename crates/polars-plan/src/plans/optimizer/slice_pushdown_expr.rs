@@ -9,6 +9,10 @@ fn pushdown(input: Node, offset: Node, length: Node, arena: &mut Arena<AExpr>) -
 }
 
 impl OptimizationRule for SlicePushDown {
+    fn name(&self) -> &str {
+        "slice_pushdown_expr"
+    }
+
     fn optimize_expr(
         &mut self,
         expr_arena: &mut Arena<AExpr>,
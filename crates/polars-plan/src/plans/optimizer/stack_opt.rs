@@ -1,5 +1,8 @@
+use std::time::{Duration, Instant};
+
 use polars_core::prelude::PolarsResult;
 
+use super::{OptimizerTrace, describe_plan};
 use crate::plans::aexpr::AExpr;
 use crate::plans::ir::IR;
 use crate::prelude::{Arena, Node};
@@ -15,6 +18,26 @@ impl StackOptimizer {
         lp_arena: &mut Arena<IR>,
         lp_top: Node,
     ) -> PolarsResult<Node> {
+        self.optimize_loop_traced(rules, expr_arena, lp_arena, lp_top, None)
+    }
+
+    /// [`optimize_loop`](Self::optimize_loop) that, if a `trace` is given, records every change
+    /// a rule makes to the plan as a pass named after the rule, with the time spent in the rule
+    /// since its previous change. The time that rules spent without changing the plan is
+    /// recorded last, as unchanged passes.
+    pub fn optimize_loop_traced(
+        &self,
+        rules: &mut [Box<dyn OptimizationRule>],
+        expr_arena: &mut Arena<AExpr>,
+        lp_arena: &mut Arena<IR>,
+        lp_top: Node,
+        trace: Option<&mut OptimizerTrace>,
+    ) -> PolarsResult<Node> {
+        let mut tracer = trace.map(|trace| RuleTracer {
+            plan: describe_plan(lp_top, lp_arena, expr_arena),
+            elapsed: vec![Duration::ZERO; rules.len()],
+            trace,
+        });
         let mut changed = true;
 
         // Nodes of expressions and lp node from which the expressions are a member of.
@@ -29,11 +52,20 @@ impl StackOptimizer {
             plans.push(lp_top);
             while let Some(current_node) = plans.pop() {
                 // Apply rules
-                for rule in rules.iter_mut() {
+                for (i, rule) in rules.iter_mut().enumerate() {
                     // keep iterating over same rule
-                    while let Some(x) = rule.optimize_plan(lp_arena, expr_arena, current_node)? {
+                    loop {
+                        let start = tracer.is_some().then(Instant::now);
+                        let out = rule.optimize_plan(lp_arena, expr_arena, current_node)?;
+                        if let Some(tracer) = &mut tracer {
+                            tracer.elapsed[i] += start.unwrap().elapsed();
+                        }
+                        let Some(x) = out else { break };
                         lp_arena.replace(current_node, x);
                         changed = true;
+                        if let Some(tracer) = &mut tracer {
+                            tracer.record(i, rule.name(), lp_top, lp_arena, expr_arena);
+                        }
                     }
                 }
 
@@ -59,16 +91,25 @@ impl StackOptimizer {
                             continue;
                         }
                     }
-                    for rule in rules.iter_mut() {
+                    for (i, rule) in rules.iter_mut().enumerate() {
                         // keep iterating over same rule
-                        while let Some(x) = rule.optimize_expr(
-                            expr_arena,
-                            current_expr_node,
-                            lp_arena,
-                            current_node,
-                        )? {
+                        loop {
+                            let start = tracer.is_some().then(Instant::now);
+                            let out = rule.optimize_expr(
+                                expr_arena,
+                                current_expr_node,
+                                lp_arena,
+                                current_node,
+                            )?;
+                            if let Some(tracer) = &mut tracer {
+                                tracer.elapsed[i] += start.unwrap().elapsed();
+                            }
+                            let Some(x) = out else { break };
                             expr_arena.replace(current_expr_node, x);
                             changed = true;
+                            if let Some(tracer) = &mut tracer {
+                                tracer.record(i, rule.name(), lp_top, lp_arena, expr_arena);
+                            }
                         }
                     }
 
@@ -78,11 +119,60 @@ impl StackOptimizer {
                 }
             }
         }
+        if let Some(tracer) = tracer {
+            tracer.finish(rules);
+        }
         Ok(lp_top)
     }
 }
 
+/// Records the changes that the rules of a [`StackOptimizer`] make to the plan.
+struct RuleTracer<'a> {
+    trace: &'a mut OptimizerTrace,
+    /// The plan after the last recorded change.
+    plan: String,
+    /// Time spent in every rule since its last recorded change.
+    elapsed: Vec<Duration>,
+}
+
+impl RuleTracer<'_> {
+    fn record(
+        &mut self,
+        rule: usize,
+        name: &str,
+        lp_top: Node,
+        lp_arena: &Arena<IR>,
+        expr_arena: &Arena<AExpr>,
+    ) {
+        let after = describe_plan(lp_top, lp_arena, expr_arena);
+        let before = std::mem::replace(&mut self.plan, after.clone());
+        let duration = std::mem::take(&mut self.elapsed[rule]);
+        self.trace.push(name, duration, before, after);
+    }
+
+    fn finish(self, rules: &[Box<dyn OptimizationRule>]) {
+        for (rule, duration) in rules.iter().zip(self.elapsed) {
+            if !duration.is_zero() {
+                let plan = self.plan.clone();
+                self.trace.push(rule.name(), duration, plan.clone(), plan);
+            }
+        }
+    }
+}
+
+/// A rewrite rule that is applied by the [`StackOptimizer`] until the plan reaches a fixed point.
+///
+/// Returning `Some` replaces the visited node and causes the plan to be visited again, so a rule
+/// must eventually return `None` for every node. User-defined rules can be added to a query
+/// with [`OptimizationRules`](super::OptimizationRules).
 pub trait OptimizationRule {
+    /// Name of the rule in an [`OptimizerTrace`].
+    fn name(&self) -> &str {
+        let name = std::any::type_name::<Self>();
+        let name = name.split('<').next().unwrap_or(name);
+        name.rsplit("::").next().unwrap_or(name)
+    }
+
     ///  Optimize (subplan) in LogicalPlan
     ///
     /// * `lp_arena` - LogicalPlan memory arena
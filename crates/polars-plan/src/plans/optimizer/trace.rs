@@ -0,0 +1,109 @@
+use std::fmt;
+use std::time::Duration;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A single optimizer pass as recorded in an [`OptimizerTrace`].
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OptimizerPass {
+    pub name: String,
+    pub duration: Duration,
+    /// The formatted plan before the pass ran.
+    pub before: String,
+    /// The formatted plan after the pass ran.
+    pub after: String,
+}
+
+impl OptimizerPass {
+    /// Whether this pass modified the plan.
+    pub fn changed(&self) -> bool {
+        self.before != self.after
+    }
+
+    /// Line based diff between the plan before and after this pass.
+    ///
+    /// Removed lines are prefixed with `-`, added lines with `+` and unchanged lines with a space.
+    pub fn diff(&self) -> Vec<String> {
+        line_diff(&self.before, &self.after)
+    }
+}
+
+/// The passes the optimizer ran on a plan, in order of execution.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OptimizerTrace {
+    pub passes: Vec<OptimizerPass>,
+}
+
+impl OptimizerTrace {
+    pub(crate) fn push(&mut self, name: &str, duration: Duration, before: String, after: String) {
+        self.passes.push(OptimizerPass {
+            name: name.to_string(),
+            duration,
+            before,
+            after,
+        })
+    }
+
+    /// Total time spent in the recorded passes.
+    pub fn total_duration(&self) -> Duration {
+        self.passes.iter().map(|p| p.duration).sum()
+    }
+}
+
+impl fmt::Display for OptimizerTrace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for pass in &self.passes {
+            let status = if pass.changed() {
+                "changed"
+            } else {
+                "unchanged"
+            };
+            writeln!(f, "== {} ({:?}, {status}) ==", pass.name, pass.duration)?;
+            if pass.changed() {
+                for line in pass.diff() {
+                    writeln!(f, "{line}")?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn line_diff(before: &str, after: &str) -> Vec<String> {
+    let a = before.lines().collect::<Vec<_>>();
+    let b = after.lines().collect::<Vec<_>>();
+
+    // Longest common subsequence table, lcs[i][j] is the LCS of a[i..] and b[j..].
+    let mut lcs = vec![vec![0u32; b.len() + 1]; a.len() + 1];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            lcs[i][j] = if a[i] == b[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut out = Vec::with_capacity(a.len().max(b.len()));
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() && j < b.len() {
+        if a[i] == b[j] {
+            out.push(format!(" {}", a[i]));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            out.push(format!("-{}", a[i]));
+            i += 1;
+        } else {
+            out.push(format!("+{}", b[j]));
+            j += 1;
+        }
+    }
+    out.extend(a[i..].iter().map(|l| format!("-{l}")));
+    out.extend(b[j..].iter().map(|l| format!("+{l}")));
+    out
+}
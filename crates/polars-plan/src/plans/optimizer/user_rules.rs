@@ -0,0 +1,102 @@
+//! User-defined [`OptimizationRule`]s.
+//!
+//! The rules of a query run in the same fixed-point loop as the built-in expression and plan
+//! rewrites if [`OptFlags::USER_OPTIMIZATION_RULES`] is set.
+use std::sync::Arc;
+
+use polars_core::prelude::PolarsResult;
+use polars_utils::arena::{Arena, Node};
+use polars_utils::pl_str::PlSmallStr;
+
+use super::OptimizationRule;
+use crate::plans::aexpr::AExpr;
+use crate::plans::ir::IR;
+
+/// Creates a new instance of a user-defined [`OptimizationRule`] for every optimized query.
+pub type OptimizationRuleFactory = Arc<dyn Fn() -> Box<dyn OptimizationRule> + Send + Sync>;
+
+/// The user-defined optimization rules of a query, in registration order.
+#[derive(Clone, Default)]
+pub struct OptimizationRules {
+    rules: Vec<(PlSmallStr, OptimizationRuleFactory)>,
+}
+
+impl OptimizationRules {
+    /// Add a rule under `name`.
+    ///
+    /// If a rule with the same name was already added, it is replaced.
+    pub fn insert(&mut self, name: &str, factory: OptimizationRuleFactory) {
+        match self.rules.iter_mut().find(|(n, _)| n == name) {
+            Some((_, f)) => *f = factory,
+            None => self.rules.push((PlSmallStr::from_str(name), factory)),
+        }
+    }
+
+    /// Remove the rule added under `name`.
+    ///
+    /// Returns `false` if no such rule was added.
+    pub fn remove(&mut self, name: &str) -> bool {
+        let len = self.rules.len();
+        self.rules.retain(|(n, _)| n != name);
+        self.rules.len() != len
+    }
+
+    /// Add the rules of `other` that are not added under the same name yet.
+    pub fn extend(&mut self, other: &OptimizationRules) {
+        for (name, factory) in &other.rules {
+            if !self.rules.iter().any(|(n, _)| n == name) {
+                self.rules.push((name.clone(), factory.clone()));
+            }
+        }
+    }
+
+    /// Names of the rules, in registration order.
+    pub fn names(&self) -> impl Iterator<Item = &PlSmallStr> {
+        self.rules.iter().map(|(n, _)| n)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    pub(super) fn instantiate(&self) -> impl Iterator<Item = Box<dyn OptimizationRule>> + '_ {
+        self.rules.iter().map(|(name, f)| {
+            Box::new(NamedRule {
+                name: name.clone(),
+                rule: f(),
+            }) as Box<dyn OptimizationRule>
+        })
+    }
+}
+
+/// A user-defined rule, named as it was added in an [`OptimizerTrace`](super::OptimizerTrace).
+struct NamedRule {
+    name: PlSmallStr,
+    rule: Box<dyn OptimizationRule>,
+}
+
+impl OptimizationRule for NamedRule {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn optimize_plan(
+        &mut self,
+        lp_arena: &mut Arena<IR>,
+        expr_arena: &mut Arena<AExpr>,
+        node: Node,
+    ) -> PolarsResult<Option<IR>> {
+        self.rule.optimize_plan(lp_arena, expr_arena, node)
+    }
+
+    fn optimize_expr(
+        &mut self,
+        expr_arena: &mut Arena<AExpr>,
+        expr_node: Node,
+        lp_arena: &Arena<IR>,
+        lp_node: Node,
+    ) -> PolarsResult<Option<AExpr>> {
+        self.rule
+            .optimize_expr(expr_arena, expr_node, lp_arena, lp_node)
+    }
+}
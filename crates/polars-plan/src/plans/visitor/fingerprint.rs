@@ -0,0 +1,163 @@
+use std::fmt::Write;
+use std::hash::{BuildHasher, Hash, Hasher};
+use std::sync::Arc;
+use std::time::SystemTime;
+
+use polars_core::prelude::*;
+use polars_utils::aliases::PlFixedStateQuality;
+use polars_utils::arena::{Arena, Node};
+
+use super::IRNode;
+use crate::prelude::*;
+
+/// Inputs of a subplan that are identified by their address rather than by their contents.
+///
+/// These must be kept alive as long as a fingerprint containing them is in use, otherwise the
+/// memory could be reused by another input and lead to a false positive match.
+#[derive(Clone)]
+pub enum PinnedInput {
+    DataFrame(Arc<DataFrame>),
+    Sources(ScanSources),
+}
+
+/// A hash of a complete subplan that identifies its result across separate queries.
+///
+/// In contrast to the identifiers used by common-subplan-elimination, this hashes the schema of
+/// every node, the full contents of literal series and the modification times of all scanned
+/// files.
+#[derive(Clone)]
+pub struct SubplanFingerprint {
+    pub hash: u64,
+    /// Canonical serialization of the subplan, i.e. all bytes that were fed into `hash`. Two
+    /// fingerprints only identify the same result if their keys are equal.
+    pub key: Vec<u8>,
+    pub pinned: Vec<PinnedInput>,
+}
+
+/// Hasher that records the bytes it is fed, so the fingerprint can be compared exactly.
+#[derive(Default)]
+struct KeyHasher {
+    key: Vec<u8>,
+}
+
+impl Hasher for KeyHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        self.key.extend_from_slice(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        PlFixedStateQuality::with_seed(0).hash_one(&self.key)
+    }
+}
+
+/// Compute the [`SubplanFingerprint`] of the plan rooted at `node`.
+///
+/// Returns `None` if the result of the subplan cannot be identified by the plan alone, e.g. it
+/// contains user-defined functions, Python scans or files of which the modification time cannot
+/// be determined.
+pub fn subplan_fingerprint(
+    node: Node,
+    lp_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) -> Option<SubplanFingerprint> {
+    let mut hasher = KeyHasher::default();
+    let mut pinned = vec![];
+    let mut stack = vec![node];
+    let mut exprs = vec![];
+    let mut dtype_buf = String::new();
+
+    while let Some(node) = stack.pop() {
+        let lp = lp_arena.get(node);
+        match lp {
+            #[cfg(feature = "python")]
+            IR::PythonScan { .. } => return None,
+            IR::MapFunction { function, .. } if !function.is_fingerprintable() => return None,
+            IR::GroupBy { apply: Some(_), .. } => return None,
+            IR::Sink { .. } | IR::SinkMultiple { .. } | IR::Invalid => return None,
+            IR::DataFrameScan { df, .. } => pinned.push(PinnedInput::DataFrame(df.clone())),
+            IR::Scan { sources, .. }
+            | IR::MapFunction {
+                function: FunctionIR::FastCount { sources, .. },
+                ..
+            } => {
+                match sources {
+                    ScanSources::Paths(_) => {},
+                    ScanSources::Files(_) | ScanSources::Buffers(_) => {
+                        pinned.push(PinnedInput::Sources(sources.clone()))
+                    },
+                }
+                sources.modification_times()?.hash(&mut hasher);
+            },
+            _ => {},
+        }
+
+        IRNode::new(node)
+            .hashable_and_cmp(lp_arena, expr_arena)
+            .hash(&mut hasher);
+
+        for (name, dtype) in lp.schema(lp_arena).iter() {
+            name.hash(&mut hasher);
+            dtype_buf.clear();
+            write!(dtype_buf, "{dtype:?}").unwrap();
+            dtype_buf.hash(&mut hasher);
+        }
+
+        exprs.clear();
+        lp.copy_exprs(&mut exprs);
+        for e in &exprs {
+            for (_, ae) in expr_arena.iter(e.node()) {
+                match ae {
+                    AExpr::AnonymousFunction { .. } => return None,
+                    // The regular hash only includes the discriminant of the dtype.
+                    AExpr::Cast { dtype, .. } => {
+                        dtype_buf.clear();
+                        write!(dtype_buf, "{dtype:?}").unwrap();
+                        dtype_buf.hash(&mut hasher);
+                    },
+                    // The regular hash only samples a few values of the series.
+                    AExpr::Literal(LiteralValue::Series(s)) => {
+                        for av in s.iter() {
+                            av.hash(&mut hasher);
+                        }
+                    },
+                    _ => {},
+                }
+            }
+        }
+
+        let n_inputs = stack.len();
+        lp.copy_inputs(&mut stack);
+        (stack.len() - n_inputs).hash(&mut hasher);
+    }
+
+    Some(SubplanFingerprint {
+        hash: hasher.finish(),
+        key: hasher.key,
+        pinned,
+    })
+}
+
+impl ScanSources {
+    /// Get the modification times of all sources.
+    ///
+    /// Returns `None` if the modification time of any of the sources cannot be determined (e.g.
+    /// cloud paths). In-memory buffers don't have a modification time and are skipped.
+    pub fn modification_times(&self) -> Option<Vec<SystemTime>> {
+        match self {
+            Self::Paths(paths) => paths
+                .iter()
+                .map(|p| {
+                    if polars_io::is_cloud_url(p) {
+                        return None;
+                    }
+                    std::fs::metadata(p).and_then(|m| m.modified()).ok()
+                })
+                .collect(),
+            Self::Files(files) => files
+                .iter()
+                .map(|f| f.metadata().and_then(|m| m.modified()).ok())
+                .collect(),
+            Self::Buffers(_) => Some(vec![]),
+        }
+    }
+}
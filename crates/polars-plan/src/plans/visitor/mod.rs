@@ -3,11 +3,15 @@
 use arrow::legacy::error::PolarsResult;
 mod expr;
 #[cfg(feature = "cse")]
+mod fingerprint;
+#[cfg(feature = "cse")]
 mod hash;
 mod lp;
 mod visitors;
 
 pub use expr::*;
+#[cfg(feature = "cse")]
+pub use fingerprint::*;
 pub use lp::*;
 pub use visitors::*;
 
@@ -71,6 +71,8 @@ pub(crate) fn any_value_into_py_object<'py>(
         AnyValue::Int64(v) => v.into_bound_py_any(py),
         AnyValue::Int128(v) => v.into_bound_py_any(py),
         AnyValue::Float32(v) => v.into_bound_py_any(py),
+        #[cfg(feature = "dtype-f16")]
+        AnyValue::Float16(v) => v.to_f32().into_bound_py_any(py),
         AnyValue::Float64(v) => v.into_bound_py_any(py),
         AnyValue::Null => py.None().into_bound_py_any(py),
         AnyValue::Boolean(v) => v.into_bound_py_any(py),
@@ -126,6 +128,30 @@ pub(crate) fn any_value_into_py_object<'py>(
         },
         AnyValue::Binary(v) => PyBytes::new(py, v).into_bound_py_any(py),
         AnyValue::BinaryOwned(v) => PyBytes::new(py, &v).into_bound_py_any(py),
+        #[cfg(feature = "dtype-interval")]
+        AnyValue::Interval(v) => {
+            let mut s = String::new();
+            polars_core::fmt::fmt_interval_string(&mut s, v).unwrap();
+            s.into_bound_py_any(py)
+        },
+        #[cfg(feature = "dtype-uuid")]
+        AnyValue::Uuid(v) => {
+            let mut s = String::new();
+            polars_core::fmt::fmt_uuid_string(&mut s, v).unwrap();
+            s.into_bound_py_any(py)
+        },
+        #[cfg(feature = "dtype-decimal256")]
+        AnyValue::Decimal256(v, scale) => {
+            let mut s = String::new();
+            polars_core::fmt::fmt_decimal256_string(&mut s, v.0, scale, false).unwrap();
+            s.into_bound_py_any(py)
+        },
+        #[cfg(feature = "dtype-variant")]
+        AnyValue::Variant(v) => polars_core::datatypes::variant_to_json(v).into_bound_py_any(py),
+        #[cfg(feature = "dtype-variant")]
+        AnyValue::VariantOwned(v) => {
+            polars_core::datatypes::variant_to_json(&v).into_bound_py_any(py)
+        },
         AnyValue::Decimal(v, scale) => {
             let convert = utils.getattr(intern!(py, "to_py_decimal"))?;
             const N: usize = 3;
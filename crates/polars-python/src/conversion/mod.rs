@@ -260,6 +260,44 @@ impl<'py> IntoPyObject<'py> for &Wrap<DataType> {
                 let inner = Wrap(*inner.clone());
                 class.call1((&inner,))
             },
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(key, value) => {
+                let class = pl.getattr(intern!(py, "Map"))?;
+                let key = Wrap(*key.clone());
+                let value = Wrap(*value.clone());
+                class.call1((&key, &value))
+            },
+            #[cfg(feature = "dtype-interval")]
+            DataType::Interval => {
+                let class = pl.getattr(intern!(py, "Interval"))?;
+                class.call0()
+            },
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => {
+                let class = pl.getattr(intern!(py, "Float16"))?;
+                class.call0()
+            },
+            #[cfg(feature = "dtype-uuid")]
+            DataType::Uuid => {
+                let class = pl.getattr(intern!(py, "Uuid"))?;
+                class.call0()
+            },
+            #[cfg(feature = "dtype-decimal256")]
+            DataType::Decimal256(precision, scale) => {
+                let class = pl.getattr(intern!(py, "Decimal256"))?;
+                let args = (*precision, *scale);
+                class.call1(args)
+            },
+            #[cfg(feature = "dtype-variant")]
+            DataType::Variant => {
+                let class = pl.getattr(intern!(py, "Variant"))?;
+                class.call0()
+            },
+            #[cfg(feature = "dtype-extension")]
+            DataType::Extension(ext) => {
+                // Extension types are defined in Rust only, Python sees their storage type.
+                Wrap(ext.storage_dtype().clone()).into_pyobject(py)
+            },
             DataType::Date => {
                 let class = pl.getattr(intern!(py, "Date"))?;
                 class.call0()
@@ -359,6 +397,8 @@ impl<'py> FromPyObject<'py> for Wrap<DataType> {
                     "UInt64" => DataType::UInt64,
                     "Float32" => DataType::Float32,
                     "Float64" => DataType::Float64,
+                    #[cfg(feature = "dtype-f16")]
+                    "Float16" => DataType::Float16,
                     "Boolean" => DataType::Boolean,
                     "String" => DataType::String,
                     "Binary" => DataType::Binary,
@@ -369,6 +409,16 @@ impl<'py> FromPyObject<'py> for Wrap<DataType> {
                     "Datetime" => DataType::Datetime(TimeUnit::Microseconds, None),
                     "Duration" => DataType::Duration(TimeUnit::Microseconds),
                     "Decimal" => DataType::Decimal(None, None), // "none" scale => "infer"
+                    #[cfg(feature = "dtype-decimal256")]
+                    "Decimal256" => DataType::Decimal256(None, None),
+                    #[cfg(feature = "dtype-interval")]
+                    "Interval" => DataType::Interval,
+                    #[cfg(feature = "dtype-uuid")]
+                    "Uuid" => DataType::Uuid,
+                    #[cfg(feature = "dtype-variant")]
+                    "Variant" => DataType::Variant,
+                    #[cfg(feature = "dtype-map")]
+                    "Map" => DataType::Map(Box::new(DataType::Null), Box::new(DataType::Null)),
                     "List" => DataType::List(Box::new(DataType::Null)),
                     "Array" => DataType::Array(Box::new(DataType::Null), 0),
                     "Struct" => DataType::Struct(vec![]),
@@ -394,6 +444,8 @@ impl<'py> FromPyObject<'py> for Wrap<DataType> {
             "UInt64" => DataType::UInt64,
             "Float32" => DataType::Float32,
             "Float64" => DataType::Float64,
+            #[cfg(feature = "dtype-f16")]
+            "Float16" => DataType::Float16,
             "Boolean" => DataType::Boolean,
             "String" => DataType::String,
             "Binary" => DataType::Binary,
@@ -428,6 +480,28 @@ impl<'py> FromPyObject<'py> for Wrap<DataType> {
                 let scale = ob.getattr(intern!(py, "scale"))?.extract()?;
                 DataType::Decimal(precision, Some(scale))
             },
+            #[cfg(feature = "dtype-decimal256")]
+            "Decimal256" => {
+                let precision = ob.getattr(intern!(py, "precision"))?.extract()?;
+                let scale = ob.getattr(intern!(py, "scale"))?.extract()?;
+                DataType::Decimal256(precision, Some(scale))
+            },
+            #[cfg(feature = "dtype-interval")]
+            "Interval" => DataType::Interval,
+            #[cfg(feature = "dtype-uuid")]
+            "Uuid" => DataType::Uuid,
+            #[cfg(feature = "dtype-variant")]
+            "Variant" => DataType::Variant,
+            #[cfg(feature = "dtype-map")]
+            "Map" => {
+                let key = ob
+                    .getattr(intern!(py, "key"))?
+                    .extract::<Wrap<DataType>>()?;
+                let value = ob
+                    .getattr(intern!(py, "value"))?
+                    .extract::<Wrap<DataType>>()?;
+                DataType::Map(Box::new(key.0), Box::new(value.0))
+            },
             "List" => {
                 let inner = ob.getattr(intern!(py, "inner")).unwrap();
                 let inner = inner.extract::<Wrap<DataType>>()?;
@@ -1120,6 +1194,16 @@ impl<'py> FromPyObject<'py> for Wrap<ClosedInterval> {
     }
 }
 
+impl<'py> FromPyObject<'py> for Wrap<RoundMode> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let mode = ob
+            .extract::<PyBackedStr>()?
+            .parse()
+            .map_err(PyPolarsErr::from)?;
+        Ok(Wrap(mode))
+    }
+}
+
 impl<'py> FromPyObject<'py> for Wrap<WindowMapping> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
         let parsed = match &*ob.extract::<PyBackedStr>()? {
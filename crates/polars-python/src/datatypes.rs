@@ -38,6 +38,18 @@ pub(crate) enum PyDataType {
     Array(usize),
     Enum(Utf8ViewArray),
     Int128,
+    #[cfg(feature = "dtype-map")]
+    Map,
+    #[cfg(feature = "dtype-interval")]
+    Interval,
+    #[cfg(feature = "dtype-uuid")]
+    Uuid,
+    #[cfg(feature = "dtype-f16")]
+    Float16,
+    #[cfg(feature = "dtype-decimal256")]
+    Decimal256(Option<usize>, usize),
+    #[cfg(feature = "dtype-variant")]
+    Variant,
 }
 
 impl From<&DataType> for PyDataType {
@@ -61,6 +73,20 @@ impl From<&DataType> for PyDataType {
             DataType::Binary => Binary,
             DataType::Array(_, width) => Array(*width),
             DataType::List(_) => List,
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(_, _) => Map,
+            #[cfg(feature = "dtype-interval")]
+            DataType::Interval => Interval,
+            #[cfg(feature = "dtype-uuid")]
+            DataType::Uuid => Uuid,
+            #[cfg(feature = "dtype-decimal256")]
+            DataType::Decimal256(p, s) => Decimal256(*p, s.expect("unexpected null decimal scale")),
+            #[cfg(feature = "dtype-variant")]
+            DataType::Variant => Variant,
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => Float16,
+            #[cfg(feature = "dtype-extension")]
+            DataType::Extension(ext) => ext.storage_dtype().into(),
             DataType::Date => Date,
             DataType::Datetime(tu, tz) => Datetime(*tu, tz.clone()),
             DataType::Duration(tu) => Duration(*tu),
@@ -113,6 +139,18 @@ impl From<PyDataType> for DataType {
             PyDataType::Decimal(p, s) => Decimal(p, Some(s)),
             PyDataType::Array(width) => Array(DataType::Null.into(), width),
             PyDataType::Int128 => Int128,
+            #[cfg(feature = "dtype-map")]
+            PyDataType::Map => Map(DataType::Null.into(), DataType::Null.into()),
+            #[cfg(feature = "dtype-interval")]
+            PyDataType::Interval => Interval,
+            #[cfg(feature = "dtype-uuid")]
+            PyDataType::Uuid => Uuid,
+            #[cfg(feature = "dtype-f16")]
+            PyDataType::Float16 => Float16,
+            #[cfg(feature = "dtype-decimal256")]
+            PyDataType::Decimal256(p, s) => Decimal256(p, Some(s)),
+            #[cfg(feature = "dtype-variant")]
+            PyDataType::Variant => Variant,
         }
     }
 }
@@ -469,8 +469,12 @@ impl PyExpr {
             .into()
     }
 
-    fn round(&self, decimals: u32) -> Self {
-        self.inner.clone().round(decimals).into()
+    #[pyo3(signature = (decimals, mode))]
+    fn round(&self, decimals: u32, mode: Option<Wrap<RoundMode>>) -> Self {
+        match mode {
+            Some(mode) => self.inner.clone().round_with_mode(decimals, mode.0).into(),
+            None => self.inner.clone().round(decimals).into(),
+        }
     }
 
     fn round_sig_figs(&self, digits: i32) -> Self {
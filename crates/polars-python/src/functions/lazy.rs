@@ -115,8 +115,8 @@ pub fn col(name: &str) -> PyExpr {
     dsl::col(name).into()
 }
 
-fn lfs_to_plans(lfs: Vec<PyLazyFrame>) -> Vec<DslPlan> {
-    lfs.into_iter().map(|lf| lf.ldf.logical_plan).collect()
+fn lfs_to_ldfs(lfs: Vec<PyLazyFrame>) -> Vec<LazyFrame> {
+    lfs.into_iter().map(|lf| lf.ldf).collect()
 }
 
 #[pyfunction]
@@ -126,16 +126,16 @@ pub fn collect_all(
     optflags: PyOptFlags,
     py: Python,
 ) -> PyResult<Vec<PyDataFrame>> {
-    let plans = lfs_to_plans(lfs);
+    let ldfs = lfs_to_ldfs(lfs);
     let dfs =
-        py.enter_polars(|| LazyFrame::collect_all_with_engine(plans, engine.0, optflags.inner))?;
+        py.enter_polars(|| LazyFrame::collect_all_with_engine(ldfs, engine.0, optflags.inner))?;
     Ok(dfs.into_iter().map(Into::into).collect())
 }
 
 #[pyfunction]
 pub fn explain_all(lfs: Vec<PyLazyFrame>, optflags: PyOptFlags, py: Python) -> PyResult<String> {
-    let plans = lfs_to_plans(lfs);
-    let explained = py.enter_polars(|| LazyFrame::explain_all(plans, optflags.inner))?;
+    let ldfs = lfs_to_ldfs(lfs);
+    let explained = py.enter_polars(|| LazyFrame::explain_all(ldfs, optflags.inner))?;
     Ok(explained)
 }
 
@@ -147,9 +147,9 @@ pub fn collect_all_with_callback(
     lambda: PyObject,
     py: Python,
 ) {
-    let plans = lfs.into_iter().map(|lf| lf.ldf.logical_plan).collect();
+    let ldfs = lfs_to_ldfs(lfs);
     let result = py
-        .enter_polars(|| LazyFrame::collect_all_with_engine(plans, engine.0, optflags.inner))
+        .enter_polars(|| LazyFrame::collect_all_with_engine(ldfs, engine.0, optflags.inner))
         .map(|dfs| {
             dfs.into_iter()
                 .map(Into::into)
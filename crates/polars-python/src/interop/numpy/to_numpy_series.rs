@@ -257,6 +257,38 @@ fn series_to_numpy_with_copy(py: Python, s: &Series, writable: bool) -> PyObject
             PyArray1::from_iter(py, values).into_py_any(py).unwrap()
         },
         List(_) => list_series_to_numpy(py, s, writable),
+        #[cfg(feature = "dtype-map")]
+        Map(_, _) => list_series_to_numpy(py, &s.to_physical_repr(), writable),
+        #[cfg(feature = "dtype-interval")]
+        Interval => {
+            let s = s.cast(&DataType::String).unwrap();
+            series_to_numpy(py, &s, writable, true).unwrap()
+        },
+        #[cfg(feature = "dtype-f16")]
+        Float16 => {
+            let s = s.cast(&DataType::Float32).unwrap();
+            series_to_numpy(py, &s, writable, true).unwrap()
+        },
+        #[cfg(feature = "dtype-uuid")]
+        Uuid => {
+            let s = s.cast(&DataType::String).unwrap();
+            series_to_numpy(py, &s, writable, true).unwrap()
+        },
+        #[cfg(feature = "dtype-decimal256")]
+        Decimal256(_, _) => {
+            let s = s.cast(&DataType::String).unwrap();
+            series_to_numpy(py, &s, writable, true).unwrap()
+        },
+        #[cfg(feature = "dtype-variant")]
+        Variant => {
+            let s = s.variant().unwrap().to_json().into_series();
+            series_to_numpy(py, &s, writable, true).unwrap()
+        },
+        #[cfg(feature = "dtype-extension")]
+        Extension(_) => {
+            let s = s.extension().unwrap().storage();
+            series_to_numpy(py, s, writable, true).unwrap()
+        },
         Array(_, _) => array_series_to_numpy(py, s, writable),
         Struct(_) => {
             let ca = s.struct_().unwrap();
@@ -302,6 +302,7 @@ impl PyLazyFrame {
         source, sources, n_rows, cache, parallel, rechunk, row_index, low_memory, cloud_options,
         credential_provider, use_statistics, hive_partitioning, schema, hive_schema,
         try_parse_hive_dates, retries, glob, include_file_paths, allow_missing_columns,
+        categorical_dictionaries=None,
     ))]
     fn new_from_parquet(
         source: Option<PyObject>,
@@ -323,6 +324,7 @@ impl PyLazyFrame {
         glob: bool,
         include_file_paths: Option<String>,
         allow_missing_columns: bool,
+        categorical_dictionaries: Option<Wrap<CategoricalDictionaries>>,
     ) -> PyResult<Self> {
         use cloud::credential_provider::PlCredentialProvider;
 
@@ -355,6 +357,7 @@ impl PyLazyFrame {
             glob,
             include_file_paths: include_file_paths.map(|x| x.into()),
             allow_missing_columns,
+            categorical_dictionaries: categorical_dictionaries.map(|x| x.0),
         };
 
         let sources = sources.0;
@@ -387,7 +390,7 @@ impl PyLazyFrame {
     #[pyo3(signature = (
         source, sources, n_rows, cache, rechunk, row_index, cloud_options,credential_provider,
         hive_partitioning, hive_schema, try_parse_hive_dates, retries, file_cache_ttl,
-        include_file_paths
+        include_file_paths, categorical_dictionaries=None,
     ))]
     fn new_from_ipc(
         source: Option<PyObject>,
@@ -404,6 +407,7 @@ impl PyLazyFrame {
         retries: usize,
         file_cache_ttl: Option<u64>,
         include_file_paths: Option<String>,
+        categorical_dictionaries: Option<Wrap<CategoricalDictionaries>>,
     ) -> PyResult<Self> {
         #[cfg(feature = "cloud")]
         use cloud::credential_provider::PlCredentialProvider;
@@ -427,6 +431,7 @@ impl PyLazyFrame {
             cloud_options: None,
             hive_options,
             include_file_paths: include_file_paths.map(|x| x.into()),
+            categorical_dictionaries: categorical_dictionaries.map(|x| x.0),
         };
 
         let sources = sources.0;
@@ -2,13 +2,16 @@ use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use polars::prelude::sync_on_close::SyncOnCloseType;
-use polars::prelude::{PartitionVariant, SinkOptions};
+use polars::prelude::{
+    CategoricalDictionaries, CategoricalDictionary, PartitionVariant, SinkOptions,
+};
 use polars_utils::IdxSize;
 use pyo3::exceptions::PyValueError;
 use pyo3::pybacked::PyBackedStr;
 use pyo3::types::{PyAnyMethods, PyDict, PyDictMethods};
 use pyo3::{Bound, FromPyObject, PyAny, PyResult, pyclass, pymethods};
 
+use crate::error::PyPolarsErr;
 use crate::expr::PyExpr;
 use crate::prelude::Wrap;
 
@@ -99,13 +102,30 @@ impl<'py> FromPyObject<'py> for Wrap<SyncOnCloseType> {
     }
 }
 
+impl<'py> FromPyObject<'py> for Wrap<CategoricalDictionaries> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        // The dictionaries are given as a mapping of column names to dictionary files.
+        let dictionaries = ob
+            .downcast::<PyDict>()?
+            .iter()
+            .map(|(name, path)| {
+                let name = name.extract::<PyBackedStr>()?;
+                let path = path.extract::<PathBuf>()?;
+                let dictionary = CategoricalDictionary::load(path).map_err(PyPolarsErr::from)?;
+                Ok(((&*name).into(), Arc::new(dictionary)))
+            })
+            .collect::<PyResult<_>>()?;
+        Ok(Wrap(dictionaries))
+    }
+}
+
 impl<'py> FromPyObject<'py> for Wrap<SinkOptions> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
         let parsed = ob.extract::<pyo3::Bound<'_, PyDict>>()?;
 
-        if parsed.len() != 3 {
+        if parsed.len() != 4 {
             return Err(PyValueError::new_err(
-                "`sink_options` must be a dictionary with the exactly 3 field.",
+                "`sink_options` must be a dictionary with the exactly 4 field.",
             ));
         }
 
@@ -125,10 +145,21 @@ impl<'py> FromPyObject<'py> for Wrap<SinkOptions> {
             .ok_or_else(|| PyValueError::new_err("`sink_options` must contain `mkdir` field"))?;
         let mkdir = mkdir.extract::<bool>()?;
 
+        let categorical_dictionaries =
+            PyDictMethods::get_item(&parsed, "categorical_dictionaries")?.ok_or_else(|| {
+                PyValueError::new_err(
+                    "`sink_options` must contain `categorical_dictionaries` field",
+                )
+            })?;
+        let categorical_dictionaries = categorical_dictionaries
+            .extract::<Option<Wrap<CategoricalDictionaries>>>()?
+            .map(|x| x.0);
+
         Ok(Wrap(SinkOptions {
             sync_on_close,
             maintain_order,
             mkdir,
+            categorical_dictionaries,
         }))
     }
 }
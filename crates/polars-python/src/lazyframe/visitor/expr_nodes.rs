@@ -736,6 +736,10 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 FunctionExpr::Bitwise(_) => {
                     return Err(PyNotImplementedError::new_err("bitwise expr"));
                 },
+                #[cfg(feature = "dtype-map")]
+                FunctionExpr::MapExpr(_) => {
+                    return Err(PyNotImplementedError::new_err("map expr"));
+                },
                 FunctionExpr::StringExpr(strfun) => match strfun {
                     StringFunction::ConcatHorizontal {
                         delimiter,
@@ -857,6 +861,10 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     StringFunction::ToDecimal(inference_length) => {
                         (PyStringFunction::ToDecimal, inference_length).into_py_any(py)
                     },
+                    #[cfg(feature = "dtype-interval")]
+                    StringFunction::ToInterval(_) => {
+                        return Err(PyNotImplementedError::new_err("to_interval"));
+                    },
                     #[cfg(feature = "nightly")]
                     StringFunction::Titlecase => (PyStringFunction::Titlecase,).into_py_any(py),
                     StringFunction::Uppercase => (PyStringFunction::Uppercase,).into_py_any(py),
@@ -1150,6 +1158,12 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 FunctionExpr::RepeatBy => ("repeat_by",).into_py_any(py),
                 FunctionExpr::ArgUnique => ("arg_unique",).into_py_any(py),
                 FunctionExpr::Repeat => ("repeat",).into_py_any(py),
+                #[cfg(feature = "dtype-uuid")]
+                FunctionExpr::Uuid(_) => {
+                    return Err(PyNotImplementedError::new_err("uuid"));
+                },
+                #[cfg(feature = "dtype-variant")]
+                FunctionExpr::VariantGet(path) => ("variant_get", path.as_str()).into_py_any(py),
                 FunctionExpr::Rank {
                     options: _,
                     seed: _,
@@ -1203,7 +1217,9 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 FunctionExpr::Log1p => ("log1p",).into_py_any(py),
                 FunctionExpr::Exp => ("exp",).into_py_any(py),
                 FunctionExpr::Unique(maintain_order) => ("unique", maintain_order).into_py_any(py),
-                FunctionExpr::Round { decimals } => ("round", decimals).into_py_any(py),
+                FunctionExpr::Round { decimals, mode } => {
+                    ("round", decimals, mode.map(Into::<&str>::into)).into_py_any(py)
+                },
                 FunctionExpr::RoundSF { digits } => ("round_sig_figs", digits).into_py_any(py),
                 FunctionExpr::Floor => ("floor",).into_py_any(py),
                 FunctionExpr::Ceil => ("ceil",).into_py_any(py),
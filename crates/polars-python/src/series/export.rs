@@ -59,6 +59,40 @@ impl PySeries {
                     }
                     v
                 },
+                #[cfg(feature = "dtype-map")]
+                DataType::Map(_, _) => {
+                    return to_list_recursive(py, &series.to_physical_repr());
+                },
+                #[cfg(feature = "dtype-interval")]
+                DataType::Interval => {
+                    let s = series.cast(&DataType::String).map_err(PyPolarsErr::from)?;
+                    return to_list_recursive(py, &s);
+                },
+                #[cfg(feature = "dtype-f16")]
+                DataType::Float16 => {
+                    let s = series.cast(&DataType::Float32).map_err(PyPolarsErr::from)?;
+                    return to_list_recursive(py, &s);
+                },
+                #[cfg(feature = "dtype-uuid")]
+                DataType::Uuid => {
+                    let s = series.cast(&DataType::String).map_err(PyPolarsErr::from)?;
+                    return to_list_recursive(py, &s);
+                },
+                #[cfg(feature = "dtype-decimal256")]
+                DataType::Decimal256(_, _) => {
+                    let s = series.cast(&DataType::String).map_err(PyPolarsErr::from)?;
+                    return to_list_recursive(py, &s);
+                },
+                #[cfg(feature = "dtype-variant")]
+                DataType::Variant => {
+                    let s = series.variant().map_err(PyPolarsErr::from)?.to_json();
+                    return to_list_recursive(py, &s.into_series());
+                },
+                #[cfg(feature = "dtype-extension")]
+                DataType::Extension(_) => {
+                    let s = series.extension().map_err(PyPolarsErr::from)?;
+                    return to_list_recursive(py, s.storage());
+                },
                 DataType::Array(_, _) => {
                     let v = PyList::empty(py);
                     let ca = series.array().map_err(PyPolarsErr::from)?;
@@ -4,11 +4,11 @@ use arrow::buffer::Buffer;
 use arrow::datatypes::ArrowDataType;
 use arrow::offset::OffsetsBuffer;
 
-use self::encode::fixed_size;
+use self::encode::{fixed_size, is_fixed_size_binary};
 use self::row::{RowEncodingCategoricalContext, RowEncodingOptions};
 use self::variable::utf8::decode_str;
 use super::*;
-use crate::fixed::{boolean, decimal, numeric, packed_u32};
+use crate::fixed::{boolean, decimal, fixed_size_binary, numeric, packed_u32};
 use crate::variable::{binary, no_order, utf8};
 
 /// Decode `rows` into a arrow format
@@ -230,6 +230,9 @@ unsafe fn decode(
             };
             StructArray::new(dtype.clone(), rows.len(), values, validity).to_boxed()
         },
+        D::FixedSizeList(_, width) if is_fixed_size_binary(dtype, dict) => {
+            fixed_size_binary::decode(rows, opt, dtype, *width).to_boxed()
+        },
         D::FixedSizeList(fsl_field, width) => {
             let validity = decode_validity(rows, opt);
 
@@ -315,6 +318,15 @@ unsafe fn decode(
                 }
             }
 
+            if matches!(dt, D::UInt16) {
+                if let Some(dict) = dict {
+                    return match dict {
+                        RowEncodingContext::Float16 => numeric::decode_f16(rows, opt).to_boxed(),
+                        _ => unreachable!(),
+                    };
+                }
+            }
+
             if matches!(dt, D::Int128) {
                 if let Some(dict) = dict {
                     return match dict {
@@ -9,7 +9,7 @@ use arrow::bitmap::Bitmap;
 use arrow::datatypes::ArrowDataType;
 use arrow::types::Offset;
 
-use crate::fixed::{boolean, decimal, numeric, packed_u32};
+use crate::fixed::{boolean, decimal, fixed_size_binary, numeric, packed_u32};
 use crate::row::{RowEncodingOptions, RowsEncoded};
 use crate::variable::{binary, no_order, utf8};
 use crate::widths::RowWidths;
@@ -259,6 +259,7 @@ fn get_encoder(
     if let Some(size) = fixed_size(dtype, dict) {
         row_widths.push_constant(size);
         let state = match dtype {
+            D::FixedSizeList(_, _) if is_fixed_size_binary(dtype, dict) => None,
             D::FixedSizeList(_, width) => {
                 let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
                 let array = array.propagate_nulls();
@@ -580,6 +581,21 @@ unsafe fn encode_flat_array(
                 }
             }
 
+            if matches!(dt, D::UInt16) {
+                if let Some(RowEncodingContext::Float16) = dict {
+                    numeric::encode_f16(
+                        buffer,
+                        array
+                            .as_any()
+                            .downcast_ref::<PrimitiveArray<u16>>()
+                            .unwrap(),
+                        opt,
+                        offsets,
+                    );
+                    return;
+                }
+            }
+
             if matches!(dt, D::Int128) {
                 if let Some(RowEncodingContext::Decimal(precision)) = dict {
                     decimal::encode(
@@ -602,6 +618,11 @@ unsafe fn encode_flat_array(
             })
         },
 
+        D::FixedSizeList(_, _) if is_fixed_size_binary(array.dtype(), dict) => {
+            let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+            fixed_size_binary::encode(buffer, array, opt, offsets);
+        },
+
         D::Binary => {
             let array = array.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
             encode_bins(buffer, array.iter(), opt, offsets);
@@ -855,6 +876,18 @@ unsafe fn encode_validity(
     }
 }
 
+/// Whether `dtype` is a `FixedSizeList` of `u8`s that is encoded as fixed-size binary.
+pub(crate) fn is_fixed_size_binary(
+    dtype: &ArrowDataType,
+    dict: Option<&RowEncodingContext>,
+) -> bool {
+    matches!(
+        (dtype, dict),
+        (ArrowDataType::FixedSizeList(f, _), Some(RowEncodingContext::FixedSizeBinary))
+            if f.dtype() == &ArrowDataType::UInt8
+    )
+}
+
 pub fn fixed_size(dtype: &ArrowDataType, dict: Option<&RowEncodingContext>) -> Option<usize> {
     use ArrowDataType as D;
     use numeric::FixedLengthEncoding;
@@ -863,7 +896,10 @@ pub fn fixed_size(dtype: &ArrowDataType, dict: Option<&RowEncodingContext>) -> O
         D::Boolean => 1,
 
         D::UInt8 => u8::ENCODED_LEN,
-        D::UInt16 => u16::ENCODED_LEN,
+        D::UInt16 => match dict {
+            None | Some(RowEncodingContext::Float16) => u16::ENCODED_LEN,
+            _ => unreachable!(),
+        },
         D::UInt32 => match dict {
             None => u32::ENCODED_LEN,
             Some(RowEncodingContext::Categorical(ctx)) => {
@@ -893,6 +929,9 @@ pub fn fixed_size(dtype: &ArrowDataType, dict: Option<&RowEncodingContext>) -> O
 
         D::Float32 => f32::ENCODED_LEN,
         D::Float64 => f64::ENCODED_LEN,
+        D::FixedSizeList(_, width) if is_fixed_size_binary(dtype, dict) => {
+            fixed_size_binary::len_from_width(*width)
+        },
         D::FixedSizeList(f, width) => 1 + width * fixed_size(f.dtype(), dict)?,
         D::Struct(fs) => match dict {
             None => {
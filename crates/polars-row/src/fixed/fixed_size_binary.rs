@@ -0,0 +1,84 @@
+#![allow(unsafe_op_in_unsafe_fn)]
+//! Row Encoding for `FixedSizeList`s of `u8`s that hold fixed-size binary values, such as UUIDs.
+//!
+//! The bytes are written as-is after the validity byte, which orders them lexicographically. This
+//! takes `1 + width` bytes per value instead of the `1 + 2 * width` bytes of the list encoding.
+
+use std::mem::MaybeUninit;
+
+use arrow::array::{Array, FixedSizeListArray, PrimitiveArray};
+use arrow::bitmap::Bitmap;
+use arrow::datatypes::ArrowDataType;
+
+use crate::row::RowEncodingOptions;
+
+pub fn len_from_width(width: usize) -> usize {
+    1 + width
+}
+
+pub unsafe fn encode(
+    buffer: &mut [MaybeUninit<u8>],
+    input: &FixedSizeListArray,
+    opt: RowEncodingOptions,
+    offsets: &mut [usize],
+) {
+    let width = input.size();
+    let values = input
+        .values()
+        .as_any()
+        .downcast_ref::<PrimitiveArray<u8>>()
+        .unwrap()
+        .values();
+    let descending = opt.contains(RowEncodingOptions::DESCENDING);
+    let null_sentinel = opt.null_sentinel();
+
+    for (i, offset) in offsets.iter_mut().enumerate() {
+        let dst = buffer.get_unchecked_mut(*offset..*offset + 1 + width);
+        if input.is_valid(i) {
+            dst[0] = MaybeUninit::new(1);
+            let src = values.get_unchecked(i * width..(i + 1) * width);
+            for (d, s) in dst[1..].iter_mut().zip(src) {
+                *d = MaybeUninit::new(if descending { !*s } else { *s });
+            }
+        } else {
+            dst[0] = MaybeUninit::new(null_sentinel);
+            dst[1..].fill(MaybeUninit::new(0));
+        }
+        *offset += 1 + width;
+    }
+}
+
+pub unsafe fn decode(
+    rows: &mut [&[u8]],
+    opt: RowEncodingOptions,
+    dtype: &ArrowDataType,
+    width: usize,
+) -> FixedSizeListArray {
+    let descending = opt.contains(RowEncodingOptions::DESCENDING);
+    let null_sentinel = opt.null_sentinel();
+
+    let mut has_nulls = false;
+    let mut values = Vec::with_capacity(rows.len() * width);
+    for row in rows.iter() {
+        has_nulls |= *row.get_unchecked(0) == null_sentinel;
+        let bytes = row.get_unchecked(1..1 + width);
+        if descending {
+            values.extend(bytes.iter().map(|b| !*b));
+        } else {
+            values.extend_from_slice(bytes);
+        }
+    }
+
+    let validity = has_nulls.then(|| {
+        rows.iter()
+            .map(|row| *row.get_unchecked(0) != null_sentinel)
+            .collect::<Bitmap>()
+    });
+
+    for row in rows.iter_mut() {
+        *row = row.get_unchecked(1 + width..);
+    }
+
+    let values = PrimitiveArray::<u8>::from_vec(values).to_boxed();
+    FixedSizeListArray::new(dtype.clone(), rows.len(), values, validity)
+}
@@ -130,6 +130,50 @@ impl FixedLengthEncoding for f64 {
     }
 }
 
+/// Map the bit pattern of a half precision float to an `i16` that orders like the float.
+///
+/// Like for the other floats, -0 and 0 and all NaNs get the same value.
+fn f16_bits_to_ordered(bits: u16) -> i16 {
+    let bits = if bits & 0x7FFF > 0x7C00 {
+        // Canonical quiet NaN.
+        0x7E00
+    } else if bits == 0x8000 {
+        0
+    } else {
+        bits
+    };
+    let s = bits as i16;
+    s ^ (((s >> 15) as u16) >> 1) as i16
+}
+
+fn ordered_to_f16_bits(s: i16) -> u16 {
+    (s ^ (((s >> 15) as u16) >> 1) as i16) as u16
+}
+
+pub unsafe fn encode_f16(
+    buffer: &mut [MaybeUninit<u8>],
+    arr: &PrimitiveArray<u16>,
+    opt: RowEncodingOptions,
+    offsets: &mut [usize],
+) {
+    encode_iter(
+        buffer,
+        arr.iter().map(|v| v.map(|v| f16_bits_to_ordered(*v))),
+        opt,
+        offsets,
+    )
+}
+
+pub unsafe fn decode_f16(rows: &mut [&[u8]], opt: RowEncodingOptions) -> PrimitiveArray<u16> {
+    let arr = decode_primitive::<i16>(rows, opt);
+    let values = arr
+        .values()
+        .iter()
+        .map(|v| ordered_to_f16_bits(*v))
+        .collect();
+    PrimitiveArray::new(ArrowDataType::UInt16, values, arr.validity().cloned())
+}
+
 pub unsafe fn encode<T: NativeType + FixedLengthEncoding>(
     buffer: &mut [MaybeUninit<u8>],
     arr: &PrimitiveArray<T>,
@@ -19,6 +19,11 @@ pub enum RowEncodingContext {
     Categorical(RowEncodingCategoricalContext),
     /// Decimal with given precision
     Decimal(usize),
+    /// Half precision float stored as its `u16` bit pattern
+    Float16,
+    /// A `FixedSizeList` of `u8`s without nulls in the list values (e.g. a UUID), encoded as
+    /// fixed-size binary.
+    FixedSizeBinary,
 }
 
 #[derive(Debug, Clone)]
@@ -1,28 +1,45 @@
+use std::borrow::Cow;
 use std::cell::RefCell;
 use std::ops::Deref;
 
+use polars_core::chunked_array::cast::CastOptions;
 use polars_core::frame::row::Row;
 use polars_core::prelude::*;
 use polars_lazy::prelude::*;
-use polars_ops::frame::JoinCoalesce;
+use polars_ops::frame::{JoinCoalesce, MaintainOrderJoin};
 use polars_plan::dsl::function_expr::StructFunction;
 use polars_plan::prelude::*;
+use polars_plan::utils::{expr_output_name, expr_to_leaf_column_names_iter};
 use polars_utils::format_pl_smallstr;
 use sqlparser::ast::{
-    BinaryOperator, CreateTable, Delete, Distinct, ExcludeSelectItem, Expr as SQLExpr, FromTable,
-    FunctionArg, GroupByExpr, Ident, JoinConstraint, JoinOperator, ObjectName, ObjectType, Offset,
-    OrderBy, Query, RenameSelectItem, Select, SelectItem, SetExpr, SetOperator, SetQuantifier,
-    Statement, TableAlias, TableFactor, TableWithJoins, UnaryOperator, Value as SQLValue, Values,
-    WildcardAdditionalOptions,
+    Assignment, AssignmentTarget, BinaryOperator, CreateTable, Delete, Distinct, ExcludeSelectItem,
+    Expr as SQLExpr, ExprWithAlias, FromTable, FunctionArg, FunctionArgExpr, FunctionArguments,
+    GroupByExpr, GroupByWithModifier, Ident, Insert, Join, JoinConstraint, JoinOperator,
+    MergeAction, MergeClauseKind, MergeInsertKind, NamedWindowDefinition, NamedWindowExpr,
+    ObjectName, ObjectType, Offset, OrderBy, PivotValueSource, Query, RenameSelectItem, Select,
+    SelectItem, SetExpr, SetOperator, SetQuantifier, ShowStatementFilter,
+    ShowStatementFilterPosition, Statement, TableAlias, TableFactor, TableWithJoins, UnaryOperator,
+    Value as SQLValue, Values, WildcardAdditionalOptions, WindowSpec, WindowType,
 };
 use sqlparser::dialect::GenericDialect;
 use sqlparser::parser::{Parser, ParserOptions};
+use sqlparser::tokenizer::{Token, Tokenizer};
 
 use crate::function_registry::{DefaultFunctionRegistry, FunctionRegistry};
+use crate::prepared::{SQLPreparedStatement, parameter_expr, typed_parameter};
 use crate::sql_expr::{
     parse_sql_array, parse_sql_expr, resolve_compound_identifier, to_sql_interface_err,
 };
 use crate::table_functions::PolarsTableFunctions;
+use crate::types::{map_sql_dtype_to_polars, polars_dtype_to_sql_type_name};
+
+/// Name of the schema holding the virtual tables that describe the registered tables.
+const INFORMATION_SCHEMA: &str = "information_schema";
+/// Name of the schema that the registered tables are reported in.
+const DEFAULT_SCHEMA: &str = "main";
+/// Name of the table with the distinct outer values of the non-equality conditions of a
+/// correlated subquery, which is also the prefix of its columns.
+const OUTER_VALUES: &str = "__POLARS_SQL_OUTER_VALUES";
 
 #[derive(Clone)]
 pub struct TableInfo {
@@ -60,19 +77,45 @@ pub struct SQLContext {
     pub(crate) lp_arena: Arena<IR>,
     pub(crate) expr_arena: Arena<AExpr>,
 
+    recursive_cte_max_iterations: usize,
     cte_map: RefCell<PlHashMap<String, LazyFrame>>,
+    /// The `information_schema` tables referenced by the current statement, by their
+    /// qualified name; these do not shadow (nor are shadowed by) tables of the same name.
+    information_schema_map: RefCell<PlHashMap<String, LazyFrame>>,
     table_aliases: RefCell<PlHashMap<String, String>>,
     joined_aliases: RefCell<PlHashMap<String, PlHashMap<String, String>>>,
+    /// The windows defined by the `WINDOW` clause of the current `SELECT`.
+    named_windows: RefCell<PlHashMap<String, WindowSpec>>,
+    /// The keys given to the `GROUPING` calls of the current `SELECT`, which are resolved once
+    /// its grouping sets are known.
+    grouping_calls: RefCell<Vec<Vec<Expr>>>,
+    /// The views created by `CREATE VIEW`, which are evaluated whenever they are referenced.
+    view_map: PlHashMap<String, SQLView>,
+    /// Whether bind parameters are allowed (when preparing a query).
+    pub(crate) allow_parameters: bool,
+}
+
+/// A view created by `CREATE VIEW`, and the names of its columns (if given).
+#[derive(Clone)]
+struct SQLView {
+    query: Query,
+    columns: Vec<PlSmallStr>,
 }
 
 impl Default for SQLContext {
     fn default() -> Self {
         Self {
-            function_registry: Arc::new(DefaultFunctionRegistry {}),
+            function_registry: Arc::new(DefaultFunctionRegistry::default()),
             table_map: Default::default(),
+            recursive_cte_max_iterations: 1000,
             cte_map: Default::default(),
+            information_schema_map: Default::default(),
             table_aliases: Default::default(),
             joined_aliases: Default::default(),
+            named_windows: Default::default(),
+            grouping_calls: Default::default(),
+            view_map: Default::default(),
+            allow_parameters: false,
             lp_arena: Default::default(),
             expr_arena: Default::default(),
         }
@@ -91,9 +134,9 @@ impl SQLContext {
         Self::default()
     }
 
-    /// Get the names of all registered tables, in sorted order.
+    /// Get the names of all registered tables and views, in sorted order.
     pub fn get_tables(&self) -> Vec<String> {
-        let mut tables = Vec::from_iter(self.table_map.keys().cloned());
+        let mut tables = Vec::from_iter(self.table_map.keys().chain(self.view_map.keys()).cloned());
         tables.sort_unstable();
         tables
     }
@@ -117,9 +160,10 @@ impl SQLContext {
         self.table_map.insert(name.to_owned(), lf);
     }
 
-    /// Unregister a [`LazyFrame`] table from the [`SQLContext`].
+    /// Unregister a [`LazyFrame`] table (or a view) from the [`SQLContext`].
     pub fn unregister(&mut self, name: &str) {
         self.table_map.remove(&name.to_owned());
+        self.view_map.remove(name);
     }
 
     /// Execute a SQL query, returning a [`LazyFrame`].
@@ -141,20 +185,8 @@ impl SQLContext {
     /// # }
     ///```
     pub fn execute(&mut self, query: &str) -> PolarsResult<LazyFrame> {
-        let mut parser = Parser::new(&GenericDialect);
-        parser = parser.with_options(ParserOptions {
-            trailing_commas: true,
-            ..Default::default()
-        });
-
-        let ast = parser
-            .try_with_sql(query)
-            .map_err(to_sql_interface_err)?
-            .parse_statements()
-            .map_err(to_sql_interface_err)?;
-
-        polars_ensure!(ast.len() == 1, SQLInterface: "one (and only one) statement can be parsed at a time");
-        let res = self.execute_statement(ast.first().unwrap())?;
+        let stmt = parse_statement(query)?;
+        let res = self.execute_statement(&stmt)?;
 
         // Ensure the result uses the proper arenas.
         // This will instantiate new arenas with a new version.
@@ -162,12 +194,61 @@ impl SQLContext {
         let expr_arena = std::mem::take(&mut self.expr_arena);
         res.set_cached_arena(lp_arena, expr_arena);
 
-        // Every execution should clear the statement-level maps.
+        self.clear_statement_maps();
+        Ok(res)
+    }
+
+    /// Prepare a SQL query with bind parameters, which can then be executed (repeatedly) with
+    /// different parameter values.
+    ///
+    /// Parameters can be positional (`$1`, `$2`, ... or `?`) or named (`:name`), and can be
+    /// used wherever the query allows an expression. The query is translated once, against
+    /// the tables as they are currently registered.
+    /// ```rust
+    /// # use polars_sql::SQLContext;
+    /// # use polars_core::prelude::*;
+    /// # use polars_lazy::prelude::*;
+    /// # fn main() {
+    ///
+    /// let mut ctx = SQLContext::new();
+    /// let df = df! {
+    ///    "a" =>  [1, 2, 3],
+    /// }
+    /// .unwrap();
+    ///
+    /// ctx.register("df", df.lazy());
+    /// let stmt = ctx.prepare("SELECT a * :factor AS a FROM df").unwrap();
+    /// let sql_df = stmt
+    ///     .execute_named(&[("factor", Scalar::from(10))])
+    ///     .unwrap()
+    ///     .collect()
+    ///     .unwrap();
+    /// assert!(sql_df.equals(&df! { "a" => [10, 20, 30] }.unwrap()));
+    /// # }
+    ///```
+    pub fn prepare(&mut self, query: &str) -> PolarsResult<SQLPreparedStatement> {
+        let stmt = parse_statement(query)?;
+        polars_ensure!(
+            matches!(stmt, Statement::Query(_)),
+            SQLInterface: "only queries can be prepared; found {}", stmt
+        );
+        self.allow_parameters = true;
+        let res = self.execute_statement(&stmt);
+        self.allow_parameters = false;
+
+        // The plan is converted again once the parameters are bound.
+        self.lp_arena = Default::default();
+        self.expr_arena = Default::default();
+        self.clear_statement_maps();
+        SQLPreparedStatement::new(res?)
+    }
+
+    /// Every execution should clear the statement-level maps.
+    fn clear_statement_maps(&self) {
         self.cte_map.borrow_mut().clear();
+        self.information_schema_map.borrow_mut().clear();
         self.table_aliases.borrow_mut().clear();
         self.joined_aliases.borrow_mut().clear();
-
-        Ok(res)
     }
 
     /// add a function registry to the SQLContext
@@ -177,6 +258,15 @@ impl SQLContext {
         self
     }
 
+    /// Set the maximum number of iterations used to evaluate a recursive CTE.
+    ///
+    /// Evaluating a recursive CTE that has not reached a fixed point after this many
+    /// iterations raises an error.
+    pub fn with_recursive_cte_max_iterations(mut self, max_iterations: usize) -> Self {
+        self.recursive_cte_max_iterations = max_iterations;
+        self
+    }
+
     /// Get the function registry of the SQLContext
     pub fn registry(&self) -> &Arc<dyn FunctionRegistry> {
         &self.function_registry
@@ -194,14 +284,22 @@ impl SQLContext {
         Ok(match ast {
             Statement::Query(query) => self.execute_query(query)?,
             stmt @ Statement::ShowTables { .. } => self.execute_show_tables(stmt)?,
+            stmt @ Statement::ShowColumns { .. } => self.execute_show_columns(stmt)?,
+            Statement::ExplainTable { table_name, .. } => {
+                self.describe_table(&table_name.to_string())?.lazy()
+            },
             stmt @ Statement::CreateTable { .. } => self.execute_create_table(stmt)?,
+            stmt @ Statement::CreateView { .. } => self.execute_create_view(stmt)?,
             stmt @ Statement::Drop {
-                object_type: ObjectType::Table,
+                object_type: ObjectType::Table | ObjectType::View,
                 ..
             } => self.execute_drop_table(stmt)?,
             stmt @ Statement::Explain { .. } => self.execute_explain(stmt)?,
             stmt @ Statement::Truncate { .. } => self.execute_truncate_table(stmt)?,
             stmt @ Statement::Delete { .. } => self.execute_delete_from_table(stmt)?,
+            stmt @ Statement::Insert { .. } => self.execute_insert(stmt)?,
+            stmt @ Statement::Update { .. } => self.execute_update(stmt)?,
+            stmt @ Statement::Merge { .. } => self.execute_merge(stmt)?,
             _ => polars_bail!(
                 SQLInterface: "statement type is not supported:\n{:?}", ast,
             ),
@@ -224,14 +322,62 @@ impl SQLContext {
 
     pub(super) fn get_table_from_current_scope(&self, name: &str) -> Option<LazyFrame> {
         let table = self.table_map.get(name).cloned();
+        let information_schema = self.information_schema_map.borrow();
         table
             .or_else(|| self.cte_map.borrow().get(name).cloned())
             .or_else(|| {
-                self.table_aliases
-                    .borrow()
-                    .get(name)
-                    .and_then(|alias| self.table_map.get(alias).cloned())
+                self.table_aliases.borrow().get(name).and_then(|alias| {
+                    self.table_map
+                        .get(alias)
+                        .cloned()
+                        .or_else(|| self.cte_map.borrow().get(alias).cloned())
+                        .or_else(|| information_schema.get(alias).cloned())
+                })
             })
+            .or_else(|| {
+                let qualified = format!("{INFORMATION_SCHEMA}.{}", name.to_lowercase());
+                information_schema.get(&qualified).cloned()
+            })
+    }
+
+    /// Evaluate the view `name` (if there is one that is not shadowed by a table or CTE) and
+    /// register the result for the rest of the current statement.
+    fn resolve_view(&mut self, name: &str) -> PolarsResult<()> {
+        if self.table_map.contains_key(name) || self.cte_map.borrow().contains_key(name) {
+            return Ok(());
+        }
+        if let Some(view) = self.view_map.get(name) {
+            let lf = self.execute_view(name, view)?;
+            self.register_cte(name, lf);
+        }
+        Ok(())
+    }
+
+    /// Evaluate the query of a view against the current tables.
+    ///
+    /// The query runs in its own context (where the view itself is not visible), so that its
+    /// aliases and CTEs do not leak into the statement that references it.
+    fn execute_view(&self, name: &str, view: &SQLView) -> PolarsResult<LazyFrame> {
+        let mut view_map = self.view_map.clone();
+        view_map.remove(name);
+        let mut ctx = SQLContext {
+            table_map: self.table_map.clone(),
+            function_registry: self.function_registry.clone(),
+            recursive_cte_max_iterations: self.recursive_cte_max_iterations,
+            view_map,
+            ..Default::default()
+        };
+        let mut lf = ctx.execute_query(&view.query)?;
+        if !view.columns.is_empty() {
+            let schema = ctx.get_frame_schema(&mut lf)?;
+            polars_ensure!(
+                schema.len() == view.columns.len(),
+                SQLSyntax: "view '{}' declares {} column names, but its query returns {} columns",
+                name, view.columns.len(), schema.len()
+            );
+            lf = lf.rename(schema.iter_names(), &view.columns, true);
+        }
+        Ok(lf)
     }
 
     fn expr_or_ordinal(
@@ -335,6 +481,7 @@ impl SQLContext {
             SetExpr::Table(tbl) => {
                 if tbl.table_name.is_some() {
                     let table_name = tbl.table_name.as_ref().unwrap();
+                    self.resolve_view(table_name)?;
                     self.get_table_from_current_scope(table_name)
                         .ok_or_else(|| {
                             polars_err!(
@@ -483,12 +630,165 @@ impl SQLContext {
         Ok(df.lazy())
     }
 
+    // SHOW COLUMNS FROM <tbl> [LIKE '<pattern>' | ILIKE '<pattern>' | WHERE <expr>]
+    fn execute_show_columns(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        let Statement::ShowColumns { show_options, .. } = stmt else {
+            polars_bail!(SQLInterface: "unexpected statement type; expected SHOW COLUMNS")
+        };
+        let Some(tbl_name) = show_options
+            .show_in
+            .as_ref()
+            .and_then(|show_in| show_in.parent_name.as_ref())
+        else {
+            polars_bail!(SQLSyntax: "SHOW COLUMNS expects a table name (eg: SHOW COLUMNS FROM tbl)")
+        };
+        let mut lf = self.describe_table(&tbl_name.to_string())?.lazy();
+        if let Some(
+            ShowStatementFilterPosition::Infix(filter)
+            | ShowStatementFilterPosition::Suffix(filter),
+        ) = &show_options.filter_position
+        {
+            let predicate = match filter {
+                ShowStatementFilter::Like(pattern) | ShowStatementFilter::NoKeyword(pattern) => {
+                    let pattern = SQLExpr::Value(SQLValue::SingleQuotedString(pattern.clone()));
+                    let like = SQLExpr::Like {
+                        negated: false,
+                        any: false,
+                        expr: Box::new(SQLExpr::Identifier(Ident::new("column_name"))),
+                        pattern: Box::new(pattern),
+                        escape_char: None,
+                    };
+                    parse_sql_expr(&like, self, None)?
+                },
+                ShowStatementFilter::ILike(pattern) => {
+                    let pattern = SQLExpr::Value(SQLValue::SingleQuotedString(pattern.clone()));
+                    let ilike = SQLExpr::ILike {
+                        negated: false,
+                        any: false,
+                        expr: Box::new(SQLExpr::Identifier(Ident::new("column_name"))),
+                        pattern: Box::new(pattern),
+                        escape_char: None,
+                    };
+                    parse_sql_expr(&ilike, self, None)?
+                },
+                ShowStatementFilter::Where(expr) => {
+                    let schema = self.get_frame_schema(&mut lf)?;
+                    parse_sql_expr(expr, self, Some(&schema))?
+                },
+            };
+            lf = lf.filter(predicate);
+        }
+        Ok(lf)
+    }
+
+    // DESCRIBE <tbl>
+    //
+    // One row per column of the table, with its name and SQL type; as the schema does not
+    // record whether a column can hold nulls, `is_nullable` is null.
+    fn describe_table(&mut self, tbl_name: &str) -> PolarsResult<DataFrame> {
+        let schema = self.get_table_schema(tbl_name)?;
+        let (names, dtypes): (Vec<_>, Vec<_>) = schema
+            .iter()
+            .map(|(name, dtype)| (name.as_str(), polars_dtype_to_sql_type_name(dtype)))
+            .unzip();
+        let n_columns = names.len();
+        DataFrame::new(vec![
+            Column::new("column_name".into(), names),
+            Column::new("data_type".into(), dtypes),
+            Column::full_null("is_nullable".into(), n_columns, &DataType::String),
+        ])
+    }
+
+    fn get_table_schema(&mut self, tbl_name: &str) -> PolarsResult<SchemaRef> {
+        self.resolve_view(tbl_name)?;
+        match self.get_table_from_current_scope(tbl_name) {
+            Some(mut lf) => self.get_frame_schema(&mut lf),
+            None => polars_bail!(SQLInterface: "relation '{}' was not found", tbl_name),
+        }
+    }
+
+    // SELECT ... FROM information_schema.tables
+    // SELECT ... FROM information_schema.columns
+    //
+    // Virtual tables describing the registered tables and views, and their columns.
+    fn get_information_schema_table(&mut self, name: &str) -> PolarsResult<LazyFrame> {
+        let tables = self.get_tables();
+        let table_type = |tbl_name: &String| {
+            if self.table_map.contains_key(tbl_name) {
+                "BASE TABLE"
+            } else {
+                "VIEW"
+            }
+        };
+        let df = match name.to_lowercase().as_str() {
+            "tables" => {
+                let table_types: Vec<_> = tables.iter().map(table_type).collect();
+                DataFrame::new(vec![
+                    Column::new("table_schema".into(), vec![DEFAULT_SCHEMA; tables.len()]),
+                    Column::new("table_name".into(), &tables),
+                    Column::new("table_type".into(), table_types),
+                ])?
+            },
+            "columns" => {
+                let mut frames = Vec::with_capacity(tables.len());
+                for tbl_name in &tables {
+                    let mut df = self.describe_table(tbl_name)?;
+                    let n_columns = df.height();
+                    let ordinal_position = Column::new(
+                        "ordinal_position".into(),
+                        (1..=n_columns as i64).collect::<Vec<_>>(),
+                    );
+                    df.insert_column(0, ordinal_position)?;
+                    df.insert_column(
+                        0,
+                        Column::new("table_name".into(), vec![tbl_name.as_str(); n_columns]),
+                    )?;
+                    df.insert_column(
+                        0,
+                        Column::new("table_schema".into(), vec![DEFAULT_SCHEMA; n_columns]),
+                    )?;
+                    frames.push(df.select([
+                        "table_schema",
+                        "table_name",
+                        "column_name",
+                        "ordinal_position",
+                        "data_type",
+                        "is_nullable",
+                    ])?);
+                }
+                match frames.into_iter().reduce(|mut acc, df| {
+                    acc.vstack_mut_owned_unchecked(df);
+                    acc
+                }) {
+                    Some(df) => df,
+                    None => DataFrame::empty_with_schema(&Schema::from_iter([
+                        Field::new("table_schema".into(), DataType::String),
+                        Field::new("table_name".into(), DataType::String),
+                        Field::new("column_name".into(), DataType::String),
+                        Field::new("ordinal_position".into(), DataType::Int64),
+                        Field::new("data_type".into(), DataType::String),
+                        Field::new("is_nullable".into(), DataType::String),
+                    ])),
+                }
+            },
+            _ => polars_bail!(SQLInterface: "relation 'information_schema.{}' was not found", name),
+        };
+        Ok(df.lazy())
+    }
+
     // DROP TABLE <tbl>
+    // DROP VIEW <view>
     fn execute_drop_table(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
         match stmt {
-            Statement::Drop { names, .. } => {
+            Statement::Drop {
+                names, object_type, ..
+            } => {
                 names.iter().for_each(|name| {
-                    self.table_map.remove(&name.to_string());
+                    if *object_type == ObjectType::View {
+                        self.view_map.remove(&name.to_string());
+                    } else {
+                        self.table_map.remove(&name.to_string());
+                    }
                 });
                 Ok(DataFrame::empty().lazy())
             },
@@ -588,30 +888,497 @@ impl SQLContext {
         }
     }
 
+    /// Get a table registered with the context, for a statement that modifies it.
+    fn get_registered_table(
+        &mut self,
+        name: &ObjectName,
+    ) -> PolarsResult<(String, LazyFrame, SchemaRef)> {
+        let tbl_name = name.0.first().unwrap().value.clone();
+        let Some(mut lf) = self.table_map.get(&tbl_name).cloned() else {
+            polars_bail!(SQLInterface: "table '{}' does not exist", tbl_name);
+        };
+        let schema = self.get_frame_schema(&mut lf)?;
+        Ok((tbl_name, lf, schema))
+    }
+
+    // INSERT INTO <tbl> [(<cols>)] VALUES ... | SELECT ...
+    fn execute_insert(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        let Statement::Insert(Insert {
+            or,
+            ignore,
+            table_name,
+            columns,
+            overwrite,
+            source,
+            partitioned,
+            after_columns,
+            on,
+            returning,
+            replace_into,
+            ..
+        }) = stmt
+        else {
+            polars_bail!(SQLInterface: "unexpected statement type; expected INSERT")
+        };
+        let error_message = match () {
+            _ if or.is_some() || *ignore || *replace_into || on.is_some() => {
+                Some("INSERT does not support conflict resolution clauses")
+            },
+            _ if partitioned.is_some() || !after_columns.is_empty() => {
+                Some("INSERT does not support the PARTITION clause")
+            },
+            _ if returning.is_some() => Some("INSERT does not support the RETURNING clause"),
+            _ => None,
+        };
+        if let Some(error_message) = error_message {
+            polars_bail!(SQLInterface: error_message);
+        }
+        let Some(source) = source else {
+            polars_bail!(SQLSyntax: "INSERT requires a VALUES clause or a query");
+        };
+        let (tbl_name, lf, schema) = self.get_registered_table(table_name)?;
+        let target_columns = insert_columns(columns, &schema)?;
+
+        let mut rows = self.execute_query(source)?;
+        let rows_schema = self.get_frame_schema(&mut rows)?;
+        polars_ensure!(
+            rows_schema.len() == target_columns.len(),
+            SQLSyntax: "INSERT expects {} values per row; found {}", target_columns.len(), rows_schema.len()
+        );
+        let values = target_columns
+            .iter()
+            .zip(rows_schema.iter_names())
+            .map(|(name, value)| (name.clone(), col(value.clone())))
+            .collect::<PlHashMap<_, _>>();
+        let rows = rows.select(insert_exprs(&schema, values));
+
+        let lf = if *overwrite {
+            rows
+        } else {
+            concat([lf, rows], UnionArgs::default())?
+        };
+        self.table_map.insert(tbl_name, lf.clone());
+        Ok(lf)
+    }
+
+    // UPDATE <tbl> SET <col> = <expr>, ... [WHERE ...]
+    fn execute_update(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        let Statement::Update {
+            table,
+            assignments,
+            from,
+            selection,
+            returning,
+            or,
+        } = stmt
+        else {
+            polars_bail!(SQLInterface: "unexpected statement type; expected UPDATE")
+        };
+        let error_message = match () {
+            _ if !table.joins.is_empty() => Some("UPDATE does not support table JOINs"),
+            _ if from.is_some() => Some("UPDATE does not support the FROM clause"),
+            _ if returning.is_some() => Some("UPDATE does not support the RETURNING clause"),
+            _ if or.is_some() => Some("UPDATE does not support conflict resolution clauses"),
+            _ => None,
+        };
+        if let Some(error_message) = error_message {
+            polars_bail!(SQLInterface: error_message);
+        }
+        let TableFactor::Table { name, .. } = &table.relation else {
+            polars_bail!(SQLInterface: "UPDATE expects a table name; found {}", table.relation);
+        };
+        let (tbl_name, _, schema) = self.get_registered_table(name)?;
+        // register the table alias (if any)
+        let (_, lf) = self.get_table(&table.relation)?;
+
+        let predicate = selection
+            .as_ref()
+            .map(|expr| parse_sql_expr(expr, self, Some(&schema)))
+            .transpose()?;
+        let exprs = self
+            .process_assignments(assignments, &schema, &schema)?
+            .into_iter()
+            .map(|(name, value)| {
+                match &predicate {
+                    Some(predicate) => when(predicate.clone())
+                        .then(value)
+                        .otherwise(col(name.clone())),
+                    None => value,
+                }
+                .alias(name)
+            })
+            .collect::<Vec<_>>();
+
+        let lf = lf.with_columns(exprs);
+        self.table_map.insert(tbl_name, lf.clone());
+        Ok(lf)
+    }
+
+    /// Resolve the `SET` assignments of an `UPDATE`, casting the values to the column types.
+    fn process_assignments(
+        &mut self,
+        assignments: &[Assignment],
+        target_schema: &Schema,
+        schema: &Schema,
+    ) -> PolarsResult<Vec<(PlSmallStr, Expr)>> {
+        let mut assigned = PlHashSet::new();
+        assignments
+            .iter()
+            .map(|assignment| {
+                let AssignmentTarget::ColumnName(target) = &assignment.target else {
+                    polars_bail!(SQLInterface: "UPDATE does not support tuple assignments");
+                };
+                let name = PlSmallStr::from_str(target.0.last().unwrap().value.as_str());
+                let Some(dtype) = target_schema.get(&name) else {
+                    polars_bail!(ColumnNotFound: "column '{}' does not exist in the target table", name);
+                };
+                polars_ensure!(
+                    assigned.insert(name.clone()),
+                    SQLSyntax: "column '{}' is assigned more than once", name
+                );
+                let value = parse_sql_expr(&assignment.value, self, Some(schema))?;
+                Ok((name, value.strict_cast(dtype.clone())))
+            })
+            .collect()
+    }
+
+    // MERGE INTO <tbl> USING <source> ON ...
+    //   WHEN MATCHED [AND ...] THEN UPDATE SET ... | DELETE
+    //   WHEN NOT MATCHED [BY TARGET] [AND ...] THEN INSERT ...
+    //   WHEN NOT MATCHED BY SOURCE [AND ...] THEN UPDATE SET ... | DELETE
+    //
+    // The target and source rows are full-joined on the condition. Each joined row is handled
+    // by the first clause that applies to it. It is an error to update or delete a target row
+    // that matches several source rows.
+    fn execute_merge(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        let Statement::Merge {
+            table,
+            source,
+            on,
+            clauses,
+            ..
+        } = stmt
+        else {
+            polars_bail!(SQLInterface: "unexpected statement type; expected MERGE")
+        };
+        let TableFactor::Table { name, .. } = table else {
+            polars_bail!(SQLInterface: "MERGE expects a target table name; found {}", table);
+        };
+        let (tbl_name, _, target_schema) = self.get_registered_table(name)?;
+
+        const TARGET_ROW: PlSmallStr = PlSmallStr::from_static("__POLARS_SQL_MERGE_TARGET_ROW");
+        const SOURCE_ROW: PlSmallStr = PlSmallStr::from_static("__POLARS_SQL_MERGE_SOURCE_ROW");
+        let mut table_info = |relation: &TableFactor, row: PlSmallStr| {
+            let (name, lf) = self.get_table(relation)?;
+            let mut frame = lf.with_row_index(row, None);
+            let schema = self.get_frame_schema(&mut frame)?;
+            PolarsResult::Ok(TableInfo {
+                frame,
+                name: name.into(),
+                schema,
+            })
+        };
+        let tbl_target = table_info(table, TARGET_ROW)?;
+        let tbl_source = table_info(source, SOURCE_ROW)?;
+        let mut joined = self.process_join(
+            &tbl_target,
+            &tbl_source,
+            &JoinConstraint::On(*on.clone()),
+            JoinType::Full,
+        )?;
+        let joined_schema = self.get_frame_schema(&mut joined)?;
+        self.register_joined_aliases(
+            &tbl_source.name,
+            &tbl_target.schema,
+            &tbl_source.schema,
+            &joined_schema,
+        );
+
+        // Determine the rows to which each clause applies.
+        let matched = col(TARGET_ROW)
+            .is_not_null()
+            .and(col(SOURCE_ROW).is_not_null());
+        let (mut taken, mut deleted, mut inserted) = (lit(false), lit(false), lit(false));
+        let mut affected = lit(false);
+        let mut updates = vec![];
+        let mut inserts = vec![];
+        for clause in clauses {
+            let mut applies = match clause.clause_kind {
+                MergeClauseKind::Matched => matched.clone(),
+                MergeClauseKind::NotMatched | MergeClauseKind::NotMatchedByTarget => {
+                    col(TARGET_ROW).is_null()
+                },
+                MergeClauseKind::NotMatchedBySource => col(SOURCE_ROW).is_null(),
+            };
+            if let Some(predicate) = &clause.predicate {
+                let predicate = parse_sql_expr(predicate, self, Some(&joined_schema))?;
+                applies = applies.and(predicate.fill_null(lit(false)));
+            }
+            if clause.clause_kind == MergeClauseKind::Matched {
+                affected = affected.or(applies.clone());
+            }
+            let is_insert = matches!(
+                clause.clause_kind,
+                MergeClauseKind::NotMatched | MergeClauseKind::NotMatchedByTarget
+            );
+            match (&clause.action, is_insert) {
+                (MergeAction::Update { assignments }, false) => {
+                    let values =
+                        self.process_assignments(assignments, &target_schema, &joined_schema)?;
+                    updates.push((applies.clone().and(taken.clone().not()), values));
+                },
+                (MergeAction::Delete, false) => {
+                    deleted = deleted.or(applies.clone().and(taken.clone().not()));
+                },
+                (MergeAction::Insert(insert), true) => {
+                    let values = match &insert.kind {
+                        MergeInsertKind::Values(Values { rows, .. }) if rows.len() == 1 => &rows[0],
+                        _ => polars_bail!(
+                            SQLInterface: "MERGE INSERT expects a single row of VALUES; found {}", insert
+                        ),
+                    };
+                    let target_columns = insert_columns(&insert.columns, &target_schema)?;
+                    polars_ensure!(
+                        values.len() == target_columns.len(),
+                        SQLSyntax: "MERGE INSERT expects {} values; found {}", target_columns.len(), values.len()
+                    );
+                    let values = target_columns
+                        .into_iter()
+                        .zip(values)
+                        .map(|(name, value)| {
+                            Ok((name, parse_sql_expr(value, self, Some(&joined_schema))?))
+                        })
+                        .collect::<PolarsResult<PlHashMap<_, _>>>()?;
+                    let applies = applies.clone().and(taken.clone().not());
+                    inserted = inserted.or(applies.clone());
+                    inserts.push((applies, values));
+                },
+                (action, _) => polars_bail!(
+                    SQLSyntax: "'{}' is not valid in a 'WHEN {}' clause", action, clause.clause_kind
+                ),
+            }
+            taken = taken.or(applies);
+        }
+
+        // A target row must not be updated or deleted by more than one source row; this is
+        // checked on the joined rows when the query is collected.
+        const AFFECTED: PlSmallStr = PlSmallStr::from_static("__POLARS_SQL_MERGE_AFFECTED");
+        let target_name = tbl_name.clone();
+        let check_affected_rows = move |df: DataFrame| {
+            let rows = df
+                .column(&TARGET_ROW)?
+                .filter(df.column(&AFFECTED)?.bool()?)?;
+            polars_ensure!(
+                rows.n_unique()? == rows.len(),
+                SQLInterface: "MERGE cannot update or delete a target row more than once; a row of '{}' matches several source rows", target_name
+            );
+            Ok(df)
+        };
+        let joined = joined.with_column(affected.alias(AFFECTED)).map(
+            check_affected_rows,
+            AllowedOptimizations::empty(),
+            None,
+            Some("MERGE CHECK"),
+        );
+
+        // Update or delete the target rows (in their original order)...
+        let updated_exprs = target_schema
+            .iter()
+            .map(|(name, dtype)| {
+                updates
+                    .iter()
+                    .filter_map(|(applies, values)| {
+                        let (_, value) = values.iter().find(|(target, _)| target == name)?;
+                        Some((applies, value))
+                    })
+                    .fold(col(name.clone()), |expr, (applies, value)| {
+                        when(applies.clone()).then(value.clone()).otherwise(expr)
+                    })
+                    .strict_cast(dtype.clone())
+                    .alias(name.clone())
+            })
+            .collect::<Vec<_>>();
+        let mut lf = joined
+            .clone()
+            .filter(col(TARGET_ROW).is_not_null())
+            // Handle every target row by the source row that affects it, if any.
+            .sort_by_exprs(
+                [col(TARGET_ROW), col(AFFECTED).not()],
+                SortMultipleOptions::default().with_maintain_order(true),
+            )
+            .unique_stable(Some(vec![TARGET_ROW]), UniqueKeepStrategy::First)
+            .filter(deleted.not())
+            .select(updated_exprs);
+
+        // ...and append the inserted source rows. In those rows, the unqualified columns that
+        // both tables have refer to the source columns.
+        if !inserts.is_empty() {
+            let source_exprs = tbl_source
+                .schema
+                .iter_names()
+                .filter(|name| tbl_target.schema.contains(name))
+                .map(|name| {
+                    col(format_pl_smallstr!("{}:{}", name, tbl_source.name)).alias(name.clone())
+                })
+                .collect::<Vec<_>>();
+            let inserted_exprs = target_schema
+                .iter()
+                .map(|(name, dtype)| {
+                    inserts
+                        .iter()
+                        .filter_map(|(applies, values)| Some((applies, values.get(name)?)))
+                        .fold(lit(NULL), |expr, (applies, value)| {
+                            when(applies.clone()).then(value.clone()).otherwise(expr)
+                        })
+                        .strict_cast(dtype.clone())
+                        .alias(name.clone())
+                })
+                .collect::<Vec<_>>();
+            let rows = joined
+                .with_columns(source_exprs)
+                .filter(inserted)
+                .sort([SOURCE_ROW], SortMultipleOptions::default())
+                .select(inserted_exprs);
+            lf = concat([lf, rows], UnionArgs::default())?;
+        }
+        self.table_map.insert(tbl_name, lf.clone());
+        Ok(lf)
+    }
+
     fn register_cte(&mut self, name: &str, lf: LazyFrame) {
         self.cte_map.borrow_mut().insert(name.to_owned(), lf);
     }
 
     fn register_ctes(&mut self, query: &Query) -> PolarsResult<()> {
         if let Some(with) = &query.with {
-            if with.recursive {
-                polars_bail!(SQLInterface: "recursive CTEs are not supported")
-            }
             for cte in &with.cte_tables {
                 let cte_name = cte.alias.name.value.clone();
-                let mut lf = self.execute_query(&cte.query)?;
-                lf = self.rename_columns_from_table_alias(lf, &cte.alias)?;
+                let lf = if with.recursive && query_references_table(&cte.query, &cte_name) {
+                    self.execute_recursive_cte(&cte_name, &cte.alias, &cte.query)?
+                } else {
+                    let lf = self.execute_query(&cte.query)?;
+                    self.rename_columns_from_table_alias(lf, &cte.alias)?
+                };
                 self.register_cte(&cte_name, lf);
             }
         }
         Ok(())
     }
 
+    /// Evaluate a recursive CTE of the form `<anchor> UNION [ALL] <recursive term>` by
+    /// iterating the recursive term on the rows produced by the previous iteration, until no
+    /// new rows are produced.
+    fn execute_recursive_cte(
+        &mut self,
+        cte_name: &str,
+        alias: &TableAlias,
+        query: &Query,
+    ) -> PolarsResult<LazyFrame> {
+        let SetExpr::SetOperation {
+            op: SetOperator::Union,
+            set_quantifier,
+            left,
+            right,
+        } = query.body.as_ref()
+        else {
+            polars_bail!(SQLInterface: "recursive CTE '{}' must be of the form '<anchor> UNION [ALL] <recursive term>'", cte_name)
+        };
+        let distinct = match set_quantifier {
+            SetQuantifier::All => false,
+            SetQuantifier::Distinct | SetQuantifier::None => true,
+            _ => {
+                polars_bail!(SQLInterface: "'UNION {}' is not supported in recursive CTE '{}'", set_quantifier, cte_name)
+            },
+        };
+        polars_ensure!(
+            !set_expr_references_table(left, cte_name),
+            SQLInterface: "the anchor term of recursive CTE '{}' cannot reference itself", cte_name
+        );
+        polars_ensure!(
+            query.order_by.is_none(),
+            SQLInterface: "ORDER BY is not supported in recursive CTE '{}'", cte_name
+        );
+        self.register_ctes(query)?;
+
+        let anchor = self.process_query(left, query)?;
+        let anchor = self.rename_columns_from_table_alias(anchor, alias)?;
+        let mut result = if distinct {
+            anchor.unique_stable(None, UniqueKeepStrategy::First)
+        } else {
+            anchor
+        }
+        .collect()?;
+        let schema = result.schema().clone();
+        let mut working = result.clone();
+
+        let mut iterations = 0;
+        while working.height() > 0 {
+            polars_ensure!(
+                iterations < self.recursive_cte_max_iterations,
+                SQLInterface: "recursive CTE '{}' did not terminate within {} iterations",
+                cte_name, self.recursive_cte_max_iterations
+            );
+            iterations += 1;
+
+            self.register_cte(cte_name, working.lazy());
+            let mut step = self.process_query(right, query)?;
+            let step_schema = self.get_frame_schema(&mut step)?;
+            polars_ensure!(
+                step_schema.len() == schema.len(),
+                SQLInterface: "recursive term of CTE '{}' returns {} columns, but the anchor returns {}",
+                cte_name, step_schema.len(), schema.len()
+            );
+            // The recursive term takes the column names and types of the anchor.
+            let step = step.select(
+                step_schema
+                    .iter_names()
+                    .zip(schema.iter())
+                    .map(|(name, (anchor_name, dtype))| {
+                        col(name.clone())
+                            .cast(dtype.clone())
+                            .alias(anchor_name.clone())
+                    })
+                    .collect::<Vec<_>>(),
+            );
+
+            if distinct {
+                // Only keep the rows that were not produced before.
+                let n_rows = result.height();
+                result = concat([result.lazy(), step], UnionArgs::default())?
+                    .unique_stable(None, UniqueKeepStrategy::First)
+                    .collect()?;
+                working = result.slice(n_rows as i64, usize::MAX);
+            } else {
+                working = step.collect()?;
+                result.vstack_mut(&working)?;
+            }
+        }
+
+        let lf = result.lazy();
+        self.process_limit_offset(lf, &query.limit, &query.offset)
+    }
+
     /// execute the 'FROM' part of the query
     fn execute_from_statement(&mut self, tbl_expr: &TableWithJoins) -> PolarsResult<LazyFrame> {
         let (l_name, mut lf) = self.get_table(&tbl_expr.relation)?;
         if !tbl_expr.joins.is_empty() {
             for join in &tbl_expr.joins {
+                if let TableFactor::Derived {
+                    lateral: true,
+                    subquery,
+                    alias,
+                } = &join.relation
+                {
+                    if let Some(joined) = self.execute_lateral_join(
+                        lf.clone(),
+                        subquery,
+                        alias.as_ref(),
+                        &join.join_operator,
+                    )? {
+                        lf = joined;
+                        continue;
+                    }
+                }
                 let (r_name, mut rf) = self.get_table(&join.relation)?;
                 if r_name.is_empty() {
                     // Require non-empty to avoid duplicate column errors from nested self-joins.
@@ -634,21 +1401,25 @@ impl SQLContext {
                     | JoinOperator::LeftSemi(constraint)
                     | JoinOperator::RightAnti(constraint)
                     | JoinOperator::RightSemi(constraint)) => {
-                        let (lf, rf) = match op {
-                            JoinOperator::RightAnti(_) | JoinOperator::RightSemi(_) => (rf, lf),
-                            _ => (lf, rf),
+                        let tbl_left = TableInfo {
+                            frame: lf,
+                            name: (&l_name).into(),
+                            schema: left_schema.clone(),
                         };
-                        self.process_join(
-                            &TableInfo {
-                                frame: lf,
-                                name: (&l_name).into(),
-                                schema: left_schema.clone(),
-                            },
-                            &TableInfo {
-                                frame: rf,
-                                name: (&r_name).into(),
-                                schema: right_schema.clone(),
+                        let tbl_right = TableInfo {
+                            frame: rf,
+                            name: (&r_name).into(),
+                            schema: right_schema.clone(),
+                        };
+                        let (tbl_left, tbl_right) = match op {
+                            JoinOperator::RightAnti(_) | JoinOperator::RightSemi(_) => {
+                                (tbl_right, tbl_left)
                             },
+                            _ => (tbl_left, tbl_right),
+                        };
+                        self.process_join(
+                            &tbl_left,
+                            &tbl_right,
                             constraint,
                             match op {
                                 JoinOperator::FullOuter(_) => JoinType::Full,
@@ -681,91 +1452,328 @@ impl SQLContext {
 
                 // track join-aliased columns so we can resolve them later
                 let joined_schema = self.get_frame_schema(&mut lf)?;
-
-                self.joined_aliases.borrow_mut().insert(
-                    r_name.clone(),
-                    right_schema
-                        .iter_names()
-                        .filter_map(|name| {
-                            // col exists in both tables and is aliased in the joined result
-                            let aliased_name = format!("{}:{}", name, r_name);
-                            if left_schema.contains(name)
-                                && joined_schema.contains(aliased_name.as_str())
-                            {
-                                Some((name.to_string(), aliased_name))
-                            } else {
-                                None
-                            }
-                        })
-                        .collect::<PlHashMap<String, String>>(),
-                );
+                self.register_joined_aliases(&r_name, &left_schema, &right_schema, &joined_schema);
             }
         };
         Ok(lf)
     }
 
-    /// Execute the 'SELECT' part of the query.
-    fn execute_select(&mut self, select_stmt: &Select, query: &Query) -> PolarsResult<LazyFrame> {
-        let mut lf = if select_stmt.from.is_empty() {
-            DataFrame::empty().lazy()
-        } else {
-            // Note: implicit joins need more work to support properly,
-            // explicit joins are preferred for now (ref: #16662)
-            let from = select_stmt.clone().from;
-            if from.len() > 1 {
-                polars_bail!(SQLInterface: "multiple tables in FROM clause are not currently supported (found {}); use explicit JOIN syntax instead", from.len())
-            }
-            self.execute_from_statement(from.first().unwrap())?
-        };
-
-        // Filter expression (WHERE clause)
-        let schema = self.get_frame_schema(&mut lf)?;
-        lf = self.process_where(lf, &select_stmt.selection, false)?;
+    fn register_joined_aliases(
+        &self,
+        r_name: &str,
+        left_schema: &Schema,
+        right_schema: &Schema,
+        joined_schema: &Schema,
+    ) {
+        self.joined_aliases.borrow_mut().insert(
+            r_name.to_string(),
+            right_schema
+                .iter_names()
+                .filter_map(|name| {
+                    // col exists in both tables and is aliased in the joined result
+                    let aliased_name = format!("{}:{}", name, r_name);
+                    if left_schema.contains(name) && joined_schema.contains(aliased_name.as_str()) {
+                        Some((name.to_string(), aliased_name))
+                    } else {
+                        None
+                    }
+                })
+                .collect::<PlHashMap<String, String>>(),
+        );
+    }
 
-        // 'SELECT *' modifiers
-        let mut select_modifiers = SelectModifiers {
-            ilike: None,
-            exclude: PlHashSet::new(),
-            rename: PlHashMap::new(),
-            replace: vec![],
+    /// Join a `LATERAL` derived table that refers to columns of the preceding tables onto `lf`.
+    ///
+    /// The table is decorrelated into a join on its equality conditions with the outer columns,
+    /// applying its `LIMIT` and `OFFSET` per group of join keys. Returns `None` if the table is
+    /// not correlated.
+    fn execute_lateral_join(
+        &mut self,
+        mut lf: LazyFrame,
+        subquery: &Query,
+        alias: Option<&TableAlias>,
+        join_operator: &JoinOperator,
+    ) -> PolarsResult<Option<LazyFrame>> {
+        let Some(alias) = alias else {
+            polars_bail!(SQLSyntax: "derived tables must have aliases");
+        };
+        let left_schema = self.get_frame_schema(&mut lf)?;
+        let Some(mut correlated) = self.split_correlated_subquery(subquery, &left_schema)? else {
+            return Ok(None);
+        };
+        let (mut join_type, condition) = match join_operator {
+            JoinOperator::CrossJoin => (JoinType::Inner, None),
+            JoinOperator::Inner(constraint) | JoinOperator::LeftOuter(constraint) => {
+                let condition = match constraint {
+                    JoinConstraint::None
+                    | JoinConstraint::On(SQLExpr::Value(SQLValue::Boolean(true))) => None,
+                    JoinConstraint::On(expr) if matches!(join_operator, JoinOperator::Inner(_)) => {
+                        Some(expr)
+                    },
+                    _ => polars_bail!(
+                        SQLInterface:
+                        "LATERAL joins only support ON conditions for INNER JOIN, and ON TRUE for LEFT JOIN"
+                    ),
+                };
+                let join_type = match join_operator {
+                    JoinOperator::Inner(_) => JoinType::Inner,
+                    _ => JoinType::Left,
+                };
+                (join_type, condition)
+            },
+            join_type => {
+                polars_bail!(SQLInterface: "join type '{:?}' not currently supported for LATERAL tables", join_type)
+            },
         };
+        let limit = correlated.query.limit.take();
+        let offset = correlated.query.offset.take();
+        polars_ensure!(
+            correlated.query.fetch.is_none(),
+            SQLInterface: "FETCH is not supported in LATERAL tables"
+        );
+        // Which of the projected columns are counts, that are zero rather than null for an
+        // outer row without matches.
+        let mut is_count = vec![];
+        if correlated.is_aggregate {
+            // An aggregation returns a row for every outer row, even if it has no matches.
+            correlated.group_by_inner_keys()?;
+            join_type = JoinType::Left;
+            is_count = correlated
+                .select
+                .projection
+                .iter()
+                .map(|item| match item {
+                    SelectItem::UnnamedExpr(SQLExpr::Function(f))
+                    | SelectItem::ExprWithAlias {
+                        expr: SQLExpr::Function(f),
+                        ..
+                    } => f.name.to_string().eq_ignore_ascii_case("count"),
+                    _ => false,
+                })
+                .collect();
+        }
 
-        let projections = self.column_projections(select_stmt, &schema, &mut select_modifiers)?;
+        let name = format_pl_smallstr!("__POLARS_SQL_LATERAL_{}", alias.name.value);
+        let mut rf =
+            self.execute_correlated_subquery(correlated.clone(), &name, &lf, &left_schema)?;
+        let (left_on, right_on) = self.correlated_join_keys(&correlated, &name, &left_schema)?;
+        if limit.is_some() || offset.is_some() {
+            let to_idx = |e: &SQLExpr, clause: &str| match e {
+                SQLExpr::Value(SQLValue::Number(n, _)) => n
+                    .parse::<IdxSize>()
+                    .map_err(|e| polars_err!(SQLInterface: "{} conversion error: {}", clause, e)),
+                _ => polars_bail!(
+                    SQLSyntax: "non-numeric arguments for LIMIT/OFFSET are not supported"
+                ),
+            };
+            let offset = match &offset {
+                Some(offset) => to_idx(&offset.value, "OFFSET")?,
+                None => 0,
+            };
+            // The (1-based) position of the rows within their group, in the order of the query.
+            let position = right_on[0].clone().cum_count(false).over(right_on.clone());
+            let mut keep = position.clone().gt(lit(offset));
+            if let Some(limit) = &limit {
+                keep = keep.and(position.lt_eq(lit(offset + to_idx(limit, "LIMIT")?)));
+            }
+            rf = rf.filter(keep);
+        }
 
-        // Check for "GROUP BY ..." (after determining projections)
-        let mut group_by_keys: Vec<Expr> = Vec::new();
-        match &select_stmt.group_by {
-            // Standard "GROUP BY x, y, z" syntax (also recognising ordinal values)
+        let rf_schema = self.get_frame_schema(&mut rf)?;
+        let mut columns = rf_schema
+            .iter_names()
+            .skip(right_on.len())
+            .cloned()
+            .collect::<Vec<_>>();
+        if !alias.columns.is_empty() {
+            polars_ensure!(
+                alias.columns.len() == columns.len(),
+                SQLSyntax: "number of columns ({}) in alias '{}' does not match the number of columns in the table/query ({})",
+                alias.columns.len(), alias.name.value, columns.len()
+            );
+            let new_columns = alias
+                .columns
+                .iter()
+                .map(|c| PlSmallStr::from_str(&c.name.value))
+                .collect::<Vec<_>>();
+            rf = rf.rename(columns, new_columns.clone(), true);
+            columns = new_columns;
+        }
+        let mut table = rf
+            .clone()
+            .select(columns.iter().cloned().map(col).collect::<Vec<_>>());
+        let right_schema = self.get_frame_schema(&mut table)?;
+        self.table_map.insert(alias.name.value.clone(), table);
+
+        let mut joined = lf
+            .join_builder()
+            .with(rf)
+            .left_on(left_on)
+            .right_on(right_on)
+            .how(join_type)
+            .suffix(format_pl_smallstr!(":{}", alias.name.value))
+            .finish();
+        let joined_schema = self.get_frame_schema(&mut joined)?;
+        // Drop the inner join keys (if they were not coalesced).
+        let mut lf = joined.select(
+            joined_schema
+                .iter_names()
+                .filter(|column| !column.starts_with(name.as_str()))
+                .map(|column| col(column.clone()))
+                .collect::<Vec<_>>(),
+        );
+        let joined_schema = self.get_frame_schema(&mut lf)?;
+        // The columns of the table follow those of the preceding tables.
+        let counts = joined_schema
+            .iter_names()
+            .skip(left_schema.len())
+            .zip(&is_count)
+            .filter(|(_, is_count)| **is_count)
+            .map(|(name, _)| col(name.clone()).fill_null(lit(0 as IdxSize)))
+            .collect::<Vec<_>>();
+        if !counts.is_empty() {
+            lf = lf.with_columns(counts);
+        }
+        self.register_joined_aliases(
+            &alias.name.value,
+            &left_schema,
+            &right_schema,
+            &joined_schema,
+        );
+        if let Some(condition) = condition {
+            let predicate = parse_sql_expr(condition, self, Some(&joined_schema))?;
+            lf = lf.filter(predicate);
+        }
+        Ok(Some(lf))
+    }
+
+    /// Execute the 'SELECT' part of the query.
+    fn execute_select(&mut self, select_stmt: &Select, query: &Query) -> PolarsResult<LazyFrame> {
+        // Named windows are only visible in the 'SELECT' that defines them.
+        let mut named_windows = PlHashMap::new();
+        for NamedWindowDefinition(name, window) in &select_stmt.named_window {
+            let spec = match window {
+                NamedWindowExpr::NamedWindow(other) => named_windows
+                    .get(&other.value)
+                    .cloned()
+                    .ok_or_else(|| polars_err!(SQLSyntax: "window '{}' is not defined", other))?,
+                NamedWindowExpr::WindowSpec(spec) => {
+                    resolve_window_spec(spec, &named_windows)?.into_owned()
+                },
+            };
+            named_windows.insert(name.value.clone(), spec);
+        }
+        let named_windows = self.named_windows.replace(named_windows);
+        let grouping_calls = self.grouping_calls.take();
+        let lf = self.process_select(select_stmt, query);
+        self.named_windows.replace(named_windows);
+        let unresolved = self.grouping_calls.replace(grouping_calls);
+        polars_ensure!(
+            lf.is_err() || unresolved.is_empty(),
+            SQLSyntax: "GROUPING is only supported in the SELECT list of a query with GROUPING SETS, ROLLUP or CUBE"
+        );
+        lf
+    }
+
+    /// Record the keys given to a `GROUPING` call, returning the name of the column it is
+    /// evaluated as.
+    pub(crate) fn add_grouping_call(&self, keys: Vec<Expr>) -> PlSmallStr {
+        let mut grouping_calls = self.grouping_calls.borrow_mut();
+        grouping_calls.push(keys);
+        format_pl_smallstr!("__POLARS_SQL_GROUPING_{}", grouping_calls.len() - 1)
+    }
+
+    /// The window specification of a window function, with references to named windows resolved.
+    pub(crate) fn window_spec<'a>(
+        &self,
+        window_type: &'a WindowType,
+    ) -> PolarsResult<Cow<'a, WindowSpec>> {
+        let named_windows = self.named_windows.borrow();
+        match window_type {
+            WindowType::NamedWindow(name) => named_windows
+                .get(&name.value)
+                .map(|spec| Cow::Owned(spec.clone()))
+                .ok_or_else(|| polars_err!(SQLSyntax: "window '{}' is not defined", name)),
+            WindowType::WindowSpec(spec) => resolve_window_spec(spec, &named_windows),
+        }
+    }
+
+    fn process_select(&mut self, select_stmt: &Select, query: &Query) -> PolarsResult<LazyFrame> {
+        let mut lf = if select_stmt.from.is_empty() {
+            DataFrame::empty().lazy()
+        } else {
+            self.execute_from_statement(&implicit_cross_joins(&select_stmt.from))?
+        };
+
+        // Filter expression (WHERE clause)
+        let schema = self.get_frame_schema(&mut lf)?;
+        lf = self.process_where(lf, &select_stmt.selection, false)?;
+
+        // 'SELECT *' modifiers
+        let mut select_modifiers = SelectModifiers {
+            ilike: None,
+            exclude: PlHashSet::new(),
+            rename: PlHashMap::new(),
+            replace: vec![],
+        };
+
+        // Decorrelate subqueries in the projection, like those in the WHERE clause; the
+        // columns that they are joined as are excluded from the result.
+        let mut select_stmt = Cow::Borrowed(select_stmt);
+        let mut projection = select_stmt.projection.clone();
+        let mut subquery_columns = vec![];
+        for item in projection.iter_mut() {
+            if let SelectItem::UnnamedExpr(SQLExpr::Subquery(subquery)) = item {
+                *item = SelectItem::ExprWithAlias {
+                    alias: self.subquery_column_name(subquery),
+                    expr: SQLExpr::Subquery(subquery.clone()),
+                };
+            }
+            if let SelectItem::UnnamedExpr(expr) | SelectItem::ExprWithAlias { expr, .. } = item {
+                self.decorrelate_subqueries(&mut lf, expr, &schema, &mut subquery_columns)?;
+            }
+        }
+        let schema = if subquery_columns.is_empty() {
+            schema
+        } else {
+            polars_ensure!(
+                matches!(&select_stmt.group_by, GroupByExpr::Expressions(keys, _) if keys.is_empty()),
+                SQLInterface: "subqueries in the SELECT list are not supported with GROUP BY"
+            );
+            select_stmt.to_mut().projection = projection;
+            select_modifiers
+                .exclude
+                .extend(subquery_columns.iter().map(|name| name.to_string()));
+            self.get_frame_schema(&mut lf)?
+        };
+        let select_stmt = select_stmt.as_ref();
+
+        let projections = self.column_projections(select_stmt, &schema, &mut select_modifiers)?;
+
+        // Check for "GROUP BY ..." (after determining projections)
+        let mut group_by_keys: Vec<Expr> = Vec::new();
+        let mut grouping_sets;
+        match &select_stmt.group_by {
+            // Standard "GROUP BY x, y, z" syntax (also recognising ordinal values), including
+            // "GROUPING SETS", "ROLLUP" and "CUBE" elements
             GroupByExpr::Expressions(group_by_exprs, modifiers) => {
-                if !modifiers.is_empty() {
-                    polars_bail!(SQLInterface: "GROUP BY does not support CUBE, ROLLUP, or TOTALS modifiers")
+                (group_by_keys, grouping_sets) =
+                    self.process_group_by_exprs(group_by_exprs, &projections, &schema)?;
+                if let Some(sets) = modifier_grouping_sets(modifiers, group_by_keys.len())? {
+                    if grouping_sets.is_some() {
+                        polars_bail!(SQLSyntax: "GROUP BY cannot combine WITH ROLLUP/CUBE and GROUPING SETS, ROLLUP or CUBE")
+                    }
+                    grouping_sets = Some(sets);
                 }
-                // translate the group expressions, allowing ordinal values
-                group_by_keys = group_by_exprs
-                    .iter()
-                    .map(|e| {
-                        self.expr_or_ordinal(
-                            e,
-                            &projections,
-                            None,
-                            Some(schema.deref()),
-                            "GROUP BY",
-                        )
-                    })
-                    .collect::<PolarsResult<_>>()?
             },
             // "GROUP BY ALL" syntax; automatically adds expressions that do not contain
             // nested agg/window funcs to the group key (also ignores literals).
             GroupByExpr::All(modifiers) => {
-                if !modifiers.is_empty() {
-                    polars_bail!(SQLInterface: "GROUP BY does not support CUBE, ROLLUP, or TOTALS modifiers")
-                }
                 projections.iter().for_each(|expr| match expr {
                     // immediately match the most common cases (col|agg|len|lit, optionally aliased).
                     Expr::Agg(_) | Expr::Len | Expr::Literal(_) => (),
                     Expr::Column(_) => group_by_keys.push(expr.clone()),
-                    Expr::Alias(e, _)
-                        if matches!(&**e, Expr::Agg(_) | Expr::Len | Expr::Literal(_)) => {},
+                    Expr::Alias(e, _) if is_agg(e) || matches!(&**e, Expr::Literal(_)) => {},
                     Expr::Alias(e, _) if matches!(&**e, Expr::Column(_)) => {
                         if let Expr::Column(name) = &**e {
                             group_by_keys.push(col(name.clone()));
@@ -773,15 +1781,12 @@ impl SQLContext {
                     },
                     _ => {
                         // If not quick-matched, add if no nested agg/window expressions
-                        if !has_expr(expr, |e| {
-                            matches!(e, Expr::Agg(_))
-                                || matches!(e, Expr::Len)
-                                || matches!(e, Expr::Window { .. })
-                        }) {
+                        if !has_expr(expr, |e| is_agg(e) || matches!(e, Expr::Window { .. })) {
                             group_by_keys.push(expr.clone())
                         }
                     },
                 });
+                grouping_sets = modifier_grouping_sets(modifiers, group_by_keys.len())?;
             },
         };
 
@@ -794,7 +1799,8 @@ impl SQLContext {
             // Final/selected cols, accounting for 'SELECT *' modifiers
             let mut retained_cols = Vec::with_capacity(projections.len());
             let mut retained_names = Vec::with_capacity(projections.len());
-            let have_order_by = query.order_by.is_some();
+            // QUALIFY can refer to the projections, so these are added to the frame as well.
+            let have_order_by = query.order_by.is_some() || select_stmt.qualify.is_some();
             // Initialize containing InheritsContext to handle empty projection case.
             let mut projection_heights = ExprSqlProjectionHeightBehavior::InheritsContext;
 
@@ -857,7 +1863,7 @@ impl SQLContext {
                                 slice: None,
                                 nulls_equal: false,
                                 coalesce: Default::default(),
-                                maintain_order: polars_ops::frame::MaintainOrderJoin::Left,
+                                maintain_order: MaintainOrderJoin::Left,
                             },
                         );
                 }
@@ -870,6 +1876,7 @@ impl SQLContext {
                 lf = lf.with_columns(select_modifiers.renamed_cols());
             }
 
+            lf = self.process_qualify(lf, &select_stmt.qualify)?;
             lf = self.process_order_by(lf, &query.order_by, Some(&retained_cols))?;
 
             // Note: If `have_order_by`, with_columns is already done above.
@@ -891,15 +1898,16 @@ impl SQLContext {
             };
             lf
         } else {
-            lf = self.process_group_by(lf, &group_by_keys, &projections)?;
+            lf = self.process_group_by(lf, &group_by_keys, grouping_sets.as_ref(), &projections)?;
             lf = self.process_order_by(lf, &query.order_by, None)?;
 
             // Apply optional 'having' clause, post-aggregation.
             let schema = Some(self.get_frame_schema(&mut lf)?);
-            match select_stmt.having.as_ref() {
+            lf = match select_stmt.having.as_ref() {
                 Some(expr) => lf.filter(parse_sql_expr(expr, self, schema.as_deref())?),
                 None => lf,
-            }
+            };
+            self.process_qualify(lf, &select_stmt.qualify)?
         };
 
         // Apply optional DISTINCT clause.
@@ -1005,8 +2013,16 @@ impl SQLContext {
                 return Ok(DataFrame::empty_with_schema(schema.as_ref()).lazy());
             }
 
-            // ...otherwise parse and apply the filter as normal
-            let mut filter_expression = parse_sql_expr(expr, self, Some(schema).as_deref())?;
+            // ...otherwise decorrelate subqueries, then parse and apply the filter as normal
+            let mut expr = expr.clone();
+            let mut subquery_columns = vec![];
+            self.decorrelate_subqueries(&mut lf, &mut expr, &schema, &mut subquery_columns)?;
+            let filter_schema = if subquery_columns.is_empty() {
+                schema.clone()
+            } else {
+                self.get_frame_schema(&mut lf)?
+            };
+            let mut filter_expression = parse_sql_expr(&expr, self, Some(&filter_schema))?;
             if filter_expression.clone().meta().has_multiple_outputs() {
                 filter_expression = all_horizontal([filter_expression])?;
             }
@@ -1016,6 +2032,453 @@ impl SQLContext {
             } else {
                 lf.filter(filter_expression)
             };
+            if !subquery_columns.is_empty() {
+                lf = lf.select(
+                    schema
+                        .iter_names()
+                        .map(|name| col(name.clone()))
+                        .collect::<Vec<_>>(),
+                );
+            }
+        }
+        Ok(lf)
+    }
+
+    /// Replace the `EXISTS` subqueries and the correlated `IN` and scalar subqueries in `expr`
+    /// by references to columns that are joined onto `lf`.
+    ///
+    /// Correlated subqueries are decorrelated by moving their equality conditions on outer
+    /// columns into the join keys (and into the group by keys of aggregated scalar subqueries).
+    fn decorrelate_subqueries(
+        &mut self,
+        lf: &mut LazyFrame,
+        expr: &mut SQLExpr,
+        schema: &Schema,
+        added_columns: &mut Vec<PlSmallStr>,
+    ) -> PolarsResult<()> {
+        let name = format_pl_smallstr!("__POLARS_SQL_SUBQUERY_{}", added_columns.len());
+        let marker = || Box::new(SQLExpr::Identifier(Ident::new(name.as_str())));
+        let replacement = match expr {
+            SQLExpr::Exists { subquery, negated } => {
+                match self.split_correlated_subquery(subquery, schema)? {
+                    Some(correlated) => {
+                        let correlated = correlated.unordered()?;
+                        let sub = self.execute_correlated_subquery(
+                            correlated.clone(),
+                            &name,
+                            lf,
+                            schema,
+                        )?;
+                        *lf =
+                            self.join_subquery_marker(lf.clone(), sub, &correlated, &name, schema)?;
+                        Some(if *negated {
+                            SQLExpr::IsNull(marker())
+                        } else {
+                            SQLExpr::IsNotNull(marker())
+                        })
+                    },
+                    None => {
+                        let sub = self.execute_query_no_ctes(subquery)?;
+                        let exists = sub.select([len().gt(lit(0)).alias(name.clone())]);
+                        *lf = lf.clone().cross_join(exists, None);
+                        Some(if *negated {
+                            SQLExpr::UnaryOp {
+                                op: UnaryOperator::Not,
+                                expr: marker(),
+                            }
+                        } else {
+                            *marker()
+                        })
+                    },
+                }
+            },
+            SQLExpr::InSubquery {
+                expr: in_expr,
+                subquery,
+                negated,
+            } => match self.split_correlated_subquery(subquery, schema)? {
+                Some(correlated) => {
+                    let mut correlated = correlated.unordered()?;
+                    let value = correlated.value_expr()?;
+                    correlated.select.projection.clear();
+                    correlated.inner_keys.push(value);
+                    correlated.outer_keys.push(in_expr.as_ref().clone());
+                    let sub =
+                        self.execute_correlated_subquery(correlated.clone(), &name, lf, schema)?;
+                    if !*negated {
+                        *lf =
+                            self.join_subquery_marker(lf.clone(), sub, &correlated, &name, schema)?;
+                        Some(SQLExpr::IsNotNull(marker()))
+                    } else {
+                        // `x NOT IN (...)` is only true if no value matches and, unless there
+                        // are no values at all, neither `x` nor any of the values is null.
+                        let (mut left_on, mut right_on) =
+                            self.correlated_join_keys(&correlated, &name, schema)?;
+                        let nulls = format_pl_smallstr!("{name}_nulls");
+                        let value = right_on.pop().unwrap();
+                        left_on.pop();
+                        let groups = sub
+                            .clone()
+                            .group_by(right_on.clone())
+                            .agg([value.is_null().any(false).alias(nulls.clone())]);
+                        let mut args = JoinArgs::new(JoinType::Left);
+                        args.maintain_order = MaintainOrderJoin::Left;
+                        *lf = self
+                            .join_subquery_marker(lf.clone(), sub, &correlated, &name, schema)?
+                            .join(groups, left_on, right_on, args);
+
+                        let nulls = Box::new(SQLExpr::Identifier(Ident::new(nulls.as_str())));
+                        let no_nulls = SQLExpr::BinaryOp {
+                            left: Box::new(SQLExpr::IsNotNull(in_expr.clone())),
+                            op: BinaryOperator::And,
+                            right: Box::new(SQLExpr::UnaryOp {
+                                op: UnaryOperator::Not,
+                                expr: nulls.clone(),
+                            }),
+                        };
+                        Some(SQLExpr::BinaryOp {
+                            left: Box::new(SQLExpr::IsNull(marker())),
+                            op: BinaryOperator::And,
+                            right: Box::new(SQLExpr::Nested(Box::new(SQLExpr::BinaryOp {
+                                left: Box::new(SQLExpr::IsNull(nulls)),
+                                op: BinaryOperator::Or,
+                                right: Box::new(SQLExpr::Nested(Box::new(no_nulls))),
+                            }))),
+                        })
+                    }
+                },
+                // Uncorrelated subqueries are evaluated as `is_in` on the subquery result.
+                None => None,
+            },
+            SQLExpr::Subquery(subquery) => {
+                match self.split_correlated_subquery(subquery, schema)? {
+                    Some(correlated) => {
+                        let mut correlated = correlated.unordered()?;
+                        let value = correlated.value_expr()?;
+                        let is_count = matches!(
+                            correlated.value.as_ref().map(strip_alias),
+                            Some(Expr::Len | Expr::Agg(AggExpr::Count(_, _)))
+                        );
+                        if correlated.is_aggregate {
+                            correlated.group_by_inner_keys()?;
+                        }
+                        correlated.select.projection = vec![SelectItem::ExprWithAlias {
+                            expr: value,
+                            alias: Ident::new(name.as_str()),
+                        }];
+                        let sub = self.execute_correlated_subquery(
+                            correlated.clone(),
+                            &name,
+                            lf,
+                            schema,
+                        )?;
+                        let (left_on, right_on) =
+                            self.correlated_join_keys(&correlated, &name, schema)?;
+                        // Unless it is aggregated, a scalar subquery may return more than one
+                        // row per outer row, which is an error.
+                        let mut args = JoinArgs::new(JoinType::Left);
+                        args.maintain_order = MaintainOrderJoin::Left;
+                        if !correlated.is_aggregate {
+                            args.validation = JoinValidation::ManyToOne;
+                        }
+                        let mut joined = lf.clone().join(sub, left_on, right_on, args);
+                        // A group without rows has a count of zero rather than null.
+                        if is_count {
+                            joined = joined.with_column(col(name.clone()).fill_null(lit(0)));
+                        }
+                        *lf = joined;
+                        Some(*marker())
+                    },
+                    // Uncorrelated subqueries are evaluated in the context of the query.
+                    None => None,
+                }
+            },
+            _ => None,
+        };
+        match replacement {
+            Some(replacement) => {
+                *expr = replacement;
+                added_columns.push(name);
+            },
+            None => {
+                for e in sql_sub_exprs_mut(expr) {
+                    self.decorrelate_subqueries(lf, e, schema, added_columns)?;
+                }
+            },
+        }
+        Ok(())
+    }
+
+    /// The column name of an unnamed subquery in the `SELECT` list, which is that of its
+    /// (single) projected expression.
+    fn subquery_column_name(&mut self, subquery: &Query) -> Ident {
+        let item = match subquery.body.as_ref() {
+            SetExpr::Select(select) => select.projection.first(),
+            _ => None,
+        };
+        match item {
+            Some(SelectItem::ExprWithAlias { alias, .. }) => alias.clone(),
+            Some(SelectItem::UnnamedExpr(expr)) => {
+                let name = parse_sql_expr(expr, self, None).and_then(|e| expr_output_name(&e));
+                match name {
+                    Ok(name) => Ident::new(name.as_str()),
+                    Err(_) => Ident::new(expr.to_string()),
+                }
+            },
+            _ => Ident::new(subquery.to_string()),
+        }
+    }
+
+    /// Split the conditions of a subquery that refer to columns of the outer query (with the
+    /// given schema) from its `WHERE` clause; returns `None` if the subquery is not correlated.
+    fn split_correlated_subquery(
+        &mut self,
+        subquery: &Query,
+        outer_schema: &Schema,
+    ) -> PolarsResult<Option<CorrelatedSubquery>> {
+        let SetExpr::Select(select) = subquery.body.as_ref() else {
+            return Ok(None);
+        };
+        let [from] = select.from.as_slice() else {
+            return Ok(None);
+        };
+
+        // Resolving the inner tables registers their aliases, which may shadow the outer ones.
+        let table_aliases = self.table_aliases.borrow().clone();
+        let joined_aliases = self.joined_aliases.borrow().clone();
+        let result = (|| {
+            let mut inner_lf = self.execute_from_statement(from)?;
+            let inner_schema = self.get_frame_schema(&mut inner_lf)?;
+            let inner_names = relation_names(from);
+            let is_outer = |path: &[Ident]| match path {
+                [ident] => {
+                    !inner_schema.contains(&ident.value) && outer_schema.contains(&ident.value)
+                },
+                [tbl, ..] => {
+                    !inner_names.contains(&tbl.value) && !inner_schema.contains(&tbl.value)
+                },
+                [] => false,
+            };
+            // Whether an expression refers to inner and/or outer columns.
+            let origin = |e: &SQLExpr| {
+                let mut idents = vec![];
+                sql_expr_identifiers(e, &mut idents);
+                (
+                    idents.iter().any(|path| !is_outer(path)),
+                    idents.iter().any(|path| is_outer(path)),
+                )
+            };
+
+            let mut conjuncts = vec![];
+            if let Some(selection) = &select.selection {
+                split_conjuncts(selection, &mut conjuncts);
+            }
+            let (mut inner_keys, mut outer_keys) = (vec![], vec![]);
+            let (mut inner_conditions, mut outer_values) = (vec![], vec![]);
+            for e in conjuncts {
+                if !origin(e).1 {
+                    inner_conditions.push(e.clone());
+                    continue;
+                }
+                let keys = match e {
+                    SQLExpr::BinaryOp {
+                        left,
+                        op: BinaryOperator::Eq,
+                        right,
+                    } => match (origin(left), origin(right)) {
+                        (_, (false, true)) if !origin(left).1 => Some((left, right)),
+                        ((false, true), _) if !origin(right).1 => Some((right, left)),
+                        _ => None,
+                    },
+                    _ => None,
+                };
+                match keys {
+                    Some((inner, outer)) => {
+                        inner_keys.push(inner.as_ref().clone());
+                        outer_keys.push(outer.as_ref().clone());
+                    },
+                    // Other conditions are evaluated on the distinct values of the outer
+                    // expressions in them, which become join keys as well.
+                    None => {
+                        let mut e = e.clone();
+                        replace_outer_exprs(&mut e, &origin, &mut outer_values);
+                        inner_conditions.push(e);
+                    },
+                }
+            }
+            for (i, value) in outer_values.iter().enumerate() {
+                inner_keys.push(SQLExpr::Identifier(Ident::new(format!(
+                    "{OUTER_VALUES}_{i}"
+                ))));
+                outer_keys.push(value.clone());
+            }
+            if outer_keys.is_empty() {
+                return Ok(None);
+            }
+            let (mut value, mut is_aggregate) = (None, false);
+            for item in &select.projection {
+                if let SelectItem::UnnamedExpr(e) | SelectItem::ExprWithAlias { expr: e, .. } = item
+                {
+                    polars_ensure!(
+                        !origin(e).1,
+                        SQLInterface: "outer columns are not supported in the projection of correlated subqueries; found {}", e
+                    );
+                    let e = parse_sql_expr(e, self, Some(&inner_schema))?;
+                    is_aggregate |= has_expr(&e, is_agg);
+                    if select.projection.len() == 1 {
+                        value = Some(e);
+                    }
+                }
+            }
+            let mut select = select.as_ref().clone();
+            select.selection =
+                inner_conditions
+                    .into_iter()
+                    .reduce(|left, right| SQLExpr::BinaryOp {
+                        left: Box::new(left),
+                        op: BinaryOperator::And,
+                        right: Box::new(right),
+                    });
+            Ok(Some(CorrelatedSubquery {
+                query: subquery.clone(),
+                select,
+                inner_keys,
+                outer_keys,
+                outer_values,
+                value,
+                is_aggregate,
+            }))
+        })();
+        *self.table_aliases.borrow_mut() = table_aliases;
+        *self.joined_aliases.borrow_mut() = joined_aliases;
+        result
+    }
+
+    /// Execute a decorrelated subquery, selecting its inner join keys (as `<name>_key_<i>`)
+    /// followed by its projection.
+    ///
+    /// The distinct outer values of the conditions that are not equalities are taken from the
+    /// outer frame `lf` (with the given schema) and cross joined onto the inner tables.
+    fn execute_correlated_subquery(
+        &mut self,
+        mut correlated: CorrelatedSubquery,
+        name: &str,
+        lf: &LazyFrame,
+        schema: &Schema,
+    ) -> PolarsResult<LazyFrame> {
+        let mut shadowed = None;
+        if !correlated.outer_values.is_empty() {
+            let values = correlated
+                .outer_values
+                .iter()
+                .enumerate()
+                .map(|(i, e)| {
+                    let e = self.translate_join_condition(e, schema)?;
+                    Ok(e.alias(format!("{OUTER_VALUES}_{i}")))
+                })
+                .collect::<PolarsResult<Vec<_>>>()?;
+            let values = lf
+                .clone()
+                .select(values)
+                .unique(None, UniqueKeepStrategy::Any);
+            shadowed = Some(self.table_map.insert(OUTER_VALUES.to_string(), values));
+            correlated.select.from[0].joins.push(Join {
+                relation: TableFactor::Table {
+                    name: ObjectName(vec![Ident::new(OUTER_VALUES)]),
+                    alias: None,
+                    args: None,
+                    with_hints: vec![],
+                    version: None,
+                    with_ordinality: false,
+                    partitions: vec![],
+                    json_path: None,
+                },
+                global: false,
+                join_operator: JoinOperator::CrossJoin,
+            });
+        }
+        let mut projection = correlated
+            .inner_keys
+            .iter()
+            .enumerate()
+            .map(|(i, e)| SelectItem::ExprWithAlias {
+                expr: e.clone(),
+                alias: Ident::new(format!("{name}_key_{i}")),
+            })
+            .collect::<Vec<_>>();
+        projection.append(&mut correlated.select.projection);
+        correlated.select.projection = projection;
+        correlated.query.body = Box::new(SetExpr::Select(Box::new(correlated.select)));
+
+        let table_aliases = self.table_aliases.borrow().clone();
+        let joined_aliases = self.joined_aliases.borrow().clone();
+        let lf = self.execute_query_no_ctes(&correlated.query);
+        *self.table_aliases.borrow_mut() = table_aliases;
+        *self.joined_aliases.borrow_mut() = joined_aliases;
+        match shadowed {
+            Some(Some(values)) => {
+                self.table_map.insert(OUTER_VALUES.to_string(), values);
+            },
+            Some(None) => {
+                self.table_map.remove(OUTER_VALUES);
+            },
+            None => {},
+        }
+        lf
+    }
+
+    /// Left join the distinct join keys of a decorrelated subquery onto `lf`, with a `name`
+    /// column that is null for the rows without a match.
+    fn join_subquery_marker(
+        &mut self,
+        lf: LazyFrame,
+        sub: LazyFrame,
+        correlated: &CorrelatedSubquery,
+        name: &PlSmallStr,
+        schema: &Schema,
+    ) -> PolarsResult<LazyFrame> {
+        let (left_on, right_on) = self.correlated_join_keys(correlated, name, schema)?;
+        let matches = sub
+            .select(right_on.clone())
+            .unique(None, UniqueKeepStrategy::Any)
+            .with_column(lit(true).alias(name.clone()));
+        let mut args = JoinArgs::new(JoinType::Left);
+        args.maintain_order = MaintainOrderJoin::Left;
+        Ok(lf.join(matches, left_on, right_on, args))
+    }
+
+    /// The outer and inner join keys of a decorrelated subquery.
+    fn correlated_join_keys(
+        &mut self,
+        correlated: &CorrelatedSubquery,
+        name: &str,
+        schema: &Schema,
+    ) -> PolarsResult<(Vec<Expr>, Vec<Expr>)> {
+        let left_on = correlated
+            .outer_keys
+            .iter()
+            .map(|e| self.translate_join_condition(e, schema))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let right_on = (0..left_on.len())
+            .map(|i| col(format!("{name}_key_{i}")))
+            .collect();
+        Ok((left_on, right_on))
+    }
+
+    /// Filter the rows on the result of window functions (QUALIFY clause).
+    fn process_qualify(
+        &mut self,
+        mut lf: LazyFrame,
+        qualify: &Option<SQLExpr>,
+    ) -> PolarsResult<LazyFrame> {
+        if let Some(expr) = qualify {
+            let schema = self.get_frame_schema(&mut lf)?;
+            let predicate = parse_sql_expr(expr, self, Some(&schema))?;
+            polars_ensure!(
+                has_expr(&predicate, |e| matches!(e, Expr::Window { .. })),
+                SQLSyntax: "QUALIFY requires a window function; found {}", expr
+            );
+            lf = lf.filter(predicate);
         }
         Ok(lf)
     }
@@ -1027,21 +2490,237 @@ impl SQLContext {
         constraint: &JoinConstraint,
         join_type: JoinType,
     ) -> PolarsResult<LazyFrame> {
-        let (left_on, right_on) = process_join_constraint(constraint, tbl_left, tbl_right)?;
+        let suffix = format_pl_smallstr!(":{}", tbl_right.name);
+        let conditions = match constraint {
+            JoinConstraint::On(expr) => self.process_join_on(expr, tbl_left, tbl_right)?,
+            _ => {
+                let (left_on, right_on) = process_join_constraint(constraint, tbl_left, tbl_right)?;
+                JoinConditions {
+                    left_on,
+                    right_on,
+                    ..Default::default()
+                }
+            },
+        };
+        if conditions.is_equi_join() {
+            return Ok(equi_join(
+                tbl_left.frame.clone(),
+                tbl_right.frame.clone(),
+                &conditions,
+                join_type,
+                suffix,
+            ));
+        }
+        if join_type == JoinType::Inner {
+            return Ok(join_matches(
+                tbl_left.frame.clone(),
+                tbl_right.frame.clone(),
+                &conditions,
+                suffix,
+            ));
+        }
 
-        let joined = tbl_left
-            .frame
-            .clone()
-            .join_builder()
-            .with(tbl_right.frame.clone())
-            .left_on(left_on)
-            .right_on(right_on)
-            .how(join_type)
-            .suffix(format!(":{}", tbl_right.name))
-            .coalesce(JoinCoalesce::KeepColumns)
-            .finish();
+        // Other joins are derived from the (row indices of) the matching rows.
+        const LEFT_ROW: PlSmallStr = PlSmallStr::from_static("__POLARS_SQL_JOIN_LEFT_ROW");
+        const RIGHT_ROW: PlSmallStr = PlSmallStr::from_static("__POLARS_SQL_JOIN_RIGHT_ROW");
+        let left = tbl_left.frame.clone().with_row_index(LEFT_ROW, None);
+        let right = tbl_right.frame.clone().with_row_index(RIGHT_ROW, None);
+        let pairs = join_matches(left.clone(), right.clone(), &conditions, suffix.clone())
+            .select([col(LEFT_ROW), col(RIGHT_ROW)]);
+        let join_rows = |lf: LazyFrame, other: LazyFrame, row: PlSmallStr, how: JoinType| {
+            lf.join(other, [col(row.clone())], [col(row)], JoinArgs::new(how))
+        };
+
+        let (joined, names): (_, Vec<_>) = match join_type {
+            #[cfg(feature = "semi_anti_join")]
+            JoinType::Semi | JoinType::Anti => (
+                join_rows(left, pairs, LEFT_ROW, join_type),
+                tbl_left.schema.iter_names().cloned().collect(),
+            ),
+            JoinType::Left | JoinType::Right | JoinType::Full => {
+                let (how_pairs, how_right) = match join_type {
+                    JoinType::Left => (JoinType::Left, JoinType::Left),
+                    JoinType::Right => (JoinType::Inner, JoinType::Right),
+                    _ => (JoinType::Left, JoinType::Full),
+                };
+                // Give the right columns the same names they have in the matching rows.
+                let (existing, new): (Vec<_>, Vec<_>) = tbl_right
+                    .schema
+                    .iter_names()
+                    .filter(|name| tbl_left.schema.contains(name))
+                    .map(|name| (name.clone(), format_pl_smallstr!("{}{}", name, suffix)))
+                    .unzip();
+                let right = right.rename(&existing, &new, true);
+                let joined = join_rows(
+                    join_rows(left, pairs, LEFT_ROW, how_pairs),
+                    right,
+                    RIGHT_ROW,
+                    how_right,
+                );
+                let names = tbl_left
+                    .schema
+                    .iter_names()
+                    .cloned()
+                    .chain(tbl_right.schema.iter_names().map(|name| {
+                        if tbl_left.schema.contains(name) {
+                            format_pl_smallstr!("{}{}", name, suffix)
+                        } else {
+                            name.clone()
+                        }
+                    }))
+                    .collect();
+                (joined, names)
+            },
+            join_type => polars_bail!(
+                SQLInterface: "non-equi join conditions are not supported for join type '{:?}'", join_type
+            ),
+        };
+        Ok(joined.select(names.into_iter().map(col).collect::<Vec<_>>()))
+    }
+
+    /// Split an `ON` join condition into equi-join keys, predicates that refer to both tables
+    /// and filters that only refer to one of them.
+    fn process_join_on(
+        &mut self,
+        expression: &SQLExpr,
+        tbl_left: &TableInfo,
+        tbl_right: &TableInfo,
+    ) -> PolarsResult<JoinConditions> {
+        let mut conjuncts = vec![];
+        split_join_conjuncts(expression, &mut conjuncts);
+
+        let mut conditions = JoinConditions::default();
+        let mut other_conjuncts = vec![];
+        for e in &conjuncts {
+            // Equalities of qualified columns are equi-join keys on the named columns.
+            if let SQLExpr::BinaryOp {
+                left,
+                op: BinaryOperator::Eq,
+                right,
+            } = e.as_ref()
+            {
+                if let (SQLExpr::CompoundIdentifier(left), SQLExpr::CompoundIdentifier(right)) =
+                    (left.as_ref(), right.as_ref())
+                {
+                    if left.len() == 2 && right.len() == 2 {
+                        let (mut left_on, mut right_on) = collect_compound_identifiers(
+                            left,
+                            right,
+                            &tbl_left.name,
+                            &tbl_right.name,
+                        )?;
+                        conditions.left_on.append(&mut left_on);
+                        conditions.right_on.append(&mut right_on);
+                        continue;
+                    }
+                }
+            }
+            let mut idents = vec![];
+            sql_expr_identifiers(e, &mut idents);
+            if let Some([ident]) = idents.iter().find(|path| {
+                matches!(path, [ident] if tbl_left.schema.contains(&ident.value)
+                    && tbl_right.schema.contains(&ident.value))
+            }) {
+                polars_bail!(SQLSyntax: "ambiguous column '{}' in join condition; qualify it with the table name", ident.value)
+            }
+            other_conjuncts.push(e);
+        }
+
+        // Translate the condition as if it were evaluated on the joined frame, in which the
+        // right columns that also exist on the left have the join suffix.
+        let mut right_names = PlHashMap::new();
+        let mut schema = tbl_left.schema.as_ref().clone();
+        for (name, dtype) in tbl_right.schema.iter() {
+            if tbl_left.schema.contains(name) {
+                let suffixed = format_pl_smallstr!("{}:{}", name, tbl_right.name);
+                schema.insert(suffixed.clone(), dtype.clone());
+                right_names.insert(suffixed, name.clone());
+            } else {
+                schema.insert(name.clone(), dtype.clone());
+            }
+        }
+        let prev_aliases = self.joined_aliases.borrow_mut().insert(
+            tbl_right.name.to_string(),
+            right_names
+                .iter()
+                .map(|(suffixed, name)| (name.to_string(), suffixed.to_string()))
+                .collect(),
+        );
+        let translated = other_conjuncts
+            .into_iter()
+            .map(|e| self.translate_join_condition(e, &schema))
+            .collect::<PolarsResult<Vec<_>>>();
+        let mut joined_aliases = self.joined_aliases.borrow_mut();
+        match prev_aliases {
+            Some(aliases) => joined_aliases.insert(tbl_right.name.to_string(), aliases),
+            None => joined_aliases.remove(tbl_right.name.as_str()),
+        };
+        drop(joined_aliases);
+
+        let origin = |e: &Expr| -> PolarsResult<JoinExprOrigin> {
+            let mut origin = JoinExprOrigin::empty();
+            for name in expr_to_leaf_column_names_iter(e) {
+                origin |= if tbl_left.schema.contains(&name) {
+                    JoinExprOrigin::LEFT
+                } else if schema.contains(&name) {
+                    JoinExprOrigin::RIGHT
+                } else {
+                    polars_bail!(ColumnNotFound: "'{}' in join condition", name)
+                };
+            }
+            Ok(origin)
+        };
+        let to_right_names = |e: Expr| {
+            e.map_expr(|e| match e {
+                Expr::Column(name) => match right_names.get(&name) {
+                    Some(name) => Expr::Column(name.clone()),
+                    None => Expr::Column(name),
+                },
+                e => e,
+            })
+        };
+
+        for e in translated? {
+            if let Expr::BinaryExpr {
+                left,
+                op: Operator::Eq,
+                right,
+            } = &e
+            {
+                let (left, right) = (left.as_ref(), right.as_ref());
+                match (origin(left)?, origin(right)?) {
+                    (JoinExprOrigin::LEFT, JoinExprOrigin::RIGHT) => {
+                        conditions.left_on.push(left.clone());
+                        conditions.right_on.push(to_right_names(right.clone()));
+                        continue;
+                    },
+                    (JoinExprOrigin::RIGHT, JoinExprOrigin::LEFT) => {
+                        conditions.left_on.push(right.clone());
+                        conditions.right_on.push(to_right_names(left.clone()));
+                        continue;
+                    },
+                    _ => {},
+                }
+            }
+            match origin(&e)? {
+                JoinExprOrigin::RIGHT => conditions.right_filters.push(to_right_names(e)),
+                JoinExprOrigin::BOTH => conditions.predicates.push(e),
+                // Conditions that do not refer to any column are evaluated on the left table.
+                _ => conditions.left_filters.push(e),
+            }
+        }
+        // Predicates refer to the right columns by their name in the joined frame.
+        conditions.suffixed_right_names = right_names.into_iter().map(|(k, v)| (v, k)).collect();
+        Ok(conditions)
+    }
 
-        Ok(joined)
+    fn translate_join_condition(&mut self, expr: &SQLExpr, schema: &Schema) -> PolarsResult<Expr> {
+        let e = parse_sql_expr(expr, self, Some(schema))?;
+        // Aliases of resolved columns are not allowed in join keys or predicates.
+        Ok(e.map_expr(|e| match e {
+            Expr::Alias(e, _) => Arc::unwrap_or_clone(e),
+            e => e,
+        }))
     }
 
     fn process_subqueries(&self, lf: LazyFrame, exprs: Vec<&mut Expr>) -> LazyFrame {
@@ -1071,6 +2750,7 @@ impl SQLContext {
         if let Statement::CreateTable(CreateTable {
             if_not_exists,
             name,
+            columns,
             query,
             ..
         }) = stmt
@@ -1081,23 +2761,100 @@ impl SQLContext {
                 polars_bail!(SQLInterface: "relation '{}' already exists", tbl_name);
                 // CREATE OR REPLACE TABLE
             }
-            if let Some(query) = query {
-                let lf = self.execute_query(query)?;
-                self.register(tbl_name, lf);
-                let out = df! {
-                    "Response" => ["CREATE TABLE"]
-                }
-                .unwrap()
-                .lazy();
-                Ok(out)
-            } else {
-                polars_bail!(SQLInterface: "only `CREATE TABLE AS SELECT ...` is currently supported");
+            // CREATE TABLE <tbl> (<col> <type>, ...) [AS SELECT ...]
+            let schema = columns
+                .iter()
+                .map(|c| {
+                    let dtype = map_sql_dtype_to_polars(&c.data_type)?;
+                    Ok(Field::new(c.name.value.as_str().into(), dtype))
+                })
+                .collect::<PolarsResult<Schema>>()?;
+
+            let lf = match query {
+                Some(query) => {
+                    let mut lf = self.execute_query(query)?;
+                    if !schema.is_empty() {
+                        let query_schema = self.get_frame_schema(&mut lf)?;
+                        polars_ensure!(
+                            query_schema.len() == schema.len(),
+                            SQLSyntax: "CREATE TABLE declares {} columns, but its query returns {} columns",
+                            schema.len(), query_schema.len()
+                        );
+                        let values = schema
+                            .iter_names()
+                            .zip(query_schema.iter_names())
+                            .map(|(name, value)| (name.clone(), col(value.clone())))
+                            .collect();
+                        lf = lf.select(insert_exprs(&schema, values));
+                    }
+                    lf
+                },
+                None if !schema.is_empty() => DataFrame::empty_with_schema(&schema).lazy(),
+                None => polars_bail!(
+                    SQLSyntax: "CREATE TABLE requires a column list or a query"
+                ),
+            };
+            self.register(tbl_name, lf);
+            let out = df! {
+                "Response" => ["CREATE TABLE"]
             }
+            .unwrap()
+            .lazy();
+            Ok(out)
         } else {
             unreachable!()
         }
     }
 
+    // CREATE [OR REPLACE] [MATERIALIZED] VIEW [IF NOT EXISTS] <view> [(<cols>)] AS SELECT ...
+    fn execute_create_view(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        let Statement::CreateView {
+            or_replace,
+            materialized,
+            name,
+            columns,
+            query,
+            if_not_exists,
+            ..
+        } = stmt
+        else {
+            polars_bail!(SQLInterface: "unexpected statement type; expected CREATE VIEW")
+        };
+        let view_name = name.0.first().unwrap().value.clone();
+        let response = df! {
+            "Response" => ["CREATE VIEW"]
+        }
+        .unwrap()
+        .lazy();
+
+        let is_view = self.view_map.contains_key(&view_name);
+        if is_view || self.table_map.contains_key(&view_name) {
+            if *if_not_exists {
+                return Ok(response);
+            }
+            polars_ensure!(
+                *or_replace && is_view,
+                SQLInterface: "relation '{}' already exists", view_name
+            );
+        }
+        let view = SQLView {
+            query: *query.clone(),
+            columns: columns
+                .iter()
+                .map(|c| PlSmallStr::from_str(c.name.value.as_str()))
+                .collect(),
+        };
+        // a materialized view is a table that holds the current result of the query
+        let lf = self.execute_view(&view_name, &view)?;
+        if *materialized {
+            self.view_map.remove(&view_name);
+            self.table_map.insert(view_name, lf);
+        } else {
+            self.view_map.insert(view_name, view);
+        }
+        Ok(response)
+    }
+
     fn get_table(&mut self, relation: &TableFactor) -> PolarsResult<(String, LazyFrame)> {
         match relation {
             TableFactor::Table {
@@ -1106,7 +2863,29 @@ impl SQLContext {
                 if let Some(args) = args {
                     return self.execute_table_function(name, alias, &args.args);
                 }
+                if let [schema, info_name] = name.0.as_slice() {
+                    if schema.value.eq_ignore_ascii_case(INFORMATION_SCHEMA) {
+                        let mut lf = self.get_information_schema_table(&info_name.value)?;
+                        let qualified =
+                            format!("{INFORMATION_SCHEMA}.{}", info_name.value.to_lowercase());
+                        let tbl_name = match alias {
+                            Some(alias) => {
+                                lf = self.rename_columns_from_table_alias(lf, alias)?;
+                                self.table_aliases
+                                    .borrow_mut()
+                                    .insert(alias.name.value.clone(), qualified.clone());
+                                alias.name.value.clone()
+                            },
+                            None => info_name.value.clone(),
+                        };
+                        self.information_schema_map
+                            .borrow_mut()
+                            .insert(qualified, lf.clone());
+                        return Ok((tbl_name, lf));
+                    }
+                }
                 let tbl_name = name.0.first().unwrap().value.as_str();
+                self.resolve_view(tbl_name)?;
                 if let Some(lf) = self.get_table_from_current_scope(tbl_name) {
                     match alias {
                         Some(alias) => {
@@ -1121,12 +2900,11 @@ impl SQLContext {
                     polars_bail!(SQLInterface: "relation '{}' was not found", tbl_name);
                 }
             },
+            // Correlated `LATERAL` tables are joined by `execute_lateral_join`, the others are
+            // regular derived tables.
             TableFactor::Derived {
-                lateral,
-                subquery,
-                alias,
+                subquery, alias, ..
             } => {
-                polars_ensure!(!(*lateral), SQLInterface: "LATERAL not supported");
                 if let Some(alias) = alias {
                     let mut lf = self.execute_query_no_ctes(subquery)?;
                     lf = self.rename_columns_from_table_alias(lf, alias)?;
@@ -1140,12 +2918,12 @@ impl SQLContext {
                 alias,
                 array_exprs,
                 with_offset,
-                with_offset_alias: _,
-                ..
+                with_offset_alias,
+                with_ordinality,
             } => {
                 if let Some(alias) = alias {
                     let table_name = alias.name.value.clone();
-                    let column_names: Vec<Option<PlSmallStr>> = alias
+                    let mut column_names: Vec<Option<PlSmallStr>> = alias
                         .columns
                         .iter()
                         .map(|c| {
@@ -1166,6 +2944,15 @@ impl SQLContext {
                         SQLSyntax:
                         "UNNEST table alias must also declare column names, eg: {} (a,b,c)", alias.name.to_string()
                     );
+                    // WITH ORDINALITY adds a (1-based) row number, which may be named by the alias
+                    let ordinality_name = (*with_ordinality).then(|| {
+                        let name = if column_names.len() == column_values.len() + 1 {
+                            column_names.pop().flatten()
+                        } else {
+                            None
+                        };
+                        name.unwrap_or_else(|| PlSmallStr::from_static("ordinality"))
+                    });
                     if column_names.len() != column_values.len() {
                         let plural = if column_values.len() > 1 { "s" } else { "" };
                         polars_bail!(
@@ -1186,12 +2973,24 @@ impl SQLContext {
                         .map(Column::from)
                         .collect();
 
-                    let lf = DataFrame::new(column_series)?.lazy();
-                    if *with_offset {
-                        // TODO: support 'WITH ORDINALITY' modifier.
-                        //  (note that 'WITH OFFSET' is BigQuery-specific syntax, not PostgreSQL)
-                        polars_bail!(SQLInterface: "UNNEST tables do not (yet) support WITH OFFSET/ORDINALITY");
+                    let mut df = DataFrame::new(column_series)?;
+                    // WITH OFFSET (BigQuery) adds a 0-based row number
+                    let offset_name = (*with_offset).then(|| {
+                        with_offset_alias.as_ref().map_or_else(
+                            || PlSmallStr::from_static("offset"),
+                            |alias| PlSmallStr::from_str(alias.value.as_str()),
+                        )
+                    });
+                    for (name, start) in [(ordinality_name, 1), (offset_name, 0)] {
+                        if let Some(name) = name {
+                            let n = df.height() as IdxSize;
+                            df.with_column(Column::new(
+                                name,
+                                (start..start + n).collect::<Vec<_>>(),
+                            ))?;
+                        }
                     }
+                    let lf = df.lazy();
                     self.table_map.insert(table_name.clone(), lf.clone());
                     Ok((table_name.clone(), lf))
                 } else {
@@ -1208,6 +3007,35 @@ impl SQLContext {
                     None => Ok(("".to_string(), lf)),
                 }
             },
+            TableFactor::Pivot {
+                table,
+                aggregate_functions,
+                value_column,
+                value_source,
+                default_on_null,
+                alias,
+            } => {
+                let (name, lf) = self.get_table(table)?;
+                let lf = self.execute_pivot(
+                    lf,
+                    aggregate_functions,
+                    value_column,
+                    value_source,
+                    default_on_null.as_ref(),
+                )?;
+                self.register_table_factor_result(name, lf, alias.as_ref())
+            },
+            TableFactor::Unpivot {
+                table,
+                value,
+                name: variable,
+                columns,
+                alias,
+            } => {
+                let (name, lf) = self.get_table(table)?;
+                let lf = self.execute_unpivot(lf, value, variable, columns)?;
+                self.register_table_factor_result(name, lf, alias.as_ref())
+            },
             // Support bare table, optionally with an alias, for now
             _ => polars_bail!(SQLInterface: "not yet implemented: {}", relation),
         }
@@ -1220,8 +3048,16 @@ impl SQLContext {
         args: &[FunctionArg],
     ) -> PolarsResult<(String, LazyFrame)> {
         let tbl_fn = name.0.first().unwrap().value.as_str();
-        let read_fn = tbl_fn.parse::<PolarsTableFunctions>()?;
-        let (tbl_name, lf) = read_fn.execute(args)?;
+        let (tbl_name, lf) = match tbl_fn.parse::<PolarsTableFunctions>() {
+            Ok(read_fn) => read_fn.execute(args)?,
+            Err(err) => match self.function_registry.get_table_udf(tbl_fn)? {
+                Some(udf) => {
+                    let args = self.evaluate_table_function_args(tbl_fn, args)?;
+                    (tbl_fn.to_string(), udf.call(&args)?)
+                },
+                None => return Err(err),
+            },
+        };
         #[allow(clippy::useless_asref)]
         let tbl_name = alias
             .as_ref()
@@ -1232,6 +3068,33 @@ impl SQLContext {
         Ok((tbl_name, lf))
     }
 
+    /// Evaluate the (constant) arguments of a user defined table function.
+    fn evaluate_table_function_args(
+        &mut self,
+        tbl_fn: &str,
+        args: &[FunctionArg],
+    ) -> PolarsResult<Vec<Scalar>> {
+        args.iter()
+            .map(|arg| {
+                let FunctionArg::Unnamed(FunctionArgExpr::Expr(e)) = arg else {
+                    polars_bail!(SQLInterface: "table function '{}' only supports positional arguments; found {}", tbl_fn, arg)
+                };
+                let value = parse_sql_expr(e, self, None)?;
+                let df = DataFrame::empty()
+                    .lazy()
+                    .select([value])
+                    .collect()
+                    .map_err(|_| polars_err!(SQLInterface: "table function '{}' expects constant arguments; found {}", tbl_fn, e))?;
+                let s = df.get_columns()[0].as_materialized_series();
+                polars_ensure!(
+                    s.len() == 1,
+                    SQLInterface: "table function '{}' expects scalar arguments; found {}", tbl_fn, e
+                );
+                Ok(Scalar::new(s.dtype().clone(), s.get(0)?.into_static()))
+            })
+            .collect()
+    }
+
     fn process_order_by(
         &mut self,
         mut lf: LazyFrame,
@@ -1288,15 +3151,122 @@ impl SQLContext {
         ))
     }
 
+    /// Translate the GROUP BY expressions into the group by keys, expanding any `GROUPING SETS`,
+    /// `ROLLUP` and `CUBE` elements into the grouping sets over those keys.
+    fn process_group_by_exprs(
+        &mut self,
+        group_by_exprs: &[SQLExpr],
+        projections: &[Expr],
+        schema: &Schema,
+    ) -> PolarsResult<(Vec<Expr>, Option<GroupingSets>)> {
+        let mut keys: Vec<Expr> = vec![];
+        let mut key_indices = |ctx: &mut Self, exprs: &[SQLExpr]| -> PolarsResult<Vec<usize>> {
+            exprs
+                .iter()
+                .map(|e| {
+                    // translate the group expressions, allowing ordinal values
+                    let key =
+                        ctx.expr_or_ordinal(e, projections, None, Some(schema), "GROUP BY")?;
+                    Ok(match keys.iter().position(|k| *k == key) {
+                        Some(idx) => idx,
+                        None => {
+                            keys.push(key);
+                            keys.len() - 1
+                        },
+                    })
+                })
+                .collect()
+        };
+
+        // Every element of the GROUP BY clause contributes one of its alternative sets of keys
+        // to every grouping set; the grouping sets are the cross product of these alternatives.
+        let mut has_sets = false;
+        let mut sets: Vec<Vec<usize>> = vec![vec![]];
+        for e in group_by_exprs {
+            let alternatives = match e {
+                SQLExpr::GroupingSets(items) => {
+                    has_sets = true;
+                    items
+                        .iter()
+                        .map(|item| key_indices(self, item))
+                        .collect::<PolarsResult<Vec<_>>>()?
+                },
+                SQLExpr::Rollup(items) | SQLExpr::Cube(items) => {
+                    has_sets = true;
+                    let items = items
+                        .iter()
+                        .map(|item| key_indices(self, item))
+                        .collect::<PolarsResult<Vec<_>>>()?;
+                    let item_sets = if matches!(e, SQLExpr::Rollup(_)) {
+                        GroupingSets::rollup(items.len())
+                    } else {
+                        GroupingSets::cube(items.len())?
+                    };
+                    item_sets
+                        .sets()
+                        .iter()
+                        .map(|set| set.iter().flat_map(|i| items[*i].clone()).collect())
+                        .collect()
+                },
+                e => vec![key_indices(self, std::slice::from_ref(e))?],
+            };
+            sets = sets
+                .iter()
+                .flat_map(|set| {
+                    alternatives.iter().map(move |alternative| {
+                        let mut set = set.clone();
+                        set.extend(alternative);
+                        set.sort_unstable();
+                        set.dedup();
+                        set
+                    })
+                })
+                .collect();
+        }
+        let sets = has_sets.then(|| GroupingSets::new(sets));
+        Ok((keys, sets))
+    }
+
     fn process_group_by(
         &mut self,
         mut lf: LazyFrame,
         group_by_keys: &[Expr],
+        grouping_sets: Option<&GroupingSets>,
         projections: &[Expr],
     ) -> PolarsResult<LazyFrame> {
-        let schema_before = self.get_frame_schema(&mut lf)?;
-        let group_by_keys_schema =
+        let mut schema_before = self.get_frame_schema(&mut lf)?;
+        let mut group_by_keys_schema =
             expressions_to_schema(group_by_keys, &schema_before, Context::Default)?;
+        polars_ensure!(
+            grouping_sets.is_some() || self.grouping_calls.borrow().is_empty(),
+            SQLSyntax: "GROUPING is only supported in the SELECT list of a query with GROUPING SETS, ROLLUP or CUBE"
+        );
+        let mut groupings = vec![];
+        if grouping_sets.is_some() {
+            let mut names = vec![];
+            // The grouping id and the `GROUPING` calls are available in the projections as if
+            // they were group by keys.
+            let grouping_calls = self.grouping_calls.take();
+            for (idx, keys) in grouping_calls.iter().enumerate() {
+                let key_indices = keys
+                    .iter()
+                    .map(|key| {
+                        group_by_keys.iter().position(|k| k == key).ok_or_else(|| {
+                            polars_err!(SQLSyntax: "GROUPING argument {:?} is not a GROUP BY key", key)
+                        })
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                let name = format_pl_smallstr!("__POLARS_SQL_GROUPING_{}", idx);
+                names.push(name.clone());
+                groupings
+                    .push(GroupingSets::grouping(&key_indices, group_by_keys.len())?.alias(name));
+            }
+            names.push(PlSmallStr::from_static(GROUPING_ID));
+            for name in names {
+                Arc::make_mut(&mut schema_before).insert(name.clone(), DataType::UInt64);
+                group_by_keys_schema.insert(name, DataType::UInt64);
+            }
+        }
 
         // Remove the group_by keys as polars adds those implicitly.
         let mut aggregation_projection = Vec::with_capacity(projections.len());
@@ -1305,10 +3275,7 @@ impl SQLContext {
         let mut group_key_aliases = PlHashSet::new();
 
         for mut e in projections {
-            // `Len` represents COUNT(*) so we treat as an aggregation here.
-            let is_agg_or_window = has_expr(e, |e| {
-                matches!(e, Expr::Agg(_) | Expr::Len | Expr::Window { .. })
-            });
+            let is_agg_or_window = has_expr(e, |e| is_agg(e) || matches!(e, Expr::Window { .. }));
 
             // Note: if simple aliased expression we defer aliasing until after the group_by.
             if let Expr::Alias(expr, alias) = e {
@@ -1349,7 +3316,13 @@ impl SQLContext {
                 }
             }
         }
-        let aggregated = lf.group_by(group_by_keys).agg(&aggregation_projection);
+        let aggregated = match grouping_sets {
+            Some(sets) => lf
+                .group_by(group_by_keys)
+                .agg_grouping_sets(sets, &aggregation_projection)?
+                .with_columns(groupings),
+            None => lf.group_by(group_by_keys).agg(&aggregation_projection),
+        };
         let projection_schema =
             expressions_to_schema(projections, &schema_before, Context::Default)?;
 
@@ -1380,6 +3353,22 @@ impl SQLContext {
         limit: &Option<SQLExpr>,
         offset: &Option<Offset>,
     ) -> PolarsResult<LazyFrame> {
+        let is_placeholder = |e: &SQLExpr| matches!(e, SQLExpr::Value(SQLValue::Placeholder(_)));
+        if limit.as_ref().is_some_and(is_placeholder)
+            || offset.as_ref().is_some_and(|o| is_placeholder(&o.value))
+        {
+            // bind parameters only have values once the query is executed, so the
+            // frame is sliced by an expression
+            let offset = match offset {
+                Some(o) => self.limit_offset_expr(&o.value, &DataType::Int64, "OFFSET")?,
+                None => lit(0i64),
+            };
+            let length = match limit {
+                Some(l) => self.limit_offset_expr(l, &IDX_DTYPE, "LIMIT")?,
+                None => lit(IdxSize::MAX),
+            };
+            return Ok(lf.select([all().slice(offset, length)]));
+        }
         match (offset, limit) {
             (
                 Some(Offset {
@@ -1419,6 +3408,26 @@ impl SQLContext {
         }
     }
 
+    /// The expression for a (numeric or bind parameter) LIMIT/OFFSET value.
+    fn limit_offset_expr(&self, e: &SQLExpr, dtype: &DataType, clause: &str) -> PolarsResult<Expr> {
+        match e {
+            SQLExpr::Value(SQLValue::Placeholder(p)) if self.allow_parameters => {
+                Ok(typed_parameter(parameter_expr(p), dtype))
+            },
+            SQLExpr::Value(SQLValue::Placeholder(p)) => {
+                polars_bail!(SQLInterface: "bind parameter '{}' requires a prepared statement (see `SQLContext::prepare`)", p)
+            },
+            SQLExpr::Value(SQLValue::Number(n, _)) => n
+                .parse::<i64>()
+                .map_err(|e| polars_err!(SQLInterface: "{} conversion error: {}", clause, e))
+                .and_then(|v| Scalar::from(v).cast_with_options(dtype, CastOptions::Strict))
+                .map(lit),
+            _ => polars_bail!(
+                SQLSyntax: "non-numeric arguments for LIMIT/OFFSET are not supported",
+            ),
+        }
+    }
+
     fn process_qualified_wildcard(
         &mut self,
         ObjectName(idents): &ObjectName,
@@ -1504,6 +3513,151 @@ impl SQLContext {
         Ok(exprs)
     }
 
+    /// Register the result of a `PIVOT` or `UNPIVOT` under its alias (if any), as is done
+    /// for derived tables.
+    fn register_table_factor_result(
+        &mut self,
+        name: String,
+        mut lf: LazyFrame,
+        alias: Option<&TableAlias>,
+    ) -> PolarsResult<(String, LazyFrame)> {
+        match alias {
+            Some(alias) => {
+                lf = self.rename_columns_from_table_alias(lf, alias)?;
+                self.table_map.insert(alias.name.value.clone(), lf.clone());
+                Ok((alias.name.value.clone(), lf))
+            },
+            None => Ok((name, lf)),
+        }
+    }
+
+    // <tbl> PIVOT (<agg>(...) [AS <alias>], ... FOR <col> IN (<value> [AS <alias>], ...))
+    //
+    // The output has one column per listed value (and aggregate), so the pivot can be done
+    // lazily: the rows are grouped by the columns that the pivot does not refer to, and every
+    // output column aggregates the rows of its group that have the corresponding value.
+    fn execute_pivot(
+        &mut self,
+        mut lf: LazyFrame,
+        aggregate_functions: &[ExprWithAlias],
+        value_column: &[Ident],
+        value_source: &PivotValueSource,
+        default_on_null: Option<&SQLExpr>,
+    ) -> PolarsResult<LazyFrame> {
+        let [value_column] = value_column else {
+            polars_bail!(SQLInterface: "PIVOT expects a single value column; found {}", value_column.len());
+        };
+        let PivotValueSource::List(values) = value_source else {
+            polars_bail!(SQLInterface: "PIVOT requires an explicit list of values; found {}", value_source);
+        };
+        let schema = self.get_frame_schema(&mut lf)?;
+        let pivot_col = PlSmallStr::from_str(value_column.value.as_str());
+        polars_ensure!(
+            schema.contains(&pivot_col),
+            ColumnNotFound: "PIVOT column '{}' does not exist", pivot_col
+        );
+        let aggs = aggregate_functions
+            .iter()
+            .map(|agg| {
+                let expr = parse_sql_expr(&agg.expr, self, Some(&schema))?;
+                // the columns are named after the value, suffixed by the aggregate if needed
+                let suffix = match &agg.alias {
+                    Some(alias) => Some(alias.value.clone()),
+                    None if aggregate_functions.len() > 1 => Some(agg.expr.to_string()),
+                    None => None,
+                };
+                Ok((expr, suffix))
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let default = default_on_null
+            .map(|expr| parse_sql_expr(expr, self, Some(&schema)))
+            .transpose()?;
+
+        let mut used = PlHashSet::new();
+        used.insert(pivot_col.clone());
+        for (expr, _) in &aggs {
+            used.extend(expr_to_leaf_column_names_iter(expr));
+        }
+        let keys = schema
+            .iter_names()
+            .filter(|name| !used.contains(*name))
+            .map(|name| col(name.clone()))
+            .collect::<Vec<_>>();
+
+        let mut exprs = vec![];
+        for value in values {
+            let value_name = match (&value.alias, &value.expr) {
+                (Some(alias), _) => alias.value.clone(),
+                (None, SQLExpr::Value(SQLValue::SingleQuotedString(s))) => s.clone(),
+                (None, expr) => expr.to_string(),
+            };
+            let is_value = col(pivot_col.clone()).eq(parse_sql_expr(&value.expr, self, None)?);
+            for (agg, suffix) in &aggs {
+                let name = match suffix {
+                    Some(suffix) => format_pl_smallstr!("{}_{}", value_name, suffix),
+                    None => PlSmallStr::from_str(value_name.as_str()),
+                };
+                // aggregate over the rows with the value (and null if there are none)
+                let agg = agg.clone().map_expr(|e| match e {
+                    Expr::Column(name) => col(name).filter(is_value.clone()),
+                    Expr::Len => col(pivot_col.clone()).filter(is_value.clone()).len(),
+                    e => e,
+                });
+                let mut expr = when(is_value.clone().any(true))
+                    .then(agg)
+                    .otherwise(lit(NULL));
+                if let Some(default) = &default {
+                    expr = expr.fill_null(default.clone());
+                }
+                exprs.push(expr.alias(name));
+            }
+        }
+        Ok(if keys.is_empty() {
+            lf.select(exprs)
+        } else {
+            lf.group_by_stable(keys).agg(exprs)
+        })
+    }
+
+    // <tbl> UNPIVOT (<value> FOR <name> IN (<col>, ...))
+    fn execute_unpivot(
+        &mut self,
+        mut lf: LazyFrame,
+        value: &Ident,
+        name: &Ident,
+        columns: &[Ident],
+    ) -> PolarsResult<LazyFrame> {
+        const ROW: PlSmallStr = PlSmallStr::from_static("__POLARS_SQL_UNPIVOT_ROW");
+        let schema = self.get_frame_schema(&mut lf)?;
+        let on = columns
+            .iter()
+            .map(|c| PlSmallStr::from_str(c.value.as_str()))
+            .collect::<Vec<_>>();
+        if let Some(missing) = on.iter().find(|c| !schema.contains(c)) {
+            polars_bail!(ColumnNotFound: "UNPIVOT column '{}' does not exist", missing);
+        }
+        let index = std::iter::once(ROW)
+            .chain(schema.iter_names().filter(|c| !on.contains(c)).cloned())
+            .collect::<Vec<_>>();
+        let value_name = PlSmallStr::from_str(value.value.as_str());
+        let args = UnpivotArgsDSL {
+            on: on.into_iter().map(Selector::from).collect(),
+            index: index.iter().cloned().map(Selector::from).collect(),
+            variable_name: Some(PlSmallStr::from_str(name.value.as_str())),
+            value_name: Some(value_name.clone()),
+        };
+        // keep the values of each row together (in the order of the columns), omitting nulls
+        Ok(lf
+            .with_row_index(ROW, None)
+            .unpivot(args)
+            .filter(col(value_name).is_not_null())
+            .sort(
+                [ROW],
+                SortMultipleOptions::default().with_maintain_order(true),
+            )
+            .drop([ROW]))
+    }
+
     fn rename_columns_from_table_alias(
         &mut self,
         mut lf: LazyFrame,
@@ -1543,6 +3697,167 @@ impl SQLContext {
     }
 }
 
+/// Parse a single SQL statement, numbering its `?` placeholders like `$1`, `$2`, ...
+fn parse_statement(query: &str) -> PolarsResult<Statement> {
+    let mut tokens = Tokenizer::new(&GenericDialect, query)
+        .with_unescape(true)
+        .tokenize_with_location()
+        .map_err(to_sql_interface_err)?;
+    let mut n_anonymous = 0;
+    let mut numbered = None;
+    for token in &mut tokens {
+        if let Token::Placeholder(p) = &mut token.token {
+            if p == "?" {
+                n_anonymous += 1;
+                *p = format!("${n_anonymous}");
+            } else if p.starts_with('$') && numbered.is_none() {
+                numbered = Some(p.clone());
+            }
+        }
+    }
+    if let (true, Some(p)) = (n_anonymous > 0, numbered) {
+        polars_bail!(SQLSyntax: "cannot mix '?' and '{}' placeholders", p);
+    }
+    // With trailing commas, a comma followed by a keyword such as `LATERAL` ends the list it is
+    // in; a statement that doesn't parse that way (eg: `FROM t, LATERAL (...)`) is parsed again
+    // without them, keeping the error of the first attempt if that fails too.
+    let parse = |trailing_commas| {
+        Parser::new(&GenericDialect)
+            .with_options(ParserOptions {
+                trailing_commas,
+                ..Default::default()
+            })
+            .with_tokens_with_locations(tokens.clone())
+            .parse_statements()
+    };
+    let mut ast = parse(true)
+        .or_else(|err| parse(false).map_err(|_| err))
+        .map_err(to_sql_interface_err)?;
+
+    polars_ensure!(ast.len() == 1, SQLInterface: "one (and only one) statement can be parsed at a time");
+    Ok(ast.pop().unwrap())
+}
+
+/// The columns that an `INSERT` provides values for (defaulting to all the table columns).
+fn insert_columns(columns: &[Ident], schema: &Schema) -> PolarsResult<Vec<PlSmallStr>> {
+    if columns.is_empty() {
+        return Ok(schema.iter_names().cloned().collect());
+    }
+    let mut names = PlHashSet::with_capacity(columns.len());
+    columns
+        .iter()
+        .map(|c| {
+            let name = PlSmallStr::from_str(c.value.as_str());
+            polars_ensure!(
+                schema.contains(&name),
+                ColumnNotFound: "column '{}' does not exist in the target table", name
+            );
+            polars_ensure!(
+                names.insert(name.clone()),
+                SQLSyntax: "column '{}' is specified more than once", name
+            );
+            Ok(name)
+        })
+        .collect()
+}
+
+/// The columns of the inserted rows; columns without a value are null.
+fn insert_exprs(schema: &Schema, mut values: PlHashMap<PlSmallStr, Expr>) -> Vec<Expr> {
+    schema
+        .iter()
+        .map(|(name, dtype)| {
+            values
+                .remove(name)
+                .unwrap_or(lit(NULL))
+                .strict_cast(dtype.clone())
+                .alias(name.clone())
+        })
+        .collect()
+}
+
+/// The grouping sets implied by the `WITH ROLLUP` and `WITH CUBE` modifiers of a GROUP BY clause.
+fn modifier_grouping_sets(
+    modifiers: &[GroupByWithModifier],
+    n_keys: usize,
+) -> PolarsResult<Option<GroupingSets>> {
+    match modifiers {
+        [] => Ok(None),
+        [GroupByWithModifier::Rollup] => Ok(Some(GroupingSets::rollup(n_keys))),
+        [GroupByWithModifier::Cube] => Ok(Some(GroupingSets::cube(n_keys)?)),
+        _ => polars_bail!(SQLInterface: "GROUP BY does not support TOTALS or multiple modifiers"),
+    }
+}
+
+/// Whether the query reads from a table or CTE with the given name.
+fn query_references_table(query: &Query, name: &str) -> bool {
+    query.with.as_ref().is_some_and(|with| {
+        with.cte_tables
+            .iter()
+            .any(|cte| query_references_table(&cte.query, name))
+    }) || set_expr_references_table(&query.body, name)
+}
+
+fn set_expr_references_table(expr: &SetExpr, name: &str) -> bool {
+    match expr {
+        SetExpr::Select(select) => {
+            select
+                .from
+                .iter()
+                .any(|tbl| table_with_joins_references_table(tbl, name))
+                || select
+                    .selection
+                    .as_ref()
+                    .is_some_and(|e| sql_expr_references_table(e, name))
+        },
+        SetExpr::Query(query) => query_references_table(query, name),
+        SetExpr::SetOperation { left, right, .. } => {
+            set_expr_references_table(left, name) || set_expr_references_table(right, name)
+        },
+        SetExpr::Table(tbl) => tbl.table_name.as_deref() == Some(name),
+        _ => false,
+    }
+}
+
+fn table_with_joins_references_table(tbl: &TableWithJoins, name: &str) -> bool {
+    table_factor_references_table(&tbl.relation, name)
+        || tbl
+            .joins
+            .iter()
+            .any(|join| table_factor_references_table(&join.relation, name))
+}
+
+fn table_factor_references_table(relation: &TableFactor, name: &str) -> bool {
+    match relation {
+        TableFactor::Table { name: tbl, .. } => {
+            tbl.0.first().is_some_and(|ident| ident.value == name)
+        },
+        TableFactor::Derived { subquery, .. } => query_references_table(subquery, name),
+        TableFactor::NestedJoin {
+            table_with_joins, ..
+        } => table_with_joins_references_table(table_with_joins, name),
+        _ => false,
+    }
+}
+
+fn sql_expr_references_table(expr: &SQLExpr, name: &str) -> bool {
+    match expr {
+        SQLExpr::Subquery(query)
+        | SQLExpr::Exists {
+            subquery: query, ..
+        } => query_references_table(query, name),
+        SQLExpr::InSubquery { expr, subquery, .. } => {
+            sql_expr_references_table(expr, name) || query_references_table(subquery, name)
+        },
+        SQLExpr::BinaryOp { left, right, .. } => {
+            sql_expr_references_table(left, name) || sql_expr_references_table(right, name)
+        },
+        SQLExpr::UnaryOp { expr, .. } | SQLExpr::Nested(expr) => {
+            sql_expr_references_table(expr, name)
+        },
+        _ => false,
+    }
+}
+
 fn collect_compound_identifiers(
     left: &[Ident],
     right: &[Ident],
@@ -1590,37 +3905,429 @@ fn is_regex_colname(nm: &str) -> bool {
     nm.starts_with('^') && nm.ends_with('$')
 }
 
-fn process_join_on(
-    expression: &sqlparser::ast::Expr,
-    tbl_left: &TableInfo,
-    tbl_right: &TableInfo,
-) -> PolarsResult<(Vec<Expr>, Vec<Expr>)> {
-    match expression {
-        SQLExpr::BinaryOp { left, op, right } => match op {
-            BinaryOperator::And => {
-                let (mut left_i, mut right_i) = process_join_on(left, tbl_left, tbl_right)?;
-                let (mut left_j, mut right_j) = process_join_on(right, tbl_left, tbl_right)?;
-                left_i.append(&mut left_j);
-                right_i.append(&mut right_j);
-                Ok((left_i, right_i))
+bitflags::bitflags! {
+    /// The tables referred to by an expression in a join condition.
+    #[derive(Clone, Copy, PartialEq)]
+    struct JoinExprOrigin: u8 {
+        const LEFT = 1 << 0;
+        const RIGHT = 1 << 1;
+        const BOTH = Self::LEFT.bits() | Self::RIGHT.bits();
+    }
+}
+
+/// The conditions of a join, split by the tables they refer to.
+#[derive(Default)]
+struct JoinConditions {
+    /// Equi-join keys, on the left and right table respectively.
+    left_on: Vec<Expr>,
+    right_on: Vec<Expr>,
+    /// Predicates that refer to both tables; right columns that also exist in the left table
+    /// are referred to by their suffixed name.
+    predicates: Vec<Expr>,
+    /// The suffixed names of the right columns that also exist in the left table.
+    suffixed_right_names: PlHashMap<PlSmallStr, PlSmallStr>,
+    /// Conditions that only refer to one of the tables.
+    left_filters: Vec<Expr>,
+    right_filters: Vec<Expr>,
+}
+
+impl JoinConditions {
+    fn is_equi_join(&self) -> bool {
+        self.predicates.is_empty() && self.left_filters.is_empty() && self.right_filters.is_empty()
+    }
+}
+
+/// A correlated subquery, without its conditions on the columns of the outer query.
+#[derive(Clone)]
+struct CorrelatedSubquery {
+    query: Query,
+    /// The `SELECT` of the query, which only keeps the conditions on its own columns.
+    select: Select,
+    /// The inner and outer sides of the correlated equalities.
+    inner_keys: Vec<SQLExpr>,
+    outer_keys: Vec<SQLExpr>,
+    /// The outer expressions of the other correlated conditions, which refer to them as
+    /// `__POLARS_SQL_OUTER_VALUES_<i>` (and are also part of the keys).
+    outer_values: Vec<SQLExpr>,
+    /// The translated projection, if the subquery selects a single expression.
+    value: Option<Expr>,
+    /// Whether the projection aggregates the rows of the subquery.
+    is_aggregate: bool,
+}
+
+impl CorrelatedSubquery {
+    /// The single projected expression of the subquery.
+    fn value_expr(&self) -> PolarsResult<SQLExpr> {
+        match self.select.projection.as_slice() {
+            [SelectItem::UnnamedExpr(e) | SelectItem::ExprWithAlias { expr: e, .. }] => {
+                Ok(e.clone())
             },
-            BinaryOperator::Eq => match (left.as_ref(), right.as_ref()) {
-                (SQLExpr::CompoundIdentifier(left), SQLExpr::CompoundIdentifier(right)) => {
-                    collect_compound_identifiers(left, right, &tbl_left.name, &tbl_right.name)
-                },
-                _ => {
-                    polars_bail!(SQLInterface: "only equi-join constraints (on identifiers) are currently supported; found lhs={:?}, rhs={:?}", left, right)
+            _ => polars_bail!(SQLSyntax: "SQL subquery returns more than one column"),
+        }
+    }
+
+    /// Drop the `ORDER BY` of a subquery whose order is irrelevant, which requires that it
+    /// has no `LIMIT` or `OFFSET`.
+    fn unordered(mut self) -> PolarsResult<Self> {
+        polars_ensure!(
+            self.query.limit.is_none() && self.query.offset.is_none() && self.query.fetch.is_none(),
+            SQLInterface: "LIMIT and OFFSET are not supported in correlated subqueries"
+        );
+        self.query.order_by = None;
+        Ok(self)
+    }
+
+    /// Aggregate the subquery per group of correlated keys.
+    fn group_by_inner_keys(&mut self) -> PolarsResult<()> {
+        polars_ensure!(
+            matches!(&self.select.group_by, GroupByExpr::Expressions(keys, _) if keys.is_empty()),
+            SQLInterface: "GROUP BY is not supported in correlated subqueries"
+        );
+        self.select.group_by = GroupByExpr::Expressions(self.inner_keys.clone(), vec![]);
+        Ok(())
+    }
+}
+
+/// Resolve the named window that a window specification refers to, if any.
+fn resolve_window_spec<'a>(
+    spec: &'a WindowSpec,
+    named_windows: &PlHashMap<String, WindowSpec>,
+) -> PolarsResult<Cow<'a, WindowSpec>> {
+    let Some(name) = &spec.window_name else {
+        return Ok(Cow::Borrowed(spec));
+    };
+    let Some(base) = named_windows.get(&name.value) else {
+        polars_bail!(SQLSyntax: "window '{}' is not defined", name)
+    };
+    polars_ensure!(
+        spec.partition_by.is_empty(),
+        SQLSyntax: "cannot override PARTITION BY of window '{}'", name
+    );
+    polars_ensure!(
+        spec.order_by.is_empty() || base.order_by.is_empty(),
+        SQLSyntax: "cannot override ORDER BY of window '{}'", name
+    );
+    Ok(Cow::Owned(WindowSpec {
+        window_name: None,
+        partition_by: base.partition_by.clone(),
+        order_by: if spec.order_by.is_empty() {
+            base.order_by.clone()
+        } else {
+            spec.order_by.clone()
+        },
+        window_frame: spec
+            .window_frame
+            .clone()
+            .or_else(|| base.window_frame.clone()),
+    }))
+}
+
+/// Combine the comma-separated tables of a `FROM` clause into one table with cross joins, which
+/// the optimizer turns into inner joins on the equality conditions of the `WHERE` clause.
+fn implicit_cross_joins(from: &[TableWithJoins]) -> Cow<'_, TableWithJoins> {
+    match from {
+        [tbl_expr] => Cow::Borrowed(tbl_expr),
+        [first, rest @ ..] => {
+            let mut tbl_expr = first.clone();
+            for other in rest {
+                tbl_expr.joins.push(Join {
+                    relation: other.relation.clone(),
+                    global: false,
+                    join_operator: JoinOperator::CrossJoin,
+                });
+                tbl_expr.joins.extend(other.joins.iter().cloned());
+            }
+            Cow::Owned(tbl_expr)
+        },
+        [] => unreachable!(),
+    }
+}
+
+/// The names by which the tables in a `FROM` clause can be referred to.
+fn relation_names(tbl_expr: &TableWithJoins) -> Vec<String> {
+    std::iter::once(&tbl_expr.relation)
+        .chain(tbl_expr.joins.iter().map(|join| &join.relation))
+        .filter_map(|relation| match relation {
+            TableFactor::Table {
+                alias: Some(alias), ..
+            }
+            | TableFactor::Derived {
+                alias: Some(alias), ..
+            } => Some(alias.name.value.clone()),
+            TableFactor::Table { name, .. } => name.0.first().map(|ident| ident.value.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Whether the expression (node) aggregates its input; `Len` represents `COUNT(*)` and
+/// aggregate UDFs are functions returning a scalar.
+fn is_agg(expr: &Expr) -> bool {
+    match expr {
+        Expr::Agg(_) | Expr::Len => true,
+        Expr::AnonymousFunction { options, .. } => options.returns_scalar(),
+        _ => false,
+    }
+}
+
+fn strip_alias(expr: &Expr) -> &Expr {
+    match expr {
+        Expr::Alias(expr, _) => expr,
+        expr => expr,
+    }
+}
+
+/// Split a condition into its `AND`-ed conditions.
+fn split_conjuncts<'a>(expr: &'a SQLExpr, conjuncts: &mut Vec<&'a SQLExpr>) {
+    match expr {
+        SQLExpr::BinaryOp {
+            left,
+            op: BinaryOperator::And,
+            right,
+        } => {
+            split_conjuncts(left, conjuncts);
+            split_conjuncts(right, conjuncts);
+        },
+        SQLExpr::Nested(expr) => split_conjuncts(expr, conjuncts),
+        expr => conjuncts.push(expr),
+    }
+}
+
+/// Split a join condition into its `AND`-ed conditions, expanding `BETWEEN` into its bounds so
+/// that range joins can be executed as inequality joins.
+fn split_join_conjuncts<'a>(expr: &'a SQLExpr, conjuncts: &mut Vec<Cow<'a, SQLExpr>>) {
+    match expr {
+        SQLExpr::BinaryOp {
+            left,
+            op: BinaryOperator::And,
+            right,
+        } => {
+            split_join_conjuncts(left, conjuncts);
+            split_join_conjuncts(right, conjuncts);
+        },
+        SQLExpr::Nested(expr) => split_join_conjuncts(expr, conjuncts),
+        SQLExpr::Between {
+            expr,
+            negated: false,
+            low,
+            high,
+        } => {
+            for (op, bound) in [(BinaryOperator::GtEq, low), (BinaryOperator::LtEq, high)] {
+                conjuncts.push(Cow::Owned(SQLExpr::BinaryOp {
+                    left: expr.clone(),
+                    op,
+                    right: bound.clone(),
+                }));
+            }
+        },
+        expr => conjuncts.push(Cow::Borrowed(expr)),
+    }
+}
+
+/// The (possibly qualified) column identifiers in (the common forms of) a SQL expression.
+fn sql_expr_identifiers<'a>(expr: &'a SQLExpr, idents: &mut Vec<&'a [Ident]>) {
+    match expr {
+        SQLExpr::Identifier(ident) => idents.push(std::slice::from_ref(ident)),
+        SQLExpr::CompoundIdentifier(parts) => idents.push(parts),
+        expr => {
+            for e in sql_sub_exprs(expr) {
+                sql_expr_identifiers(e, idents);
+            }
+        },
+    }
+}
+
+/// The direct sub-expressions of (the common forms of) a SQL expression.
+fn sql_sub_exprs(expr: &SQLExpr) -> Vec<&SQLExpr> {
+    match expr {
+        SQLExpr::BinaryOp { left, right, .. }
+        | SQLExpr::Like {
+            expr: left,
+            pattern: right,
+            ..
+        }
+        | SQLExpr::ILike {
+            expr: left,
+            pattern: right,
+            ..
+        } => vec![left, right],
+        SQLExpr::UnaryOp { expr, .. }
+        | SQLExpr::Nested(expr)
+        | SQLExpr::Cast { expr, .. }
+        | SQLExpr::IsNull(expr)
+        | SQLExpr::IsNotNull(expr)
+        | SQLExpr::IsTrue(expr)
+        | SQLExpr::IsFalse(expr)
+        | SQLExpr::InSubquery { expr, .. } => vec![expr],
+        SQLExpr::Between {
+            expr, low, high, ..
+        } => vec![expr, low, high],
+        SQLExpr::InList { expr, list, .. } => std::iter::once(expr.as_ref()).chain(list).collect(),
+        SQLExpr::Case {
+            operand,
+            conditions,
+            results,
+            else_result,
+        } => operand
+            .iter()
+            .map(|e| e.as_ref())
+            .chain(conditions)
+            .chain(results)
+            .chain(else_result.iter().map(|e| e.as_ref()))
+            .collect(),
+        SQLExpr::Function(function) => match &function.args {
+            FunctionArguments::List(list) => list
+                .args
+                .iter()
+                .filter_map(|arg| match arg {
+                    FunctionArg::Unnamed(FunctionArgExpr::Expr(e)) => Some(e),
+                    _ => None,
+                })
+                .collect(),
+            _ => vec![],
+        },
+        _ => vec![],
+    }
+}
+
+/// Replace the (largest) sub-expressions of `expr` that only refer to outer columns by
+/// references to the columns of the distinct outer values, adding them to `outer_values`.
+fn replace_outer_exprs(
+    expr: &mut SQLExpr,
+    origin: &dyn Fn(&SQLExpr) -> (bool, bool),
+    outer_values: &mut Vec<SQLExpr>,
+) {
+    match origin(expr) {
+        (false, true) => {
+            let i = match outer_values.iter().position(|e| e == expr) {
+                Some(i) => i,
+                None => {
+                    outer_values.push(expr.clone());
+                    outer_values.len() - 1
                 },
-            },
-            _ => {
-                polars_bail!(SQLInterface: "only equi-join constraints (combined with 'AND') are currently supported; found op = '{:?}'", op)
-            },
+            };
+            *expr = SQLExpr::Identifier(Ident::new(format!("{OUTER_VALUES}_{i}")));
+        },
+        (true, true) => {
+            for e in sql_sub_exprs_mut(expr) {
+                replace_outer_exprs(e, origin, outer_values);
+            }
         },
-        SQLExpr::Nested(expr) => process_join_on(expr, tbl_left, tbl_right),
-        _ => {
-            polars_bail!(SQLInterface: "only equi-join constraints are currently supported; found expression = {:?}", expression)
+        _ => {},
+    }
+}
+
+/// The mutable counterpart of [`sql_sub_exprs`].
+fn sql_sub_exprs_mut(expr: &mut SQLExpr) -> Vec<&mut SQLExpr> {
+    match expr {
+        SQLExpr::BinaryOp { left, right, .. }
+        | SQLExpr::Like {
+            expr: left,
+            pattern: right,
+            ..
+        }
+        | SQLExpr::ILike {
+            expr: left,
+            pattern: right,
+            ..
+        } => vec![left, right],
+        SQLExpr::UnaryOp { expr, .. }
+        | SQLExpr::Nested(expr)
+        | SQLExpr::Cast { expr, .. }
+        | SQLExpr::IsNull(expr)
+        | SQLExpr::IsNotNull(expr)
+        | SQLExpr::IsTrue(expr)
+        | SQLExpr::IsFalse(expr)
+        | SQLExpr::InSubquery { expr, .. } => vec![expr],
+        SQLExpr::Between {
+            expr, low, high, ..
+        } => vec![expr, low, high],
+        SQLExpr::InList { expr, list, .. } => std::iter::once(expr.as_mut()).chain(list).collect(),
+        SQLExpr::Case {
+            operand,
+            conditions,
+            results,
+            else_result,
+        } => operand
+            .iter_mut()
+            .map(|e| e.as_mut())
+            .chain(conditions)
+            .chain(results)
+            .chain(else_result.iter_mut().map(|e| e.as_mut()))
+            .collect(),
+        SQLExpr::Function(function) => match &mut function.args {
+            FunctionArguments::List(list) => list
+                .args
+                .iter_mut()
+                .filter_map(|arg| match arg {
+                    FunctionArg::Unnamed(FunctionArgExpr::Expr(e)) => Some(e),
+                    _ => None,
+                })
+                .collect(),
+            _ => vec![],
         },
+        _ => vec![],
+    }
+}
+
+fn equi_join(
+    left: LazyFrame,
+    right: LazyFrame,
+    conditions: &JoinConditions,
+    join_type: JoinType,
+    suffix: PlSmallStr,
+) -> LazyFrame {
+    left.join_builder()
+        .with(right)
+        .left_on(conditions.left_on.clone())
+        .right_on(conditions.right_on.clone())
+        .how(join_type)
+        .suffix(suffix)
+        .coalesce(JoinCoalesce::KeepColumns)
+        .finish()
+}
+
+/// Inner join on all of the join conditions.
+fn join_matches(
+    mut left: LazyFrame,
+    mut right: LazyFrame,
+    conditions: &JoinConditions,
+    suffix: PlSmallStr,
+) -> LazyFrame {
+    if let Some(predicate) = conditions.left_filters.iter().cloned().reduce(Expr::and) {
+        left = left.filter(predicate);
+    }
+    if let Some(predicate) = conditions.right_filters.iter().cloned().reduce(Expr::and) {
+        right = right.filter(predicate);
+    }
+    if conditions.predicates.is_empty() {
+        return if conditions.left_on.is_empty() {
+            left.cross_join(right, Some(suffix))
+        } else {
+            equi_join(left, right, conditions, JoinType::Inner, suffix)
+        };
     }
+    // Equi-join keys become equality predicates, that are executed as part of the join.
+    let to_suffixed_names = |e: Expr| {
+        e.map_expr(|e| match e {
+            Expr::Column(name) => match conditions.suffixed_right_names.get(&name) {
+                Some(name) => Expr::Column(name.clone()),
+                None => Expr::Column(name),
+            },
+            e => e,
+        })
+    };
+    let predicates = conditions
+        .left_on
+        .iter()
+        .zip(&conditions.right_on)
+        .map(|(l, r)| l.clone().eq(to_suffixed_names(r.clone())))
+        .chain(conditions.predicates.iter().cloned())
+        .collect();
+    left.join_builder()
+        .with(right)
+        .suffix(suffix)
+        .join_where(predicates)
 }
 
 fn process_join_constraint(
@@ -1629,9 +4336,6 @@ fn process_join_constraint(
     tbl_right: &TableInfo,
 ) -> PolarsResult<(Vec<Expr>, Vec<Expr>)> {
     match constraint {
-        JoinConstraint::On(expr @ SQLExpr::BinaryOp { .. }) => {
-            process_join_on(expr, tbl_left, tbl_right)
-        },
         JoinConstraint::Using(idents) if !idents.is_empty() => {
             let using: Vec<Expr> = idents.iter().map(|id| col(id.value.as_str())).collect();
             Ok((using.clone(), using))
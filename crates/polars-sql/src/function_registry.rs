@@ -1,30 +1,255 @@
 //! This module defines a FunctionRegistry for supported SQL functions and UDFs.
 
+use std::sync::Arc;
+
+use polars_core::prelude::*;
 use polars_error::{PolarsResult, polars_bail};
+use polars_lazy::prelude::LazyFrame;
 use polars_plan::prelude::udf::UserDefinedFunction;
 pub use polars_plan::prelude::{Context, FunctionOptions};
+
 /// A registry that holds user defined functions.
 pub trait FunctionRegistry: Send + Sync {
     /// Register a function.
     fn register(&mut self, name: &str, fun: UserDefinedFunction) -> PolarsResult<()>;
     /// Call a user defined function.
     fn get_udf(&self, name: &str) -> PolarsResult<Option<UserDefinedFunction>>;
-    /// Check if a function is registered.
+    /// Check if a (scalar or aggregate) function is registered.
     fn contains(&self, name: &str) -> bool;
+
+    /// Register an aggregate function.
+    fn register_aggregate(
+        &mut self,
+        _name: &str,
+        _fun: UserDefinedAggregateFunction,
+    ) -> PolarsResult<()> {
+        polars_bail!(ComputeError: "'register_aggregate' not implemented on this registry")
+    }
+    /// Get a user defined aggregate function.
+    fn get_aggregate_udf(&self, _name: &str) -> PolarsResult<Option<UserDefinedAggregateFunction>> {
+        Ok(None)
+    }
+    /// Register a table function.
+    fn register_table_function(
+        &mut self,
+        _name: &str,
+        _fun: UserDefinedTableFunction,
+    ) -> PolarsResult<()> {
+        polars_bail!(ComputeError: "'register_table_function' not implemented on this registry")
+    }
+    /// Get a user defined table function.
+    fn get_table_udf(&self, _name: &str) -> PolarsResult<Option<UserDefinedTableFunction>> {
+        Ok(None)
+    }
+}
+
+type AggregateFn = dyn Fn(&[Column]) -> PolarsResult<Scalar> + Send + Sync;
+type TableFn = dyn Fn(&[Scalar]) -> PolarsResult<LazyFrame> + Send + Sync;
+
+/// A user defined aggregate function, reducing its arguments to a single value (per group).
+#[derive(Clone)]
+pub struct UserDefinedAggregateFunction {
+    /// name
+    pub name: PlSmallStr,
+    /// The function signature; the arguments are cast to these types.
+    pub input_fields: Vec<Field>,
+    /// The function output type.
+    pub return_type: DataType,
+    fun: Arc<AggregateFn>,
+}
+
+impl std::fmt::Debug for UserDefinedAggregateFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("UserDefinedAggregateFunction")
+            .field("name", &self.name)
+            .field("signature", &self.input_fields)
+            .field("return_type", &self.return_type)
+            .field("fun", &"<FUNC>")
+            .finish()
+    }
 }
 
-/// A default registry that does not support registering or calling functions.
-pub struct DefaultFunctionRegistry {}
+impl UserDefinedAggregateFunction {
+    /// Create a new UserDefinedAggregateFunction
+    pub fn new(
+        name: PlSmallStr,
+        input_fields: Vec<Field>,
+        return_type: DataType,
+        fun: impl Fn(&[Column]) -> PolarsResult<Scalar> + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            name,
+            input_fields,
+            return_type,
+            fun: Arc::new(fun),
+        }
+    }
+
+    /// Apply the function to (the values of one group of) its arguments.
+    pub fn evaluate(&self, args: &[Column]) -> PolarsResult<Scalar> {
+        (self.fun)(args)
+    }
+}
+
+/// A user defined table function, producing a table from its (constant) arguments.
+#[derive(Clone)]
+pub struct UserDefinedTableFunction {
+    /// name
+    pub name: PlSmallStr,
+    /// The function signature; the arguments are cast to these types.
+    pub input_fields: Vec<Field>,
+    /// The schema of the table returned by the function.
+    pub schema: SchemaRef,
+    fun: Arc<TableFn>,
+}
+
+impl std::fmt::Debug for UserDefinedTableFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("UserDefinedTableFunction")
+            .field("name", &self.name)
+            .field("signature", &self.input_fields)
+            .field("schema", &self.schema)
+            .field("fun", &"<FUNC>")
+            .finish()
+    }
+}
+
+impl UserDefinedTableFunction {
+    /// Create a new UserDefinedTableFunction
+    pub fn new(
+        name: PlSmallStr,
+        input_fields: Vec<Field>,
+        schema: SchemaRef,
+        fun: impl Fn(&[Scalar]) -> PolarsResult<LazyFrame> + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            name,
+            input_fields,
+            schema,
+            fun: Arc::new(fun),
+        }
+    }
+
+    /// Produce the table for the given arguments; these are cast to the function signature
+    /// and the result is checked against (and cast to) the function schema.
+    pub fn call(&self, args: &[Scalar]) -> PolarsResult<LazyFrame> {
+        polars_ensure!(
+            args.len() == self.input_fields.len(),
+            SQLInterface: "table function '{}' expects {} arguments; found {}", self.name, self.input_fields.len(), args.len()
+        );
+        let args = args
+            .iter()
+            .zip(&self.input_fields)
+            .map(|(arg, fld)| cast_scalar(arg, fld.dtype()))
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        let mut lf = (self.fun)(&args)?;
+        let schema = lf.collect_schema()?;
+        polars_ensure!(
+            schema.len() == self.schema.len()
+                && self.schema.iter_names().all(|name| schema.contains(name)),
+            SQLInterface: "table function '{}' returned columns {:?}; expected {:?}",
+            self.name, schema.iter_names().collect::<Vec<_>>(), self.schema.iter_names().collect::<Vec<_>>()
+        );
+        Ok(lf.select(
+            self.schema
+                .iter()
+                .map(|(name, dtype)| {
+                    let c = polars_lazy::dsl::col(name.clone());
+                    if schema.get(name) == Some(dtype) {
+                        c
+                    } else {
+                        c.strict_cast(dtype.clone())
+                    }
+                })
+                .collect::<Vec<_>>(),
+        ))
+    }
+}
+
+fn cast_scalar(value: &Scalar, dtype: &DataType) -> PolarsResult<Scalar> {
+    if value.dtype() == dtype || matches!(dtype, DataType::Unknown(_)) {
+        return Ok(value.clone());
+    }
+    let s = value
+        .clone()
+        .into_series(PlSmallStr::EMPTY)
+        .strict_cast(dtype)?;
+    Ok(Scalar::new(dtype.clone(), s.get(0)?.into_static()))
+}
+
+/// The default registry, holding the scalar, aggregate and table functions registered on it.
+///
+/// Function names are case-insensitive; registering a function replaces any function of the
+/// same name.
+#[derive(Clone, Default)]
+pub struct DefaultFunctionRegistry {
+    scalar: PlHashMap<PlSmallStr, UserDefinedFunction>,
+    aggregate: PlHashMap<PlSmallStr, UserDefinedAggregateFunction>,
+    table: PlHashMap<PlSmallStr, UserDefinedTableFunction>,
+}
+
+impl DefaultFunctionRegistry {
+    /// Create an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn remove(&mut self, name: &PlSmallStr) {
+        self.scalar.remove(name);
+        self.aggregate.remove(name);
+        self.table.remove(name);
+    }
+}
+
+fn normalize(name: &str) -> PlSmallStr {
+    PlSmallStr::from_string(name.to_lowercase())
+}
 
 impl FunctionRegistry for DefaultFunctionRegistry {
-    fn register(&mut self, _name: &str, _fun: UserDefinedFunction) -> PolarsResult<()> {
-        polars_bail!(ComputeError: "'register' not implemented on DefaultFunctionRegistry'")
+    fn register(&mut self, name: &str, fun: UserDefinedFunction) -> PolarsResult<()> {
+        let name = normalize(name);
+        self.remove(&name);
+        self.scalar.insert(name, fun);
+        Ok(())
+    }
+
+    fn get_udf(&self, name: &str) -> PolarsResult<Option<UserDefinedFunction>> {
+        Ok(self.scalar.get(&normalize(name)).cloned())
+    }
+
+    fn contains(&self, name: &str) -> bool {
+        let name = normalize(name);
+        self.scalar.contains_key(&name) || self.aggregate.contains_key(&name)
+    }
+
+    fn register_aggregate(
+        &mut self,
+        name: &str,
+        fun: UserDefinedAggregateFunction,
+    ) -> PolarsResult<()> {
+        let name = normalize(name);
+        self.remove(&name);
+        self.aggregate.insert(name, fun);
+        Ok(())
     }
 
-    fn get_udf(&self, _name: &str) -> PolarsResult<Option<UserDefinedFunction>> {
-        polars_bail!(ComputeError: "'get_udf' not implemented on DefaultFunctionRegistry'")
+    fn get_aggregate_udf(&self, name: &str) -> PolarsResult<Option<UserDefinedAggregateFunction>> {
+        Ok(self.aggregate.get(&normalize(name)).cloned())
     }
-    fn contains(&self, _name: &str) -> bool {
-        false
+
+    fn register_table_function(
+        &mut self,
+        name: &str,
+        fun: UserDefinedTableFunction,
+    ) -> PolarsResult<()> {
+        let name = normalize(name);
+        self.remove(&name);
+        self.table.insert(name, fun);
+        Ok(())
+    }
+
+    fn get_table_udf(&self, name: &str) -> PolarsResult<Option<UserDefinedTableFunction>> {
+        Ok(self.table.get(&normalize(name)).cloned())
     }
 }
@@ -2,26 +2,34 @@ use std::ops::Sub;
 
 use polars_core::chunked_array::ops::{SortMultipleOptions, SortOptions};
 use polars_core::prelude::{
-    DataType, PolarsResult, QuantileMethod, Schema, TimeUnit, polars_bail, polars_err,
+    Column, DataType, Field, IDX_DTYPE, IdxSize, PolarsResult, QuantileMethod,
+    RollingOptionsFixedWindow, Schema, TimeUnit, polars_bail, polars_ensure, polars_err,
 };
 use polars_lazy::dsl::Expr;
 #[cfg(feature = "list_eval")]
 use polars_lazy::dsl::ListNameSpaceExtension;
 use polars_ops::chunked_array::UnicodeForm;
-use polars_plan::dsl::{coalesce, concat_str, len, max_horizontal, min_horizontal, when};
-use polars_plan::plans::{DynLiteralValue, LiteralValue, typed_lit};
-use polars_plan::prelude::{StrptimeOptions, col, cols, lit};
+use polars_plan::dsl::{
+    GetOutput, apply_multiple, arg_sort_by, coalesce, concat_str, int_range, len, max_horizontal,
+    min_horizontal, when,
+};
+use polars_plan::plans::{DynLiteralValue, LiteralValue, NULL, typed_lit};
+use polars_plan::prelude::{Context, GROUPING_ID, StrptimeOptions, col, cols, lit};
+use polars_time::chunkedarray::RollingOptionsDynamicWindow;
+use polars_time::{ClosedWindow, Duration};
 use polars_utils::pl_str::PlSmallStr;
 use sqlparser::ast::helpers::attached_token::AttachedToken;
 use sqlparser::ast::{
     DateTimeField, DuplicateTreatment, Expr as SQLExpr, Function as SQLFunction, FunctionArg,
     FunctionArgExpr, FunctionArgumentClause, FunctionArgumentList, FunctionArguments, Ident,
-    OrderByExpr, Value as SQLValue, WindowSpec, WindowType,
+    OrderByExpr, Value as SQLValue, WindowFrameBound, WindowFrameUnits, WindowSpec, WindowType,
 };
 use sqlparser::tokenizer::Span;
 
 use crate::SQLContext;
-use crate::sql_expr::{adjust_one_indexed_param, parse_extract_date_part, parse_sql_expr};
+use crate::sql_expr::{
+    adjust_one_indexed_param, interval_to_duration, parse_extract_date_part, parse_sql_expr,
+};
 
 pub(crate) struct SQLFunctionVisitor<'a> {
     pub(crate) func: &'a SQLFunction,
@@ -525,6 +533,19 @@ pub(crate) enum PolarsSQLFunctions {
     /// SELECT FIRST(column_1) FROM df;
     /// ```
     First,
+    /// SQL 'grouping' function
+    /// Returns the bitmask of the given group keys that are aggregated over in the current row
+    /// of a `GROUPING SETS`, `ROLLUP` or `CUBE` query.
+    /// ```sql
+    /// SELECT a, b, SUM(c), GROUPING(a) FROM df GROUP BY ROLLUP(a, b);
+    /// ```
+    Grouping,
+    /// SQL 'grouping_id' function
+    /// Same as `GROUPING`.
+    /// ```sql
+    /// SELECT a, b, SUM(c), GROUPING_ID(a, b) FROM df GROUP BY ROLLUP(a, b);
+    /// ```
+    GroupingId,
     /// SQL 'last' function
     /// Returns the last element of the grouping.
     /// ```sql
@@ -720,6 +741,8 @@ impl PolarsSQLFunctions {
             "first",
             "floor",
             "greatest",
+            "grouping",
+            "grouping_id",
             "if",
             "ifnull",
             "initcap",
@@ -885,6 +908,8 @@ impl PolarsSQLFunctions {
             "avg" => Self::Avg,
             "count" => Self::Count,
             "first" => Self::First,
+            "grouping" => Self::Grouping,
+            "grouping_id" => Self::GroupingId,
             "last" => Self::Last,
             "max" => Self::Max,
             "median" => Self::Median,
@@ -943,6 +968,11 @@ impl SQLFunctionVisitor<'_> {
         if function.null_treatment.is_some() {
             polars_bail!(SQLInterface: "'IGNORE|RESPECT NULLS' is not currently supported")
         }
+        if let Some(agg) = FrameAggregation::from_function(&function_name) {
+            if let Some(expr) = self.visit_framed_aggregate(agg)? {
+                return Ok(expr);
+            }
+        }
 
         match function_name {
             // ----
@@ -1363,6 +1393,8 @@ impl SQLFunctionVisitor<'_> {
             Avg => self.visit_unary(Expr::mean),
             Count => self.visit_count(),
             First => self.visit_unary(Expr::first),
+            Grouping => self.visit_grouping("grouping"),
+            GroupingId => self.visit_grouping(GROUPING_ID),
             Last => self.visit_unary(Expr::last),
             Max => self.visit_unary_with_opt_cumulative(Expr::max, Expr::cum_max),
             Median => self.visit_unary(Expr::median),
@@ -1506,11 +1538,67 @@ impl SQLFunctionVisitor<'_> {
             })
             .collect::<PolarsResult<Vec<_>>>()?;
 
-        self.ctx
-            .function_registry
+        let registry = &self.ctx.function_registry;
+        if let Some(udf) = registry.get_aggregate_udf(func_name)? {
+            let args = self.typed_udf_args(func_name, args, &udf.input_fields)?;
+            let return_type = udf.return_type.clone();
+            let name = udf.name.clone();
+            let fun = move |c: &mut [Column]| {
+                let value = udf.evaluate(c)?;
+                Ok(Some(value.into_column(name.clone())))
+            };
+            // Applied group-wise, the function yields a single value per group.
+            return Ok(apply_multiple(
+                fun,
+                args,
+                GetOutput::from_type(return_type),
+                true,
+            ));
+        }
+        let udf = registry
             .get_udf(func_name)?
-            .ok_or_else(|| polars_err!(SQLInterface: "UDF {} not found", func_name))?
-            .call(args)
+            .ok_or_else(|| polars_err!(SQLInterface: "UDF {} not found", func_name))?;
+        let args = self.typed_udf_args(func_name, args, &udf.input_fields)?;
+        Ok(udf.call_unchecked(args))
+    }
+
+    /// Check the arguments of a UDF against its signature, casting them to the signature
+    /// types where needed (an `Unknown` type accepts any argument).
+    fn typed_udf_args(
+        &mut self,
+        func_name: &str,
+        args: Vec<Expr>,
+        input_fields: &[Field],
+    ) -> PolarsResult<Vec<Expr>> {
+        polars_ensure!(
+            args.len() == input_fields.len(),
+            SQLSyntax: "{} expects {} arguments; found {}", func_name.to_uppercase(), input_fields.len(), args.len()
+        );
+        args.into_iter()
+            .zip(input_fields)
+            .map(|(arg, fld)| {
+                let dtype = fld.dtype();
+                if matches!(dtype, DataType::Unknown(_)) {
+                    return Ok(arg);
+                }
+                let arg_field = self
+                    .active_schema
+                    .and_then(|schema| arg.to_field(schema, Context::Default).ok());
+                if let Some(Field {
+                    dtype: arg_dtype, ..
+                }) = arg_field
+                {
+                    if &arg_dtype == dtype {
+                        return Ok(arg);
+                    }
+                    polars_ensure!(
+                        arg_dtype.can_cast_to(dtype) != Some(false),
+                        SQLSyntax: "{} expects argument '{}' of type {}; found {}", func_name.to_uppercase(), fld.name(), dtype, arg_dtype
+                    );
+                }
+                Ok(arg.strict_cast(dtype.clone()))
+            })
+            .collect()
     }
 
     /// Window specs without partition bys are essentially cumulative functions
@@ -1583,14 +1671,11 @@ impl SQLFunctionVisitor<'_> {
         cumulative_f: impl Fn(Expr, bool) -> Expr,
     ) -> PolarsResult<Expr> {
         match self.func.over.as_ref() {
-            Some(WindowType::WindowSpec(spec)) => {
-                self.apply_cumulative_window(f, cumulative_f, spec)
+            Some(window_type) => {
+                let spec = self.ctx.window_spec(window_type)?;
+                self.apply_cumulative_window(f, cumulative_f, &spec)
             },
-            Some(WindowType::NamedWindow(named_window)) => polars_bail!(
-                SQLInterface: "Named windows are not currently supported; found {:?}",
-                named_window
-            ),
-            _ => self.visit_unary(f),
+            None => self.visit_unary(f),
         }
     }
 
@@ -1671,6 +1756,23 @@ impl SQLFunctionVisitor<'_> {
         }
     }
 
+    fn visit_grouping(&mut self, name: &'static str) -> PolarsResult<Expr> {
+        let args = extract_args(self.func)?;
+        polars_ensure!(
+            !args.is_empty(),
+            SQLSyntax: "{} requires the GROUP BY keys it refers to as arguments", name.to_uppercase()
+        );
+        let mut keys = vec![];
+        for arg in args {
+            if let FunctionArgExpr::Expr(sql_expr) = arg {
+                keys.push(parse_sql_expr(sql_expr, self.ctx, self.active_schema)?);
+            } else {
+                return self.not_supported_error();
+            };
+        }
+        Ok(col(self.ctx.add_grouping_call(keys)).alias(name))
+    }
+
     fn visit_nullary(&self, f: impl Fn() -> Expr) -> PolarsResult<Expr> {
         let args = extract_args(self.func)?;
         if !args.is_empty() {
@@ -1771,6 +1873,14 @@ impl SQLFunctionVisitor<'_> {
     }
 
     fn apply_order_by(&mut self, expr: Expr, order_by: &[OrderByExpr]) -> PolarsResult<Expr> {
+        let (by, sort_options) = self.parse_order_by(order_by)?;
+        Ok(expr.sort_by(by, sort_options))
+    }
+
+    fn parse_order_by(
+        &mut self,
+        order_by: &[OrderByExpr],
+    ) -> PolarsResult<(Vec<Expr>, SortMultipleOptions)> {
         let mut by = Vec::with_capacity(order_by.len());
         let mut descending = Vec::with_capacity(order_by.len());
         let mut nulls_last = Vec::with_capacity(order_by.len());
@@ -1783,13 +1893,11 @@ impl SQLFunctionVisitor<'_> {
             nulls_last.push(!ob.nulls_first.unwrap_or(desc_order));
             descending.push(desc_order);
         }
-        Ok(expr.sort_by(
-            by,
-            SortMultipleOptions::default()
-                .with_order_descending_multi(descending)
-                .with_nulls_last_multi(nulls_last)
-                .with_maintain_order(true),
-        ))
+        let sort_options = SortMultipleOptions::default()
+            .with_order_descending_multi(descending)
+            .with_nulls_last_multi(nulls_last)
+            .with_maintain_order(true);
+        Ok((by, sort_options))
     }
 
     fn apply_window_spec(
@@ -1798,7 +1906,8 @@ impl SQLFunctionVisitor<'_> {
         window_type: &Option<WindowType>,
     ) -> PolarsResult<Expr> {
         Ok(match &window_type {
-            Some(WindowType::WindowSpec(window_spec)) => {
+            Some(window_type) => {
+                let window_spec = self.ctx.window_spec(window_type)?;
                 if window_spec.partition_by.is_empty() {
                     let exprs = window_spec
                         .order_by
@@ -1821,14 +1930,147 @@ impl SQLFunctionVisitor<'_> {
                     expr.over(partition_by)
                 }
             },
-            Some(WindowType::NamedWindow(named_window)) => polars_bail!(
-                SQLInterface: "Named windows are not currently supported; found {:?}",
-                named_window
-            ),
             None => expr,
         })
     }
 
+    /// Evaluate an aggregate over the explicit frame of its window, if it has one.
+    ///
+    /// The frame is computed on the rows in window order, with the cumulative and rolling
+    /// functions, after which the result is put back in the original row order.
+    fn visit_framed_aggregate(&mut self, agg: FrameAggregation) -> PolarsResult<Option<Expr>> {
+        let Some(window_type) = &self.func.over else {
+            return Ok(None);
+        };
+        let spec = self.ctx.window_spec(window_type)?;
+        let Some(frame) = &spec.window_frame else {
+            return Ok(None);
+        };
+        let (args, is_distinct) = extract_args_distinct(self.func)?;
+        polars_ensure!(
+            !is_distinct,
+            SQLInterface: "DISTINCT is not supported with a window frame; found {}", self.func
+        );
+        let expr = match (agg, args.as_slice()) {
+            (FrameAggregation::Count, [FunctionArgExpr::Wildcard] | []) => {
+                int_range(lit(0 as IdxSize), len(), 1, IDX_DTYPE)
+            },
+            (_, [FunctionArgExpr::Expr(sql_expr)]) => {
+                parse_sql_expr(sql_expr, self.ctx, self.active_schema)?
+            },
+            _ => return self.not_supported_error().map(Some),
+        };
+        let partition_by = spec
+            .partition_by
+            .iter()
+            .map(|p| parse_sql_expr(p, self.ctx, self.active_schema))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let (order_by, sort_options) = self.parse_order_by(&spec.order_by)?;
+        let in_window_order = |e: Expr| {
+            if order_by.is_empty() {
+                e
+            } else {
+                e.sort_by(&order_by, sort_options.clone())
+            }
+        };
+
+        let start = &frame.start_bound;
+        let end = frame
+            .end_bound
+            .as_ref()
+            .unwrap_or(&WindowFrameBound::CurrentRow);
+        let unsupported_frame =
+            || polars_err!(SQLInterface: "unsupported window frame in ({})", spec);
+        let expr = match (frame.units, start, end) {
+            (WindowFrameUnits::Groups, _, _) => return Err(unsupported_frame()),
+            (_, WindowFrameBound::Preceding(None), WindowFrameBound::Following(None)) => {
+                agg.aggregate(expr)
+            },
+            // Without ORDER BY all rows are peers, so RANGE frames span the whole partition.
+            (WindowFrameUnits::Range, _, _) if order_by.is_empty() => agg.aggregate(expr),
+            (WindowFrameUnits::Rows, _, _) => {
+                let start = rows_frame_offset(start)?;
+                let end = rows_frame_offset(end)?;
+                let framed = match (start, end) {
+                    (Some(start), Some(end)) => {
+                        polars_ensure!(start <= end, SQLSyntax: "window frame starts after its end; found ({})", spec);
+                        agg.rolling_rows(in_window_order(expr), start, end)?
+                    },
+                    (None, Some(end)) => {
+                        let framed = agg.cumulative(in_window_order(expr), false)?;
+                        shift_frame(framed, end).forward_fill(None)
+                    },
+                    (Some(start), None) => {
+                        let framed = agg.cumulative(in_window_order(expr), true)?;
+                        shift_frame(framed, start).backward_fill(None)
+                    },
+                    (None, None) => return Err(unsupported_frame()),
+                };
+                agg.finish(framed)
+            },
+            (
+                WindowFrameUnits::Range,
+                WindowFrameBound::Preceding(None),
+                WindowFrameBound::CurrentRow,
+            ) => {
+                // The frame ends at the last peer of the current row.
+                let framed = agg.cumulative(in_window_order(expr), false)?;
+                let keys = order_by.iter().map(|e| in_window_order(e.clone()));
+                agg.finish(framed.gather(peer_index(keys, false)))
+            },
+            (
+                WindowFrameUnits::Range,
+                WindowFrameBound::CurrentRow,
+                WindowFrameBound::Following(None),
+            ) => {
+                // The frame starts at the first peer of the current row.
+                let framed = agg.cumulative(in_window_order(expr), true)?;
+                let keys = order_by.iter().map(|e| in_window_order(e.clone()));
+                agg.finish(framed.gather(peer_index(keys, true)))
+            },
+            (
+                WindowFrameUnits::Range,
+                WindowFrameBound::Preceding(Some(offset)),
+                WindowFrameBound::CurrentRow,
+            ) => {
+                polars_ensure!(
+                    order_by.len() == 1 && !sort_options.descending[0],
+                    SQLSyntax: "RANGE with an offset requires exactly one ascending ORDER BY column; found ({})", spec
+                );
+                let window_size = match &**offset {
+                    SQLExpr::Interval(interval) => interval_to_duration(interval, false)?,
+                    offset => Duration::parse(&format!("{}i", frame_offset_value(offset)?)),
+                };
+                let options = RollingOptionsDynamicWindow {
+                    window_size,
+                    min_periods: 1,
+                    closed_window: ClosedWindow::Both,
+                    fn_params: None,
+                };
+                let by = in_window_order(order_by[0].clone());
+                let framed = agg.rolling_by(in_window_order(expr), by.clone(), options);
+                // The frame ends at the last peer of the current row.
+                agg.finish(framed.gather(peer_index([by], false)))
+            },
+            _ => return Err(unsupported_frame()),
+        };
+
+        // Put the rows of a framed (non-aggregated) result back in their original order.
+        let expr = if matches!(expr, Expr::Agg(_)) || order_by.is_empty() {
+            expr
+        } else {
+            expr.sort_by(
+                [arg_sort_by(&order_by, sort_options)],
+                SortMultipleOptions::default(),
+            )
+        };
+        Ok(Some(if partition_by.is_empty() {
+            expr
+        } else {
+            expr.over(partition_by)
+        }))
+    }
+
     fn not_supported_error(&self) -> PolarsResult<Expr> {
         polars_bail!(
             SQLInterface:
@@ -1838,6 +2080,219 @@ impl SQLFunctionVisitor<'_> {
     }
 }
 
+/// Aggregate functions that can be evaluated over an explicit window frame.
+#[derive(Clone, Copy)]
+enum FrameAggregation {
+    Count,
+    Max,
+    Mean,
+    Min,
+    StdDev,
+    Sum,
+    Variance,
+}
+
+impl FrameAggregation {
+    fn from_function(function: &PolarsSQLFunctions) -> Option<Self> {
+        Some(match function {
+            PolarsSQLFunctions::Avg => Self::Mean,
+            PolarsSQLFunctions::Count => Self::Count,
+            PolarsSQLFunctions::Max => Self::Max,
+            PolarsSQLFunctions::Min => Self::Min,
+            PolarsSQLFunctions::StdDev => Self::StdDev,
+            PolarsSQLFunctions::Sum => Self::Sum,
+            PolarsSQLFunctions::Variance => Self::Variance,
+            _ => return None,
+        })
+    }
+
+    /// Aggregate all rows of the window.
+    fn aggregate(self, expr: Expr) -> Expr {
+        match self {
+            Self::Count => expr.count(),
+            Self::Max => expr.max(),
+            Self::Mean => expr.mean(),
+            Self::Min => expr.min(),
+            Self::StdDev => expr.std(1),
+            Self::Sum => expr.sum(),
+            Self::Variance => expr.var(1),
+        }
+    }
+
+    /// Aggregate the rows between the `start` and `end` offsets from the current row.
+    fn rolling_rows(self, expr: Expr, start: i64, end: i64) -> PolarsResult<Expr> {
+        let window_size = (end - start + 1) as usize;
+        if end <= 0 {
+            // The rolling window ends at the current row, so it is moved to end at `end`.
+            return Ok(shift_frame(self.rolling(expr, window_size), end));
+        }
+        match self {
+            Self::Mean => {
+                let sum = Self::Sum.rolling_rows(expr.clone(), start, end)?;
+                let count = Self::Count.rolling_rows(expr, start, end)?;
+                return Ok(sum.cast(DataType::Float64) / count.cast(DataType::Float64));
+            },
+            Self::StdDev | Self::Variance => {
+                polars_bail!(SQLInterface: "window frames ending after the current row are not supported for STDDEV and VARIANCE")
+            },
+            _ => {},
+        }
+        if start >= 0 {
+            // Near the last row the frame is cut off, and holds all rows from `start` on.
+            let framed = shift_frame(self.rolling(expr.clone(), window_size), end);
+            let remaining = shift_frame(self.cumulative(expr, true)?, start);
+            let row = int_range(lit(0 as IdxSize), len(), 1, IDX_DTYPE);
+            return Ok(when((row + lit(end)).lt(len()))
+                .then(framed)
+                .otherwise(remaining));
+        }
+        // Otherwise combine the preceding rows (and current row) with the following rows.
+        let preceding = self.rolling_rows(expr.clone(), start, 0)?;
+        let following = self.rolling_rows(expr, 1, end)?;
+        Ok(match self {
+            Self::Max => max_horizontal([preceding, following])?,
+            Self::Min => min_horizontal([preceding, following])?,
+            _ => coalesce(&[preceding.clone() + following.clone(), preceding, following]),
+        })
+    }
+
+    fn rolling(self, expr: Expr, window_size: usize) -> Expr {
+        let options = RollingOptionsFixedWindow {
+            window_size,
+            min_periods: 1,
+            ..Default::default()
+        };
+        match self {
+            Self::Count => expr.is_not_null().cast(IDX_DTYPE).rolling_sum(options),
+            Self::Max => expr.rolling_max(options),
+            Self::Mean => expr.rolling_mean(options),
+            Self::Min => expr.rolling_min(options),
+            Self::StdDev => expr.rolling_std(options),
+            Self::Sum => expr.rolling_sum(options),
+            Self::Variance => expr.rolling_var(options),
+        }
+    }
+
+    /// Aggregate the rows that are within `options.window_size` before the current row.
+    fn rolling_by(self, expr: Expr, by: Expr, options: RollingOptionsDynamicWindow) -> Expr {
+        // The rolling functions do not accept null values, so these are replaced by values that
+        // do not change the result, and frames without values are set to null.
+        let count = expr
+            .clone()
+            .is_not_null()
+            .cast(IDX_DTYPE)
+            .rolling_sum_by(by.clone(), options.clone());
+        let framed = match self {
+            Self::Count => return count,
+            Self::Max => expr
+                .clone()
+                .fill_null(expr.min())
+                .rolling_max_by(by, options),
+            Self::Mean => {
+                let sum = Self::Sum.rolling_by(expr, by, options);
+                return sum.cast(DataType::Float64) / count.cast(DataType::Float64);
+            },
+            Self::Min => expr
+                .clone()
+                .fill_null(expr.max())
+                .rolling_min_by(by, options),
+            Self::StdDev => return expr.rolling_std_by(by, options),
+            Self::Sum => expr.fill_null(lit(0)).rolling_sum_by(by, options),
+            Self::Variance => return expr.rolling_var_by(by, options),
+        };
+        when(count.gt(lit(0))).then(framed).otherwise(lit(NULL))
+    }
+
+    /// Aggregate the rows up to the current row, or from the current row if `reverse`.
+    fn cumulative(self, expr: Expr, reverse: bool) -> PolarsResult<Expr> {
+        let expr = match self {
+            Self::Count => expr.is_not_null().cast(IDX_DTYPE).cum_sum(reverse),
+            Self::Max => expr.cum_max(reverse),
+            Self::Mean => {
+                let sum = Self::Sum.cumulative(expr.clone(), reverse)?;
+                let count = Self::Count.cumulative(expr, reverse)?;
+                return Ok(sum.cast(DataType::Float64) / count.cast(DataType::Float64));
+            },
+            Self::Min => expr.cum_min(reverse),
+            Self::Sum => expr.cum_sum(reverse),
+            Self::StdDev | Self::Variance => {
+                polars_bail!(SQLInterface: "unbounded window frames are not supported for STDDEV and VARIANCE")
+            },
+        };
+        // The cumulative result is null on null rows, where it should carry the previous value.
+        Ok(if reverse {
+            expr.backward_fill(None)
+        } else {
+            expr.forward_fill(None)
+        })
+    }
+
+    /// A frame without rows counts zero rows.
+    fn finish(self, expr: Expr) -> Expr {
+        match self {
+            Self::Count => expr.fill_null(lit(0 as IdxSize)),
+            _ => expr,
+        }
+    }
+}
+
+/// The offset of a ROWS frame bound from the current row, or `None` if it is unbounded.
+fn rows_frame_offset(bound: &WindowFrameBound) -> PolarsResult<Option<i64>> {
+    Ok(match bound {
+        WindowFrameBound::CurrentRow => Some(0),
+        WindowFrameBound::Preceding(offset) => match offset {
+            Some(offset) => Some(-frame_offset_value(offset)?),
+            None => None,
+        },
+        WindowFrameBound::Following(offset) => match offset {
+            Some(offset) => Some(frame_offset_value(offset)?),
+            None => None,
+        },
+    })
+}
+
+fn frame_offset_value(offset: &SQLExpr) -> PolarsResult<i64> {
+    match offset {
+        SQLExpr::Value(SQLValue::Number(n, _)) => n
+            .parse::<i64>()
+            .ok()
+            .filter(|n| *n >= 0)
+            .ok_or_else(|| polars_err!(SQLSyntax: "window frame offset must be a non-negative integer; found {}", n)),
+        _ => polars_bail!(SQLSyntax: "window frame offset must be a non-negative integer; found {}", offset),
+    }
+}
+
+/// Move the frame results so that the result for a row is the one of the row at `offset`.
+fn shift_frame(expr: Expr, offset: i64) -> Expr {
+    if offset == 0 {
+        expr
+    } else {
+        expr.shift(lit(-offset))
+    }
+}
+
+/// The index of the last peer of each row (the first peer if `first`), where peers are
+/// consecutive rows with equal sort keys.
+fn peer_index(keys: impl IntoIterator<Item = Expr>, first: bool) -> Expr {
+    let neighbour = if first { 1 } else { -1 };
+    let same_as_neighbour = keys
+        .into_iter()
+        .map(|key| key.clone().eq_missing(key.shift(lit(neighbour))))
+        .reduce(Expr::and)
+        .unwrap_or(lit(false));
+    let index = when(same_as_neighbour).then(lit(NULL)).otherwise(int_range(
+        lit(0 as IdxSize),
+        len(),
+        1,
+        IDX_DTYPE,
+    ));
+    if first {
+        index.forward_fill(None)
+    } else {
+        index.backward_fill(None)
+    }
+}
+
 fn extract_args(func: &SQLFunction) -> PolarsResult<Vec<&FunctionArgExpr>> {
     let (args, _, _) = _extract_func_args(func, false, false)?;
     Ok(args)
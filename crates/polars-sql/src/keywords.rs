@@ -22,9 +22,11 @@ pub fn all_keywords() -> Vec<&'static str> {
         keywords::BOOLEAN,
         keywords::BY,
         keywords::CASE,
+        keywords::COLUMNS,
         keywords::CREATE,
         keywords::DATE,
         keywords::DATETIME,
+        keywords::DESCRIBE,
         keywords::DESC,
         keywords::DISTINCT,
         keywords::DOUBLE,
@@ -5,9 +5,11 @@ mod context;
 pub mod function_registry;
 mod functions;
 pub mod keywords;
+mod prepared;
 mod sql_expr;
 mod table_functions;
 mod types;
 
 pub use context::SQLContext;
+pub use prepared::SQLPreparedStatement;
 pub use sql_expr::sql_expr;
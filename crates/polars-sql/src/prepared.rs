@@ -0,0 +1,231 @@
+use polars_core::chunked_array::cast::CastOptions;
+use polars_core::prelude::*;
+use polars_core::utils::try_get_supertype;
+use polars_lazy::prelude::*;
+
+/// Prefix of the names that mark the bind parameters of a prepared query.
+const PARAMETER_PREFIX: &str = "__POLARS_SQL_PARAMETER:";
+
+/// The expression that stands in for a bind parameter (eg: `$1`, `?` or `:name`) until a
+/// value is bound to it.
+///
+/// The parameter is a null literal of the type of the parameter, which is `Null` until a
+/// type is declared or inferred for it (see [`typed_parameter`]).
+pub(crate) fn parameter_expr(placeholder: &str) -> Expr {
+    lit(NULL).alias(format!("{PARAMETER_PREFIX}{placeholder}"))
+}
+
+/// Give an untyped bind parameter the type `dtype`; other expressions are returned as-is.
+pub(crate) fn typed_parameter(expr: Expr, dtype: &DataType) -> Expr {
+    match parameter(&expr) {
+        Some((name, DataType::Null)) if !matches!(dtype, DataType::Null | DataType::Unknown(_)) => {
+            lit(Scalar::null(dtype.clone())).alias(format!("{PARAMETER_PREFIX}{name}"))
+        },
+        _ => expr,
+    }
+}
+
+/// Whether `expr` is a bind parameter that has no type yet.
+pub(crate) fn is_untyped_parameter(expr: &Expr) -> bool {
+    matches!(parameter(expr), Some((_, DataType::Null)))
+}
+
+/// The placeholder and type of a bind parameter.
+fn parameter(expr: &Expr) -> Option<(&str, DataType)> {
+    match expr {
+        Expr::Alias(inner, name) => match &**inner {
+            Expr::Literal(lv) => Some((name.strip_prefix(PARAMETER_PREFIX)?, lv.get_datatype())),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// The type of a parameter used in several places: untyped uses defer to typed ones, and
+/// numeric or temporal uses take their supertype. Any other mix of types is a conflict.
+fn merge_parameter_dtypes(left: &DataType, right: &DataType) -> Option<DataType> {
+    match (left, right) {
+        (l, r) if l == r => Some(l.clone()),
+        (DataType::Null, dt) | (dt, DataType::Null) => Some(dt.clone()),
+        (l, r)
+            if (l.is_primitive_numeric() && r.is_primitive_numeric())
+                || (l.is_temporal() && r.is_temporal()) =>
+        {
+            try_get_supertype(l, r).ok()
+        },
+        _ => None,
+    }
+}
+
+/// A SQL query with bind parameters, prepared by [`SQLContext::prepare`].
+///
+/// The query is parsed and translated into a [`LazyFrame`] once; executing the statement
+/// substitutes the bound values into that plan as literals.
+///
+/// The type of a parameter is declared with a cast (eg: `CAST(? AS DATE)`), or inferred from
+/// the column it is compared with (eg: `WHERE dt > ?`), matched against with `LIKE`, or from
+/// its use in `LIMIT`/`OFFSET`. The bound values are (strictly) cast to that type, so a string
+/// can be bound to a temporal parameter. Parameters without a type take the type of the value.
+///
+/// [`SQLContext::prepare`]: crate::SQLContext::prepare
+#[derive(Clone)]
+pub struct SQLPreparedStatement {
+    lf: LazyFrame,
+    parameters: Vec<PlSmallStr>,
+    dtypes: Vec<DataType>,
+}
+
+impl SQLPreparedStatement {
+    pub(crate) fn new(lf: LazyFrame) -> PolarsResult<Self> {
+        let mut dtypes = PlIndexMap::<PlSmallStr, DataType>::new();
+        let mut result = Ok(());
+        lf.logical_plan.clone().map_exprs(&mut |e| {
+            if let Some((name, dtype)) = parameter(&e) {
+                let name = PlSmallStr::from_str(name);
+                match dtypes.get(&name) {
+                    Some(prev) if result.is_ok() => match merge_parameter_dtypes(prev, &dtype) {
+                        Some(merged) => {
+                            dtypes.insert(name, merged);
+                        },
+                        None => {
+                            result = Err(polars_err!(
+                                SQLInterface: "parameter '{}' is used as both {} and {}", name, prev, dtype
+                            ));
+                        },
+                    },
+                    Some(_) => {},
+                    None => {
+                        dtypes.insert(name, dtype);
+                    },
+                }
+            }
+            e
+        });
+        result?;
+        let mut parameters = Vec::from_iter(dtypes.keys().cloned());
+        let is_positional = |p: &PlSmallStr| positional_index(p).is_some();
+        if parameters.iter().all(is_positional) {
+            parameters.sort_by_key(|p| positional_index(p));
+            for (i, p) in parameters.iter().enumerate() {
+                polars_ensure!(
+                    positional_index(p) == Some(i),
+                    SQLSyntax: "positional parameter ${} is not used", i + 1
+                );
+            }
+        } else {
+            polars_ensure!(
+                !parameters.iter().any(is_positional),
+                SQLSyntax: "cannot mix positional and named parameters"
+            );
+        }
+        let dtypes = parameters.iter().map(|p| dtypes[p].clone()).collect();
+        Ok(Self {
+            lf,
+            parameters,
+            dtypes,
+        })
+    }
+
+    /// Get the placeholders of the bind parameters (eg: `$1`, `:name`); positional
+    /// parameters are listed in order and `?` placeholders are numbered like `$1`, `$2`, ...
+    pub fn parameters(&self) -> &[PlSmallStr] {
+        &self.parameters
+    }
+
+    /// Get the types of the bind parameters, in the order of [`parameters`]; the type is
+    /// `Null` for parameters that take the type of the bound value.
+    ///
+    /// [`parameters`]: Self::parameters
+    pub fn parameter_dtypes(&self) -> &[DataType] {
+        &self.dtypes
+    }
+
+    /// Execute the statement, binding the given values to its positional parameters.
+    /// ```rust
+    /// # use polars_sql::SQLContext;
+    /// # use polars_core::prelude::*;
+    /// # use polars_lazy::prelude::*;
+    /// # fn main() {
+    ///
+    /// let mut ctx = SQLContext::new();
+    /// let df = df! {
+    ///    "a" =>  [1, 2, 3],
+    /// }
+    /// .unwrap();
+    ///
+    /// ctx.register("df", df.lazy());
+    /// let stmt = ctx.prepare("SELECT a FROM df WHERE a > ?").unwrap();
+    /// let sql_df = stmt.execute(&[Scalar::from(1)]).unwrap().collect().unwrap();
+    /// assert_eq!(sql_df.height(), 2);
+    /// # }
+    ///```
+    pub fn execute(&self, values: &[Scalar]) -> PolarsResult<LazyFrame> {
+        polars_ensure!(
+            self.parameters.iter().all(|p| positional_index(p).is_some()),
+            SQLInterface: "statement has named parameters; use `execute_named`"
+        );
+        polars_ensure!(
+            values.len() == self.parameters.len(),
+            SQLInterface: "statement expects {} parameter values; found {}", self.parameters.len(), values.len()
+        );
+        self.bind(|p| values[positional_index(p).unwrap()].clone())
+    }
+
+    /// Execute the statement, binding the given values to its named parameters
+    /// (identified by their name without the `:`, `@` or `$` prefix).
+    pub fn execute_named(&self, values: &[(&str, Scalar)]) -> PolarsResult<LazyFrame> {
+        let values = PlHashMap::from_iter(values.iter().cloned());
+        for p in &self.parameters {
+            polars_ensure!(
+                values.contains_key(unprefixed(p)),
+                SQLInterface: "no value given for parameter '{}'", p
+            );
+        }
+        if let Some(name) = values
+            .keys()
+            .find(|name| !self.parameters.iter().any(|p| unprefixed(p) == **name))
+        {
+            polars_bail!(SQLInterface: "statement has no parameter named '{}'", name);
+        }
+        self.bind(|p| values[unprefixed(p)].clone())
+    }
+
+    fn bind(&self, value: impl Fn(&str) -> Scalar) -> PolarsResult<LazyFrame> {
+        let mut values = PlHashMap::with_capacity(self.parameters.len());
+        for (p, dtype) in self.parameters.iter().zip(&self.dtypes) {
+            let v = value(p);
+            let v = if dtype.is_null() {
+                v
+            } else {
+                let desc = v.value().clone();
+                v.cast_with_options(dtype, CastOptions::Strict).map_err(|_| {
+                    polars_err!(
+                        SQLInterface: "cannot bind value {} to parameter '{}' of type {}", desc, p, dtype
+                    )
+                })?
+            };
+            values.insert(p.as_str(), v);
+        }
+        let plan = self
+            .lf
+            .logical_plan
+            .clone()
+            .map_exprs(&mut |e| match parameter(&e) {
+                Some((name, _)) => Expr::Literal(LiteralValue::Scalar(values[name].clone())),
+                None => e,
+            });
+        Ok(LazyFrame::from(plan).with_optimizations(self.lf.get_current_optimizations()))
+    }
+}
+
+/// The (0-based) index of a positional parameter (eg: `$1`).
+fn positional_index(placeholder: &str) -> Option<usize> {
+    let n = placeholder.strip_prefix('$')?.parse::<usize>().ok()?;
+    n.checked_sub(1)
+}
+
+fn unprefixed(placeholder: &str) -> &str {
+    placeholder
+        .strip_prefix([':', '@', '$'])
+        .unwrap_or(placeholder)
+}
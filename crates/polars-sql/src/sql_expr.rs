@@ -12,7 +12,7 @@ use std::ops::Div;
 use polars_core::prelude::*;
 use polars_lazy::prelude::*;
 use polars_plan::plans::DynLiteralValue;
-use polars_plan::prelude::typed_lit;
+use polars_plan::prelude::{Context, typed_lit};
 use polars_time::Duration;
 use rand::distributions::Alphanumeric;
 use rand::{Rng, thread_rng};
@@ -28,6 +28,7 @@ use sqlparser::parser::{Parser, ParserOptions};
 
 use crate::SQLContext;
 use crate::functions::SQLFunctionVisitor;
+use crate::prepared::{is_untyped_parameter, parameter_expr, typed_parameter};
 use crate::types::{
     bitstring_to_bytes_literal, is_iso_date, is_iso_datetime, is_iso_time, map_sql_dtype_to_polars,
 };
@@ -123,8 +124,24 @@ impl SQLExprVisitor<'_> {
                 negated,
             } => {
                 let expr = self.visit_expr(expr)?;
-                let elems = self.visit_array_expr(list, false, Some(&expr))?;
-                let is_in = expr.is_in(elems, false);
+                let is_in = if list
+                    .iter()
+                    .any(|e| matches!(e, SQLExpr::Value(SQLValue::Placeholder(_))))
+                {
+                    // bind parameters only have values once the query is executed
+                    list.iter()
+                        .map(|e| {
+                            let e = self.visit_expr(e)?;
+                            Ok(expr.clone().eq(self.infer_parameter_dtype(e, &expr)))
+                        })
+                        .collect::<PolarsResult<Vec<_>>>()?
+                        .into_iter()
+                        .reduce(Expr::or)
+                        .unwrap()
+                } else {
+                    let elems = self.visit_array_expr(list, false, Some(&expr))?;
+                    expr.is_in(elems, false)
+                };
                 Ok(if *negated { is_in.not() } else { is_in })
             },
             SQLExpr::InSubquery {
@@ -132,6 +149,17 @@ impl SQLExprVisitor<'_> {
                 subquery,
                 negated,
             } => self.visit_in_subquery(expr, subquery, *negated),
+            #[cfg(feature = "dtype-interval")]
+            SQLExpr::Interval(interval) => {
+                // intervals with calendar parts become `Interval` literals
+                let duration = interval_to_duration(interval, false)?;
+                if duration.months() != 0 {
+                    let v = AnyValue::Interval(duration.to_interval()?);
+                    return Ok(lit(Scalar::new(DataType::Interval, v)));
+                }
+                Ok(lit(interval_to_duration(interval, true)?))
+            },
+            #[cfg(not(feature = "dtype-interval"))]
             SQLExpr::Interval(interval) => Ok(lit(interval_to_duration(interval, true)?)),
             SQLExpr::IsDistinctFrom(e1, e2) => {
                 Ok(self.visit_expr(e1)?.neq_missing(self.visit_expr(e2)?))
@@ -315,6 +343,14 @@ impl SQLExprVisitor<'_> {
             Ok(Expr::Literal(lv)) if lv.extract_str().is_some() => {
                 PlSmallStr::from_str(lv.extract_str().unwrap())
             },
+            // a bind parameter only has its value once the query is executed, so the
+            // pattern is converted to a regex by the query itself
+            Ok(e) if is_untyped_parameter(&e) => {
+                let rx =
+                    like_pattern_to_regex(typed_parameter(e, &DataType::String), case_insensitive);
+                let matches = self.visit_expr(expr)?.str().contains(rx, true);
+                return Ok(if negated { matches.not() } else { matches });
+            },
             _ => {
                 polars_bail!(SQLSyntax: "LIKE/ILIKE pattern must be a string literal; found {}", pattern)
             },
@@ -361,6 +397,20 @@ impl SQLExprVisitor<'_> {
     /// Handle implicit temporal string comparisons.
     ///
     /// eg: "dt >= '2024-04-30'", or "dtm::date = '2077-10-10'"
+    /// Give an untyped bind parameter the type of the expression it is compared with.
+    fn infer_parameter_dtype(&self, param: Expr, other: &Expr) -> Expr {
+        if !is_untyped_parameter(&param) {
+            return param;
+        }
+        match self
+            .active_schema
+            .and_then(|schema| other.to_field(schema, Context::Default).ok())
+        {
+            Some(field) => typed_parameter(param, field.dtype()),
+            None => param,
+        }
+    }
+
     fn convert_temporal_strings(&mut self, left: &Expr, right: &Expr) -> Expr {
         if let (Some(name), Some(s), expr_dtype) = match (left, right) {
             // identify "col <op> string" expressions
@@ -462,7 +512,7 @@ impl SQLExprVisitor<'_> {
         right: &SQLExpr,
     ) -> PolarsResult<Expr> {
         // need special handling for interval offsets and comparisons
-        let (lhs, mut rhs) = match (left, op, right) {
+        let (mut lhs, mut rhs) = match (left, op, right) {
             (_, SQLBinaryOperator::Minus, SQLExpr::Interval(v)) => {
                 let duration = interval_to_duration(v, false)?;
                 return Ok(self
@@ -498,6 +548,19 @@ impl SQLExprVisitor<'_> {
             _ => (self.visit_expr(left)?, self.visit_expr(right)?),
         };
         rhs = self.convert_temporal_strings(&lhs, &rhs);
+        if matches!(
+            op,
+            SQLBinaryOperator::Eq
+                | SQLBinaryOperator::NotEq
+                | SQLBinaryOperator::Gt
+                | SQLBinaryOperator::GtEq
+                | SQLBinaryOperator::Lt
+                | SQLBinaryOperator::LtEq
+                | SQLBinaryOperator::Spaceship
+        ) {
+            rhs = self.infer_parameter_dtype(rhs, &lhs);
+            lhs = self.infer_parameter_dtype(lhs, &rhs);
+        }
 
         Ok(match op {
             // ----
@@ -775,7 +838,10 @@ impl SQLExprVisitor<'_> {
         }
         let polars_type = map_sql_dtype_to_polars(dtype)?;
         Ok(match cast_kind {
-            CastKind::Cast | CastKind::DoubleColon => expr.strict_cast(polars_type),
+            // a (strict) cast declares the type of a bind parameter
+            CastKind::Cast | CastKind::DoubleColon => {
+                typed_parameter(expr, &polars_type).strict_cast(polars_type)
+            },
             CastKind::TryCast | CastKind::SafeCast => expr.cast(polars_type),
         })
     }
@@ -814,6 +880,10 @@ impl SQLExprVisitor<'_> {
                 bitstring_to_bytes_literal(b)?
             },
             SQLValue::SingleQuotedString(s) => lit(s.clone()),
+            SQLValue::Placeholder(p) if self.ctx.allow_parameters => parameter_expr(p),
+            SQLValue::Placeholder(p) => {
+                polars_bail!(SQLInterface: "bind parameter '{}' requires a prepared statement (see `SQLContext::prepare`)", p)
+            },
             other => {
                 polars_bail!(SQLInterface: "value {:?} is not a supported literal type", other)
             },
@@ -887,6 +957,8 @@ impl SQLExprVisitor<'_> {
         let expr = self.visit_expr(expr)?;
         let low = self.visit_expr(low)?;
         let high = self.visit_expr(high)?;
+        let low = self.infer_parameter_dtype(low, &expr);
+        let high = self.infer_parameter_dtype(high, &expr);
 
         let low = self.convert_temporal_strings(&expr, &low);
         let high = self.convert_temporal_strings(&expr, &high);
@@ -1245,6 +1317,20 @@ pub(crate) fn adjust_one_indexed_param(idx: Expr, null_if_zero: bool) -> Expr {
     }
 }
 
+/// Convert a LIKE/ILIKE pattern expression to an (anchored) regex expression, escaping the
+/// regex metacharacters and translating the SQL wildcard chars ('%' => '.*', '_' => '.').
+fn like_pattern_to_regex(pattern: Expr, case_insensitive: bool) -> Expr {
+    let rx = pattern
+        .str()
+        .replace_all(lit(r"[\\.+*?()|\[\]{}^$#\&\-\~]"), lit(r"\$0"), false)
+        .str()
+        .replace_all(lit("%"), lit(".*"), true)
+        .str()
+        .replace_all(lit("_"), lit("."), true);
+    let flags = if case_insensitive { "^(?is)" } else { "^(?s)" };
+    concat_str([lit(flags), rx, lit("$")], "", false)
+}
+
 fn resolve_column<'a>(
     ctx: &'a mut SQLContext,
     ident_root: &'a Ident,
@@ -176,8 +176,11 @@ pub(crate) fn map_sql_dtype_to_polars(dtype: &SQLDataType) -> PolarsResult<DataT
         | SQLDataType::Clob(_)
         | SQLDataType::String(_)
         | SQLDataType::Text
-        | SQLDataType::Uuid
         | SQLDataType::Varchar(_) => DataType::String,
+        #[cfg(feature = "dtype-uuid")]
+        SQLDataType::Uuid => DataType::Uuid,
+        #[cfg(not(feature = "dtype-uuid"))]
+        SQLDataType::Uuid => DataType::String,
 
         // ---------------------------------
         // custom
@@ -206,3 +209,62 @@ pub(crate) fn map_sql_dtype_to_polars(dtype: &SQLDataType) -> PolarsResult<DataT
         },
     })
 }
+
+/// Get the SQL name of a Polars datatype, as reported by `DESCRIBE` and `information_schema`
+/// (nested types are described in terms of their inner types, eg: `STRUCT(a BIGINT)[]`).
+pub(crate) fn polars_dtype_to_sql_type_name(dtype: &DataType) -> String {
+    match dtype {
+        DataType::Boolean => "BOOLEAN".into(),
+        DataType::Int8 => "TINYINT".into(),
+        DataType::Int16 => "SMALLINT".into(),
+        DataType::Int32 => "INTEGER".into(),
+        DataType::Int64 => "BIGINT".into(),
+        DataType::UInt8 => "TINYINT UNSIGNED".into(),
+        DataType::UInt16 => "SMALLINT UNSIGNED".into(),
+        DataType::UInt32 => "INTEGER UNSIGNED".into(),
+        DataType::UInt64 => "BIGINT UNSIGNED".into(),
+        DataType::Float32 => "REAL".into(),
+        DataType::Float64 => "DOUBLE".into(),
+        #[cfg(feature = "dtype-decimal")]
+        DataType::Decimal(precision, scale) => match (precision, scale) {
+            (Some(p), Some(s)) => format!("DECIMAL({p},{s})"),
+            (Some(p), None) => format!("DECIMAL({p})"),
+            _ => "DECIMAL".into(),
+        },
+        DataType::String => "VARCHAR".into(),
+        DataType::Binary => "BLOB".into(),
+        DataType::Date => "DATE".into(),
+        DataType::Time => "TIME".into(),
+        DataType::Datetime(tu, tz) => {
+            let precision = match tu {
+                TimeUnit::Milliseconds => 3,
+                TimeUnit::Microseconds => 6,
+                TimeUnit::Nanoseconds => 9,
+            };
+            match tz {
+                Some(_) => format!("TIMESTAMP({precision}) WITH TIME ZONE"),
+                None => format!("TIMESTAMP({precision})"),
+            }
+        },
+        DataType::Duration(_) => "INTERVAL".into(),
+        DataType::List(inner) => format!("{}[]", polars_dtype_to_sql_type_name(inner)),
+        DataType::Array(inner, size) => {
+            format!("{}[{}]", polars_dtype_to_sql_type_name(inner), size)
+        },
+        DataType::Struct(fields) => {
+            let fields = fields
+                .iter()
+                .map(|fld| {
+                    format!(
+                        "{} {}",
+                        fld.name(),
+                        polars_dtype_to_sql_type_name(fld.dtype())
+                    )
+                })
+                .collect::<Vec<_>>();
+            format!("STRUCT({})", fields.join(", "))
+        },
+        DataType::Null => "NULL".into(),
+        dtype => dtype.to_string().to_uppercase(),
+    }
+}
@@ -219,6 +219,22 @@ fn test_literal_exprs() {
     assert!(df_sql.equals_missing(&df_pl));
 }
 
+#[test]
+#[cfg(feature = "dtype-interval")]
+fn test_calendar_interval_literal() {
+    let mut context = SQLContext::new();
+    context.register("df", create_sample_df().lazy());
+    let sql = "SELECT INTERVAL '1 month 2 days 3 hours' AS i FROM df LIMIT 1";
+    let df_sql = context.execute(sql).unwrap().collect().unwrap();
+
+    let i = df_sql.column("i").unwrap();
+    assert_eq!(i.dtype(), &DataType::Interval);
+    assert_eq!(
+        i.get(0).unwrap(),
+        AnyValue::Interval(months_days_ns::new(1, 2, 3 * 3_600_000_000_000))
+    );
+}
+
 #[test]
 fn test_implicit_date_string() {
     let df = df! {
@@ -623,6 +639,138 @@ fn test_group_by_2() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_group_by_grouping_sets() -> PolarsResult<()> {
+    let df = df! {
+        "region" => ["eu", "eu", "eu", "us"],
+        "city" => ["ams", "ams", "ber", "nyc"],
+        "sales" => [1, 2, 3, 4],
+    }?;
+    let mut context = SQLContext::new();
+    context.register("df", df.lazy());
+
+    let expected = df! {
+        "region" => [Some("eu"), Some("eu"), Some("us"), Some("eu"), Some("us"), None],
+        "city" => [Some("ams"), Some("ber"), Some("nyc"), None, None, None],
+        "total" => [3, 3, 4, 6, 4, 10],
+        "gid" => [0u64, 0, 0, 1, 1, 3],
+    }?;
+    for group_by in [
+        "ROLLUP(region, city)",
+        "region, city WITH ROLLUP",
+        "GROUPING SETS ((region, city), (region), ())",
+        "GROUPING SETS ((1, 2), (1), ())",
+    ] {
+        let sql = format!(
+            "SELECT region, city, SUM(sales) AS total, GROUPING_ID(region, city) AS gid
+            FROM df GROUP BY {group_by} ORDER BY gid, region, city"
+        );
+        let df_sql = context.execute(&sql)?.collect()?;
+        assert!(df_sql.equals_missing(&expected), "{group_by}: {df_sql:?}");
+    }
+
+    let df_sql = context
+        .execute(
+            "SELECT region, city, COUNT(*) AS n FROM df
+            GROUP BY CUBE(region, city) ORDER BY region, city",
+        )?
+        .collect()?;
+    let expected = df! {
+        "region" => [Some("eu"), Some("eu"), Some("eu"), Some("us"), Some("us"), None, None, None, None],
+        "city" => [Some("ams"), Some("ber"), None, Some("nyc"), None, Some("ams"), Some("ber"), Some("nyc"), None],
+        "n" => [2u32, 1, 3, 1, 1, 2, 1, 1, 4],
+    }?;
+    assert!(df_sql.equals_missing(&expected), "{df_sql:?}");
+
+    // plain keys are part of every grouping set
+    let df_sql = context
+        .execute(
+            "SELECT region, city, SUM(sales) AS total FROM df
+            GROUP BY region, ROLLUP(city) ORDER BY region, city",
+        )?
+        .collect()?;
+    let expected = df! {
+        "region" => ["eu", "eu", "eu", "us", "us"],
+        "city" => [Some("ams"), Some("ber"), None, Some("nyc"), None],
+        "total" => [3, 3, 6, 4, 4],
+    }?;
+    assert!(df_sql.equals_missing(&expected), "{df_sql:?}");
+
+    // GROUPING of a subset of the keys, in the given order
+    let df_sql = context
+        .execute(
+            "SELECT region, city, GROUPING(city) AS g_city, GROUPING(city, region) AS g
+            FROM df GROUP BY CUBE(region, city) ORDER BY g, region, city",
+        )?
+        .collect()?;
+    let expected = df! {
+        "region" => [Some("eu"), Some("eu"), Some("us"), None, None, None, Some("eu"), Some("us"), None],
+        "city" => [Some("ams"), Some("ber"), Some("nyc"), Some("ams"), Some("ber"), Some("nyc"), None, None, None],
+        "g_city" => [0u64, 0, 0, 0, 0, 0, 1, 1, 1],
+        "g" => [0u64, 0, 0, 1, 1, 1, 2, 2, 3],
+    }?;
+    assert!(df_sql.equals_missing(&expected), "{df_sql:?}");
+
+    // The grand total is a single row, even without input rows.
+    let df_sql = context
+        .execute("SELECT region, COUNT(*) AS n FROM df WHERE sales > 10 GROUP BY ROLLUP(region)")?
+        .collect()?;
+    let expected = df! {
+        "region" => [None::<&str>],
+        "n" => [0 as IdxSize],
+    }?;
+    assert!(df_sql.equals_missing(&expected), "{df_sql:?}");
+
+    for (sql, err) in [
+        (
+            "SELECT region, GROUPING_ID() FROM df GROUP BY ROLLUP(region)",
+            "requires the GROUP BY keys",
+        ),
+        (
+            "SELECT region, GROUPING(sales) FROM df GROUP BY ROLLUP(region)",
+            "is not a GROUP BY key",
+        ),
+        (
+            "SELECT region, GROUPING(region) FROM df GROUP BY region",
+            "only supported in the SELECT list",
+        ),
+    ] {
+        let res = context.execute(sql).and_then(|lf| lf.collect());
+        assert!(res.unwrap_err().to_string().contains(err), "{sql}");
+    }
+    Ok(())
+}
+
+#[test]
+fn test_group_by_grouping_sets_too_many_keys() {
+    let columns = (0..64)
+        .map(|i| Column::new(format!("c{i}").into(), [i]))
+        .collect::<Vec<_>>();
+    let mut context = SQLContext::new();
+    context.register("df", DataFrame::new(columns).unwrap().lazy());
+
+    let keys = (0..64)
+        .map(|i| format!("c{i}"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    for (group_by, msg) in [
+        (format!("ROLLUP({keys})"), "at most 63 keys"),
+        (
+            format!("CUBE({keys})"),
+            "CUBE supports at most 12 keys, got 64",
+        ),
+        (
+            "CUBE(c0, c1, c2, c3, c4, c5, c6, c7, c8, c9, c10, c11, c12)".to_string(),
+            "got 13",
+        ),
+    ] {
+        let sql = format!("SELECT COUNT(*) FROM df GROUP BY {group_by}");
+        let res = context.execute(&sql).and_then(|lf| lf.collect());
+        let err = res.unwrap_err().to_string();
+        assert!(err.contains(msg), "{group_by}: {err}");
+    }
+}
+
 #[test]
 fn test_case_expr() {
     let df = create_sample_df().head(Some(10));
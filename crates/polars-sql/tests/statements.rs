@@ -626,3 +626,980 @@ fn test_compound_invalid_3() {
     let sql = "SELECT * FROM df1 INNER JOIN df2 ON df1.a = df2.a AND b";
     let _ = ctx.execute(sql).unwrap();
 }
+
+fn prepare_range_join_context() -> SQLContext {
+    let events = df! {
+        "id" => [1, 2, 3, 4],
+        "ts" => [5, 12, 25, 40],
+        "k" => ["A", "b", "C", "d"],
+    }
+    .unwrap();
+    let windows = df! {
+        "name" => ["w1", "w2", "w3"],
+        "start" => [0, 10, 20],
+        "end" => [10, 30, 30],
+        "k" => ["a", "b", "c"],
+    }
+    .unwrap();
+    let mut ctx = SQLContext::new();
+    ctx.register("events", events.lazy());
+    ctx.register("windows", windows.lazy());
+    ctx
+}
+
+#[test]
+fn test_join_on_between() {
+    let mut ctx = prepare_range_join_context();
+    let sql = r#"
+        SELECT e.id, w.name
+        FROM events e
+        INNER JOIN windows w ON e.ts BETWEEN w.start AND w.end
+        ORDER BY e.id, w.name
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+        "id" => [1, 2, 3, 3],
+        "name" => ["w1", "w2", "w2", "w3"],
+    }
+    .unwrap();
+    assert!(actual.equals(&expected), "{actual:?}");
+}
+
+#[test]
+fn test_join_on_inequality_outer() {
+    let mut ctx = prepare_range_join_context();
+    let sql = r#"
+        SELECT e.id, w.name
+        FROM events e
+        LEFT JOIN windows w ON e.ts < w.start AND w.name <> 'w3'
+        ORDER BY e.id, w.name
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+        "id" => [Some(1), Some(2), Some(3), Some(4)],
+        "name" => [Some("w2"), None, None, None],
+    }
+    .unwrap();
+    assert!(actual.equals_missing(&expected), "{actual:?}");
+
+    let sql = r#"
+        SELECT e.id, w.name
+        FROM events e
+        FULL JOIN windows w ON e.ts >= w.end
+        ORDER BY e.id, w.name
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+        "id" => [Some(1), Some(2), Some(3), Some(4), Some(4), Some(4)],
+        "name" => [None, Some("w1"), Some("w1"), Some("w1"), Some("w2"), Some("w3")],
+    }
+    .unwrap();
+    assert!(actual.equals_missing(&expected), "{actual:?}");
+}
+
+#[test]
+fn test_join_on_computed_keys() {
+    let mut ctx = prepare_range_join_context();
+    let sql = r#"
+        SELECT e.id, e.k, w.name, w.k AS wk
+        FROM events e
+        INNER JOIN windows w ON lower(e.k) = w.k
+        ORDER BY e.id
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+        "id" => [1, 2, 3],
+        "k" => ["A", "b", "C"],
+        "name" => ["w1", "w2", "w3"],
+        "wk" => ["a", "b", "c"],
+    }
+    .unwrap();
+    assert!(actual.equals(&expected), "{actual:?}");
+
+    // ambiguous columns must be qualified
+    let sql = "SELECT * FROM events e INNER JOIN windows w ON lower(k) = w.k";
+    assert!(ctx.execute(sql).is_err());
+}
+
+fn prepare_graph_context() -> SQLContext {
+    let employees = df! {
+        "id" => [1, 2, 3, 4, 5],
+        "manager_id" => [None, Some(1), Some(1), Some(2), Some(4)],
+    }
+    .unwrap();
+    let edges = df! {
+        "src" => [1, 2, 3],
+        "dst" => [2, 3, 1],
+    }
+    .unwrap();
+    let mut ctx = SQLContext::new();
+    ctx.register("employees", employees.lazy());
+    ctx.register("edges", edges.lazy());
+    ctx
+}
+
+#[test]
+fn test_recursive_cte_union_all() {
+    let mut ctx = prepare_graph_context();
+    let sql = r#"
+        WITH RECURSIVE chain(id, depth) AS (
+            SELECT id, 0 FROM employees WHERE manager_id IS NULL
+            UNION ALL
+            SELECT e.id, c.depth + 1
+            FROM employees e
+            INNER JOIN chain c ON e.manager_id = c.id
+        )
+        SELECT * FROM chain ORDER BY id
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+        "id" => [1, 2, 3, 4, 5],
+        "depth" => [0, 1, 1, 2, 3],
+    }
+    .unwrap();
+    assert!(
+        actual.equals(&expected),
+        "expected = {:?}\nactual={:?}",
+        expected,
+        actual
+    );
+}
+
+#[test]
+fn test_recursive_cte_union_distinct_cycle() {
+    let mut ctx = prepare_graph_context();
+    // The graph contains a cycle, `UNION` stops once no new rows are found.
+    let sql = r#"
+        WITH RECURSIVE reachable AS (
+            SELECT 1 AS node
+            UNION
+            SELECT edges.dst FROM edges INNER JOIN reachable ON edges.src = reachable.node
+        )
+        SELECT node FROM reachable ORDER BY node
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! { "node" => [1, 2, 3] }.unwrap();
+    assert!(
+        actual.equals(&expected),
+        "expected = {:?}\nactual={:?}",
+        expected,
+        actual
+    );
+
+    // With `UNION ALL` the cycle never terminates.
+    let mut ctx = prepare_graph_context().with_recursive_cte_max_iterations(10);
+    let sql = sql.replace("UNION", "UNION ALL");
+    let Err(err) = ctx.execute(&sql) else {
+        panic!("expected recursive CTE to exceed the iteration limit")
+    };
+    assert!(
+        err.to_string()
+            .contains("did not terminate within 10 iterations")
+    );
+}
+
+fn prepare_subquery_context() -> SQLContext {
+    let customers = df! {
+        "id" => [1, 2, 3, 4],
+        "name" => ["ann", "bob", "cat", "dan"],
+        "tier" => [Some(1), Some(2), Some(1), None],
+    }
+    .unwrap();
+    let orders = df! {
+        "customer_id" => [1, 1, 2, 3, 3, 3],
+        "amount" => [10, 30, 5, 20, 20, 50],
+        "tier" => [1, 1, 1, 1, 2, 2],
+    }
+    .unwrap();
+    let mut ctx = SQLContext::new();
+    ctx.register("customers", customers.lazy());
+    ctx.register("orders", orders.lazy());
+    ctx
+}
+
+#[test]
+fn test_correlated_exists() {
+    let mut ctx = prepare_subquery_context();
+    for (sql, expected) in [
+        (
+            r#"
+            SELECT name FROM customers c
+            WHERE EXISTS (SELECT 1 FROM orders o WHERE o.customer_id = c.id AND o.amount > 25)
+            ORDER BY name
+            "#,
+            vec!["ann", "cat"],
+        ),
+        (
+            r#"
+            SELECT name FROM customers c
+            WHERE NOT EXISTS (SELECT * FROM orders o WHERE o.customer_id = c.id)
+            ORDER BY name
+            "#,
+            vec!["dan"],
+        ),
+        (
+            r#"
+            SELECT name FROM customers c
+            WHERE c.id = 4 OR EXISTS (SELECT 1 FROM orders WHERE customer_id = id AND amount < 10)
+            ORDER BY name
+            "#,
+            vec!["bob", "dan"],
+        ),
+        (
+            r#"
+            SELECT name FROM customers
+            WHERE NOT EXISTS (SELECT 1 FROM orders WHERE amount > 100)
+            ORDER BY name
+            "#,
+            vec!["ann", "bob", "cat", "dan"],
+        ),
+    ] {
+        let actual = ctx.execute(sql).unwrap().collect().unwrap();
+        let expected = df! { "name" => expected }.unwrap();
+        assert!(actual.equals(&expected), "{sql}\n{actual:?}");
+    }
+}
+
+#[test]
+fn test_correlated_in_subquery() {
+    let mut ctx = prepare_subquery_context();
+    for (sql, expected) in [
+        (
+            r#"
+            SELECT name FROM customers c
+            WHERE c.tier IN (SELECT o.tier FROM orders o WHERE o.customer_id = c.id)
+            ORDER BY name
+            "#,
+            vec!["ann", "cat"],
+        ),
+        (
+            r#"
+            SELECT name FROM customers c
+            WHERE c.tier NOT IN (SELECT o.tier FROM orders o WHERE o.customer_id = c.id)
+            ORDER BY name
+            "#,
+            // There are no orders of "dan", so the null tier is not in them.
+            vec!["bob", "dan"],
+        ),
+    ] {
+        let actual = ctx.execute(sql).unwrap().collect().unwrap();
+        let expected = df! { "name" => expected }.unwrap();
+        assert!(actual.equals(&expected), "{sql}\n{actual:?}");
+    }
+}
+
+#[test]
+fn test_correlated_scalar_subquery() {
+    let mut ctx = prepare_subquery_context();
+    for (sql, expected) in [
+        (
+            r#"
+            SELECT customer_id, amount FROM orders o1
+            WHERE amount > (SELECT AVG(amount) FROM orders o2 WHERE o2.customer_id = o1.customer_id)
+            ORDER BY customer_id, amount
+            "#,
+            df! { "customer_id" => [1, 3], "amount" => [30, 50] },
+        ),
+        (
+            r#"
+            SELECT id AS customer_id, tier AS amount FROM customers c
+            WHERE (SELECT COUNT(*) FROM orders o WHERE o.customer_id = c.id) < 2
+            ORDER BY id
+            "#,
+            df! { "customer_id" => [2, 4], "amount" => [Some(2), None] },
+        ),
+    ] {
+        let actual = ctx.execute(sql).unwrap().collect().unwrap();
+        let expected = expected.unwrap();
+        assert!(actual.equals_missing(&expected), "{sql}\n{actual:?}");
+    }
+
+    // Conditions other than equalities are evaluated on the distinct outer values.
+    let sql = r#"
+        SELECT name FROM customers c
+        WHERE 20 < (SELECT MAX(amount) FROM orders o WHERE o.customer_id > c.id)
+        ORDER BY name
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    assert!(
+        actual.equals(&df! { "name" => ["ann", "bob"] }.unwrap()),
+        "{actual:?}"
+    );
+
+    // A non-aggregated scalar subquery must return at most one row per outer row.
+    let sql = r#"
+        SELECT name FROM customers c
+        WHERE (SELECT amount FROM orders o WHERE o.customer_id = c.id AND o.amount < 10) = 5
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    assert!(
+        actual.equals(&df! { "name" => ["bob"] }.unwrap()),
+        "{actual:?}"
+    );
+    let sql = r#"
+        SELECT name FROM customers c
+        WHERE (SELECT amount FROM orders o WHERE o.customer_id = c.id) > 10
+    "#;
+    assert!(ctx.execute(sql).unwrap().collect().is_err());
+}
+
+#[test]
+fn test_correlated_subquery_in_select() {
+    let mut ctx = prepare_subquery_context();
+    let sql = r#"
+        SELECT
+          name,
+          (SELECT MAX(amount) FROM orders o WHERE o.customer_id = c.id) AS max_amount,
+          (SELECT COUNT(*) FROM orders o WHERE o.customer_id = c.id AND o.tier = c.tier) AS n,
+          (SELECT SUM(amount) FROM orders o WHERE o.customer_id < c.id) AS before,
+          EXISTS (SELECT 1 FROM orders o WHERE o.customer_id = c.id AND o.amount > 25) AS big
+        FROM customers c
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+        "name" => ["ann", "bob", "cat", "dan"],
+        "max_amount" => [Some(30), Some(5), Some(50), None],
+        "n" => [2u32, 0, 1, 0],
+        "before" => [None, Some(40), Some(45), Some(135)],
+        "big" => [true, false, true, false],
+    }
+    .unwrap();
+    assert!(actual.equals_missing(&expected), "{actual:?}");
+
+    // An unnamed subquery is named after its column, and a wildcard does not include the
+    // columns of the decorrelated subqueries.
+    let sql = r#"
+        SELECT *, (SELECT MIN(amount) FROM orders o WHERE o.customer_id = c.id)
+        FROM customers c
+        WHERE id < 3
+        ORDER BY id DESC
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+        "id" => [2, 1],
+        "name" => ["bob", "ann"],
+        "tier" => [2, 1],
+        "amount" => [5, 10],
+    }
+    .unwrap();
+    assert!(actual.equals(&expected), "{actual:?}");
+
+    let sql = r#"
+        SELECT tier, (SELECT MAX(amount) FROM orders o WHERE o.customer_id = c.id)
+        FROM customers c
+        GROUP BY tier
+    "#;
+    assert!(ctx.execute(sql).is_err());
+}
+
+#[test]
+fn test_implicit_join() {
+    let mut ctx = prepare_subquery_context();
+    let sql = r#"
+        SELECT c.name, o.amount, o.tier
+        FROM customers c, orders o
+        WHERE c.id = o.customer_id AND o.amount > 20
+        ORDER BY c.name, o.amount
+    "#;
+    let lf = ctx.execute(sql).unwrap();
+    let plan = lf.clone().explain(true).unwrap();
+    assert!(!plan.contains("CROSS JOIN"), "{plan}");
+
+    let actual = lf.collect().unwrap();
+    let expected = df! {
+        "name" => ["ann", "cat"],
+        "amount" => [30, 50],
+        "tier" => [1, 2],
+    }
+    .unwrap();
+    assert!(actual.equals(&expected), "{actual:?}");
+}
+
+#[test]
+fn test_lateral_join() {
+    let mut ctx = prepare_subquery_context();
+    for (sql, expected) in [
+        (
+            r#"
+            SELECT c.name, t.amount, t.tier
+            FROM customers c, LATERAL (
+                SELECT amount, tier FROM orders o
+                WHERE o.customer_id = c.id
+                ORDER BY amount DESC LIMIT 1
+            ) AS t
+            ORDER BY c.name
+            "#,
+            df! {
+                "name" => ["ann", "bob", "cat"],
+                "amount" => [30, 5, 50],
+                "tier" => [1, 1, 2],
+            },
+        ),
+        (
+            r#"
+            SELECT c.name, t.amount, t.tier
+            FROM customers c LEFT JOIN LATERAL (
+                SELECT amount, tier FROM orders o
+                WHERE o.customer_id = c.id
+                ORDER BY amount, tier LIMIT 1 OFFSET 1
+            ) AS t ON TRUE
+            ORDER BY c.name
+            "#,
+            df! {
+                "name" => ["ann", "bob", "cat", "dan"],
+                "amount" => [Some(30), None, Some(20), None],
+                "tier" => [Some(1), None, Some(2), None],
+            },
+        ),
+        (
+            r#"
+            SELECT c.name, t.amount, t.tier
+            FROM customers c CROSS JOIN LATERAL (
+                SELECT SUM(amount), MAX(tier) FROM orders
+                WHERE customer_id = c.id
+            ) AS t (amount, tier)
+            ORDER BY c.name
+            "#,
+            df! {
+                "name" => ["ann", "bob", "cat", "dan"],
+                "amount" => [Some(40), Some(5), Some(90), None],
+                "tier" => [Some(1), Some(1), Some(2), None],
+            },
+        ),
+        (
+            r#"
+            SELECT c.name, t.n, t.amount
+            FROM customers c,LATERAL (
+                SELECT COUNT(*) AS n, SUM(amount) AS amount FROM orders
+                WHERE customer_id = c.id
+            ) AS t
+            ORDER BY c.name
+            "#,
+            df! {
+                "name" => ["ann", "bob", "cat", "dan"],
+                "n" => [2 as IdxSize, 1, 3, 0],
+                "amount" => [Some(40), Some(5), Some(90), None],
+            },
+        ),
+    ] {
+        let actual = ctx.execute(sql).unwrap().collect().unwrap();
+        let expected = expected.unwrap();
+        assert!(actual.equals_missing(&expected), "{sql}\n{actual:?}");
+    }
+
+    // "lateral" is still usable as a column name
+    let df = df! { "id" => [1, 2], "lateral" => ["x", "y"] }.unwrap();
+    ctx.register("tbl", df.clone().lazy());
+    let sql = "SELECT id, lateral FROM tbl ORDER BY id";
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    assert!(actual.equals(&df), "{actual:?}");
+}
+
+fn prepare_window_context() -> SQLContext {
+    let df = df! {
+        "grp" => ["a", "a", "a", "a", "b", "b"],
+        "ts" => [1, 2, 2, 5, 1, 3],
+        "val" => [Some(1), Some(2), Some(3), Some(4), None, Some(10)],
+    }
+    .unwrap();
+    let mut ctx = SQLContext::new();
+    ctx.register("df", df.lazy());
+    ctx
+}
+
+#[test]
+fn test_named_window_and_qualify() {
+    let mut ctx = prepare_window_context();
+    let sql = r#"
+        SELECT grp, ts, val, SUM(val) OVER w AS total
+        FROM df
+        WINDOW w AS (PARTITION BY grp)
+        QUALIFY val = MAX(val) OVER w
+        ORDER BY grp
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+        "grp" => ["a", "b"],
+        "ts" => [5, 3],
+        "val" => [4, 10],
+        "total" => [10, 10],
+    }
+    .unwrap();
+    assert!(actual.equals_missing(&expected), "{actual:?}");
+
+    let sql = "SELECT grp, ts FROM df QUALIFY ts > 1";
+    let Err(err) = ctx.execute(sql) else {
+        panic!("QUALIFY without a window function should fail")
+    };
+    assert!(
+        err.to_string()
+            .contains("QUALIFY requires a window function")
+    );
+}
+
+#[test]
+fn test_window_frames() {
+    let mut ctx = prepare_window_context();
+    let sql = r#"
+        SELECT
+          grp,
+          ts,
+          SUM(val) OVER (w ROWS BETWEEN 1 PRECEDING AND 1 FOLLOWING) AS sum_rows,
+          COUNT(val) OVER (w ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW) AS count_rows,
+          MAX(val) OVER (w ROWS BETWEEN CURRENT ROW AND UNBOUNDED FOLLOWING) AS max_rows,
+          SUM(val) OVER (w RANGE BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW) AS sum_range,
+          SUM(val) OVER (w RANGE BETWEEN 1 PRECEDING AND CURRENT ROW) AS sum_offset,
+          MIN(val) OVER (w ROWS BETWEEN UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING) AS min_all
+        FROM df
+        WINDOW w AS (PARTITION BY grp ORDER BY ts)
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+        "grp" => ["a", "a", "a", "a", "b", "b"],
+        "ts" => [1, 2, 2, 5, 1, 3],
+        "sum_rows" => [Some(3), Some(6), Some(9), Some(7), Some(10), Some(10)],
+        "count_rows" => [1 as IdxSize, 2, 3, 4, 0, 1],
+        "max_rows" => [Some(4), Some(4), Some(4), Some(4), Some(10), Some(10)],
+        "sum_range" => [Some(1), Some(6), Some(6), Some(10), None, Some(10)],
+        "sum_offset" => [Some(1), Some(6), Some(6), Some(4), None, Some(10)],
+        "min_all" => [Some(1), Some(1), Some(1), Some(1), Some(10), Some(10)],
+    }
+    .unwrap();
+    assert!(actual.equals_missing(&expected), "{actual:?}");
+
+    let sql = "SELECT SUM(val) OVER (ORDER BY ts GROUPS 1 PRECEDING) FROM df";
+    let Err(err) = ctx.execute(sql).and_then(|lf| lf.collect()) else {
+        panic!("GROUPS frames should not be supported")
+    };
+    assert!(err.to_string().contains("unsupported window frame"));
+}
+
+fn prepare_dml_context() -> SQLContext {
+    let df = df! {
+        "id" => [1, 2, 3],
+        "val" => ["a", "b", "c"],
+    }
+    .unwrap();
+    let mut ctx = SQLContext::new();
+    ctx.register("tbl", df.lazy());
+    ctx
+}
+
+#[test]
+fn test_insert_and_update() {
+    let mut ctx = prepare_dml_context();
+    for sql in [
+        "INSERT INTO tbl VALUES (4, 'd'), (5, 'e')",
+        "INSERT INTO tbl (id) SELECT id + 10 FROM tbl WHERE id < 2",
+        "UPDATE tbl SET val = UPPER(val), id = id * 2 WHERE id IN (2, 4)",
+        "UPDATE tbl SET val = 'z' WHERE val IS NULL",
+    ] {
+        ctx.execute(sql).unwrap().collect().unwrap();
+    }
+    let actual = ctx.execute("SELECT * FROM tbl").unwrap().collect().unwrap();
+    let expected = df! {
+        "id" => [1, 4, 3, 8, 5, 11],
+        "val" => ["a", "B", "c", "D", "e", "z"],
+    }
+    .unwrap();
+    assert!(actual.equals_missing(&expected), "{actual:?}");
+
+    let Err(err) = ctx.execute("INSERT INTO tbl VALUES (1)") else {
+        panic!("INSERT with too few values should fail")
+    };
+    assert!(err.to_string().contains("expects 2 values per row"));
+    let Err(err) = ctx.execute("UPDATE missing SET id = 0") else {
+        panic!("UPDATE of a missing table should fail")
+    };
+    assert!(err.to_string().contains("table 'missing' does not exist"));
+}
+
+#[test]
+fn test_merge() {
+    let mut ctx = prepare_dml_context();
+    let src = df! {
+        "id" => [2, 3, 4, 5],
+        "val" => ["x", "y", "z", "w"],
+    }
+    .unwrap();
+    ctx.register("src", src.lazy());
+    let sql = r#"
+        MERGE INTO tbl AS t
+        USING src AS s ON t.id = s.id
+        WHEN MATCHED AND s.val = 'y' THEN DELETE
+        WHEN MATCHED THEN UPDATE SET val = s.val
+        WHEN NOT MATCHED AND s.id < 5 THEN INSERT (id, val) VALUES (s.id, val)
+        WHEN NOT MATCHED BY SOURCE THEN UPDATE SET val = t.val || '!'
+    "#;
+    ctx.execute(sql).unwrap().collect().unwrap();
+    let actual = ctx.execute("SELECT * FROM tbl").unwrap().collect().unwrap();
+    let expected = df! {
+        "id" => [1, 2, 4],
+        "val" => ["a!", "x", "z"],
+    }
+    .unwrap();
+    assert!(actual.equals_missing(&expected), "{actual:?}");
+}
+
+#[test]
+fn test_merge_multiple_source_matches() {
+    let mut ctx = prepare_dml_context();
+    let src = df! {
+        "id" => [2, 2, 5],
+        "val" => ["x", "y", "z"],
+    }
+    .unwrap();
+    ctx.register("src", src.clone().lazy());
+
+    // The check runs when the result is collected.
+    let sql =
+        "MERGE INTO tbl USING src ON tbl.id = src.id WHEN MATCHED THEN UPDATE SET val = src.val";
+    let lf = ctx.execute(sql).unwrap();
+    let Err(err) = lf.collect() else {
+        panic!("MERGE updating a target row twice should fail")
+    };
+    assert!(err.to_string().contains("more than once"), "{err}");
+    let mut ctx = prepare_dml_context();
+    ctx.register("src", src.lazy());
+
+    // Duplicate matches are fine as long as at most one of them affects the target row.
+    for sql in [
+        "MERGE INTO tbl USING src ON tbl.id = src.id WHEN MATCHED AND src.val = 'y' THEN DELETE",
+        "MERGE INTO tbl USING src ON tbl.id = src.id WHEN NOT MATCHED AND src.id > 2 THEN INSERT VALUES (src.id, src.val)",
+    ] {
+        ctx.execute(sql).unwrap().collect().unwrap();
+    }
+    let actual = ctx.execute("SELECT * FROM tbl").unwrap().collect().unwrap();
+    let expected = df! {
+        "id" => [1, 3, 5],
+        "val" => ["a", "c", "z"],
+    }
+    .unwrap();
+    assert!(actual.equals(&expected), "{actual:?}");
+}
+
+#[test]
+fn test_create_table_and_view() {
+    let mut ctx = prepare_dml_context();
+    for sql in [
+        "CREATE TABLE new_tbl (id INT, name VARCHAR, score DOUBLE)",
+        "INSERT INTO new_tbl (name, id) VALUES ('x', 1)",
+        "CREATE VIEW tbl_view (n, v) AS SELECT id, val FROM tbl WHERE id > 1",
+        "INSERT INTO tbl VALUES (4, 'd')",
+    ] {
+        ctx.execute(sql).unwrap().collect().unwrap();
+    }
+    let actual = ctx
+        .execute("SELECT * FROM new_tbl")
+        .unwrap()
+        .collect()
+        .unwrap();
+    let expected = df! {
+        "id" => [1i32],
+        "name" => ["x"],
+        "score" => [None::<f64>],
+    }
+    .unwrap();
+    assert!(actual.equals_missing(&expected), "{actual:?}");
+
+    // the view reflects the current contents of the table
+    let sql = "SELECT v.n, v.v FROM tbl_view AS v ORDER BY v.n";
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+        "n" => [2, 3, 4],
+        "v" => ["b", "c", "d"],
+    }
+    .unwrap();
+    assert!(actual.equals(&expected), "{actual:?}");
+    assert_eq!(ctx.get_tables(), ["new_tbl", "tbl", "tbl_view"]);
+
+    let Err(err) = ctx.execute("CREATE VIEW tbl AS SELECT 1") else {
+        panic!("CREATE VIEW over an existing table should fail")
+    };
+    assert!(err.to_string().contains("relation 'tbl' already exists"));
+    ctx.execute("DROP VIEW tbl_view")
+        .unwrap()
+        .collect()
+        .unwrap();
+    assert!(ctx.execute("SELECT * FROM tbl_view").is_err());
+}
+
+fn prepare_pivot_context() -> SQLContext {
+    let df = df! {
+        "region" => ["east", "east", "west", "west", "east"],
+        "quarter" => ["q1", "q2", "q1", "q1", "q1"],
+        "sales" => [10, 20, 30, 40, 5],
+    }
+    .unwrap();
+    let mut ctx = SQLContext::new();
+    ctx.register("sales", df.lazy());
+    ctx
+}
+
+#[test]
+fn test_pivot_and_unpivot() {
+    let mut ctx = prepare_pivot_context();
+    let sql = r#"
+        SELECT * FROM sales
+        PIVOT (SUM(sales) FOR quarter IN ('q1', 'q2', 'q3' AS third))
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+        "region" => ["east", "west"],
+        "q1" => [Some(15), Some(70)],
+        "q2" => [Some(20), None],
+        "third" => [None::<i32>, None],
+    }
+    .unwrap();
+    assert!(actual.equals_missing(&expected), "{actual:?}");
+
+    let sql = r#"
+        SELECT * FROM (
+          SELECT p.region, p.first, p.second FROM sales
+          PIVOT (SUM(sales) FOR quarter IN ('q1', 'q2')) AS p (region, first, second)
+        ) AS p
+        UNPIVOT (total FOR quarter IN (first, second))
+        ORDER BY region, quarter
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+        "region" => ["east", "east", "west"],
+        "quarter" => ["first", "second", "first"],
+        "total" => [15, 20, 70],
+    }
+    .unwrap();
+    assert!(actual.equals(&expected), "{actual:?}");
+}
+
+#[test]
+fn test_unnest_with_ordinality() {
+    let mut ctx = SQLContext::new();
+    let sql = "SELECT * FROM UNNEST([10, 20, 30]) WITH ORDINALITY AS t (val, n)";
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+        "val" => [10i64, 20, 30],
+        "n" => [1 as IdxSize, 2, 3],
+    }
+    .unwrap();
+    assert!(actual.equals(&expected), "{actual:?}");
+}
+
+#[test]
+fn test_prepared_statement() {
+    let mut ctx = prepare_dml_context();
+    let stmt = ctx
+        .prepare("SELECT id, val || ? AS val FROM tbl WHERE id IN (?, ?) ORDER BY id")
+        .unwrap();
+    assert_eq!(stmt.parameters(), ["$1", "$2", "$3"]);
+    for (ids, expected) in [
+        ([1, 3], df! { "id" => [1, 3], "val" => ["a!", "c!"] }),
+        ([2, 5], df! { "id" => [2], "val" => ["b!"] }),
+    ] {
+        let params = [
+            Scalar::from(PlSmallStr::from_static("!")),
+            Scalar::from(ids[0]),
+            Scalar::from(ids[1]),
+        ];
+        let actual = stmt.execute(&params).unwrap().collect().unwrap();
+        assert!(actual.equals(&expected.unwrap()), "{actual:?}");
+    }
+
+    let stmt = ctx
+        .prepare("SELECT COUNT(*) AS n FROM tbl WHERE id > :min_id AND val <> :val")
+        .unwrap();
+    assert_eq!(stmt.parameters(), [":min_id", ":val"]);
+    let params = [
+        ("min_id", Scalar::from(1)),
+        ("val", Scalar::from(PlSmallStr::from_static("c"))),
+    ];
+    let actual = stmt.execute_named(&params).unwrap().collect().unwrap();
+    assert_eq!(actual.column("n").unwrap().idx().unwrap().get(0), Some(1));
+
+    let Err(err) = stmt.execute_named(&[("min_id", Scalar::from(1))]) else {
+        panic!("executing without all the parameter values should fail")
+    };
+    assert!(
+        err.to_string()
+            .contains("no value given for parameter ':val'")
+    );
+    let Err(err) = ctx.execute("SELECT * FROM tbl WHERE id = $1") else {
+        panic!("bind parameters should require a prepared statement")
+    };
+    assert!(err.to_string().contains("requires a prepared statement"));
+
+    for sql in [
+        "SELECT * FROM tbl WHERE id = ? OR id = $1",
+        "SELECT * FROM tbl WHERE id = $1 OR id = ?",
+    ] {
+        let Err(err) = ctx.prepare(sql) else {
+            panic!("mixing '?' and '$n' placeholders should fail: {sql}")
+        };
+        assert!(
+            err.to_string()
+                .contains("cannot mix '?' and '$1' placeholders"),
+            "{sql}: {err}"
+        );
+    }
+}
+
+#[test]
+fn test_prepared_statement_typed_parameters() {
+    let mut ctx = prepare_dml_context();
+    let dates = df! { "dt" => ["2024-01-01", "2024-06-30"] }
+        .unwrap()
+        .lazy()
+        .select([col("dt").str().to_date(StrptimeOptions::default())]);
+    ctx.register("dates", dates);
+
+    // inferred from a comparison, LIKE, and LIMIT/OFFSET; declared by a cast
+    let stmt = ctx
+        .prepare(
+            "SELECT id, CAST(? AS DATE) AS d FROM tbl \
+             WHERE id >= ? AND val LIKE ? ORDER BY id LIMIT ? OFFSET ?",
+        )
+        .unwrap();
+    assert_eq!(
+        stmt.parameter_dtypes(),
+        [
+            DataType::Date,
+            DataType::Int32,
+            DataType::String,
+            IDX_DTYPE,
+            DataType::Int64,
+        ]
+    );
+    let params = [
+        Scalar::from(PlSmallStr::from_static("2024-03-01")),
+        Scalar::from(1),
+        Scalar::from(PlSmallStr::from_static("%")),
+        Scalar::from(2 as IdxSize),
+        Scalar::from(1i64),
+    ];
+    let actual = stmt.execute(&params).unwrap().collect().unwrap();
+    let expected = df! { "id" => [2, 3] }
+        .unwrap()
+        .lazy()
+        .with_column(
+            lit("2024-03-01")
+                .str()
+                .to_date(StrptimeOptions::default())
+                .alias("d"),
+        )
+        .collect()
+        .unwrap();
+    assert!(actual.equals(&expected), "{actual:?}");
+
+    // LIKE with wildcards and regex metacharacters in the bound pattern
+    let stmt = ctx
+        .prepare("SELECT val FROM tbl WHERE val LIKE ? OR val NOT LIKE ? ORDER BY val")
+        .unwrap();
+    for (patterns, expected) in [
+        (["_", "%"], vec!["a", "b", "c"]),
+        (["b%", "_"], vec!["b"]),
+        ([".", "%"], vec![]),
+    ] {
+        let params = patterns.map(|p| Scalar::from(PlSmallStr::from(p)));
+        let actual = stmt.execute(&params).unwrap().collect().unwrap();
+        let actual: Vec<_> = actual["val"].str().unwrap().into_no_null_iter().collect();
+        assert_eq!(actual, expected, "{patterns:?}");
+    }
+
+    // string values are coerced to the temporal type of the compared column
+    let stmt = ctx
+        .prepare("SELECT COUNT(*) AS n FROM dates WHERE dt > ?")
+        .unwrap();
+    assert_eq!(stmt.parameter_dtypes(), [DataType::Date]);
+    let params = [Scalar::from(PlSmallStr::from_static("2024-03-01"))];
+    let actual = stmt.execute(&params).unwrap().collect().unwrap();
+    assert_eq!(actual.column("n").unwrap().idx().unwrap().get(0), Some(1));
+
+    // values that cannot be cast to the parameter type are rejected on bind
+    let stmt = ctx.prepare("SELECT * FROM tbl WHERE id = ?").unwrap();
+    let Err(err) = stmt.execute(&[Scalar::from(PlSmallStr::from_static("x"))]) else {
+        panic!("binding a non-numeric string to an integer parameter should fail")
+    };
+    assert!(
+        err.to_string()
+            .contains("cannot bind value \"x\" to parameter '$1' of type i32"),
+        "{err}"
+    );
+    let Err(err) = ctx.prepare("SELECT * FROM tbl WHERE id = :p OR val = :p") else {
+        panic!("a parameter used with conflicting types should fail")
+    };
+    assert!(
+        err.to_string().contains("parameter ':p' is used as both"),
+        "{err}"
+    );
+}
+
+#[test]
+fn test_describe_and_information_schema() {
+    let mut ctx = SQLContext::new();
+    let df = df! {
+        "id" => [1i64, 2],
+        "tags" => [Series::new("".into(), ["a"]), Series::new("".into(), ["b", "c"])],
+    }
+    .unwrap()
+    .lazy()
+    .with_column(as_struct(vec![col("id"), col("tags")]).alias("info"));
+    ctx.register("tbl", df);
+    ctx.execute("CREATE VIEW tbl_view AS SELECT id FROM tbl")
+        .unwrap()
+        .collect()
+        .unwrap();
+
+    let expected = df! {
+        "column_name" => ["id", "tags", "info"],
+        "data_type" => ["BIGINT", "VARCHAR[]", "STRUCT(id BIGINT, tags VARCHAR[])"],
+        "is_nullable" => [None::<&str>, None, None],
+    }
+    .unwrap();
+    for sql in ["DESCRIBE tbl", "SHOW COLUMNS FROM tbl"] {
+        let actual = ctx.execute(sql).unwrap().collect().unwrap();
+        assert!(actual.equals_missing(&expected), "{sql}: {actual:?}");
+    }
+    let actual = ctx
+        .execute("SHOW COLUMNS FROM tbl LIKE 't%'")
+        .unwrap()
+        .collect()
+        .unwrap();
+    assert_eq!(actual.height(), 1);
+    assert!(ctx.execute("DESCRIBE missing").is_err());
+
+    let actual = ctx
+        .execute("SELECT table_name, table_type FROM information_schema.tables")
+        .unwrap()
+        .collect()
+        .unwrap();
+    let expected = df! {
+        "table_name" => ["tbl", "tbl_view"],
+        "table_type" => ["BASE TABLE", "VIEW"],
+    }
+    .unwrap();
+    assert!(actual.equals(&expected), "{actual:?}");
+
+    let sql = "
+        SELECT c.table_name, c.column_name, c.ordinal_position
+        FROM information_schema.columns AS c
+        WHERE c.data_type = 'BIGINT'
+        ORDER BY c.table_name
+    ";
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+        "table_name" => ["tbl", "tbl_view"],
+        "column_name" => ["id", "id"],
+        "ordinal_position" => [1i64, 1],
+    }
+    .unwrap();
+    assert!(actual.equals(&expected), "{actual:?}");
+
+    // The information schema does not shadow tables (or CTEs) of the same name.
+    let sql = "
+        WITH tables AS (SELECT 'tbl_view' AS name)
+        SELECT table_name FROM information_schema.tables
+        WHERE table_name IN (SELECT name FROM tables)
+    ";
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! { "table_name" => ["tbl_view"] }.unwrap();
+    assert!(actual.equals(&expected), "{actual:?}");
+}
@@ -2,7 +2,10 @@ use polars_core::prelude::*;
 use polars_lazy::prelude::IntoLazy;
 use polars_plan::prelude::{GetOutput, UserDefinedFunction};
 use polars_sql::SQLContext;
-use polars_sql::function_registry::FunctionRegistry;
+use polars_sql::function_registry::{
+    DefaultFunctionRegistry, FunctionRegistry, UserDefinedAggregateFunction,
+    UserDefinedTableFunction,
+};
 
 struct MyFunctionRegistry {
     functions: PlHashMap<String, UserDefinedFunction>,
@@ -98,3 +101,95 @@ fn test_udfs() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_default_registry_udfs() -> PolarsResult<()> {
+    let mut ctx =
+        SQLContext::new().with_function_registry(Arc::new(DefaultFunctionRegistry::new()));
+    let df = df! {
+        "k" => &["x", "y", "x"],
+        "v" => &[1, 2, 4],
+    }?;
+    ctx.register("df", df.lazy());
+
+    // scalar UDF; the argument is cast to the signature type
+    let halve = UserDefinedFunction::new(
+        "halve".into(),
+        vec![Field::new("value".into(), DataType::Float64)],
+        GetOutput::from_type(DataType::Float64),
+        |c: &mut [Column]| Ok(Some(&c[0] / 2)),
+    );
+    ctx.registry_mut().register("HALVE", halve)?;
+    let res = ctx.execute("SELECT halve(v) AS h FROM df")?.collect()?;
+    let expected = df! { "h" => &[0.5, 1.0, 2.0] }?;
+    assert!(expected.equals(&res));
+    assert!(ctx.execute("SELECT halve(v, v) FROM df").is_err());
+    assert!(
+        ctx.execute("SELECT halve(k) FROM df")
+            .and_then(|lf| lf.collect())
+            .is_err()
+    );
+
+    // aggregate UDF, with and without GROUP BY
+    let span = UserDefinedAggregateFunction::new(
+        "span".into(),
+        vec![Field::new("value".into(), DataType::Int64)],
+        DataType::Int64,
+        |c: &[Column]| {
+            let s = c[0].as_materialized_series();
+            let span = match (s.max::<i64>()?, s.min::<i64>()?) {
+                (Some(max), Some(min)) => AnyValue::Int64(max - min),
+                _ => AnyValue::Null,
+            };
+            Ok(Scalar::new(DataType::Int64, span))
+        },
+    );
+    ctx.registry_mut().register_aggregate("span", span)?;
+    let res = ctx
+        .execute("SELECT k, span(v) AS s FROM df GROUP BY k ORDER BY k")?
+        .collect()?;
+    let expected = df! {
+        "k" => &["x", "y"],
+        "s" => &[3i64, 0],
+    }?;
+    assert!(expected.equals(&res));
+    let res = ctx.execute("SELECT span(v) AS s FROM df")?.collect()?;
+    assert!(df! { "s" => &[3i64] }?.equals(&res));
+    let res = ctx
+        .execute("SELECT k, span(v) AS s, SUM(v) AS t FROM df GROUP BY k HAVING s > 0 ORDER BY k")?
+        .collect()?;
+    let expected = df! {
+        "k" => &["x"],
+        "s" => &[3i64],
+        "t" => &[5],
+    }?;
+    assert!(expected.equals(&res), "{res:?}");
+    let res = ctx
+        .execute("SELECT span(v) AS s FROM df WHERE v > 10")?
+        .collect()?;
+    assert!(
+        df! { "s" => &[None::<i64>] }?.equals_missing(&res),
+        "{res:?}"
+    );
+
+    // table UDF; the returned table is checked against the declared schema
+    let schema = Arc::new(Schema::from_iter([Field::new("n".into(), DataType::Int64)]));
+    let numbers = UserDefinedTableFunction::new(
+        "numbers".into(),
+        vec![Field::new("count".into(), DataType::Int32)],
+        schema,
+        |args: &[Scalar]| {
+            let count = args[0].value().extract::<i32>().unwrap_or(0);
+            Ok(df! { "n" => (1..=count).collect::<Vec<_>>() }?.lazy())
+        },
+    );
+    ctx.registry_mut()
+        .register_table_function("numbers", numbers)?;
+    let res = ctx
+        .execute("SELECT n FROM numbers(2 + 1) AS t WHERE n > 1")?
+        .collect()?;
+    assert!(df! { "n" => &[2i64, 3] }?.equals(&res));
+    assert!(ctx.execute("SELECT * FROM numbers(v)").is_err());
+    assert!(ctx.execute("SELECT * FROM unknown_fn(1)").is_err());
+    Ok(())
+}
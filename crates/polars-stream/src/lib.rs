@@ -4,7 +4,7 @@ mod skeleton;
 
 use std::sync::LazyLock;
 
-pub use skeleton::run_query;
+pub use skeleton::{physical_plan_json, run_query};
 
 mod execute;
 pub(crate) mod expression;
@@ -311,8 +311,11 @@ impl SinkNode for IpcSinkNode {
             let writer = BufWriter::new(&mut *file);
             let mut writer = IpcWriter::new(writer)
                 .with_compression(write_options.compression)
-                .with_parallel(false)
-                .batched(&input_schema)?;
+                .with_parallel(false);
+            if let Some(custom_metadata) = sink_options.custom_metadata() {
+                writer.set_custom_schema_metadata(custom_metadata);
+            }
+            let mut writer = writer.batched(&input_schema)?;
 
             while let Ok((dicts, record_batch)) = io_rx.recv().await {
                 // @TODO: At the moment this is a sync write, this is not ideal because we can only
@@ -264,7 +264,8 @@ impl SinkNode for ParquetSinkNode {
                 parquet_schema,
                 write_options,
             ));
-            let mut writer = BatchedWriter::new(file_writer, encodings, write_options, false);
+            let mut writer = BatchedWriter::new(file_writer, encodings, write_options, false)
+                .with_custom_metadata(sink_options.custom_metadata());
 
             let num_parquet_columns = writer.parquet_schema().leaves().len();
             while let Ok(current_row_group) = io_rx.recv().await {
@@ -2,13 +2,16 @@ use std::fmt::Write;
 
 use polars_plan::dsl::FileScan;
 use polars_plan::plans::expr_ir::ExprIR;
-use polars_plan::plans::{AExpr, EscapeLabel};
+use polars_plan::plans::{
+    AExpr, EscapeLabel, JSON_PLAN_FORMAT_VERSION, exprs_to_json, schema_to_json,
+};
 use polars_plan::prelude::FileType;
 use polars_utils::arena::Arena;
 use polars_utils::itertools::Itertools;
+use serde_json::json;
 use slotmap::{Key, SecondaryMap, SlotMap};
 
-use super::{PhysNode, PhysNodeKey, PhysNodeKind};
+use super::{PhysNode, PhysNodeKey, PhysNodeKind, PhysStream};
 
 fn escape_graphviz(s: &str) -> String {
     s.replace('\\', "\\\\")
@@ -312,3 +315,178 @@ pub fn visualize_plan(
     out.push("}".to_string());
     out.join("\n")
 }
+
+struct JsonNodeInfo<'a> {
+    kind: &'static str,
+    inputs: Vec<PhysStream>,
+    exprs: Vec<ExprIR>,
+    predicate: Option<&'a ExprIR>,
+    estimated_rows: Option<usize>,
+}
+
+fn json_node_info(kind: &PhysNodeKind) -> JsonNodeInfo<'_> {
+    let mut info = JsonNodeInfo {
+        kind: "",
+        inputs: vec![],
+        exprs: vec![],
+        predicate: None,
+        estimated_rows: None,
+    };
+    let (name, inputs) = match kind {
+        PhysNodeKind::InMemorySource { df } => {
+            info.estimated_rows = Some(df.height());
+            ("in-memory-source", vec![])
+        },
+        #[cfg(feature = "python")]
+        PhysNodeKind::PythonScan { .. } => ("python-scan", vec![]),
+        PhysNodeKind::SinkMultiple { sinks } => (
+            "sink-multiple",
+            sinks.iter().map(|s| PhysStream::first(*s)).collect(),
+        ),
+        PhysNodeKind::Select {
+            input,
+            selectors,
+            extend_original,
+        } => {
+            info.exprs.clone_from(selectors);
+            let name = if *extend_original {
+                "with-columns"
+            } else {
+                "select"
+            };
+            (name, vec![*input])
+        },
+        PhysNodeKind::WithRowIndex { input, .. } => ("with-row-index", vec![*input]),
+        PhysNodeKind::InputIndependentSelect { selectors } => {
+            info.exprs.clone_from(selectors);
+            ("input-independent-select", vec![])
+        },
+        PhysNodeKind::Reduce { input, exprs } => {
+            info.exprs.clone_from(exprs);
+            ("reduce", vec![*input])
+        },
+        PhysNodeKind::StreamingSlice { input, .. } => ("slice", vec![*input]),
+        PhysNodeKind::NegativeSlice { input, .. } => ("negative-slice", vec![*input]),
+        PhysNodeKind::Filter { input, predicate } => {
+            info.predicate = Some(predicate);
+            ("filter", vec![*input])
+        },
+        PhysNodeKind::SimpleProjection { input, .. } => ("simple-projection", vec![*input]),
+        PhysNodeKind::InMemorySink { input } => ("in-memory-sink", vec![*input]),
+        PhysNodeKind::FileSink { input, .. } => ("file-sink", vec![*input]),
+        PhysNodeKind::PartitionSink { input, .. } => ("partition-sink", vec![*input]),
+        PhysNodeKind::InMemoryMap { input, .. } => ("in-memory-map", vec![*input]),
+        PhysNodeKind::Map { input, .. } => ("map", vec![*input]),
+        PhysNodeKind::Sort {
+            input, by_column, ..
+        } => {
+            info.exprs.clone_from(by_column);
+            ("sort", vec![*input])
+        },
+        PhysNodeKind::OrderedUnion { inputs } => ("ordered-union", inputs.clone()),
+        PhysNodeKind::Zip {
+            inputs,
+            null_extend,
+        } => {
+            let name = if *null_extend {
+                "zip-null-extend"
+            } else {
+                "zip"
+            };
+            (name, inputs.clone())
+        },
+        PhysNodeKind::Multiplexer { input } => ("multiplexer", vec![*input]),
+        PhysNodeKind::MultiScan { predicate, .. } => {
+            info.predicate = predicate.as_ref();
+            ("multi-scan-source", vec![])
+        },
+        PhysNodeKind::FileScan {
+            file_info,
+            predicate,
+            ..
+        } => {
+            info.predicate = predicate.as_ref();
+            info.estimated_rows = file_info.row_estimation.0;
+            ("file-scan-source", vec![])
+        },
+        PhysNodeKind::GroupBy { input, key, aggs } => {
+            info.exprs = key.iter().chain(aggs).cloned().collect();
+            ("group-by", vec![*input])
+        },
+        PhysNodeKind::InMemoryJoin {
+            input_left,
+            input_right,
+            left_on,
+            right_on,
+            ..
+        } => {
+            info.exprs = left_on.iter().chain(right_on).cloned().collect();
+            ("in-memory-join", vec![*input_left, *input_right])
+        },
+        PhysNodeKind::EquiJoin {
+            input_left,
+            input_right,
+            left_on,
+            right_on,
+            ..
+        } => {
+            info.exprs = left_on.iter().chain(right_on).cloned().collect();
+            ("equi-join", vec![*input_left, *input_right])
+        },
+        #[cfg(feature = "merge_sorted")]
+        PhysNodeKind::MergeSorted {
+            input_left,
+            input_right,
+            ..
+        } => ("merge-sorted", vec![*input_left, *input_right]),
+    };
+    info.kind = name;
+    info.inputs = inputs;
+    info
+}
+
+/// Machine-readable JSON representation of a physical plan.
+///
+/// The output follows the same layout as the JSON representation of the IR: an object with the
+/// format `version`, the `root` node id and a flat list of `nodes`. Inputs refer to the `node`
+/// and output `port` they read from.
+pub fn plan_to_json(
+    root: PhysNodeKey,
+    phys_sm: &SlotMap<PhysNodeKey, PhysNode>,
+    expr_arena: &Arena<AExpr>,
+) -> String {
+    let mut visited: SecondaryMap<PhysNodeKey, ()> = SecondaryMap::new();
+    let mut stack = vec![root];
+    let mut nodes = vec![];
+    while let Some(node_key) = stack.pop() {
+        if visited.insert(node_key, ()).is_some() {
+            continue;
+        }
+
+        let node = &phys_sm[node_key];
+        let info = json_node_info(&node.kind);
+        let inputs = info
+            .inputs
+            .iter()
+            .map(|i| json!({ "node": i.node.data().as_ffi(), "port": i.port }))
+            .collect_vec();
+        nodes.push(json!({
+            "id": node_key.data().as_ffi(),
+            "kind": info.kind,
+            "inputs": inputs,
+            "schema": schema_to_json(&node.output_schema),
+            "exprs": exprs_to_json(&info.exprs, expr_arena),
+            "predicate": info.predicate.map(|p| p.display(expr_arena).to_string()),
+            "estimated_rows": info.estimated_rows,
+        }));
+
+        stack.extend(info.inputs.iter().rev().map(|i| i.node));
+    }
+
+    json!({
+        "version": JSON_PLAN_FORMAT_VERSION,
+        "root": root.data().as_ffi(),
+        "nodes": nodes,
+    })
+    .to_string()
+}
@@ -12,6 +12,19 @@ use slotmap::{SecondaryMap, SlotMap};
 
 use crate::physical_plan::PhysNodeKind;
 
+/// Lower the IR plan to a physical plan and return its JSON representation.
+pub fn physical_plan_json(
+    node: Node,
+    ir_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+) -> PolarsResult<String> {
+    let mut phys_sm = SlotMap::with_capacity_and_key(ir_arena.len());
+    let root = crate::physical_plan::build_physical_plan(node, ir_arena, expr_arena, &mut phys_sm)?;
+    Ok(crate::physical_plan::plan_to_json(
+        root, &phys_sm, expr_arena,
+    ))
+}
+
 pub fn run_query(
     node: Node,
     ir_arena: &mut Arena<IR>,
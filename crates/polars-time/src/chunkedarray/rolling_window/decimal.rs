@@ -0,0 +1,91 @@
+use polars_core::chunked_array::arithmetic::_get_decimal_scale_div;
+use polars_core::chunked_array::ops::decimal::DecimalMoments;
+
+use super::*;
+
+#[derive(Clone, Copy)]
+pub(super) enum DecimalRollingAgg {
+    Sum,
+    Mean,
+    Var,
+    Std,
+}
+
+/// Exact fixed window sum, mean, variance and standard deviation of a decimal column.
+///
+/// The sums over the window are kept in 256-bit integers, so no precision is lost when
+/// values leave the window.
+pub(super) fn rolling_decimal(
+    ca: &DecimalChunked,
+    options: RollingOptionsFixedWindow,
+    agg: DecimalRollingAgg,
+) -> PolarsResult<Series> {
+    polars_ensure!(options.min_periods <= options.window_size, InvalidOperation: "`min_periods` should be <= `window_size`");
+    polars_ensure!(
+        options.weights.is_none(),
+        InvalidOperation: "weighted rolling aggregations are not supported for decimals, cast to float first"
+    );
+    let ddof = match options.fn_params {
+        Some(RollingFnParams::Var(params)) => params.ddof,
+        _ => 1,
+    };
+    let scale = ca.scale();
+    let (precision, out_scale) = match agg {
+        DecimalRollingAgg::Sum => (ca.precision(), scale),
+        _ => (None, _get_decimal_scale_div(scale)),
+    };
+    let mode = RoundMode::default();
+
+    let phys = ca.physical().rechunk();
+    let arr = phys.downcast_as_array();
+    let len = arr.len();
+    let window_size = options.window_size;
+    let offsets = |i: usize| {
+        if options.center {
+            let right = window_size.div_ceil(2);
+            (i.saturating_sub(window_size - right), len.min(i + right))
+        } else {
+            (i.saturating_sub(window_size - 1), i + 1)
+        }
+    };
+
+    let mut moments = DecimalMoments::new();
+    let (mut start, mut end) = (0, 0);
+    let mut out = Vec::with_capacity(len);
+    for i in 0..len {
+        let (new_start, new_end) = offsets(i);
+        for v in (start..new_start).filter_map(|j| arr.get(j)) {
+            moments.remove(v);
+        }
+        for v in (end.max(new_start)..new_end).filter_map(|j| arr.get(j)) {
+            moments.insert(v);
+        }
+        (start, end) = (new_start, new_end);
+        if moments.has_overflow() && matches!(agg, DecimalRollingAgg::Var | DecimalRollingAgg::Std)
+        {
+            // Rebuild the sum of squares from the current window, it may fit again.
+            moments = DecimalMoments::new();
+            for v in (start..end).filter_map(|j| arr.get(j)) {
+                moments.insert(v);
+            }
+        }
+
+        let v = if (moments.count() as usize) < options.min_periods.max(1) {
+            None
+        } else {
+            match agg {
+                DecimalRollingAgg::Sum => Some(moments.sum()?),
+                DecimalRollingAgg::Mean => moments.mean(scale, out_scale, mode)?,
+                DecimalRollingAgg::Var => moments.var(scale, out_scale, ddof, mode)?,
+                DecimalRollingAgg::Std => moments.std(scale, out_scale, ddof, mode)?,
+            }
+        };
+        out.push(v);
+    }
+
+    Ok(
+        Int128Chunked::from_iter_options(ca.name().clone(), out.into_iter())
+            .into_decimal_unchecked(precision, out_scale)
+            .into_series(),
+    )
+}
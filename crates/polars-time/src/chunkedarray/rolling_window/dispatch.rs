@@ -1,6 +1,8 @@
 use polars_core::{with_match_physical_float_polars_type, with_match_physical_numeric_polars_type};
 use polars_ops::series::SeriesMethods;
 
+#[cfg(all(feature = "rolling_window", feature = "dtype-decimal"))]
+use super::decimal::{DecimalRollingAgg, rolling_decimal};
 use super::*;
 use crate::prelude::*;
 use crate::series::AsSeries;
@@ -177,6 +179,10 @@ pub trait SeriesOpsTime: AsSeries {
     /// See: [`RollingAgg::rolling_mean`]
     #[cfg(feature = "rolling_window")]
     fn rolling_mean(&self, options: RollingOptionsFixedWindow) -> PolarsResult<Series> {
+        #[cfg(feature = "dtype-decimal")]
+        if let Ok(ca) = self.as_series().decimal() {
+            return rolling_decimal(ca, options, DecimalRollingAgg::Mean);
+        }
         let s = self.as_series().to_float()?;
         with_match_physical_float_polars_type!(s.dtype(), |$T| {
             let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
@@ -219,6 +225,10 @@ pub trait SeriesOpsTime: AsSeries {
     /// Apply a rolling sum to a Series.
     #[cfg(feature = "rolling_window")]
     fn rolling_sum(&self, options: RollingOptionsFixedWindow) -> PolarsResult<Series> {
+        #[cfg(feature = "dtype-decimal")]
+        if let Ok(ca) = self.as_series().decimal() {
+            return rolling_decimal(ca, options, DecimalRollingAgg::Sum);
+        }
         let mut s = self.as_series().clone();
         if options.weights.is_some() {
             s = s.to_float()?;
@@ -307,6 +317,17 @@ pub trait SeriesOpsTime: AsSeries {
     /// Apply a rolling min to a Series.
     #[cfg(feature = "rolling_window")]
     fn rolling_min(&self, options: RollingOptionsFixedWindow) -> PolarsResult<Series> {
+        #[cfg(feature = "dtype-decimal")]
+        if let Ok(ca) = self.as_series().decimal() {
+            if options.weights.is_none() {
+                let out = ca.physical().clone().into_series().rolling_min(options)?;
+                return Ok(out
+                    .i128()?
+                    .clone()
+                    .into_decimal_unchecked(ca.precision(), ca.scale())
+                    .into_series());
+            }
+        }
         let mut s = self.as_series().clone();
         if options.weights.is_some() {
             s = s.to_float()?;
@@ -362,6 +383,17 @@ pub trait SeriesOpsTime: AsSeries {
     /// Apply a rolling max to a Series.
     #[cfg(feature = "rolling_window")]
     fn rolling_max(&self, options: RollingOptionsFixedWindow) -> PolarsResult<Series> {
+        #[cfg(feature = "dtype-decimal")]
+        if let Ok(ca) = self.as_series().decimal() {
+            if options.weights.is_none() {
+                let out = ca.physical().clone().into_series().rolling_max(options)?;
+                return Ok(out
+                    .i128()?
+                    .clone()
+                    .into_decimal_unchecked(ca.precision(), ca.scale())
+                    .into_series());
+            }
+        }
         let mut s = self.as_series().clone();
         if options.weights.is_some() {
             s = s.to_float()?;
@@ -411,6 +443,10 @@ pub trait SeriesOpsTime: AsSeries {
     /// Apply a rolling variance to a Series.
     #[cfg(feature = "rolling_window")]
     fn rolling_var(&self, options: RollingOptionsFixedWindow) -> PolarsResult<Series> {
+        #[cfg(feature = "dtype-decimal")]
+        if let Ok(ca) = self.as_series().decimal() {
+            return rolling_decimal(ca, options, DecimalRollingAgg::Var);
+        }
         let s = self.as_series().to_float()?;
 
         with_match_physical_float_polars_type!(s.dtype(), |$T| {
@@ -452,6 +488,10 @@ pub trait SeriesOpsTime: AsSeries {
     /// Apply a rolling std_dev to a Series.
     #[cfg(feature = "rolling_window")]
     fn rolling_std(&self, options: RollingOptionsFixedWindow) -> PolarsResult<Series> {
+        #[cfg(feature = "dtype-decimal")]
+        if let Ok(ca) = self.as_series().decimal() {
+            return rolling_decimal(ca, options, DecimalRollingAgg::Std);
+        }
         self.rolling_var(options).map(|mut s| {
             match s.dtype().clone() {
                 DataType::Float32 => {
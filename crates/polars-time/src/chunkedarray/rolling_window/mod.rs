@@ -1,3 +1,5 @@
+#[cfg(all(feature = "rolling_window", feature = "dtype-decimal"))]
+mod decimal;
 mod dispatch;
 #[cfg(feature = "rolling_window_by")]
 mod rolling_kernels;
@@ -353,6 +353,35 @@ pub trait StringMethods: AsString {
             }
         }
     }
+
+    #[cfg(feature = "dtype-interval")]
+    /// Parse duration strings such as `"1mo 2d 3h"` or `"2 months 5 days"` into an
+    /// [`IntervalChunked`]. Each whitespace separated part may carry its own minus sign.
+    ///
+    /// If `strict` is false, unparsable values become null instead of raising an error.
+    fn as_interval(&self, strict: bool) -> PolarsResult<IntervalChunked> {
+        let string_ca = self.as_string();
+        let parse = |s: &str| -> PolarsResult<months_days_ns> {
+            let by_parts = s.split_whitespace().try_fold(
+                months_days_ns::new(0, 0, 0),
+                |acc, part| -> PolarsResult<_> {
+                    let v = crate::Duration::try_parse(part)?.to_interval()?;
+                    Ok(months_days_ns::new(
+                        acc.months() + v.months(),
+                        acc.days() + v.days(),
+                        acc.ns() + v.ns(),
+                    ))
+                },
+            );
+            by_parts.or_else(|_| crate::Duration::try_parse_interval(s)?.to_interval())
+        };
+        let ca = if strict {
+            string_ca.try_apply_nonnull_values_generic(|s| parse(s).map(interval_to_i128))?
+        } else {
+            unary_elementwise(string_ca, |opt_s| parse(opt_s?).ok().map(interval_to_i128))
+        };
+        Ok(ca.into_interval())
+    }
 }
 
 pub trait AsString {
@@ -57,36 +57,44 @@ fn apply_offsets_to_datetime(
     }
 }
 
-pub fn impl_offset_by(ts: &Series, offsets: &Series) -> PolarsResult<Series> {
-    let offsets = offsets.str()?;
-    let dtype = ts.dtype();
-
-    // Sortedness may not be preserved for non-constant durations,
-    // see https://github.com/pola-rs/polars/issues/19608 for a counterexample.
-    // Constant durations (e.g. 2 hours) always preserve sortedness.
-    let tz = match dtype {
-        DataType::Date => None,
-        DataType::Datetime(_, tz) => tz.clone(),
-        _ => polars_bail!(InvalidOperation: "expected Date or Datetime, got {}", dtype),
+#[cfg(feature = "dtype-interval")]
+fn apply_intervals_to_datetime(
+    datetime: &Logical<DatetimeType, Int64Type>,
+    offsets: &IntervalChunked,
+    time_zone: Option<&Tz>,
+) -> PolarsResult<Int64Chunked> {
+    let offset_fn = match datetime.time_unit() {
+        TimeUnit::Milliseconds => Duration::add_ms,
+        TimeUnit::Microseconds => Duration::add_us,
+        TimeUnit::Nanoseconds => Duration::add_ns,
     };
-    let preserve_sortedness = match offsets.len() {
-        1 => match offsets.get(0) {
-            Some(offset) => {
-                let offset = Duration::parse(offset);
-                offset.is_constant_duration(tz.as_deref())
-            },
-            None => false,
-        },
-        _ => false,
+    // Months are added before days, and days before nanoseconds, so that e.g. adding
+    // "1mo 1d" to January 31st gives March 1st.
+    let add_interval = |timestamp: i64, offset: i128| {
+        Duration::split_interval(i128_to_interval(offset))
+            .iter()
+            .try_fold(timestamp, |t, d| offset_fn(d, t, time_zone))
     };
+    broadcast_try_binary_elementwise(datetime, offsets.physical(), |timestamp_opt, offset_opt| {
+        match (timestamp_opt, offset_opt) {
+            (Some(timestamp), Some(offset)) => add_interval(timestamp, offset).map(Some),
+            _ => Ok(None),
+        }
+    })
+}
 
-    let out = match dtype {
+/// Apply `apply` to `ts` viewed as a `Datetime`, casting `Date` input to and from milliseconds.
+fn offset_temporal<F>(ts: &Series, apply: F) -> PolarsResult<Series>
+where
+    F: Fn(&Logical<DatetimeType, Int64Type>, Option<&Tz>) -> PolarsResult<Int64Chunked>,
+{
+    match ts.dtype() {
         DataType::Date => {
             let ts = ts
                 .cast(&DataType::Datetime(TimeUnit::Milliseconds, None))
                 .unwrap();
             let datetime = ts.datetime().unwrap();
-            let out = apply_offsets_to_datetime(datetime, offsets, None)?;
+            let out = apply(datetime, None)?;
             out.cast(&DataType::Datetime(TimeUnit::Milliseconds, None))
                 .unwrap()
                 .cast(&DataType::Date)
@@ -96,17 +104,70 @@ pub fn impl_offset_by(ts: &Series, offsets: &Series) -> PolarsResult<Series> {
 
             let out = match tz {
                 #[cfg(feature = "timezones")]
-                Some(tz) => {
-                    apply_offsets_to_datetime(datetime, offsets, tz.parse::<Tz>().ok().as_ref())?
-                },
-                _ => apply_offsets_to_datetime(datetime, offsets, None)?,
+                Some(tz) => apply(datetime, tz.parse::<Tz>().ok().as_ref())?,
+                _ => apply(datetime, None)?,
             };
             out.cast(&DataType::Datetime(*tu, tz.clone()))
         },
         dt => polars_bail!(
             ComputeError: "cannot use 'offset_by' on Series of datatype {}", dt,
         ),
+    }
+}
+
+pub fn impl_offset_by(ts: &Series, offsets: &Series) -> PolarsResult<Series> {
+    let dtype = ts.dtype();
+
+    // Sortedness may not be preserved for non-constant durations,
+    // see https://github.com/pola-rs/polars/issues/19608 for a counterexample.
+    // Constant durations (e.g. 2 hours) always preserve sortedness.
+    let tz = match dtype {
+        DataType::Date => None,
+        DataType::Datetime(_, tz) => tz.clone(),
+        _ => polars_bail!(InvalidOperation: "expected Date or Datetime, got {}", dtype),
+    };
+
+    #[cfg(feature = "dtype-interval")]
+    if offsets.dtype() == &DataType::Interval {
+        let offsets = offsets.interval()?;
+        let preserve_sortedness = match offsets.len() {
+            1 => match offsets.iter_intervals().next().flatten() {
+                Some(offset) => Duration::split_interval(offset)
+                    .iter()
+                    .all(|d| d.is_constant_duration(tz.as_deref())),
+                None => false,
+            },
+            _ => false,
+        };
+        let out = offset_temporal(ts, |datetime, time_zone| {
+            apply_intervals_to_datetime(datetime, offsets, time_zone)
+        });
+        return set_offset_sorted_flag(ts, out, preserve_sortedness);
+    }
+
+    let offsets = offsets.str()?;
+    let preserve_sortedness = match offsets.len() {
+        1 => match offsets.get(0) {
+            Some(offset) => {
+                let offset = Duration::parse(offset);
+                offset.is_constant_duration(tz.as_deref())
+            },
+            None => false,
+        },
+        _ => false,
     };
+
+    let out = offset_temporal(ts, |datetime, time_zone| {
+        apply_offsets_to_datetime(datetime, offsets, time_zone)
+    });
+    set_offset_sorted_flag(ts, out, preserve_sortedness)
+}
+
+fn set_offset_sorted_flag(
+    ts: &Series,
+    out: PolarsResult<Series>,
+    preserve_sortedness: bool,
+) -> PolarsResult<Series> {
     if preserve_sortedness {
         out.map(|mut out| {
             out.set_sorted_flag(ts.is_sorted_flag());
@@ -11,6 +11,8 @@ use arrow::temporal_conversions::{
 };
 use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
 use polars_core::datatypes::DataType;
+#[cfg(feature = "dtype-interval")]
+use polars_core::datatypes::months_days_ns;
 use polars_core::prelude::{
     PolarsResult, datetime_to_timestamp_ms, datetime_to_timestamp_ns, datetime_to_timestamp_us,
     polars_bail,
@@ -456,6 +458,33 @@ impl Duration {
         self.negative
     }
 
+    /// Convert to a calendar interval. Weeks are counted as 7 days.
+    #[cfg(feature = "dtype-interval")]
+    pub fn to_interval(&self) -> PolarsResult<months_days_ns> {
+        let sign = if self.negative { -1 } else { 1 };
+        let months = i32::try_from(self.months * sign).ok();
+        let days = self
+            .weeks
+            .checked_mul(7)
+            .and_then(|d| d.checked_add(self.days))
+            .and_then(|d| i32::try_from(d * sign).ok());
+        match (months, days) {
+            (Some(months), Some(days)) => Ok(months_days_ns::new(months, days, self.nsecs * sign)),
+            _ => polars_bail!(ComputeError: "duration '{}' does not fit in an interval", self),
+        }
+    }
+
+    /// Split a calendar interval into its month, day and nanosecond parts, which have to be
+    /// applied in that order.
+    #[cfg(feature = "dtype-interval")]
+    pub(crate) fn split_interval(v: months_days_ns) -> [Self; 3] {
+        [
+            Self::from_months(v.months() as i64),
+            Self::from_days(v.days() as i64),
+            Self::from_nsecs(v.ns()),
+        ]
+    }
+
     /// Estimated duration of the window duration. Not a very good one if not a constant duration.
     #[doc(hidden)]
     pub const fn duration_ns(&self) -> i64 {
@@ -307,6 +307,13 @@
 //! | UInt16                  | dtype-u16         |
 //! | Categorical             | dtype-categorical |
 //! | Struct                  | dtype-struct      |
+//! | Map                     | dtype-map         |
+//! | Interval                | dtype-interval    |
+//! | Uuid                    | dtype-uuid        |
+//! | Float16                 | dtype-f16         |
+//! | Decimal256              | dtype-decimal256  |
+//! | Variant                 | dtype-variant     |
+//! | Extension               | dtype-extension   |
 //!
 //!
 //! Or you can choose one of the preconfigured pre-sets.
@@ -0,0 +1,351 @@
+use super::*;
+
+fn decimal(name: &str, values: &[Option<i128>], scale: usize) -> Series {
+    Int128Chunked::from_slice_options(name.into(), values)
+        .into_decimal_unchecked(None, scale)
+        .into_series()
+}
+
+fn mantissas(s: &Series) -> Vec<Option<i128>> {
+    Vec::from(s.decimal().unwrap().physical())
+}
+
+#[test]
+fn test_decimal_arithmetic_scales() -> PolarsResult<()> {
+    // [1.00, 2.50, -3.25] and [0.3, 0.0, 2.0]
+    let a = decimal("a", &[Some(100), Some(250), Some(-325)], 2);
+    let b = decimal("b", &[Some(3), Some(0), Some(20)], 1);
+
+    let out = (&a + &b)?;
+    assert_eq!(out.dtype(), &DataType::Decimal(None, Some(2)));
+    assert_eq!(mantissas(&out), [Some(130), Some(250), Some(-125)]);
+
+    // Both sides are brought to a common scale first.
+    let out = (&a * &b)?;
+    assert_eq!(out.dtype(), &DataType::Decimal(None, Some(4)));
+    assert_eq!(mantissas(&out), [Some(3000), Some(0), Some(-65000)]);
+
+    // Division adds 4 to the scale of the dividend, division by zero is null.
+    let out = (&a / &b)?;
+    assert_eq!(out.dtype(), &DataType::Decimal(None, Some(6)));
+    assert_eq!(mantissas(&out), [Some(3333333), None, Some(-1625000)]);
+
+    let out = (&a % &b)?;
+    assert_eq!(out.dtype(), &DataType::Decimal(None, Some(2)));
+    assert_eq!(mantissas(&out), [Some(10), None, Some(-125)]);
+
+    // 2 / 3 is rounded, not truncated.
+    let two = decimal("a", &[Some(2)], 0);
+    let three = decimal("b", &[Some(3)], 0);
+    assert_eq!(mantissas(&(&two / &three)?), [Some(6667)]);
+    Ok(())
+}
+
+#[test]
+fn test_decimal_division_with_mode() -> PolarsResult<()> {
+    // [2, -2] / 3
+    let a = decimal("a", &[Some(2), Some(-2)], 0);
+    let b = decimal("b", &[Some(3), Some(3)], 0);
+    let (a, b) = (a.decimal()?, b.decimal()?);
+    let div = |mode| -> PolarsResult<_> { Ok(Vec::from(a.div_with_mode(b, mode)?.physical())) };
+    assert_eq!(div(RoundMode::TowardZero)?, [Some(6666), Some(-6666)]);
+    assert_eq!(div(RoundMode::Floor)?, [Some(6666), Some(-6667)]);
+    assert_eq!(div(RoundMode::Ceiling)?, [Some(6667), Some(-6666)]);
+    // The operators use the default mode.
+    assert_eq!(
+        div(RoundMode::default())?,
+        mantissas(&(&a.clone().into_series() / &b.clone().into_series())?)
+    );
+    Ok(())
+}
+
+#[test]
+fn test_decimal_overflow_is_an_error() -> PolarsResult<()> {
+    let big = decimal("a", &[Some(10i128.pow(37))], 0);
+    let hundred = decimal("b", &[Some(100)], 0);
+    let err = (&big * &hundred).unwrap_err();
+    assert!(err.to_string().contains("decimal overflow"));
+    assert!((&big * &decimal("b", &[Some(9)], 0)).is_ok());
+
+    let nines = decimal(
+        "a",
+        &[Some(9 * 10i128.pow(37)), Some(9 * 10i128.pow(37))],
+        0,
+    );
+    assert!((&nines + &nines).is_err());
+    assert!(nines.sum_reduce().is_err());
+    Ok(())
+}
+
+#[test]
+fn test_decimal_moments_are_exact() -> PolarsResult<()> {
+    // [1.00, 2.00, 2.00, null]
+    let s = decimal("x", &[Some(100), Some(200), Some(200), None], 2);
+
+    let mean = s.mean_reduce();
+    assert_eq!(mean.dtype(), &DataType::Decimal(None, Some(6)));
+    assert_eq!(mean.value(), &AnyValue::Decimal(1666667, 6));
+
+    let var = s.var_reduce(1)?;
+    assert_eq!(var.value(), &AnyValue::Decimal(333333, 6));
+
+    let std = s.std_reduce(1)?;
+    assert_eq!(std.value(), &AnyValue::Decimal(577350, 6));
+
+    let empty = decimal("x", &[None], 2);
+    assert!(empty.mean_reduce().is_null());
+    assert!(empty.std_reduce(1)?.is_null());
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "lazy")]
+fn test_decimal_group_by_moments() -> PolarsResult<()> {
+    let df = DataFrame::new(vec![
+        Column::new("g".into(), ["a", "a", "b"]),
+        decimal("x", &[Some(100), Some(200), Some(200)], 2).into_column(),
+    ])?;
+    let out = df
+        .lazy()
+        .group_by([col("g")])
+        .agg([
+            col("x").sum().alias("sum"),
+            col("x").mean().alias("mean"),
+            col("x").std(1).alias("std"),
+        ])
+        .sort(["g"], Default::default())
+        .collect()?;
+
+    let sum = out.column("sum")?.as_materialized_series();
+    assert_eq!(sum.dtype(), &DataType::Decimal(None, Some(2)));
+    assert_eq!(mantissas(sum), [Some(300), Some(200)]);
+
+    let mean = out.column("mean")?.as_materialized_series();
+    assert_eq!(mean.dtype(), &DataType::Decimal(None, Some(6)));
+    assert_eq!(mantissas(mean), [Some(1500000), Some(2000000)]);
+
+    let std = out.column("std")?.as_materialized_series();
+    assert_eq!(std.dtype(), &DataType::Decimal(None, Some(6)));
+    assert_eq!(mantissas(std), [Some(707107), None]);
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "lazy", feature = "round_series"))]
+fn test_decimal_round_modes() -> PolarsResult<()> {
+    // [2.25, -2.25, 2.35]
+    let df = DataFrame::new(vec![
+        decimal("x", &[Some(225), Some(-225), Some(235)], 2).into_column(),
+    ])?;
+    let modes = [
+        (RoundMode::HalfToEven, [220, -220, 240]),
+        (RoundMode::HalfAwayFromZero, [230, -230, 240]),
+        (RoundMode::TowardZero, [220, -220, 230]),
+        (RoundMode::AwayFromZero, [230, -230, 240]),
+        (RoundMode::Floor, [220, -230, 230]),
+        (RoundMode::Ceiling, [230, -220, 240]),
+    ];
+    for (mode, expected) in modes {
+        let out = df
+            .clone()
+            .lazy()
+            .select([col("x").round_with_mode(1, mode)])
+            .collect()?;
+        let x = out.column("x")?.as_materialized_series();
+        assert_eq!(x.dtype(), &DataType::Decimal(None, Some(2)));
+        assert_eq!(mantissas(x), expected.map(Some), "{mode}");
+    }
+
+    let floats = df!("x" => [2.5, -2.5, 0.25])?
+        .lazy()
+        .select([col("x").round_with_mode(0, RoundMode::HalfToEven)])
+        .collect()?;
+    assert_eq!(
+        Vec::from(floats.column("x")?.f64()?),
+        [Some(2.0), Some(-2.0), Some(0.0)]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "lazy", feature = "cum_agg"))]
+fn test_decimal_cum_sum() -> PolarsResult<()> {
+    let df = DataFrame::new(vec![
+        decimal("x", &[Some(100), Some(250), None, Some(-325)], 2).into_column(),
+    ])?;
+    let out = df.lazy().select([col("x").cum_sum(false)]).collect()?;
+    let x = out.column("x")?.as_materialized_series();
+    assert_eq!(mantissas(x), [Some(100), Some(350), None, Some(25)]);
+
+    let nines = decimal(
+        "x",
+        &[Some(9 * 10i128.pow(37)), Some(9 * 10i128.pow(37))],
+        0,
+    );
+    let out = DataFrame::new(vec![nines.into_column()])?
+        .lazy()
+        .select([col("x").cum_sum(false)])
+        .collect();
+    assert!(out.is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "lazy")]
+fn test_decimal_pow() -> PolarsResult<()> {
+    // [1.5, -0.2]
+    let df = DataFrame::new(vec![decimal("x", &[Some(15), Some(-2)], 1).into_column()])?;
+    let out = df
+        .clone()
+        .lazy()
+        .select([
+            col("x").pow(3).alias("int"),
+            col("x").pow(lit(2.0)).alias("float"),
+        ])
+        .collect()?;
+    let int = out.column("int")?.as_materialized_series();
+    assert_eq!(int.dtype(), &DataType::Decimal(None, Some(1)));
+    // 3.375 and -0.008 rounded half to even at the scale of the base
+    assert_eq!(mantissas(int), [Some(34), Some(0)]);
+    assert_eq!(out.column("float")?.dtype(), &DataType::Float64);
+
+    let err = df.lazy().select([col("x").pow(-1)]).collect();
+    assert!(err.is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "lazy", feature = "rolling_window"))]
+fn test_decimal_rolling() -> PolarsResult<()> {
+    // [1.00, 2.00, 4.00]
+    let df = DataFrame::new(vec![
+        decimal("x", &[Some(100), Some(200), Some(400)], 2).into_column(),
+    ])?;
+    let window = |window_size, min_periods| RollingOptionsFixedWindow {
+        window_size,
+        min_periods,
+        ..Default::default()
+    };
+    let out = df
+        .lazy()
+        .select([
+            col("x").rolling_sum(window(2, 1)).alias("sum"),
+            col("x").rolling_mean(window(2, 1)).alias("mean"),
+            col("x").rolling_max(window(2, 1)).alias("max"),
+            col("x").rolling_std(window(3, 2)).alias("std"),
+        ])
+        .collect()?;
+
+    let sum = out.column("sum")?.as_materialized_series();
+    assert_eq!(sum.dtype(), &DataType::Decimal(None, Some(2)));
+    assert_eq!(mantissas(sum), [Some(100), Some(300), Some(600)]);
+
+    let mean = out.column("mean")?.as_materialized_series();
+    assert_eq!(mean.dtype(), &DataType::Decimal(None, Some(6)));
+    assert_eq!(
+        mantissas(mean),
+        [Some(1000000), Some(1500000), Some(3000000)]
+    );
+
+    let max = out.column("max")?.as_materialized_series();
+    assert_eq!(mantissas(max), [Some(100), Some(200), Some(400)]);
+
+    let std = out.column("std")?.as_materialized_series();
+    assert_eq!(std.dtype(), &DataType::Decimal(None, Some(6)));
+    assert_eq!(mantissas(std), [None, Some(707107), Some(1527525)]);
+    Ok(())
+}
+
+#[cfg(feature = "dtype-decimal256")]
+const DECIMAL256: DataType = DataType::Decimal256(Some(50), Some(2));
+
+#[cfg(feature = "dtype-decimal256")]
+fn decimal256(values: &[Option<&str>]) -> PolarsResult<Series> {
+    Series::new("x".into(), values).cast(&DECIMAL256)
+}
+
+#[test]
+#[cfg(feature = "dtype-decimal256")]
+fn test_decimal256_cast() -> PolarsResult<()> {
+    let x = decimal256(&[
+        Some("123456789012345678901234567890123456789012.50"),
+        None,
+        Some("-1.25"),
+        Some("0.05"),
+    ])?;
+    assert_eq!(x.dtype(), &DECIMAL256);
+    assert_eq!(x.null_count(), 1);
+
+    let s = x.cast(&DataType::String)?;
+    assert_eq!(
+        s.str()?.get(0),
+        Some("123456789012345678901234567890123456789012.50")
+    );
+    assert_eq!(s.str()?.get(2), Some("-1.25"));
+
+    // values that don't fit the 128-bit decimal become null
+    let narrow = x.cast(&DataType::Decimal(Some(38), Some(2)))?;
+    assert_eq!(narrow.null_count(), 2);
+    assert_eq!(narrow.get(2)?, AnyValue::Decimal(-125, 2));
+
+    // and are rejected by strict casts
+    assert!(
+        x.strict_cast(&DataType::Decimal(Some(38), Some(2)))
+            .is_err()
+    );
+
+    // widening from a 128-bit decimal is lossless
+    let wide = narrow.cast(&DataType::Decimal256(None, None))?;
+    assert_eq!(wide.dtype(), &DataType::Decimal256(Some(38), Some(2)));
+    assert_eq!(wide.get(3)?.to_string(), "0.05");
+
+    let f = x.cast(&DataType::Float64)?;
+    assert_eq!(f.f64()?.get(2), Some(-1.25));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-decimal256")]
+fn test_decimal256_comparison_and_arithmetic() -> PolarsResult<()> {
+    let x = &decimal256(&[
+        Some("123456789012345678901234567890123456789012.50"),
+        None,
+        Some("-1.25"),
+        Some("0.05"),
+    ])?;
+
+    let zero = decimal256(&[Some("0"); 4])?;
+    let mask = x.gt(&zero)?;
+    assert_eq!(
+        Vec::from(&mask),
+        [Some(true), None, Some(false), Some(true)]
+    );
+
+    let sum = (x + x)?;
+    assert_eq!(sum.dtype(), &DataType::Decimal256(None, Some(2)));
+    assert_eq!(
+        sum.get(0)?.to_string(),
+        "246913578024691357802469135780246913578025.00"
+    );
+
+    let two = decimal256(&[Some("2"); 4])?;
+    let product = (x * &two)?;
+    assert_eq!(product.dtype(), &DataType::Decimal256(None, Some(4)));
+    assert_eq!(product.get(2)?.to_string(), "-2.5000");
+    // the square of the first value does not fit 76 digits
+    assert!((x * x).is_err());
+
+    let quotient = (x / &zero)?;
+    assert_eq!(quotient.null_count(), 4);
+
+    // sliced and multi-chunk input
+    let mut chunked = x.slice(2, 2);
+    chunked.append(&x.slice(0, 2))?;
+    assert_eq!(chunked.n_chunks(), 2);
+    let sorted = chunked.sort(SortOptions::default().with_nulls_last(true))?;
+    assert_eq!(sorted.dtype(), &DECIMAL256);
+    assert_eq!(sorted.get(0)?.to_string(), "-1.25");
+    assert_eq!(sorted.get(1)?.to_string(), "0.05");
+    assert_eq!(sorted.get(3)?, AnyValue::Null);
+    Ok(())
+}
@@ -1,4 +1,6 @@
 mod date_like;
+#[cfg(feature = "dtype-decimal")]
+mod decimal;
 mod group_by;
 mod joins;
 mod list;
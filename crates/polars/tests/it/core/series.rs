@@ -41,3 +41,287 @@ fn test_construct_list_of_null_series() {
     assert_eq!(s.null_count(), 0);
     assert_eq!(s.field().name(), "a");
 }
+
+#[test]
+#[cfg(feature = "dtype-uuid")]
+fn test_uuid_cast_and_display() -> PolarsResult<()> {
+    let uuids = [
+        Some("67e55044-10b1-426f-9247-bb680e5fe0c8"),
+        None,
+        Some("00000000-0000-0000-0000-000000000000"),
+        Some("ffffffff-ffff-ffff-ffff-ffffffffffff"),
+    ];
+    let ids = Series::new("id".into(), uuids).cast(&DataType::Uuid)?;
+    assert_eq!(ids.dtype(), &DataType::Uuid);
+    assert_eq!(ids.str_value(0)?, uuids[0].unwrap());
+    assert!(format!("{ids}").contains("uuid"));
+
+    let strings = ids.cast(&DataType::String)?;
+    assert!(strings.equals_missing(&Series::new("id".into(), uuids)));
+
+    // the simple form is parsed as well, and the bytes are stored big-endian
+    let s = Series::new("id".into(), ["67E5504410B1426F9247BB680E5FE0C8"]).cast(&DataType::Uuid)?;
+    assert_eq!(s.str_value(0)?, uuids[0].unwrap());
+    let bytes = s.cast(&DataType::Binary)?;
+    assert_eq!(bytes.binary()?.get(0).unwrap()[..2], [0x67, 0xe5]);
+
+    // invalid values error in strict casts
+    let s = Series::new("id".into(), ["not a uuid"]);
+    assert!(s.strict_cast(&DataType::Uuid).is_err());
+    assert_eq!(s.cast(&DataType::Uuid)?.null_count(), 1);
+
+    // uuids order like their bytes
+    let sorted = ids.sort(SortOptions::default().with_nulls_last(true))?;
+    assert_eq!(sorted.str_value(0)?, uuids[2].unwrap());
+    assert_eq!(sorted.str_value(2)?, uuids[3].unwrap());
+
+    // they are stored as 16-byte fixed-size binary, and exported as the arrow uuid extension type
+    assert_eq!(
+        ids.to_physical_repr().dtype(),
+        &DataType::Array(Box::new(DataType::UInt8), 16)
+    );
+    let field = ids.field().to_arrow(CompatLevel::newest());
+    let ArrowDataType::Extension(ext) = &field.dtype else {
+        panic!("expected an extension type, got {:?}", field.dtype)
+    };
+    assert_eq!(ext.name, "arrow.uuid");
+    assert_eq!(ext.inner, ArrowDataType::FixedSizeBinary(16));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-f16")]
+fn test_float16_cast_and_arithmetic() -> PolarsResult<()> {
+    let x = &Series::new("x".into(), [Some(1.5f32), None, Some(-2.0), Some(0.25)])
+        .cast(&DataType::Float16)?;
+    assert_eq!(x.dtype(), &DataType::Float16);
+    assert!(format!("{x}").contains("f16"));
+
+    // values are rounded to the nearest half precision float
+    let s = Series::new("x".into(), [0.1f64, 65504.0, 1e6]).cast(&DataType::Float16)?;
+    let back = s.cast(&DataType::Float64)?;
+    let back = back.f64()?;
+    assert_eq!(back.get(0), Some(0.0999755859375));
+    assert_eq!(back.get(1), Some(65504.0));
+    assert_eq!(back.get(2), Some(f64::INFINITY));
+
+    // arithmetic is computed in single precision and narrowed back
+    let out = (x + x)?;
+    assert_eq!(out.dtype(), &DataType::Float16);
+    let out = out.cast(&DataType::Float32)?;
+    assert_eq!(
+        Vec::from(out.f32()?),
+        [Some(3.0), None, Some(-4.0), Some(0.5)]
+    );
+
+    // mixing with wider floats widens the result
+    let y = Series::new("y".into(), [1.0f64, 1.0, 1.0, 1.0]);
+    assert_eq!((x + &y)?.dtype(), &DataType::Float64);
+
+    let mask = x.gt(0.5)?;
+    assert_eq!(
+        Vec::from(&mask),
+        [Some(true), None, Some(false), Some(false)]
+    );
+
+    // aggregations over sliced and multi-chunk input
+    let mut chunked = x.slice(1, 3);
+    chunked.append(&x.slice(0, 1))?;
+    assert_eq!(chunked.n_chunks(), 2);
+    assert_eq!(
+        chunked.min_reduce()?.value(),
+        &AnyValue::Float16(f16::from_f32(-2.0))
+    );
+    assert_eq!(
+        chunked.max_reduce()?.value(),
+        &AnyValue::Float16(f16::from_f32(1.5))
+    );
+    assert_eq!(
+        chunked.sum_reduce()?.value(),
+        &AnyValue::Float16(f16::from_f32(-0.25))
+    );
+    assert_eq!(chunked.mean(), Some(-0.25 / 3.0));
+    assert_eq!(
+        x.clear().sum_reduce()?.value(),
+        &AnyValue::Float16(f16::from_f32(0.0))
+    );
+
+    let sorted = chunked.sort(SortOptions::default().with_nulls_last(true))?;
+    assert_eq!(sorted.dtype(), &DataType::Float16);
+    let sorted = sorted.cast(&DataType::Float32)?;
+    assert_eq!(
+        Vec::from(sorted.f32()?),
+        [Some(-2.0), Some(0.25), Some(1.5), None]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-variant")]
+fn test_variant_json_cast_and_get_path() -> PolarsResult<()> {
+    let v = Series::new(
+        "v".into(),
+        [
+            Some(r#"{"id": 1, "name": "a", "tags": ["x", "y"]}"#),
+            None,
+            Some(r#"{"name": "b", "id": 2, "extra": 1.5}"#),
+            Some(r#"{"id": 3, "name": null}"#),
+            Some("42"),
+        ],
+    )
+    .cast(&DataType::Variant)?;
+    assert_eq!(v.dtype(), &DataType::Variant);
+    assert_eq!(v.get(4)?.to_string(), "42");
+    assert!(format!("{v}").contains("variant"));
+
+    let json = v.variant()?.to_json().into_series();
+    let expected = Series::new(
+        "v".into(),
+        [
+            Some(r#"{"id":1,"name":"a","tags":["x","y"]}"#),
+            None,
+            Some(r#"{"extra":1.5,"id":2,"name":"b"}"#),
+            Some(r#"{"id":3,"name":null}"#),
+            Some("42"),
+        ],
+    );
+    assert!(json.equals_missing(&expected));
+
+    // strings are returned as is, other values as json
+    let s = Series::new("s".into(), [r#""text""#, "[1, 2]"]).cast(&DataType::Variant)?;
+    let s = s.cast(&DataType::String)?;
+    assert_eq!(s.str()?.get(0), Some("text"));
+    assert_eq!(s.str()?.get(1), Some("[1,2]"));
+
+    // invalid json errors in strict casts
+    let s = Series::new("s".into(), ["{"]);
+    assert!(s.strict_cast(&DataType::Variant).is_err());
+    assert_eq!(s.cast(&DataType::Variant)?.null_count(), 1);
+
+    let ids = v.variant()?.get_path("$.id")?.into_series();
+    let ids = ids.cast(&DataType::Int64)?;
+    let expected = Series::new("v".into(), [Some(1i64), None, Some(2), Some(3), None]);
+    assert!(ids.equals_missing(&expected));
+
+    let tags = v.variant()?.get_path("$.tags[1]")?.into_series();
+    assert_eq!(tags.dtype(), &DataType::Variant);
+    let tags = tags.cast(&DataType::String)?;
+    assert_eq!(tags.str()?.get(0), Some("y"));
+    assert_eq!(tags.null_count(), 4);
+
+    // values that don't convert error in strict casts, variant nulls don't
+    let names = v.variant()?.get_path("$.name")?.into_series();
+    assert!(names.strict_cast(&DataType::Int64).is_err());
+    let extra = v.variant()?.get_path("$.extra")?.into_series();
+    assert_eq!(
+        extra.strict_cast(&DataType::Float64)?.f64()?.get(2),
+        Some(1.5)
+    );
+    assert!(v.variant()?.get_path("$.a..b").is_err());
+
+    // paths over sliced and empty input
+    let ids = v.slice(2, 2).variant()?.get_path("$.id")?.into_series();
+    assert_eq!(ids.cast(&DataType::Int64)?.i64()?.get(1), Some(3));
+    assert!(v.clear().variant()?.get_path("$.id")?.is_empty());
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-map")]
+fn test_map_ffi_round_trip() -> PolarsResult<()> {
+    let keys = Series::new(
+        "".into(),
+        [
+            Some(Series::new("".into(), ["a", "b"])),
+            None,
+            Some(Series::new("".into(), Vec::<&str>::new())),
+        ],
+    );
+    let values = Series::new(
+        "".into(),
+        [
+            Some(Series::new("".into(), [Some(1i64), None])),
+            None,
+            Some(Series::new("".into(), Vec::<i64>::new())),
+        ],
+    );
+    let s = map::map_from_lists(keys.list()?, values.list()?)?.into_series();
+    for s in [s.clone(), s.slice(1, 2)] {
+        let field = s.field().to_arrow(CompatLevel::newest());
+        let array = s.rechunk().to_arrow(0, CompatLevel::newest());
+
+        let schema_ffi = arrow::ffi::export_field_to_c(&field);
+        let array_ffi = arrow::ffi::export_array_to_c(array);
+        let field = unsafe { arrow::ffi::import_field_from_c(&schema_ffi)? };
+        let array = unsafe { arrow::ffi::import_array_from_c(array_ffi, field.dtype().clone())? };
+
+        let out = Series::try_from((&field, array))?;
+        assert_eq!(out.dtype(), s.dtype());
+        assert!(out.equals_missing(&s));
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-extension")]
+fn test_extension_cast_and_display() -> PolarsResult<()> {
+    use polars::chunked_array::cast::CastOptions;
+
+    #[derive(Debug)]
+    struct Uuid;
+
+    impl PolarsExtensionType for Uuid {
+        fn name(&self) -> &str {
+            "test.uuid"
+        }
+
+        fn storage_dtype(&self) -> &DataType {
+            &DataType::Binary
+        }
+
+        fn fmt_value(&self, value: AnyValue<'_>) -> String {
+            let AnyValue::Binary(bytes) = value else {
+                unreachable!()
+            };
+            bytes.iter().map(|b| format!("{b:02x}")).collect()
+        }
+
+        fn cast_to_storage(&self, s: &Series, options: CastOptions) -> PolarsResult<Series> {
+            let storage = s.cast_with_options(&DataType::Binary, options)?;
+            polars_ensure!(
+                storage.binary()?.into_iter().flatten().all(|v| v.len() == 16),
+                ComputeError: "a uuid must be 16 bytes"
+            );
+            Ok(storage)
+        }
+    }
+
+    let dtype = DataType::Extension(std::sync::Arc::new(Uuid));
+    let storage = Series::new(
+        "id".into(),
+        [
+            Some([0u8; 16].as_slice()),
+            None,
+            Some([0xab; 16].as_slice()),
+        ],
+    );
+    let ids = storage.cast(&dtype)?;
+    assert_eq!(ids.dtype(), &dtype);
+    assert_eq!(ids.str_value(2)?, "ab".repeat(16));
+    assert!(format!("{ids}").contains("extension[test.uuid]"));
+
+    // the cast hook validates the values
+    let s = Series::new("id".into(), [[1u8; 3].as_slice()]);
+    assert!(s.cast(&dtype).is_err());
+
+    // equality is defined by the storage, ordering is not supported
+    assert!(ids.equal_missing(&ids)?.all());
+    assert!(ids.lt(&ids).is_err());
+
+    // operations keep the type
+    let mut chunked = ids.slice(1, 2);
+    chunked.append(&ids.clear())?;
+    assert_eq!(chunked.dtype(), &dtype);
+    assert!(chunked.equals_missing(&ids.slice(1, 2)));
+    Ok(())
+}
@@ -0,0 +1,185 @@
+use polars::prelude::*;
+
+fn dictionary() -> PolarsResult<CategoricalDictionary> {
+    let s = Series::new("c".into(), ["b", "a", "b", "c"]);
+    CategoricalDictionary::from_series(&s)
+}
+
+#[test]
+fn test_categorical_dictionary_save_and_load() -> PolarsResult<()> {
+    let tmp_dir = tempfile::tempdir()?;
+    let path = tmp_dir.path().join("c.dict");
+
+    let mut dict = dictionary()?;
+    assert_eq!(dict.version(), 1);
+    assert_eq!(dict.find("b"), Some(0));
+    assert_eq!(dict.find("c"), Some(2));
+    dict.save(&path)?;
+
+    let loaded = CategoricalDictionary::load(&path)?;
+    assert_eq!(loaded, dict);
+
+    // adding categories bumps the version and keeps the existing codes
+    assert_eq!(dict.extend(["a", "d"]), 1);
+    assert_eq!(dict.version(), 2);
+    assert_eq!(dict.find("d"), Some(3));
+    assert!(loaded.is_prefix_of(&dict));
+    assert!(!dict.is_prefix_of(&loaded));
+
+    std::fs::write(&path, b"not a dictionary")?;
+    assert!(CategoricalDictionary::load(&path).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_categorical_dictionary_encode() -> PolarsResult<()> {
+    let dict = dictionary()?;
+
+    // columns encoded separately have the same codes and compatible categories
+    let left = dict.encode(&Series::new("c".into(), [Some("c"), None, Some("a")]))?;
+    let right = Series::new("c".into(), ["a", "c", "a"])
+        .cast(&DataType::Categorical(None, CategoricalOrdering::Physical))?;
+    let right = dict.encode(&right)?;
+    assert_eq!(left.dtype(), &dict.dtype());
+    assert_eq!(right.dtype(), &dict.dtype());
+    let codes = left.categorical()?.physical().clone();
+    assert_eq!(Vec::from(&codes), [Some(2), None, Some(1)]);
+    assert!(
+        left.categorical()?
+            .get_rev_map()
+            .same_src(right.categorical()?.get_rev_map())
+    );
+
+    let left = DataFrame::new(vec![left.into_column()])?;
+    let right = DataFrame::new(vec![
+        right.into_column(),
+        Column::new("x".into(), [1, 2, 3]),
+    ])?;
+    let stacked = left.vstack(&right.select(["c"])?)?;
+    assert_eq!(stacked.height(), 6);
+    let joined = left.join(&right, ["c"], ["c"], JoinArgs::new(JoinType::Inner), None)?;
+    assert_eq!(joined.height(), 3);
+
+    // values that are not in the dictionary error
+    assert!(dict.encode(&Series::new("c".into(), ["e"])).is_err());
+    assert!(dict.encode(&Series::new("c".into(), [1])).is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "ipc", feature = "lazy"))]
+fn test_categorical_dictionary_scan_and_sink() -> PolarsResult<()> {
+    let tmp_dir = tempfile::tempdir()?;
+    let path = tmp_dir.path().join("data.ipc");
+    let dict = Arc::new(dictionary()?);
+    let dictionaries = CategoricalDictionaries::from_iter([("c".into(), dict.clone())]);
+
+    // the stable codes and the dictionary are written to the file
+    let df = df!("c" => ["c", "a", "c"], "x" => [1, 2, 3])?;
+    let sink_options = SinkOptions {
+        categorical_dictionaries: Some(dictionaries.clone()),
+        ..Default::default()
+    };
+    df.clone()
+        .lazy()
+        .sink_ipc(&path, IpcWriterOptions::default(), None, sink_options)?
+        .collect()?;
+    let mut reader = IpcReader::new(std::fs::File::open(&path)?);
+    let metadata = reader.custom_metadata()?.unwrap();
+    assert!(
+        metadata
+            .keys()
+            .any(|key| key.as_str() == "_PL_CATEGORICAL_DICTIONARY:c")
+    );
+    let out = reader.finish()?;
+    let codes = out.column("c")?.categorical()?.physical().clone();
+    assert_eq!(Vec::from(&codes), [Some(2), Some(1), Some(2)]);
+
+    // scans encode with the dictionary
+    let args = ScanArgsIpc {
+        categorical_dictionaries: Some(dictionaries),
+        ..Default::default()
+    };
+    let mut lf = LazyFrame::scan_ipc(&path, args)?;
+    assert_eq!(lf.collect_schema()?.get("c"), Some(&dict.dtype()));
+    let out = lf.collect()?;
+    let codes = out.column("c")?.categorical()?.physical().clone();
+    assert_eq!(Vec::from(&codes), [Some(2), Some(1), Some(2)]);
+
+    // a later version of the dictionary is compatible, a different dictionary is not
+    let mut later = dictionary()?;
+    later.extend(["d"]);
+    let args = ScanArgsIpc {
+        categorical_dictionaries: Some(CategoricalDictionaries::from_iter([(
+            "c".into(),
+            Arc::new(later),
+        )])),
+        ..Default::default()
+    };
+    assert_eq!(LazyFrame::scan_ipc(&path, args)?.collect()?.height(), 3);
+    let other = CategoricalDictionary::new(["a", "b", "c"])?;
+    let args = ScanArgsIpc {
+        categorical_dictionaries: Some(CategoricalDictionaries::from_iter([(
+            "c".into(),
+            Arc::new(other),
+        )])),
+        ..Default::default()
+    };
+    assert!(LazyFrame::scan_ipc(&path, args).is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "parquet", feature = "lazy"))]
+fn test_categorical_dictionary_parquet() -> PolarsResult<()> {
+    let tmp_dir = tempfile::tempdir()?;
+    let path = tmp_dir.path().join("data.parquet");
+    let dict = Arc::new(dictionary()?);
+    let dictionaries = CategoricalDictionaries::from_iter([("c".into(), dict.clone())]);
+
+    let df = df!("c" => [Some("b"), None, Some("c")])?;
+    let sink_options = SinkOptions {
+        categorical_dictionaries: Some(dictionaries.clone()),
+        ..Default::default()
+    };
+    df.lazy()
+        .sink_parquet(&path, ParquetWriteOptions::default(), None, sink_options)?
+        .collect()?;
+
+    let args = ScanArgsParquet {
+        categorical_dictionaries: Some(dictionaries),
+        ..Default::default()
+    };
+    let out = LazyFrame::scan_parquet(&path, args)?.collect()?;
+    let codes = out.column("c")?.categorical()?.physical().clone();
+    assert_eq!(Vec::from(&codes), [Some(0), None, Some(2)]);
+
+    let other = CategoricalDictionary::new(["c", "b"])?;
+    let args = ScanArgsParquet {
+        categorical_dictionaries: Some(CategoricalDictionaries::from_iter([(
+            "c".into(),
+            Arc::new(other),
+        )])),
+        ..Default::default()
+    };
+    assert!(LazyFrame::scan_parquet(&path, args).is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "serde-lazy", feature = "lazy"))]
+fn test_categorical_dictionary_plan_serde() -> PolarsResult<()> {
+    let dict = Arc::new(dictionary()?);
+    let dictionaries = CategoricalDictionaries::from_iter([("c".into(), dict)]);
+    let lf = df!("c" => ["c", "a"])?
+        .lazy()
+        .with_categorical_dictionaries(&dictionaries);
+
+    let mut buf = vec![];
+    lf.logical_plan.serialize_versioned(&mut buf)?;
+    let plan = DslPlan::deserialize_versioned(buf.as_slice())?;
+    let out = LazyFrame::from(plan).collect()?;
+    let codes = out.column("c")?.categorical()?.physical().clone();
+    assert_eq!(Vec::from(&codes), [Some(2), Some(1)]);
+    Ok(())
+}
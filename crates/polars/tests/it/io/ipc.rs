@@ -151,3 +151,67 @@ fn write_and_read_ipc_empty_series() {
     let df_read = IpcReader::new(buf).finish().unwrap();
     assert!(df.equals(&df_read));
 }
+
+fn round_trip(df: &mut DataFrame) -> PolarsResult<DataFrame> {
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    IpcWriter::new(&mut buf).finish(df)?;
+    buf.set_position(0);
+    IpcReader::new(buf).finish()
+}
+
+#[test]
+fn test_ipc_logical_types_round_trip() -> PolarsResult<()> {
+    for mut df in super::round_trip_cases(&super::logical_types_df()?)? {
+        let out = round_trip(&mut df)?;
+        assert_eq!(out.schema(), df.schema());
+        assert!(out.equals_missing(&df), "{df:?}");
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-extension")]
+fn test_ipc_extension_registry() -> PolarsResult<()> {
+    use std::sync::Arc;
+
+    #[derive(Debug)]
+    struct Tagged;
+
+    impl PolarsExtensionType for Tagged {
+        fn name(&self) -> &str {
+            "test.json"
+        }
+
+        fn storage_dtype(&self) -> &DataType {
+            &DataType::String
+        }
+
+        fn fmt_value(&self, value: AnyValue<'_>) -> String {
+            format!("json({})", value.get_str().unwrap())
+        }
+    }
+
+    let mut df = super::logical_types_df()?.select(["json"])?;
+    let out = round_trip(&mut df)?;
+    let json = out.column("json")?.as_materialized_series();
+    assert_eq!(json.extension()?.extension_type().name(), "test.json");
+    assert_eq!(json.str_value(0)?, "{}");
+
+    // the type is resolved through a registry
+    let mut registry = ExtensionRegistry::default();
+    let constructor: ExtensionTypeConstructor =
+        Arc::new(|_, _| Ok(Arc::new(Tagged) as ExtensionTypeRef));
+    registry.register("test.json".into(), constructor);
+    let mut out = registry.resolve_frame(&out)?;
+    let json = out.column("json")?.as_materialized_series();
+    assert_eq!(json.str_value(0)?, "json({})");
+    assert!(out.equals_missing(&df));
+
+    // types that are not registered keep their name and are written back unchanged
+    assert!(registry.unregister("test.json"));
+    let out = registry.resolve_frame(&round_trip(&mut out)?)?;
+    assert_eq!(out.schema(), df.schema());
+    let json = out.column("json")?.as_materialized_series();
+    assert_eq!(json.str_value(0)?, "{}");
+    Ok(())
+}
@@ -9,6 +9,8 @@ mod parquet;
 #[cfg(feature = "avro")]
 mod avro;
 
+#[cfg(feature = "dtype-categorical")]
+mod categorical_dictionary;
 #[cfg(feature = "ipc")]
 mod ipc;
 #[cfg(feature = "ipc_streaming")]
@@ -21,3 +23,131 @@ pub(crate) fn create_df() -> DataFrame {
     let s1 = Column::new("temp".into(), [22.1, 19.9, 7., 2., 3.].as_ref());
     DataFrame::new(vec![s0, s1]).unwrap()
 }
+
+#[cfg(feature = "dtype-extension")]
+#[derive(Debug)]
+pub(crate) struct JsonType;
+
+#[cfg(feature = "dtype-extension")]
+impl PolarsExtensionType for JsonType {
+    fn name(&self) -> &str {
+        "test.json"
+    }
+
+    fn storage_dtype(&self) -> &DataType {
+        &DataType::String
+    }
+}
+
+/// A frame with a column of every data type that is stored as an Arrow extension type or
+/// needs a logical type to survive a round trip.
+#[allow(unused_mut)]
+pub(crate) fn logical_types_df() -> PolarsResult<DataFrame> {
+    let mut columns = vec![Column::new("i".into(), [1, 2, 3, 4])];
+    #[cfg(feature = "dtype-map")]
+    {
+        let keys = Series::new(
+            "".into(),
+            [
+                Some(Series::new("".into(), ["a", "b"])),
+                None,
+                Some(Series::new("".into(), ["c"])),
+                Some(Series::new("".into(), Vec::<&str>::new())),
+            ],
+        );
+        let values = Series::new(
+            "".into(),
+            [
+                Some(Series::new("".into(), [Some(1i64), None])),
+                None,
+                Some(Series::new("".into(), [3i64])),
+                Some(Series::new("".into(), Vec::<i64>::new())),
+            ],
+        );
+        let map = map::map_from_lists(keys.list()?, values.list()?)?;
+        columns.push(map.into_series().with_name("map".into()).into_column());
+    }
+    #[cfg(feature = "dtype-interval")]
+    columns.push(
+        IntervalChunked::from_intervals(
+            "interval".into(),
+            [
+                Some(months_days_ns::new(1, 2, 3_000_000_001)),
+                None,
+                Some(months_days_ns::new(-14, 0, -1)),
+                Some(months_days_ns::new(0, -3, 0)),
+            ],
+        )
+        .into_column(),
+    );
+    #[cfg(feature = "dtype-uuid")]
+    columns.push(
+        Column::new(
+            "uuid".into(),
+            [
+                Some("67e55044-10b1-426f-9247-bb680e5fe0c8"),
+                None,
+                Some("00000000-0000-0000-0000-000000000000"),
+                Some("ffffffff-ffff-ffff-ffff-ffffffffffff"),
+            ],
+        )
+        .cast(&DataType::Uuid)?,
+    );
+    #[cfg(feature = "dtype-f16")]
+    columns.push(
+        Column::new("f16".into(), [Some(1.5f32), None, Some(-2.0), Some(0.25)])
+            .cast(&DataType::Float16)?,
+    );
+    #[cfg(feature = "dtype-decimal256")]
+    columns.push(
+        Column::new(
+            "decimal256".into(),
+            [
+                Some("123456789012345678901234567890123456789012.50"),
+                None,
+                Some("-1.25"),
+                Some("0.05"),
+            ],
+        )
+        .cast(&DataType::Decimal256(Some(50), Some(2)))?,
+    );
+    #[cfg(feature = "dtype-variant")]
+    columns.push(
+        Column::new(
+            "variant".into(),
+            [
+                Some(r#"{"id": 1, "name": "a", "tags": ["x", "y"]}"#),
+                None,
+                Some(r#"{"name": "b", "id": 2, "extra": 1.5}"#),
+                Some("42"),
+            ],
+        )
+        .cast(&DataType::Variant)?,
+    );
+    #[cfg(feature = "dtype-extension")]
+    columns.push(
+        Column::new("json".into(), [Some("{}"), Some("[1]"), None, Some("2")])
+            .cast(&DataType::Extension(std::sync::Arc::new(JsonType)))?,
+    );
+    DataFrame::new(columns)
+}
+
+/// The frames a round trip of `df` is tested with: `df` itself, a slice of it, an empty frame,
+/// a frame of nulls and a frame of multiple chunks.
+pub(crate) fn round_trip_cases(df: &DataFrame) -> PolarsResult<Vec<DataFrame>> {
+    let nulls = df
+        .get_columns()
+        .iter()
+        .map(|c| Column::full_null(c.name().clone(), 3, c.dtype()))
+        .collect();
+    let mut chunked = df.clone();
+    chunked.vstack_mut(&df.slice(1, 2))?;
+    assert_eq!(chunked.first_col_n_chunks(), 2);
+    Ok(vec![
+        df.clone(),
+        df.slice(1, 2),
+        df.clear(),
+        DataFrame::new(nulls)?,
+        chunked,
+    ])
+}
@@ -4,6 +4,8 @@ use std::sync::Arc;
 use arrow::array::{ArrayRef, Utf8ViewArray};
 use arrow::datatypes::{ArrowSchema, Field};
 use arrow::record_batch::RecordBatchT;
+use polars::io::parquet::metadata::FileMetadataRef;
+use polars::prelude::{CompatLevel, DataFrame, ParquetReader, ParquetWriter, SerReader};
 use polars_error::PolarsResult;
 use polars_parquet::arrow::write::{FileWriter, WriteOptions};
 use polars_parquet::read::read_metadata;
@@ -84,3 +86,128 @@ fn roundtrip_binview() -> PolarsResult<()> {
         vec![Encoding::Plain],
     )
 }
+
+fn round_trip_df(df: &mut DataFrame) -> PolarsResult<(DataFrame, FileMetadataRef)> {
+    let mut buf = Cursor::new(vec![]);
+    ParquetWriter::new(&mut buf).finish(df)?;
+    buf.set_position(0);
+
+    let mut reader = ParquetReader::new(buf);
+    let metadata = reader.get_metadata()?.clone();
+    Ok((reader.finish()?, metadata))
+}
+
+#[test]
+fn roundtrip_logical_types() -> PolarsResult<()> {
+    for mut df in crate::io::round_trip_cases(&crate::io::logical_types_df()?)? {
+        let (out, _) = round_trip_df(&mut df)?;
+        assert_eq!(out.schema(), df.schema());
+        assert!(out.equals_missing(&df), "{df:?}");
+    }
+    Ok(())
+}
+
+#[test]
+fn roundtrip_logical_types_arrow() -> PolarsResult<()> {
+    let df = crate::io::logical_types_df()?;
+    for column in df.get_columns() {
+        if column.name() == "variant" {
+            // variants are shredded by the `ParquetWriter` only
+            continue;
+        }
+        let array = column
+            .as_materialized_series()
+            .to_arrow(0, CompatLevel::newest());
+        // a map has a leaf column for its keys and one for its values
+        let n_leaves = if column.dtype().is_map() { 2 } else { 1 };
+        for version in [Version::V1, Version::V2] {
+            round_trip(
+                &array,
+                version,
+                CompressionOptions::Uncompressed,
+                vec![Encoding::Plain; n_leaves],
+            )?;
+        }
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-uuid")]
+fn roundtrip_uuid_schema() -> PolarsResult<()> {
+    use polars_parquet::parquet::schema::types::PrimitiveLogicalType;
+
+    let (_, metadata) = round_trip_df(&mut crate::io::logical_types_df()?.select(["uuid"])?)?;
+    let primitive_type = &metadata.schema().columns()[0].descriptor.primitive_type;
+    assert_eq!(
+        primitive_type.logical_type,
+        Some(PrimitiveLogicalType::Uuid)
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-f16")]
+fn roundtrip_float16_schema_and_statistics() -> PolarsResult<()> {
+    use polars::prelude::f16;
+    use polars_parquet::parquet::schema::types::{PhysicalType, PrimitiveLogicalType};
+
+    let (_, metadata) = round_trip_df(&mut crate::io::logical_types_df()?.select(["f16"])?)?;
+    let primitive_type = &metadata.schema().columns()[0].descriptor.primitive_type;
+    assert_eq!(
+        primitive_type.physical_type,
+        PhysicalType::FixedLenByteArray(2)
+    );
+    assert_eq!(
+        primitive_type.logical_type,
+        Some(PrimitiveLogicalType::Float16)
+    );
+
+    // the statistics hold the half precision extrema
+    let stats = metadata.row_groups[0].parquet_columns()[0]
+        .statistics()
+        .unwrap()?;
+    let stats = stats.expect_fixedlen();
+    assert_eq!(
+        stats.min_value.as_deref(),
+        Some(&f16::from_f32(-2.0).to_bits().to_le_bytes()[..])
+    );
+    assert_eq!(
+        stats.max_value.as_deref(),
+        Some(&f16::from_f32(1.5).to_bits().to_le_bytes()[..])
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-decimal256")]
+fn roundtrip_decimal256_schema() -> PolarsResult<()> {
+    use polars_parquet::parquet::schema::types::PrimitiveLogicalType;
+
+    let (_, metadata) = round_trip_df(&mut crate::io::logical_types_df()?.select(["decimal256"])?)?;
+    let primitive_type = &metadata.schema().columns()[0].descriptor.primitive_type;
+    assert_eq!(
+        primitive_type.logical_type,
+        Some(PrimitiveLogicalType::Decimal(50, 2))
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-variant")]
+fn roundtrip_variant_shredding() -> PolarsResult<()> {
+    let (_, metadata) = round_trip_df(&mut crate::io::logical_types_df()?.select(["variant"])?)?;
+
+    // the fields that most variant objects have are shredded into typed columns
+    let paths = metadata
+        .schema()
+        .columns()
+        .iter()
+        .map(|c| c.path_in_schema.join("."))
+        .collect::<Vec<_>>();
+    assert!(paths.contains(&"variant.metadata".to_string()));
+    assert!(paths.contains(&"variant.typed_value.id.typed_value".to_string()));
+    assert!(paths.contains(&"variant.typed_value.name.typed_value".to_string()));
+    assert!(!paths.iter().any(|p| p.contains("tags")));
+    Ok(())
+}
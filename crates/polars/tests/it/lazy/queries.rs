@@ -272,3 +272,356 @@ fn test_group_by_on_lists() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "dtype-map")]
+fn test_map_expressions() -> PolarsResult<()> {
+    let keys = Series::new(
+        "".into(),
+        [
+            Some(Series::new("".into(), ["a", "b"])),
+            None,
+            Some(Series::new("".into(), Vec::<&str>::new())),
+        ],
+    );
+    let values = Series::new(
+        "".into(),
+        [
+            Some(Series::new("".into(), [Some(1i64), None])),
+            None,
+            Some(Series::new("".into(), Vec::<i64>::new())),
+        ],
+    );
+    let map = map::map_from_lists(keys.list()?, values.list()?)?;
+    let map_dtype = map.dtype().clone();
+    let df = DataFrame::new(vec![
+        map.into_series().with_name("map".into()).into_column(),
+    ])?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([
+            col("map").map_().get(lit("a")).alias("a"),
+            col("map").map_().get(lit("b")).alias("b"),
+            col("map").map_().keys().alias("keys"),
+            col("map").map_().values().list().len().alias("n"),
+            map_from_lists(col("map").map_().keys(), col("map").map_().values()).alias("again"),
+        ])
+        .collect()?;
+    assert_eq!(Vec::from(out.column("a")?.i64()?), &[Some(1), None, None]);
+    assert_eq!(Vec::from(out.column("b")?.i64()?), &[None, None, None]);
+    assert_eq!(
+        out.column("keys")?.dtype(),
+        &DataType::List(Box::new(DataType::String))
+    );
+    assert_eq!(
+        Vec::from(out.column("n")?.idx()?),
+        &[Some(2), None, Some(0)]
+    );
+    assert_eq!(out.column("again")?.dtype(), &map_dtype);
+
+    let out = df
+        .clear()
+        .lazy()
+        .select([col("map").map_().get(lit("a"))])
+        .collect()?;
+    assert_eq!(out.column("map")?.dtype(), &DataType::Int64);
+    assert_eq!(out.height(), 0);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-uuid")]
+fn test_uuid_lazy() -> PolarsResult<()> {
+    let uuid = "67e55044-10b1-426f-9247-bb680e5fe0c8";
+    let df = DataFrame::new(vec![
+        Column::new("id".into(), [Some(uuid), None, Some(uuid)]).cast(&DataType::Uuid)?,
+        Column::new("x".into(), [1, 2, 3]),
+    ])?;
+
+    // comparing with a string literal parses the literal
+    let out = df
+        .clone()
+        .lazy()
+        .filter(col("id").eq(lit(uuid)))
+        .collect()?;
+    assert_eq!(out.height(), 2);
+
+    // uuids are hashable group keys, nulls are a group of their own
+    let out = df
+        .lazy()
+        .group_by([col("id")])
+        .agg([col("x").sum()])
+        .sort(["x"], Default::default())
+        .collect()?;
+    assert_eq!(out.column("id")?.dtype(), &DataType::Uuid);
+    assert_eq!(Vec::from(out.column("x")?.i32()?), &[Some(2), Some(4)]);
+
+    let mut lf = DataFrame::empty()
+        .lazy()
+        .select([uuid_v4(lit(3)), uuid_v7(lit(3)).alias("v7")]);
+    assert_eq!(lf.collect_schema()?.get("uuid"), Some(&DataType::Uuid));
+    let out = lf.collect()?;
+    assert_eq!(out.height(), 3);
+    assert_eq!(out.column("uuid")?.n_unique()?, 3);
+    assert!(
+        out.column("v7")?
+            .as_materialized_series()
+            .is_sorted(Default::default())?
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "dtype-uuid", feature = "streaming"))]
+fn test_uuid_keyed_ops() -> PolarsResult<()> {
+    let a = "00000000-0000-0000-0000-000000000001";
+    let b = "ffffffff-ffff-ffff-ffff-ffffffffffff";
+    let left = DataFrame::new(vec![
+        Column::new("id".into(), [Some(b), Some(a), None, Some(b)]).cast(&DataType::Uuid)?,
+        Column::new("k".into(), [1i32, 1, 1, 2]),
+        Column::new("x".into(), [1i32, 2, 3, 4]),
+    ])?;
+    let right = DataFrame::new(vec![
+        Column::new("id".into(), [Some(a), Some(b)]).cast(&DataType::Uuid)?,
+        Column::new("k".into(), [1i32, 2]),
+        Column::new("y".into(), [10i32, 20]),
+    ])?;
+
+    for engine in [Engine::InMemory, Engine::OldStreaming] {
+        // single and multiple keys
+        let out = left
+            .clone()
+            .lazy()
+            .group_by([col("id")])
+            .agg([col("x").sum()])
+            .sort(["x"], Default::default())
+            .collect_with_engine(engine)?;
+        assert_eq!(out.column("id")?.dtype(), &DataType::Uuid);
+        assert_eq!(out.column("id")?.as_materialized_series().str_value(0)?, a);
+        assert_eq!(
+            Vec::from(out.column("x")?.i32()?),
+            &[Some(2), Some(3), Some(5)]
+        );
+
+        let out = left
+            .clone()
+            .lazy()
+            .group_by([col("id"), col("k")])
+            .agg([col("x").sum()])
+            .sort(["x"], Default::default())
+            .collect_with_engine(engine)?;
+        assert_eq!(out.column("id")?.dtype(), &DataType::Uuid);
+        assert_eq!(out.column("id")?.as_materialized_series().str_value(0)?, b);
+        assert_eq!(out.height(), 4);
+
+        let out = left
+            .clone()
+            .lazy()
+            .join(
+                right.clone().lazy(),
+                [col("id")],
+                [col("id")],
+                JoinArgs::new(JoinType::Inner),
+            )
+            .sort(["x"], Default::default())
+            .collect_with_engine(engine)?;
+        assert_eq!(
+            Vec::from(out.column("y")?.i32()?),
+            &[Some(20), Some(10), Some(20)]
+        );
+
+        let out = left
+            .clone()
+            .lazy()
+            .join(
+                right.clone().lazy(),
+                [col("id"), col("k")],
+                [col("id"), col("k")],
+                JoinArgs::new(JoinType::Inner),
+            )
+            .sort(["x"], Default::default())
+            .collect_with_engine(engine)?;
+        assert_eq!(Vec::from(out.column("x")?.i32()?), &[Some(2), Some(4)]);
+
+        let out = left
+            .clone()
+            .lazy()
+            .sort_by_exprs(
+                [col("id"), col("x")],
+                SortMultipleOptions::default()
+                    .with_order_descending_multi([true, false])
+                    .with_nulls_last(true),
+            )
+            .collect_with_engine(engine)?;
+        assert_eq!(
+            Vec::from(out.column("x")?.i32()?),
+            &[Some(1), Some(4), Some(2), Some(3)]
+        );
+
+        let out = left
+            .clone()
+            .lazy()
+            .select([col("id").unique().sort(Default::default())])
+            .collect_with_engine(engine)?;
+        assert_eq!(out.column("id")?.dtype(), &DataType::Uuid);
+        assert_eq!(out.column("id")?.null_count(), 1);
+        assert_eq!(out.column("id")?.as_materialized_series().str_value(2)?, b);
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-f16")]
+fn test_float16_group_by() -> PolarsResult<()> {
+    let df = DataFrame::new(vec![
+        Column::new("x".into(), [Some(1.5f32), None, Some(-2.0), Some(0.25)])
+            .cast(&DataType::Float16)?,
+        Column::new("g".into(), ["a", "b", "a", "b"]),
+    ])?;
+    let out = df
+        .lazy()
+        .group_by([col("g")])
+        .agg([
+            col("x").sum().alias("sum"),
+            col("x").max().alias("max"),
+            col("x").mean().alias("mean"),
+        ])
+        .sort(["g"], Default::default())
+        .collect()?;
+    assert_eq!(out.column("sum")?.dtype(), &DataType::Float16);
+    assert_eq!(out.column("max")?.dtype(), &DataType::Float16);
+    assert_eq!(
+        out.column("sum")?.get(0)?,
+        AnyValue::Float16(f16::from_f32(-0.5))
+    );
+    assert_eq!(
+        out.column("max")?.get(1)?,
+        AnyValue::Float16(f16::from_f32(0.25))
+    );
+    assert_eq!(
+        out.column("mean")?.cast(&DataType::Float64)?.get(0)?,
+        AnyValue::Float64(-0.25)
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "dtype-f16", feature = "streaming"))]
+fn test_float16_group_by_signed_zero_and_nan() -> PolarsResult<()> {
+    let x = [0.0f32, -0.0, f32::NAN, -f32::NAN, 1.0];
+    let df = DataFrame::new(vec![
+        Column::new("x".into(), x).cast(&DataType::Float16)?,
+        Column::new("g".into(), ["a"; 5]),
+        Column::new("v".into(), [1i32, 2, 3, 4, 5]),
+    ])?;
+
+    assert_eq!(df.column("x")?.n_unique()?, 3);
+    assert_eq!(df.column("x")?.unique()?.len(), 3);
+
+    for engine in [Engine::InMemory, Engine::OldStreaming] {
+        for keys in [vec![col("x")], vec![col("x"), col("g")]] {
+            let out = df
+                .clone()
+                .lazy()
+                .group_by(keys)
+                .agg([col("v").sum()])
+                .sort(["v"], Default::default())
+                .collect_with_engine(engine)?;
+            assert_eq!(out.height(), 3);
+            assert_eq!(
+                out.column("v")?.as_materialized_series(),
+                &Series::new("v".into(), [3i32, 5, 7])
+            );
+            let x = out.column("x")?.cast(&DataType::Float32)?;
+            let x = x.f32()?;
+            assert_eq!(x.get(0), Some(0.0));
+            assert_eq!(x.get(1), Some(1.0));
+            assert!(x.get(2).unwrap().is_nan());
+        }
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "dtype-f16", feature = "streaming"))]
+fn test_float16_sort_multiple() -> PolarsResult<()> {
+    let df = DataFrame::new(vec![
+        Column::new("x".into(), [1.5f32, -2.0, 0.5, -2.0, 1.5]).cast(&DataType::Float16)?,
+        Column::new("v".into(), [1i32, 2, 3, 4, 5]),
+    ])?;
+
+    for engine in [Engine::InMemory, Engine::OldStreaming] {
+        let out = df
+            .clone()
+            .lazy()
+            .sort_by_exprs(
+                [col("x"), col("v")],
+                SortMultipleOptions::default().with_order_descending_multi([true, false]),
+            )
+            .collect_with_engine(engine)?;
+        assert_eq!(out.column("x")?.dtype(), &DataType::Float16);
+        assert_eq!(
+            out.column("x")?
+                .cast(&DataType::Float32)?
+                .as_materialized_series(),
+            &Series::new("x".into(), [1.5f32, 1.5, 0.5, -2.0, -2.0])
+        );
+        assert_eq!(
+            out.column("v")?.as_materialized_series(),
+            &Series::new("v".into(), [1i32, 5, 3, 2, 4])
+        );
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-variant")]
+fn test_variant_lazy() -> PolarsResult<()> {
+    let df = DataFrame::new(vec![
+        Column::new(
+            "v".into(),
+            [Some(r#"{"id": 1}"#), None, Some(r#"{"id": 5}"#)],
+        )
+        .cast(&DataType::Variant)?,
+    ])?;
+    let mut lf = df.lazy().select([col("v").variant_get("$.id").alias("id")]);
+    assert_eq!(lf.collect_schema()?.get("id"), Some(&DataType::Variant));
+
+    let out = lf
+        .select([col("id").cast(DataType::Int64).sum()])
+        .collect()?;
+    assert_eq!(out.column("id")?.get(0)?, AnyValue::Int64(6));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-extension")]
+fn test_extension_lazy_schema() -> PolarsResult<()> {
+    #[derive(Debug)]
+    struct Json;
+
+    impl PolarsExtensionType for Json {
+        fn name(&self) -> &str {
+            "test.json"
+        }
+
+        fn storage_dtype(&self) -> &DataType {
+            &DataType::String
+        }
+    }
+
+    let dtype = DataType::Extension(std::sync::Arc::new(Json));
+    let df = DataFrame::new(vec![
+        Column::new("json".into(), ["{}", "[1]", "2"]).cast(&dtype)?,
+        Column::new("x".into(), [1, 2, 3]),
+    ])?;
+    let mut lf = df.lazy().slice(1, 2).select([col("json")]);
+    assert_eq!(lf.collect_schema()?.get("json"), Some(&dtype));
+
+    let out = lf.collect()?;
+    assert_eq!(out.column("json")?.dtype(), &dtype);
+    assert_eq!(out.height(), 2);
+    Ok(())
+}
@@ -0,0 +1,107 @@
+use chrono::NaiveDate;
+use polars::prelude::*;
+
+#[test]
+fn test_interval_date_arithmetic() -> PolarsResult<()> {
+    let dates = DateChunked::from_naive_date(
+        "date".into(),
+        [
+            NaiveDate::from_ymd_opt(2024, 1, 31).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 3, 31).unwrap(),
+        ],
+    );
+    let intervals = IntervalChunked::from_intervals(
+        "interval".into(),
+        [
+            Some(months_days_ns::new(1, 0, 0)),
+            Some(months_days_ns::new(1, 1, 0)),
+        ],
+    );
+    let df = DataFrame::new(vec![
+        dates.into_series().into_column(),
+        intervals.into_series().into_column(),
+    ])?;
+
+    let out = df
+        .lazy()
+        .select([
+            (col("date") + col("interval")).alias("plus"),
+            (col("interval") + col("date")).alias("plus_flipped"),
+            (col("date") - col("interval")).alias("minus"),
+        ])
+        .collect()?;
+
+    let expected = |dates: [(i32, u32, u32); 2]| {
+        DateChunked::from_naive_date(
+            "".into(),
+            dates.map(|(y, m, d)| NaiveDate::from_ymd_opt(y, m, d).unwrap()),
+        )
+        .into_series()
+    };
+    let plus = expected([(2024, 2, 29), (2024, 5, 1)]);
+    assert!(out.column("plus")?.as_materialized_series().equals(&plus));
+    assert!(
+        out.column("plus_flipped")?
+            .as_materialized_series()
+            .equals(&plus)
+    );
+    let minus = expected([(2023, 12, 31), (2024, 2, 28)]);
+    assert!(out.column("minus")?.as_materialized_series().equals(&minus));
+    Ok(())
+}
+
+#[test]
+fn test_interval_datetime_arithmetic() -> PolarsResult<()> {
+    let df = df![
+        "datetime" => [NaiveDate::from_ymd_opt(2024, 1, 31).unwrap().and_hms_opt(12, 0, 0).unwrap()],
+    ]?;
+    let interval = months_days_ns::new(1, 0, 3_600_000_000_000);
+
+    let out = df
+        .lazy()
+        .select([col("datetime")
+            + lit(Scalar::new(
+                DataType::Interval,
+                AnyValue::Interval(interval),
+            ))])
+        .collect()?;
+
+    let expected = NaiveDate::from_ymd_opt(2024, 2, 29)
+        .unwrap()
+        .and_hms_opt(13, 0, 0)
+        .unwrap();
+    let expected = df!["datetime" => [expected]]?;
+    assert!(out.equals(&expected));
+    Ok(())
+}
+
+#[test]
+fn test_str_to_interval() -> PolarsResult<()> {
+    let df = df![
+        "s" => [Some("1mo 2d 3h"), Some("-1y 5d"), Some("2 months 5 days"), Some("nope"), None],
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([col("s").str().to_interval(false)])
+        .collect()?;
+    let out: Vec<_> = out.column("s")?.interval()?.iter_intervals().collect();
+    assert_eq!(
+        out,
+        [
+            Some(months_days_ns::new(1, 2, 3 * 3_600_000_000_000)),
+            Some(months_days_ns::new(-12, 5, 0)),
+            Some(months_days_ns::new(2, 5, 0)),
+            None,
+            None,
+        ]
+    );
+
+    let strict = df
+        .lazy()
+        .select([col("s").str().to_interval(true)])
+        .collect();
+    assert!(strict.is_err());
+    Ok(())
+}
@@ -1 +1,3 @@
 mod date_range;
+#[cfg(all(feature = "dtype-interval", feature = "lazy", feature = "offset_by"))]
+mod interval;
@@ -55,9 +55,11 @@ from polars.datatypes import (
     Date,
     Datetime,
     Decimal,
+    Decimal256,
     Duration,
     Enum,
     Field,
+    Float16,
     Float32,
     Float64,
     Int8,
@@ -65,7 +67,9 @@ from polars.datatypes import (
     Int32,
     Int64,
     Int128,
+    Interval,
     List,
+    Map,
     Null,
     Object,
     String,
@@ -75,8 +79,10 @@ from polars.datatypes import (
     UInt16,
     UInt32,
     UInt64,
+    Uuid,
     Unknown,
     Utf8,
+    Variant,
 )
 from polars.expr import Expr
 from polars.functions import (
@@ -249,9 +255,11 @@ __all__ = [
     "Date",
     "Datetime",
     "Decimal",
+    "Decimal256",
     "Duration",
     "Enum",
     "Field",
+    "Float16",
     "Float32",
     "Float64",
     "Int8",
@@ -259,7 +267,9 @@ __all__ = [
     "Int32",
     "Int64",
     "Int128",
+    "Interval",
     "List",
+    "Map",
     "Null",
     "Object",
     "String",
@@ -269,8 +279,10 @@ __all__ = [
     "UInt16",
     "UInt32",
     "UInt64",
+    "Uuid",
     "Unknown",
     "Utf8",
+    "Variant",
     # polars.io
     "defer",
     "PartitionByKey",
@@ -9,9 +9,11 @@ from polars.datatypes.classes import (
     Date,
     Datetime,
     Decimal,
+    Decimal256,
     Duration,
     Enum,
     Field,
+    Float16,
     Float32,
     Float64,
     Int8,
@@ -19,8 +21,10 @@ from polars.datatypes.classes import (
     Int32,
     Int64,
     Int128,
+    Interval,
     IntegerType,
     List,
+    Map,
     Null,
     Object,
     String,
@@ -31,8 +35,10 @@ from polars.datatypes.classes import (
     UInt16,
     UInt32,
     UInt64,
+    Uuid,
     Unknown,
     Utf8,
+    Variant,
 )
 from polars.datatypes.constants import (
     DTYPE_TEMPORAL_UNITS,
@@ -66,18 +72,22 @@ __all__ = [
     "Date",
     "Datetime",
     "Decimal",
+    "Decimal256",
     "Duration",
     "Enum",
     "Field",
+    "Float16",
     "Float32",
     "Float64",
     "Int16",
     "Int128",
+    "Interval",
     "Int32",
     "Int64",
     "Int8",
     "IntegerType",
     "List",
+    "Map",
     "Null",
     "Object",
     "String",
@@ -87,9 +97,11 @@ __all__ = [
     "UInt16",
     "UInt32",
     "UInt64",
+    "Uuid",
     "UInt8",
     "Unknown",
     "Utf8",
+    "Variant",
     # constants
     "N_INFER_DEFAULT",
     "DTYPE_TEMPORAL_UNITS",
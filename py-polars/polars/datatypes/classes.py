@@ -359,6 +359,10 @@ class UInt64(UnsignedIntegerType):
     """64-bit unsigned integer type."""
 
 
+class Float16(FloatType):
+    """16-bit (half precision) floating point type."""
+
+
 class Float32(FloatType):
     """32-bit floating point type."""
 
@@ -423,6 +427,60 @@ class Decimal(NumericType):
         return hash((self.__class__, self.precision, self.scale))
 
 
+class Decimal256(NumericType):
+    """
+    Decimal 256-bit type with an optional precision and non-negative scale.
+
+    .. warning::
+        This functionality is considered **unstable**.
+        It is a work-in-progress feature and may not always work as expected.
+        It may be changed at any point without it being considered a breaking change.
+
+    Parameters
+    ----------
+    precision
+        Maximum number of digits in each number (at most 76).
+        If set to `None` (default), the precision is inferred.
+    scale
+        Number of digits to the right of the decimal point in each number.
+    """
+
+    precision: int | None
+    scale: int
+
+    def __init__(
+        self,
+        precision: int | None = None,
+        scale: int = 0,
+    ) -> None:
+        from polars._utils.unstable import issue_unstable_warning
+
+        issue_unstable_warning(
+            "The Decimal256 data type is considered unstable."
+            " It is a work-in-progress feature and may not always work as expected."
+        )
+
+        self.precision = precision
+        self.scale = scale
+
+    def __repr__(self) -> str:
+        return (
+            f"{self.__class__.__name__}(precision={self.precision}, scale={self.scale})"
+        )
+
+    def __eq__(self, other: PolarsDataType) -> bool:  # type: ignore[override]
+        # allow comparing object instances to class
+        if type(other) is DataTypeClass and issubclass(other, Decimal256):
+            return True
+        elif isinstance(other, Decimal256):
+            return self.precision == other.precision and self.scale == other.scale
+        else:
+            return False
+
+    def __hash__(self) -> int:
+        return hash((self.__class__, self.precision, self.scale))
+
+
 class Boolean(DataType):
     """Boolean type."""
 
@@ -439,6 +497,24 @@ class Binary(DataType):
     """Binary type."""
 
 
+class Uuid(DataType):
+    """
+    128-bit universally unique identifier type.
+
+    The values are stored as 16-byte fixed-size binary, which is also how they are
+    exported to Arrow, IPC and Parquet. Values are returned to Python as their
+    canonical hyphenated string.
+    """
+
+
+class Variant(DataType):
+    """
+    Semi-structured data type holding arbitrary JSON-like values.
+
+    Values are returned to Python as their JSON string.
+    """
+
+
 class Date(TemporalType):
     """
     Data type representing a calendar date.
@@ -607,6 +683,15 @@ class Duration(TemporalType):
         return f"{class_name}(time_unit={self.time_unit!r})"
 
 
+class Interval(DataType):
+    """
+    Calendar-aware interval type made up of months, days and nanoseconds.
+
+    Unlike :class:`Duration`, the month and day components keep their calendar
+    meaning. Values are returned to Python as their string representation.
+    """
+
+
 class Categorical(DataType):
     """
     A categorical encoding of a set of strings.
@@ -796,6 +881,46 @@ class List(NestedType):
         return f"{class_name}({self.inner!r})"
 
 
+class Map(NestedType):
+    """
+    Map type, a list of key/value entries per row.
+
+    Parameters
+    ----------
+    key
+        The `DataType` of the keys.
+    value
+        The `DataType` of the values.
+    """
+
+    key: PolarsDataType
+    value: PolarsDataType
+
+    def __init__(
+        self,
+        key: PolarsDataType | PythonDataType,
+        value: PolarsDataType | PythonDataType,
+    ) -> None:
+        self.key = polars.datatypes.parse_into_dtype(key)
+        self.value = polars.datatypes.parse_into_dtype(value)
+
+    def __eq__(self, other: PolarsDataType) -> bool:  # type: ignore[override]
+        # allow comparing object instances to class
+        if type(other) is DataTypeClass and issubclass(other, Map):
+            return True
+        elif isinstance(other, Map):
+            return self.key == other.key and self.value == other.value
+        else:
+            return False
+
+    def __hash__(self) -> int:
+        return hash((self.__class__, self.key, self.value))
+
+    def __repr__(self) -> str:
+        class_name = self.__class__.__name__
+        return f"{class_name}({self.key!r}, {self.value!r})"
+
+
 class Array(NestedType):
     """
     Fixed length list type.
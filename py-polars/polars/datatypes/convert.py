@@ -19,9 +19,11 @@ from polars.datatypes.classes import (
     Date,
     Datetime,
     Decimal,
+    Decimal256,
     Duration,
     Enum,
     Field,
+    Float16,
     Float32,
     Float64,
     Int8,
@@ -29,7 +31,9 @@ from polars.datatypes.classes import (
     Int32,
     Int64,
     Int128,
+    Interval,
     List,
+    Map,
     Null,
     Object,
     String,
@@ -39,7 +43,9 @@ from polars.datatypes.classes import (
     UInt16,
     UInt32,
     UInt64,
+    Uuid,
     Unknown,
+    Variant,
 )
 from polars.dependencies import numpy as np
 from polars.dependencies import pyarrow as pa
@@ -176,7 +182,9 @@ class _DataTypeMappings:
             Date: date,
             Datetime: datetime,
             Decimal: PyDecimal,
+            Decimal256: str,
             Duration: timedelta,
+            Float16: float,
             Float32: float,
             Float64: float,
             Int128: int,
@@ -184,7 +192,9 @@ class _DataTypeMappings:
             Int32: int,
             Int64: int,
             Int8: int,
+            Interval: str,
             List: list,
+            Map: list,
             Null: None.__class__,
             Object: object,
             String: str,
@@ -194,6 +204,8 @@ class _DataTypeMappings:
             UInt32: int,
             UInt64: int,
             UInt8: int,
+            Uuid: str,
+            Variant: str,
             # the below mappings are appropriate as we restrict cat/enum to strings
             Enum: str,
             Categorical: str,
@@ -80,6 +80,7 @@ if TYPE_CHECKING:
         PolarsDataType,
         RankMethod,
         RollingInterpolationMethod,
+        RoundMode,
         SchemaDict,
         SearchSortedSide,
         SerializationFormat,
@@ -1629,14 +1630,17 @@ class Expr:
         """
         return self._from_pyexpr(self._pyexpr.ceil())
 
-    def round(self, decimals: int = 0) -> Expr:
+    def round(self, decimals: int = 0, mode: RoundMode | None = None) -> Expr:
         """
-        Round underlying floating point data by `decimals` digits.
+        Round underlying floating point or decimal data by `decimals` digits.
 
         Parameters
         ----------
         decimals
             Number of decimals to round by.
+        mode : {'half_to_even', 'half_away_from_zero', 'toward_zero', 'away_from_zero', 'floor', 'ceiling'}
+            How to round values that lie between two candidates. By default floats
+            round half away from zero and decimals round half to even.
 
         Examples
         --------
@@ -1654,7 +1658,7 @@ class Expr:
         │ 1.2 │
         └─────┘
         """
-        return self._from_pyexpr(self._pyexpr.round(decimals))
+        return self._from_pyexpr(self._pyexpr.round(decimals, mode))
 
     def round_sig_figs(self, digits: int) -> Expr:
         """
@@ -379,6 +379,7 @@ def scan_ipc(
     hive_schema: SchemaDict | None = None,
     try_parse_hive_dates: bool = True,
     include_file_paths: str | None = None,
+    categorical_dictionaries: dict[str, str | Path] | None = None,
 ) -> LazyFrame:
     """
     Lazily read from an Arrow IPC (Feather v2) file or multiple files via glob patterns.
@@ -452,6 +453,14 @@ def scan_ipc(
         Whether to try parsing hive values as date/datetime types.
     include_file_paths
         Include the path of the source file(s) as a column with this name.
+    categorical_dictionaries
+        Encode `String` or `Categorical` columns with the persisted categorical
+        dictionaries at the given paths, by column name. Errors if a file was
+        written with a version of a dictionary that is incompatible with it.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
     """
     sources: list[str] | list[Path] | list[IO[bytes]] | list[bytes] = []
     if isinstance(source, (str, Path)):
@@ -496,5 +505,6 @@ def scan_ipc(
         hive_schema=hive_schema,
         try_parse_hive_dates=try_parse_hive_dates,
         include_file_paths=include_file_paths,
+        categorical_dictionaries=categorical_dictionaries,
     )
     return wrap_ldf(pylf)
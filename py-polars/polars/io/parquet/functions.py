@@ -352,6 +352,7 @@ def scan_parquet(
     retries: int = 2,
     include_file_paths: str | None = None,
     allow_missing_columns: bool = False,
+    categorical_dictionaries: dict[str, str | Path] | None = None,
 ) -> LazyFrame:
     """
     Lazily read from a local or cloud-hosted parquet file (or files).
@@ -455,6 +456,14 @@ def scan_parquet(
         raise an error. However, if `allow_missing_columns` is set to
         `True`, a full-NULL column is returned instead of erroring for the files
         that do not contain the column.
+    categorical_dictionaries
+        Encode `String` or `Categorical` columns with the persisted categorical
+        dictionaries at the given paths, by column name. Errors if a file was
+        written with a version of a dictionary that is incompatible with it.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
 
     See Also
     --------
@@ -517,6 +526,7 @@ def scan_parquet(
         glob=glob,
         include_file_paths=include_file_paths,
         allow_missing_columns=allow_missing_columns,
+        categorical_dictionaries=categorical_dictionaries,
     )
 
 
@@ -541,6 +551,7 @@ def _scan_parquet_impl(
     retries: int = 2,
     include_file_paths: str | None = None,
     allow_missing_columns: bool = False,
+    categorical_dictionaries: dict[str, str | Path] | None = None,
 ) -> LazyFrame:
     if isinstance(source, list):
         sources = source
@@ -574,5 +585,6 @@ def _scan_parquet_impl(
         glob=glob,
         include_file_paths=include_file_paths,
         allow_missing_columns=allow_missing_columns,
+        categorical_dictionaries=categorical_dictionaries,
     )
     return wrap_ldf(pylf)
@@ -2435,6 +2435,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         retries: int = 2,
         sync_on_close: SyncOnCloseMethod | None = None,
         mkdir: bool = False,
+        categorical_dictionaries: dict[str, str | Path] | None = None,
         lazy: Literal[False] = ...,
         engine: EngineType = "auto",
     ) -> None: ...
@@ -2464,6 +2465,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         retries: int = 2,
         sync_on_close: SyncOnCloseMethod | None = None,
         mkdir: bool = False,
+        categorical_dictionaries: dict[str, str | Path] | None = None,
         lazy: Literal[True],
         engine: EngineType = "auto",
     ) -> LazyFrame: ...
@@ -2493,6 +2495,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         retries: int = 2,
         sync_on_close: SyncOnCloseMethod | None = None,
         mkdir: bool = False,
+        categorical_dictionaries: dict[str, str | Path] | None = None,
         lazy: bool = False,
         engine: EngineType = "auto",
     ) -> LazyFrame | None:
@@ -2595,6 +2598,15 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             * `all` syncs the file contents and metadata.
         mkdir: bool
             Recursively create all the directories in the path.
+        categorical_dictionaries
+            Encode `String` or `Categorical` columns with the persisted categorical
+            dictionaries at the given paths, by column name. The dictionary versions
+            are recorded in the file, so that scans with the same dictionaries can
+            validate them.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
         lazy: bool
             Wait to start execution until `collect` is called.
         engine
@@ -2673,6 +2685,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             "sync_on_close": sync_on_close or "none",
             "maintain_order": maintain_order,
             "mkdir": mkdir,
+            "categorical_dictionaries": categorical_dictionaries,
         }
 
         lf = lf.sink_parquet(
@@ -2717,6 +2730,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         retries: int = 2,
         sync_on_close: SyncOnCloseMethod | None = None,
         mkdir: bool = False,
+        categorical_dictionaries: dict[str, str | Path] | None = None,
         lazy: Literal[False] = ...,
         engine: EngineType = "auto",
     ) -> None: ...
@@ -2743,6 +2757,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         retries: int = 2,
         sync_on_close: SyncOnCloseMethod | None = None,
         mkdir: bool = False,
+        categorical_dictionaries: dict[str, str | Path] | None = None,
         lazy: Literal[True],
         engine: EngineType = "auto",
     ) -> LazyFrame: ...
@@ -2769,6 +2784,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         retries: int = 2,
         sync_on_close: SyncOnCloseMethod | None = None,
         mkdir: bool = False,
+        categorical_dictionaries: dict[str, str | Path] | None = None,
         lazy: bool = False,
         engine: EngineType = "auto",
     ) -> LazyFrame | None:
@@ -2840,6 +2856,15 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             * `all` syncs the file contents and metadata.
         mkdir: bool
             Recursively create all the directories in the path.
+        categorical_dictionaries
+            Encode `String` or `Categorical` columns with the persisted categorical
+            dictionaries at the given paths, by column name. The dictionary versions
+            are recorded in the file, so that scans with the same dictionaries can
+            validate them.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
         lazy: bool
             Wait to start execution until `collect` is called.
         engine
@@ -2901,6 +2926,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             "sync_on_close": sync_on_close or "none",
             "maintain_order": maintain_order,
             "mkdir": mkdir,
+            "categorical_dictionaries": categorical_dictionaries,
         }
 
         if compat_level is None:
@@ -3218,6 +3244,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             "sync_on_close": sync_on_close or "none",
             "maintain_order": maintain_order,
             "mkdir": mkdir,
+            "categorical_dictionaries": None,
         }
 
         lf = lf.sink_csv(
@@ -3442,6 +3469,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             "sync_on_close": sync_on_close or "none",
             "maintain_order": maintain_order,
             "mkdir": mkdir,
+            "categorical_dictionaries": None,
         }
 
         lf = lf.sink_json(
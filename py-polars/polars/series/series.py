@@ -135,6 +135,7 @@ if TYPE_CHECKING:
         PythonLiteral,
         RankMethod,
         RollingInterpolationMethod,
+        RoundMode,
         SearchSortedSide,
         SeriesBuffers,
         SingleIndexSelector,
@@ -5018,9 +5019,9 @@ class Series:
         ]
         """
 
-    def round(self, decimals: int = 0) -> Series:
+    def round(self, decimals: int = 0, mode: RoundMode | None = None) -> Series:
         """
-        Round underlying floating point data by `decimals` digits.
+        Round underlying floating point or decimal data by `decimals` digits.
 
         Examples
         --------
@@ -5038,6 +5039,9 @@ class Series:
         ----------
         decimals
             number of decimals to round by.
+        mode : {'half_to_even', 'half_away_from_zero', 'toward_zero', 'away_from_zero', 'floor', 'ceiling'}
+            How to round values that lie between two candidates. By default floats
+            round half away from zero and decimals round half to even.
         """
 
     def round_sig_figs(self, digits: int) -> Series:
@@ -0,0 +1,35 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+
+
+@pytest.mark.parametrize(
+    ("values", "dtype"),
+    [
+        ([1.5, None, -2.25], pl.Float16),
+        (["550e8400-e29b-41d4-a716-446655440000", None], pl.Uuid),
+        (['{"a": 1}', None, "[1, 2]"], pl.Variant),
+    ],
+)
+def test_logical_dtype_reported(values: list[object], dtype: pl.DataType) -> None:
+    s = pl.Series("a", values).cast(dtype)
+    assert s.dtype == dtype
+    assert pl.DataFrame([s]).schema == {"a": dtype}
+
+
+def test_decimal256_dtype_reported() -> None:
+    s = pl.Series("a", [1, None, 3]).cast(pl.Decimal256(50, 2))
+    assert s.dtype == pl.Decimal256(50, 2)
+    assert s.dtype != pl.Decimal(38, 2)
+    assert s.dtype == pl.Decimal256
+
+
+def test_map_dtype_equality() -> None:
+    dtype = pl.Map(pl.String, pl.Int64)
+    assert dtype == pl.Map
+    assert dtype == pl.Map(pl.String, pl.Int64)
+    assert dtype != pl.Map(pl.String, pl.Int32)
+    assert dtype != pl.List(pl.Struct({"key": pl.String, "value": pl.Int64}))
+    assert repr(dtype) == "Map(String, Int64)"
@@ -309,25 +309,26 @@ def test_join_misc_16255() -> None:
 
 
 @pytest.mark.parametrize(
-    "constraint", ["tbl.a != tbl.b", "tbl.a > tbl.b", "a >= b", "a < b", "b <= a"]
+    ("constraint", "expected"),
+    [
+        ("t1.a > t2.b", [(1, None), (2, None), (3, 2)]),
+        ("t2.b >= t1.a + 2", [(1, 3), (1, 4), (2, 4), (3, None)]),
+        ("t1.a BETWEEN t2.b - 1 AND t2.b", [(1, 2), (2, 2), (2, 3), (3, 3), (3, 4)]),
+        ("t1.a <> t2.b AND t2.b < 3", [(1, 2), (2, None), (3, 2)]),
+    ],
 )
-def test_non_equi_joins(constraint: str) -> None:
-    # no support (yet) for non equi-joins in polars joins
-    # TODO: integrate awareness of new IEJoin
-    with (
-        pytest.raises(
-            SQLInterfaceError,
-            match=r"only equi-join constraints \(combined with 'AND'\) are currently supported",
-        ),
-        pl.SQLContext({"tbl": pl.DataFrame({"a": [1, 2, 3], "b": [4, 3, 2]})}) as ctx,
-    ):
-        ctx.execute(
+def test_non_equi_joins(constraint: str, expected: list[tuple[int, int | None]]) -> None:
+    with pl.SQLContext({"tbl": pl.DataFrame({"a": [1, 2, 3], "b": [4, 3, 2]})}) as ctx:
+        res = ctx.execute(
             f"""
-            SELECT *
-            FROM tbl
-            LEFT JOIN tbl ON {constraint}  -- not an equi-join
-            """
+            SELECT t1.a, t2.b
+            FROM tbl t1
+            LEFT JOIN tbl t2 ON {constraint}
+            ORDER BY t1.a, t2.b
+            """,
+            eager=True,
         )
+    assert res.rows() == expected
 
 
 def test_implicit_joins() -> None:
@@ -720,3 +721,32 @@ def test_nulls_equal_19624() -> None:
     result_df = df2.join(df1, how="inner", on="a", nulls_equal=False, validate="m:1")
     expected_df = pl.DataFrame({"a": [1, 1, 2, 2], "b": [0, 1, 2, 3]})
     assert_frame_equal(result_df, expected_df)
+
+
+def test_implicit_and_lateral_joins() -> None:
+    df1 = pl.DataFrame({"id": [1, 2, 3], "name": ["a", "b", "c"]})
+    df2 = pl.DataFrame({"id": [1, 1, 2, 3], "value": [10, 20, 30, 40]})
+
+    with pl.SQLContext(df1=df1, df2=df2) as ctx:
+        res = ctx.execute(
+            """
+            SELECT df1.name, df2.value
+            FROM df1, df2
+            WHERE df1.id = df2.id AND df2.value > 10
+            ORDER BY df1.name
+            """,
+            eager=True,
+        )
+        assert res.rows() == [("a", 20), ("b", 30), ("c", 40)]
+
+        res = ctx.execute(
+            """
+            SELECT df1.name, t.value
+            FROM df1, LATERAL (
+              SELECT value FROM df2 WHERE df2.id = df1.id ORDER BY value LIMIT 1
+            ) AS t
+            ORDER BY df1.name
+            """,
+            eager=True,
+        )
+        assert res.rows() == [("a", 10), ("b", 30), ("c", 40)]
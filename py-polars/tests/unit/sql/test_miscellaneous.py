@@ -518,3 +518,48 @@ def test_select_explode_height_filter_order_by() -> None:
         ),
         pl.Series("list", [2, 1, 3, 4, 5, 6]).to_frame(),
     )
+
+
+def test_window_frames_and_qualify() -> None:
+    df = pl.DataFrame(
+        {
+            "grp": ["a", "a", "a", "a", "b", "b"],
+            "ts": [1, 2, 2, 5, 1, 3],
+            "val": [1, 2, 3, 4, None, 10],
+        }
+    )
+    res = df.sql(
+        """
+        SELECT
+          grp,
+          ts,
+          SUM(val) OVER (w ROWS BETWEEN 1 PRECEDING AND 1 FOLLOWING) AS sum_rows,
+          SUM(val) OVER (w RANGE BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW) AS sum_range,
+          AVG(val) OVER (w RANGE BETWEEN 1 PRECEDING AND CURRENT ROW) AS avg_offset
+        FROM self
+        WINDOW w AS (PARTITION BY grp ORDER BY ts)
+        """
+    )
+    assert res.to_dict(as_series=False) == {
+        "grp": ["a", "a", "a", "a", "b", "b"],
+        "ts": [1, 2, 2, 5, 1, 3],
+        "sum_rows": [3, 6, 9, 7, 10, 10],
+        "sum_range": [1, 6, 6, 10, None, 10],
+        "avg_offset": [1.0, 2.0, 2.0, 4.0, None, 10.0],
+    }
+
+    res = df.sql(
+        """
+        SELECT grp, ts, val FROM self
+        QUALIFY val = MAX(val) OVER (PARTITION BY grp)
+        ORDER BY grp
+        """
+    )
+    assert res.to_dict(as_series=False) == {
+        "grp": ["a", "b"],
+        "ts": [5, 3],
+        "val": [4, 10],
+    }
+
+    with pytest.raises(SQLSyntaxError, match="QUALIFY requires a window function"):
+        df.sql("SELECT grp FROM self QUALIFY ts > 1")
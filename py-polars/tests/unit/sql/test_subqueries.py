@@ -146,3 +146,53 @@ def test_in_subquery() -> None:
             """,
             eager=True,
         )
+
+
+def test_correlated_subqueries() -> None:
+    customers = pl.DataFrame(
+        {
+            "id": [1, 2, 3, 4],
+            "name": ["ann", "bob", "cat", "dan"],
+        }
+    )
+    orders = pl.DataFrame(
+        {
+            "customer_id": [1, 1, 2, 3, 3, 3],
+            "amount": [10, 30, 5, 20, 20, 50],
+        }
+    )
+    with pl.SQLContext(customers=customers, orders=orders) as ctx:
+        for query, expected in (
+            (
+                """
+                SELECT name FROM customers c
+                WHERE EXISTS (SELECT 1 FROM orders o WHERE o.customer_id = c.id)
+                """,
+                ["ann", "bob", "cat"],
+            ),
+            (
+                """
+                SELECT name FROM customers c
+                WHERE NOT EXISTS (SELECT 1 FROM orders o WHERE o.customer_id = c.id)
+                """,
+                ["dan"],
+            ),
+            (
+                """
+                SELECT name FROM customers c
+                WHERE 20 IN (SELECT amount FROM orders o WHERE o.customer_id = c.id)
+                """,
+                ["cat"],
+            ),
+            (
+                """
+                SELECT name FROM customers c
+                WHERE (
+                  SELECT SUM(amount) FROM orders o WHERE o.customer_id = c.id
+                ) >= 40
+                """,
+                ["ann", "cat"],
+            ),
+        ):
+            res = ctx.execute(f"{query} ORDER BY name", eager=True)
+            assert res["name"].to_list() == expected
@@ -100,6 +100,38 @@ def test_show_tables(test_frame: pl.LazyFrame) -> None:
         assert_frame_equal(res, pl.DataFrame({"name": ["tbl1", "tbl2", "tbl3"]}))
 
 
+def test_describe_and_information_schema() -> None:
+    df = pl.LazyFrame(
+        {
+            "id": [1, 2],
+            "info": [{"tags": ["a"], "score": 1.5}, {"tags": ["b"], "score": 2.5}],
+        }
+    )
+    with pl.SQLContext(tbl=df, eager=True) as ctx:
+        expected = pl.DataFrame(
+            {
+                "column_name": ["id", "info"],
+                "data_type": ["BIGINT", "STRUCT(tags VARCHAR[], score DOUBLE)"],
+                "is_nullable": [None, None],
+            },
+            schema_overrides={"is_nullable": pl.String},
+        )
+        for sql in ("DESCRIBE tbl", "SHOW COLUMNS FROM tbl"):
+            assert_frame_equal(ctx.execute(sql), expected)
+
+        res = ctx.execute("SELECT table_name, table_type FROM information_schema.tables")
+        assert res.rows() == [("tbl", "BASE TABLE")]
+
+        res = ctx.execute(
+            """
+            SELECT column_name, ordinal_position
+            FROM information_schema.columns
+            WHERE table_name = 'tbl'
+            """
+        )
+        assert res.rows() == [("id", 1), ("info", 2)]
+
+
 @pytest.mark.parametrize(
     "truncate_sql",
     [
@@ -117,3 +149,118 @@ def test_truncate_table(truncate_sql: str, test_frame: pl.LazyFrame) -> None:
 
         res = ctx.execute("SELECT * FROM frame")
         assert_frame_equal(res, expected)
+
+
+def test_insert_update_merge(test_frame: pl.LazyFrame) -> None:
+    updates = pl.LazyFrame({"x": [2, 4], "y": ["xxx", "ddd"]})
+    with pl.SQLContext(frame=test_frame, updates=updates, eager=True) as ctx:
+        ctx.execute("INSERT INTO frame (x, y) VALUES (5, 'eee')")
+        ctx.execute("UPDATE frame SET y = UPPER(y) WHERE x < 2")
+        ctx.execute(
+            """
+            MERGE INTO frame AS f USING updates AS u ON f.x = u.x
+            WHEN MATCHED THEN UPDATE SET y = u.y
+            WHEN NOT MATCHED THEN INSERT (x, y) VALUES (u.x, u.y)
+            """
+        )
+        res = ctx.execute("SELECT x, y, z FROM frame")
+        assert_frame_equal(
+            res,
+            pl.DataFrame(
+                {
+                    "x": [1, 2, 3, 5, 4],
+                    "y": ["AAA", "xxx", "ccc", "eee", "ddd"],
+                    "z": [
+                        date(2000, 12, 31),
+                        date(1978, 11, 15),
+                        date(2077, 10, 20),
+                        None,
+                        None,
+                    ],
+                },
+                schema_overrides={"x": pl.UInt8},
+            ),
+        )
+
+
+def test_merge_multiple_source_matches(test_frame: pl.LazyFrame) -> None:
+    updates = pl.LazyFrame({"x": [2, 2], "y": ["xxx", "yyy"]})
+    with pl.SQLContext(frame=test_frame, updates=updates) as ctx:
+        with pytest.raises(SQLInterfaceError, match="more than once"):
+            ctx.execute(
+                """
+                MERGE INTO frame USING updates ON frame.x = updates.x
+                WHEN MATCHED THEN UPDATE SET y = updates.y
+                """
+            )
+
+
+def test_create_table_and_view(test_frame: pl.LazyFrame) -> None:
+    with pl.SQLContext(frame=test_frame, eager=True) as ctx:
+        ctx.execute("CREATE TABLE tbl (id INT, name VARCHAR)")
+        assert ctx.execute("SELECT * FROM tbl").schema == {
+            "id": pl.Int32,
+            "name": pl.String,
+        }
+        ctx.execute(
+            "CREATE VIEW frame_view (a, b) AS SELECT x, y FROM frame WHERE x > 1"
+        )
+        ctx.execute("DELETE FROM frame WHERE x = 3")
+        ctx.execute("TRUNCATE frame")
+        assert ctx.tables() == ["frame", "frame_view", "tbl"]
+        assert ctx.execute("SELECT * FROM frame_view").is_empty()
+
+        with pytest.raises(SQLInterfaceError, match="relation 'tbl' already exists"):
+            ctx.execute("CREATE VIEW tbl AS SELECT 1")
+
+        ctx.execute("DROP VIEW frame_view")
+        assert ctx.tables() == ["frame", "tbl"]
+
+
+def test_pivot_unpivot() -> None:
+    df = pl.DataFrame(
+        {
+            "region": ["east", "east", "west"],
+            "quarter": ["q1", "q2", "q1"],
+            "sales": [10, 20, 30],
+        }
+    )
+    res = df.sql(
+        """
+        SELECT * FROM self
+        PIVOT (SUM(sales) AS total FOR quarter IN ('q1', 'q2'))
+        """
+    )
+    expected = pl.DataFrame(
+        {
+            "region": ["east", "west"],
+            "q1_total": [10, 30],
+            "q2_total": [20, None],
+        }
+    )
+    assert_frame_equal(res, expected)
+
+    res = expected.sql(
+        """
+        SELECT * FROM self
+        UNPIVOT (sales FOR quarter IN (q1_total, q2_total))
+        """
+    )
+    assert_frame_equal(
+        res,
+        pl.DataFrame(
+            {
+                "region": ["east", "east", "west"],
+                "quarter": ["q1_total", "q2_total", "q1_total"],
+                "sales": [10, 20, 30],
+            }
+        ),
+    )
+
+
+def test_unnest_with_ordinality() -> None:
+    res = pl.sql(
+        "SELECT * FROM UNNEST(['a', 'b', 'c']) WITH ORDINALITY AS t (val, n)",
+        eager=True,
+    )
+    assert res.rows() == [("a", 1), ("b", 2), ("c", 3)]